@@ -35,6 +35,7 @@ fn main() {
             true => String::from("sim"),
             false => String::from("não"),
         },
+        countdown: None,
         render_config: RenderConfig::default(),
     }
     .prompt()
@@ -1,4 +1,7 @@
-use inquire::{error::CustomUserError, length, required, ui::RenderConfig, Text};
+use inquire::{
+    error::CustomUserError, length, required, ui::RenderConfig, InputKeymap, LengthUnit, Text,
+    UndoKeys,
+};
 
 fn main() {
     let answer = Text::new("What's your name?")
@@ -14,12 +17,29 @@ fn main() {
         message: "How are you feeling?",
         initial_value: None,
         default: None,
+        default_chain: None,
         placeholder: Some("Good"),
+        dynamic_placeholder: None,
         help_message: None,
+        dynamic_help_message: None,
         formatter: Text::DEFAULT_FORMATTER,
         validators: Vec::new(),
+        on_change: None,
+        transformer: None,
         page_size: Text::DEFAULT_PAGE_SIZE,
+        ghost_text: Text::DEFAULT_GHOST_TEXT,
+        dropdown: Text::DEFAULT_DROPDOWN,
+        vim_mode: Text::DEFAULT_VIM_MODE,
+        mask: None,
+        delimiter: None,
+        length_counter: None,
+        max_length: None,
+        length_unit: LengthUnit::default(),
         autocompleter: None,
+        loading_message: Text::DEFAULT_LOADING_MESSAGE,
+        history: None,
+        undo_keys: UndoKeys::default(),
+        keymap: InputKeymap::default(),
         render_config: RenderConfig::default(),
     }
     .prompt()
@@ -8,9 +8,28 @@
 //! selection, if any. Then the developer may return a [Replacement] action
 //! where the current user text input is replaced or not by a provided string.
 //!
+//! Suggestions backed by network APIs or large indexes can be computed on a
+//! worker thread with [BackgroundAutocomplete], so that typing is not blocked
+//! while they are loading.
+//!
+//! Prompts asking for filesystem paths can use the built-in [PathCompleter],
+//! while [CommandCompleter] reuses the completions of existing command-line
+//! tools.
+//!
 //! Check the example files to see some usages, recommended are `expense_tracker.rs`
 //! and `complex_autocompletion.rs`.
 
+use std::{
+    env, fs,
+    path::{Path, PathBuf},
+    process::Command,
+    sync::{
+        mpsc::{self, Receiver, Sender, TryRecvError},
+        Arc, Mutex,
+    },
+    thread,
+};
+
 use dyn_clone::DynClone;
 
 use crate::CustomUserError;
@@ -55,6 +74,24 @@ pub trait Autocomplete: DynClone {
         input: &str,
         highlighted_suggestion: Option<String>,
     ) -> Result<Replacement, CustomUserError>;
+
+    /// Whether the suggestions requested by the last call to `get_suggestions`
+    /// are still being computed, in which case the prompt displays a loading
+    /// indicator and calls `poll_suggestions` until they are ready.
+    ///
+    /// The default implementation returns false, as suggestions are usually
+    /// returned right away by `get_suggestions`.
+    fn is_loading(&self) -> bool {
+        false
+    }
+
+    /// Suggestions computed since the last poll, if any, replacing the ones
+    /// displayed to the user.
+    ///
+    /// The default implementation returns `None`.
+    fn poll_suggestions(&mut self) -> Result<Option<Vec<String>>, CustomUserError> {
+        Ok(None)
+    }
 }
 
 impl Clone for Box<dyn Autocomplete> {
@@ -98,3 +135,605 @@ where
         Ok(suggestion)
     }
 }
+
+type SuggestionsResponse = (u64, Result<Vec<String>, CustomUserError>);
+
+/// Autocompleter that computes the suggestions of another one on a worker
+/// thread, instead of blocking the prompt on every keystroke.
+///
+/// While the suggestions for the current input are loading, the previous ones
+/// are still displayed, along with a loading indicator. When the input changes
+/// faster than the suggestions are computed, only the latest input is sent to
+/// the wrapped autocompleter.
+///
+/// Completions are computed right away by a clone of the wrapped autocompleter,
+/// which does not see the calls to `get_suggestions` made on the worker thread.
+///
+/// The suggestions are polled while waiting for key presses with a timeout,
+/// which the `console` backend does not support, failing the prompt with an
+/// `InquireError::InvalidConfiguration` error once they start loading.
+///
+/// # Example
+///
+/// ```no_run
+/// use inquire::{autocompletion::BackgroundAutocomplete, CustomUserError, Text};
+///
+/// let suggester = |input: &str| -> Result<Vec<String>, CustomUserError> {
+///     // query a remote API for the packages matching the input
+///     std::thread::sleep(std::time::Duration::from_millis(300));
+///     Ok(vec![format!("{input}-core"), format!("{input}-utils")])
+/// };
+///
+/// let package = Text::new("Package:")
+///     .with_autocomplete(BackgroundAutocomplete::new(suggester))
+///     .prompt();
+/// ```
+#[derive(Clone)]
+pub struct BackgroundAutocomplete<A> {
+    autocompleter: A,
+    requests: Sender<(u64, String)>,
+    responses: Arc<Mutex<Receiver<SuggestionsResponse>>>,
+    // identifiers of the last requested and received suggestions
+    requested: u64,
+    received: u64,
+    suggestions: Vec<String>,
+}
+
+impl<A> BackgroundAutocomplete<A>
+where
+    A: Autocomplete + Clone + Send + 'static,
+{
+    /// Creates an autocompleter that calls the `get_suggestions` method of the
+    /// provided one on a worker thread, which finishes once every clone of the
+    /// autocompleter is dropped.
+    pub fn new(autocompleter: A) -> Self {
+        let (requests, request_receiver) = mpsc::channel::<(u64, String)>();
+        let (response_sender, responses) = mpsc::channel();
+
+        let worker = autocompleter.clone();
+        thread::spawn(move || suggest_in_background(worker, request_receiver, response_sender));
+
+        Self {
+            autocompleter,
+            requests,
+            responses: Arc::new(Mutex::new(responses)),
+            requested: 0,
+            received: 0,
+            suggestions: vec![],
+        }
+    }
+}
+
+/// Computes the suggestions requested by a [BackgroundAutocomplete] until
+/// every clone of it is dropped.
+fn suggest_in_background<A: Autocomplete>(
+    mut autocompleter: A,
+    requests: Receiver<(u64, String)>,
+    responses: Sender<SuggestionsResponse>,
+) {
+    while let Ok(mut request) = requests.recv() {
+        // suggestions for outdated inputs are not worth computing
+        while let Ok(newer) = requests.try_recv() {
+            request = newer;
+        }
+
+        let (id, input) = request;
+        let suggestions = autocompleter.get_suggestions(&input);
+
+        if responses.send((id, suggestions)).is_err() {
+            break;
+        }
+    }
+}
+
+impl<A> Autocomplete for BackgroundAutocomplete<A>
+where
+    A: Autocomplete + Clone,
+{
+    fn get_suggestions(&mut self, input: &str) -> Result<Vec<String>, CustomUserError> {
+        self.requested += 1;
+
+        if self
+            .requests
+            .send((self.requested, input.to_string()))
+            .is_err()
+        {
+            // the worker thread is gone, so no suggestions are coming
+            self.received = self.requested;
+        }
+
+        Ok(self.suggestions.clone())
+    }
+
+    fn get_completion(
+        &mut self,
+        input: &str,
+        highlighted_suggestion: Option<String>,
+    ) -> Result<Replacement, CustomUserError> {
+        self.autocompleter
+            .get_completion(input, highlighted_suggestion)
+    }
+
+    fn is_loading(&self) -> bool {
+        self.received < self.requested
+    }
+
+    fn poll_suggestions(&mut self) -> Result<Option<Vec<String>>, CustomUserError> {
+        let responses = match self.responses.lock() {
+            Ok(responses) => responses,
+            Err(_err) => {
+                self.received = self.requested;
+                return Ok(None);
+            }
+        };
+
+        loop {
+            match responses.try_recv() {
+                Ok((id, suggestions)) if id == self.requested => {
+                    self.received = id;
+                    self.suggestions = suggestions?;
+                    return Ok(Some(self.suggestions.clone()));
+                }
+                Ok(_outdated) => {}
+                Err(TryRecvError::Empty) => return Ok(None),
+                Err(TryRecvError::Disconnected) => {
+                    self.received = self.requested;
+                    return Ok(None);
+                }
+            }
+        }
+    }
+}
+
+/// Autocompleter of filesystem paths, suggesting the entries of the
+/// directory typed so far whose names start with the last component of the
+/// input, e.g. `src/main.rs` and `src/macros/` for `src/ma`.
+///
+/// A leading `~` stands for the home directory of the user, and is kept in
+/// the suggestions as typed, so the answer can be resolved with
+/// [`PathCompleter::resolve`]. Directories are suggested with a trailing `/`,
+/// so that completing one lists its entries right away.
+///
+/// Pressing the autocompletion hotkey without a highlighted suggestion
+/// completes the input to the longest prefix shared by every suggestion, as
+/// shells do.
+///
+/// # Example
+///
+/// ```no_run
+/// use inquire::{autocompletion::PathCompleter, Text};
+///
+/// let path = Text::new("Configuration file:")
+///     .with_autocomplete(PathCompleter::new().with_show_hidden(true))
+///     .prompt()
+///     .map(|path| PathCompleter::resolve(&path));
+/// ```
+#[derive(Clone, Debug)]
+pub struct PathCompleter {
+    show_hidden: bool,
+    directories_first: bool,
+}
+
+impl PathCompleter {
+    /// Default value of whether hidden entries are suggested, false.
+    pub const DEFAULT_SHOW_HIDDEN: bool = false;
+
+    /// Default value of whether directories are suggested before files, true.
+    pub const DEFAULT_DIRECTORIES_FIRST: bool = true;
+
+    /// Creates a path autocompleter with the default options.
+    pub fn new() -> Self {
+        Self {
+            show_hidden: Self::DEFAULT_SHOW_HIDDEN,
+            directories_first: Self::DEFAULT_DIRECTORIES_FIRST,
+        }
+    }
+
+    /// Sets whether entries whose names start with a dot are suggested. They
+    /// are always suggested once the last component of the input starts with
+    /// a dot.
+    pub fn with_show_hidden(mut self, show_hidden: bool) -> Self {
+        self.show_hidden = show_hidden;
+        self
+    }
+
+    /// Sets whether directories are suggested before files, instead of
+    /// sorting both by name only.
+    pub fn with_directories_first(mut self, directories_first: bool) -> Self {
+        self.directories_first = directories_first;
+        self
+    }
+
+    /// Path typed by the user, with a leading `~` replaced by the home
+    /// directory, if it is known.
+    pub fn resolve(path: &str) -> PathBuf {
+        expand_home(path, home_dir().as_deref())
+    }
+
+    fn suggest(&self, input: &str) -> Vec<String> {
+        let (directory, prefix) = match input.rfind(std::path::is_separator) {
+            Some(idx) => input.split_at(idx + 1),
+            None => ("", input),
+        };
+
+        let scanned = match directory {
+            "" => PathBuf::from("."),
+            directory => Self::resolve(directory),
+        };
+
+        // unreadable directories, e.g. while their name is being typed, have
+        // no suggestions instead of failing the prompt
+        let read_dir = match fs::read_dir(scanned) {
+            Ok(read_dir) => read_dir,
+            Err(_) => return vec![],
+        };
+
+        let mut entries = read_dir
+            .filter_map(Result::ok)
+            .filter_map(|entry| {
+                let name = entry.file_name().to_string_lossy().to_string();
+                let is_hidden = name.starts_with('.') && !prefix.starts_with('.');
+
+                if !name.starts_with(prefix) || (is_hidden && !self.show_hidden) {
+                    return None;
+                }
+
+                Some((entry.path().is_dir(), name))
+            })
+            .collect::<Vec<(bool, String)>>();
+
+        entries.sort_by(|(a_is_dir, a), (b_is_dir, b)| {
+            let by_kind = match self.directories_first {
+                true => b_is_dir.cmp(a_is_dir),
+                false => std::cmp::Ordering::Equal,
+            };
+
+            by_kind
+                .then_with(|| a.to_lowercase().cmp(&b.to_lowercase()))
+                .then_with(|| a.cmp(b))
+        });
+
+        entries
+            .into_iter()
+            .map(|(is_dir, name)| match is_dir {
+                true => format!("{directory}{name}/"),
+                false => format!("{directory}{name}"),
+            })
+            .collect()
+    }
+}
+
+impl Default for PathCompleter {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Autocomplete for PathCompleter {
+    fn get_suggestions(&mut self, input: &str) -> Result<Vec<String>, CustomUserError> {
+        Ok(self.suggest(input))
+    }
+
+    fn get_completion(
+        &mut self,
+        input: &str,
+        highlighted_suggestion: Option<String>,
+    ) -> Result<Replacement, CustomUserError> {
+        if highlighted_suggestion.is_some() {
+            return Ok(highlighted_suggestion);
+        }
+
+        let suggestions = self.suggest(input);
+        let completion = longest_common_prefix(&suggestions);
+
+        Ok((completion.len() > input.len()).then(|| completion.to_string()))
+    }
+}
+
+/// Autocompleter whose candidates are printed by a command, one per line,
+/// such as `git --list-cmds=main` or `kubectl __complete get`, so that
+/// wrappers of command-line tools offer the same completions.
+///
+/// The candidates complete the last word of the input, being suggested when
+/// they start with it. Anything after a tab in a line is a description, which
+/// is discarded, and lines starting with `:` are ignored, as they hold the
+/// directives printed by the completion commands of the `cobra` library.
+///
+/// By default the command is run once, its output being reused for every
+/// input. With [`with_input_words`](CommandCompleter::with_input_words), the
+/// words of the input are appended to its arguments instead, running it again
+/// whenever they change. Slow commands can be wrapped in a
+/// [BackgroundAutocomplete].
+///
+/// Failing to run the command is returned as an error, while a command
+/// exiting with a failure status has no candidates.
+///
+/// # Example
+///
+/// ```no_run
+/// use inquire::{autocompletion::CommandCompleter, Text};
+///
+/// let completer = CommandCompleter::new("kubectl")
+///     .with_args(["__complete", "get"])
+///     .with_input_words(true);
+///
+/// let resource = Text::new("kubectl get")
+///     .with_autocomplete(completer)
+///     .prompt();
+/// ```
+#[derive(Clone, Debug)]
+pub struct CommandCompleter {
+    program: String,
+    args: Vec<String>,
+    input_words: bool,
+    // arguments of the last run, along with the candidates printed
+    cache: Option<(Vec<String>, Vec<String>)>,
+}
+
+impl CommandCompleter {
+    /// Creates an autocompleter running the given program without arguments.
+    pub fn new(program: &str) -> Self {
+        Self {
+            program: program.to_string(),
+            args: vec![],
+            input_words: false,
+            cache: None,
+        }
+    }
+
+    /// Sets the arguments passed to the program.
+    pub fn with_args<I, S>(mut self, args: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: AsRef<str>,
+    {
+        self.args = args
+            .into_iter()
+            .map(|arg| arg.as_ref().to_string())
+            .collect();
+        self
+    }
+
+    /// Sets whether the words of the input are appended to the arguments of
+    /// the program, the last one being empty when the input ends with a space.
+    pub fn with_input_words(mut self, input_words: bool) -> Self {
+        self.input_words = input_words;
+        self
+    }
+
+    fn candidates(&mut self, input: &str) -> Result<&[String], CustomUserError> {
+        let mut args = self.args.clone();
+        if self.input_words {
+            args.extend(input_words(input).map(String::from));
+        }
+
+        let is_cached = matches!(&self.cache, Some((cached, _)) if *cached == args);
+        if !is_cached {
+            let output = Command::new(&self.program).args(&args).output()?;
+
+            let candidates = match output.status.success() {
+                true => String::from_utf8_lossy(&output.stdout)
+                    .lines()
+                    .filter(|line| !line.starts_with(':'))
+                    .map(|line| line.split('\t').next().unwrap_or_default().trim())
+                    .filter(|candidate| !candidate.is_empty())
+                    .map(String::from)
+                    .collect(),
+                false => vec![],
+            };
+
+            self.cache = Some((args, candidates));
+        }
+
+        Ok(self
+            .cache
+            .as_ref()
+            .map(|(_, candidates)| candidates.as_slice())
+            .unwrap_or_default())
+    }
+
+    fn suggest(&mut self, input: &str) -> Result<Vec<String>, CustomUserError> {
+        let word = input.rsplit(char::is_whitespace).next().unwrap_or_default();
+        let head = &input[..input.len() - word.len()];
+
+        let suggestions = self
+            .candidates(input)?
+            .iter()
+            .filter(|candidate| candidate.starts_with(word))
+            .map(|candidate| format!("{head}{candidate}"))
+            .collect();
+
+        Ok(suggestions)
+    }
+}
+
+impl Autocomplete for CommandCompleter {
+    fn get_suggestions(&mut self, input: &str) -> Result<Vec<String>, CustomUserError> {
+        self.suggest(input)
+    }
+
+    fn get_completion(
+        &mut self,
+        input: &str,
+        highlighted_suggestion: Option<String>,
+    ) -> Result<Replacement, CustomUserError> {
+        if highlighted_suggestion.is_some() {
+            return Ok(highlighted_suggestion);
+        }
+
+        let suggestions = self.suggest(input)?;
+        let completion = longest_common_prefix(&suggestions);
+
+        Ok((completion.len() > input.len()).then(|| completion.to_string()))
+    }
+}
+
+/// Words of the input, the last one being empty when the input ends with
+/// whitespace, as it is the word about to be typed.
+fn input_words(input: &str) -> impl Iterator<Item = &str> {
+    let trailing = input.ends_with(char::is_whitespace) || input.is_empty();
+
+    input.split_whitespace().chain(trailing.then_some(""))
+}
+
+fn home_dir() -> Option<PathBuf> {
+    env::var_os("HOME")
+        .or_else(|| env::var_os("USERPROFILE"))
+        .filter(|home| !home.is_empty())
+        .map(PathBuf::from)
+}
+
+fn expand_home(path: &str, home: Option<&Path>) -> PathBuf {
+    match (path.strip_prefix('~'), home) {
+        (Some(""), Some(home)) => home.to_path_buf(),
+        (Some(rest), Some(home)) if rest.starts_with(std::path::is_separator) => {
+            home.join(&rest[1..])
+        }
+        _ => PathBuf::from(path),
+    }
+}
+
+fn longest_common_prefix(values: &[String]) -> &str {
+    let first = match values.first() {
+        Some(first) => first,
+        None => return "",
+    };
+
+    let len = values[1..].iter().fold(first.len(), |len, value| {
+        first[..len]
+            .char_indices()
+            .zip(value.chars())
+            .find(|((_, a), b)| a != b)
+            .map(|((idx, _), _)| idx)
+            .unwrap_or_else(|| len.min(value.len()))
+    });
+
+    &first[..len]
+}
+
+#[cfg(test)]
+mod test {
+    use std::{env, fs, path::Path, thread, time::Duration};
+
+    use super::{
+        expand_home, Autocomplete, BackgroundAutocomplete, CommandCompleter, PathCompleter,
+    };
+    use crate::CustomUserError;
+
+    #[test]
+    fn background_suggestions_are_polled_for_the_latest_input() {
+        let suggester = |input: &str| -> Result<Vec<String>, CustomUserError> {
+            Ok(vec![input.to_uppercase()])
+        };
+        let mut autocompleter = BackgroundAutocomplete::new(suggester);
+
+        assert!(autocompleter.get_suggestions("a").unwrap().is_empty());
+        assert!(autocompleter.get_suggestions("ab").unwrap().is_empty());
+        assert!(autocompleter.is_loading());
+
+        let mut suggestions = None;
+        while suggestions.is_none() {
+            thread::sleep(Duration::from_millis(10));
+            suggestions = autocompleter.poll_suggestions().unwrap();
+        }
+
+        assert_eq!(Some(vec![String::from("AB")]), suggestions);
+        assert!(!autocompleter.is_loading());
+        assert_eq!(vec!["AB"], autocompleter.get_suggestions("abc").unwrap());
+    }
+
+    #[test]
+    fn paths_are_suggested_directories_first_and_completed() {
+        let root = env::temp_dir().join(format!("inquire-paths-{}", std::process::id()));
+        let _ = fs::remove_dir_all(&root);
+        fs::create_dir_all(root.join("src/macros")).unwrap();
+        for file in ["src/main.rs", "src/mask.rs", "src/.hidden"] {
+            fs::write(root.join(file), "").unwrap();
+        }
+
+        let dir = format!("{}/src/", root.display());
+        let mut completer = PathCompleter::new();
+
+        assert_eq!(
+            vec![
+                format!("{dir}macros/"),
+                format!("{dir}main.rs"),
+                format!("{dir}mask.rs"),
+            ],
+            completer.get_suggestions(&format!("{dir}ma")).unwrap()
+        );
+        assert_eq!(
+            vec![format!("{dir}.hidden")],
+            completer.get_suggestions(&format!("{dir}.")).unwrap()
+        );
+        assert_eq!(
+            Some(format!("{dir}ma")),
+            completer.get_completion(&format!("{dir}m"), None).unwrap()
+        );
+        assert_eq!(
+            Some(format!("{dir}mask.rs")),
+            completer
+                .get_completion(&format!("{dir}mask"), None)
+                .unwrap()
+        );
+
+        let mut completer = PathCompleter::new()
+            .with_show_hidden(true)
+            .with_directories_first(false);
+        assert_eq!(
+            vec![
+                format!("{dir}.hidden"),
+                format!("{dir}macros/"),
+                format!("{dir}main.rs"),
+                format!("{dir}mask.rs"),
+            ],
+            completer.get_suggestions(&dir).unwrap()
+        );
+
+        fs::remove_dir_all(root).unwrap();
+    }
+
+    #[test]
+    fn home_is_expanded_at_the_start_of_paths() {
+        let home = Some(Path::new("/home/user"));
+
+        assert_eq!(Path::new("/home/user"), expand_home("~", home));
+        assert_eq!(Path::new("/home/user/src"), expand_home("~/src", home));
+        assert_eq!(Path::new("~other/src"), expand_home("~other/src", home));
+        assert_eq!(Path::new("src/~"), expand_home("src/~", home));
+        assert_eq!(Path::new("~/src"), expand_home("~/src", None));
+    }
+
+    #[test]
+    #[cfg(unix)]
+    fn command_candidates_complete_the_last_word() {
+        let mut completer = CommandCompleter::new("printf")
+            .with_args(["commit\\nclone\\tClone a repository\\n:4\\ncheckout\\n"]);
+
+        assert_eq!(
+            vec!["git commit", "git clone", "git checkout"],
+            completer.get_suggestions("git ").unwrap()
+        );
+        assert_eq!(
+            vec!["git clone"],
+            completer.get_suggestions("git cl").unwrap()
+        );
+        assert_eq!(
+            Some(String::from("git c")),
+            completer.get_completion("git ", None).unwrap()
+        );
+
+        let mut completer = CommandCompleter::new("printf")
+            .with_args(["%s-done\\n"])
+            .with_input_words(true);
+
+        assert_eq!(
+            vec!["get pods-done"],
+            completer.get_suggestions("get pods").unwrap()
+        );
+        assert_eq!(
+            vec!["get get-done", "get -done"],
+            completer.get_suggestions("get ").unwrap()
+        );
+    }
+}
@@ -1,6 +1,9 @@
 //! Global config definitions.
 
-use std::sync::Mutex;
+use std::sync::{
+    atomic::{AtomicBool, Ordering},
+    Mutex,
+};
 
 use once_cell::sync::Lazy;
 
@@ -20,6 +23,22 @@ pub fn set_global_render_config(config: RenderConfig<'static>) {
     *guard = config;
 }
 
+static GLOBAL_WRAP_AROUND: AtomicBool = AtomicBool::new(true);
+
+pub fn get_wrap_around() -> bool {
+    GLOBAL_WRAP_AROUND.load(Ordering::Relaxed)
+}
+
+/// Sets whether moving past the last option of list prompts, such as
+/// [Select](crate::Select) and [MultiSelect](crate::MultiSelect), wraps
+/// around to the first one and vice versa, or stops at the ends of the list.
+///
+/// Applies to prompts created after the call, which can still override it
+/// with their own `with_wrap_around`. Defaults to true.
+pub fn set_global_wrap_around(wrap_around: bool) {
+    GLOBAL_WRAP_AROUND.store(wrap_around, Ordering::Relaxed);
+}
+
 /// Default page size when displaying options to the user.
 pub const DEFAULT_PAGE_SIZE: usize = 7;
 
@@ -1,11 +1,15 @@
 use core::panic;
 
-use chrono::NaiveDate;
+use chrono::{NaiveDate, NaiveTime};
 
 pub fn get_current_date() -> NaiveDate {
     chrono::Local::now().date_naive()
 }
 
+pub fn get_current_time() -> NaiveTime {
+    chrono::Local::now().time()
+}
+
 pub fn get_start_date(month: chrono::Month, year: i32) -> NaiveDate {
     NaiveDate::from_ymd_opt(year, month.number_from_month(), 1).unwrap()
 }
@@ -0,0 +1,248 @@
+//! Chains of sources providing the default values of prompts.
+//!
+//! Command-line tools often take the default answer of a question from
+//! several places: an environment variable, a key of their configuration
+//! file, or the answer given the last time. A [`DefaultChain`] tries these
+//! sources in order when the prompt is displayed, the first one providing a
+//! value winning.
+
+use std::{
+    env, fs,
+    path::{Path, PathBuf},
+};
+
+use crate::history::History;
+
+#[derive(Clone)]
+enum DefaultSource {
+    Env(String),
+    ConfigKey(PathBuf, String),
+    History(Box<dyn History>),
+}
+
+impl DefaultSource {
+    fn resolve(&self) -> Option<ResolvedDefault> {
+        let (value, source) = match self {
+            Self::Env(name) => (env::var(name).ok()?, format!("${name}")),
+            Self::ConfigKey(path, key) => {
+                let content = fs::read_to_string(path).ok()?;
+                let file_name = path.file_name()?.to_string_lossy();
+
+                (config_value(&content, key)?, format!("{file_name}: {key}"))
+            }
+            Self::History(history) => (
+                history.entries().into_iter().next()?,
+                String::from("last used"),
+            ),
+        };
+
+        match value.is_empty() {
+            true => None,
+            false => Some(ResolvedDefault { value, source }),
+        }
+    }
+}
+
+/// Default value provided by a [`DefaultChain`], along with a description of
+/// its source, such as `$MYTOOL_REGION` or `config.toml: region`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ResolvedDefault {
+    /// Value provided by the source.
+    pub value: String,
+    /// Description of the source that provided the value.
+    pub source: String,
+}
+
+/// Sources of the default value of a prompt, tried in the order they are
+/// added until one of them provides a non-empty value.
+///
+/// The chain is resolved when the prompt is displayed, so that it picks up
+/// the current environment. An explicit default set on the prompt takes
+/// precedence over every source of the chain.
+///
+/// # Examples
+///
+/// ```no_run
+/// use inquire::{default_value::DefaultChain, history::FileHistory, Text};
+///
+/// let history = FileHistory::open("/home/user/.cache/mytool/regions")?;
+///
+/// let defaults = DefaultChain::new()
+///     .with_env("MYTOOL_REGION")
+///     .with_config_key("/home/user/.config/mytool/config.toml", "aws.region")
+///     .with_history(history)
+///     .with_source_displayed(true);
+///
+/// let region = Text::new("Region:").with_default_chain(defaults).prompt();
+/// # Ok::<(), std::io::Error>(())
+/// ```
+#[derive(Clone, Default)]
+pub struct DefaultChain {
+    sources: Vec<DefaultSource>,
+    display_source: bool,
+}
+
+impl DefaultChain {
+    /// Creates an empty chain, which provides no default value.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds the environment variable with the given name to the chain.
+    pub fn with_env(mut self, name: &str) -> Self {
+        self.sources.push(DefaultSource::Env(name.to_string()));
+        self
+    }
+
+    /// Adds a key of a configuration file to the chain.
+    ///
+    /// The file is read as `key = value` or `key: value` lines, where the
+    /// keys under a `[section]` header are prefixed by the name of the
+    /// section and a dot, e.g. `aws.region`. Values may be quoted, and lines
+    /// starting with `#` or `;` are comments, which covers simple TOML, INI
+    /// and YAML files. A missing file provides no value.
+    pub fn with_config_key<P: AsRef<Path>>(mut self, path: P, key: &str) -> Self {
+        self.sources.push(DefaultSource::ConfigKey(
+            path.as_ref().to_path_buf(),
+            key.to_string(),
+        ));
+        self
+    }
+
+    /// Adds the most recent entry of the history to the chain, i.e. the last
+    /// answer it recorded.
+    pub fn with_history<H>(mut self, history: H) -> Self
+    where
+        H: History + 'static,
+    {
+        self.sources.push(DefaultSource::History(Box::new(history)));
+        self
+    }
+
+    /// Sets whether the source of the default value is displayed next to it,
+    /// e.g. `(eu-west-1 from $MYTOOL_REGION)`. Defaults to false.
+    pub fn with_source_displayed(mut self, display_source: bool) -> Self {
+        self.display_source = display_source;
+        self
+    }
+
+    /// Whether the source of the default value is displayed next to it.
+    pub fn displays_source(&self) -> bool {
+        self.display_source
+    }
+
+    /// Default value provided by the first source of the chain that has
+    /// one, if any.
+    pub fn resolve(&self) -> Option<ResolvedDefault> {
+        self.sources.iter().find_map(DefaultSource::resolve)
+    }
+}
+
+/// Value of the key in the content of a configuration file.
+fn config_value(content: &str, key: &str) -> Option<String> {
+    let mut section = String::new();
+
+    for line in content.lines().map(str::trim) {
+        if line.is_empty() || line.starts_with('#') || line.starts_with(';') {
+            continue;
+        }
+
+        if let Some(name) = line.strip_prefix('[').and_then(|l| l.strip_suffix(']')) {
+            section = name.trim().to_string();
+            continue;
+        }
+
+        let (line_key, value) = match line.find(['=', ':']) {
+            Some(idx) => (line[..idx].trim(), line[idx + 1..].trim()),
+            None => continue,
+        };
+
+        let matches = match section.is_empty() {
+            true => line_key == key,
+            false => {
+                key.strip_prefix(section.as_str())
+                    .and_then(|rest| rest.strip_prefix('.'))
+                    == Some(line_key)
+            }
+        };
+
+        if matches {
+            return Some(unquote(value).to_string());
+        }
+    }
+
+    None
+}
+
+fn unquote(value: &str) -> &str {
+    ['"', '\'']
+        .iter()
+        .find_map(|quote| value.strip_prefix(*quote)?.strip_suffix(*quote))
+        .unwrap_or(value)
+}
+
+#[cfg(test)]
+mod test {
+    use std::{env, fs};
+
+    use super::{config_value, DefaultChain, ResolvedDefault};
+    use crate::history::History;
+
+    #[derive(Clone)]
+    struct Entries(Vec<String>);
+
+    impl History for Entries {
+        fn entries(&self) -> Vec<String> {
+            self.0.clone()
+        }
+
+        fn record(&mut self, entry: &str) {
+            self.0.insert(0, entry.to_string());
+        }
+    }
+
+    #[test]
+    fn first_source_with_a_value_wins() {
+        let path = env::temp_dir().join(format!("inquire-defaults-{}.toml", std::process::id()));
+        fs::write(&path, "[aws]\nregion = \"eu-west-1\"\n").unwrap();
+
+        let chain = DefaultChain::new()
+            .with_env("INQUIRE_TEST_UNSET_REGION")
+            .with_config_key(&path, "aws.profile")
+            .with_config_key(&path, "aws.region")
+            .with_history(Entries(vec![String::from("us-east-1")]));
+
+        let file_name = path.file_name().unwrap().to_string_lossy().to_string();
+        assert_eq!(
+            Some(ResolvedDefault {
+                value: String::from("eu-west-1"),
+                source: format!("{file_name}: aws.region"),
+            }),
+            chain.resolve()
+        );
+
+        fs::remove_file(&path).unwrap();
+
+        assert_eq!(
+            Some(ResolvedDefault {
+                value: String::from("us-east-1"),
+                source: String::from("last used"),
+            }),
+            chain.resolve()
+        );
+        assert_eq!(None, DefaultChain::new().resolve());
+    }
+
+    #[test]
+    fn config_values_are_read_from_sections_and_unquoted() {
+        let content = "# comment\nname: 'mytool'\n\n[server]\nport=8080\n; host = ignored\n";
+
+        assert_eq!(Some(String::from("mytool")), config_value(content, "name"));
+        assert_eq!(
+            Some(String::from("8080")),
+            config_value(content, "server.port")
+        );
+        assert_eq!(None, config_value(content, "port"));
+        assert_eq!(None, config_value(content, "server.host"));
+    }
+}
@@ -148,8 +148,43 @@ pub type MultiOptionFormatter<'a, T> = &'a dyn Fn(&[ListOption<&T>]) -> String;
 /// ```
 pub type CustomTypeFormatter<'a, T> = &'a dyn Fn(T) -> String;
 
-#[cfg(feature = "date")]
+#[cfg(feature = "url")]
+/// Type alias for formatters used in [`Url`](crate::Url) prompts.
+///
+/// Formatters receive the parsed URL and return a [String] to be displayed
+/// to the user as the final answer.
+///
+/// # Examples
+///
+/// ```
+/// use inquire::formatter::UrlFormatter;
+///
+/// let formatter: UrlFormatter = &|url| url.host_str().unwrap_or_default().to_string();
+///
+/// let url = url::Url::parse("https://example.com/docs").unwrap();
+/// assert_eq!(String::from("example.com"), formatter(&url));
+/// ```
+pub type UrlFormatter<'a> = &'a dyn Fn(&url::Url) -> String;
 
+#[cfg(feature = "semver")]
+/// Type alias for formatters used in [`Version`](crate::Version) prompts.
+///
+/// Formatters receive the parsed version and return a [String] to be displayed
+/// to the user as the final answer.
+///
+/// # Examples
+///
+/// ```
+/// use inquire::formatter::VersionFormatter;
+///
+/// let formatter: VersionFormatter = &|version| format!("v{}", version);
+///
+/// let version = semver::Version::new(1, 4, 2);
+/// assert_eq!(String::from("v1.4.2"), formatter(&version));
+/// ```
+pub type VersionFormatter<'a> = &'a dyn Fn(&semver::Version) -> String;
+
+#[cfg(feature = "date")]
 /// Type alias for formatters used in [`DateSelect`](crate::DateSelect) prompts.
 ///
 /// Formatters receive the user input and return a [String] to be displayed
@@ -170,6 +205,195 @@ pub type CustomTypeFormatter<'a, T> = &'a dyn Fn(T) -> String;
 /// ```
 pub type DateFormatter<'a> = &'a dyn Fn(chrono::NaiveDate) -> String;
 
+#[cfg(feature = "date")]
+/// Type alias for formatters used in [`TimeSelect`](crate::TimeSelect) prompts.
+///
+/// Formatters receive the user input and return a [String] to be displayed
+/// to the user as the final answer.
+///
+/// # Examples
+///
+/// ```
+/// use chrono::NaiveTime;
+/// use inquire::formatter::TimeFormatter;
+///
+/// let formatter: TimeFormatter = &|val| val.format("%Hh%M").to_string();
+///
+/// assert_eq!(
+///     String::from("14h30"),
+///     formatter(NaiveTime::from_hms_opt(14, 30, 0).unwrap()),
+/// );
+/// ```
+pub type TimeFormatter<'a> = &'a dyn Fn(chrono::NaiveTime) -> String;
+
+#[cfg(feature = "date")]
+/// Type alias for formatters used in [`DateTimeSelect`](crate::DateTimeSelect) prompts.
+///
+/// Formatters receive the user input and return a [String] to be displayed
+/// to the user as the final answer.
+///
+/// # Examples
+///
+/// ```
+/// use chrono::NaiveDate;
+/// use inquire::formatter::DateTimeFormatter;
+///
+/// let formatter: DateTimeFormatter = &|val| val.format("%d/%m/%Y %H:%M").to_string();
+///
+/// assert_eq!(
+///     String::from("25/07/2021 14:30"),
+///     formatter(NaiveDate::from_ymd_opt(2021, 7, 25).unwrap().and_hms_opt(14, 30, 0).unwrap()),
+/// );
+/// ```
+pub type DateTimeFormatter<'a> = &'a dyn Fn(chrono::NaiveDateTime) -> String;
+
+/// Type alias for formatters used in [`FileSelect`](crate::FileSelect) and
+/// [`DirectorySelect`](crate::DirectorySelect) prompts.
+///
+/// Formatters receive the selected path and return a [String] to be displayed
+/// to the user as the final answer.
+///
+/// # Examples
+///
+/// ```
+/// use std::path::Path;
+/// use inquire::formatter::PathFormatter;
+///
+/// let formatter: PathFormatter = &|path| {
+///     path.file_name()
+///         .map(|name| name.to_string_lossy().to_string())
+///         .unwrap_or_default()
+/// };
+///
+/// assert_eq!(String::from("main.rs"), formatter(Path::new("/home/user/src/main.rs")));
+/// ```
+pub type PathFormatter<'a> = &'a dyn Fn(&std::path::Path) -> String;
+
+/// Type alias for formatters used in [`ColorSelect`](crate::ColorSelect) prompts.
+///
+/// Formatters receive the selected color and return a [String] to be displayed
+/// to the user as the final answer.
+///
+/// # Examples
+///
+/// ```
+/// use inquire::formatter::ColorFormatter;
+/// use inquire::ui::Color;
+///
+/// let formatter: ColorFormatter = &|color| match color {
+///     Color::Rgb { r, g, b } => format!("rgb({}, {}, {})", r, g, b),
+///     color => format!("{:?}", color),
+/// };
+///
+/// assert_eq!(String::from("rgb(255, 136, 0)"), formatter(Color::rgb(255, 136, 0)));
+/// assert_eq!(String::from("DarkBlue"), formatter(Color::DarkBlue));
+/// ```
+pub type ColorFormatter<'a> = &'a dyn Fn(crate::ui::Color) -> String;
+
+/// Type alias for formatters used in [`KeyCapture`](crate::KeyCapture) prompts.
+///
+/// Formatters receive the captured key combination and return a [String] to be
+/// displayed to the user as the final answer.
+///
+/// # Examples
+///
+/// ```
+/// use inquire::formatter::KeyFormatter;
+/// use inquire::ui::{Key, KeyModifiers};
+///
+/// let formatter: KeyFormatter = &|key| format!("<{}>", key);
+///
+/// assert_eq!(String::from("<Ctrl+K>"), formatter(Key::Char('k', KeyModifiers::CONTROL)));
+/// ```
+pub type KeyFormatter<'a> = &'a dyn Fn(crate::ui::Key) -> String;
+
+/// Type alias for formatters used in [`Tags`](crate::Tags) prompts.
+///
+/// Formatters receive the tags entered by the user and return a [String] to be
+/// displayed to the user as the final answer.
+///
+/// # Examples
+///
+/// ```
+/// use inquire::formatter::TagsFormatter;
+///
+/// let formatter: TagsFormatter = &|tags| format!("{} tags", tags.len());
+///
+/// assert_eq!(String::from("2 tags"), formatter(&[String::from("bug"), String::from("ui")]));
+/// ```
+pub type TagsFormatter<'a> = &'a dyn Fn(&[String]) -> String;
+
+/// Type alias for formatters used in [`ListEditor`](crate::ListEditor) prompts.
+///
+/// Formatters receive the items left in the list by the user and return a [String]
+/// to be displayed to the user as the final answer.
+///
+/// # Examples
+///
+/// ```
+/// use inquire::formatter::ListFormatter;
+///
+/// let formatter: ListFormatter = &|items| format!("{} hosts", items.len());
+///
+/// assert_eq!(String::from("2 hosts"), formatter(&[String::from("a"), String::from("b")]));
+/// ```
+pub type ListFormatter<'a> = &'a dyn Fn(&[String]) -> String;
+
+/// Type alias for formatters used in [`MapEditor`](crate::MapEditor) prompts.
+///
+/// Formatters receive the entries left in the map by the user, as pairs of key and
+/// value in the order they are listed, and return a [String] to be displayed to the
+/// user as the final answer.
+///
+/// # Examples
+///
+/// ```
+/// use inquire::formatter::MapFormatter;
+///
+/// let formatter: MapFormatter = &|entries| format!("{} variables", entries.len());
+///
+/// assert_eq!(String::from("2 variables"), formatter(&[("HOME", "/root"), ("TERM", "xterm")]));
+/// ```
+pub type MapFormatter<'a> = &'a dyn Fn(&[(&str, &str)]) -> String;
+
+/// Type alias for formatters used in [`TableEditor`](crate::TableEditor) prompts.
+///
+/// Formatters receive the rows submitted by the user, as their cells in column
+/// order, and return a [String] to be displayed to the user as the final answer.
+///
+/// # Examples
+///
+/// ```
+/// use inquire::formatter::TableFormatter;
+///
+/// let formatter: TableFormatter = &|rows| format!("{} port mappings", rows.len());
+///
+/// assert_eq!(
+///     String::from("1 port mappings"),
+///     formatter(&[vec![String::from("8080"), String::from("80")]])
+/// );
+/// ```
+pub type TableFormatter<'a> = &'a dyn Fn(&[Vec<String>]) -> String;
+
+/// Type alias for formatters used in [`CheckboxMatrix`](crate::CheckboxMatrix) prompts.
+///
+/// Formatters receive the checked cells, as pairs of row and column labels sorted
+/// by row, and return a [String] to be displayed to the user as the final answer.
+///
+/// # Examples
+///
+/// ```
+/// use inquire::formatter::MatrixFormatter;
+///
+/// let formatter: MatrixFormatter = &|cells| format!("{} permissions granted", cells.len());
+///
+/// assert_eq!(
+///     String::from("2 permissions granted"),
+///     formatter(&[("admin", "read"), ("admin", "write")]),
+/// );
+/// ```
+pub type MatrixFormatter<'a> = &'a dyn Fn(&[(&str, &str)]) -> String;
+
 /// String formatter used by default in inputs that return a `String` as input.
 /// Its behavior is to just echo the received input.
 ///
@@ -226,3 +450,20 @@ pub const DEFAULT_BOOL_FORMATTER: BoolFormatter<'_> = &|ans| {
 /// );
 /// ```
 pub const DEFAULT_DATE_FORMATTER: DateFormatter<'_> = &|val| val.format("%B %-e, %Y").to_string();
+
+/// Type alias for formatters used in [`Menu`](crate::Menu) prompts.
+///
+/// Formatters receive the path of entries selected by the user, from the root
+/// menu down to the chosen entry, and return a [String] to be displayed to the
+/// user as the final answer.
+///
+/// # Examples
+///
+/// ```
+/// use inquire::formatter::MenuFormatter;
+///
+/// let formatter: MenuFormatter<&str> = &|path| path.join("/");
+///
+/// assert_eq!(String::from("Settings/Network"), formatter(&["Settings", "Network"]));
+/// ```
+pub type MenuFormatter<'a, T> = &'a dyn Fn(&[T]) -> String;
@@ -0,0 +1,532 @@
+//! Trait and structs used by prompts to apply syntax highlighting to the
+//! user's text input.
+//!
+//! Highlighters receive each line of the input and split it into tokens, such
+//! as strings, numbers or keywords, which are rendered with the style sheets
+//! set in the [`CodeRenderConfig`](crate::ui::CodeRenderConfig) of the prompt.
+//!
+//! This module provides lightweight highlighters for JSON and YAML snippets.
+//! With the `syntect` feature, the [`SyntectHighlighter`] covers the many
+//! languages supported by the `syntect` engine. Other languages can be plugged
+//! in by implementing the [`Highlighter`] trait and mapping their own tokens
+//! to a [`TokenKind`].
+
+use std::ops::Range;
+
+use dyn_clone::DynClone;
+#[cfg(feature = "syntect")]
+use once_cell::sync::Lazy;
+#[cfg(feature = "syntect")]
+use syntect::parsing::{ParseState, Scope, ScopeStack, SyntaxReference, SyntaxSet};
+
+/// Kind of a highlighted token, which defines the style sheet it is rendered with.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum TokenKind {
+    /// Key of a mapping, e.g. `"name"` in `{"name": "inquire"}`.
+    Key,
+    /// String literal.
+    String,
+    /// Number literal.
+    Number,
+    /// Reserved word, such as `true`, `false` or `null`.
+    Keyword,
+    /// Comment, up to the end of the line.
+    Comment,
+    /// Punctuation, such as brackets, commas and colons.
+    Punctuation,
+}
+
+/// Mechanism to apply syntax highlighting to the user's text input.
+///
+/// Highlighters are called for each line of the input, returning the tokens
+/// found in the line as byte ranges of the line and their kinds. Parts of the
+/// line not covered by any token are rendered with the default text input style.
+///
+/// # Examples
+///
+/// ```
+/// use std::ops::Range;
+/// use inquire::highlighter::{Highlighter, TokenKind};
+///
+/// #[derive(Clone)]
+/// struct CommentHighlighter;
+///
+/// impl Highlighter for CommentHighlighter {
+///     fn highlight(&self, line: &str) -> Vec<(Range<usize>, TokenKind)> {
+///         match line.find("//") {
+///             Some(start) => vec![(start..line.len(), TokenKind::Comment)],
+///             None => vec![],
+///         }
+///     }
+/// }
+///
+/// assert_eq!(vec![(4..10, TokenKind::Comment)], CommentHighlighter.highlight("a++ // inc"));
+/// ```
+pub trait Highlighter: DynClone {
+    /// Splits the line into highlighted tokens, sorted by their position and
+    /// without overlaps.
+    fn highlight(&self, line: &str) -> Vec<(Range<usize>, TokenKind)>;
+}
+
+impl Clone for Box<dyn Highlighter> {
+    fn clone(&self) -> Self {
+        dyn_clone::clone_box(&**self)
+    }
+}
+
+/// Built-in highlighter of JSON snippets.
+///
+/// # Examples
+///
+/// ```
+/// use inquire::highlighter::{Highlighter, JsonHighlighter, TokenKind};
+///
+/// assert_eq!(
+///     vec![
+///         (0..1, TokenKind::Punctuation),
+///         (1..7, TokenKind::Key),
+///         (7..8, TokenKind::Punctuation),
+///         (9..13, TokenKind::Keyword),
+///         (13..14, TokenKind::Punctuation),
+///     ],
+///     JsonHighlighter.highlight(r#"{"beta": true}"#)
+/// );
+/// ```
+#[derive(Copy, Clone, Debug, Default)]
+pub struct JsonHighlighter;
+
+impl Highlighter for JsonHighlighter {
+    fn highlight(&self, line: &str) -> Vec<(Range<usize>, TokenKind)> {
+        let mut tokens = vec![];
+        let mut chars = line.char_indices().peekable();
+
+        while let Some((start, c)) = chars.next() {
+            let end = match c {
+                '"' => {
+                    let end = quoted_string_end(line, start);
+                    while chars.next_if(|(i, _)| *i < end).is_some() {}
+
+                    let kind = match line[end..].trim_start().starts_with(':') {
+                        true => TokenKind::Key,
+                        false => TokenKind::String,
+                    };
+                    tokens.push((start..end, kind));
+                    continue;
+                }
+                '{' | '}' | '[' | ']' | ',' | ':' => {
+                    tokens.push((start..start + 1, TokenKind::Punctuation));
+                    continue;
+                }
+                c if c == '-' || c.is_ascii_digit() => {
+                    while chars
+                        .next_if(|(_, c)| c.is_ascii_digit() || "+-.eE".contains(*c))
+                        .is_some()
+                    {}
+                    chars.peek().map(|(i, _)| *i).unwrap_or(line.len())
+                }
+                c if c.is_ascii_alphabetic() => {
+                    while chars.next_if(|(_, c)| c.is_ascii_alphabetic()).is_some() {}
+                    let end = chars.peek().map(|(i, _)| *i).unwrap_or(line.len());
+
+                    if matches!(&line[start..end], "true" | "false" | "null") {
+                        tokens.push((start..end, TokenKind::Keyword));
+                    }
+                    continue;
+                }
+                _ => continue,
+            };
+
+            tokens.push((start..end, TokenKind::Number));
+        }
+
+        tokens
+    }
+}
+
+/// Built-in highlighter of YAML snippets.
+///
+/// Plain scalars are highlighted as numbers or keywords when the whole value
+/// is one, and as strings otherwise.
+///
+/// # Examples
+///
+/// ```
+/// use inquire::highlighter::{Highlighter, TokenKind, YamlHighlighter};
+///
+/// assert_eq!(
+///     vec![
+///         (0..1, TokenKind::Punctuation),
+///         (2..6, TokenKind::Key),
+///         (6..7, TokenKind::Punctuation),
+///         (8..10, TokenKind::Number),
+///         (11..17, TokenKind::Comment),
+///     ],
+///     YamlHighlighter.highlight("- port: 80 # http")
+/// );
+/// ```
+#[derive(Copy, Clone, Debug, Default)]
+pub struct YamlHighlighter;
+
+impl Highlighter for YamlHighlighter {
+    fn highlight(&self, line: &str) -> Vec<(Range<usize>, TokenKind)> {
+        let trimmed = line.trim();
+        if trimmed == "---" || trimmed == "..." {
+            let start = line.len() - line.trim_start().len();
+            return vec![(start..start + 3, TokenKind::Punctuation)];
+        }
+
+        let mut tokens = vec![];
+        let mut flow_depth = 0usize;
+        let mut i = 0;
+
+        while let Some(c) = line[i..].chars().next() {
+            let preceded_by_space = line[..i].ends_with(char::is_whitespace) || i == 0;
+
+            match c {
+                '#' if preceded_by_space => {
+                    tokens.push((i..line.len(), TokenKind::Comment));
+                    break;
+                }
+                '"' | '\'' => {
+                    let end = match c {
+                        '"' => quoted_string_end(line, i),
+                        _ => line[i + 1..]
+                            .find('\'')
+                            .map(|e| i + e + 2)
+                            .unwrap_or(line.len()),
+                    };
+                    tokens.push((i..end, Self::scalar_kind(line, end, TokenKind::String)));
+                    i = end;
+                    continue;
+                }
+                '{' | '[' => flow_depth += 1,
+                '}' | ']' => flow_depth = flow_depth.saturating_sub(1),
+                ',' if flow_depth > 0 => {}
+                '-' if preceded_by_space && Self::is_indicator_end(line, i + 1) => {}
+                ':' if Self::is_indicator_end(line, i + 1) => {}
+                c if c.is_whitespace() => {
+                    i += c.len_utf8();
+                    continue;
+                }
+                _ => {
+                    let end = Self::plain_scalar_end(line, i, flow_depth > 0);
+                    let value = &line[i..end];
+
+                    let is_numeric =
+                        value.starts_with(|c: char| c.is_ascii_digit() || "+-.".contains(c));
+
+                    let kind = if is_numeric && value.parse::<f64>().is_ok() {
+                        TokenKind::Number
+                    } else if matches!(
+                        value,
+                        "true" | "false" | "null" | "~" | "True" | "False" | "Null"
+                    ) {
+                        TokenKind::Keyword
+                    } else {
+                        TokenKind::String
+                    };
+
+                    tokens.push((i..end, Self::scalar_kind(line, end, kind)));
+                    i = end;
+                    continue;
+                }
+            }
+
+            tokens.push((i..i + 1, TokenKind::Punctuation));
+            i += 1;
+        }
+
+        tokens
+    }
+}
+
+impl YamlHighlighter {
+    /// Whether the indicator ending at the given position is followed by a
+    /// whitespace or the end of the line, such as the colon after a key.
+    fn is_indicator_end(line: &str, end: usize) -> bool {
+        !matches!(line[end..].chars().next(), Some(c) if !c.is_whitespace())
+    }
+
+    /// Kind of a scalar ending at the given position, which is a key when
+    /// followed by a colon.
+    fn scalar_kind(line: &str, end: usize, kind: TokenKind) -> TokenKind {
+        let rest = line[end..].trim_start();
+
+        match rest.strip_prefix(':') {
+            Some(after) if !matches!(after.chars().next(), Some(c) if !c.is_whitespace()) => {
+                TokenKind::Key
+            }
+            _ => kind,
+        }
+    }
+
+    /// End of a plain scalar starting at the given position, without the
+    /// trailing whitespaces.
+    fn plain_scalar_end(line: &str, start: usize, in_flow: bool) -> usize {
+        let mut end = line.len();
+
+        for (i, c) in line[start..].char_indices() {
+            let i = start + i;
+
+            let ends = match c {
+                ':' => Self::is_indicator_end(line, i + 1),
+                '#' => line[..i].ends_with(char::is_whitespace),
+                ',' | ']' | '}' => in_flow,
+                _ => false,
+            };
+
+            if ends {
+                end = i;
+                break;
+            }
+        }
+
+        start + line[start..end].trim_end().len()
+    }
+}
+
+/// Default syntaxes of `syntect`, loaded on the first use of a [`SyntectHighlighter`].
+#[cfg(feature = "syntect")]
+static SYNTAXES: Lazy<SyntaxSet> = Lazy::new(SyntaxSet::load_defaults_nonewlines);
+
+/// Highlighter backed by the `syntect` engine and its default syntaxes, which
+/// cover languages such as JSON, YAML, Rust or Python.
+///
+/// Available via the `syntect` feature. The scopes found by `syntect` are
+/// mapped to a [`TokenKind`], and each line is highlighted on its own, so
+/// constructs spanning multiple lines, such as block comments, are only
+/// highlighted on their first line.
+///
+/// # Examples
+///
+/// ```
+/// use inquire::highlighter::{Highlighter, SyntectHighlighter, TokenKind};
+///
+/// let highlighter = SyntectHighlighter::for_extension("py").unwrap();
+///
+/// assert_eq!(
+///     vec![
+///         (2..3, TokenKind::Punctuation),
+///         (4..8, TokenKind::Keyword),
+///         (10..13, TokenKind::Comment),
+///     ],
+///     highlighter.highlight("x = True  # c")
+/// );
+/// ```
+#[cfg(feature = "syntect")]
+#[derive(Clone, Debug)]
+pub struct SyntectHighlighter {
+    syntax: SyntaxReference,
+}
+
+#[cfg(feature = "syntect")]
+impl SyntectHighlighter {
+    /// Creates a highlighter for the language of the given file extension,
+    /// e.g. `json` or `rs`, if it is among the default syntaxes.
+    pub fn for_extension(extension: &str) -> Option<Self> {
+        SYNTAXES
+            .find_syntax_by_extension(extension)
+            .map(|syntax| Self {
+                syntax: syntax.clone(),
+            })
+    }
+
+    /// Creates a highlighter for the language of the given name, e.g. `JSON`
+    /// or `Rust`, if it is among the default syntaxes.
+    pub fn for_name(name: &str) -> Option<Self> {
+        SYNTAXES.find_syntax_by_name(name).map(|syntax| Self {
+            syntax: syntax.clone(),
+        })
+    }
+
+    /// Kind of a token with the given stack of scopes, if it is highlighted.
+    fn token_kind(scopes: &[Scope]) -> Option<TokenKind> {
+        let names = scopes
+            .iter()
+            .map(|scope| scope.build_string())
+            .collect::<Vec<String>>();
+        let has = |prefixes: &[&str]| {
+            names
+                .iter()
+                .any(|name| prefixes.iter().any(|prefix| name.starts_with(prefix)))
+        };
+
+        // keys are usually scoped as strings as well, so they are looked up first
+        if has(&["comment"]) {
+            Some(TokenKind::Comment)
+        } else if has(&[
+            "meta.mapping.key",
+            "meta.structure.dictionary.key",
+            "entity.name.tag",
+        ]) {
+            Some(TokenKind::Key)
+        } else if has(&["string"]) {
+            Some(TokenKind::String)
+        } else if has(&["constant.numeric"]) {
+            Some(TokenKind::Number)
+        } else if has(&["constant.language", "storage"]) {
+            Some(TokenKind::Keyword)
+        } else if has(&["keyword.operator", "punctuation"]) {
+            Some(TokenKind::Punctuation)
+        } else if has(&["keyword"]) {
+            Some(TokenKind::Keyword)
+        } else {
+            None
+        }
+    }
+}
+
+#[cfg(feature = "syntect")]
+impl Highlighter for SyntectHighlighter {
+    fn highlight(&self, line: &str) -> Vec<(Range<usize>, TokenKind)> {
+        let ops = match ParseState::new(&self.syntax).parse_line(line, &SYNTAXES) {
+            Ok(ops) => ops,
+            Err(_) => return vec![],
+        };
+
+        let mut tokens: Vec<(Range<usize>, TokenKind)> = vec![];
+        let mut stack = ScopeStack::new();
+        let mut start = 0;
+
+        let ends = ops.iter().map(|(i, op)| (*i, Some(op)));
+        for (end, op) in ends.chain(std::iter::once((line.len(), None))) {
+            if let Some(kind) = Self::token_kind(stack.as_slice()).filter(|_| start < end) {
+                // the scopes of a token, e.g. the quotes of a string, are
+                // merged, unlike consecutive punctuation marks
+                match tokens.last_mut() {
+                    Some((last, last_kind))
+                        if *last_kind == kind
+                            && kind != TokenKind::Punctuation
+                            && last.end == start =>
+                    {
+                        last.end = end;
+                    }
+                    _ => tokens.push((start..end, kind)),
+                }
+            }
+
+            if let Some(op) = op {
+                if stack.apply(op).is_err() {
+                    break;
+                }
+            }
+            start = end;
+        }
+
+        tokens
+    }
+}
+
+/// Byte position right after the closing quote of the double-quoted string
+/// starting at the given position, or the end of the line if it is not closed.
+fn quoted_string_end(line: &str, start: usize) -> usize {
+    let mut escaped = false;
+
+    for (i, c) in line[start + 1..].char_indices() {
+        match c {
+            '\\' if !escaped => escaped = true,
+            '"' if !escaped => return start + 1 + i + 1,
+            _ => escaped = false,
+        }
+    }
+
+    line.len()
+}
+
+#[cfg(test)]
+mod test {
+    use super::{Highlighter, JsonHighlighter, TokenKind, YamlHighlighter};
+
+    fn kinds<'a>(highlighter: &dyn Highlighter, line: &'a str) -> Vec<(&'a str, TokenKind)> {
+        highlighter
+            .highlight(line)
+            .into_iter()
+            .map(|(range, kind)| (&line[range], kind))
+            .collect()
+    }
+
+    #[test]
+    fn json_strings_followed_by_colons_are_keys() {
+        assert_eq!(
+            vec![
+                (r#""a\"b""#, TokenKind::Key),
+                (":", TokenKind::Punctuation),
+                (r#""c""#, TokenKind::String),
+                (",", TokenKind::Punctuation),
+                ("-1.5e3", TokenKind::Number),
+                (",", TokenKind::Punctuation),
+                ("null", TokenKind::Keyword),
+            ],
+            kinds(&JsonHighlighter, r#""a\"b" : "c", -1.5e3, null"#)
+        );
+    }
+
+    #[test]
+    fn json_unclosed_strings_end_at_the_end_of_line() {
+        assert_eq!(
+            vec![
+                ("[", TokenKind::Punctuation),
+                (r#""abc, 1"#, TokenKind::String)
+            ],
+            kinds(&JsonHighlighter, r#"["abc, 1"#)
+        );
+    }
+
+    #[test]
+    fn yaml_scalars_are_classified_by_value() {
+        assert_eq!(
+            vec![
+                ("name", TokenKind::Key),
+                (":", TokenKind::Punctuation),
+                ("my app", TokenKind::String),
+            ],
+            kinds(&YamlHighlighter, "name: my app")
+        );
+        assert_eq!(
+            vec![
+                ("tags", TokenKind::Key),
+                (":", TokenKind::Punctuation),
+                ("[", TokenKind::Punctuation),
+                ("1", TokenKind::Number),
+                (",", TokenKind::Punctuation),
+                ("'a, b'", TokenKind::String),
+                (",", TokenKind::Punctuation),
+                ("true", TokenKind::Keyword),
+                ("]", TokenKind::Punctuation),
+            ],
+            kinds(&YamlHighlighter, "tags: [1, 'a, b', true]")
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "syntect")]
+    fn syntect_scopes_are_mapped_to_token_kinds() {
+        let json = super::SyntectHighlighter::for_extension("json").unwrap();
+        let line = r#"{"a": [1.5, "b", null]}"#;
+        assert_eq!(JsonHighlighter.highlight(line), json.highlight(line));
+
+        let yaml = super::SyntectHighlighter::for_name("YAML").unwrap();
+        assert_eq!(
+            vec![
+                ("port", TokenKind::Key),
+                (":", TokenKind::Punctuation),
+                ("80", TokenKind::Number),
+                ("# http", TokenKind::Comment),
+            ],
+            kinds(&yaml, "port: 80 # http")
+        );
+    }
+
+    #[test]
+    fn yaml_hash_only_starts_comments_after_whitespace() {
+        assert_eq!(
+            vec![
+                ("url", TokenKind::Key),
+                (":", TokenKind::Punctuation),
+                ("a#b", TokenKind::String),
+                ("# c", TokenKind::Comment),
+            ],
+            kinds(&YamlHighlighter, "url: a#b # c")
+        );
+    }
+}
@@ -0,0 +1,318 @@
+//! Trait used by prompts to remember previous answers of the user.
+//!
+//! Histories hold the entries recorded when the prompts are submitted, such
+//! as the option selected in a [`Select`](crate::Select) prompt, which is
+//! listed first the next time the prompt is displayed.
+//!
+//! Entries are plain strings, so that they can be loaded and saved by any
+//! storage the application already uses, be it a file, a database or a
+//! configuration entry. [`FileHistory`] keeps them in a file across runs of
+//! the program.
+
+use std::{
+    cell::RefCell,
+    fs, io,
+    path::{Path, PathBuf},
+    rc::Rc,
+};
+
+use dyn_clone::DynClone;
+
+/// Store of the previous answers of the user, the most recent first.
+///
+/// Prompts call [`entries`](History::entries) when they are created and
+/// [`record`](History::record) when their answer is submitted. Persisting
+/// the entries, deduplicating them and limiting their number are left to the
+/// implementors, such as [`FileHistory`].
+///
+/// Prompts own the history they are given, so that the entries they record
+/// are only visible to the caller through a shared handle, an
+/// `Rc<RefCell<_>>` of the history, which is a history itself.
+///
+/// # Examples
+///
+/// ```
+/// use inquire::history::History;
+///
+/// #[derive(Clone, Default)]
+/// struct RecentRegions(Vec<String>);
+///
+/// impl History for RecentRegions {
+///     fn entries(&self) -> Vec<String> {
+///         self.0.clone()
+///     }
+///
+///     fn record(&mut self, entry: &str) {
+///         self.0.retain(|e| e != entry);
+///         self.0.insert(0, entry.to_string());
+///         self.0.truncate(5);
+///     }
+/// }
+///
+/// let mut history = RecentRegions::default();
+/// history.record("eu-west-1");
+/// history.record("us-east-1");
+/// history.record("eu-west-1");
+///
+/// assert_eq!(vec!["eu-west-1", "us-east-1"], history.entries());
+/// ```
+pub trait History: DynClone {
+    /// Entries recorded so far, the most recent first.
+    fn entries(&self) -> Vec<String>;
+
+    /// Records the answer submitted by the user.
+    fn record(&mut self, entry: &str);
+}
+
+impl Clone for Box<dyn History> {
+    fn clone(&self) -> Self {
+        dyn_clone::clone_box(&**self)
+    }
+}
+
+impl<H: History> History for Rc<RefCell<H>> {
+    fn entries(&self) -> Vec<String> {
+        self.borrow().entries()
+    }
+
+    fn record(&mut self, entry: &str) {
+        self.borrow_mut().record(entry);
+    }
+}
+
+/// History stored in a file, one entry per line from the oldest to the most
+/// recent, so that the previous answers are remembered across runs of the
+/// program.
+///
+/// The file is written every time an entry is recorded, creating its parent
+/// directories if needed. As recording an entry can not fail the prompt,
+/// errors when writing the file are ignored.
+///
+/// By default, recording an entry removes its previous occurrences and only
+/// the [`DEFAULT_MAX_SIZE`](FileHistory::DEFAULT_MAX_SIZE) most recent entries
+/// are kept.
+///
+/// # Examples
+///
+/// ```no_run
+/// use std::{cell::RefCell, rc::Rc};
+///
+/// use inquire::{
+///     history::{FileHistory, History},
+///     Text,
+/// };
+///
+/// let history = FileHistory::open("/home/user/.cache/mytool/history")?
+///     .with_max_size(100);
+/// let history = Rc::new(RefCell::new(history));
+///
+/// let query = Text::new("Search:")
+///     .with_history(Rc::clone(&history))
+///     .prompt();
+///
+/// // the answer was recorded through the shared handle
+/// println!("Recent searches: {:?}", history.entries());
+/// # Ok::<(), std::io::Error>(())
+/// ```
+#[derive(Clone, Debug)]
+pub struct FileHistory {
+    path: PathBuf,
+    // the most recent first
+    entries: Vec<String>,
+    max_size: usize,
+    deduplicate: bool,
+}
+
+impl FileHistory {
+    /// Default maximum number of entries kept in the history, 500.
+    pub const DEFAULT_MAX_SIZE: usize = 500;
+
+    /// Loads the history stored in the file at the given path, which is
+    /// empty if the file does not exist yet.
+    pub fn open<P: AsRef<Path>>(path: P) -> io::Result<Self> {
+        let path = path.as_ref().to_path_buf();
+
+        let entries = match fs::read_to_string(&path) {
+            Ok(content) => content.lines().rev().map(unescape).collect(),
+            Err(err) if err.kind() == io::ErrorKind::NotFound => vec![],
+            Err(err) => return Err(err),
+        };
+
+        // the entries beyond the maximum size are only dropped once an entry
+        // is recorded, so that a larger maximum can still be set
+        Ok(Self {
+            path,
+            entries,
+            max_size: Self::DEFAULT_MAX_SIZE,
+            deduplicate: true,
+        })
+    }
+
+    /// Sets the maximum number of entries kept in the history, the oldest ones
+    /// being dropped first.
+    pub fn with_max_size(mut self, max_size: usize) -> Self {
+        self.max_size = max_size;
+        self.entries.truncate(max_size);
+        self
+    }
+
+    /// Sets whether recording an entry removes its previous occurrences from
+    /// the history. Defaults to true.
+    pub fn with_deduplication(mut self, deduplicate: bool) -> Self {
+        self.deduplicate = deduplicate;
+        self
+    }
+
+    fn save(&self) -> io::Result<()> {
+        if let Some(parent) = self.path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+
+        let mut content = String::new();
+        for entry in self.entries.iter().rev() {
+            content.push_str(&escape(entry));
+            content.push('\n');
+        }
+
+        fs::write(&self.path, content)
+    }
+}
+
+impl History for FileHistory {
+    fn entries(&self) -> Vec<String> {
+        self.entries.iter().take(self.max_size).cloned().collect()
+    }
+
+    fn record(&mut self, entry: &str) {
+        if self.deduplicate {
+            self.entries.retain(|e| e != entry);
+        }
+
+        self.entries.insert(0, entry.to_string());
+        self.entries.truncate(self.max_size);
+
+        let _ = self.save();
+    }
+}
+
+/// Escapes the line breaks of an entry, which is stored in a single line.
+fn escape(entry: &str) -> String {
+    entry.replace('\\', "\\\\").replace('\n', "\\n")
+}
+
+fn unescape(line: &str) -> String {
+    let mut entry = String::with_capacity(line.len());
+    let mut chars = line.chars();
+
+    while let Some(c) = chars.next() {
+        match (c, chars.clone().next()) {
+            ('\\', Some('n')) => {
+                entry.push('\n');
+                chars.next();
+            }
+            ('\\', Some('\\')) => {
+                entry.push('\\');
+                chars.next();
+            }
+            (c, _) => entry.push(c),
+        }
+    }
+
+    entry
+}
+
+#[cfg(test)]
+mod test {
+    use std::{cell::RefCell, fs, rc::Rc};
+
+    use super::{FileHistory, History};
+    use crate::{
+        test::fake_backend,
+        ui::{Key, KeyModifiers},
+        Select, Text,
+    };
+
+    #[test]
+    fn file_history_is_saved_deduplicated_and_truncated() {
+        let path = std::env::temp_dir()
+            .join(format!("inquire-history-{}", std::process::id()))
+            .join("history");
+
+        let mut history = FileHistory::open(&path).unwrap().with_max_size(3);
+        assert!(history.entries().is_empty());
+
+        history.record("a");
+        history.record("multi\nline \\n");
+        history.record("b");
+        history.record("a");
+        history.record("c");
+
+        let reopened = FileHistory::open(&path).unwrap();
+        assert_eq!(vec!["c", "a", "b"], reopened.entries());
+
+        let mut history = reopened.with_deduplication(false);
+        history.record("b");
+        history.record("b");
+        assert_eq!(vec!["b", "b", "c", "a", "b"], history.entries());
+
+        let mut history = FileHistory::open(&path).unwrap();
+        history.record("multi\nline \\n");
+        assert_eq!(
+            "multi\nline \\n",
+            FileHistory::open(&path).unwrap().entries()[0]
+        );
+
+        fs::remove_dir_all(path.parent().unwrap()).unwrap();
+    }
+
+    #[test]
+    fn larger_maximum_keeps_every_entry_of_the_file() {
+        let path = std::env::temp_dir()
+            .join(format!("inquire-history-large-{}", std::process::id()))
+            .join("history");
+        fs::create_dir_all(path.parent().unwrap()).unwrap();
+
+        let content = (0..600).map(|i| format!("{i}\n")).collect::<String>();
+        fs::write(&path, content).unwrap();
+
+        assert_eq!(
+            FileHistory::DEFAULT_MAX_SIZE,
+            FileHistory::open(&path).unwrap().entries().len()
+        );
+
+        let mut history = FileHistory::open(&path).unwrap().with_max_size(1000);
+        assert_eq!(600, history.entries().len());
+
+        history.record("600");
+        let reopened = FileHistory::open(&path).unwrap().with_max_size(1000);
+        assert_eq!(601, reopened.entries().len());
+        assert_eq!("0", reopened.entries()[600]);
+
+        fs::remove_dir_all(path.parent().unwrap()).unwrap();
+    }
+
+    #[test]
+    fn shared_handle_sees_the_entries_recorded_by_prompts() {
+        let path = std::env::temp_dir()
+            .join(format!("inquire-history-shared-{}", std::process::id()))
+            .join("history");
+        let history = Rc::new(RefCell::new(FileHistory::open(&path).unwrap()));
+
+        let mut backend = fake_backend(vec![Key::Char('a', KeyModifiers::NONE), Key::Enter]);
+        Text::new("")
+            .with_history(Rc::clone(&history))
+            .prompt_with_backend(&mut backend)
+            .unwrap();
+
+        let mut backend = fake_backend(vec![Key::Down(KeyModifiers::NONE), Key::Enter]);
+        Select::new("", vec!["x", "y"])
+            .with_history(Rc::clone(&history))
+            .prompt_with_backend(&mut backend)
+            .unwrap();
+
+        assert_eq!(vec!["y", "a"], history.entries());
+        assert_eq!(vec!["y", "a"], FileHistory::open(&path).unwrap().entries());
+
+        fs::remove_dir_all(path.parent().unwrap()).unwrap();
+    }
+}
@@ -16,6 +16,12 @@ pub enum InputAction {
     /// Writes a character to the content, according to the current cursor
     /// position.
     Write(char),
+    /// Inserts the text deleted last by a word or line deletion at the
+    /// cursor position.
+    Yank,
+    /// Right after a yank, replaces the yanked text by the one deleted
+    /// before it, cycling through the kill ring.
+    YankPop,
 }
 
 impl InnerAction for InputAction {
@@ -27,11 +33,9 @@ impl InnerAction for InputAction {
     {
         let action = match key {
             Key::Backspace => Self::Delete(Magnitude::Char, LineDirection::Left),
+            // most terminals send Ctrl+Backspace as Ctrl+H
             Key::Char('h', m) if m.contains(KeyModifiers::CONTROL) => {
-                // Ctrl+Backspace is tricky, we don't want to handle a Ctrl+H
-                // but also don't want ctrl+h to simply write h.
-                // Let's catch this combination and ignore it.
-                return None;
+                Self::Delete(Magnitude::Word, LineDirection::Left)
             }
 
             Key::Delete(m) if m.contains(KeyModifiers::CONTROL) => {
@@ -59,6 +63,100 @@ impl InnerAction for InputAction {
     }
 }
 
+/// Preset of key bindings used to edit the text input of prompts such as
+/// [`Text`](crate::Text), [`CustomType`](crate::CustomType) and
+/// [`Password`](crate::Password).
+///
+/// Presets only add bindings on top of the default ones, such as the arrow,
+/// `Home`, `End`, `Backspace` and `Delete` keys.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub enum InputKeymap {
+    /// Default bindings only.
+    #[default]
+    Default,
+    /// Bindings of GNU readline, as found in most shells:
+    ///
+    /// - `Ctrl+a` and `Ctrl+e` move to the start and end of the line.
+    /// - `Alt+b` and `Alt+f` move one word backwards and forwards.
+    /// - `Ctrl+w` deletes the word before the cursor.
+    /// - `Ctrl+k` deletes everything after the cursor.
+    /// - `Ctrl+u` deletes everything before the cursor.
+    /// - `Ctrl+y` yanks the text deleted last, taking precedence over the
+    ///   default redo key, and `Alt+y` then cycles through the text deleted
+    ///   before it.
+    ///
+    /// Consecutive deletions are merged, so that they are yanked at once.
+    Readline,
+}
+
+impl InputKeymap {
+    /// Returns the action bound to the key by this keymap.
+    pub fn action(self, key: Key) -> Option<InputAction> {
+        if let Some(action) = self.yank_action(key) {
+            return Some(action);
+        }
+
+        let action = match (self, key) {
+            (Self::Readline, Key::Char('a', KeyModifiers::CONTROL)) => {
+                InputAction::MoveCursor(Magnitude::Line, LineDirection::Left)
+            }
+            (Self::Readline, Key::Char('e', KeyModifiers::CONTROL)) => {
+                InputAction::MoveCursor(Magnitude::Line, LineDirection::Right)
+            }
+            (Self::Readline, Key::Char('b', KeyModifiers::ALT)) => {
+                InputAction::MoveCursor(Magnitude::Word, LineDirection::Left)
+            }
+            (Self::Readline, Key::Char('f', KeyModifiers::ALT)) => {
+                InputAction::MoveCursor(Magnitude::Word, LineDirection::Right)
+            }
+            (Self::Readline, Key::Char('w', KeyModifiers::CONTROL)) => {
+                InputAction::Delete(Magnitude::Word, LineDirection::Left)
+            }
+            (Self::Readline, Key::Char('k', KeyModifiers::CONTROL)) => {
+                InputAction::Delete(Magnitude::Line, LineDirection::Right)
+            }
+            (Self::Readline, Key::Char('u', KeyModifiers::CONTROL)) => {
+                InputAction::Delete(Magnitude::Line, LineDirection::Left)
+            }
+            (_, key) => return InputAction::from_key(key, &()),
+        };
+
+        Some(action)
+    }
+
+    /// Returns the yank action bound to the key by this keymap, if any,
+    /// which prompts handle before their undo keys.
+    pub fn yank_action(self, key: Key) -> Option<InputAction> {
+        match (self, key) {
+            (Self::Readline, Key::Char('y', KeyModifiers::CONTROL)) => Some(InputAction::Yank),
+            (Self::Readline, Key::Char('y', KeyModifiers::ALT)) => Some(InputAction::YankPop),
+            _ => None,
+        }
+    }
+}
+
+/// Key bindings to undo and redo the edits made to the text input of
+/// prompts such as [`Text`](crate::Text), [`CustomType`](crate::CustomType)
+/// and [`Password`](crate::Password).
+///
+/// Defaults to `Ctrl+z` and `Ctrl+y`.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct UndoKeys {
+    /// Key that reverts the last edit.
+    pub undo: Key,
+    /// Key that reapplies the last edit reverted.
+    pub redo: Key,
+}
+
+impl Default for UndoKeys {
+    fn default() -> Self {
+        Self {
+            undo: Key::Char('z', KeyModifiers::CONTROL),
+            redo: Key::Char('y', KeyModifiers::CONTROL),
+        }
+    }
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
@@ -88,13 +186,10 @@ mod test {
     }
 
     #[test]
-    fn ctrl_backspace_does_nothing() {
-        // Ctrl+Backspace is tricky, we don't want to handle a Ctrl+H
-        // but also don't want ctrl+h to simply write h.
-        // Let's catch this combination and ignore it.
+    fn ctrl_backspace_results_in_delete_word_left() {
         assert_eq!(
             InputAction::from_key(Key::Char('h', KeyModifiers::CONTROL), &()),
-            None
+            Some(InputAction::Delete(Magnitude::Word, LineDirection::Left))
         );
     }
 
@@ -200,6 +295,32 @@ mod test {
         );
     }
 
+    #[test]
+    fn readline_keymap_adds_emacs_bindings() {
+        assert_eq!(
+            InputKeymap::Readline.action(Key::Char('w', KeyModifiers::CONTROL)),
+            Some(InputAction::Delete(Magnitude::Word, LineDirection::Left))
+        );
+        assert_eq!(
+            InputKeymap::Readline.action(Key::Char('b', KeyModifiers::ALT)),
+            Some(InputAction::MoveCursor(
+                Magnitude::Word,
+                LineDirection::Left
+            ))
+        );
+        assert_eq!(
+            InputKeymap::Readline.action(Key::Home),
+            Some(InputAction::MoveCursor(
+                Magnitude::Line,
+                LineDirection::Left
+            ))
+        );
+        assert_eq!(
+            InputKeymap::Default.action(Key::Char('w', KeyModifiers::CONTROL)),
+            Some(InputAction::Write('w'))
+        );
+    }
+
     #[test]
     fn page_up_and_down_do_nothing() {
         assert_eq!(
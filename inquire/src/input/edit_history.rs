@@ -0,0 +1,269 @@
+use super::{Input, InputActionResult, LineDirection, Magnitude};
+use crate::InputAction;
+
+/// Maximum number of deleted texts kept in the kill ring.
+const KILL_RING_SIZE: usize = 16;
+
+/// Edits made to a text input, which can be undone and redone.
+///
+/// Characters typed in a row within a word are undone at once, like in
+/// most text editors.
+///
+/// Texts deleted by word and line deletions are kept in a kill ring, from
+/// which they are yanked back, like in shells.
+#[derive(Clone, Debug, Default)]
+pub struct EditHistory {
+    // inputs as they were before each edit, the latest last
+    undo: Vec<Input>,
+    // inputs as they were before each undo, the latest last
+    redo: Vec<Input>,
+    // whether the last edit was a character typed within a word
+    typing: bool,
+    // texts deleted by word and line deletions, the latest last
+    kills: Vec<String>,
+    // whether the last edit was a deletion, which the next one is merged with
+    killing: bool,
+    // position in the kill ring of the text inserted by the last edit, if it
+    // was a yank, which a yank pop replaces
+    yanked: Option<usize>,
+}
+
+impl EditHistory {
+    /// Performs the action on the input, recording its previous state if
+    /// the content changed.
+    pub fn handle(&mut self, input: &mut Input, action: InputAction) -> InputActionResult {
+        let yanked = self.yanked.take();
+
+        match action {
+            InputAction::Yank => return self.yank(input, 0),
+            InputAction::YankPop => match yanked {
+                Some(index) => return self.yank_pop(input, index),
+                None => return InputActionResult::Clean,
+            },
+            _ => {}
+        }
+
+        let before = input.clone();
+        let result = input.handle(action);
+        let killing = std::mem::take(&mut self.killing);
+
+        if let (
+            InputActionResult::ContentChanged,
+            InputAction::Delete(Magnitude::Word | Magnitude::Line, direction),
+        ) = (result, action)
+        {
+            self.kill(&before, input, direction, killing);
+        }
+
+        match result {
+            InputActionResult::ContentChanged => {
+                let typing = matches!(action, InputAction::Write(c) if !c.is_whitespace());
+
+                if !(typing && self.typing) {
+                    self.undo.push(before);
+                }
+
+                self.typing = typing;
+                self.redo.clear();
+            }
+            // moving the cursor ends the word being typed
+            InputActionResult::PositionChanged => self.typing = false,
+            InputActionResult::Clean => {}
+        }
+
+        result
+    }
+
+    /// Records the text removed from the input in the kill ring, merging it
+    /// with the previous one when the last edit was a deletion as well.
+    fn kill(&mut self, before: &Input, after: &Input, direction: LineDirection, merge: bool) {
+        let start = after.pre_cursor().len();
+        let len = before.content().len() - after.content().len();
+        let killed = &before.content()[start..start + len];
+
+        if let (true, Some(last)) = (merge, self.kills.last_mut()) {
+            match direction {
+                LineDirection::Left => last.insert_str(0, killed),
+                LineDirection::Right => last.push_str(killed),
+            }
+        } else {
+            self.kills.push(killed.to_string());
+            if self.kills.len() > KILL_RING_SIZE {
+                self.kills.remove(0);
+            }
+        }
+
+        self.killing = true;
+    }
+
+    /// Inserts the text of the kill ring at the given position, counted from
+    /// its latest entry, at the cursor, as a single edit.
+    fn yank(&mut self, input: &mut Input, index: usize) -> InputActionResult {
+        let text = match self.kills.iter().rev().nth(index) {
+            Some(text) => text.clone(),
+            None => return InputActionResult::Clean,
+        };
+
+        let result = self.paste(input, &text);
+        self.yanked = Some(index);
+
+        result
+    }
+
+    /// Replaces the text inserted by the last yank by the entry of the kill
+    /// ring preceding it, going back to the latest one after the oldest.
+    fn yank_pop(&mut self, input: &mut Input, index: usize) -> InputActionResult {
+        // the input as it was before the yank, which was recorded as an edit
+        match self.undo.pop() {
+            Some(before_yank) => *input = before_yank,
+            None => return InputActionResult::Clean,
+        }
+
+        self.yank(input, (index + 1) % self.kills.len())
+    }
+
+    /// Input as the yank or yank pop would leave it, without performing it,
+    /// or `None` if the action is not a yank or there is nothing to yank.
+    pub fn preview_yank(&self, input: &Input, action: InputAction) -> Option<Input> {
+        let (mut input, index) = match (action, self.yanked) {
+            (InputAction::Yank, _) => (input.clone(), 0),
+            (InputAction::YankPop, Some(index)) => {
+                (self.undo.last()?.clone(), (index + 1) % self.kills.len())
+            }
+            _ => return None,
+        };
+
+        input.insert_str(self.kills.iter().rev().nth(index)?);
+
+        Some(input)
+    }
+
+    /// Inserts the pasted text at the cursor position, as a single edit.
+    pub fn paste(&mut self, input: &mut Input, text: &str) -> InputActionResult {
+        let before = input.clone();
+        let result = input.insert_str(text);
+
+        if let InputActionResult::ContentChanged = result {
+            self.push(before);
+        }
+
+        result
+    }
+
+    /// Records the state of an input before it is replaced as a whole, e.g.
+    /// by an autocompletion.
+    pub fn push(&mut self, before: Input) {
+        self.undo.push(before);
+        self.redo.clear();
+        self.typing = false;
+        self.killing = false;
+        self.yanked = None;
+    }
+
+    /// Reverts the last edit of the input, returning whether there was any.
+    pub fn undo(&mut self, input: &mut Input) -> bool {
+        match self.undo.pop() {
+            Some(previous) => {
+                self.redo.push(std::mem::replace(input, previous));
+                self.typing = false;
+                self.killing = false;
+                self.yanked = None;
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Reapplies the last edit undone, returning whether there was any.
+    pub fn redo(&mut self, input: &mut Input) -> bool {
+        match self.redo.pop() {
+            Some(next) => {
+                self.undo.push(std::mem::replace(input, next));
+                self.typing = false;
+                self.killing = false;
+                self.yanked = None;
+                true
+            }
+            None => false,
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::EditHistory;
+    use crate::{
+        input::{Input, InputActionResult, LineDirection, Magnitude},
+        InputAction,
+    };
+
+    #[test]
+    fn words_typed_in_a_row_are_undone_at_once() {
+        let mut input = Input::new();
+        let mut edits = EditHistory::default();
+
+        for c in "hello world".chars() {
+            edits.handle(&mut input, InputAction::Write(c));
+        }
+        edits.handle(
+            &mut input,
+            InputAction::Delete(Magnitude::Char, LineDirection::Left),
+        );
+
+        assert!(edits.undo(&mut input));
+        assert_eq!("hello world", input.content());
+        assert!(edits.undo(&mut input));
+        assert_eq!("hello ", input.content());
+        assert!(edits.undo(&mut input));
+        assert_eq!("hello", input.content());
+        assert!(edits.undo(&mut input));
+        assert_eq!("", input.content());
+        assert!(!edits.undo(&mut input));
+
+        assert!(edits.redo(&mut input));
+        assert!(edits.redo(&mut input));
+        assert_eq!("hello ", input.content());
+
+        edits.handle(&mut input, InputAction::Write('!'));
+        assert!(!edits.redo(&mut input));
+        assert_eq!("hello !", input.content());
+    }
+
+    #[test]
+    fn deleted_text_is_yanked_from_the_kill_ring() {
+        let mut input = Input::new_with("alpha beta gamma");
+        let mut edits = EditHistory::default();
+        let kill_word = InputAction::Delete(Magnitude::Word, LineDirection::Left);
+
+        edits.handle(&mut input, kill_word);
+        edits.handle(&mut input, kill_word);
+        edits.handle(
+            &mut input,
+            InputAction::MoveCursor(Magnitude::Line, LineDirection::Left),
+        );
+        edits.handle(
+            &mut input,
+            InputAction::Delete(Magnitude::Line, LineDirection::Right),
+        );
+        assert_eq!("", input.content());
+
+        edits.handle(&mut input, InputAction::Yank);
+        assert_eq!("alpha ", input.content());
+        edits.handle(&mut input, InputAction::YankPop);
+        assert_eq!("beta gamma", input.content());
+        edits.handle(&mut input, InputAction::YankPop);
+        assert_eq!("alpha ", input.content());
+
+        let preview = edits.preview_yank(&input, InputAction::YankPop);
+        assert_eq!(Some("beta gamma"), preview.as_ref().map(Input::content));
+        assert_eq!("alpha ", input.content());
+
+        assert!(edits.undo(&mut input));
+        assert_eq!("", input.content());
+        assert!(edits.preview_yank(&input, InputAction::YankPop).is_none());
+        assert_eq!(
+            InputActionResult::Clean,
+            edits.handle(&mut input, InputAction::YankPop)
+        );
+    }
+}
@@ -1,7 +1,10 @@
 pub mod action;
+mod edit_history;
 #[cfg(test)]
 mod test;
 
+pub use edit_history::EditHistory;
+
 use unicode_segmentation::UnicodeSegmentation;
 
 use crate::InputAction;
@@ -19,8 +22,36 @@ pub enum LineDirection {
     Right,
 }
 
-fn is_alphanumeric(grapheme: &str) -> bool {
-    grapheme.unicode_words().count() > 0
+/// Positions of the words of the content, in graphemes, split at the Unicode
+/// word boundaries, so that words such as `don't` or `3.14` are kept whole.
+fn word_ranges(content: &str) -> Vec<(usize, usize)> {
+    let mut ranges = vec![];
+    let mut start = 0;
+
+    for segment in content.split_word_bounds() {
+        let end = start + segment.graphemes(true).count();
+
+        // segments without letters or digits are punctuation or whitespace
+        if segment.unicode_words().next().is_some() {
+            ranges.push((start, end));
+        }
+
+        start = end;
+    }
+
+    ranges
+}
+
+/// Prepares text pasted into an input, replacing its line breaks by the
+/// separator and its other control characters, such as tabs, by spaces.
+///
+/// The line break copied along with the last line is dropped.
+pub fn clean_paste(text: &str, line_separator: &str) -> String {
+    text.trim_end_matches(['\r', '\n'])
+        .lines()
+        .map(|line| line.replace(|c: char| c.is_control(), " "))
+        .collect::<Vec<String>>()
+        .join(line_separator)
 }
 
 #[derive(Clone, Debug, PartialEq)]
@@ -93,9 +124,25 @@ impl Input {
                 LineDirection::Right => self.forwards_delete(mag),
             },
             InputAction::Write(c) => self.insert(c),
+            // the kill ring is kept by the edit history
+            InputAction::Yank | InputAction::YankPop => InputActionResult::Clean,
         }
     }
 
+    /// Inserts the text at the cursor position, moving the cursor after it.
+    pub fn insert_str(&mut self, text: &str) -> InputActionResult {
+        if text.is_empty() {
+            return InputActionResult::Clean;
+        }
+
+        let at = self.pre_cursor().len();
+        self.content.insert_str(at, text);
+        self.update_length();
+        self.cursor = self.content[..at + text.len()].graphemes(true).count();
+
+        InputActionResult::ContentChanged
+    }
+
     pub fn clear(&mut self) {
         self.content.clear();
         self.cursor = 0;
@@ -165,42 +212,20 @@ impl Input {
     }
 
     fn next_word_index(&mut self) -> usize {
-        let graphemes = self.content.graphemes(true).enumerate().skip(self.cursor);
-        let mut seen_word = false;
-
-        for (idx, g) in graphemes {
-            if is_alphanumeric(g) {
-                seen_word = true;
-            } else if seen_word {
-                return idx;
-            }
-        }
-
-        self.length
+        word_ranges(&self.content)
+            .into_iter()
+            .map(|(_start, end)| end)
+            .find(|end| *end > self.cursor)
+            .unwrap_or(self.length)
     }
 
     fn prev_word_index(&mut self) -> usize {
-        let mut seen_word = false;
-        let left = self.cursor;
-        let right = self.length - left;
-        let graphemes = self
-            .content
-            .graphemes(true)
+        word_ranges(&self.content)
+            .into_iter()
+            .map(|(start, _end)| start)
             .rev()
-            .skip(right)
-            .enumerate()
-            .map(|(idx, g)| (idx.saturating_add(1), g)); // Item.0 = distance to cursor
-
-        for (dist, g) in graphemes {
-            if is_alphanumeric(g) {
-                seen_word = true;
-            } else if seen_word {
-                // word found
-                return self.cursor.saturating_sub(dist - 1);
-            }
-        }
-
-        0
+            .find(|start| *start < self.cursor)
+            .unwrap_or(0)
     }
 
     fn insert(&mut self, c: char) -> InputActionResult {
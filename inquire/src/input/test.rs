@@ -53,6 +53,26 @@ fn move_previous_word() {
     }
 }
 
+#[test]
+fn words_are_split_at_unicode_word_boundaries() {
+    let content = "don't pay 3.14€";
+
+    let mut input = Input::new_with(content).with_cursor(0);
+    let mut ends = vec![];
+    while input.cursor() < input.length() {
+        input.handle(InputAction::MoveCursor(
+            Magnitude::Word,
+            LineDirection::Right,
+        ));
+        ends.push(input.cursor());
+    }
+    assert_eq!(vec![5, 9, 14, 15], ends);
+
+    let mut input = Input::new_with(content);
+    input.handle(InputAction::Delete(Magnitude::Word, LineDirection::Left));
+    assert_eq!("don't pay ", input.content());
+}
+
 #[test]
 // https://github.com/mikaelmello/inquire/issues/5
 fn regression_issue_5() {
@@ -475,3 +495,16 @@ fn generic_user_scenario() {
     assert_eq!("great idea! you", input.pre_cursor());
     assert_eq!(15, input.cursor());
 }
+
+#[test]
+fn pasted_text_is_inserted_at_the_cursor() {
+    let mut input = Input::new_with("hello world").with_cursor(6);
+
+    let result = input.insert_str(&super::clean_paste("big\r\nwide\t\n", " "));
+    assert_eq!(InputActionResult::ContentChanged, result);
+    assert_eq!("hello big wide world", input.content());
+    assert_eq!("hello big wide ", input.pre_cursor());
+    assert_eq!(20, input.length());
+
+    assert_eq!(InputActionResult::Clean, input.insert_str(""));
+}
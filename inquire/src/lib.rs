@@ -6,13 +6,55 @@
 //! - [`Text`] to get text input from the user, with _built-in autocompletion support_;
 //! - [`Editor`]* to get longer text inputs by opening a text editor for the user;
 //! - [`DateSelect`]* to get a date input from the user, selected via an _interactive calendar_;
+//! - [`TimeSelect`]* to get a time of the day from the user, selected via an _interactive time picker_;
+//! - [`DateTimeSelect`]* to get both a date and a time of the day from the user in a single prompt;
+//! - [`MonthSelect`]* to get a month and year from the user, such as a billing period, without a day;
+//! - [`WeekSelect`]* to get an ISO week from the user, selected via an _interactive calendar_ highlighting whole weeks;
 //! - [`Select`] to ask the user to select one option from a given list;
 //! - [`MultiSelect`] to ask the user to select an arbitrary number of options from a given list;
 //! - [`Confirm`] for simple yes/no confirmation prompts;
 //! - [`CustomType`] for text prompts that you would like to parse to a custom type, such as numbers or UUIDs;
+//! - [`Number`] for numeric inputs that can also be incremented and decremented with the arrow keys;
+//! - [`Slider`] to pick a value from a bounded numeric range by moving the handle of a horizontal bar;
+//! - [`TextArea`] to get multi-line text inputs directly in the terminal;
+//! - [`FileSelect`] for browsing the filesystem and selecting a file;
+//! - [`DirectorySelect`] for browsing the filesystem and selecting a directory;
+//! - [`TreeSelect`] to select one item of a nested structure by expanding and collapsing its nodes;
+//! - [`Order`] to rank a list of options by reordering them;
+//! - [`Toggle`] to switch between two labeled states, such as on and off;
+//! - [`ColorSelect`] Color picker, with a palette of swatches and optional hex input;
+//! - [`Rating`] Score in a small scale, rendered as stars or other symbols;
+//! - [`TableSelect`] Selection of one row of structured data, displayed in aligned columns;
+//! - [`Transfer`] Choice of a subset of options, moved between side-by-side panes;
+//! - [`DurationInput`] Human-friendly durations, such as `1h30m`, parsed into a `Duration`;
+//! - [`IpPrompt`] IP addresses and CIDR networks, validated as you type;
+//! - [`KeyCapture`] Key combination pressed by the user, e.g. for shortcut configuration;
+//! - [`Tags`] Free-form list of values with autocomplete suggestions, returning a `Vec<String>`;
+//! - [`FuzzyFinder`] Full-screen selection among thousands of options, with previews;
+//! - [`GridSelect`] Selection among short options laid out in a grid;
+//! - [`CheckboxMatrix`] Grid of checkboxes with labeled rows and columns, such as permissions per role;
+//! - [`NumberRange`] Lower and upper bounds of a numeric range, in two linked fields;
+//! - [`Wizard`] Sequence of prompts with progress, where ESC goes back to edit the previous answer;
+//! - [`Menu`] to navigate through nested menus, returning the path of selected entries;
+//! - [`Spinner`] to display an animated spinner while a task runs between prompts;
+//! - [`Email`] for email addresses, checked and normalized on submit;
+//! - [`Url`]* for URLs, parsed into a `url::Url` on submit;
+//! - [`PhoneNumber`] Phone number input filled through a digit mask;
+//! - [`Currency`] Amount of money, formatted while typed and returned as a fixed-point value;
+//! - [`Percentage`] Percentage between 0% and 100% with a bar preview;
+//! - [`Version`]* for semantic versions, with bump suggestions, parsed into a `semver::Version` on submit;
+//! - [`HotkeySelect`] Selection of an option by pressing its hotkey, e.g. `[d]eploy  [r]ollback`;
+//! - [`CommandPalette`] Command palette with fuzzy search, shortcut hints, recently used commands and categories;
+//! - [`TypeToConfirm`] to confirm a destructive action by typing an exact phrase;
+//! - [`ListEditor`] Add, edit, remove and reorder a list of items;
+//! - [`MapEditor`] Add, edit and remove the entries of a map of keys to values, e.g. environment variables;
+//! - [`TableEditor`] Small editable table of fixed columns and dynamic rows, such as port mappings;
+//! - [`PagedSelect`] Select one option among a huge list, fetched a page at a time from a provider;
 //! - [`Password`] for secretive text prompts.
 //!
-//! \* The Editor and DateSelect prompts are available by enabling the `editor` and `date` features, respectively.
+//! \* The Editor prompt is available by enabling the `editor` feature, the DateSelect, TimeSelect, DateTimeSelect, MonthSelect and WeekSelect prompts are available by enabling the `date` feature, the Url prompt is available by enabling the `url` feature, and the Version prompt is available by enabling the `semver` feature. The `SyntectHighlighter` of TextArea prompts is available by enabling the `syntect` feature.
+//!
+//! \* Mouse support in [`Select`] and [`MultiSelect`] prompts is available by enabling the `mouse` feature, along with the `crossterm` back-end. Note that capturing the mouse disables the text selection of the terminal while these prompts are open.
 //!
 //! Check out the [GitHub repository](https://github.com/mikaelmello/inquire) to see demos of what you can do with `inquire`.
 //!
@@ -55,10 +97,50 @@
 //!
 //! [`Text`]: crate::Text
 //! [`DateSelect`]: crate::DateSelect
+//! [`TimeSelect`]: crate::TimeSelect
+//! [`DateTimeSelect`]: crate::DateTimeSelect
+//! [`MonthSelect`]: crate::MonthSelect
+//! [`WeekSelect`]: crate::WeekSelect
 //! [`Select`]: crate::Select
 //! [`MultiSelect`]: crate::MultiSelect
 //! [`Confirm`]: crate::Confirm
 //! [`CustomType`]: crate::CustomType
+//! [`Number`]: crate::Number
+//! [`Slider`]: crate::Slider
+//! [`TextArea`]: crate::TextArea
+//! [`FileSelect`]: crate::FileSelect
+//! [`DirectorySelect`]: crate::DirectorySelect
+//! [`TreeSelect`]: crate::TreeSelect
+//! [`Order`]: crate::Order
+//! [`Toggle`]: crate::Toggle
+//! [`ColorSelect`]: crate::ColorSelect
+//! [`Rating`]: crate::Rating
+//! [`TableSelect`]: crate::TableSelect
+//! [`Transfer`]: crate::Transfer
+//! [`DurationInput`]: crate::DurationInput
+//! [`IpPrompt`]: crate::IpPrompt
+//! [`KeyCapture`]: crate::KeyCapture
+//! [`Tags`]: crate::Tags
+//! [`FuzzyFinder`]: crate::FuzzyFinder
+//! [`GridSelect`]: crate::GridSelect
+//! [`CheckboxMatrix`]: crate::CheckboxMatrix
+//! [`NumberRange`]: crate::NumberRange
+//! [`Wizard`]: crate::Wizard
+//! [`Menu`]: crate::Menu
+//! [`Spinner`]: crate::Spinner
+//! [`Email`]: crate::Email
+//! [`Url`]: crate::Url
+//! [`PhoneNumber`]: crate::PhoneNumber
+//! [`Currency`]: crate::Currency
+//! [`Percentage`]: crate::Percentage
+//! [`Version`]: crate::Version
+//! [`HotkeySelect`]: crate::HotkeySelect
+//! [`CommandPalette`]: crate::CommandPalette
+//! [`TypeToConfirm`]: crate::TypeToConfirm
+//! [`ListEditor`]: crate::ListEditor
+//! [`MapEditor`]: crate::MapEditor
+//! [`TableEditor`]: crate::TableEditor
+//! [`PagedSelect`]: crate::PagedSelect
 //! [`Password`]: crate::Password
 //! [`Editor`]: crate::Editor
 
@@ -71,10 +153,14 @@ pub mod autocompletion;
 mod config;
 #[cfg(feature = "date")]
 mod date_utils;
+pub mod default_value;
 pub mod error;
 pub mod formatter;
+pub mod highlighter;
+pub mod history;
 mod input;
 pub mod list_option;
+pub mod mask;
 pub mod parser;
 mod prompts;
 mod terminal;
@@ -84,7 +170,7 @@ mod utils;
 pub mod validator;
 
 pub use crate::autocompletion::Autocomplete;
-pub use crate::config::set_global_render_config;
+pub use crate::config::{set_global_render_config, set_global_wrap_around};
 pub use crate::error::{CustomUserError, InquireError};
 pub use crate::input::action::*;
 pub use crate::prompts::*;
@@ -1,5 +1,5 @@
-//! Utilities used to wrap user selections in [Select](crate::Select) and
-//! [`MultiSelect`](crate::MultiSelect) prompts.
+//! Utilities used to wrap the options and user selections of [Select](crate::Select)
+//! and [`MultiSelect`](crate::MultiSelect) prompts.
 
 use std::fmt;
 
@@ -47,3 +47,58 @@ where
         self.value.fmt(f)
     }
 }
+
+/// Option of [Select](crate::Select) and [`MultiSelect`](crate::MultiSelect)
+/// prompts carrying an arbitrary value along with the label displayed to the
+/// user, which is also the text searched when filtering the options.
+///
+/// This avoids maintaining a separate list of values to map the index of the
+/// answer back to the value it stands for.
+///
+/// # Examples
+///
+/// ```
+/// use inquire::list_option::OptionItem;
+///
+/// let regions = vec![
+///     OptionItem::new("N. Virginia", "us-east-1"),
+///     OptionItem::new("Ireland", "eu-west-1"),
+/// ];
+///
+/// // prompting with `Select::new("Region:", regions).prompt()` returns the
+/// // selected item, whose value is the region code
+/// assert_eq!("Ireland", regions[1].to_string());
+/// assert_eq!("eu-west-1", regions[1].value);
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct OptionItem<T> {
+    /// Label of the option, displayed to the user.
+    pub label: String,
+
+    /// Value carried by the option.
+    pub value: T,
+}
+
+impl<T> OptionItem<T> {
+    /// Creates an option with the provided label and value.
+    pub fn new<L>(label: L, value: T) -> Self
+    where
+        L: Into<String>,
+    {
+        Self {
+            label: label.into(),
+            value,
+        }
+    }
+
+    /// Consumes the option, returning its value.
+    pub fn into_value(self) -> T {
+        self.value
+    }
+}
+
+impl<T> fmt::Display for OptionItem<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.label.fmt(f)
+    }
+}
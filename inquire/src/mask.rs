@@ -0,0 +1,207 @@
+//! Masks that format the text input of [`Text`](crate::Text) prompts while
+//! the user types, such as dates or license plates.
+//!
+//! Each character of a mask specification is either a slot, accepting one
+//! character of a given class, or a literal inserted automatically:
+//!
+//! - `#` accepts a digit.
+//! - `A` accepts a letter.
+//! - `*` accepts a letter or a digit.
+//! - `\` escapes the next character, e.g. `\#` is a literal `#`.
+//! - Every other character is a literal.
+
+use std::fmt;
+
+/// Class of the characters accepted by a slot of a mask.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+enum Slot {
+    Digit,
+    Letter,
+    Alphanumeric,
+}
+
+impl Slot {
+    fn accepts(self, c: char) -> bool {
+        match self {
+            Self::Digit => c.is_ascii_digit(),
+            Self::Letter => c.is_alphabetic(),
+            Self::Alphanumeric => c.is_alphanumeric(),
+        }
+    }
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+enum Token {
+    Slot(Slot),
+    Literal(char),
+}
+
+/// Mask formatting the text input of a [`Text`](crate::Text) prompt, parsed
+/// from a specification such as `##/##/####` or `AA-####`.
+///
+/// The user only types the characters filling the slots, which must match
+/// their classes, while the literals are inserted as soon as the next slot is
+/// filled. Typing a literal is accepted as well, and ignored.
+///
+/// # Examples
+///
+/// ```
+/// use inquire::mask::InputMask;
+///
+/// let mask = InputMask::new("AA-####");
+///
+/// assert_eq!("AB-12", mask.format("AB12"));
+/// assert_eq!("AB12", mask.raw("AB-12"));
+/// assert!(!mask.is_complete("AB-12"));
+/// assert!(mask.is_complete("AB-1234"));
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct InputMask {
+    specification: String,
+    tokens: Vec<Token>,
+}
+
+impl InputMask {
+    /// Parses the mask specification.
+    pub fn new(specification: &str) -> Self {
+        let mut tokens = vec![];
+        let mut chars = specification.chars();
+
+        while let Some(c) = chars.next() {
+            let token = match c {
+                '#' => Token::Slot(Slot::Digit),
+                'A' => Token::Slot(Slot::Letter),
+                '*' => Token::Slot(Slot::Alphanumeric),
+                '\\' => match chars.next() {
+                    Some(escaped) => Token::Literal(escaped),
+                    None => Token::Literal('\\'),
+                },
+                c => Token::Literal(c),
+            };
+
+            tokens.push(token);
+        }
+
+        Self {
+            specification: specification.to_string(),
+            tokens,
+        }
+    }
+
+    /// Number of characters typed by the user to fill the mask.
+    pub fn slot_count(&self) -> usize {
+        self.slots().count()
+    }
+
+    /// Whether the character is accepted by the slot at the given position,
+    /// counting slots only.
+    pub fn accepts(&self, position: usize, c: char) -> bool {
+        matches!(self.slots().nth(position), Some(slot) if slot.accepts(c))
+    }
+
+    /// Applies the mask to the characters typed by the user, inserting the
+    /// literals up to the last character. Characters beyond the last slot are
+    /// dropped.
+    pub fn format(&self, raw: &str) -> String {
+        let mut raw = raw.chars().peekable();
+        let mut formatted = String::new();
+
+        for token in &self.tokens {
+            if raw.peek().is_none() {
+                break;
+            }
+
+            match token {
+                Token::Literal(literal) => formatted.push(*literal),
+                Token::Slot(_) => formatted.extend(raw.next()),
+            }
+        }
+
+        formatted
+    }
+
+    /// Extracts the characters typed by the user from the formatted value,
+    /// removing the literals of the mask.
+    pub fn raw(&self, formatted: &str) -> String {
+        self.tokens
+            .iter()
+            .zip(formatted.chars())
+            .filter_map(|(token, c)| match token {
+                Token::Slot(_) => Some(c),
+                Token::Literal(_) => None,
+            })
+            .collect()
+    }
+
+    /// Whether every slot of the mask is filled in the formatted value.
+    pub fn is_complete(&self, formatted: &str) -> bool {
+        self.raw(formatted).chars().count() == self.slot_count()
+    }
+
+    /// Mask with its slots displayed as `_`, e.g. `__/__/____`, used as the
+    /// placeholder of the input.
+    pub fn placeholder(&self) -> String {
+        self.tokens
+            .iter()
+            .map(|token| match token {
+                Token::Slot(_) => '_',
+                Token::Literal(literal) => *literal,
+            })
+            .collect()
+    }
+
+    fn slots(&self) -> impl Iterator<Item = Slot> + '_ {
+        self.tokens.iter().filter_map(|token| match token {
+            Token::Slot(slot) => Some(*slot),
+            Token::Literal(_) => None,
+        })
+    }
+}
+
+impl fmt::Display for InputMask {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.specification)
+    }
+}
+
+/// Answer of a [`Text`](crate::Text) prompt with a mask, returned by
+/// [`Text::masked_prompt`](crate::Text::masked_prompt).
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct MaskedAnswer {
+    /// Characters typed by the user, without the literals of the mask,
+    /// e.g. `12052024`.
+    pub raw: String,
+    /// Answer with the mask applied, e.g. `12/05/2024`.
+    pub formatted: String,
+}
+
+#[cfg(test)]
+mod test {
+    use super::InputMask;
+
+    #[test]
+    fn literals_are_inserted_before_the_next_slot() {
+        let mask = InputMask::new("##/##/####");
+
+        assert_eq!("", mask.format(""));
+        assert_eq!("12", mask.format("12"));
+        assert_eq!("12/0", mask.format("120"));
+        assert_eq!("12/05/2024", mask.format("12052024999"));
+        assert_eq!("12052024", mask.raw("12/05/2024"));
+        assert_eq!("__/__/____", mask.placeholder());
+    }
+
+    #[test]
+    fn slots_accept_their_classes_and_escapes_are_literals() {
+        let mask = InputMask::new("\\#A*#");
+
+        assert_eq!(3, mask.slot_count());
+        assert!(mask.accepts(0, 'é'));
+        assert!(!mask.accepts(0, '1'));
+        assert!(mask.accepts(1, '1'));
+        assert!(mask.accepts(1, 'b'));
+        assert!(!mask.accepts(2, 'b'));
+        assert!(!mask.accepts(3, '1'));
+        assert_eq!("#é1", mask.format("é1"));
+    }
+}
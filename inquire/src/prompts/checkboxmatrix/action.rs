@@ -0,0 +1,52 @@
+use crate::{
+    ui::{Key, KeyModifiers},
+    InnerAction,
+};
+
+use super::config::CheckboxMatrixConfig;
+
+/// Set of actions for a CheckboxMatrixPrompt.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum CheckboxMatrixPromptAction {
+    /// Moves the cursor to the column on the left.
+    MoveLeft,
+    /// Moves the cursor to the column on the right.
+    MoveRight,
+    /// Moves the cursor to the row above.
+    MoveUp,
+    /// Moves the cursor to the row below.
+    MoveDown,
+    /// Moves the cursor to the page above.
+    PageUp,
+    /// Moves the cursor to the page below.
+    PageDown,
+    /// Moves the cursor to the first row.
+    MoveToStart,
+    /// Moves the cursor to the last row.
+    MoveToEnd,
+    /// Toggles the checkbox under the cursor.
+    ToggleCurrentCell,
+}
+
+impl InnerAction for CheckboxMatrixPromptAction {
+    type Config = CheckboxMatrixConfig;
+
+    fn from_key(key: Key, _config: &CheckboxMatrixConfig) -> Option<Self> {
+        let action = match key {
+            Key::Left(KeyModifiers::NONE) | Key::Char('b', KeyModifiers::CONTROL) => Self::MoveLeft,
+            Key::Right(KeyModifiers::NONE) | Key::Char('f', KeyModifiers::CONTROL) => {
+                Self::MoveRight
+            }
+            Key::Up(KeyModifiers::NONE) | Key::Char('p', KeyModifiers::CONTROL) => Self::MoveUp,
+            Key::Down(KeyModifiers::NONE) | Key::Char('n', KeyModifiers::CONTROL) => Self::MoveDown,
+            Key::PageUp(_) => Self::PageUp,
+            Key::PageDown(_) => Self::PageDown,
+            Key::Home => Self::MoveToStart,
+            Key::End => Self::MoveToEnd,
+            Key::Char(' ', KeyModifiers::NONE) => Self::ToggleCurrentCell,
+            _ => return None,
+        };
+
+        Some(action)
+    }
+}
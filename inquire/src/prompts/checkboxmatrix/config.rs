@@ -0,0 +1,16 @@
+use crate::CheckboxMatrix;
+
+/// Configuration settings used in the execution of a CheckboxMatrixPrompt.
+#[derive(Copy, Clone, Debug)]
+pub struct CheckboxMatrixConfig {
+    /// Number of rows displayed at once.
+    pub page_size: usize,
+}
+
+impl From<&CheckboxMatrix<'_>> for CheckboxMatrixConfig {
+    fn from(value: &CheckboxMatrix<'_>) -> Self {
+        Self {
+            page_size: value.page_size,
+        }
+    }
+}
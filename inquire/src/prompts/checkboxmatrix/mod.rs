@@ -0,0 +1,229 @@
+mod action;
+mod config;
+mod prompt;
+#[cfg(test)]
+mod test;
+
+pub use action::*;
+use std::{collections::BTreeSet, fmt::Display};
+
+use crate::{
+    config::get_configuration,
+    error::{InquireError, InquireResult},
+    formatter::MatrixFormatter,
+    prompts::prompt::Prompt,
+    terminal::get_default_terminal,
+    ui::{Backend, CheckboxMatrixBackend, RenderConfig},
+};
+
+use self::prompt::CheckboxMatrixPrompt;
+
+/// Prompt suitable for when you need the user to check any number of cells in a grid of rows and columns, such as permissions granted to each role.
+///
+/// The row labels are displayed on the left and the column labels on a header above the checkboxes. The user moves through the cells with all four arrow keys, toggles the checkbox under the cursor with space and submits the answer with enter.
+///
+/// The answer is the set of checked cells, as `(row, column)` index pairs.
+///
+/// This prompt requires a prompt message and **non-empty** lists of row and column labels. The labels can be of any type as long as they implement the `Display` trait.
+/// - If any of the lists is empty, the prompt operation will fail with an `InquireError::InvalidConfiguration` error.
+///
+/// Checkbox matrix prompts provide several options of configuration:
+///
+/// - **Prompt message**: Required when creating the prompt.
+/// - **Row and column labels**: Required when creating the prompt. Must be **non-empty**.
+/// - **Default selections**: Cells that are checked when the prompt is first rendered. If any of the coordinates is out-of-range, the prompt will fail with an [`InquireError::InvalidConfiguration`] error.
+/// - **Help message**: Message displayed at the line below the prompt.
+/// - **Formatter**: Custom formatter in case you need to pre-process the user input before showing it as the final answer.
+///   - Prints the checked columns of each row by default.
+/// - **Page size**: Number of rows displayed at once, 7 by default.
+///
+/// # Example
+///
+/// ```no_run
+/// use inquire::CheckboxMatrix;
+///
+/// let roles = vec!["admin", "editor", "viewer"];
+/// let permissions = vec!["read", "write", "delete"];
+///
+/// let ans = CheckboxMatrix::new("Permissions:", &roles, &permissions)
+///     .with_default(&[(0, 0), (0, 1), (0, 2), (1, 0), (1, 1), (2, 0)])
+///     .prompt();
+///
+/// match ans {
+///     Ok(cells) => {
+///         for (role, permission) in cells {
+///             println!("{} can {}", roles[role], permissions[permission]);
+///         }
+///     }
+///     Err(_) => println!("The permissions could not be processed"),
+/// }
+/// ```
+///
+/// [`InquireError::InvalidConfiguration`]: crate::error::InquireError::InvalidConfiguration
+#[derive(Clone)]
+pub struct CheckboxMatrix<'a> {
+    /// Message to be presented to the user.
+    pub message: &'a str,
+
+    /// Labels of the rows of the matrix.
+    pub rows: Vec<String>,
+
+    /// Labels of the columns of the matrix.
+    pub columns: Vec<String>,
+
+    /// Default cells checked, as `(row, column)` index pairs.
+    pub default: Option<Vec<(usize, usize)>>,
+
+    /// Help message to be presented to the user.
+    pub help_message: Option<&'a str>,
+
+    /// Number of rows displayed at once.
+    pub page_size: usize,
+
+    /// Function that formats the user input and presents it to the user as the final rendering of the prompt.
+    pub formatter: MatrixFormatter<'a>,
+
+    /// RenderConfig to apply to the rendered interface.
+    ///
+    /// Note: The default render config considers if the NO_COLOR environment variable
+    /// is set to decide whether to render the colored config or the empty one.
+    ///
+    /// When overriding the config in a prompt, NO_COLOR is no longer considered and your
+    /// config is treated as the only source of truth. If you want to customize colors
+    /// and still support NO_COLOR, you will have to do this on your end.
+    pub render_config: RenderConfig<'a>,
+}
+
+impl<'a> CheckboxMatrix<'a> {
+    /// String formatter used by default in [CheckboxMatrix](crate::CheckboxMatrix) prompts.
+    /// Prints each row with checked cells followed by its checked columns, separated by semicolons.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use inquire::CheckboxMatrix;
+    ///
+    /// let formatter = CheckboxMatrix::DEFAULT_FORMATTER;
+    /// assert_eq!(
+    ///     String::from("admin: read, write; viewer: read"),
+    ///     formatter(&[("admin", "read"), ("admin", "write"), ("viewer", "read")]),
+    /// );
+    /// ```
+    pub const DEFAULT_FORMATTER: MatrixFormatter<'a> = &|cells| {
+        let mut rows: Vec<(&str, Vec<&str>)> = vec![];
+
+        for (row, column) in cells {
+            match rows.last_mut() {
+                Some((last_row, columns)) if last_row == row => columns.push(column),
+                _ => rows.push((row, vec![column])),
+            }
+        }
+
+        rows.iter()
+            .map(|(row, columns)| format!("{}: {}", row, columns.join(", ")))
+            .collect::<Vec<String>>()
+            .join("; ")
+    };
+
+    /// Default page size, in rows.
+    pub const DEFAULT_PAGE_SIZE: usize = crate::config::DEFAULT_PAGE_SIZE;
+
+    /// Default help message.
+    pub const DEFAULT_HELP_MESSAGE: Option<&'a str> =
+        Some("↑↓←→ to move, space to toggle, enter to submit");
+
+    /// Creates a [CheckboxMatrix] with the provided message, row and column labels, along with default configuration values.
+    pub fn new<R, C>(message: &'a str, rows: &[R], columns: &[C]) -> Self
+    where
+        R: Display,
+        C: Display,
+    {
+        Self {
+            message,
+            rows: rows.iter().map(R::to_string).collect(),
+            columns: columns.iter().map(C::to_string).collect(),
+            default: None,
+            help_message: Self::DEFAULT_HELP_MESSAGE,
+            page_size: Self::DEFAULT_PAGE_SIZE,
+            formatter: Self::DEFAULT_FORMATTER,
+            render_config: get_configuration(),
+        }
+    }
+
+    /// Sets the cells checked by default, as `(row, column)` index pairs.
+    pub fn with_default(mut self, default: &'a [(usize, usize)]) -> Self {
+        self.default = Some(default.to_vec());
+        self
+    }
+
+    /// Sets the help message of the prompt.
+    pub fn with_help_message(mut self, message: &'a str) -> Self {
+        self.help_message = Some(message);
+        self
+    }
+
+    /// Removes the set help message.
+    pub fn without_help_message(mut self) -> Self {
+        self.help_message = None;
+        self
+    }
+
+    /// Sets the page size, in rows.
+    pub fn with_page_size(mut self, page_size: usize) -> Self {
+        self.page_size = page_size;
+        self
+    }
+
+    /// Sets the formatter.
+    pub fn with_formatter(mut self, formatter: MatrixFormatter<'a>) -> Self {
+        self.formatter = formatter;
+        self
+    }
+
+    /// Sets the provided color theme to this prompt.
+    ///
+    /// Note: The default render config considers if the NO_COLOR environment variable
+    /// is set to decide whether to render the colored config or the empty one.
+    ///
+    /// When overriding the config in a prompt, NO_COLOR is no longer considered and your
+    /// config is treated as the only source of truth. If you want to customize colors
+    /// and still support NO_COLOR, you will have to do this on your end.
+    pub fn with_render_config(mut self, render_config: RenderConfig<'a>) -> Self {
+        self.render_config = render_config;
+        self
+    }
+
+    /// Parses the provided behavioral and rendering options and prompts
+    /// the CLI user for input according to the defined rules.
+    ///
+    /// This method is intended for flows where the user skipping/cancelling
+    /// the prompt - by pressing ESC - is considered normal behavior. In this case,
+    /// it does not return `Err(InquireError::OperationCanceled)`, but `Ok(None)`.
+    ///
+    /// Meanwhile, if the user does submit an answer, the method wraps the return
+    /// type with `Some`.
+    pub fn prompt_skippable(self) -> InquireResult<Option<BTreeSet<(usize, usize)>>> {
+        match self.prompt() {
+            Ok(answer) => Ok(Some(answer)),
+            Err(InquireError::OperationCanceled) => Ok(None),
+            Err(err) => Err(err),
+        }
+    }
+
+    /// Parses the provided behavioral and rendering options and prompts
+    /// the CLI user for input according to the defined rules.
+    ///
+    /// Returns the set of checked cells, as `(row, column)` index pairs.
+    pub fn prompt(self) -> InquireResult<BTreeSet<(usize, usize)>> {
+        let (input_reader, terminal) = get_default_terminal()?;
+        let mut backend = Backend::new(input_reader, terminal, self.render_config)?;
+        self.prompt_with_backend(&mut backend)
+    }
+
+    pub(crate) fn prompt_with_backend<B: CheckboxMatrixBackend>(
+        self,
+        backend: &mut B,
+    ) -> InquireResult<BTreeSet<(usize, usize)>> {
+        CheckboxMatrixPrompt::new(self)?.prompt(backend)
+    }
+}
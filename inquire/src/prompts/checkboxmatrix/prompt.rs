@@ -0,0 +1,176 @@
+use std::collections::BTreeSet;
+
+use crate::{
+    error::InquireResult,
+    formatter::MatrixFormatter,
+    list_option::ListOption,
+    prompts::prompt::{ActionResult, Prompt},
+    ui::CheckboxMatrixBackend,
+    utils::paginate,
+    CheckboxMatrix, InquireError,
+};
+
+use super::{action::CheckboxMatrixPromptAction, config::CheckboxMatrixConfig};
+
+pub struct CheckboxMatrixPrompt<'a> {
+    message: &'a str,
+    config: CheckboxMatrixConfig,
+    rows: Vec<String>,
+    columns: Vec<String>,
+    checked: BTreeSet<(usize, usize)>,
+    help_message: Option<&'a str>,
+    cursor_row: usize,
+    cursor_column: usize,
+    formatter: MatrixFormatter<'a>,
+}
+
+impl<'a> CheckboxMatrixPrompt<'a> {
+    pub fn new(cbm: CheckboxMatrix<'a>) -> InquireResult<Self> {
+        if cbm.rows.is_empty() {
+            return Err(InquireError::InvalidConfiguration(
+                "Rows can not be empty".into(),
+            ));
+        }
+
+        if cbm.columns.is_empty() {
+            return Err(InquireError::InvalidConfiguration(
+                "Columns can not be empty".into(),
+            ));
+        }
+
+        let mut checked = BTreeSet::new();
+
+        if let Some(default) = &cbm.default {
+            for &(row, column) in default {
+                if row >= cbm.rows.len() || column >= cbm.columns.len() {
+                    return Err(InquireError::InvalidConfiguration(format!(
+                        "Default cell ({}, {}) is out-of-bounds for {} rows and {} columns",
+                        row,
+                        column,
+                        cbm.rows.len(),
+                        cbm.columns.len()
+                    )));
+                }
+
+                checked.insert((row, column));
+            }
+        }
+
+        Ok(Self {
+            message: cbm.message,
+            config: (&cbm).into(),
+            rows: cbm.rows,
+            columns: cbm.columns,
+            checked,
+            help_message: cbm.help_message,
+            cursor_row: 0,
+            cursor_column: 0,
+            formatter: cbm.formatter,
+        })
+    }
+
+    fn move_cursor_to(&mut self, row: usize, column: usize) -> ActionResult {
+        if (row, column) != (self.cursor_row, self.cursor_column) {
+            self.cursor_row = row;
+            self.cursor_column = column;
+            ActionResult::NeedsRedraw
+        } else {
+            ActionResult::Clean
+        }
+    }
+
+    fn move_rows_up(&mut self, qty: usize) -> ActionResult {
+        let row = self.cursor_row.saturating_sub(qty);
+        self.move_cursor_to(row, self.cursor_column)
+    }
+
+    fn move_rows_down(&mut self, qty: usize) -> ActionResult {
+        let row = self.cursor_row.saturating_add(qty).min(self.rows.len() - 1);
+        self.move_cursor_to(row, self.cursor_column)
+    }
+
+    fn toggle_current_cell(&mut self) -> ActionResult {
+        let cell = (self.cursor_row, self.cursor_column);
+
+        if !self.checked.remove(&cell) {
+            self.checked.insert(cell);
+        }
+
+        ActionResult::NeedsRedraw
+    }
+}
+
+impl<'a, Backend> Prompt<Backend> for CheckboxMatrixPrompt<'a>
+where
+    Backend: CheckboxMatrixBackend,
+{
+    type Config = CheckboxMatrixConfig;
+    type InnerAction = CheckboxMatrixPromptAction;
+    type Output = BTreeSet<(usize, usize)>;
+
+    fn message(&self) -> &str {
+        self.message
+    }
+
+    fn config(&self) -> &CheckboxMatrixConfig {
+        &self.config
+    }
+
+    fn format_answer(&self, answer: &BTreeSet<(usize, usize)>) -> String {
+        let cells = answer
+            .iter()
+            .map(|&(row, column)| (self.rows[row].as_str(), self.columns[column].as_str()))
+            .collect::<Vec<(&str, &str)>>();
+
+        (self.formatter)(&cells)
+    }
+
+    fn submit(&mut self) -> InquireResult<Option<BTreeSet<(usize, usize)>>> {
+        Ok(Some(std::mem::take(&mut self.checked)))
+    }
+
+    fn handle(&mut self, action: CheckboxMatrixPromptAction) -> InquireResult<ActionResult> {
+        let last_column = self.columns.len() - 1;
+
+        let result = match action {
+            CheckboxMatrixPromptAction::MoveLeft => {
+                self.move_cursor_to(self.cursor_row, self.cursor_column.saturating_sub(1))
+            }
+            CheckboxMatrixPromptAction::MoveRight => {
+                self.move_cursor_to(self.cursor_row, (self.cursor_column + 1).min(last_column))
+            }
+            CheckboxMatrixPromptAction::MoveUp => self.move_rows_up(1),
+            CheckboxMatrixPromptAction::MoveDown => self.move_rows_down(1),
+            CheckboxMatrixPromptAction::PageUp => self.move_rows_up(self.config.page_size),
+            CheckboxMatrixPromptAction::PageDown => self.move_rows_down(self.config.page_size),
+            CheckboxMatrixPromptAction::MoveToStart => self.move_rows_up(usize::MAX),
+            CheckboxMatrixPromptAction::MoveToEnd => self.move_rows_down(usize::MAX),
+            CheckboxMatrixPromptAction::ToggleCurrentCell => self.toggle_current_cell(),
+        };
+
+        Ok(result)
+    }
+
+    fn render(&self, backend: &mut Backend) -> InquireResult<()> {
+        let prompt = &self.message;
+
+        backend.render_checkbox_matrix_prompt(prompt)?;
+
+        let rows = self
+            .rows
+            .iter()
+            .enumerate()
+            .map(|(i, row)| ListOption::new(i, row))
+            .collect::<Vec<ListOption<&String>>>();
+
+        let page = paginate(self.config.page_size, &rows, Some(self.cursor_row));
+
+        backend.render_checkbox_matrix(page, &self.columns, &self.checked, self.cursor_column)?;
+
+        if let Some(help_message) = self.help_message {
+            backend.render_help_message(help_message)?;
+        }
+
+        Ok(())
+    }
+}
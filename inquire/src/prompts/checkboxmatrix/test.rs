@@ -0,0 +1,137 @@
+use std::collections::BTreeSet;
+
+use crate::{
+    error::InquireResult,
+    ui::{
+        test::{FakeBackend, Token},
+        Key, KeyModifiers,
+    },
+    CheckboxMatrix, InquireError,
+};
+
+const ROLES: [&str; 3] = ["admin", "editor", "viewer"];
+const PERMISSIONS: [&str; 3] = ["read", "write", "delete"];
+
+fn cells(cells: &[(usize, usize)]) -> BTreeSet<(usize, usize)> {
+    cells.iter().copied().collect()
+}
+
+#[test]
+fn space_toggles_cell_under_cursor() -> InquireResult<()> {
+    let mut backend = FakeBackend::new(vec![
+        Key::Char(' ', KeyModifiers::NONE),
+        Key::Right(KeyModifiers::NONE),
+        Key::Down(KeyModifiers::NONE),
+        Key::Char(' ', KeyModifiers::NONE),
+        Key::Enter,
+    ]);
+
+    let ans = CheckboxMatrix::new("Permissions", &ROLES, &PERMISSIONS)
+        .prompt_with_backend(&mut backend)?;
+
+    assert_eq!(cells(&[(0, 0), (1, 1)]), ans);
+    assert!(backend.frames()[3].has_token(&Token::Matrix {
+        rows: ROLES.map(String::from).to_vec(),
+        checked: vec![(0, 0)],
+        cursor: Some((1, 1)),
+    }));
+    assert!(backend
+        .frames()
+        .last()
+        .unwrap()
+        .has_token(&Token::AnsweredPrompt(
+            "Permissions".into(),
+            "admin: read; editor: write".into()
+        )));
+
+    Ok(())
+}
+
+#[test]
+fn default_cells_can_be_unchecked() -> InquireResult<()> {
+    let mut backend = FakeBackend::new(vec![
+        Key::Right(KeyModifiers::NONE),
+        Key::Char(' ', KeyModifiers::NONE),
+        Key::Enter,
+    ]);
+
+    let ans = CheckboxMatrix::new("Permissions", &ROLES, &PERMISSIONS)
+        .with_default(&[(0, 0), (0, 1), (2, 0)])
+        .prompt_with_backend(&mut backend)?;
+
+    assert_eq!(cells(&[(0, 0), (2, 0)]), ans);
+
+    Ok(())
+}
+
+#[test]
+fn edges_of_the_matrix_do_not_wrap() -> InquireResult<()> {
+    let mut backend = FakeBackend::new(vec![
+        Key::Left(KeyModifiers::NONE),
+        Key::Up(KeyModifiers::NONE),
+        Key::End,
+        Key::Down(KeyModifiers::NONE),
+        Key::Right(KeyModifiers::NONE),
+        Key::Right(KeyModifiers::NONE),
+        Key::Right(KeyModifiers::NONE),
+        Key::Char(' ', KeyModifiers::NONE),
+        Key::Enter,
+    ]);
+
+    let ans = CheckboxMatrix::new("Permissions", &ROLES, &PERMISSIONS)
+        .prompt_with_backend(&mut backend)?;
+
+    assert_eq!(cells(&[(2, 2)]), ans);
+
+    Ok(())
+}
+
+#[test]
+fn rows_are_paginated() -> InquireResult<()> {
+    let mut backend = FakeBackend::new(vec![
+        Key::PageDown(KeyModifiers::NONE),
+        Key::Char(' ', KeyModifiers::NONE),
+        Key::Enter,
+    ]);
+
+    let ans = CheckboxMatrix::new("Permissions", &ROLES, &PERMISSIONS)
+        .with_page_size(2)
+        .prompt_with_backend(&mut backend)?;
+
+    assert_eq!(cells(&[(2, 0)]), ans);
+    assert!(backend.frames()[1].has_token(&Token::Matrix {
+        rows: vec!["editor".into(), "viewer".into()],
+        checked: vec![],
+        cursor: Some((2, 0)),
+    }));
+
+    Ok(())
+}
+
+#[test]
+fn empty_answer_is_allowed() -> InquireResult<()> {
+    let mut backend = FakeBackend::new(vec![Key::Enter]);
+
+    let ans = CheckboxMatrix::new("Permissions", &ROLES, &PERMISSIONS)
+        .prompt_with_backend(&mut backend)?;
+
+    assert!(ans.is_empty());
+
+    Ok(())
+}
+
+#[test]
+fn invalid_configurations_are_rejected() {
+    let ans = CheckboxMatrix::new::<&str, &str>("Permissions", &[], &PERMISSIONS)
+        .prompt_with_backend(&mut FakeBackend::new(vec![Key::Enter]));
+    assert!(matches!(ans, Err(InquireError::InvalidConfiguration(_))));
+
+    let ans = CheckboxMatrix::new::<&str, &str>("Permissions", &ROLES, &[])
+        .prompt_with_backend(&mut FakeBackend::new(vec![Key::Enter]));
+    assert!(matches!(ans, Err(InquireError::InvalidConfiguration(_))));
+
+    let ans = CheckboxMatrix::new("Permissions", &ROLES, &PERMISSIONS)
+        .with_default(&[(0, 3)])
+        .prompt_with_backend(&mut FakeBackend::new(vec![Key::Enter]));
+    assert!(matches!(ans, Err(InquireError::InvalidConfiguration(_))));
+}
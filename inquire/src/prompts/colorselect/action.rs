@@ -0,0 +1,51 @@
+use crate::{
+    ui::{Key, KeyModifiers},
+    InnerAction, InputAction,
+};
+
+use super::config::ColorSelectConfig;
+
+/// Set of actions for a ColorSelectPrompt.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum ColorSelectPromptAction {
+    /// Action on the hex value text input handler.
+    HexInput(InputAction),
+    /// Moves the cursor to the swatch on the left.
+    MoveLeft,
+    /// Moves the cursor to the swatch on the right.
+    MoveRight,
+    /// Moves the cursor to the swatch above.
+    MoveUp,
+    /// Moves the cursor to the swatch below.
+    MoveDown,
+    /// Moves the cursor to the first swatch of the palette.
+    MoveToStart,
+    /// Moves the cursor to the last swatch of the palette.
+    MoveToEnd,
+}
+
+impl InnerAction for ColorSelectPromptAction {
+    type Config = ColorSelectConfig;
+
+    fn from_key(key: Key, config: &ColorSelectConfig) -> Option<Self> {
+        let action = match key {
+            Key::Left(KeyModifiers::NONE) | Key::Char('b', KeyModifiers::CONTROL) => Self::MoveLeft,
+            Key::Right(KeyModifiers::NONE) | Key::Char('f', KeyModifiers::CONTROL) => {
+                Self::MoveRight
+            }
+            Key::Up(KeyModifiers::NONE) | Key::Char('p', KeyModifiers::CONTROL) => Self::MoveUp,
+            Key::Down(KeyModifiers::NONE) | Key::Char('n', KeyModifiers::CONTROL) => Self::MoveDown,
+            Key::Home => Self::MoveToStart,
+            Key::End => Self::MoveToEnd,
+
+            key if config.hex_input => match InputAction::from_key(key, &()) {
+                Some(action) => Self::HexInput(action),
+                None => return None,
+            },
+
+            _ => return None,
+        };
+
+        Some(action)
+    }
+}
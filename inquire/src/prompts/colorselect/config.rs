@@ -0,0 +1,19 @@
+use crate::ColorSelect;
+
+/// Configuration settings used in the execution of a ColorSelectPrompt.
+#[derive(Copy, Clone, Debug)]
+pub struct ColorSelectConfig {
+    /// Whether the user can type a hexadecimal color.
+    pub hex_input: bool,
+    /// Number of swatches displayed in each row of the palette.
+    pub columns: usize,
+}
+
+impl From<&ColorSelect<'_>> for ColorSelectConfig {
+    fn from(value: &ColorSelect<'_>) -> Self {
+        Self {
+            hex_input: value.hex_input,
+            columns: value.columns,
+        }
+    }
+}
@@ -0,0 +1,243 @@
+mod action;
+mod config;
+mod prompt;
+#[cfg(test)]
+mod test;
+
+pub use action::*;
+
+use crate::{
+    config::get_configuration,
+    error::{InquireError, InquireResult},
+    formatter::ColorFormatter,
+    prompts::prompt::Prompt,
+    terminal::get_default_terminal,
+    ui::{Backend, Color, ColorSelectBackend, RenderConfig},
+};
+
+use self::prompt::ColorSelectPrompt;
+
+/// Prompt that displays a palette of color swatches for the user to pick one from.
+///
+/// The palette is rendered as a grid, where the user moves the cursor with the arrow keys and submits the highlighted color by pressing enter. By default, the palette contains the 16 standard ANSI colors, laid out in two rows of eight.
+///
+/// Optionally, the prompt can also accept a hexadecimal color typed by the user, such as `#ff8800`. When hex input is enabled and the user has typed something, the typed value takes precedence over the highlighted swatch and is returned as a [`Color::Rgb`] on submission. Invalid values are rejected with an error message.
+///
+/// This prompt requires a prompt message and a **non-empty** palette.
+/// - If the palette is empty, the number of columns is zero or the starting cursor is out of bounds, the prompt operation will fail with an `InquireError::InvalidConfiguration` error.
+///
+/// Color selects provide several options of configuration:
+///
+/// - **Prompt message**: Required when creating the prompt.
+/// - **Palette**: Colors displayed to the user, the 16 standard ANSI colors by default.
+/// - **Columns**: Number of swatches displayed in each row, 8 by default.
+/// - **Starting cursor**: Index of the swatch highlighted when the prompt is first rendered, 0 by default.
+/// - **Hex input**: Whether the user can type a hexadecimal color, false by default.
+/// - **Help message**: Message displayed at the line below the prompt.
+/// - **Formatter**: Custom formatter in case you need to pre-process the user input before showing it as the final answer.
+///   - Prints RGB colors in their hexadecimal notation and the name of named colors by default.
+///
+/// Note that terminals without true color support might not render RGB swatches accurately.
+///
+/// # Example
+///
+/// ```no_run
+/// use inquire::{ui::Color, ColorSelect};
+///
+/// let ans = ColorSelect::new("Accent color:")
+///     .with_hex_input(true)
+///     .prompt();
+///
+/// match ans {
+///     Ok(Color::Rgb { r, g, b }) => println!("Using rgb({}, {}, {})", r, g, b),
+///     Ok(color) => println!("Using {:?}", color),
+///     Err(_) => println!("There was an error, please try again"),
+/// }
+/// ```
+#[derive(Clone)]
+pub struct ColorSelect<'a> {
+    /// Message to be presented to the user.
+    pub message: &'a str,
+
+    /// Colors displayed to the user.
+    pub palette: Vec<Color>,
+
+    /// Number of swatches displayed in each row of the palette.
+    pub columns: usize,
+
+    /// Index of the swatch highlighted when the prompt is first rendered.
+    pub starting_cursor: usize,
+
+    /// Whether the user can type a hexadecimal color instead of picking one from the palette.
+    pub hex_input: bool,
+
+    /// Help message to be presented to the user.
+    pub help_message: Option<&'a str>,
+
+    /// Function that formats the selected color and presents it to the user as the final rendering of the prompt.
+    pub formatter: ColorFormatter<'a>,
+
+    /// RenderConfig to apply to the rendered interface.
+    ///
+    /// Note: The default render config considers if the NO_COLOR environment variable
+    /// is set to decide whether to render the colored config or the empty one.
+    ///
+    /// When overriding the config in a prompt, NO_COLOR is no longer considered and your
+    /// config is treated as the only source of truth. If you want to customize colors
+    /// and still support NO_COLOR, you will have to do this on your end.
+    pub render_config: RenderConfig<'a>,
+}
+
+impl<'a> ColorSelect<'a> {
+    /// Default palette, containing the 16 standard ANSI colors in the order of their codes.
+    pub const DEFAULT_PALETTE: &'static [Color] = &[
+        Color::Black,
+        Color::DarkRed,
+        Color::DarkGreen,
+        Color::DarkYellow,
+        Color::DarkBlue,
+        Color::DarkMagenta,
+        Color::DarkCyan,
+        Color::Grey,
+        Color::DarkGrey,
+        Color::LightRed,
+        Color::LightGreen,
+        Color::LightYellow,
+        Color::LightBlue,
+        Color::LightMagenta,
+        Color::LightCyan,
+        Color::White,
+    ];
+
+    /// Default number of swatches displayed in each row of the palette.
+    pub const DEFAULT_COLUMNS: usize = 8;
+
+    /// Default starting cursor index.
+    pub const DEFAULT_STARTING_CURSOR: usize = 0;
+
+    /// Default value of whether the user can type a hexadecimal color.
+    pub const DEFAULT_HEX_INPUT: bool = false;
+
+    /// Default help message.
+    pub const DEFAULT_HELP_MESSAGE: Option<&'a str> = Some("arrows to move, enter to select");
+
+    /// Default formatter, which prints RGB colors in their hexadecimal notation,
+    /// ANSI values by their code and the remaining colors by their name.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use inquire::{ui::Color, ColorSelect};
+    ///
+    /// let formatter = ColorSelect::DEFAULT_FORMATTER;
+    /// assert_eq!(String::from("#ff8800"), formatter(Color::rgb(255, 136, 0)));
+    /// assert_eq!(String::from("ANSI 208"), formatter(Color::AnsiValue(208)));
+    /// assert_eq!(String::from("LightBlue"), formatter(Color::LightBlue));
+    /// ```
+    pub const DEFAULT_FORMATTER: ColorFormatter<'a> = &|color| match color {
+        Color::Rgb { r, g, b } => format!("#{r:02x}{g:02x}{b:02x}"),
+        Color::AnsiValue(value) => format!("ANSI {value}"),
+        color => format!("{color:?}"),
+    };
+
+    /// Creates a [ColorSelect] with the provided message, along with default configuration values.
+    pub fn new(message: &'a str) -> Self {
+        Self {
+            message,
+            palette: Self::DEFAULT_PALETTE.to_vec(),
+            columns: Self::DEFAULT_COLUMNS,
+            starting_cursor: Self::DEFAULT_STARTING_CURSOR,
+            hex_input: Self::DEFAULT_HEX_INPUT,
+            help_message: Self::DEFAULT_HELP_MESSAGE,
+            formatter: Self::DEFAULT_FORMATTER,
+            render_config: get_configuration(),
+        }
+    }
+
+    /// Sets the colors displayed to the user.
+    pub fn with_palette(mut self, palette: Vec<Color>) -> Self {
+        self.palette = palette;
+        self
+    }
+
+    /// Sets the number of swatches displayed in each row of the palette.
+    pub fn with_columns(mut self, columns: usize) -> Self {
+        self.columns = columns;
+        self
+    }
+
+    /// Sets the index of the swatch highlighted when the prompt is first rendered.
+    pub fn with_starting_cursor(mut self, starting_cursor: usize) -> Self {
+        self.starting_cursor = starting_cursor;
+        self
+    }
+
+    /// Sets whether the user can type a hexadecimal color instead of picking one from the palette.
+    pub fn with_hex_input(mut self, hex_input: bool) -> Self {
+        self.hex_input = hex_input;
+        self
+    }
+
+    /// Sets the help message of the prompt.
+    pub fn with_help_message(mut self, message: &'a str) -> Self {
+        self.help_message = Some(message);
+        self
+    }
+
+    /// Removes the set help message.
+    pub fn without_help_message(mut self) -> Self {
+        self.help_message = None;
+        self
+    }
+
+    /// Sets the formatter.
+    pub fn with_formatter(mut self, formatter: ColorFormatter<'a>) -> Self {
+        self.formatter = formatter;
+        self
+    }
+
+    /// Sets the provided color theme to this prompt.
+    ///
+    /// Note: The default render config considers if the NO_COLOR environment variable
+    /// is set to decide whether to render the colored config or the empty one.
+    ///
+    /// When overriding the config in a prompt, NO_COLOR is no longer considered and your
+    /// config is treated as the only source of truth. If you want to customize colors
+    /// and still support NO_COLOR, you will have to do this on your end.
+    pub fn with_render_config(mut self, render_config: RenderConfig<'a>) -> Self {
+        self.render_config = render_config;
+        self
+    }
+
+    /// Parses the provided behavioral and rendering options and prompts
+    /// the CLI user for input according to the defined rules.
+    ///
+    /// This method is intended for flows where the user skipping/cancelling
+    /// the prompt - by pressing ESC - is considered normal behavior. In this case,
+    /// it does not return `Err(InquireError::OperationCanceled)`, but `Ok(None)`.
+    ///
+    /// Meanwhile, if the user does submit an answer, the method wraps the return
+    /// type with `Some`.
+    pub fn prompt_skippable(self) -> InquireResult<Option<Color>> {
+        match self.prompt() {
+            Ok(answer) => Ok(Some(answer)),
+            Err(InquireError::OperationCanceled) => Ok(None),
+            Err(err) => Err(err),
+        }
+    }
+
+    /// Parses the provided behavioral and rendering options and prompts
+    /// the CLI user for input according to the defined rules.
+    pub fn prompt(self) -> InquireResult<Color> {
+        let (input_reader, terminal) = get_default_terminal()?;
+        let mut backend = Backend::new(input_reader, terminal, self.render_config)?;
+        self.prompt_with_backend(&mut backend)
+    }
+
+    pub(crate) fn prompt_with_backend<B: ColorSelectBackend>(
+        self,
+        backend: &mut B,
+    ) -> InquireResult<Color> {
+        ColorSelectPrompt::new(self)?.prompt(backend)
+    }
+}
@@ -0,0 +1,165 @@
+use crate::{
+    error::InquireResult,
+    formatter::ColorFormatter,
+    input::Input,
+    prompts::prompt::{ActionResult, Prompt},
+    ui::{Color, ColorSelectBackend},
+    validator::ErrorMessage,
+    ColorSelect, InquireError,
+};
+
+use super::{action::ColorSelectPromptAction, config::ColorSelectConfig};
+
+pub struct ColorSelectPrompt<'a> {
+    message: &'a str,
+    config: ColorSelectConfig,
+    palette: Vec<Color>,
+    help_message: Option<&'a str>,
+    cursor_index: usize,
+    hex_input: Input,
+    formatter: ColorFormatter<'a>,
+    error: Option<ErrorMessage>,
+}
+
+impl<'a> ColorSelectPrompt<'a> {
+    pub fn new(co: ColorSelect<'a>) -> InquireResult<Self> {
+        if co.palette.is_empty() {
+            return Err(InquireError::InvalidConfiguration(
+                "Palette can not be empty".into(),
+            ));
+        }
+
+        if co.columns == 0 {
+            return Err(InquireError::InvalidConfiguration(
+                "Number of columns must be greater than zero".into(),
+            ));
+        }
+
+        if co.starting_cursor >= co.palette.len() {
+            return Err(InquireError::InvalidConfiguration(format!(
+                "Starting cursor index {} is out-of-bounds for length {} of palette",
+                co.starting_cursor,
+                co.palette.len()
+            )));
+        }
+
+        Ok(Self {
+            message: co.message,
+            config: (&co).into(),
+            palette: co.palette,
+            help_message: co.help_message,
+            cursor_index: co.starting_cursor,
+            hex_input: Input::new().with_placeholder("#rrggbb"),
+            formatter: co.formatter,
+            error: None,
+        })
+    }
+
+    fn update_cursor_position(&mut self, new_position: usize) -> ActionResult {
+        if new_position != self.cursor_index && new_position < self.palette.len() {
+            self.cursor_index = new_position;
+            ActionResult::NeedsRedraw
+        } else {
+            ActionResult::Clean
+        }
+    }
+
+    fn column(&self) -> usize {
+        self.cursor_index % self.config.columns
+    }
+}
+
+impl<'a, Backend> Prompt<Backend> for ColorSelectPrompt<'a>
+where
+    Backend: ColorSelectBackend,
+{
+    type Config = ColorSelectConfig;
+    type InnerAction = ColorSelectPromptAction;
+    type Output = Color;
+
+    fn message(&self) -> &str {
+        self.message
+    }
+
+    fn config(&self) -> &ColorSelectConfig {
+        &self.config
+    }
+
+    fn format_answer(&self, answer: &Color) -> String {
+        (self.formatter)(*answer)
+    }
+
+    fn submit(&mut self) -> InquireResult<Option<Color>> {
+        if self.hex_input.is_empty() {
+            return Ok(Some(self.palette[self.cursor_index]));
+        }
+
+        if let Some(color) = Color::from_hex(self.hex_input.content()) {
+            return Ok(Some(color));
+        }
+
+        self.error = Some(ErrorMessage::Custom(String::from(
+            "Invalid hex color, e.g. #ff8800",
+        )));
+        Ok(None)
+    }
+
+    fn handle(&mut self, action: ColorSelectPromptAction) -> InquireResult<ActionResult> {
+        let columns = self.config.columns;
+
+        let result = match action {
+            ColorSelectPromptAction::MoveLeft if self.column() > 0 => {
+                self.update_cursor_position(self.cursor_index - 1)
+            }
+            ColorSelectPromptAction::MoveRight if self.column() < columns - 1 => {
+                self.update_cursor_position(self.cursor_index + 1)
+            }
+            ColorSelectPromptAction::MoveUp if self.cursor_index >= columns => {
+                self.update_cursor_position(self.cursor_index - columns)
+            }
+            ColorSelectPromptAction::MoveDown => {
+                self.update_cursor_position(self.cursor_index.saturating_add(columns))
+            }
+            ColorSelectPromptAction::MoveLeft
+            | ColorSelectPromptAction::MoveRight
+            | ColorSelectPromptAction::MoveUp => ActionResult::Clean,
+            ColorSelectPromptAction::MoveToStart => self.update_cursor_position(0),
+            ColorSelectPromptAction::MoveToEnd => {
+                self.update_cursor_position(self.palette.len() - 1)
+            }
+            ColorSelectPromptAction::HexInput(input_action) => {
+                let result = self.hex_input.handle(input_action);
+
+                if result.needs_redraw() {
+                    self.error = None;
+                }
+
+                result.into()
+            }
+        };
+
+        Ok(result)
+    }
+
+    fn render(&self, backend: &mut Backend) -> InquireResult<()> {
+        let prompt = &self.message;
+
+        if let Some(err) = &self.error {
+            backend.render_error_message(err)?;
+        }
+
+        let hex_input = match self.config.hex_input {
+            true => Some(&self.hex_input),
+            false => None,
+        };
+
+        backend.render_color_select_prompt(prompt, hex_input)?;
+        backend.render_palette(&self.palette, self.config.columns, self.cursor_index)?;
+
+        if let Some(help_message) = self.help_message {
+            backend.render_help_message(help_message)?;
+        }
+
+        Ok(())
+    }
+}
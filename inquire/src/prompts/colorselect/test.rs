@@ -0,0 +1,163 @@
+use crate::{
+    error::InquireResult,
+    ui::{
+        test::{FakeBackend, Token},
+        Color, Key, KeyModifiers,
+    },
+    validator::ErrorMessage,
+    ColorSelect, InquireError,
+};
+
+#[test]
+fn enter_selects_first_color_by_default() -> InquireResult<()> {
+    let mut backend = FakeBackend::new(vec![Key::Enter]);
+
+    let ans = ColorSelect::new("Question").prompt_with_backend(&mut backend)?;
+
+    assert_eq!(Color::Black, ans);
+    assert!(backend.frames()[0].has_token(&Token::Palette {
+        columns: 8,
+        cursor: 0
+    }));
+    assert!(
+        backend.frames()[1].has_token(&Token::AnsweredPrompt("Question".into(), "Black".into()))
+    );
+
+    Ok(())
+}
+
+#[test]
+fn arrows_navigate_the_grid() -> InquireResult<()> {
+    let mut backend = FakeBackend::new(vec![
+        Key::Right(KeyModifiers::NONE),
+        Key::Right(KeyModifiers::NONE),
+        Key::Down(KeyModifiers::NONE),
+        Key::Left(KeyModifiers::NONE),
+        Key::Enter,
+    ]);
+
+    let ans = ColorSelect::new("Question").prompt_with_backend(&mut backend)?;
+
+    // the cursor moved to index 2, then to the row below
+    // and back one column, landing on index 9
+    assert_eq!(Color::LightRed, ans);
+    assert!(backend.frames()[3].has_token(&Token::Palette {
+        columns: 8,
+        cursor: 10
+    }));
+
+    Ok(())
+}
+
+#[test]
+fn cursor_does_not_wrap_at_the_edges_of_the_grid() -> InquireResult<()> {
+    let mut backend = FakeBackend::new(vec![
+        Key::Left(KeyModifiers::NONE),
+        Key::Up(KeyModifiers::NONE),
+        Key::End,
+        Key::Right(KeyModifiers::NONE),
+        Key::Down(KeyModifiers::NONE),
+        Key::Enter,
+    ]);
+
+    let palette = vec![Color::DarkRed, Color::DarkGreen, Color::DarkBlue];
+
+    let ans = ColorSelect::new("Question")
+        .with_palette(palette)
+        .with_columns(2)
+        .prompt_with_backend(&mut backend)?;
+
+    assert_eq!(Color::DarkBlue, ans);
+    // initial render, the move to the end and the answer
+    assert_eq!(3, backend.frames().len());
+
+    Ok(())
+}
+
+#[test]
+fn typed_hex_value_takes_precedence_over_palette() -> InquireResult<()> {
+    let mut backend = FakeBackend::new(vec![
+        Key::Right(KeyModifiers::NONE),
+        Key::Char('#', KeyModifiers::NONE),
+        Key::Char('f', KeyModifiers::NONE),
+        Key::Char('8', KeyModifiers::NONE),
+        Key::Char('0', KeyModifiers::NONE),
+        Key::Enter,
+    ]);
+
+    let ans = ColorSelect::new("Question")
+        .with_hex_input(true)
+        .prompt_with_backend(&mut backend)?;
+
+    assert_eq!(Color::rgb(255, 136, 0), ans);
+    assert!(backend
+        .frames()
+        .last()
+        .unwrap()
+        .has_token(&Token::AnsweredPrompt("Question".into(), "#ff8800".into())));
+
+    Ok(())
+}
+
+#[test]
+fn invalid_hex_value_shows_error() -> InquireResult<()> {
+    let mut backend = FakeBackend::new(vec![
+        Key::Char('x', KeyModifiers::NONE),
+        Key::Enter,
+        Key::Backspace,
+        Key::Enter,
+    ]);
+
+    let ans = ColorSelect::new("Question")
+        .with_hex_input(true)
+        .prompt_with_backend(&mut backend)?;
+
+    assert_eq!(Color::Black, ans);
+    assert!(
+        backend.frames()[2].has_token(&Token::ErrorMessage(ErrorMessage::Custom(
+            "Invalid hex color, e.g. #ff8800".into()
+        )))
+    );
+    assert!(!backend.frames()[3]
+        .tokens()
+        .iter()
+        .any(|t| matches!(t, Token::ErrorMessage(_))));
+
+    Ok(())
+}
+
+#[test]
+fn characters_are_ignored_without_hex_input() -> InquireResult<()> {
+    let mut backend = FakeBackend::new(vec![Key::Char('f', KeyModifiers::NONE), Key::Enter]);
+
+    let ans = ColorSelect::new("Question").prompt_with_backend(&mut backend)?;
+
+    assert_eq!(Color::Black, ans);
+    assert_eq!(2, backend.frames().len());
+
+    Ok(())
+}
+
+#[test]
+fn invalid_configurations_are_rejected() {
+    let empty = ColorSelect::new("Question")
+        .with_palette(vec![])
+        .prompt_with_backend(&mut FakeBackend::new(vec![Key::Enter]));
+    assert!(matches!(empty, Err(InquireError::InvalidConfiguration(_))));
+
+    let no_columns = ColorSelect::new("Question")
+        .with_columns(0)
+        .prompt_with_backend(&mut FakeBackend::new(vec![Key::Enter]));
+    assert!(matches!(
+        no_columns,
+        Err(InquireError::InvalidConfiguration(_))
+    ));
+
+    let out_of_bounds = ColorSelect::new("Question")
+        .with_starting_cursor(16)
+        .prompt_with_backend(&mut FakeBackend::new(vec![Key::Enter]));
+    assert!(matches!(
+        out_of_bounds,
+        Err(InquireError::InvalidConfiguration(_))
+    ));
+}
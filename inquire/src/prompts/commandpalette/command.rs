@@ -0,0 +1,58 @@
+/// Command listed in a [CommandPalette](crate::CommandPalette) prompt.
+///
+/// Besides its value, a command can be assigned to a category, under which it
+/// is grouped when no filter is typed, and a keyboard shortcut hint, displayed
+/// next to it.
+///
+/// # Example
+///
+/// ```
+/// use inquire::PaletteCommand;
+///
+/// let command = PaletteCommand::new("Commit")
+///     .with_category("Git")
+///     .with_shortcut("ctrl+enter");
+///
+/// assert_eq!(Some("Git"), command.category);
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct PaletteCommand<'a, T> {
+    /// Value of the command, returned when it is selected.
+    pub value: T,
+
+    /// Category under which the command is grouped.
+    pub category: Option<&'a str>,
+
+    /// Keyboard shortcut hint displayed next to the command.
+    pub shortcut: Option<&'a str>,
+}
+
+impl<'a, T> PaletteCommand<'a, T> {
+    /// Creates a [PaletteCommand] with the provided value, without category
+    /// or shortcut.
+    pub fn new(value: T) -> Self {
+        Self {
+            value,
+            category: None,
+            shortcut: None,
+        }
+    }
+
+    /// Sets the category of the command.
+    pub fn with_category(mut self, category: &'a str) -> Self {
+        self.category = Some(category);
+        self
+    }
+
+    /// Sets the keyboard shortcut hint of the command.
+    pub fn with_shortcut(mut self, shortcut: &'a str) -> Self {
+        self.shortcut = Some(shortcut);
+        self
+    }
+}
+
+impl<'a, T> From<T> for PaletteCommand<'a, T> {
+    fn from(value: T) -> Self {
+        Self::new(value)
+    }
+}
@@ -0,0 +1,16 @@
+use crate::CommandPalette;
+
+/// Configuration settings used in the execution of a CommandPalettePrompt.
+#[derive(Copy, Clone, Debug)]
+pub struct CommandPaletteConfig {
+    /// Page size of the list of commands.
+    pub page_size: usize,
+}
+
+impl<T> From<&CommandPalette<'_, T>> for CommandPaletteConfig {
+    fn from(value: &CommandPalette<'_, T>) -> Self {
+        Self {
+            page_size: value.page_size,
+        }
+    }
+}
@@ -0,0 +1,295 @@
+mod action;
+mod command;
+mod config;
+mod prompt;
+#[cfg(test)]
+mod test;
+
+pub use action::*;
+pub use command::PaletteCommand;
+use std::fmt::Display;
+
+use crate::{
+    config::get_configuration,
+    error::{InquireError, InquireResult},
+    formatter::OptionFormatter,
+    list_option::ListOption,
+    prompts::prompt::Prompt,
+    terminal::get_default_terminal,
+    type_aliases::Scorer,
+    ui::{Backend, CommandPaletteBackend, RenderConfig},
+};
+
+use self::prompt::CommandPalettePrompt;
+
+#[cfg(feature = "fuzzy")]
+use fuzzy_matcher::{skim::SkimMatcherV2, FuzzyMatcher};
+#[cfg(feature = "fuzzy")]
+use once_cell::sync::Lazy;
+#[cfg(feature = "fuzzy")]
+static DEFAULT_MATCHER: Lazy<SkimMatcherV2> = Lazy::new(|| SkimMatcherV2::default().ignore_case());
+
+/// Prompt suitable for when you need the user to run one command among many, in the style of the command palettes of code editors.
+///
+/// Each [`PaletteCommand`] can be assigned to a category and a keyboard shortcut hint, displayed next to it. While the filter input is empty, the recently used commands are listed first, followed by the remaining ones grouped by category. Once the user starts typing, the commands are searched by their category and value, ranked by score and listed without groups, with the matched characters highlighted.
+///
+/// Recently used commands keep a score boost when filtering, decreasing from the most recent one, so that the commands the user runs the most are easier to reach.
+///
+/// This prompt requires a prompt message and a **non-empty** `Vec` of commands to be displayed to the user. The command values can be of any type as long as they implement the `Display` trait.
+/// - If the list is empty, the prompt operation will fail with an `InquireError::InvalidConfiguration` error.
+///
+/// Command palette prompts provide several options of configuration:
+///
+/// - **Prompt message**: Required when creating the prompt.
+/// - **Commands list**: Commands displayed to the user. Must be **non-empty**.
+/// - **Recently used commands**: Indexes of the recently used commands, from the most recent one. If any of the indexes is out-of-range of the command list, the prompt will fail with an [`InquireError::InvalidConfiguration`] error.
+/// - **Recent label**: Header of the group of recently used commands, "recently used" by default.
+/// - **Recent boost**: Score bonus of the most recently used command when filtering, 20 by default.
+/// - **Help message**: Message displayed at the line below the prompt.
+/// - **Formatter**: Custom formatter in case you need to pre-process the user input before showing it as the final answer.
+///   - Prints the selected command value by default.
+/// - **Page size**: Number of commands displayed at once, 7 by default.
+/// - **Scorer function**: Function that defines the order of commands and if displayed as all. It receives the value of the command prefixed by its category.
+///
+/// # Example
+///
+/// ```no_run
+/// use inquire::{CommandPalette, PaletteCommand};
+///
+/// let commands = vec![
+///     PaletteCommand::new("Commit").with_category("Git").with_shortcut("ctrl+enter"),
+///     PaletteCommand::new("Push").with_category("Git"),
+///     PaletteCommand::new("Format document").with_shortcut("shift+alt+f"),
+///     PaletteCommand::new("Toggle terminal").with_category("View"),
+/// ];
+///
+/// let ans = CommandPalette::new(">", commands).with_recent(&[1]).prompt();
+///
+/// match ans {
+///     Ok(command) => println!("Running {}", command),
+///     Err(_) => println!("No command was run"),
+/// }
+/// ```
+///
+/// [`InquireError::InvalidConfiguration`]: crate::error::InquireError::InvalidConfiguration
+#[derive(Clone)]
+pub struct CommandPalette<'a, T> {
+    /// Message to be presented to the user.
+    pub message: &'a str,
+
+    /// Commands displayed to the user.
+    pub commands: Vec<PaletteCommand<'a, T>>,
+
+    /// Indexes of the recently used commands, from the most recent one.
+    pub recent: Vec<usize>,
+
+    /// Header of the group of recently used commands.
+    pub recent_label: &'a str,
+
+    /// Score bonus of the most recently used command when filtering.
+    pub recent_boost: i64,
+
+    /// Help message to be presented to the user.
+    pub help_message: Option<&'a str>,
+
+    /// Page size of the commands displayed to the user.
+    pub page_size: usize,
+
+    /// Function called with the current user input to score the provided
+    /// commands.
+    pub scorer: Scorer<'a, T>,
+
+    /// Function that formats the user input and presents it to the user as the final rendering of the prompt.
+    pub formatter: OptionFormatter<'a, T>,
+
+    /// RenderConfig to apply to the rendered interface.
+    ///
+    /// Note: The default render config considers if the NO_COLOR environment variable
+    /// is set to decide whether to render the colored config or the empty one.
+    ///
+    /// When overriding the config in a prompt, NO_COLOR is no longer considered and your
+    /// config is treated as the only source of truth. If you want to customize colors
+    /// and still support NO_COLOR, you will have to do this on your end.
+    pub render_config: RenderConfig<'a>,
+}
+
+impl<'a, T> CommandPalette<'a, T>
+where
+    T: Display,
+{
+    /// String formatter used by default in [CommandPalette](crate::CommandPalette) prompts.
+    /// Simply prints the string value contained in the selected command.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use inquire::list_option::ListOption;
+    /// use inquire::CommandPalette;
+    ///
+    /// let formatter = CommandPalette::<&str>::DEFAULT_FORMATTER;
+    /// assert_eq!(String::from("Commit"), formatter(ListOption::new(0, &"Commit")));
+    /// ```
+    pub const DEFAULT_FORMATTER: OptionFormatter<'a, T> = &|ans| ans.to_string();
+
+    /// Default scoring function, the same as the one used by [Select](crate::Select) prompts.
+    /// The return will be sorted in Descending order, leaving options with None as a score.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use inquire::CommandPalette;
+    ///
+    /// let scorer = CommandPalette::<&str>::DEFAULT_SCORER;
+    /// assert_eq!(None,     scorer("sa", &"New York",      "New York",      0));
+    /// assert_eq!(Some(49), scorer("sa", &"Sacramento",    "Sacramento",    1));
+    /// assert_eq!(Some(35), scorer("sa", &"Kansas",        "Kansas",        2));
+    /// ```
+    #[cfg(feature = "fuzzy")]
+    pub const DEFAULT_SCORER: Scorer<'a, T> =
+        &|input, _option, string_value, _idx| -> Option<i64> {
+            DEFAULT_MATCHER.fuzzy_match(string_value, input)
+        };
+
+    #[cfg(not(feature = "fuzzy"))]
+    pub const DEFAULT_SCORER: Scorer<'a, T> =
+        &|input, _option, string_value, _idx| -> Option<i64> {
+            let filter = input.to_lowercase();
+            match string_value.to_lowercase().contains(&filter) {
+                true => Some(0),
+                false => None,
+            }
+        };
+
+    /// Default header of the group of recently used commands.
+    pub const DEFAULT_RECENT_LABEL: &'a str = "recently used";
+
+    /// Default score bonus of the most recently used command.
+    pub const DEFAULT_RECENT_BOOST: i64 = 20;
+
+    /// Default page size.
+    pub const DEFAULT_PAGE_SIZE: usize = crate::config::DEFAULT_PAGE_SIZE;
+
+    /// Default help message.
+    pub const DEFAULT_HELP_MESSAGE: Option<&'a str> =
+        Some("↑↓ to move, enter to run, type to search");
+
+    /// Creates a [CommandPalette] with the provided message and commands, along with default configuration values.
+    pub fn new(message: &'a str, commands: Vec<PaletteCommand<'a, T>>) -> Self {
+        Self {
+            message,
+            commands,
+            recent: vec![],
+            recent_label: Self::DEFAULT_RECENT_LABEL,
+            recent_boost: Self::DEFAULT_RECENT_BOOST,
+            help_message: Self::DEFAULT_HELP_MESSAGE,
+            page_size: Self::DEFAULT_PAGE_SIZE,
+            scorer: Self::DEFAULT_SCORER,
+            formatter: Self::DEFAULT_FORMATTER,
+            render_config: get_configuration(),
+        }
+    }
+
+    /// Sets the indexes of the recently used commands, from the most recent one.
+    pub fn with_recent(mut self, recent: &[usize]) -> Self {
+        self.recent = recent.to_vec();
+        self
+    }
+
+    /// Sets the header of the group of recently used commands.
+    pub fn with_recent_label(mut self, recent_label: &'a str) -> Self {
+        self.recent_label = recent_label;
+        self
+    }
+
+    /// Sets the score bonus of the most recently used command when filtering.
+    pub fn with_recent_boost(mut self, recent_boost: i64) -> Self {
+        self.recent_boost = recent_boost;
+        self
+    }
+
+    /// Sets the help message of the prompt.
+    pub fn with_help_message(mut self, message: &'a str) -> Self {
+        self.help_message = Some(message);
+        self
+    }
+
+    /// Removes the set help message.
+    pub fn without_help_message(mut self) -> Self {
+        self.help_message = None;
+        self
+    }
+
+    /// Sets the page size.
+    pub fn with_page_size(mut self, page_size: usize) -> Self {
+        self.page_size = page_size;
+        self
+    }
+
+    /// Sets the scoring function.
+    pub fn with_scorer(mut self, scorer: Scorer<'a, T>) -> Self {
+        self.scorer = scorer;
+        self
+    }
+
+    /// Sets the formatter.
+    pub fn with_formatter(mut self, formatter: OptionFormatter<'a, T>) -> Self {
+        self.formatter = formatter;
+        self
+    }
+
+    /// Sets the provided color theme to this prompt.
+    ///
+    /// Note: The default render config considers if the NO_COLOR environment variable
+    /// is set to decide whether to render the colored config or the empty one.
+    ///
+    /// When overriding the config in a prompt, NO_COLOR is no longer considered and your
+    /// config is treated as the only source of truth. If you want to customize colors
+    /// and still support NO_COLOR, you will have to do this on your end.
+    pub fn with_render_config(mut self, render_config: RenderConfig<'a>) -> Self {
+        self.render_config = render_config;
+        self
+    }
+
+    /// Parses the provided behavioral and rendering options and prompts
+    /// the CLI user for input according to the defined rules.
+    ///
+    /// Returns the owned value of the command selected by the user.
+    pub fn prompt(self) -> InquireResult<T> {
+        self.raw_prompt().map(|op| op.value)
+    }
+
+    /// Parses the provided behavioral and rendering options and prompts
+    /// the CLI user for input according to the defined rules.
+    ///
+    /// This method is intended for flows where the user skipping/cancelling
+    /// the prompt - by pressing ESC - is considered normal behavior. In this case,
+    /// it does not return `Err(InquireError::OperationCanceled)`, but `Ok(None)`.
+    ///
+    /// Meanwhile, if the user does submit an answer, the method wraps the return
+    /// type with `Some`.
+    pub fn prompt_skippable(self) -> InquireResult<Option<T>> {
+        match self.prompt() {
+            Ok(answer) => Ok(Some(answer)),
+            Err(InquireError::OperationCanceled) => Ok(None),
+            Err(err) => Err(err),
+        }
+    }
+
+    /// Parses the provided behavioral and rendering options and prompts
+    /// the CLI user for input according to the defined rules.
+    ///
+    /// Returns a [`ListOption`](crate::list_option::ListOption) containing
+    /// the index of the command and its owned value selected by the user.
+    pub fn raw_prompt(self) -> InquireResult<ListOption<T>> {
+        let (input_reader, terminal) = get_default_terminal()?;
+        let mut backend = Backend::new(input_reader, terminal, self.render_config)?;
+        self.prompt_with_backend(&mut backend)
+    }
+
+    pub(crate) fn prompt_with_backend<B: CommandPaletteBackend>(
+        self,
+        backend: &mut B,
+    ) -> InquireResult<ListOption<T>> {
+        CommandPalettePrompt::new(self)?.prompt(backend)
+    }
+}
@@ -0,0 +1,320 @@
+use std::{cmp::Reverse, fmt::Display};
+
+use crate::{
+    error::InquireResult,
+    formatter::OptionFormatter,
+    input::{Input, InputActionResult},
+    list_option::ListOption,
+    prompts::prompt::{ActionResult, Prompt},
+    type_aliases::Scorer,
+    ui::CommandPaletteBackend,
+    utils::paginate,
+    CommandPalette, InquireError, PaletteCommand,
+};
+
+use super::{action::CommandPalettePromptAction, config::CommandPaletteConfig};
+
+/// Header of the group of uncategorized commands, when listed after other groups.
+const OTHER_COMMANDS_HEADER: &str = "other commands";
+
+pub struct CommandPalettePrompt<'a, T> {
+    message: &'a str,
+    config: CommandPaletteConfig,
+    commands: Vec<PaletteCommand<'a, T>>,
+    labels: Vec<String>,
+    search_strings: Vec<String>,
+    recent: Vec<usize>,
+    recent_label: &'a str,
+    recent_boost: i64,
+    help_message: Option<&'a str>,
+    cursor_index: usize,
+    input: Input,
+    // indexes of the listed commands, in display order, and the
+    // group each one of them belongs to
+    rows: Vec<usize>,
+    groups: Vec<Option<&'a str>>,
+    scorer: Scorer<'a, T>,
+    formatter: OptionFormatter<'a, T>,
+}
+
+impl<'a, T> CommandPalettePrompt<'a, T>
+where
+    T: Display,
+{
+    pub fn new(cp: CommandPalette<'a, T>) -> InquireResult<Self> {
+        if cp.commands.is_empty() {
+            return Err(InquireError::InvalidConfiguration(
+                "Available commands can not be empty".into(),
+            ));
+        }
+
+        let mut recent = vec![];
+        for i in cp.recent.iter().copied() {
+            if i >= cp.commands.len() {
+                return Err(InquireError::InvalidConfiguration(format!(
+                    "Index {} is out-of-bounds for length {} of commands",
+                    i,
+                    cp.commands.len()
+                )));
+            }
+
+            if !recent.contains(&i) {
+                recent.push(i);
+            }
+        }
+
+        let labels = cp
+            .commands
+            .iter()
+            .map(|command| command.value.to_string())
+            .collect::<Vec<String>>();
+        let search_strings = cp
+            .commands
+            .iter()
+            .zip(&labels)
+            .map(|(command, label)| match command.category {
+                Some(category) => format!("{category}: {label}"),
+                None => label.clone(),
+            })
+            .collect();
+
+        Ok(Self {
+            message: cp.message,
+            config: (&cp).into(),
+            commands: cp.commands,
+            labels,
+            search_strings,
+            recent,
+            recent_label: cp.recent_label,
+            recent_boost: cp.recent_boost,
+            help_message: cp.help_message,
+            cursor_index: 0,
+            input: Input::new(),
+            rows: vec![],
+            groups: vec![],
+            scorer: cp.scorer,
+            formatter: cp.formatter,
+        })
+    }
+
+    fn move_cursor_up(&mut self, qty: usize, wrap: bool) -> ActionResult {
+        let new_position = if wrap {
+            let after_wrap = qty.saturating_sub(self.cursor_index);
+            self.cursor_index
+                .checked_sub(qty)
+                .unwrap_or_else(|| self.rows.len().saturating_sub(after_wrap))
+        } else {
+            self.cursor_index.saturating_sub(qty)
+        };
+
+        self.update_cursor_position(new_position)
+    }
+
+    fn move_cursor_down(&mut self, qty: usize, wrap: bool) -> ActionResult {
+        let mut new_position = self.cursor_index.saturating_add(qty);
+
+        if new_position >= self.rows.len() {
+            new_position = if self.rows.is_empty() {
+                0
+            } else if wrap {
+                new_position % self.rows.len()
+            } else {
+                self.rows.len().saturating_sub(1)
+            }
+        }
+
+        self.update_cursor_position(new_position)
+    }
+
+    fn update_cursor_position(&mut self, new_position: usize) -> ActionResult {
+        if new_position != self.cursor_index {
+            self.cursor_index = new_position;
+            ActionResult::NeedsRedraw
+        } else {
+            ActionResult::Clean
+        }
+    }
+
+    /// Score bonus of a recently used command, the most recent one getting
+    /// the full boost, decreasing linearly for the older ones.
+    fn recent_bonus(&self, index: usize) -> i64 {
+        let count = self.recent.len() as i64;
+
+        match self.recent.iter().position(|i| *i == index) {
+            Some(rank) => self.recent_boost * (count - rank as i64) / count,
+            None => 0,
+        }
+    }
+
+    /// Lists the recently used commands first, followed by the remaining
+    /// ones grouped by category, in the order the categories first appear.
+    fn group_commands(&mut self) {
+        self.rows = self.recent.clone();
+        self.groups = vec![Some(self.recent_label); self.rows.len()];
+
+        let mut categories = vec![];
+        for command in &self.commands {
+            if command.category.is_some() && !categories.contains(&command.category) {
+                categories.push(command.category);
+            }
+        }
+        categories.push(None);
+
+        for category in categories {
+            let group = (0..self.commands.len())
+                .filter(|i| !self.recent.contains(i) && self.commands[*i].category == category)
+                .collect::<Vec<usize>>();
+
+            // uncategorized commands only get a header when listed after other groups
+            let header = match category {
+                None if self.rows.is_empty() => None,
+                None => Some(OTHER_COMMANDS_HEADER),
+                category => category,
+            };
+
+            self.groups
+                .extend(std::iter::repeat(header).take(group.len()));
+            self.rows.extend(group);
+        }
+    }
+
+    fn run_scorer(&mut self) {
+        let content = self.input.content();
+
+        if content.is_empty() {
+            self.group_commands();
+        } else {
+            let mut options = self
+                .commands
+                .iter()
+                .enumerate()
+                .filter_map(|(i, command)| {
+                    (self.scorer)(content, &command.value, &self.search_strings[i], i)
+                        .map(|score| (i, score.saturating_add(self.recent_bonus(i))))
+                })
+                .collect::<Vec<(usize, i64)>>();
+
+            // stable sort, so that commands with the same score keep their original order
+            options.sort_by_key(|(_idx, score)| Reverse(*score));
+
+            self.rows = options.iter().map(|(idx, _)| *idx).collect();
+            self.groups = vec![None; self.rows.len()];
+        }
+
+        let _ = self.update_cursor_position(0);
+    }
+}
+
+impl<'a, Backend, T> Prompt<Backend> for CommandPalettePrompt<'a, T>
+where
+    Backend: CommandPaletteBackend,
+    T: Display,
+{
+    type Config = CommandPaletteConfig;
+    type InnerAction = CommandPalettePromptAction;
+    type Output = ListOption<T>;
+
+    fn message(&self) -> &str {
+        self.message
+    }
+
+    fn config(&self) -> &CommandPaletteConfig {
+        &self.config
+    }
+
+    fn format_answer(&self, answer: &ListOption<T>) -> String {
+        (self.formatter)(answer.as_ref())
+    }
+
+    fn setup(&mut self) -> InquireResult<()> {
+        self.run_scorer();
+        Ok(())
+    }
+
+    fn submit(&mut self) -> InquireResult<Option<ListOption<T>>> {
+        let answer = match self.rows.get(self.cursor_index) {
+            Some(index) => {
+                let index = *index;
+                let command = self.commands.swap_remove(index);
+                Some(ListOption::new(index, command.value))
+            }
+            None => None,
+        };
+
+        Ok(answer)
+    }
+
+    fn handle(&mut self, action: CommandPalettePromptAction) -> InquireResult<ActionResult> {
+        let page_size = self.config.page_size;
+
+        let result = match action {
+            CommandPalettePromptAction::MoveUp => self.move_cursor_up(1, true),
+            CommandPalettePromptAction::MoveDown => self.move_cursor_down(1, true),
+            CommandPalettePromptAction::PageUp => self.move_cursor_up(page_size, false),
+            CommandPalettePromptAction::PageDown => self.move_cursor_down(page_size, false),
+            CommandPalettePromptAction::MoveToStart => self.move_cursor_up(usize::MAX, false),
+            CommandPalettePromptAction::MoveToEnd => self.move_cursor_down(usize::MAX, false),
+            CommandPalettePromptAction::FilterInput(input_action) => {
+                let result = self.input.handle(input_action);
+
+                if let InputActionResult::ContentChanged = result {
+                    self.run_scorer();
+                }
+
+                result.into()
+            }
+        };
+
+        Ok(result)
+    }
+
+    fn render(&self, backend: &mut Backend) -> InquireResult<()> {
+        let prompt = &self.message;
+
+        backend.render_command_palette_prompt(prompt, &self.input)?;
+
+        // the category is part of the displayed command while filtering,
+        // as the commands are no longer grouped
+        let filtering = !self.input.is_empty();
+        let displayed = match filtering {
+            true => &self.search_strings,
+            false => &self.labels,
+        };
+
+        let choices = self
+            .rows
+            .iter()
+            .map(|i| ListOption::new(*i, displayed[*i].as_str()))
+            .collect::<Vec<ListOption<&str>>>();
+
+        let page = paginate(self.config.page_size, &choices, Some(self.cursor_index));
+
+        let mut headers = vec![];
+        let mut shortcuts = vec![];
+        let mut matched_chars = vec![];
+
+        for (idx, option) in page.content.iter().enumerate() {
+            let row = self.rows.iter().position(|i| *i == option.index).unwrap();
+
+            // the header is repeated at the top of the page when a group
+            // is split between pages
+            let starts_group = idx == 0 || self.groups[row] != self.groups[row - 1];
+            headers.push(self.groups[row].filter(|_| starts_group));
+            shortcuts.push(self.commands[option.index].shortcut);
+            matched_chars.push(match filtering {
+                true => {
+                    crate::prompts::fuzzyfinder::matched_chars(self.input.content(), option.value)
+                }
+                false => vec![],
+            });
+        }
+
+        backend.render_commands(page, &headers, &shortcuts, &matched_chars)?;
+
+        if let Some(help_message) = self.help_message {
+            backend.render_help_message(help_message)?;
+        }
+
+        Ok(())
+    }
+}
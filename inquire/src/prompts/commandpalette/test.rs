@@ -0,0 +1,131 @@
+use crate::{
+    error::InquireResult,
+    list_option::ListOption,
+    ui::{
+        test::{FakeBackend, Token},
+        Key, KeyModifiers,
+    },
+    CommandPalette, InquireError, PaletteCommand,
+};
+
+fn commands() -> Vec<PaletteCommand<'static, &'static str>> {
+    vec![
+        PaletteCommand::new("Commit")
+            .with_category("Git")
+            .with_shortcut("ctrl+enter"),
+        PaletteCommand::new("Format document"),
+        PaletteCommand::new("Toggle terminal").with_category("View"),
+        PaletteCommand::new("Push").with_category("Git"),
+    ]
+}
+
+fn find_commands(backend: &FakeBackend, frame: usize) -> Token {
+    backend.frames()[frame]
+        .tokens()
+        .iter()
+        .find(|token| matches!(token, Token::Commands { .. }))
+        .cloned()
+        .unwrap()
+}
+
+#[test]
+fn commands_are_grouped_by_category_after_recent_ones() -> InquireResult<()> {
+    let mut backend = FakeBackend::new(vec![Key::Enter]);
+
+    let ans = CommandPalette::new(">", commands())
+        .with_recent(&[2])
+        .prompt_with_backend(&mut backend)?;
+
+    assert_eq!(ListOption::new(2, "Toggle terminal"), ans);
+    assert_eq!(
+        Token::Commands {
+            options: vec![
+                "Toggle terminal".into(),
+                "Commit".into(),
+                "Push".into(),
+                "Format document".into(),
+            ],
+            headers: vec![
+                Some("recently used".into()),
+                Some("Git".into()),
+                None,
+                Some("other commands".into()),
+            ],
+            shortcuts: vec![None, Some("ctrl+enter".into()), None, None],
+            matched_chars: vec![vec![], vec![], vec![], vec![]],
+            cursor: Some(0),
+        },
+        find_commands(&backend, 0)
+    );
+
+    Ok(())
+}
+
+#[test]
+fn uncategorized_commands_have_no_header_when_listed_first() -> InquireResult<()> {
+    let mut backend = FakeBackend::new(vec![Key::Enter]);
+
+    let commands = vec![PaletteCommand::new("Save"), PaletteCommand::new("Quit")];
+    let ans = CommandPalette::new(">", commands).prompt_with_backend(&mut backend)?;
+
+    assert_eq!(ListOption::new(0, "Save"), ans);
+    assert!(matches!(
+        find_commands(&backend, 0),
+        Token::Commands { headers, .. } if headers == vec![None, None]
+    ));
+
+    Ok(())
+}
+
+#[test]
+fn filtering_searches_categories_and_highlights_matches() -> InquireResult<()> {
+    let mut input = Key::char_keys_from_str("gitpu");
+    input.push(Key::Enter);
+    let mut backend = FakeBackend::new(input);
+
+    let ans = CommandPalette::new(">", commands()).prompt_with_backend(&mut backend)?;
+
+    assert_eq!(ListOption::new(3, "Push"), ans);
+    assert_eq!(
+        Token::Commands {
+            options: vec!["Git: Push".into()],
+            headers: vec![None],
+            shortcuts: vec![None],
+            matched_chars: vec![vec![0, 1, 2, 5, 6]],
+            cursor: Some(0),
+        },
+        find_commands(&backend, 5)
+    );
+
+    Ok(())
+}
+
+#[test]
+fn recent_commands_are_boosted_when_filtering() -> InquireResult<()> {
+    let mut input = Key::char_keys_from_str("git");
+    input.push(Key::Enter);
+    let mut backend = FakeBackend::new(input.clone());
+
+    let ans = CommandPalette::new(">", commands()).prompt_with_backend(&mut backend)?;
+    assert_eq!(ListOption::new(0, "Commit"), ans);
+
+    let mut backend = FakeBackend::new(input);
+
+    let ans = CommandPalette::new(">", commands())
+        .with_recent(&[3])
+        .prompt_with_backend(&mut backend)?;
+    assert_eq!(ListOption::new(3, "Push"), ans);
+
+    Ok(())
+}
+
+#[test]
+fn out_of_bounds_recent_command_is_invalid_configuration() {
+    let mut backend = FakeBackend::new(vec![Key::Down(KeyModifiers::NONE), Key::Enter]);
+
+    let ans = CommandPalette::new(">", commands())
+        .with_recent(&[4])
+        .prompt_with_backend(&mut backend);
+
+    assert!(matches!(ans, Err(InquireError::InvalidConfiguration(_))));
+}
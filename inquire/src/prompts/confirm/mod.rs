@@ -1,19 +1,25 @@
 mod action;
+mod prompt;
 #[cfg(test)]
 mod test;
 
 pub use action::*;
 
+use std::time::Duration;
+
 use crate::{
     config::get_configuration,
     error::{InquireError, InquireResult},
     formatter::{BoolFormatter, DEFAULT_BOOL_FORMATTER},
     parser::{BoolParser, DEFAULT_BOOL_PARSER},
+    prompts::prompt::Prompt,
     terminal::get_default_terminal,
     ui::{Backend, CustomTypeBackend, RenderConfig},
-    CustomType,
+    CustomType, InputKeymap, UndoKeys,
 };
 
+use self::prompt::ConfirmCountdownPrompt;
+
 /// Prompt to ask the user for simple yes/no questions, commonly known by asking the user displaying the `(y/n)` text.
 ///
 /// This prompt is basically a wrapper around the behavior of `CustomType` prompts, providing a sensible set of defaults to ask for simple `true/false` questions, such as confirming an action.
@@ -39,6 +45,8 @@ use crate::{
 ///   - By default, displays "y/n" with the default value capitalized, e.g. "y/N".
 /// - **Error message**: Error message to display when a value could not be parsed from the input.
 ///   - Set to "Invalid answer, try typing 'y' for yes or 'n' for no" by default.
+/// - **Countdown**: Time after which the default value is submitted automatically, useful for scripts that might run unattended. The remaining time is displayed below the prompt, and any key pressed by the user stops the countdown. Requires a default value, otherwise the prompt fails with an `InquireError::InvalidConfiguration` error.
+///   - The countdown relies on reading keys with a timeout, which is supported by the `crossterm` and `termion` backends. On the `console` backend, the prompt fails with an `InquireError::InvalidConfiguration` error.
 ///
 /// # Example
 ///
@@ -91,6 +99,10 @@ pub struct Confirm<'a> {
     /// Error message displayed when a value could not be parsed from input.
     pub error_message: String,
 
+    /// Time after which the default value is submitted automatically,
+    /// unless the user interacts with the prompt before.
+    pub countdown: Option<Duration>,
+
     /// RenderConfig to apply to the rendered interface.
     ///
     /// Note: The default render config considers if the NO_COLOR environment variable
@@ -132,6 +144,7 @@ impl<'a> Confirm<'a> {
             parser: Self::DEFAULT_PARSER,
             default_value_formatter: Self::DEFAULT_DEFAULT_VALUE_FORMATTER,
             error_message: String::from(Self::DEFAULT_ERROR_MESSAGE),
+            countdown: None,
             render_config: get_configuration(),
         }
     }
@@ -188,6 +201,14 @@ impl<'a> Confirm<'a> {
         self
     }
 
+    /// Sets the time after which the default value is submitted automatically.
+    ///
+    /// Any key pressed by the user stops the countdown. Requires a default value.
+    pub fn with_countdown(mut self, countdown: Duration) -> Self {
+        self.countdown = Some(countdown);
+        self
+    }
+
     /// Sets the provided color theme to this prompt.
     ///
     /// Note: The default render config considers if the NO_COLOR environment variable
@@ -230,7 +251,10 @@ impl<'a> Confirm<'a> {
         self,
         backend: &mut B,
     ) -> InquireResult<bool> {
-        CustomType::from(self).prompt_with_backend(backend)
+        match self.countdown {
+            Some(countdown) => ConfirmCountdownPrompt::new(self, countdown)?.prompt(backend),
+            None => CustomType::from(self).prompt_with_backend(backend),
+        }
     }
 }
 
@@ -253,6 +277,9 @@ impl<'a> From<Confirm<'a>> for CustomType<'a, bool> {
             parser: co.parser,
             validators: vec![],
             error_message: co.error_message,
+            undo_keys: UndoKeys::default(),
+            keymap: InputKeymap::default(),
+            on_change: None,
             render_config: co.render_config,
         }
     }
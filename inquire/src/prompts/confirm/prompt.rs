@@ -0,0 +1,128 @@
+use std::time::{Duration, Instant};
+
+use crate::{
+    error::InquireResult,
+    formatter::BoolFormatter,
+    prompts::{
+        custom_type::{config::CustomTypeConfig, prompt::CustomTypePrompt},
+        prompt::{ActionResult, Prompt},
+    },
+    ui::CustomTypeBackend,
+    Action, Confirm, CustomType, InquireError,
+};
+
+use super::action::ConfirmPromptAction;
+
+/// Confirm prompt that submits its default value automatically when the
+/// countdown expires, unless the user interacts with it before.
+pub struct ConfirmCountdownPrompt<'a> {
+    inner: CustomTypePrompt<'a, bool>,
+    default: bool,
+    formatter: BoolFormatter<'a>,
+    countdown: Duration,
+    deadline: Option<Instant>,
+}
+
+impl<'a> ConfirmCountdownPrompt<'a> {
+    pub fn new(co: Confirm<'a>, countdown: Duration) -> InquireResult<Self> {
+        let default = match co.default {
+            Some(default) => default,
+            None => {
+                return Err(InquireError::InvalidConfiguration(
+                    "A default value is required when a countdown is set".into(),
+                ))
+            }
+        };
+
+        let formatter = co.formatter;
+
+        Ok(Self {
+            inner: CustomType::from(co).into(),
+            default,
+            formatter,
+            countdown,
+            deadline: None,
+        })
+    }
+
+    fn remaining(&self) -> Option<Duration> {
+        self.deadline
+            .map(|deadline| deadline.saturating_duration_since(Instant::now()))
+    }
+}
+
+impl<'a, Backend> Prompt<Backend> for ConfirmCountdownPrompt<'a>
+where
+    Backend: CustomTypeBackend,
+{
+    type Config = CustomTypeConfig;
+    type InnerAction = ConfirmPromptAction;
+    type Output = bool;
+
+    fn message(&self) -> &str {
+        Prompt::<Backend>::message(&self.inner)
+    }
+
+    fn config(&self) -> &CustomTypeConfig {
+        Prompt::<Backend>::config(&self.inner)
+    }
+
+    fn format_answer(&self, answer: &bool) -> String {
+        Prompt::<Backend>::format_answer(&self.inner, answer)
+    }
+
+    fn setup(&mut self) -> InquireResult<()> {
+        self.deadline = Some(Instant::now() + self.countdown);
+        Prompt::<Backend>::setup(&mut self.inner)
+    }
+
+    fn tick_interval(&self) -> Option<Duration> {
+        // wake up whenever the displayed number of seconds changes
+        self.remaining().map(|remaining| {
+            match Duration::from_nanos(u64::from(remaining.subsec_nanos())) {
+                Duration::ZERO if remaining > Duration::ZERO => Duration::from_secs(1),
+                interval => interval,
+            }
+        })
+    }
+
+    fn tick(&mut self) -> InquireResult<Option<Action<ConfirmPromptAction>>> {
+        match self.remaining() {
+            Some(Duration::ZERO) => {
+                self.deadline = None;
+                Ok(Some(Action::Submit))
+            }
+            _ => Ok(None),
+        }
+    }
+
+    fn submit(&mut self) -> InquireResult<Option<bool>> {
+        Prompt::<Backend>::submit(&mut self.inner)
+    }
+
+    fn handle(&mut self, action: ConfirmPromptAction) -> InquireResult<ActionResult> {
+        // any interaction stops the countdown, leaving the answer to the user
+        let result = match self.deadline.take() {
+            Some(_) => ActionResult::NeedsRedraw,
+            None => ActionResult::Clean,
+        };
+
+        Ok(result.merge(Prompt::<Backend>::handle(&mut self.inner, action)?))
+    }
+
+    fn render(&self, backend: &mut Backend) -> InquireResult<()> {
+        self.inner.render(backend)?;
+
+        if let Some(remaining) = self.remaining() {
+            let seconds = remaining.as_secs() + u64::from(remaining.subsec_nanos() > 0);
+            let message = format!(
+                "Defaulting to {} in {}s",
+                (self.formatter)(self.default),
+                seconds
+            );
+            backend.render_help_message(&message)?;
+        }
+
+        Ok(())
+    }
+}
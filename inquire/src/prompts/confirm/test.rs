@@ -1,4 +1,4 @@
-use std::vec;
+use std::{time::Duration, vec};
 
 use rstest::rstest;
 
@@ -548,3 +548,62 @@ fn ctrl_c_interrupts_prompt() -> InquireResult<()> {
 
     Ok(())
 }
+
+#[test]
+fn countdown_submits_default_when_it_expires() -> InquireResult<()> {
+    let mut backend = FakeBackend::new(vec![]);
+
+    let result = Confirm::new("Question")
+        .with_default(true)
+        .with_countdown(Duration::from_millis(50))
+        .prompt_with_backend(&mut backend)?;
+
+    assert!(result, "Countdown did not submit the default value");
+    assert!(backend.frames()[0].has_token(&Token::HelpMessage("Defaulting to Yes in 1s".into())));
+
+    Ok(())
+}
+
+#[test]
+fn countdown_expires_while_waiting_for_keys() -> InquireResult<()> {
+    let mut backend = crate::prompts::test::fake_backend_with_timeouts(vec![]);
+
+    let result = Confirm::new("Question")
+        .with_default(false)
+        .with_countdown(Duration::from_millis(50))
+        .prompt_with_backend(&mut backend)?;
+
+    assert!(!result, "Countdown did not submit the default value");
+
+    Ok(())
+}
+
+#[test]
+fn key_press_stops_countdown() -> InquireResult<()> {
+    let mut backend = FakeBackend::new(vec![Key::Char('n', KeyModifiers::NONE), Key::Enter]);
+
+    let result = Confirm::new("Question")
+        .with_default(true)
+        .with_countdown(Duration::from_secs(60))
+        .prompt_with_backend(&mut backend)?;
+
+    assert!(!result, "Answer was not the expected one");
+    assert!(backend.frames()[0].has_token(&Token::HelpMessage("Defaulting to Yes in 60s".into())));
+    assert!(!backend.frames()[1]
+        .tokens()
+        .iter()
+        .any(|token| matches!(token, Token::HelpMessage(_))));
+
+    Ok(())
+}
+
+#[test]
+fn countdown_requires_default_value() {
+    let mut backend = FakeBackend::new(vec![Key::Enter]);
+
+    let result = Confirm::new("Question")
+        .with_countdown(Duration::from_secs(10))
+        .prompt_with_backend(&mut backend);
+
+    assert!(matches!(result, Err(InquireError::InvalidConfiguration(_))));
+}
@@ -0,0 +1,23 @@
+use crate::{ui::Key, InnerAction, InputAction};
+
+use super::config::CurrencyConfig;
+
+/// Set of actions for a CurrencyPrompt.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum CurrencyPromptAction {
+    /// Action on the value text input handler.
+    ValueInput(InputAction),
+}
+
+impl InnerAction for CurrencyPromptAction {
+    type Config = CurrencyConfig;
+
+    fn from_key(key: Key, _config: &CurrencyConfig) -> Option<Self> {
+        let action = match InputAction::from_key(key, &()) {
+            Some(action) => Self::ValueInput(action),
+            None => return None,
+        };
+
+        Some(action)
+    }
+}
@@ -0,0 +1,191 @@
+use std::fmt::Display;
+
+/// Maximum number of decimal places of an [`Amount`], so that the scale
+/// factor fits in an `i64`.
+pub(super) const MAX_PRECISION: u32 = 18;
+
+/// Fixed-point amount returned by [`Currency`](crate::Currency) prompts.
+///
+/// The amount is stored as an integer number of minor units, e.g. cents,
+/// along with the number of decimal places, so that no rounding error is
+/// introduced between what the user typed and what is returned.
+///
+/// # Examples
+///
+/// ```
+/// use inquire::Amount;
+///
+/// let amount = Amount::new(-123450, 2);
+///
+/// assert_eq!(-123450, amount.minor_units());
+/// assert_eq!("-1234.50", amount.to_string());
+/// ```
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Amount {
+    minor_units: i64,
+    precision: u32,
+}
+
+impl Amount {
+    /// Creates an amount from its number of minor units and decimal places,
+    /// e.g. `Amount::new(1999, 2)` is `19.99`.
+    pub fn new(minor_units: i64, precision: u32) -> Self {
+        Self {
+            minor_units,
+            precision,
+        }
+    }
+
+    /// Returns the amount as an integer number of minor units, e.g. cents.
+    pub fn minor_units(&self) -> i64 {
+        self.minor_units
+    }
+
+    /// Returns the number of decimal places of the amount.
+    pub fn precision(&self) -> u32 {
+        self.precision
+    }
+
+    fn scale(&self) -> u64 {
+        10u64.pow(self.precision)
+    }
+}
+
+impl Display for Amount {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let sign = if self.minor_units < 0 { "-" } else { "" };
+        let units = self.minor_units.unsigned_abs();
+        let scale = self.scale();
+
+        write!(f, "{}{}", sign, units / scale)?;
+
+        if self.precision > 0 {
+            write!(
+                f,
+                ".{:0width$}",
+                units % scale,
+                width = self.precision as usize
+            )?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Rules used to read and display amounts in a [`Currency`](crate::Currency) prompt.
+#[derive(Copy, Clone)]
+pub(super) struct AmountFormat<'a> {
+    pub symbol: &'a str,
+    pub precision: u32,
+    pub thousands_separator: char,
+    pub decimal_separator: char,
+    pub allow_negative: bool,
+}
+
+impl<'a> AmountFormat<'a> {
+    /// Keeps only the characters of the input that make up the amount: a
+    /// leading minus sign, when allowed, the digits and the first decimal
+    /// separator, dropping everything else, like thousands separators.
+    pub fn sanitize(&self, input: &str) -> String {
+        let mut sanitized = String::new();
+        let mut has_separator = false;
+
+        for c in input.chars() {
+            let leading_minus = c == '-' && self.allow_negative && sanitized.is_empty();
+
+            if c.is_ascii_digit() || leading_minus {
+                sanitized.push(c);
+            } else if c == self.decimal_separator && !has_separator && self.precision > 0 {
+                sanitized.push(c);
+                has_separator = true;
+            }
+        }
+
+        sanitized
+    }
+
+    /// Number of decimal places of a sanitized input.
+    pub fn decimal_places(&self, sanitized: &str) -> usize {
+        match sanitized.split_once(self.decimal_separator) {
+            Some((_, decimals)) => decimals.len(),
+            None => 0,
+        }
+    }
+
+    /// Inserts the thousands separators in the integer part of a sanitized input.
+    pub fn group(&self, sanitized: &str) -> String {
+        let (sign, unsigned) = match sanitized.strip_prefix('-') {
+            Some(unsigned) => ("-", unsigned),
+            None => ("", sanitized),
+        };
+
+        let (integer, decimals) = match unsigned.find(self.decimal_separator) {
+            Some(idx) => unsigned.split_at(idx),
+            None => (unsigned, ""),
+        };
+
+        let mut grouped = String::from(sign);
+
+        for (idx, digit) in integer.chars().enumerate() {
+            if idx > 0 && (integer.len() - idx) % 3 == 0 {
+                grouped.push(self.thousands_separator);
+            }
+            grouped.push(digit);
+        }
+
+        grouped.push_str(decimals);
+        grouped
+    }
+
+    /// Parses the input, returning `None` when it is incomplete, e.g. `-`,
+    /// has more decimal places than allowed or does not fit in an [`Amount`].
+    pub fn parse(&self, input: &str) -> Option<Amount> {
+        let sanitized = self.sanitize(input);
+
+        let (negative, unsigned) = match sanitized.strip_prefix('-') {
+            Some(unsigned) => (true, unsigned),
+            None => (false, sanitized.as_str()),
+        };
+
+        let (integer, decimals) = match unsigned.split_once(self.decimal_separator) {
+            Some((integer, decimals)) => (integer, decimals),
+            None => (unsigned, ""),
+        };
+
+        if integer.is_empty() && decimals.is_empty() {
+            return None;
+        }
+
+        if decimals.len() > self.precision as usize {
+            return None;
+        }
+
+        let mut units: i64 = 0;
+
+        for digit in integer.chars().chain(decimals.chars()) {
+            let digit = i64::from(digit.to_digit(10)?);
+            units = units.checked_mul(10)?.checked_add(digit)?;
+        }
+
+        for _ in decimals.len()..self.precision as usize {
+            units = units.checked_mul(10)?;
+        }
+
+        let units = if negative { -units } else { units };
+
+        Some(Amount::new(units, self.precision))
+    }
+
+    /// Displays the amount with the currency symbol and separators, e.g. `-$1,234.50`.
+    pub fn display(&self, amount: Amount) -> String {
+        let sanitized = amount
+            .to_string()
+            .replace('.', &self.decimal_separator.to_string());
+        let grouped = self.group(&sanitized);
+
+        match grouped.strip_prefix('-') {
+            Some(unsigned) => format!("-{}{}", self.symbol, unsigned),
+            None => format!("{}{}", self.symbol, grouped),
+        }
+    }
+}
@@ -0,0 +1,10 @@
+use crate::Currency;
+
+/// Configuration settings used in the execution of a CurrencyPrompt.
+pub struct CurrencyConfig {}
+
+impl From<&Currency<'_>> for CurrencyConfig {
+    fn from(_value: &Currency<'_>) -> Self {
+        Self {}
+    }
+}
@@ -0,0 +1,309 @@
+mod action;
+mod amount;
+mod config;
+mod prompt;
+#[cfg(test)]
+mod test;
+
+pub use action::*;
+pub use amount::Amount;
+
+use crate::{
+    config::get_configuration,
+    error::{InquireError, InquireResult},
+    formatter::CustomTypeFormatter,
+    prompts::prompt::Prompt,
+    terminal::get_default_terminal,
+    ui::{Backend, CustomTypeBackend, RenderConfig},
+    validator::CustomTypeValidator,
+};
+
+use self::prompt::CurrencyPrompt;
+
+/// Prompt suitable for amounts of money, such as prices, budgets and transfers.
+///
+/// It behaves like a [`CustomType`](crate::CustomType) prompt, with the difference that the input is formatted while the user types it: the currency symbol is displayed before the amount and thousands separators are inserted automatically, e.g. `$1,234,567.89`. Characters that are not part of an amount are ignored, as are decimal places beyond the configured precision.
+///
+/// The prompt returns an [`Amount`], a fixed-point value stored as an integer number of minor units, e.g. cents, instead of a floating point number, avoiding rounding errors.
+///
+/// Currency prompts provide several options of configuration:
+///
+/// - **Prompt message**: Required when creating the prompt.
+/// - **Starting input**: Initial value of the prompt's text input.
+/// - **Default value**: Default value returned when the user submits an empty response. Must have the same precision as the prompt.
+/// - **Symbol**: Currency symbol displayed before the amount, `$` by default.
+/// - **Precision**: Number of decimal places, 2 by default and at most 18.
+/// - **Separators**: Thousands and decimal separators, `,` and `.` by default.
+/// - **Allow negative**: Whether negative amounts are accepted, `false` by default.
+/// - **Placeholder**: Short hint that describes the expected value of the input.
+/// - **Help message**: Message displayed at the line below the prompt.
+/// - **Formatter**: Custom formatter in case you need to pre-process the user input before showing it as the final answer.
+///   - By default, the amount is displayed with the currency symbol and separators.
+/// - **Validators**: Custom validators to the parsed amount, displaying an error message if it does not pass the requirements.
+/// - **Error message**: Error message to display when the input is not a complete amount.
+///
+/// # Example
+///
+/// ```no_run
+/// use inquire::{validator::Validation, Currency};
+///
+/// let ans = Currency::new("Budget:")
+///     .with_symbol("€")
+///     .with_separators('.', ',')
+///     .with_validator(|amount: &inquire::Amount| {
+///         if amount.minor_units() > 0 {
+///             Ok(Validation::Valid)
+///         } else {
+///             Ok(Validation::Invalid("The budget must be positive".into()))
+///         }
+///     })
+///     .prompt();
+///
+/// match ans {
+///     Ok(amount) => println!("Budget of {} cents", amount.minor_units()),
+///     Err(_) => println!("Could not read the budget"),
+/// }
+/// ```
+#[derive(Clone)]
+pub struct Currency<'a> {
+    /// Message to be presented to the user.
+    pub message: &'a str,
+
+    /// Initial value of the prompt's text input.
+    ///
+    /// If you want to set a default value for the prompt, returned when the user's submission is empty, see [`default`].
+    ///
+    /// [`default`]: Self::default
+    pub starting_input: Option<&'a str>,
+
+    /// Default value, returned when the user input is empty.
+    pub default: Option<Amount>,
+
+    /// Currency symbol displayed before the amount.
+    pub symbol: &'a str,
+
+    /// Number of decimal places of the amount.
+    pub precision: u32,
+
+    /// Separator inserted between each group of three digits of the integer part.
+    pub thousands_separator: char,
+
+    /// Separator between the integer and decimal parts.
+    pub decimal_separator: char,
+
+    /// Whether negative amounts are accepted.
+    pub allow_negative: bool,
+
+    /// Short hint that describes the expected value of the input.
+    pub placeholder: Option<&'a str>,
+
+    /// Help message to be presented to the user.
+    pub help_message: Option<&'a str>,
+
+    /// Function that formats the user input and presents it to the user as the final rendering of the prompt.
+    ///
+    /// When `None`, the amount is displayed with the currency symbol and separators.
+    pub formatter: Option<CustomTypeFormatter<'a, Amount>>,
+
+    /// Collection of validators to apply to the parsed amount.
+    ///
+    /// Validators are executed in the order they are stored, stopping at and displaying to the user
+    /// only the first validation error that might appear.
+    ///
+    /// The possible error is displayed to the user one line above the prompt.
+    pub validators: Vec<Box<dyn CustomTypeValidator<Amount>>>,
+
+    /// Error message displayed when the input is not a complete amount.
+    pub error_message: String,
+
+    /// RenderConfig to apply to the rendered interface.
+    ///
+    /// Note: The default render config considers if the NO_COLOR environment variable
+    /// is set to decide whether to render the colored config or the empty one.
+    ///
+    /// When overriding the config in a prompt, NO_COLOR is no longer considered and your
+    /// config is treated as the only source of truth. If you want to customize colors
+    /// and still support NO_COLOR, you will have to do this on your end.
+    pub render_config: RenderConfig<'a>,
+}
+
+impl<'a> Currency<'a> {
+    /// Default currency symbol.
+    pub const DEFAULT_SYMBOL: &'a str = "$";
+
+    /// Default number of decimal places.
+    pub const DEFAULT_PRECISION: u32 = 2;
+
+    /// Default thousands separator.
+    pub const DEFAULT_THOUSANDS_SEPARATOR: char = ',';
+
+    /// Default decimal separator.
+    pub const DEFAULT_DECIMAL_SEPARATOR: char = '.';
+
+    /// Default validators added to the [Currency] prompt, none.
+    pub const DEFAULT_VALIDATORS: Vec<Box<dyn CustomTypeValidator<Amount>>> = vec![];
+
+    /// Default error message displayed when parsing fails.
+    pub const DEFAULT_ERROR_MESSAGE: &'a str = "Please type a valid amount";
+
+    /// Creates a [Currency] with the provided message and default configuration values.
+    pub fn new(message: &'a str) -> Self {
+        Self {
+            message,
+            starting_input: None,
+            default: None,
+            symbol: Self::DEFAULT_SYMBOL,
+            precision: Self::DEFAULT_PRECISION,
+            thousands_separator: Self::DEFAULT_THOUSANDS_SEPARATOR,
+            decimal_separator: Self::DEFAULT_DECIMAL_SEPARATOR,
+            allow_negative: false,
+            placeholder: None,
+            help_message: None,
+            formatter: None,
+            validators: Self::DEFAULT_VALIDATORS,
+            error_message: String::from(Self::DEFAULT_ERROR_MESSAGE),
+            render_config: get_configuration(),
+        }
+    }
+
+    /// Sets the initial value of the prompt's text input.
+    ///
+    /// If you want to set a default value for the prompt, returned when the user's submission is empty, see [`with_default`].
+    ///
+    /// [`with_default`]: Self::with_default
+    pub fn with_starting_input(mut self, message: &'a str) -> Self {
+        self.starting_input = Some(message);
+        self
+    }
+
+    /// Sets the default input.
+    pub fn with_default(mut self, default: Amount) -> Self {
+        self.default = Some(default);
+        self
+    }
+
+    /// Sets the currency symbol.
+    pub fn with_symbol(mut self, symbol: &'a str) -> Self {
+        self.symbol = symbol;
+        self
+    }
+
+    /// Sets the number of decimal places.
+    pub fn with_precision(mut self, precision: u32) -> Self {
+        self.precision = precision;
+        self
+    }
+
+    /// Sets the thousands and decimal separators, e.g. `('.', ',')` for `1.234,50`.
+    pub fn with_separators(mut self, thousands_separator: char, decimal_separator: char) -> Self {
+        self.thousands_separator = thousands_separator;
+        self.decimal_separator = decimal_separator;
+        self
+    }
+
+    /// Sets whether negative amounts are accepted.
+    pub fn with_allow_negative(mut self, allow_negative: bool) -> Self {
+        self.allow_negative = allow_negative;
+        self
+    }
+
+    /// Sets the placeholder.
+    pub fn with_placeholder(mut self, placeholder: &'a str) -> Self {
+        self.placeholder = Some(placeholder);
+        self
+    }
+
+    /// Sets the help message of the prompt.
+    pub fn with_help_message(mut self, message: &'a str) -> Self {
+        self.help_message = Some(message);
+        self
+    }
+
+    /// Sets the formatter.
+    pub fn with_formatter(mut self, formatter: CustomTypeFormatter<'a, Amount>) -> Self {
+        self.formatter = Some(formatter);
+        self
+    }
+
+    /// Adds a validator to the collection of validators. You might want to use this feature
+    /// in case you need to require certain features from the parsed user's answer.
+    ///
+    /// Validators are executed in the order they are stored, stopping at and displaying to the user
+    /// only the first validation error that might appear.
+    ///
+    /// The possible error is displayed to the user one line above the prompt.
+    pub fn with_validator<V>(mut self, validator: V) -> Self
+    where
+        V: CustomTypeValidator<Amount> + 'static,
+    {
+        self.validators.push(Box::new(validator));
+        self
+    }
+
+    /// Adds the validators to the collection of validators in the order they are given.
+    /// You might want to use this feature in case you need to require certain features
+    /// from the parsed user's answer.
+    ///
+    /// Validators are executed in the order they are stored, stopping at and displaying to the user
+    /// only the first validation error that might appear.
+    ///
+    /// The possible error is displayed to the user one line above the prompt.
+    pub fn with_validators(mut self, validators: &[Box<dyn CustomTypeValidator<Amount>>]) -> Self {
+        for validator in validators {
+            #[allow(suspicious_double_ref_op)]
+            self.validators.push(validator.clone());
+        }
+        self
+    }
+
+    /// Sets a custom error message displayed when the input is not a complete amount.
+    pub fn with_error_message(mut self, error_message: &'a str) -> Self {
+        self.error_message = String::from(error_message);
+        self
+    }
+
+    /// Sets the provided color theme to this prompt.
+    ///
+    /// Note: The default render config considers if the NO_COLOR environment variable
+    /// is set to decide whether to render the colored config or the empty one.
+    ///
+    /// When overriding the config in a prompt, NO_COLOR is no longer considered and your
+    /// config is treated as the only source of truth. If you want to customize colors
+    /// and still support NO_COLOR, you will have to do this on your end.
+    pub fn with_render_config(mut self, render_config: RenderConfig<'a>) -> Self {
+        self.render_config = render_config;
+        self
+    }
+
+    /// Parses the provided behavioral and rendering options and prompts
+    /// the CLI user for input according to the defined rules.
+    ///
+    /// This method is intended for flows where the user skipping/cancelling
+    /// the prompt - by pressing ESC - is considered normal behavior. In this case,
+    /// it does not return `Err(InquireError::OperationCanceled)`, but `Ok(None)`.
+    ///
+    /// Meanwhile, if the user does submit an answer, the method wraps the return
+    /// type with `Some`.
+    pub fn prompt_skippable(self) -> InquireResult<Option<Amount>> {
+        match self.prompt() {
+            Ok(answer) => Ok(Some(answer)),
+            Err(InquireError::OperationCanceled) => Ok(None),
+            Err(err) => Err(err),
+        }
+    }
+
+    /// Parses the provided behavioral and rendering options and prompts
+    /// the CLI user for input according to the defined rules.
+    pub fn prompt(self) -> InquireResult<Amount> {
+        let (input_reader, terminal) = get_default_terminal()?;
+        let mut backend = Backend::new(input_reader, terminal, self.render_config)?;
+        self.prompt_with_backend(&mut backend)
+    }
+
+    pub(crate) fn prompt_with_backend<B: CustomTypeBackend>(
+        self,
+        backend: &mut B,
+    ) -> InquireResult<Amount> {
+        CurrencyPrompt::new(self)?.prompt(backend)
+    }
+}
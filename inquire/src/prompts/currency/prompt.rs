@@ -0,0 +1,267 @@
+use unicode_segmentation::UnicodeSegmentation;
+
+use crate::{
+    error::InquireResult,
+    formatter::CustomTypeFormatter,
+    input::{Input, InputActionResult},
+    prompts::prompt::{ActionResult, Prompt},
+    ui::CustomTypeBackend,
+    validator::{CustomTypeValidator, ErrorMessage, Validation},
+    Currency, InquireError,
+};
+
+use super::{
+    action::CurrencyPromptAction,
+    amount::{Amount, AmountFormat, MAX_PRECISION},
+    config::CurrencyConfig,
+};
+
+pub struct CurrencyPrompt<'a> {
+    message: &'a str,
+    config: CurrencyConfig,
+    error: Option<ErrorMessage>,
+    help_message: Option<&'a str>,
+    default: Option<Amount>,
+    format: AmountFormat<'a>,
+    placeholder: Option<&'a str>,
+    input: Input,
+    formatter: Option<CustomTypeFormatter<'a, Amount>>,
+    validators: Vec<Box<dyn CustomTypeValidator<Amount>>>,
+    error_message: String,
+}
+
+impl<'a> CurrencyPrompt<'a> {
+    pub fn new(co: Currency<'a>) -> InquireResult<Self> {
+        if co.precision > MAX_PRECISION {
+            return Err(InquireError::InvalidConfiguration(format!(
+                "Precision can not be greater than {}",
+                MAX_PRECISION
+            )));
+        }
+
+        if co.thousands_separator == co.decimal_separator {
+            return Err(InquireError::InvalidConfiguration(
+                "Thousands and decimal separators must be different".into(),
+            ));
+        }
+
+        if let Some(default) = co.default {
+            if default.precision() != co.precision {
+                return Err(InquireError::InvalidConfiguration(
+                    "Default value must have the same precision as the prompt".into(),
+                ));
+            }
+
+            if default.minor_units() < 0 && !co.allow_negative {
+                return Err(InquireError::InvalidConfiguration(
+                    "Default value can not be negative".into(),
+                ));
+            }
+        }
+
+        let format = AmountFormat {
+            symbol: co.symbol,
+            precision: co.precision,
+            thousands_separator: co.thousands_separator,
+            decimal_separator: co.decimal_separator,
+            allow_negative: co.allow_negative,
+        };
+
+        let starting_input = format.group(&format.sanitize(co.starting_input.unwrap_or_default()));
+        let input = Input::new_with(starting_input);
+        let input = if let Some(placeholder) = co.placeholder {
+            input.with_placeholder(placeholder)
+        } else {
+            input
+        };
+
+        Ok(Self {
+            message: co.message,
+            config: (&co).into(),
+            error: None,
+            help_message: co.help_message,
+            default: co.default,
+            format,
+            placeholder: co.placeholder,
+            input,
+            formatter: co.formatter,
+            validators: co.validators,
+            error_message: co.error_message,
+        })
+    }
+
+    /// Formats the input after its content changed, keeping the cursor after
+    /// the same digit it was before. Changes that would exceed the precision
+    /// are discarded, restoring the previous input.
+    fn reformat_input(&mut self, previous: Input) -> ActionResult {
+        let sanitized = self.format.sanitize(self.input.content());
+
+        if self.format.decimal_places(&sanitized) > self.format.precision as usize {
+            self.input = previous;
+            return ActionResult::Clean;
+        }
+
+        let kept_before_cursor = self
+            .format
+            .sanitize(self.input.pre_cursor())
+            .chars()
+            .count();
+        let grouped = self.format.group(&sanitized);
+        let separator = self.format.thousands_separator.to_string();
+
+        let mut cursor = 0;
+        let mut kept = 0;
+        for grapheme in grouped.graphemes(true) {
+            if kept == kept_before_cursor {
+                break;
+            }
+            if grapheme != separator {
+                kept += 1;
+            }
+            cursor += 1;
+        }
+
+        let input = Input::new_with(grouped).with_cursor(cursor);
+        let input = if let Some(placeholder) = self.placeholder {
+            input.with_placeholder(placeholder)
+        } else {
+            input
+        };
+
+        let result = if input == previous {
+            ActionResult::Clean
+        } else {
+            ActionResult::NeedsRedraw
+        };
+
+        self.input = input;
+        result
+    }
+
+    /// Input displayed to the user, with the currency symbol before the amount.
+    fn display_input(&self) -> Input {
+        let content = self.input.content();
+
+        if content.is_empty() {
+            return self.input.clone();
+        }
+
+        let symbol_len = self.format.symbol.graphemes(true).count();
+        let cursor = self.input.cursor();
+
+        let (display, cursor) = match content.strip_prefix('-') {
+            Some(unsigned) if cursor == 0 => (format!("-{}{}", self.format.symbol, unsigned), 0),
+            Some(unsigned) => (
+                format!("-{}{}", self.format.symbol, unsigned),
+                cursor + symbol_len,
+            ),
+            None => (
+                format!("{}{}", self.format.symbol, content),
+                cursor + symbol_len,
+            ),
+        };
+
+        Input::new_with(display).with_cursor(cursor)
+    }
+
+    fn validate_current_answer(&self, value: &Amount) -> InquireResult<Validation> {
+        for validator in &self.validators {
+            match validator.validate(value) {
+                Ok(Validation::Valid) => {}
+                Ok(Validation::Invalid(msg)) => return Ok(Validation::Invalid(msg)),
+                Err(err) => return Err(InquireError::Custom(err)),
+            }
+        }
+
+        Ok(Validation::Valid)
+    }
+
+    fn get_final_answer(&self) -> Result<Amount, String> {
+        match self.default {
+            Some(val) if self.input.content().is_empty() => return Ok(val),
+            _ => {}
+        }
+
+        match self.format.parse(self.input.content()) {
+            Some(val) => Ok(val),
+            None => Err(self.error_message.clone()),
+        }
+    }
+}
+
+impl<'a, Backend> Prompt<Backend> for CurrencyPrompt<'a>
+where
+    Backend: CustomTypeBackend,
+{
+    type Config = CurrencyConfig;
+    type InnerAction = CurrencyPromptAction;
+    type Output = Amount;
+
+    fn message(&self) -> &str {
+        self.message
+    }
+
+    fn config(&self) -> &CurrencyConfig {
+        &self.config
+    }
+
+    fn format_answer(&self, answer: &Amount) -> String {
+        match self.formatter {
+            Some(formatter) => formatter(*answer),
+            None => self.format.display(*answer),
+        }
+    }
+
+    fn submit(&mut self) -> InquireResult<Option<Amount>> {
+        let answer = match self.get_final_answer() {
+            Ok(answer) => match self.validate_current_answer(&answer)? {
+                Validation::Valid => Some(answer),
+                Validation::Invalid(msg) => {
+                    self.error = Some(msg);
+                    None
+                }
+            },
+            Err(message) => {
+                self.error = Some(message.into());
+                None
+            }
+        };
+
+        Ok(answer)
+    }
+
+    fn handle(&mut self, action: CurrencyPromptAction) -> InquireResult<ActionResult> {
+        let result = match action {
+            CurrencyPromptAction::ValueInput(input_action) => {
+                let previous = self.input.clone();
+                let result = self.input.handle(input_action);
+
+                if let InputActionResult::ContentChanged = result {
+                    self.reformat_input(previous)
+                } else {
+                    result.into()
+                }
+            }
+        };
+
+        Ok(result)
+    }
+
+    fn render(&self, backend: &mut Backend) -> InquireResult<()> {
+        let prompt = &self.message;
+
+        if let Some(error) = &self.error {
+            backend.render_error_message(error)?;
+        }
+
+        let default_message = self.default.map(|default| self.format.display(default));
+
+        backend.render_prompt(prompt, default_message.as_deref(), &self.display_input())?;
+
+        if let Some(message) = self.help_message {
+            backend.render_help_message(message)?;
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,132 @@
+use crate::{
+    error::InquireResult,
+    input::Input,
+    ui::{
+        test::{FakeBackend, Token},
+        Key, KeyModifiers,
+    },
+    validator::{ErrorMessage, Validation},
+    Amount, Currency,
+};
+
+#[test]
+fn amount_is_formatted_while_typing() -> InquireResult<()> {
+    let mut input = Key::char_keys_from_str("1234567.89");
+    input.push(Key::Enter);
+    let mut backend = FakeBackend::new(input);
+
+    let ans = Currency::new("Price").prompt_with_backend(&mut backend)?;
+
+    assert_eq!(Amount::new(123456789, 2), ans);
+    assert!(backend.frames()[4].has_token(&Token::Input(Input::new_with("$1,234"))));
+    assert!(backend
+        .frames()
+        .last()
+        .unwrap()
+        .has_token(&Token::AnsweredPrompt(
+            "Price".into(),
+            "$1,234,567.89".into()
+        )));
+
+    Ok(())
+}
+
+#[test]
+fn extra_decimals_and_invalid_characters_are_ignored() -> InquireResult<()> {
+    let mut input = Key::char_keys_from_str("1a2.3.456");
+    input.push(Key::Enter);
+    let mut backend = FakeBackend::new(input);
+
+    let ans = Currency::new("Price").prompt_with_backend(&mut backend)?;
+
+    assert_eq!(Amount::new(1234, 2), ans);
+    assert_eq!("12.34", ans.to_string());
+
+    Ok(())
+}
+
+#[test]
+fn cursor_stays_after_the_same_digit() -> InquireResult<()> {
+    let mut input = Key::char_keys_from_str("123");
+    input.push(Key::Left(KeyModifiers::NONE));
+    input.push(Key::Left(KeyModifiers::NONE));
+    input.extend(Key::char_keys_from_str("0"));
+    input.push(Key::Enter);
+    let mut backend = FakeBackend::new(input);
+
+    let ans = Currency::new("Price")
+        .with_precision(0)
+        .prompt_with_backend(&mut backend)?;
+
+    assert_eq!(Amount::new(1023, 0), ans);
+    assert!(backend.frames()[6].has_token(&Token::Input(Input::new_with("$1,023").with_cursor(4))));
+
+    Ok(())
+}
+
+#[test]
+fn custom_symbol_separators_and_negative_amounts() -> InquireResult<()> {
+    let mut input = Key::char_keys_from_str("-9876,5");
+    input.push(Key::Enter);
+    let mut backend = FakeBackend::new(input);
+
+    let ans = Currency::new("Transfer")
+        .with_symbol("€")
+        .with_separators('.', ',')
+        .with_allow_negative(true)
+        .prompt_with_backend(&mut backend)?;
+
+    assert_eq!(Amount::new(-987650, 2), ans);
+    assert!(backend
+        .frames()
+        .last()
+        .unwrap()
+        .has_token(&Token::AnsweredPrompt(
+            "Transfer".into(),
+            "-€9.876,50".into()
+        )));
+
+    Ok(())
+}
+
+#[test]
+fn incomplete_amount_is_rejected() -> InquireResult<()> {
+    let mut input = Key::char_keys_from_str(".");
+    input.push(Key::Enter);
+    input.extend(Key::char_keys_from_str("5"));
+    input.push(Key::Enter);
+    let mut backend = FakeBackend::new(input);
+
+    let ans = Currency::new("Tip")
+        .with_validator(|amount: &Amount| {
+            if amount.minor_units() <= 5000 {
+                Ok(Validation::Valid)
+            } else {
+                Ok(Validation::Invalid("Too generous".into()))
+            }
+        })
+        .prompt_with_backend(&mut backend)?;
+
+    assert_eq!(Amount::new(50, 2), ans);
+    assert!(
+        backend.frames()[2].has_token(&Token::ErrorMessage(ErrorMessage::Custom(
+            "Please type a valid amount".into()
+        )))
+    );
+
+    Ok(())
+}
+
+#[test]
+fn empty_input_submits_default() -> InquireResult<()> {
+    let mut backend = FakeBackend::new(vec![Key::Enter]);
+
+    let ans = Currency::new("Price")
+        .with_default(Amount::new(100000, 2))
+        .prompt_with_backend(&mut backend)?;
+
+    assert_eq!(Amount::new(100000, 2), ans);
+    assert!(backend.frames()[0].has_token(&Token::DefaultValue("$1,000.00".into())));
+
+    Ok(())
+}
@@ -7,15 +7,27 @@ use super::config::CustomTypeConfig;
 pub enum CustomTypePromptAction {
     /// Action on the value text input handler.
     ValueInput(InputAction),
+    /// Reverts the last edit made to the input.
+    Undo,
+    /// Reapplies the last edit reverted.
+    Redo,
 }
 
 impl InnerAction for CustomTypePromptAction {
     type Config = CustomTypeConfig;
 
-    fn from_key(key: Key, _config: &CustomTypeConfig) -> Option<Self> {
-        let action = match InputAction::from_key(key, &()) {
-            Some(action) => Self::ValueInput(action),
-            None => return None,
+    fn from_key(key: Key, config: &CustomTypeConfig) -> Option<Self> {
+        if let Some(action) = config.keymap.yank_action(key) {
+            return Some(Self::ValueInput(action));
+        }
+
+        let action = match key {
+            key if key == config.undo_keys.undo => Self::Undo,
+            key if key == config.undo_keys.redo => Self::Redo,
+            key => match config.keymap.action(key) {
+                Some(action) => Self::ValueInput(action),
+                None => return None,
+            },
         };
 
         Some(action)
@@ -1,10 +1,18 @@
-use crate::CustomType;
+use crate::{CustomType, InputKeymap, UndoKeys};
 
 /// Configuration settings used in the execution of a CustomTypePrompt.
-pub struct CustomTypeConfig {}
+pub struct CustomTypeConfig {
+    /// Key bindings to undo and redo the edits made to the input.
+    pub undo_keys: UndoKeys,
+    /// Preset of key bindings used to edit the input.
+    pub keymap: InputKeymap,
+}
 
 impl<T> From<&CustomType<'_, T>> for CustomTypeConfig {
-    fn from(_value: &CustomType<'_, T>) -> Self {
-        Self {}
+    fn from(value: &CustomType<'_, T>) -> Self {
+        Self {
+            undo_keys: value.undo_keys,
+            keymap: value.keymap,
+        }
     }
 }
@@ -1,6 +1,6 @@
 mod action;
-mod config;
-mod prompt;
+pub(super) mod config;
+pub(super) mod prompt;
 
 pub use action::*;
 
@@ -13,8 +13,10 @@ use crate::{
     parser::CustomTypeParser,
     prompts::prompt::Prompt,
     terminal::get_default_terminal,
+    type_aliases::OnChange,
     ui::{Backend, CustomTypeBackend, RenderConfig},
     validator::CustomTypeValidator,
+    InputKeymap, UndoKeys,
 };
 
 use self::prompt::CustomTypePrompt;
@@ -38,7 +40,7 @@ use self::prompt::CustomTypePrompt;
 /// If your type `T` does not satisfy these constraints, you can always manually instantiate the entire struct yourself like this:
 ///
 /// ```no_run
-/// use inquire::{CustomType, ui::RenderConfig};
+/// use inquire::{CustomType, InputKeymap, UndoKeys, ui::RenderConfig};
 ///
 /// let amount_prompt: CustomType<f64> = CustomType {
 ///     message: "How much is your travel going to cost?",
@@ -49,11 +51,14 @@ use self::prompt::CustomTypePrompt;
 ///     validators: vec![],
 ///     placeholder: Some("123.45"),
 ///     error_message: "Please type a valid number.".into(),
+///     on_change: None,
 ///     help_message: "Do not use currency and the number should use dots as the decimal separator.".into(),
 ///     parser: &|i| match i.parse::<f64>() {
 ///         Ok(val) => Ok(val),
 ///         Err(_) => Err(()),
 ///     },
+///     undo_keys: UndoKeys::default(),
+///     keymap: InputKeymap::default(),
 ///     render_config: RenderConfig::default(),
 /// };
 /// ```
@@ -117,6 +122,15 @@ pub struct CustomType<'a, T> {
     /// Error message displayed when value could not be parsed from input.
     pub error_message: String,
 
+    /// Function called with the current input whenever it changes.
+    pub on_change: Option<OnChange<'a>>,
+
+    /// Key bindings to undo and redo the edits made to the input.
+    pub undo_keys: UndoKeys,
+
+    /// Preset of key bindings used to edit the input, in addition to the default ones.
+    pub keymap: InputKeymap,
+
     /// RenderConfig to apply to the rendered interface.
     ///
     /// Note: The default render config considers if the NO_COLOR environment variable
@@ -151,6 +165,9 @@ where
             parser: &|a| a.parse::<T>().map_err(|_e| ()),
             validators: Self::DEFAULT_VALIDATORS,
             error_message: "Invalid input".into(),
+            on_change: None,
+            undo_keys: UndoKeys::default(),
+            keymap: InputKeymap::default(),
             render_config: get_configuration(),
         }
     }
@@ -241,6 +258,26 @@ where
         self
     }
 
+    /// Sets the function called with the current input whenever it changes,
+    /// e.g. to display a live preview of the parsed value.
+    pub fn with_on_change(mut self, on_change: OnChange<'a>) -> Self {
+        self.on_change = Some(on_change);
+        self
+    }
+
+    /// Sets the key bindings to undo and redo the edits made to the input.
+    pub fn with_undo_keys(mut self, undo_keys: UndoKeys) -> Self {
+        self.undo_keys = undo_keys;
+        self
+    }
+
+    /// Sets the preset of key bindings used to edit the input, such as
+    /// [`InputKeymap::Readline`].
+    pub fn with_keymap(mut self, keymap: InputKeymap) -> Self {
+        self.keymap = keymap;
+        self
+    }
+
     /// Sets the provided color theme to this prompt.
     ///
     /// Note: The default render config considers if the NO_COLOR environment variable
@@ -1,9 +1,10 @@
 use crate::{
     error::InquireResult,
     formatter::CustomTypeFormatter,
-    input::Input,
+    input::{clean_paste, EditHistory, Input},
     parser::CustomTypeParser,
     prompts::prompt::{ActionResult, Prompt},
+    type_aliases::OnChange,
     ui::CustomTypeBackend,
     validator::{CustomTypeValidator, ErrorMessage, Validation},
     CustomType, InquireError,
@@ -18,11 +19,13 @@ pub struct CustomTypePrompt<'a, T> {
     help_message: Option<&'a str>,
     default: Option<T>,
     input: Input,
+    edits: EditHistory,
     formatter: CustomTypeFormatter<'a, T>,
     default_value_formatter: CustomTypeFormatter<'a, T>,
     validators: Vec<Box<dyn CustomTypeValidator<T>>>,
     parser: CustomTypeParser<'a, T>,
     error_message: String,
+    on_change: Option<OnChange<'a>>,
 }
 
 impl<'a, T> From<CustomType<'a, T>> for CustomTypePrompt<'a, T>
@@ -48,7 +51,9 @@ where
             validators: co.validators,
             parser: co.parser,
             input,
+            edits: EditHistory::default(),
             error_message: co.error_message,
+            on_change: co.on_change,
         }
     }
 }
@@ -80,6 +85,16 @@ where
             Err(_) => Err(self.error_message.clone()),
         }
     }
+
+    /// Calls the on change hook if the input differs from its previous
+    /// content, which is only recorded when there is a hook.
+    fn notify_change(&self, previous: Option<String>) {
+        if let (Some(on_change), Some(previous)) = (self.on_change, previous) {
+            if previous != self.input.content() {
+                on_change(self.input.content());
+            }
+        }
+    }
 }
 
 impl<'a, Backend, T> Prompt<Backend> for CustomTypePrompt<'a, T>
@@ -103,6 +118,14 @@ where
         (self.formatter)((*answer).clone())
     }
 
+    fn handle_paste(&mut self, text: &str) -> InquireResult<ActionResult> {
+        let previous = self.on_change.map(|_| self.input.content().to_owned());
+        let result = self.edits.paste(&mut self.input, &clean_paste(text, " "));
+        self.notify_change(previous);
+
+        Ok(result.into())
+    }
+
     fn submit(&mut self) -> InquireResult<Option<T>> {
         let answer = match self.get_final_answer() {
             Ok(answer) => match self.validate_current_answer(&answer)? {
@@ -122,12 +145,24 @@ where
     }
 
     fn handle(&mut self, action: CustomTypePromptAction) -> InquireResult<ActionResult> {
+        let previous = self.on_change.map(|_| self.input.content().to_owned());
+
         let result = match action {
             CustomTypePromptAction::ValueInput(input_action) => {
-                self.input.handle(input_action).into()
+                self.edits.handle(&mut self.input, input_action).into()
             }
+            CustomTypePromptAction::Undo => match self.edits.undo(&mut self.input) {
+                true => ActionResult::NeedsRedraw,
+                false => ActionResult::Clean,
+            },
+            CustomTypePromptAction::Redo => match self.edits.redo(&mut self.input) {
+                true => ActionResult::NeedsRedraw,
+                false => ActionResult::Clean,
+            },
         };
 
+        self.notify_change(previous);
+
         Ok(result)
     }
 
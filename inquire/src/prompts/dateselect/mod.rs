@@ -7,6 +7,9 @@ mod test;
 
 pub use action::*;
 
+pub(crate) use config::DateSelectConfig;
+pub(crate) use prompt::shift_date;
+
 use chrono::NaiveDate;
 
 use crate::{
@@ -55,29 +55,6 @@ impl<'a> DateSelectPrompt<'a> {
         })
     }
 
-    fn shift_date(&mut self, duration: Duration) -> ActionResult {
-        self.update_date(self.current_date.add(duration))
-    }
-
-    fn shift_months(&mut self, qty: i32) -> ActionResult {
-        let new_date = match qty.cmp(&0) {
-            Ordering::Greater | Ordering::Equal => {
-                let qty_as_months = Months::new(qty as u32);
-                self.current_date
-                    .checked_add_months(qty_as_months)
-                    .unwrap_or(NaiveDate::MAX)
-            }
-            Ordering::Less => {
-                let qty_as_months = Months::new((-qty) as u32);
-                self.current_date
-                    .checked_sub_months(qty_as_months)
-                    .unwrap_or(NaiveDate::MIN)
-            }
-        };
-
-        self.update_date(new_date)
-    }
-
     fn update_date(&mut self, new_date: NaiveDate) -> ActionResult {
         if self.current_date == new_date {
             return ActionResult::Clean;
@@ -144,30 +121,9 @@ where
     }
 
     fn handle(&mut self, action: DateSelectPromptAction) -> InquireResult<ActionResult> {
-        let result = match action {
-            DateSelectPromptAction::GoToPrevWeek => self.shift_date(
-                Duration::try_weeks(-1)
-                    .expect("unexpected overflow when calculating duration of 1 week"),
-            ),
-            DateSelectPromptAction::GoToNextWeek => self.shift_date(
-                Duration::try_weeks(1)
-                    .expect("unexpected overflow when calculating duration of 1 week"),
-            ),
-            DateSelectPromptAction::GoToPrevDay => self.shift_date(
-                Duration::try_days(-1)
-                    .expect("unexpected overflow when calculating duration of 1 day"),
-            ),
-            DateSelectPromptAction::GoToNextDay => self.shift_date(
-                Duration::try_days(1)
-                    .expect("unexpected overflow when calculating duration of 1 day"),
-            ),
-            DateSelectPromptAction::GoToPrevYear => self.shift_months(-12),
-            DateSelectPromptAction::GoToNextYear => self.shift_months(12),
-            DateSelectPromptAction::GoToPrevMonth => self.shift_months(-1),
-            DateSelectPromptAction::GoToNextMonth => self.shift_months(1),
-        };
+        let new_date = shift_date(self.current_date, action);
 
-        Ok(result)
+        Ok(self.update_date(new_date))
     }
 
     fn render(&self, backend: &mut B) -> InquireResult<()> {
@@ -196,3 +152,43 @@ where
         Ok(())
     }
 }
+
+/// Returns the date resulting from applying a navigation action to `date`,
+/// without taking any min or max dates into account.
+pub(crate) fn shift_date(date: NaiveDate, action: DateSelectPromptAction) -> NaiveDate {
+    match action {
+        DateSelectPromptAction::GoToPrevWeek => date.add(
+            Duration::try_weeks(-1)
+                .expect("unexpected overflow when calculating duration of 1 week"),
+        ),
+        DateSelectPromptAction::GoToNextWeek => date.add(
+            Duration::try_weeks(1)
+                .expect("unexpected overflow when calculating duration of 1 week"),
+        ),
+        DateSelectPromptAction::GoToPrevDay => date.add(
+            Duration::try_days(-1).expect("unexpected overflow when calculating duration of 1 day"),
+        ),
+        DateSelectPromptAction::GoToNextDay => date.add(
+            Duration::try_days(1).expect("unexpected overflow when calculating duration of 1 day"),
+        ),
+        DateSelectPromptAction::GoToPrevYear => shift_months(date, -12),
+        DateSelectPromptAction::GoToNextYear => shift_months(date, 12),
+        DateSelectPromptAction::GoToPrevMonth => shift_months(date, -1),
+        DateSelectPromptAction::GoToNextMonth => shift_months(date, 1),
+    }
+}
+
+fn shift_months(date: NaiveDate, qty: i32) -> NaiveDate {
+    match qty.cmp(&0) {
+        Ordering::Greater | Ordering::Equal => {
+            let qty_as_months = Months::new(qty as u32);
+            date.checked_add_months(qty_as_months)
+                .unwrap_or(NaiveDate::MAX)
+        }
+        Ordering::Less => {
+            let qty_as_months = Months::new((-qty) as u32);
+            date.checked_sub_months(qty_as_months)
+                .unwrap_or(NaiveDate::MIN)
+        }
+    }
+}
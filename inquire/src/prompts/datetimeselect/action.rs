@@ -0,0 +1,42 @@
+use crate::{ui::Key, DateSelectPromptAction, InnerAction, TimeSelectPromptAction};
+
+use super::config::DateTimeSelectConfig;
+
+/// Set of actions for a DateTimeSelectPrompt.
+///
+/// Most key events have different meanings depending on whether the calendar
+/// or the time picker is focused, e.g. the left arrow moves to the previous day
+/// in the calendar and to the previous field in the time picker. These events
+/// are parsed into a [Navigate](DateTimeSelectPromptAction::Navigate) action
+/// carrying both interpretations, and the prompt applies the one matching the
+/// focused section.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum DateTimeSelectPromptAction {
+    /// Move the focus between the calendar and the time picker.
+    ToggleFocus,
+    /// Navigation in the focused section of the prompt.
+    Navigate {
+        /// Action applied when the calendar is focused.
+        date: Option<DateSelectPromptAction>,
+        /// Action applied when the time picker is focused.
+        time: Option<TimeSelectPromptAction>,
+    },
+}
+
+impl InnerAction for DateTimeSelectPromptAction {
+    type Config = DateTimeSelectConfig;
+
+    fn from_key(key: Key, config: &DateTimeSelectConfig) -> Option<Self> {
+        if let Key::Tab = key {
+            return Some(Self::ToggleFocus);
+        }
+
+        let date = DateSelectPromptAction::from_key(key, &config.date);
+        let time = TimeSelectPromptAction::from_key(key, &config.time);
+
+        match (date, time) {
+            (None, None) => None,
+            (date, time) => Some(Self::Navigate { date, time }),
+        }
+    }
+}
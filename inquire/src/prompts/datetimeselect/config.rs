@@ -0,0 +1,31 @@
+use crate::{
+    prompts::{dateselect::DateSelectConfig, timeselect::TimeSelectConfig},
+    DateTimeSelect,
+};
+
+/// Configuration settings used in the execution of a DateTimeSelectPrompt.
+#[derive(Copy, Clone, Debug)]
+pub struct DateTimeSelectConfig {
+    /// Settings of the calendar section of the prompt.
+    pub date: DateSelectConfig,
+
+    /// Settings of the time picker section of the prompt.
+    pub time: TimeSelectConfig,
+}
+
+impl From<&DateTimeSelect<'_>> for DateTimeSelectConfig {
+    fn from(value: &DateTimeSelect<'_>) -> Self {
+        Self {
+            date: DateSelectConfig {
+                min_date: value.min_date,
+                max_date: value.max_date,
+                week_start: value.week_start,
+            },
+            time: TimeSelectConfig {
+                hour_format: value.hour_format,
+                with_seconds: value.with_seconds,
+                minute_step: value.minute_step,
+            },
+        }
+    }
+}
@@ -0,0 +1,296 @@
+mod action;
+mod config;
+mod prompt;
+#[cfg(test)]
+mod test;
+
+pub use action::*;
+
+use chrono::{NaiveDate, NaiveDateTime};
+
+use crate::{
+    config::get_configuration,
+    date_utils::{get_current_date, get_current_time},
+    error::{InquireError, InquireResult},
+    formatter::DateTimeFormatter,
+    prompts::prompt::Prompt,
+    terminal::get_default_terminal,
+    ui::{date::DateTimeSelectBackend, Backend, RenderConfig},
+    validator::DateTimeValidator,
+    HourFormat,
+};
+
+use self::prompt::DateTimeSelectPrompt;
+
+/// Prompt that allows user to select a date and a time of the day in a single interaction, combining an interactive calendar with a time picker. Available via the `date` feature.
+///
+/// By default, the initial selected date and time are the current ones. The prompt starts with the calendar focused, where the user can navigate in the same way as in a [`DateSelect`](crate::DateSelect) prompt. Pressing `tab` moves the focus to the time picker, where the user can move between fields with the left and right arrows and change the value of the selected field with the up and down arrows, in the same way as in a [`TimeSelect`](crate::TimeSelect) prompt. Pressing `tab` again moves the focus back to the calendar.
+///
+/// Finally, the user selects the date and time by pressing the enter key.
+///
+/// `DateTimeSelect` prompts provide several options of configuration:
+///
+/// - **Prompt message**: Required when creating the prompt.
+/// - **Default value**: Date and time selected when the prompt is displayed, and the ones returned if the user submits without any previous actions. Current date and time by default.
+/// - **Help message**: Message displayed at the line below the prompt.
+/// - **Formatter**: Custom formatter in case you need to pre-process the user input before showing it as the final answer.
+///   - By default, the date is formatted as "Month Day, Year", followed by the time formatted the same way it is displayed in the prompt.
+/// - **Validators**: Custom validators to the user's selected date and time, displaying an error message if the value does not pass the requirements.
+/// - **Week start**: Which day of the week should be displayed in the first column of the calendar, Sunday by default.
+/// - **Min and max date**: Inclusive boundaries of allowed dates in the interactive calendar.
+/// - **Hour format**, **seconds** and **minute step**: Display settings of the time picker, see [`TimeSelect`](crate::TimeSelect).
+///
+/// # Example
+///
+/// ```no_run
+/// use chrono::NaiveDate;
+/// use inquire::DateTimeSelect;
+///
+/// let departure = DateTimeSelect::new("When do you want to depart?")
+///     .with_min_date(NaiveDate::from_ymd_opt(2021, 8, 1).unwrap())
+///     .with_minute_step(15)
+///     .prompt();
+///
+/// match departure {
+///     Ok(departure) => println!("Departing at {}", departure),
+///     Err(_) => println!("There was an error in the system."),
+/// }
+/// ```
+#[derive(Clone)]
+pub struct DateTimeSelect<'a> {
+    /// Message to be presented to the user.
+    pub message: &'a str,
+
+    /// First day of the week when displaying week rows.
+    pub week_start: chrono::Weekday,
+
+    /// Starting date and time to be selected.
+    pub starting_datetime: NaiveDateTime,
+
+    /// Min date allowed to be selected.
+    pub min_date: Option<NaiveDate>,
+
+    /// Max date allowed to be selected.
+    pub max_date: Option<NaiveDate>,
+
+    /// Whether hours are displayed in the 12-hour or 24-hour format.
+    pub hour_format: HourFormat,
+
+    /// Whether the seconds field is displayed and selectable.
+    pub with_seconds: bool,
+
+    /// Amount of minutes added or subtracted on each change of the minutes field.
+    pub minute_step: u32,
+
+    /// Help message to be presented to the user.
+    pub help_message: Option<&'a str>,
+
+    /// Function that formats the user input and presents it to the user as the final rendering of the prompt.
+    ///
+    /// If not set, the date is formatted as "Month Day, Year", followed by the time
+    /// formatted the same way it is displayed in the prompt.
+    pub formatter: Option<DateTimeFormatter<'a>>,
+
+    /// Collection of validators to apply to the user input.
+    ///
+    /// Validators are executed in the order they are stored, stopping at and displaying to the user
+    /// only the first validation error that might appear.
+    ///
+    /// The possible error is displayed to the user one line above the prompt.
+    pub validators: Vec<Box<dyn DateTimeValidator>>,
+
+    /// RenderConfig to apply to the rendered interface.
+    ///
+    /// Note: The default render config considers if the NO_COLOR environment variable
+    /// is set to decide whether to render the colored config or the empty one.
+    ///
+    /// When overriding the config in a prompt, NO_COLOR is no longer considered and your
+    /// config is treated as the only source of truth. If you want to customize colors
+    /// and still support NO_COLOR, you will have to do this on your end.
+    pub render_config: RenderConfig<'a>,
+}
+
+impl<'a> DateTimeSelect<'a> {
+    /// Default help message.
+    pub const DEFAULT_HELP_MESSAGE: Option<&'a str> =
+        Some("arrows to move, tab to switch between date and time, enter to select");
+
+    /// Default validators added to the [DateTimeSelect] prompt, none.
+    pub const DEFAULT_VALIDATORS: Vec<Box<dyn DateTimeValidator>> = vec![];
+
+    /// Default week start.
+    pub const DEFAULT_WEEK_START: chrono::Weekday = chrono::Weekday::Sun;
+
+    /// Default min date.
+    pub const DEFAULT_MIN_DATE: Option<NaiveDate> = None;
+
+    /// Default max date.
+    pub const DEFAULT_MAX_DATE: Option<NaiveDate> = None;
+
+    /// Default hour format, 24-hour.
+    pub const DEFAULT_HOUR_FORMAT: HourFormat = HourFormat::TwentyFourHour;
+
+    /// Default value of whether seconds are displayed, false.
+    pub const DEFAULT_WITH_SECONDS: bool = false;
+
+    /// Default minute step, 1.
+    pub const DEFAULT_MINUTE_STEP: u32 = 1;
+
+    /// Creates a [DateTimeSelect] with the provided message, along with default configuration values.
+    pub fn new(message: &'a str) -> Self {
+        Self {
+            message,
+            starting_datetime: get_current_date().and_time(get_current_time()),
+            min_date: Self::DEFAULT_MIN_DATE,
+            max_date: Self::DEFAULT_MAX_DATE,
+            week_start: Self::DEFAULT_WEEK_START,
+            hour_format: Self::DEFAULT_HOUR_FORMAT,
+            with_seconds: Self::DEFAULT_WITH_SECONDS,
+            minute_step: Self::DEFAULT_MINUTE_STEP,
+            help_message: Self::DEFAULT_HELP_MESSAGE,
+            formatter: None,
+            validators: Self::DEFAULT_VALIDATORS,
+            render_config: get_configuration(),
+        }
+    }
+
+    /// Sets the help message of the prompt.
+    pub fn with_help_message(mut self, message: &'a str) -> Self {
+        self.help_message = Some(message);
+        self
+    }
+
+    /// Removes the set help message.
+    pub fn without_help_message(mut self) -> Self {
+        self.help_message = None;
+        self
+    }
+
+    /// Sets the default date and time of the prompt. Equivalent to [DateTimeSelect::with_starting_datetime](DateTimeSelect::with_starting_datetime).
+    pub fn with_default(self, default: NaiveDateTime) -> Self {
+        self.with_starting_datetime(default)
+    }
+
+    /// Sets the starting date and time. Equivalent to [DateTimeSelect::with_default](DateTimeSelect::with_default).
+    pub fn with_starting_datetime(mut self, starting_datetime: NaiveDateTime) -> Self {
+        self.starting_datetime = starting_datetime;
+        self
+    }
+
+    /// Sets the week start.
+    pub fn with_week_start(mut self, week_start: chrono::Weekday) -> Self {
+        self.week_start = week_start;
+        self
+    }
+
+    /// Sets the min date.
+    pub fn with_min_date(mut self, min_date: NaiveDate) -> Self {
+        self.min_date = Some(min_date);
+        self
+    }
+
+    /// Sets the max date.
+    pub fn with_max_date(mut self, max_date: NaiveDate) -> Self {
+        self.max_date = Some(max_date);
+        self
+    }
+
+    /// Sets the hour format.
+    pub fn with_hour_format(mut self, hour_format: HourFormat) -> Self {
+        self.hour_format = hour_format;
+        self
+    }
+
+    /// Sets whether the seconds field is displayed.
+    pub fn with_seconds(mut self, with_seconds: bool) -> Self {
+        self.with_seconds = with_seconds;
+        self
+    }
+
+    /// Sets the minute step. It must be a divisor of 60, such as 5, 15 or 30.
+    pub fn with_minute_step(mut self, minute_step: u32) -> Self {
+        self.minute_step = minute_step;
+        self
+    }
+
+    /// Adds a validator to the collection of validators. You might want to use this feature
+    /// in case you need to limit the user to specific choices, such as dates in the future.
+    ///
+    /// Validators are executed in the order they are stored, stopping at and displaying to the user
+    /// only the first validation error that might appear.
+    ///
+    /// The possible error is displayed to the user one line above the prompt.
+    pub fn with_validator<V>(mut self, validator: V) -> Self
+    where
+        V: DateTimeValidator + 'static,
+    {
+        self.validators.push(Box::new(validator));
+        self
+    }
+
+    /// Adds the validators to the collection of validators in the order they are given.
+    /// You might want to use this feature in case you need to limit the user to specific
+    /// choices, such as dates in the future.
+    ///
+    /// Validators are executed in the order they are stored, stopping at and displaying to the user
+    /// only the first validation error that might appear.
+    ///
+    /// The possible error is displayed to the user one line above the prompt.
+    pub fn with_validators(mut self, validators: &[Box<dyn DateTimeValidator>]) -> Self {
+        for validator in validators {
+            self.validators.push(validator.clone());
+        }
+        self
+    }
+
+    /// Sets the formatter.
+    pub fn with_formatter(mut self, formatter: DateTimeFormatter<'a>) -> Self {
+        self.formatter = Some(formatter);
+        self
+    }
+
+    /// Sets the provided color theme to this prompt.
+    ///
+    /// Note: The default render config considers if the NO_COLOR environment variable
+    /// is set to decide whether to render the colored config or the empty one.
+    ///
+    /// When overriding the config in a prompt, NO_COLOR is no longer considered and your
+    /// config is treated as the only source of truth. If you want to customize colors
+    /// and still support NO_COLOR, you will have to do this on your end.
+    pub fn with_render_config(mut self, render_config: RenderConfig<'a>) -> Self {
+        self.render_config = render_config;
+        self
+    }
+
+    /// Parses the provided behavioral and rendering options and prompts
+    /// the CLI user for input according to the defined rules.
+    ///
+    /// This method is intended for flows where the user skipping/cancelling
+    /// the prompt - by pressing ESC - is considered normal behavior. In this case,
+    /// it does not return `Err(InquireError::OperationCanceled)`, but `Ok(None)`.
+    ///
+    /// Meanwhile, if the user does submit an answer, the method wraps the return
+    /// type with `Some`.
+    pub fn prompt_skippable(self) -> InquireResult<Option<NaiveDateTime>> {
+        match self.prompt() {
+            Ok(answer) => Ok(Some(answer)),
+            Err(InquireError::OperationCanceled) => Ok(None),
+            Err(err) => Err(err),
+        }
+    }
+
+    /// Parses the provided behavioral and rendering options and prompts
+    /// the CLI user for input according to the defined rules.
+    pub fn prompt(self) -> InquireResult<NaiveDateTime> {
+        let (input_reader, terminal) = get_default_terminal()?;
+        let mut backend = Backend::new(input_reader, terminal, self.render_config)?;
+        self.prompt_with_backend(&mut backend)
+    }
+
+    pub(crate) fn prompt_with_backend<B: DateTimeSelectBackend>(
+        self,
+        backend: &mut B,
+    ) -> InquireResult<NaiveDateTime> {
+        DateTimeSelectPrompt::new(self)?.prompt(backend)
+    }
+}
@@ -0,0 +1,225 @@
+use std::cmp::{max, min};
+
+use chrono::{Datelike, NaiveDate, NaiveDateTime};
+
+use crate::{
+    date_utils::{get_current_date, get_month},
+    error::InquireResult,
+    formatter::{DateTimeFormatter, DEFAULT_DATE_FORMATTER},
+    prompts::{
+        dateselect::shift_date,
+        prompt::{ActionResult, Prompt},
+        timeselect::TimePicker,
+    },
+    ui::date::DateTimeSelectBackend,
+    validator::{DateTimeValidator, ErrorMessage, Validation},
+    DateTimeSelect, InquireError, TimeSelectPromptAction,
+};
+
+use super::{action::DateTimeSelectPromptAction, config::DateTimeSelectConfig};
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+enum Focus {
+    Calendar,
+    Time,
+}
+
+pub struct DateTimeSelectPrompt<'a> {
+    message: &'a str,
+    config: DateTimeSelectConfig,
+    focus: Focus,
+    current_date: NaiveDate,
+    picker: TimePicker,
+    help_message: Option<&'a str>,
+    formatter: Option<DateTimeFormatter<'a>>,
+    validators: Vec<Box<dyn DateTimeValidator>>,
+    error: Option<ErrorMessage>,
+}
+
+impl<'a> DateTimeSelectPrompt<'a> {
+    pub fn new(so: DateTimeSelect<'a>) -> InquireResult<Self> {
+        let starting_date = so.starting_datetime.date();
+
+        if let Some(min_date) = so.min_date {
+            if min_date > starting_date {
+                return Err(InquireError::InvalidConfiguration(
+                    "Min date can not be greater than starting date".into(),
+                ));
+            }
+        }
+        if let Some(max_date) = so.max_date {
+            if max_date < starting_date {
+                return Err(InquireError::InvalidConfiguration(
+                    "Max date can not be smaller than starting date".into(),
+                ));
+            }
+        }
+        if so.minute_step == 0 || 60 % so.minute_step != 0 {
+            return Err(InquireError::InvalidConfiguration(
+                "Minute step must be a divisor of 60".into(),
+            ));
+        }
+
+        let picker = TimePicker::new(
+            so.starting_datetime.time(),
+            so.hour_format,
+            so.with_seconds,
+            so.minute_step,
+        );
+
+        Ok(Self {
+            message: so.message,
+            config: (&so).into(),
+            focus: Focus::Calendar,
+            current_date: starting_date,
+            picker,
+            help_message: so.help_message,
+            formatter: so.formatter,
+            validators: so.validators,
+            error: None,
+        })
+    }
+
+    fn update_date(&mut self, new_date: NaiveDate) -> ActionResult {
+        let mut new_date = new_date;
+        if let Some(min_date) = self.config.date.min_date {
+            new_date = max(new_date, min_date);
+        }
+        if let Some(max_date) = self.config.date.max_date {
+            new_date = min(new_date, max_date);
+        }
+
+        if self.current_date == new_date {
+            return ActionResult::Clean;
+        }
+
+        self.current_date = new_date;
+        ActionResult::NeedsRedraw
+    }
+
+    fn handle_time_action(&mut self, action: TimeSelectPromptAction) -> ActionResult {
+        match action {
+            TimeSelectPromptAction::SelectPrevField => self.picker.select_prev_field(),
+            TimeSelectPromptAction::SelectNextField => self.picker.select_next_field(),
+            TimeSelectPromptAction::IncrementField => self.picker.increment(),
+            TimeSelectPromptAction::DecrementField => self.picker.decrement(),
+        }
+    }
+
+    fn validate_current_answer(&self) -> InquireResult<Validation> {
+        for validator in &self.validators {
+            match validator.validate(self.cur_answer()) {
+                Ok(Validation::Valid) => {}
+                Ok(Validation::Invalid(msg)) => return Ok(Validation::Invalid(msg)),
+                Err(err) => return Err(InquireError::Custom(err)),
+            }
+        }
+
+        Ok(Validation::Valid)
+    }
+
+    fn cur_answer(&self) -> NaiveDateTime {
+        self.current_date.and_time(self.picker.time())
+    }
+}
+
+impl<'a, B> Prompt<B> for DateTimeSelectPrompt<'a>
+where
+    B: DateTimeSelectBackend,
+{
+    type Config = DateTimeSelectConfig;
+    type InnerAction = DateTimeSelectPromptAction;
+    type Output = NaiveDateTime;
+
+    fn message(&self) -> &str {
+        self.message
+    }
+
+    fn format_answer(&self, answer: &NaiveDateTime) -> String {
+        match self.formatter {
+            Some(formatter) => formatter(*answer),
+            None => format!(
+                "{} {}",
+                DEFAULT_DATE_FORMATTER(answer.date()),
+                self.picker.display().0
+            ),
+        }
+    }
+
+    fn config(&self) -> &DateTimeSelectConfig {
+        &self.config
+    }
+
+    fn submit(&mut self) -> InquireResult<Option<NaiveDateTime>> {
+        let answer = match self.validate_current_answer()? {
+            Validation::Valid => Some(self.cur_answer()),
+            Validation::Invalid(msg) => {
+                self.error = Some(msg);
+                None
+            }
+        };
+
+        Ok(answer)
+    }
+
+    fn handle(&mut self, action: DateTimeSelectPromptAction) -> InquireResult<ActionResult> {
+        let result = match (action, self.focus) {
+            (DateTimeSelectPromptAction::ToggleFocus, Focus::Calendar) => {
+                self.focus = Focus::Time;
+                ActionResult::NeedsRedraw
+            }
+            (DateTimeSelectPromptAction::ToggleFocus, Focus::Time) => {
+                self.focus = Focus::Calendar;
+                ActionResult::NeedsRedraw
+            }
+            (
+                DateTimeSelectPromptAction::Navigate {
+                    date: Some(action), ..
+                },
+                Focus::Calendar,
+            ) => self.update_date(shift_date(self.current_date, action)),
+            (
+                DateTimeSelectPromptAction::Navigate {
+                    time: Some(action), ..
+                },
+                Focus::Time,
+            ) => self.handle_time_action(action),
+            (DateTimeSelectPromptAction::Navigate { .. }, _) => ActionResult::Clean,
+        };
+
+        Ok(result)
+    }
+
+    fn render(&self, backend: &mut B) -> InquireResult<()> {
+        let prompt = &self.message;
+
+        if let Some(err) = &self.error {
+            backend.render_error_message(err)?;
+        }
+
+        backend.render_calendar_prompt(prompt)?;
+
+        backend.render_calendar(
+            get_month(self.current_date.month()),
+            self.current_date.year(),
+            self.config.date.week_start,
+            get_current_date(),
+            self.current_date,
+            self.config.date.min_date,
+            self.config.date.max_date,
+        )?;
+
+        let (time, selected_field) = self.picker.display();
+        let selected_field = match self.focus {
+            Focus::Time => Some(selected_field),
+            Focus::Calendar => None,
+        };
+        backend.render_time(&time, selected_field)?;
+
+        if let Some(help_message) = self.help_message {
+            backend.render_help_message(help_message)?;
+        }
+
+        Ok(())
+    }
+}
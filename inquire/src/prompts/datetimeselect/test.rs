@@ -0,0 +1,123 @@
+use chrono::{NaiveDate, NaiveDateTime};
+
+use crate::{
+    error::InquireResult,
+    ui::{
+        test::{FakeBackend, Token},
+        Key, KeyModifiers,
+    },
+    DateTimeSelect, InquireError,
+};
+
+fn datetime(y: i32, mo: u32, d: u32, h: u32, mi: u32) -> NaiveDateTime {
+    NaiveDate::from_ymd_opt(y, mo, d)
+        .unwrap()
+        .and_hms_opt(h, mi, 0)
+        .unwrap()
+}
+
+#[test]
+fn enter_returns_starting_datetime() -> InquireResult<()> {
+    let mut backend = FakeBackend::new(vec![Key::Enter]);
+
+    let ans = DateTimeSelect::new("Question")
+        .with_starting_datetime(datetime(2023, 1, 15, 10, 30))
+        .prompt_with_backend(&mut backend)?;
+
+    assert_eq!(datetime(2023, 1, 15, 10, 30), ans);
+
+    Ok(())
+}
+
+#[test]
+fn arrows_navigate_calendar_until_focus_is_toggled() -> InquireResult<()> {
+    let mut backend = FakeBackend::new(vec![
+        Key::Right(KeyModifiers::NONE),
+        Key::Down(KeyModifiers::NONE),
+        Key::Tab,
+        Key::Up(KeyModifiers::NONE),
+        Key::Right(KeyModifiers::NONE),
+        Key::Down(KeyModifiers::NONE),
+        Key::Enter,
+    ]);
+
+    let ans = DateTimeSelect::new("Question")
+        .with_starting_datetime(datetime(2023, 1, 15, 10, 30))
+        .with_minute_step(15)
+        .prompt_with_backend(&mut backend)?;
+
+    assert_eq!(datetime(2023, 1, 23, 11, 15), ans);
+
+    Ok(())
+}
+
+#[test]
+fn toggling_focus_back_returns_to_calendar() -> InquireResult<()> {
+    let mut backend = FakeBackend::new(vec![
+        Key::Tab,
+        Key::Up(KeyModifiers::NONE),
+        Key::Tab,
+        Key::Up(KeyModifiers::NONE),
+        Key::Enter,
+    ]);
+
+    let ans = DateTimeSelect::new("Question")
+        .with_starting_datetime(datetime(2023, 1, 15, 10, 30))
+        .prompt_with_backend(&mut backend)?;
+
+    assert_eq!(datetime(2023, 1, 8, 11, 30), ans);
+
+    Ok(())
+}
+
+#[test]
+fn time_field_is_only_highlighted_when_focused() -> InquireResult<()> {
+    let mut backend = FakeBackend::new(vec![Key::Tab, Key::Enter]);
+
+    let ans = DateTimeSelect::new("Question")
+        .with_starting_datetime(datetime(2023, 1, 15, 10, 30))
+        .prompt_with_backend(&mut backend)?;
+
+    let frames = backend.frames();
+    assert!(frames[0].has_token(&Token::Time {
+        content: "10:30".into(),
+        selected: None,
+    }));
+    assert!(frames[1].has_token(&Token::Time {
+        content: "10:30".into(),
+        selected: Some(0..2),
+    }));
+    assert!(frames[2].has_token(&Token::AnsweredPrompt(
+        "Question".into(),
+        "January 15, 2023 10:30".into()
+    )));
+    assert_eq!(datetime(2023, 1, 15, 10, 30), ans);
+
+    Ok(())
+}
+
+#[test]
+fn calendar_respects_min_date() -> InquireResult<()> {
+    let mut backend = FakeBackend::new(vec![Key::Up(KeyModifiers::NONE), Key::Enter]);
+
+    let ans = DateTimeSelect::new("Question")
+        .with_starting_datetime(datetime(2023, 1, 15, 10, 30))
+        .with_min_date(NaiveDate::from_ymd_opt(2023, 1, 12).unwrap())
+        .prompt_with_backend(&mut backend)?;
+
+    assert_eq!(datetime(2023, 1, 12, 10, 30), ans);
+
+    Ok(())
+}
+
+#[test]
+fn starting_date_before_min_date_is_invalid_configuration() {
+    let mut backend = FakeBackend::new(vec![Key::Enter]);
+
+    let ans = DateTimeSelect::new("Question")
+        .with_starting_datetime(datetime(2023, 1, 15, 10, 30))
+        .with_min_date(NaiveDate::from_ymd_opt(2023, 2, 1).unwrap())
+        .prompt_with_backend(&mut backend);
+
+    assert!(matches!(ans, Err(InquireError::InvalidConfiguration(_))));
+}
@@ -0,0 +1,67 @@
+use crate::{
+    ui::{Key, KeyModifiers},
+    InnerAction, InputAction,
+};
+
+use super::config::DirectorySelectConfig;
+
+/// Set of actions for a DirectorySelectPrompt.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum DirectorySelectPromptAction {
+    /// Action on the value text input handler.
+    FilterInput(InputAction),
+    /// Moves the cursor to the entry above.
+    MoveUp,
+    /// Moves the cursor to the entry below.
+    MoveDown,
+    /// Moves the cursor to the page above.
+    PageUp,
+    /// Moves the cursor to the page below.
+    PageDown,
+    /// Moves the cursor to the start of the list.
+    MoveToStart,
+    /// Moves the cursor to the end of the list.
+    MoveToEnd,
+    /// Enters the highlighted directory, creating it first when it is a new one.
+    EnterDirectory,
+    /// Moves to the parent of the current directory.
+    GoToParent,
+}
+
+impl InnerAction for DirectorySelectPromptAction {
+    type Config = DirectorySelectConfig;
+
+    fn from_key(key: Key, config: &DirectorySelectConfig) -> Option<Self> {
+        if config.vim_mode {
+            let action = match key {
+                Key::Char('k', KeyModifiers::NONE) => Some(Self::MoveUp),
+                Key::Char('j', KeyModifiers::NONE) => Some(Self::MoveDown),
+                _ => None,
+            };
+
+            if action.is_some() {
+                return action;
+            }
+        }
+
+        let action = match key {
+            Key::Up(KeyModifiers::NONE) | Key::Char('p', KeyModifiers::CONTROL) => Self::MoveUp,
+            Key::PageUp(_) => Self::PageUp,
+            Key::Home => Self::MoveToStart,
+
+            Key::Down(KeyModifiers::NONE) | Key::Char('n', KeyModifiers::CONTROL) => Self::MoveDown,
+            Key::PageDown(_) => Self::PageDown,
+            Key::End => Self::MoveToEnd,
+
+            Key::Right(KeyModifiers::NONE) | Key::Tab => Self::EnterDirectory,
+            Key::Left(KeyModifiers::NONE) => Self::GoToParent,
+
+            key => match InputAction::from_key(key, &()) {
+                Some(action) => Self::FilterInput(action),
+                None => return None,
+            },
+        };
+
+        Some(action)
+    }
+}
@@ -0,0 +1,19 @@
+use crate::DirectorySelect;
+
+/// Configuration settings used in the execution of a DirectorySelectPrompt.
+#[derive(Copy, Clone, Debug)]
+pub struct DirectorySelectConfig {
+    /// Whether to use vim-style keybindings.
+    pub vim_mode: bool,
+    /// Page size of the list of entries.
+    pub page_size: usize,
+}
+
+impl From<&DirectorySelect<'_>> for DirectorySelectConfig {
+    fn from(value: &DirectorySelect<'_>) -> Self {
+        Self {
+            vim_mode: value.vim_mode,
+            page_size: value.page_size,
+        }
+    }
+}
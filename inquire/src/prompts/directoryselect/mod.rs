@@ -0,0 +1,240 @@
+mod action;
+mod config;
+mod prompt;
+#[cfg(test)]
+#[cfg(feature = "crossterm")]
+mod test;
+
+pub use action::*;
+
+use std::path::{Path, PathBuf};
+
+use crate::{
+    config::get_configuration,
+    error::{InquireError, InquireResult},
+    formatter::PathFormatter,
+    prompts::prompt::Prompt,
+    terminal::get_default_terminal,
+    ui::{Backend, FileSelectBackend, RenderConfig},
+};
+
+use self::prompt::DirectorySelectPrompt;
+
+/// Prompt that lets the user browse the filesystem and select a directory.
+///
+/// Unlike [`FileSelect`](crate::FileSelect), only directories are listed. The first entry, `./`, represents the directory currently being browsed, so pressing enter right away selects the starting path. Pressing enter on any other directory selects it as well, while the right arrow enters it and the left arrow, or backspace on an empty filter, goes to the parent directory. Typing filters the listed directories.
+///
+/// When creating directories is allowed, typing the name of a directory that does not exist lists it as a new entry. Selecting it creates the directory inside the current one and returns its path, while entering it creates the directory and continues browsing from there.
+///
+/// The selected path is returned as a [`PathBuf`].
+///
+/// Directory selects provide several options of configuration:
+///
+/// - **Prompt message**: Required when creating the prompt.
+/// - **Starting path**: Directory listed when the prompt is first rendered, the current working directory by default.
+/// - **Show hidden**: Whether directories whose names start with a dot are listed, false by default.
+/// - **Allow create**: Whether the user can create new directories inline, false by default.
+/// - **Help message**: Message displayed at the line below the prompt.
+/// - **Formatter**: Custom formatter in case you need to pre-process the user input before showing it as the final answer.
+///   - Prints the full path by default.
+/// - **Page size**: Number of entries displayed at once, 7 by default.
+///
+/// If the starting path could not be read, the prompt fails with an [`InquireError::IO`] error.
+///
+/// # Example
+///
+/// ```no_run
+/// use inquire::DirectorySelect;
+///
+/// let location = DirectorySelect::new("Where should the project be created?")
+///     .with_allow_create(true)
+///     .prompt();
+///
+/// match location {
+///     Ok(path) => println!("Creating project at {}", path.display()),
+///     Err(_) => println!("There was an error, please try again"),
+/// }
+/// ```
+///
+/// [`InquireError::IO`]: crate::error::InquireError::IO
+#[derive(Clone)]
+pub struct DirectorySelect<'a> {
+    /// Message to be presented to the user.
+    pub message: &'a str,
+
+    /// Directory listed when the prompt is first rendered.
+    ///
+    /// When `None`, the current working directory is used.
+    pub starting_path: Option<&'a Path>,
+
+    /// Whether to list directories whose names start with a dot.
+    pub show_hidden: bool,
+
+    /// Whether the user can create new directories inline.
+    pub allow_create: bool,
+
+    /// Help message to be presented to the user.
+    pub help_message: Option<&'a str>,
+
+    /// Page size of the entries displayed to the user.
+    pub page_size: usize,
+
+    /// Whether vim mode is enabled. When enabled, the user can
+    /// navigate through the entries using jk.
+    pub vim_mode: bool,
+
+    /// Function that formats the selected path and presents it to the user as the final rendering of the prompt.
+    pub formatter: PathFormatter<'a>,
+
+    /// RenderConfig to apply to the rendered interface.
+    ///
+    /// Note: The default render config considers if the NO_COLOR environment variable
+    /// is set to decide whether to render the colored config or the empty one.
+    ///
+    /// When overriding the config in a prompt, NO_COLOR is no longer considered and your
+    /// config is treated as the only source of truth. If you want to customize colors
+    /// and still support NO_COLOR, you will have to do this on your end.
+    pub render_config: RenderConfig<'a>,
+}
+
+impl<'a> DirectorySelect<'a> {
+    /// Default formatter, which prints the full path of the selected directory.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::path::Path;
+    /// use inquire::DirectorySelect;
+    ///
+    /// let formatter = DirectorySelect::DEFAULT_FORMATTER;
+    /// assert_eq!(String::from("/home/user/projects"), formatter(Path::new("/home/user/projects")));
+    /// ```
+    pub const DEFAULT_FORMATTER: PathFormatter<'a> = &|path| path.display().to_string();
+
+    /// Default page size.
+    pub const DEFAULT_PAGE_SIZE: usize = crate::config::DEFAULT_PAGE_SIZE;
+
+    /// Default value of vim mode.
+    pub const DEFAULT_VIM_MODE: bool = crate::config::DEFAULT_VIM_MODE;
+
+    /// Default value of whether hidden entries are listed.
+    pub const DEFAULT_SHOW_HIDDEN: bool = false;
+
+    /// Default value of whether new directories can be created.
+    pub const DEFAULT_ALLOW_CREATE: bool = false;
+
+    /// Default help message.
+    pub const DEFAULT_HELP_MESSAGE: Option<&'a str> =
+        Some("↑↓ to move, enter to select, → to enter a directory, ← to go up, type to filter");
+
+    /// Creates a [DirectorySelect] with the provided message, along with default configuration values.
+    pub fn new(message: &'a str) -> Self {
+        Self {
+            message,
+            starting_path: None,
+            show_hidden: Self::DEFAULT_SHOW_HIDDEN,
+            allow_create: Self::DEFAULT_ALLOW_CREATE,
+            help_message: Self::DEFAULT_HELP_MESSAGE,
+            page_size: Self::DEFAULT_PAGE_SIZE,
+            vim_mode: Self::DEFAULT_VIM_MODE,
+            formatter: Self::DEFAULT_FORMATTER,
+            render_config: get_configuration(),
+        }
+    }
+
+    /// Sets the directory listed when the prompt is first rendered.
+    pub fn with_starting_path<P>(mut self, path: &'a P) -> Self
+    where
+        P: AsRef<Path> + ?Sized,
+    {
+        self.starting_path = Some(path.as_ref());
+        self
+    }
+
+    /// Sets whether directories whose names start with a dot are listed.
+    pub fn with_show_hidden(mut self, show_hidden: bool) -> Self {
+        self.show_hidden = show_hidden;
+        self
+    }
+
+    /// Sets whether the user can create new directories inline.
+    pub fn with_allow_create(mut self, allow_create: bool) -> Self {
+        self.allow_create = allow_create;
+        self
+    }
+
+    /// Sets the help message of the prompt.
+    pub fn with_help_message(mut self, message: &'a str) -> Self {
+        self.help_message = Some(message);
+        self
+    }
+
+    /// Removes the set help message.
+    pub fn without_help_message(mut self) -> Self {
+        self.help_message = None;
+        self
+    }
+
+    /// Sets the page size.
+    pub fn with_page_size(mut self, page_size: usize) -> Self {
+        self.page_size = page_size;
+        self
+    }
+
+    /// Enables or disables vim_mode.
+    pub fn with_vim_mode(mut self, vim_mode: bool) -> Self {
+        self.vim_mode = vim_mode;
+        self
+    }
+
+    /// Sets the formatter.
+    pub fn with_formatter(mut self, formatter: PathFormatter<'a>) -> Self {
+        self.formatter = formatter;
+        self
+    }
+
+    /// Sets the provided color theme to this prompt.
+    ///
+    /// Note: The default render config considers if the NO_COLOR environment variable
+    /// is set to decide whether to render the colored config or the empty one.
+    ///
+    /// When overriding the config in a prompt, NO_COLOR is no longer considered and your
+    /// config is treated as the only source of truth. If you want to customize colors
+    /// and still support NO_COLOR, you will have to do this on your end.
+    pub fn with_render_config(mut self, render_config: RenderConfig<'a>) -> Self {
+        self.render_config = render_config;
+        self
+    }
+
+    /// Parses the provided behavioral and rendering options and prompts
+    /// the CLI user for input according to the defined rules.
+    ///
+    /// This method is intended for flows where the user skipping/cancelling
+    /// the prompt - by pressing ESC - is considered normal behavior. In this case,
+    /// it does not return `Err(InquireError::OperationCanceled)`, but `Ok(None)`.
+    ///
+    /// Meanwhile, if the user does submit an answer, the method wraps the return
+    /// type with `Some`.
+    pub fn prompt_skippable(self) -> InquireResult<Option<PathBuf>> {
+        match self.prompt() {
+            Ok(answer) => Ok(Some(answer)),
+            Err(InquireError::OperationCanceled) => Ok(None),
+            Err(err) => Err(err),
+        }
+    }
+
+    /// Parses the provided behavioral and rendering options and prompts
+    /// the CLI user for input according to the defined rules.
+    pub fn prompt(self) -> InquireResult<PathBuf> {
+        let (input_reader, terminal) = get_default_terminal()?;
+        let mut backend = Backend::new(input_reader, terminal, self.render_config)?;
+        self.prompt_with_backend(&mut backend)
+    }
+
+    pub(crate) fn prompt_with_backend<B: FileSelectBackend>(
+        self,
+        backend: &mut B,
+    ) -> InquireResult<PathBuf> {
+        DirectorySelectPrompt::new(self)?.prompt(backend)
+    }
+}
@@ -0,0 +1,205 @@
+use std::{env, fs, io, path::PathBuf};
+
+use crate::{
+    error::InquireResult,
+    formatter::PathFormatter,
+    prompts::{
+        fileselect::{EntryKind, PathBrowser, PathBrowserOptions},
+        prompt::{ActionResult, Prompt},
+    },
+    ui::FileSelectBackend,
+    utils::paginate,
+    validator::ErrorMessage,
+    DirectorySelect,
+};
+
+use super::{action::DirectorySelectPromptAction, config::DirectorySelectConfig};
+
+pub struct DirectorySelectPrompt<'a> {
+    message: &'a str,
+    config: DirectorySelectConfig,
+    browser: PathBrowser,
+    help_message: Option<&'a str>,
+    formatter: PathFormatter<'a>,
+    error: Option<ErrorMessage>,
+}
+
+impl<'a> DirectorySelectPrompt<'a> {
+    pub fn new(ds: DirectorySelect<'a>) -> InquireResult<Self> {
+        let starting_path = match ds.starting_path {
+            Some(path) => path.to_path_buf(),
+            None => env::current_dir()?,
+        };
+
+        let options = PathBrowserOptions {
+            show_hidden: ds.show_hidden,
+            extensions: vec![],
+            allow_new: ds.allow_create,
+            directories_only: true,
+            list_current: true,
+        };
+
+        let browser = PathBrowser::new(fs::canonicalize(starting_path)?, options)?;
+
+        Ok(Self {
+            message: ds.message,
+            config: (&ds).into(),
+            browser,
+            help_message: ds.help_message,
+            formatter: ds.formatter,
+            error: None,
+        })
+    }
+
+    /// Converts the result of a navigation in the filesystem, displaying
+    /// an error message to the user when the directory could not be read.
+    fn navigation_result(&mut self, result: io::Result<ActionResult>) -> ActionResult {
+        match result {
+            Ok(ActionResult::Clean) => ActionResult::Clean,
+            Ok(ActionResult::NeedsRedraw) => {
+                self.error = None;
+                ActionResult::NeedsRedraw
+            }
+            Err(err) => {
+                self.error = Some(ErrorMessage::Custom(format!(
+                    "Could not read directory: {err}"
+                )));
+                ActionResult::NeedsRedraw
+            }
+        }
+    }
+
+    /// Creates the highlighted new directory, displaying an error message
+    /// to the user on failure.
+    fn create_directory(&mut self) -> Option<PathBuf> {
+        match self.browser.create_highlighted() {
+            Ok(path) => path,
+            Err(err) => {
+                self.error = Some(ErrorMessage::Custom(format!(
+                    "Could not create directory: {err}"
+                )));
+                None
+            }
+        }
+    }
+
+    fn enter_directory(&mut self) -> ActionResult {
+        let is_new = self
+            .browser
+            .highlighted()
+            .map(|entry| entry.kind == EntryKind::New)
+            .unwrap_or(false);
+
+        if !is_new {
+            let result = self.browser.enter_highlighted();
+            return self.navigation_result(result);
+        }
+
+        match self.create_directory() {
+            Some(path) => {
+                let result = self
+                    .browser
+                    .change_directory(path)
+                    .map(|_| ActionResult::NeedsRedraw);
+                self.navigation_result(result)
+            }
+            None => ActionResult::NeedsRedraw,
+        }
+    }
+}
+
+impl<'a, Backend> Prompt<Backend> for DirectorySelectPrompt<'a>
+where
+    Backend: FileSelectBackend,
+{
+    type Config = DirectorySelectConfig;
+    type InnerAction = DirectorySelectPromptAction;
+    type Output = PathBuf;
+
+    fn message(&self) -> &str {
+        self.message
+    }
+
+    fn config(&self) -> &DirectorySelectConfig {
+        &self.config
+    }
+
+    fn format_answer(&self, answer: &PathBuf) -> String {
+        (self.formatter)(answer)
+    }
+
+    fn submit(&mut self) -> InquireResult<Option<PathBuf>> {
+        let entry = match self.browser.highlighted() {
+            Some(entry) => entry,
+            None => return Ok(None),
+        };
+
+        match entry.kind {
+            EntryKind::Current | EntryKind::Directory | EntryKind::File => {
+                Ok(Some(entry.path.clone()))
+            }
+            EntryKind::New => Ok(self.create_directory()),
+            EntryKind::Parent => {
+                let result = self.browser.enter_highlighted();
+                self.navigation_result(result);
+                Ok(None)
+            }
+        }
+    }
+
+    fn handle(&mut self, action: DirectorySelectPromptAction) -> InquireResult<ActionResult> {
+        let result = match action {
+            DirectorySelectPromptAction::MoveUp => self.browser.move_cursor_up(1, true),
+            DirectorySelectPromptAction::MoveDown => self.browser.move_cursor_down(1, true),
+            DirectorySelectPromptAction::PageUp => {
+                self.browser.move_cursor_up(self.config.page_size, false)
+            }
+            DirectorySelectPromptAction::PageDown => {
+                self.browser.move_cursor_down(self.config.page_size, false)
+            }
+            DirectorySelectPromptAction::MoveToStart => {
+                self.browser.move_cursor_up(usize::MAX, false)
+            }
+            DirectorySelectPromptAction::MoveToEnd => {
+                self.browser.move_cursor_down(usize::MAX, false)
+            }
+            DirectorySelectPromptAction::EnterDirectory => self.enter_directory(),
+            DirectorySelectPromptAction::GoToParent => {
+                let result = self.browser.go_to_parent();
+                self.navigation_result(result)
+            }
+            DirectorySelectPromptAction::FilterInput(input_action) => {
+                let result = self.browser.handle_filter(input_action);
+                self.navigation_result(result)
+            }
+        };
+
+        Ok(result)
+    }
+
+    fn render(&self, backend: &mut Backend) -> InquireResult<()> {
+        let prompt = &self.message;
+
+        if let Some(err) = &self.error {
+            backend.render_error_message(err)?;
+        }
+
+        backend.render_select_prompt(prompt, Some(self.browser.filter()))?;
+        backend.render_current_directory(self.browser.directory())?;
+
+        let entries = self.browser.visible_entries();
+        let page = paginate(
+            self.config.page_size,
+            &entries,
+            Some(self.browser.cursor_index()),
+        );
+
+        backend.render_options(page)?;
+
+        if let Some(help_message) = self.help_message {
+            backend.render_help_message(help_message)?;
+        }
+
+        Ok(())
+    }
+}
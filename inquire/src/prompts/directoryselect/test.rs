@@ -0,0 +1,187 @@
+use std::{
+    env, fs,
+    path::{Path, PathBuf},
+};
+
+use crate::{
+    test::fake_backend,
+    ui::{Key, KeyModifiers},
+    DirectorySelect,
+};
+
+/// Directory tree created under the system's temporary directory, removed on drop.
+///
+/// ```text
+/// .git/
+/// README.md
+/// docs/
+/// src/bin/
+/// ```
+struct TestTree(PathBuf);
+
+impl TestTree {
+    fn new(name: &str) -> Self {
+        let root = env::temp_dir().join(format!(
+            "inquire-directoryselect-{}-{}",
+            name,
+            std::process::id()
+        ));
+        let _ = fs::remove_dir_all(&root);
+
+        fs::create_dir_all(root.join(".git")).unwrap();
+        fs::create_dir_all(root.join("docs")).unwrap();
+        fs::create_dir_all(root.join("src").join("bin")).unwrap();
+        fs::write(root.join("README.md"), "").unwrap();
+
+        Self(fs::canonicalize(root).unwrap())
+    }
+
+    fn path(&self) -> &Path {
+        &self.0
+    }
+}
+
+impl Drop for TestTree {
+    fn drop(&mut self) {
+        let _ = fs::remove_dir_all(&self.0);
+    }
+}
+
+#[test]
+fn enter_selects_current_directory() {
+    let tree = TestTree::new("current");
+
+    let mut backend = fake_backend(vec![Key::Enter]);
+
+    let ans = DirectorySelect::new("Question")
+        .with_starting_path(tree.path())
+        .prompt_with_backend(&mut backend)
+        .unwrap();
+
+    assert_eq!(tree.path(), ans);
+}
+
+#[test]
+fn files_are_not_listed() {
+    let tree = TestTree::new("no-files");
+
+    // ./, ../, docs/, src/
+    let mut backend = fake_backend(vec![Key::End, Key::Enter]);
+
+    let ans = DirectorySelect::new("Question")
+        .with_starting_path(tree.path())
+        .prompt_with_backend(&mut backend)
+        .unwrap();
+
+    assert_eq!(tree.path().join("src"), ans);
+}
+
+#[test]
+fn right_arrow_enters_highlighted_directory() {
+    let tree = TestTree::new("enter");
+
+    let mut keys = Key::char_keys_from_str("src");
+    keys.push(Key::Right(KeyModifiers::NONE));
+    keys.extend(Key::char_keys_from_str("bin"));
+    keys.push(Key::Enter);
+    let mut backend = fake_backend(keys);
+
+    let ans = DirectorySelect::new("Question")
+        .with_starting_path(tree.path())
+        .prompt_with_backend(&mut backend)
+        .unwrap();
+
+    assert_eq!(tree.path().join("src").join("bin"), ans);
+}
+
+#[test]
+fn enter_on_parent_goes_up() {
+    let tree = TestTree::new("parent");
+    let starting_path = tree.path().join("src");
+
+    // ./, ../, bin/
+    let mut backend = fake_backend(vec![Key::Down(KeyModifiers::NONE), Key::Enter, Key::Enter]);
+
+    let ans = DirectorySelect::new("Question")
+        .with_starting_path(&starting_path)
+        .prompt_with_backend(&mut backend)
+        .unwrap();
+
+    assert_eq!(tree.path(), ans);
+}
+
+#[test]
+fn hidden_directories_are_listed_when_enabled() {
+    let tree = TestTree::new("hidden");
+
+    let mut keys = Key::char_keys_from_str("git");
+    keys.push(Key::Enter);
+    let mut backend = fake_backend(keys);
+
+    let ans = DirectorySelect::new("Question")
+        .with_starting_path(tree.path())
+        .with_show_hidden(true)
+        .prompt_with_backend(&mut backend)
+        .unwrap();
+
+    assert_eq!(tree.path().join(".git"), ans);
+}
+
+#[test]
+fn new_directory_is_created_on_selection() {
+    let tree = TestTree::new("create");
+
+    let mut keys = Key::char_keys_from_str("my-project");
+    keys.push(Key::Enter);
+    let mut backend = fake_backend(keys);
+
+    let ans = DirectorySelect::new("Question")
+        .with_starting_path(tree.path())
+        .with_allow_create(true)
+        .prompt_with_backend(&mut backend)
+        .unwrap();
+
+    assert_eq!(tree.path().join("my-project"), ans);
+    assert!(ans.is_dir());
+}
+
+#[test]
+fn new_directory_can_be_entered() {
+    let tree = TestTree::new("create-enter");
+
+    let mut keys = Key::char_keys_from_str("apps");
+    keys.push(Key::Right(KeyModifiers::NONE));
+    keys.extend(Key::char_keys_from_str("web"));
+    keys.push(Key::Enter);
+    let mut backend = fake_backend(keys);
+
+    let ans = DirectorySelect::new("Question")
+        .with_starting_path(tree.path())
+        .with_allow_create(true)
+        .prompt_with_backend(&mut backend)
+        .unwrap();
+
+    assert_eq!(tree.path().join("apps").join("web"), ans);
+    assert!(ans.is_dir());
+}
+
+#[test]
+fn new_directories_are_not_listed_by_default() {
+    let tree = TestTree::new("no-create");
+
+    let mut keys = Key::char_keys_from_str("my-project");
+    keys.push(Key::Enter);
+    for _ in 0.."my-project".len() {
+        keys.push(Key::Backspace);
+    }
+    keys.push(Key::Enter);
+    let mut backend = fake_backend(keys);
+
+    let ans = DirectorySelect::new("Question")
+        .with_starting_path(tree.path())
+        .prompt_with_backend(&mut backend)
+        .unwrap();
+
+    assert_eq!(tree.path(), ans);
+    assert!(!tree.path().join("my-project").exists());
+}
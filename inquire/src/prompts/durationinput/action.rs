@@ -0,0 +1,23 @@
+use crate::{ui::Key, InnerAction, InputAction};
+
+use super::config::DurationInputConfig;
+
+/// Set of actions for a DurationInputPrompt.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum DurationInputPromptAction {
+    /// Action on the value text input handler.
+    ValueInput(InputAction),
+}
+
+impl InnerAction for DurationInputPromptAction {
+    type Config = DurationInputConfig;
+
+    fn from_key(key: Key, _config: &DurationInputConfig) -> Option<Self> {
+        let action = match InputAction::from_key(key, &()) {
+            Some(action) => Self::ValueInput(action),
+            None => return None,
+        };
+
+        Some(action)
+    }
+}
@@ -0,0 +1,10 @@
+use crate::DurationInput;
+
+/// Configuration settings used in the execution of a DurationInputPrompt.
+pub struct DurationInputConfig {}
+
+impl From<&DurationInput<'_>> for DurationInputConfig {
+    fn from(_value: &DurationInput<'_>) -> Self {
+        Self {}
+    }
+}
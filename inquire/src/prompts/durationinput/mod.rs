@@ -0,0 +1,269 @@
+mod action;
+mod config;
+mod parse;
+mod prompt;
+#[cfg(test)]
+mod test;
+
+pub use action::*;
+
+use std::time::Duration;
+
+use crate::{
+    config::get_configuration,
+    error::{InquireError, InquireResult},
+    formatter::CustomTypeFormatter,
+    prompts::prompt::Prompt,
+    terminal::get_default_terminal,
+    ui::{Backend, DurationInputBackend, RenderConfig},
+    validator::CustomTypeValidator,
+};
+
+use self::prompt::DurationInputPrompt;
+
+/// Prompt that parses a human-friendly duration, such as `1h30m`, `90s` or `2d`, into a [`Duration`].
+///
+/// The input is a sequence of integer amounts, each one followed by a unit. The accepted units are days (`d`), hours (`h`), minutes (`m`), seconds (`s`) and milliseconds (`ms`), along with their long forms such as `hours` or `min`. Whitespace between the components is ignored, so `1h 30m` and `1h30m` are equivalent.
+///
+/// While the user types, the prompt displays below the input a normalized preview of the parsed duration, e.g. `1h 30m` for an input of `90m`, or the reason why the current input can not be parsed.
+///
+/// Duration inputs provide several options of configuration:
+///
+/// - **Prompt message**: Required when creating the prompt.
+/// - **Starting input**: Initial value of the prompt's text input.
+/// - **Default value**: Default value returned when the user submits an empty response.
+/// - **Placeholder**: Short hint that describes the expected value of the input.
+/// - **Help message**: Message displayed at the line below the prompt.
+/// - **Formatter**: Custom formatter in case you need to pre-process the user input before showing it as the final answer.
+///   - Prints the normalized duration by default, e.g. `1h 30m`.
+/// - **Validators**: Custom validators to the parsed duration, displaying an error message if the value does not pass the requirements.
+///
+/// # Example
+///
+/// ```no_run
+/// use std::time::Duration;
+/// use inquire::{validator::Validation, DurationInput};
+///
+/// let timeout = DurationInput::new("Request timeout:")
+///     .with_default(Duration::from_secs(30))
+///     .with_validator(|val: &Duration| match val.as_secs() {
+///         0 => Ok(Validation::Invalid("The timeout must be at least one second".into())),
+///         _ => Ok(Validation::Valid),
+///     })
+///     .prompt();
+///
+/// match timeout {
+///     Ok(timeout) => println!("Timing out after {} seconds", timeout.as_secs()),
+///     Err(_) => println!("Could not read the timeout"),
+/// }
+/// ```
+#[derive(Clone)]
+pub struct DurationInput<'a> {
+    /// Message to be presented to the user.
+    pub message: &'a str,
+
+    /// Initial value of the prompt's text input.
+    ///
+    /// If you want to set a default value for the prompt, returned when the user's submission is empty, see [`default`].
+    ///
+    /// [`default`]: Self::default
+    pub starting_input: Option<&'a str>,
+
+    /// Default value, returned when the user input is empty.
+    pub default: Option<Duration>,
+
+    /// Short hint that describes the expected value of the input.
+    pub placeholder: Option<&'a str>,
+
+    /// Help message to be presented to the user.
+    pub help_message: Option<&'a str>,
+
+    /// Function that formats the user input and presents it to the user as the final rendering of the prompt.
+    pub formatter: CustomTypeFormatter<'a, Duration>,
+
+    /// Function that formats the default value when it is displayed next to the prompt message.
+    pub default_value_formatter: CustomTypeFormatter<'a, Duration>,
+
+    /// Collection of validators to apply to the parsed duration.
+    ///
+    /// Validators are executed in the order they are stored, stopping at and displaying to the user
+    /// only the first validation error that might appear.
+    ///
+    /// The possible error is displayed to the user one line above the prompt.
+    pub validators: Vec<Box<dyn CustomTypeValidator<Duration>>>,
+
+    /// RenderConfig to apply to the rendered interface.
+    ///
+    /// Note: The default render config considers if the NO_COLOR environment variable
+    /// is set to decide whether to render the colored config or the empty one.
+    ///
+    /// When overriding the config in a prompt, NO_COLOR is no longer considered and your
+    /// config is treated as the only source of truth. If you want to customize colors
+    /// and still support NO_COLOR, you will have to do this on your end.
+    pub render_config: RenderConfig<'a>,
+}
+
+impl<'a> DurationInput<'a> {
+    /// Default formatter, which prints the duration in its normalized form,
+    /// with the components equal to zero omitted.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::time::Duration;
+    /// use inquire::DurationInput;
+    ///
+    /// let formatter = DurationInput::DEFAULT_FORMATTER;
+    /// assert_eq!(String::from("1h 30m"), formatter(Duration::from_secs(5400)));
+    /// assert_eq!(String::from("2d 1s"), formatter(Duration::from_secs(172_801)));
+    /// assert_eq!(String::from("0s"), formatter(Duration::ZERO));
+    /// ```
+    pub const DEFAULT_FORMATTER: CustomTypeFormatter<'a, Duration> = &parse::format_duration;
+
+    /// Default validators added to the [DurationInput] prompt, none.
+    pub const DEFAULT_VALIDATORS: Vec<Box<dyn CustomTypeValidator<Duration>>> = vec![];
+
+    /// Default help message.
+    pub const DEFAULT_HELP_MESSAGE: Option<&'a str> = Some("e.g. 1h30m, 90s or 2d");
+
+    /// Creates a [DurationInput] with the provided message, along with default configuration values.
+    pub fn new(message: &'a str) -> Self {
+        Self {
+            message,
+            starting_input: None,
+            default: None,
+            placeholder: None,
+            help_message: Self::DEFAULT_HELP_MESSAGE,
+            formatter: Self::DEFAULT_FORMATTER,
+            default_value_formatter: Self::DEFAULT_FORMATTER,
+            validators: Self::DEFAULT_VALIDATORS,
+            render_config: get_configuration(),
+        }
+    }
+
+    /// Sets the initial value of the prompt's text input.
+    ///
+    /// If you want to set a default value for the prompt, returned when the user's submission is empty, see [`with_default`].
+    ///
+    /// [`with_default`]: Self::with_default
+    pub fn with_starting_input(mut self, message: &'a str) -> Self {
+        self.starting_input = Some(message);
+        self
+    }
+
+    /// Sets the default input.
+    pub fn with_default(mut self, default: Duration) -> Self {
+        self.default = Some(default);
+        self
+    }
+
+    /// Sets the placeholder.
+    pub fn with_placeholder(mut self, placeholder: &'a str) -> Self {
+        self.placeholder = Some(placeholder);
+        self
+    }
+
+    /// Sets the help message of the prompt.
+    pub fn with_help_message(mut self, message: &'a str) -> Self {
+        self.help_message = Some(message);
+        self
+    }
+
+    /// Removes the set help message.
+    pub fn without_help_message(mut self) -> Self {
+        self.help_message = None;
+        self
+    }
+
+    /// Sets the formatter.
+    pub fn with_formatter(mut self, formatter: CustomTypeFormatter<'a, Duration>) -> Self {
+        self.formatter = formatter;
+        self
+    }
+
+    /// Sets the formatter for default values.
+    pub fn with_default_value_formatter(
+        mut self,
+        formatter: CustomTypeFormatter<'a, Duration>,
+    ) -> Self {
+        self.default_value_formatter = formatter;
+        self
+    }
+
+    /// Adds a validator to the collection of validators. You might want to use this feature
+    /// in case you need to require certain features from the parsed duration, such as bounds.
+    ///
+    /// Validators are executed in the order they are stored, stopping at and displaying to the user
+    /// only the first validation error that might appear.
+    ///
+    /// The possible error is displayed to the user one line above the prompt.
+    pub fn with_validator<V>(mut self, validator: V) -> Self
+    where
+        V: CustomTypeValidator<Duration> + 'static,
+    {
+        self.validators.push(Box::new(validator));
+        self
+    }
+
+    /// Adds the validators to the collection of validators in the order they are given.
+    ///
+    /// Validators are executed in the order they are stored, stopping at and displaying to the user
+    /// only the first validation error that might appear.
+    ///
+    /// The possible error is displayed to the user one line above the prompt.
+    pub fn with_validators(
+        mut self,
+        validators: &[Box<dyn CustomTypeValidator<Duration>>],
+    ) -> Self {
+        for validator in validators {
+            #[allow(suspicious_double_ref_op)]
+            self.validators.push(validator.clone());
+        }
+        self
+    }
+
+    /// Sets the provided color theme to this prompt.
+    ///
+    /// Note: The default render config considers if the NO_COLOR environment variable
+    /// is set to decide whether to render the colored config or the empty one.
+    ///
+    /// When overriding the config in a prompt, NO_COLOR is no longer considered and your
+    /// config is treated as the only source of truth. If you want to customize colors
+    /// and still support NO_COLOR, you will have to do this on your end.
+    pub fn with_render_config(mut self, render_config: RenderConfig<'a>) -> Self {
+        self.render_config = render_config;
+        self
+    }
+
+    /// Parses the provided behavioral and rendering options and prompts
+    /// the CLI user for input according to the defined rules.
+    ///
+    /// This method is intended for flows where the user skipping/cancelling
+    /// the prompt - by pressing ESC - is considered normal behavior. In this case,
+    /// it does not return `Err(InquireError::OperationCanceled)`, but `Ok(None)`.
+    ///
+    /// Meanwhile, if the user does submit an answer, the method wraps the return
+    /// type with `Some`.
+    pub fn prompt_skippable(self) -> InquireResult<Option<Duration>> {
+        match self.prompt() {
+            Ok(answer) => Ok(Some(answer)),
+            Err(InquireError::OperationCanceled) => Ok(None),
+            Err(err) => Err(err),
+        }
+    }
+
+    /// Parses the provided behavioral and rendering options and prompts
+    /// the CLI user for input according to the defined rules.
+    pub fn prompt(self) -> InquireResult<Duration> {
+        let (input_reader, terminal) = get_default_terminal()?;
+        let mut backend = Backend::new(input_reader, terminal, self.render_config)?;
+        self.prompt_with_backend(&mut backend)
+    }
+
+    pub(crate) fn prompt_with_backend<B: DurationInputBackend>(
+        self,
+        backend: &mut B,
+    ) -> InquireResult<Duration> {
+        DurationInputPrompt::from(self).prompt(backend)
+    }
+}
@@ -0,0 +1,124 @@
+use std::time::Duration;
+
+const UNITS: &[(&[&str], u64)] = &[
+    (&["d", "day", "days"], 86_400_000),
+    (&["h", "hr", "hrs", "hour", "hours"], 3_600_000),
+    (&["m", "min", "mins", "minute", "minutes"], 60_000),
+    (&["s", "sec", "secs", "second", "seconds"], 1_000),
+    (&["ms", "msec", "msecs", "millisecond", "milliseconds"], 1),
+];
+
+/// Parses a human-friendly duration such as `1h30m`, `90s` or `2d 4h`.
+///
+/// The input is a sequence of integer amounts, each one followed by a unit.
+/// Whitespace between the components is ignored.
+pub(crate) fn parse_duration(input: &str) -> Result<Duration, String> {
+    let mut rest = input.trim();
+
+    if rest.is_empty() {
+        return Err(String::from("Empty duration"));
+    }
+
+    let mut total_millis: u64 = 0;
+
+    while !rest.is_empty() {
+        let digits_len = rest
+            .find(|c: char| !c.is_ascii_digit())
+            .unwrap_or(rest.len());
+
+        if digits_len == 0 {
+            return Err(format!("Expected a number at '{rest}'"));
+        }
+
+        let (digits, tail) = rest.split_at(digits_len);
+        let tail = tail.trim_start();
+
+        let unit_len = tail
+            .find(|c: char| !c.is_alphabetic())
+            .unwrap_or(tail.len());
+        let (unit, tail) = tail.split_at(unit_len);
+
+        if unit.is_empty() {
+            return Err(format!("Missing unit after {digits}, e.g. {digits}s"));
+        }
+
+        let multiplier = UNITS
+            .iter()
+            .find(|(names, _)| names.contains(&unit.to_lowercase().as_str()))
+            .map(|(_, multiplier)| *multiplier)
+            .ok_or_else(|| format!("Unknown unit '{unit}', use d, h, m, s or ms"))?;
+
+        total_millis = digits
+            .parse::<u64>()
+            .ok()
+            .and_then(|amount| amount.checked_mul(multiplier))
+            .and_then(|millis| total_millis.checked_add(millis))
+            .ok_or_else(|| String::from("Duration is too long"))?;
+
+        rest = tail.trim_start();
+    }
+
+    Ok(Duration::from_millis(total_millis))
+}
+
+/// Formats a duration in its normalized form, e.g. `1h 30m`.
+///
+/// Components are written from days down to milliseconds, omitting the ones
+/// equal to zero. Sub-millisecond precision is discarded.
+pub(crate) fn format_duration(duration: Duration) -> String {
+    let mut millis = duration.as_millis();
+
+    let components = UNITS
+        .iter()
+        .filter_map(|(names, multiplier)| {
+            let multiplier = u128::from(*multiplier);
+            let amount = millis / multiplier;
+            millis %= multiplier;
+
+            match amount {
+                0 => None,
+                amount => Some(format!("{}{}", amount, names[0])),
+            }
+        })
+        .collect::<Vec<String>>();
+
+    if components.is_empty() {
+        String::from("0s")
+    } else {
+        components.join(" ")
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::time::Duration;
+
+    use super::{format_duration, parse_duration};
+
+    #[test]
+    fn parses_compound_durations() {
+        assert_eq!(Ok(Duration::from_secs(5400)), parse_duration("1h30m"));
+        assert_eq!(Ok(Duration::from_secs(90)), parse_duration("90s"));
+        assert_eq!(Ok(Duration::from_secs(172_800)), parse_duration("2d"));
+        assert_eq!(Ok(Duration::from_millis(1500)), parse_duration("1s 500ms"));
+        assert_eq!(Ok(Duration::from_secs(300)), parse_duration(" 5 Minutes "));
+    }
+
+    #[test]
+    fn rejects_malformed_durations() {
+        assert!(parse_duration("").is_err());
+        assert!(parse_duration("90").is_err());
+        assert!(parse_duration("1.5h").is_err());
+        assert!(parse_duration("3y").is_err());
+        assert!(parse_duration("h").is_err());
+        assert!(parse_duration("99999999999999999999d").is_err());
+    }
+
+    #[test]
+    fn formats_normalized_durations() {
+        assert_eq!("1h 30m", format_duration(Duration::from_secs(5400)));
+        assert_eq!("1d 1s", format_duration(Duration::from_secs(86_401)));
+        assert_eq!("250ms", format_duration(Duration::from_millis(250)));
+        assert_eq!("0s", format_duration(Duration::ZERO));
+    }
+}
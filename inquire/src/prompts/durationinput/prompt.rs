@@ -0,0 +1,145 @@
+use std::time::Duration;
+
+use crate::{
+    error::InquireResult,
+    formatter::CustomTypeFormatter,
+    input::Input,
+    prompts::prompt::{ActionResult, Prompt},
+    ui::DurationInputBackend,
+    validator::{CustomTypeValidator, ErrorMessage, Validation},
+    DurationInput, InquireError,
+};
+
+use super::{
+    action::DurationInputPromptAction,
+    config::DurationInputConfig,
+    parse::{format_duration, parse_duration},
+};
+
+pub struct DurationInputPrompt<'a> {
+    message: &'a str,
+    config: DurationInputConfig,
+    error: Option<ErrorMessage>,
+    help_message: Option<&'a str>,
+    default: Option<Duration>,
+    input: Input,
+    formatter: CustomTypeFormatter<'a, Duration>,
+    default_value_formatter: CustomTypeFormatter<'a, Duration>,
+    validators: Vec<Box<dyn CustomTypeValidator<Duration>>>,
+}
+
+impl<'a> From<DurationInput<'a>> for DurationInputPrompt<'a> {
+    fn from(so: DurationInput<'a>) -> Self {
+        let input = Input::new_with(so.starting_input.unwrap_or_default());
+        let input = if let Some(placeholder) = so.placeholder {
+            input.with_placeholder(placeholder)
+        } else {
+            input
+        };
+
+        Self {
+            message: so.message,
+            config: (&so).into(),
+            error: None,
+            default: so.default,
+            help_message: so.help_message,
+            formatter: so.formatter,
+            default_value_formatter: so.default_value_formatter,
+            validators: so.validators,
+            input,
+        }
+    }
+}
+
+impl<'a> DurationInputPrompt<'a> {
+    fn validate_current_answer(&self, value: &Duration) -> InquireResult<Validation> {
+        for validator in &self.validators {
+            match validator.validate(value) {
+                Ok(Validation::Valid) => {}
+                Ok(Validation::Invalid(msg)) => return Ok(Validation::Invalid(msg)),
+                Err(err) => return Err(InquireError::Custom(err)),
+            }
+        }
+
+        Ok(Validation::Valid)
+    }
+
+    fn get_final_answer(&self) -> Result<Duration, String> {
+        match self.default {
+            Some(val) if self.input.content().is_empty() => Ok(val),
+            _ => parse_duration(self.input.content()),
+        }
+    }
+}
+
+impl<'a, Backend> Prompt<Backend> for DurationInputPrompt<'a>
+where
+    Backend: DurationInputBackend,
+{
+    type Config = DurationInputConfig;
+    type InnerAction = DurationInputPromptAction;
+    type Output = Duration;
+
+    fn message(&self) -> &str {
+        self.message
+    }
+
+    fn config(&self) -> &DurationInputConfig {
+        &self.config
+    }
+
+    fn format_answer(&self, answer: &Duration) -> String {
+        (self.formatter)(*answer)
+    }
+
+    fn submit(&mut self) -> InquireResult<Option<Duration>> {
+        let answer = match self.get_final_answer() {
+            Ok(answer) => match self.validate_current_answer(&answer)? {
+                Validation::Valid => Some(answer),
+                Validation::Invalid(msg) => {
+                    self.error = Some(msg);
+                    None
+                }
+            },
+            Err(message) => {
+                self.error = Some(message.into());
+                None
+            }
+        };
+
+        Ok(answer)
+    }
+
+    fn handle(&mut self, action: DurationInputPromptAction) -> InquireResult<ActionResult> {
+        let result = match action {
+            DurationInputPromptAction::ValueInput(input_action) => {
+                self.input.handle(input_action).into()
+            }
+        };
+
+        Ok(result)
+    }
+
+    fn render(&self, backend: &mut Backend) -> InquireResult<()> {
+        let prompt = &self.message;
+
+        if let Some(error) = &self.error {
+            backend.render_error_message(error)?;
+        }
+
+        let default_message = self.default.map(self.default_value_formatter);
+
+        backend.render_prompt(prompt, default_message.as_deref(), &self.input)?;
+
+        if !self.input.content().trim().is_empty() {
+            let preview = parse_duration(self.input.content()).map(format_duration);
+            backend.render_duration_preview(preview.as_deref().map_err(String::as_str))?;
+        }
+
+        if let Some(message) = self.help_message {
+            backend.render_help_message(message)?;
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,126 @@
+use std::time::Duration;
+
+use crate::{
+    error::InquireResult,
+    ui::{
+        test::{FakeBackend, Token},
+        Key,
+    },
+    validator::{ErrorMessage, Validation},
+    DurationInput,
+};
+
+#[test]
+fn typed_duration_is_parsed() -> InquireResult<()> {
+    let mut keys = Key::char_keys_from_str("1h30m");
+    keys.push(Key::Enter);
+    let mut backend = FakeBackend::new(keys);
+
+    let ans = DurationInput::new("Question").prompt_with_backend(&mut backend)?;
+
+    assert_eq!(Duration::from_secs(5400), ans);
+    assert!(backend
+        .frames()
+        .last()
+        .unwrap()
+        .has_token(&Token::AnsweredPrompt("Question".into(), "1h 30m".into())));
+
+    Ok(())
+}
+
+#[test]
+fn preview_shows_normalized_duration() -> InquireResult<()> {
+    let mut keys = Key::char_keys_from_str("90m");
+    keys.push(Key::Enter);
+    let mut backend = FakeBackend::new(keys);
+
+    DurationInput::new("Question").prompt_with_backend(&mut backend)?;
+
+    assert!(!backend.frames()[0]
+        .tokens()
+        .iter()
+        .any(|token| matches!(token, Token::DurationPreview(_))));
+    assert!(backend.frames()[2].has_token(&Token::DurationPreview(Err(
+        "Missing unit after 90, e.g. 90s".into()
+    ))));
+    assert!(backend.frames()[3].has_token(&Token::DurationPreview(Ok("1h 30m".into()))));
+
+    Ok(())
+}
+
+#[test]
+fn invalid_duration_is_not_submitted() -> InquireResult<()> {
+    let mut keys = Key::char_keys_from_str("3y");
+    keys.push(Key::Enter);
+    keys.push(Key::Backspace);
+    keys.push(Key::Backspace);
+    keys.extend(Key::char_keys_from_str("2d"));
+    keys.push(Key::Enter);
+    let mut backend = FakeBackend::new(keys);
+
+    let ans = DurationInput::new("Question").prompt_with_backend(&mut backend)?;
+
+    assert_eq!(Duration::from_secs(172_800), ans);
+    assert!(
+        backend.frames()[3].has_token(&Token::ErrorMessage(ErrorMessage::Custom(
+            "Unknown unit 'y', use d, h, m, s or ms".into()
+        )))
+    );
+
+    Ok(())
+}
+
+#[test]
+fn empty_input_returns_default() -> InquireResult<()> {
+    let mut backend = FakeBackend::new(vec![Key::Enter]);
+
+    let ans = DurationInput::new("Question")
+        .with_default(Duration::from_secs(30))
+        .prompt_with_backend(&mut backend)?;
+
+    assert_eq!(Duration::from_secs(30), ans);
+    assert!(backend.frames()[0].has_token(&Token::DefaultValue("30s".into())));
+
+    Ok(())
+}
+
+#[test]
+fn empty_input_without_default_is_rejected() -> InquireResult<()> {
+    let mut keys = vec![Key::Enter];
+    keys.extend(Key::char_keys_from_str("5s"));
+    keys.push(Key::Enter);
+    let mut backend = FakeBackend::new(keys);
+
+    let ans = DurationInput::new("Question").prompt_with_backend(&mut backend)?;
+
+    assert_eq!(Duration::from_secs(5), ans);
+    assert!(
+        backend.frames()[1].has_token(&Token::ErrorMessage(ErrorMessage::Custom(
+            "Empty duration".into()
+        )))
+    );
+
+    Ok(())
+}
+
+#[test]
+fn validators_receive_parsed_duration() -> InquireResult<()> {
+    let mut keys = Key::char_keys_from_str("2h");
+    keys.push(Key::Enter);
+    keys.push(Key::Backspace);
+    keys.push(Key::Backspace);
+    keys.extend(Key::char_keys_from_str("45m"));
+    keys.push(Key::Enter);
+    let mut backend = FakeBackend::new(keys);
+
+    let ans = DurationInput::new("Question")
+        .with_validator(|val: &Duration| match val.as_secs() {
+            s if s > 3600 => Ok(Validation::Invalid("At most one hour".into())),
+            _ => Ok(Validation::Valid),
+        })
+        .prompt_with_backend(&mut backend)?;
+
+    assert_eq!(Duration::from_secs(2700), ans);
+
+    Ok(())
+}
@@ -0,0 +1,23 @@
+use crate::{ui::Key, InnerAction, InputAction};
+
+use super::config::EmailConfig;
+
+/// Set of actions for an EmailPrompt.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum EmailPromptAction {
+    /// Action on the value text input handler.
+    ValueInput(InputAction),
+}
+
+impl InnerAction for EmailPromptAction {
+    type Config = EmailConfig;
+
+    fn from_key(key: Key, _config: &EmailConfig) -> Option<Self> {
+        let action = match InputAction::from_key(key, &()) {
+            Some(action) => Self::ValueInput(action),
+            None => return None,
+        };
+
+        Some(action)
+    }
+}
@@ -0,0 +1,102 @@
+/// Maximum length of an address, as limited by the path of SMTP commands.
+const MAX_ADDRESS_LENGTH: usize = 254;
+const MAX_LOCAL_PART_LENGTH: usize = 64;
+const MAX_LABEL_LENGTH: usize = 63;
+
+/// Characters allowed in the local part besides ASCII letters, digits and dots.
+const LOCAL_PART_SPECIALS: &str = "!#$%&'*+/=?^_`{|}~-";
+
+/// Checks an address in the `local@domain` form, where the local part is a
+/// dot-separated sequence of atoms and the domain is a host name with at least
+/// two labels.
+///
+/// Quoted local parts, comments and IP address literals are valid according to
+/// RFC 5322, but are rejected here as they are almost never what the user meant.
+pub(super) fn check_address(address: &str) -> Result<(), String> {
+    let (local_part, domain) = match address.rsplit_once('@') {
+        Some(parts) => parts,
+        None => return Err("Email address must contain an @".into()),
+    };
+
+    if address.chars().count() > MAX_ADDRESS_LENGTH {
+        return Err(format!(
+            "Email address must have at most {} characters",
+            MAX_ADDRESS_LENGTH
+        ));
+    }
+
+    check_local_part(local_part)?;
+    check_domain(domain)
+}
+
+fn check_local_part(local_part: &str) -> Result<(), String> {
+    if local_part.is_empty() {
+        return Err("Email address must have a name before the @".into());
+    }
+
+    if local_part.len() > MAX_LOCAL_PART_LENGTH {
+        return Err(format!(
+            "The name before the @ must have at most {} characters",
+            MAX_LOCAL_PART_LENGTH
+        ));
+    }
+
+    if let Some(c) = local_part
+        .chars()
+        .find(|c| !c.is_ascii_alphanumeric() && *c != '.' && !LOCAL_PART_SPECIALS.contains(*c))
+    {
+        return Err(format!("Email address can not contain '{}'", c));
+    }
+
+    if local_part.starts_with('.') || local_part.ends_with('.') || local_part.contains("..") {
+        return Err("Dots in the name before the @ must be between other characters".into());
+    }
+
+    Ok(())
+}
+
+fn check_domain(domain: &str) -> Result<(), String> {
+    if domain.is_empty() {
+        return Err("Email address must have a domain after the @".into());
+    }
+
+    let labels = domain.split('.').collect::<Vec<&str>>();
+
+    if labels.len() < 2 {
+        return Err(format!(
+            "The domain {} must have a dot, e.g. {}.com",
+            domain, domain
+        ));
+    }
+
+    for label in &labels {
+        if label.is_empty() {
+            return Err("Dots in the domain must be between other characters".into());
+        }
+
+        if label.chars().count() > MAX_LABEL_LENGTH {
+            return Err(format!(
+                "Each part of the domain must have at most {} characters",
+                MAX_LABEL_LENGTH
+            ));
+        }
+
+        if let Some(c) = label.chars().find(|c| !c.is_alphanumeric() && *c != '-') {
+            return Err(format!("The domain can not contain '{}'", c));
+        }
+
+        if label.starts_with('-') || label.ends_with('-') {
+            return Err("Hyphens in the domain must be between other characters".into());
+        }
+    }
+
+    if labels
+        .last()
+        .map(|tld| tld.chars().all(|c| c.is_ascii_digit()))
+        .unwrap_or_default()
+    {
+        return Err(format!("The domain {} must end with a name", domain));
+    }
+
+    Ok(())
+}
@@ -0,0 +1,10 @@
+use crate::Email;
+
+/// Configuration settings used in the execution of an EmailPrompt.
+pub struct EmailConfig {}
+
+impl From<&Email<'_>> for EmailConfig {
+    fn from(_value: &Email<'_>) -> Self {
+        Self {}
+    }
+}
@@ -0,0 +1,257 @@
+mod action;
+mod address;
+mod config;
+mod prompt;
+#[cfg(test)]
+mod test;
+
+pub use action::*;
+
+use crate::{
+    config::get_configuration,
+    error::{InquireError, InquireResult},
+    formatter::StringFormatter,
+    prompts::prompt::Prompt,
+    terminal::get_default_terminal,
+    ui::{Backend, CustomTypeBackend, RenderConfig},
+    validator::StringValidator,
+};
+
+use self::prompt::EmailPrompt;
+
+/// Prompt suitable for email addresses, such as `jane.doe@example.com`.
+///
+/// The input is checked when the user submits it, rejecting addresses that are not in the `name@domain` form, with a clear error message about what is wrong. The check follows the rules of RFC 5322 for the common forms of addresses, rejecting the rare ones, such as quoted names or IP address domains, as they are almost never what the user meant.
+///
+/// Leading and trailing whitespace is always removed and, by default, the address is lowercased before being checked and returned. The prompt can also be restricted to a list of allowed domains, such as the ones of your organization.
+///
+/// Email prompts provide several options of configuration:
+///
+/// - **Prompt message**: Required when creating the prompt.
+/// - **Starting input**: Initial value of the prompt's text input.
+/// - **Default value**: Default value returned when the user submits an empty response.
+/// - **Placeholder**: Short hint that describes the expected value of the input.
+/// - **Help message**: Message displayed at the line below the prompt.
+/// - **Lowercase**: Whether the address is lowercased, true by default.
+/// - **Allowed domains**: Domains the address must belong to, any domain by default.
+/// - **Formatter**: Custom formatter in case you need to pre-process the user input before showing it as the final answer.
+///   - Prints the normalized address by default.
+/// - **Validators**: Custom validators to the normalized address, displaying an error message if it does not pass the requirements.
+///
+/// # Example
+///
+/// ```no_run
+/// use inquire::Email;
+///
+/// let ans = Email::new("Work email:")
+///     .with_placeholder("jane.doe@acme.com")
+///     .with_allowed_domains(&["acme.com", "acme.org"])
+///     .prompt();
+///
+/// match ans {
+///     Ok(email) => println!("Sending the invitation to {}", email),
+///     Err(_) => println!("Could not read the email address"),
+/// }
+/// ```
+#[derive(Clone)]
+pub struct Email<'a> {
+    /// Message to be presented to the user.
+    pub message: &'a str,
+
+    /// Initial value of the prompt's text input.
+    ///
+    /// If you want to set a default value for the prompt, returned when the user's submission is empty, see [`default`].
+    ///
+    /// [`default`]: Self::default
+    pub starting_input: Option<&'a str>,
+
+    /// Default value, returned when the user input is empty.
+    pub default: Option<&'a str>,
+
+    /// Short hint that describes the expected value of the input.
+    pub placeholder: Option<&'a str>,
+
+    /// Help message to be presented to the user.
+    pub help_message: Option<&'a str>,
+
+    /// Whether the address is lowercased before being checked and returned.
+    pub lowercase: bool,
+
+    /// Domains the address must belong to, compared case-insensitively. When
+    /// empty, addresses of any domain are accepted.
+    pub allowed_domains: &'a [&'a str],
+
+    /// Function that formats the user input and presents it to the user as the final rendering of the prompt.
+    pub formatter: StringFormatter<'a>,
+
+    /// Collection of validators to apply to the normalized address.
+    ///
+    /// Validators are executed in the order they are stored, stopping at and displaying to the user
+    /// only the first validation error that might appear.
+    ///
+    /// The possible error is displayed to the user one line above the prompt.
+    pub validators: Vec<Box<dyn StringValidator>>,
+
+    /// RenderConfig to apply to the rendered interface.
+    ///
+    /// Note: The default render config considers if the NO_COLOR environment variable
+    /// is set to decide whether to render the colored config or the empty one.
+    ///
+    /// When overriding the config in a prompt, NO_COLOR is no longer considered and your
+    /// config is treated as the only source of truth. If you want to customize colors
+    /// and still support NO_COLOR, you will have to do this on your end.
+    pub render_config: RenderConfig<'a>,
+}
+
+impl<'a> Email<'a> {
+    /// Default formatter, which prints the normalized address.
+    pub const DEFAULT_FORMATTER: StringFormatter<'a> = &|ans| ans.to_owned();
+
+    /// Default value of whether the address is lowercased.
+    pub const DEFAULT_LOWERCASE: bool = true;
+
+    /// Default allowed domains, any domain is accepted.
+    pub const DEFAULT_ALLOWED_DOMAINS: &'static [&'static str] = &[];
+
+    /// Default validators added to the [Email] prompt, none.
+    pub const DEFAULT_VALIDATORS: Vec<Box<dyn StringValidator>> = vec![];
+
+    /// Creates an [Email] with the provided message, along with default configuration values.
+    pub fn new(message: &'a str) -> Self {
+        Self {
+            message,
+            starting_input: None,
+            default: None,
+            placeholder: None,
+            help_message: None,
+            lowercase: Self::DEFAULT_LOWERCASE,
+            allowed_domains: Self::DEFAULT_ALLOWED_DOMAINS,
+            formatter: Self::DEFAULT_FORMATTER,
+            validators: Self::DEFAULT_VALIDATORS,
+            render_config: get_configuration(),
+        }
+    }
+
+    /// Sets the initial value of the prompt's text input.
+    ///
+    /// If you want to set a default value for the prompt, returned when the user's submission is empty, see [`with_default`].
+    ///
+    /// [`with_default`]: Self::with_default
+    pub fn with_starting_input(mut self, message: &'a str) -> Self {
+        self.starting_input = Some(message);
+        self
+    }
+
+    /// Sets the default input.
+    pub fn with_default(mut self, default: &'a str) -> Self {
+        self.default = Some(default);
+        self
+    }
+
+    /// Sets the placeholder.
+    pub fn with_placeholder(mut self, placeholder: &'a str) -> Self {
+        self.placeholder = Some(placeholder);
+        self
+    }
+
+    /// Sets the help message of the prompt.
+    pub fn with_help_message(mut self, message: &'a str) -> Self {
+        self.help_message = Some(message);
+        self
+    }
+
+    /// Sets whether the address is lowercased before being checked and returned.
+    pub fn with_lowercase(mut self, lowercase: bool) -> Self {
+        self.lowercase = lowercase;
+        self
+    }
+
+    /// Sets the domains the address must belong to.
+    pub fn with_allowed_domains(mut self, domains: &'a [&'a str]) -> Self {
+        self.allowed_domains = domains;
+        self
+    }
+
+    /// Sets the formatter.
+    pub fn with_formatter(mut self, formatter: StringFormatter<'a>) -> Self {
+        self.formatter = formatter;
+        self
+    }
+
+    /// Adds a validator to the collection of validators. You might want to use this feature
+    /// in case you need to require certain features from the address, such as not being
+    /// already registered.
+    ///
+    /// Validators are executed in the order they are stored, stopping at and displaying to the user
+    /// only the first validation error that might appear.
+    ///
+    /// The possible error is displayed to the user one line above the prompt.
+    pub fn with_validator<V>(mut self, validator: V) -> Self
+    where
+        V: StringValidator + 'static,
+    {
+        self.validators.push(Box::new(validator));
+        self
+    }
+
+    /// Adds the validators to the collection of validators in the order they are given.
+    ///
+    /// Validators are executed in the order they are stored, stopping at and displaying to the user
+    /// only the first validation error that might appear.
+    ///
+    /// The possible error is displayed to the user one line above the prompt.
+    pub fn with_validators(mut self, validators: &[Box<dyn StringValidator>]) -> Self {
+        for validator in validators {
+            #[allow(suspicious_double_ref_op)]
+            self.validators.push(validator.clone());
+        }
+        self
+    }
+
+    /// Sets the provided color theme to this prompt.
+    ///
+    /// Note: The default render config considers if the NO_COLOR environment variable
+    /// is set to decide whether to render the colored config or the empty one.
+    ///
+    /// When overriding the config in a prompt, NO_COLOR is no longer considered and your
+    /// config is treated as the only source of truth. If you want to customize colors
+    /// and still support NO_COLOR, you will have to do this on your end.
+    pub fn with_render_config(mut self, render_config: RenderConfig<'a>) -> Self {
+        self.render_config = render_config;
+        self
+    }
+
+    /// Parses the provided behavioral and rendering options and prompts
+    /// the CLI user for input according to the defined rules.
+    ///
+    /// This method is intended for flows where the user skipping/cancelling
+    /// the prompt - by pressing ESC - is considered normal behavior. In this case,
+    /// it does not return `Err(InquireError::OperationCanceled)`, but `Ok(None)`.
+    ///
+    /// Meanwhile, if the user does submit an answer, the method wraps the return
+    /// type with `Some`.
+    pub fn prompt_skippable(self) -> InquireResult<Option<String>> {
+        match self.prompt() {
+            Ok(answer) => Ok(Some(answer)),
+            Err(InquireError::OperationCanceled) => Ok(None),
+            Err(err) => Err(err),
+        }
+    }
+
+    /// Parses the provided behavioral and rendering options and prompts
+    /// the CLI user for input according to the defined rules.
+    ///
+    /// Returns the normalized address.
+    pub fn prompt(self) -> InquireResult<String> {
+        let (input_reader, terminal) = get_default_terminal()?;
+        let mut backend = Backend::new(input_reader, terminal, self.render_config)?;
+        self.prompt_with_backend(&mut backend)
+    }
+
+    pub(crate) fn prompt_with_backend<B: CustomTypeBackend>(
+        self,
+        backend: &mut B,
+    ) -> InquireResult<String> {
+        EmailPrompt::from(self).prompt(backend)
+    }
+}
@@ -0,0 +1,162 @@
+use crate::{
+    error::InquireResult,
+    formatter::StringFormatter,
+    input::Input,
+    prompts::prompt::{ActionResult, Prompt},
+    ui::CustomTypeBackend,
+    validator::{ErrorMessage, StringValidator, Validation},
+    Email, InquireError,
+};
+
+use super::{action::EmailPromptAction, address::check_address, config::EmailConfig};
+
+pub struct EmailPrompt<'a> {
+    message: &'a str,
+    config: EmailConfig,
+    error: Option<ErrorMessage>,
+    help_message: Option<&'a str>,
+    default: Option<&'a str>,
+    input: Input,
+    lowercase: bool,
+    allowed_domains: &'a [&'a str],
+    formatter: StringFormatter<'a>,
+    validators: Vec<Box<dyn StringValidator>>,
+}
+
+impl<'a> From<Email<'a>> for EmailPrompt<'a> {
+    fn from(eo: Email<'a>) -> Self {
+        let input = Input::new_with(eo.starting_input.unwrap_or_default());
+        let input = if let Some(placeholder) = eo.placeholder {
+            input.with_placeholder(placeholder)
+        } else {
+            input
+        };
+
+        Self {
+            message: eo.message,
+            config: (&eo).into(),
+            error: None,
+            help_message: eo.help_message,
+            default: eo.default,
+            input,
+            lowercase: eo.lowercase,
+            allowed_domains: eo.allowed_domains,
+            formatter: eo.formatter,
+            validators: eo.validators,
+        }
+    }
+}
+
+impl<'a> EmailPrompt<'a> {
+    fn normalize(&self, input: &str) -> String {
+        let address = input.trim();
+
+        if self.lowercase {
+            address.to_lowercase()
+        } else {
+            address.to_owned()
+        }
+    }
+
+    fn check_domain(&self, address: &str) -> Result<(), String> {
+        if self.allowed_domains.is_empty() {
+            return Ok(());
+        }
+
+        let domain = address.rsplit('@').next().unwrap_or_default();
+
+        if self
+            .allowed_domains
+            .iter()
+            .any(|allowed| allowed.eq_ignore_ascii_case(domain))
+        {
+            Ok(())
+        } else {
+            Err(format!(
+                "Only addresses from {} are allowed",
+                self.allowed_domains.join(", ")
+            ))
+        }
+    }
+
+    fn validate_current_answer(&self, address: &str) -> InquireResult<Validation> {
+        if let Err(message) = check_address(address).and_then(|_| self.check_domain(address)) {
+            return Ok(Validation::Invalid(message.into()));
+        }
+
+        for validator in &self.validators {
+            match validator.validate(address) {
+                Ok(Validation::Valid) => {}
+                Ok(Validation::Invalid(msg)) => return Ok(Validation::Invalid(msg)),
+                Err(err) => return Err(InquireError::Custom(err)),
+            }
+        }
+
+        Ok(Validation::Valid)
+    }
+}
+
+impl<'a, Backend> Prompt<Backend> for EmailPrompt<'a>
+where
+    Backend: CustomTypeBackend,
+{
+    type Config = EmailConfig;
+    type InnerAction = EmailPromptAction;
+    type Output = String;
+
+    fn message(&self) -> &str {
+        self.message
+    }
+
+    fn config(&self) -> &EmailConfig {
+        &self.config
+    }
+
+    fn format_answer(&self, answer: &String) -> String {
+        (self.formatter)(answer)
+    }
+
+    fn submit(&mut self) -> InquireResult<Option<String>> {
+        if self.input.content().trim().is_empty() {
+            if let Some(default) = self.default {
+                return Ok(Some(default.to_owned()));
+            }
+        }
+
+        let address = self.normalize(self.input.content());
+
+        let answer = match self.validate_current_answer(&address)? {
+            Validation::Valid => Some(address),
+            Validation::Invalid(msg) => {
+                self.error = Some(msg);
+                None
+            }
+        };
+
+        Ok(answer)
+    }
+
+    fn handle(&mut self, action: EmailPromptAction) -> InquireResult<ActionResult> {
+        let result = match action {
+            EmailPromptAction::ValueInput(input_action) => self.input.handle(input_action).into(),
+        };
+
+        Ok(result)
+    }
+
+    fn render(&self, backend: &mut Backend) -> InquireResult<()> {
+        let prompt = &self.message;
+
+        if let Some(error) = &self.error {
+            backend.render_error_message(error)?;
+        }
+
+        backend.render_prompt(prompt, self.default, &self.input)?;
+
+        if let Some(message) = self.help_message {
+            backend.render_help_message(message)?;
+        }
+
+        Ok(())
+    }
+}
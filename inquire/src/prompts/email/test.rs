@@ -0,0 +1,186 @@
+use crate::{
+    error::InquireResult,
+    ui::{
+        test::{FakeBackend, Token},
+        Key,
+    },
+    validator::{ErrorMessage, Validation},
+    Email,
+};
+
+use super::address::check_address;
+
+fn keys(steps: &[&str]) -> Vec<Key> {
+    let mut keys = vec![];
+    for step in steps {
+        keys.extend(Key::char_keys_from_str(step));
+        keys.push(Key::Enter);
+    }
+    keys
+}
+
+fn clear(count: usize) -> Vec<Key> {
+    vec![Key::Backspace; count]
+}
+
+#[test]
+fn address_is_trimmed_and_lowercased() -> InquireResult<()> {
+    let mut backend = FakeBackend::new(keys(&["  Jane.Doe@Example.COM "]));
+
+    let ans = Email::new("Email").prompt_with_backend(&mut backend)?;
+
+    assert_eq!("jane.doe@example.com", ans);
+    assert!(backend
+        .frames()
+        .last()
+        .unwrap()
+        .has_token(&Token::AnsweredPrompt(
+            "Email".into(),
+            "jane.doe@example.com".into()
+        )));
+
+    let mut backend = FakeBackend::new(keys(&["Jane.Doe@Example.COM"]));
+
+    let ans = Email::new("Email")
+        .with_lowercase(false)
+        .prompt_with_backend(&mut backend)?;
+
+    assert_eq!("Jane.Doe@Example.COM", ans);
+
+    Ok(())
+}
+
+#[test]
+fn invalid_address_is_rejected_on_submit() -> InquireResult<()> {
+    let mut input = keys(&["jane"]);
+    input.extend(keys(&["@example.com"]));
+    let mut backend = FakeBackend::new(input);
+
+    let ans = Email::new("Email").prompt_with_backend(&mut backend)?;
+
+    assert_eq!("jane@example.com", ans);
+    assert!(
+        backend.frames()[5].has_token(&Token::ErrorMessage(ErrorMessage::Custom(
+            "Email address must contain an @".into()
+        )))
+    );
+
+    Ok(())
+}
+
+#[test]
+fn empty_input_returns_the_default() -> InquireResult<()> {
+    let mut backend = FakeBackend::new(vec![Key::Enter]);
+
+    let ans = Email::new("Email")
+        .with_default("jane@example.com")
+        .prompt_with_backend(&mut backend)?;
+
+    assert_eq!("jane@example.com", ans);
+    assert!(backend.frames()[0].has_token(&Token::DefaultValue("jane@example.com".into())));
+
+    Ok(())
+}
+
+#[test]
+fn only_allowed_domains_are_accepted() -> InquireResult<()> {
+    let mut input = keys(&["jane@example.com"]);
+    input.extend(clear(11));
+    input.extend(keys(&["ACME.org"]));
+    let mut backend = FakeBackend::new(input);
+
+    let ans = Email::new("Email")
+        .with_lowercase(false)
+        .with_allowed_domains(&["acme.com", "acme.org"])
+        .prompt_with_backend(&mut backend)?;
+
+    assert_eq!("jane@ACME.org", ans);
+    assert!(
+        backend.frames()[17].has_token(&Token::ErrorMessage(ErrorMessage::Custom(
+            "Only addresses from acme.com, acme.org are allowed".into()
+        )))
+    );
+
+    Ok(())
+}
+
+#[test]
+fn validators_receive_the_normalized_address() -> InquireResult<()> {
+    let mut input = keys(&["Admin@example.com"]);
+    input.extend(clear(17));
+    input.extend(keys(&["jane@example.com"]));
+    let mut backend = FakeBackend::new(input);
+
+    let ans = Email::new("Email")
+        .with_validator(|address: &str| {
+            if address.starts_with("admin@") {
+                Ok(Validation::Invalid("This address is reserved".into()))
+            } else {
+                Ok(Validation::Valid)
+            }
+        })
+        .prompt_with_backend(&mut backend)?;
+
+    assert_eq!("jane@example.com", ans);
+    assert!(backend
+        .frames()
+        .iter()
+        .any(
+            |frame| frame.has_token(&Token::ErrorMessage(ErrorMessage::Custom(
+                "This address is reserved".into()
+            )))
+        ));
+
+    Ok(())
+}
+
+#[test]
+fn addresses_are_checked() {
+    for valid in [
+        "jane@example.com",
+        "jane.doe+news@mail.example.co.uk",
+        "o'brien@example.ie",
+        "user@xn--bcher-kva.example",
+        "user@bücher.example",
+    ] {
+        assert_eq!(Ok(()), check_address(valid), "{}", valid);
+    }
+
+    for (invalid, error) in [
+        ("jane", "Email address must contain an @"),
+        (
+            "@example.com",
+            "Email address must have a name before the @",
+        ),
+        ("jane@", "Email address must have a domain after the @"),
+        ("jane doe@example.com", "Email address can not contain ' '"),
+        (
+            "jane..doe@example.com",
+            "Dots in the name before the @ must be between other characters",
+        ),
+        (
+            "jane@localhost",
+            "The domain localhost must have a dot, e.g. localhost.com",
+        ),
+        (
+            "jane@example..com",
+            "Dots in the domain must be between other characters",
+        ),
+        ("jane@exa_mple.com", "The domain can not contain '_'"),
+        (
+            "jane@-example.com",
+            "Hyphens in the domain must be between other characters",
+        ),
+        (
+            "jane@127.0.0.1",
+            "The domain 127.0.0.1 must end with a name",
+        ),
+    ] {
+        assert_eq!(
+            Err(String::from(error)),
+            check_address(invalid),
+            "{}",
+            invalid
+        );
+    }
+}
@@ -0,0 +1,342 @@
+use std::{
+    cmp::Ordering,
+    fmt::Display,
+    fs, io,
+    path::{Path, PathBuf},
+};
+
+use crate::{
+    input::{Input, InputActionResult, LineDirection},
+    list_option::ListOption,
+    prompts::prompt::ActionResult,
+    InputAction,
+};
+
+/// Kind of an entry listed by a [PathBrowser].
+#[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub(crate) enum EntryKind {
+    /// The current directory itself.
+    Current,
+    /// Parent of the current directory.
+    Parent,
+    /// Path that does not exist yet, named after the current filter input.
+    New,
+    /// Sub-directory of the current directory.
+    Directory,
+    /// File inside the current directory.
+    File,
+}
+
+/// Entry listed by a [PathBrowser].
+#[derive(Clone, Debug)]
+pub(crate) struct PathEntry {
+    pub name: String,
+    pub path: PathBuf,
+    pub kind: EntryKind,
+}
+
+impl Display for PathEntry {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self.kind {
+            EntryKind::Current => write!(f, "./"),
+            EntryKind::Parent => write!(f, "../"),
+            EntryKind::New => write!(f, "{} (new)", self.name),
+            EntryKind::Directory => write!(f, "{}/", self.name),
+            EntryKind::File => write!(f, "{}", self.name),
+        }
+    }
+}
+
+/// Rules that define which entries are listed by a [PathBrowser].
+#[derive(Clone, Debug, Default)]
+pub(crate) struct PathBrowserOptions {
+    /// Whether entries whose names start with a dot are listed.
+    pub show_hidden: bool,
+    /// Extensions, without the leading dot, of the files that are listed.
+    /// All files are listed when empty.
+    pub extensions: Vec<String>,
+    /// Whether to list a new entry named after the filter input when no
+    /// existing entry has the exact same name.
+    pub allow_new: bool,
+    /// Whether files are left out of the listing.
+    pub directories_only: bool,
+    /// Whether to list an entry for the current directory itself.
+    pub list_current: bool,
+}
+
+/// Navigable listing of a directory, used by prompts that let the user pick
+/// a path from the filesystem.
+///
+/// Entries are filtered by the user input, directories are listed before
+/// files and both are sorted by name.
+pub(crate) struct PathBrowser {
+    directory: PathBuf,
+    options: PathBrowserOptions,
+    entries: Vec<PathEntry>,
+    filtered_entries: Vec<usize>,
+    cursor_index: usize,
+    filter: Input,
+}
+
+impl PathBrowser {
+    pub fn new(directory: PathBuf, options: PathBrowserOptions) -> io::Result<Self> {
+        let mut browser = Self {
+            directory: PathBuf::new(),
+            options,
+            entries: vec![],
+            filtered_entries: vec![],
+            cursor_index: 0,
+            filter: Input::new(),
+        };
+
+        browser.change_directory(directory)?;
+
+        Ok(browser)
+    }
+
+    pub fn directory(&self) -> &Path {
+        &self.directory
+    }
+
+    pub fn filter(&self) -> &Input {
+        &self.filter
+    }
+
+    /// Entry currently highlighted by the cursor, if any.
+    pub fn highlighted(&self) -> Option<&PathEntry> {
+        self.filtered_entries
+            .get(self.cursor_index)
+            .and_then(|idx| self.entries.get(*idx))
+    }
+
+    pub fn cursor_index(&self) -> usize {
+        self.cursor_index
+    }
+
+    /// Entries that match the current filter, in the order they are displayed.
+    pub fn visible_entries(&self) -> Vec<ListOption<&PathEntry>> {
+        self.filtered_entries
+            .iter()
+            .map(|idx| ListOption::new(*idx, &self.entries[*idx]))
+            .collect()
+    }
+
+    /// Lists the entries of the given directory, clearing the filter input.
+    ///
+    /// On failure, the browser is kept at the previous directory.
+    pub fn change_directory(&mut self, directory: PathBuf) -> io::Result<()> {
+        let mut entries = vec![];
+
+        if self.options.list_current {
+            entries.push(PathEntry {
+                name: String::from("."),
+                path: directory.clone(),
+                kind: EntryKind::Current,
+            });
+        }
+
+        if let Some(parent) = directory.parent() {
+            entries.push(PathEntry {
+                name: String::from(".."),
+                path: parent.to_path_buf(),
+                kind: EntryKind::Parent,
+            });
+        }
+
+        for dir_entry in fs::read_dir(&directory)? {
+            let dir_entry = dir_entry?;
+            let name = dir_entry.file_name().to_string_lossy().to_string();
+
+            if !self.options.show_hidden && name.starts_with('.') {
+                continue;
+            }
+
+            let path = dir_entry.path();
+            let kind = match path.is_dir() {
+                true => EntryKind::Directory,
+                false => EntryKind::File,
+            };
+
+            if kind == EntryKind::File
+                && (self.options.directories_only || !self.has_listed_extension(&path))
+            {
+                continue;
+            }
+
+            entries.push(PathEntry { name, path, kind });
+        }
+
+        entries.sort_by(compare_entries);
+
+        self.directory = directory;
+        self.entries = entries;
+        self.filter = Input::new();
+        self.cursor_index = 0;
+        self.run_filter();
+
+        Ok(())
+    }
+
+    /// Creates the directory of the highlighted new entry, returning its path.
+    pub fn create_highlighted(&mut self) -> io::Result<Option<PathBuf>> {
+        match self.highlighted() {
+            Some(entry) if entry.kind == EntryKind::New => {
+                let path = entry.path.clone();
+                fs::create_dir(&path)?;
+                Ok(Some(path))
+            }
+            _ => Ok(None),
+        }
+    }
+
+    /// Moves to the highlighted entry when it is a directory.
+    pub fn enter_highlighted(&mut self) -> io::Result<ActionResult> {
+        match self.highlighted() {
+            Some(entry) if matches!(entry.kind, EntryKind::Parent | EntryKind::Directory) => {
+                let path = entry.path.clone();
+                self.change_directory(path)?;
+                Ok(ActionResult::NeedsRedraw)
+            }
+            _ => Ok(ActionResult::Clean),
+        }
+    }
+
+    /// Moves to the parent of the current directory, highlighting the
+    /// directory the user just left.
+    pub fn go_to_parent(&mut self) -> io::Result<ActionResult> {
+        let (current, parent) = match self.directory.parent() {
+            Some(parent) => (self.directory.clone(), parent.to_path_buf()),
+            None => return Ok(ActionResult::Clean),
+        };
+
+        self.change_directory(parent)?;
+
+        if let Some(position) = self
+            .filtered_entries
+            .iter()
+            .position(|idx| self.entries[*idx].path == current)
+        {
+            self.cursor_index = position;
+        }
+
+        Ok(ActionResult::NeedsRedraw)
+    }
+
+    /// Applies the action to the filter input. Deleting backwards on an empty
+    /// filter moves to the parent directory.
+    pub fn handle_filter(&mut self, action: InputAction) -> io::Result<ActionResult> {
+        if self.filter.is_empty() && matches!(action, InputAction::Delete(_, LineDirection::Left)) {
+            return self.go_to_parent();
+        }
+
+        let result = self.filter.handle(action);
+
+        if let InputActionResult::ContentChanged = result {
+            self.cursor_index = 0;
+            self.run_filter();
+        }
+
+        Ok(result.into())
+    }
+
+    pub fn move_cursor_up(&mut self, qty: usize, wrap: bool) -> ActionResult {
+        let new_position = if wrap {
+            let after_wrap = qty.saturating_sub(self.cursor_index);
+            self.cursor_index
+                .checked_sub(qty)
+                .unwrap_or_else(|| self.filtered_entries.len().saturating_sub(after_wrap))
+        } else {
+            self.cursor_index.saturating_sub(qty)
+        };
+
+        self.update_cursor_position(new_position)
+    }
+
+    pub fn move_cursor_down(&mut self, qty: usize, wrap: bool) -> ActionResult {
+        let mut new_position = self.cursor_index.saturating_add(qty);
+
+        if new_position >= self.filtered_entries.len() {
+            new_position = if self.filtered_entries.is_empty() {
+                0
+            } else if wrap {
+                new_position % self.filtered_entries.len()
+            } else {
+                self.filtered_entries.len().saturating_sub(1)
+            }
+        }
+
+        self.update_cursor_position(new_position)
+    }
+
+    fn update_cursor_position(&mut self, new_position: usize) -> ActionResult {
+        if new_position != self.cursor_index {
+            self.cursor_index = new_position;
+            ActionResult::NeedsRedraw
+        } else {
+            ActionResult::Clean
+        }
+    }
+
+    fn has_listed_extension(&self, path: &Path) -> bool {
+        if self.options.extensions.is_empty() {
+            return true;
+        }
+
+        let extension = match path.extension() {
+            Some(extension) => extension.to_string_lossy().to_lowercase(),
+            None => return false,
+        };
+
+        self.options
+            .extensions
+            .iter()
+            .any(|e| e.trim_start_matches('.').to_lowercase() == extension)
+    }
+
+    fn run_filter(&mut self) {
+        self.entries.retain(|entry| entry.kind != EntryKind::New);
+
+        let filter = self.filter.content().to_lowercase();
+
+        if self.options.allow_new
+            && !filter.is_empty()
+            && !self.entries.iter().any(|e| e.name == self.filter.content())
+        {
+            let name = self.filter.content().to_string();
+
+            self.entries.insert(
+                0,
+                PathEntry {
+                    path: self.directory.join(&name),
+                    name,
+                    kind: EntryKind::New,
+                },
+            );
+        }
+
+        self.filtered_entries = self
+            .entries
+            .iter()
+            .enumerate()
+            .filter(|(_, entry)| match entry.kind {
+                EntryKind::Current | EntryKind::Parent => filter.is_empty(),
+                EntryKind::New => true,
+                EntryKind::Directory | EntryKind::File => {
+                    entry.name.to_lowercase().contains(&filter)
+                }
+            })
+            .map(|(idx, _)| idx)
+            .collect();
+
+        if self.filtered_entries.len() <= self.cursor_index {
+            self.cursor_index = self.filtered_entries.len().saturating_sub(1);
+        }
+    }
+}
+
+fn compare_entries(a: &PathEntry, b: &PathEntry) -> Ordering {
+    a.kind
+        .cmp(&b.kind)
+        .then_with(|| a.name.to_lowercase().cmp(&b.name.to_lowercase()))
+        .then_with(|| a.name.cmp(&b.name))
+}
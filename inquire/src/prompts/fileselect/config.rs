@@ -0,0 +1,19 @@
+use crate::FileSelect;
+
+/// Configuration settings used in the execution of a FileSelectPrompt.
+#[derive(Copy, Clone, Debug)]
+pub struct FileSelectConfig {
+    /// Whether to use vim-style keybindings.
+    pub vim_mode: bool,
+    /// Page size of the list of entries.
+    pub page_size: usize,
+}
+
+impl From<&FileSelect<'_>> for FileSelectConfig {
+    fn from(value: &FileSelect<'_>) -> Self {
+        Self {
+            vim_mode: value.vim_mode,
+            page_size: value.page_size,
+        }
+    }
+}
@@ -0,0 +1,264 @@
+mod action;
+mod browser;
+mod config;
+mod prompt;
+#[cfg(test)]
+#[cfg(feature = "crossterm")]
+mod test;
+
+pub use action::*;
+pub(crate) use browser::{EntryKind, PathBrowser, PathBrowserOptions};
+
+use std::path::{Path, PathBuf};
+
+use crate::{
+    config::get_configuration,
+    error::{InquireError, InquireResult},
+    formatter::PathFormatter,
+    prompts::prompt::Prompt,
+    terminal::get_default_terminal,
+    ui::{Backend, FileSelectBackend, RenderConfig},
+};
+
+use self::prompt::FileSelectPrompt;
+
+/// Defines whether a [`FileSelect`] prompt only accepts files that already exist.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub enum FileSelectMode {
+    /// Only files listed in the browsed directories can be selected.
+    #[default]
+    MustExist,
+
+    /// Besides existing files, the user can type the name of a file that does
+    /// not exist yet and select it, e.g. when choosing where to save a file.
+    ///
+    /// The prompt does not create the file, it only returns its path.
+    MayCreate,
+}
+
+/// Prompt that lets the user browse the filesystem and select a file.
+///
+/// The prompt lists the contents of the current directory, with directories displayed before files. The user can enter the highlighted directory by pressing enter or the right arrow, and go to the parent directory by pressing the left arrow or backspace on an empty filter. Typing filters the entries of the current directory.
+///
+/// The selected path is returned as a [`PathBuf`].
+///
+/// File selects provide several options of configuration:
+///
+/// - **Prompt message**: Required when creating the prompt.
+/// - **Starting path**: Directory listed when the prompt is first rendered, the current working directory by default.
+/// - **Extensions**: Only files with one of these extensions are listed. All files are listed by default.
+/// - **Show hidden**: Whether files and directories whose names start with a dot are listed, false by default.
+/// - **Mode**: Whether the user can only select existing files or may also type the name of a new one. See [`FileSelectMode`].
+/// - **Help message**: Message displayed at the line below the prompt.
+/// - **Formatter**: Custom formatter in case you need to pre-process the user input before showing it as the final answer.
+///   - Prints the full path by default.
+/// - **Page size**: Number of entries displayed at once, 7 by default.
+///
+/// If the starting path could not be read, the prompt fails with an [`InquireError::IO`] error.
+///
+/// # Example
+///
+/// ```no_run
+/// use inquire::FileSelect;
+///
+/// let config = FileSelect::new("Which config file should be loaded?")
+///     .with_extensions(&["toml", "yaml"])
+///     .prompt();
+///
+/// match config {
+///     Ok(path) => println!("Loading {}", path.display()),
+///     Err(_) => println!("There was an error, please try again"),
+/// }
+/// ```
+///
+/// [`InquireError::IO`]: crate::error::InquireError::IO
+#[derive(Clone)]
+pub struct FileSelect<'a> {
+    /// Message to be presented to the user.
+    pub message: &'a str,
+
+    /// Directory listed when the prompt is first rendered.
+    ///
+    /// When `None`, the current working directory is used.
+    pub starting_path: Option<&'a Path>,
+
+    /// Extensions of the files listed to the user. All files are listed when empty.
+    pub extensions: Vec<&'a str>,
+
+    /// Whether to list files and directories whose names start with a dot.
+    pub show_hidden: bool,
+
+    /// Whether the user can only select existing files or may also type the name of a new one.
+    pub mode: FileSelectMode,
+
+    /// Help message to be presented to the user.
+    pub help_message: Option<&'a str>,
+
+    /// Page size of the entries displayed to the user.
+    pub page_size: usize,
+
+    /// Whether vim mode is enabled. When enabled, the user can
+    /// navigate through the entries using jk.
+    pub vim_mode: bool,
+
+    /// Function that formats the selected path and presents it to the user as the final rendering of the prompt.
+    pub formatter: PathFormatter<'a>,
+
+    /// RenderConfig to apply to the rendered interface.
+    ///
+    /// Note: The default render config considers if the NO_COLOR environment variable
+    /// is set to decide whether to render the colored config or the empty one.
+    ///
+    /// When overriding the config in a prompt, NO_COLOR is no longer considered and your
+    /// config is treated as the only source of truth. If you want to customize colors
+    /// and still support NO_COLOR, you will have to do this on your end.
+    pub render_config: RenderConfig<'a>,
+}
+
+impl<'a> FileSelect<'a> {
+    /// Default formatter, which prints the full path of the selected file.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::path::Path;
+    /// use inquire::FileSelect;
+    ///
+    /// let formatter = FileSelect::DEFAULT_FORMATTER;
+    /// assert_eq!(String::from("/tmp/notes.txt"), formatter(Path::new("/tmp/notes.txt")));
+    /// ```
+    pub const DEFAULT_FORMATTER: PathFormatter<'a> = &|path| path.display().to_string();
+
+    /// Default page size.
+    pub const DEFAULT_PAGE_SIZE: usize = crate::config::DEFAULT_PAGE_SIZE;
+
+    /// Default value of vim mode.
+    pub const DEFAULT_VIM_MODE: bool = crate::config::DEFAULT_VIM_MODE;
+
+    /// Default value of whether hidden entries are listed.
+    pub const DEFAULT_SHOW_HIDDEN: bool = false;
+
+    /// Default help message.
+    pub const DEFAULT_HELP_MESSAGE: Option<&'a str> =
+        Some("↑↓ to move, → to enter a directory, ← to go up, type to filter");
+
+    /// Creates a [FileSelect] with the provided message, along with default configuration values.
+    pub fn new(message: &'a str) -> Self {
+        Self {
+            message,
+            starting_path: None,
+            extensions: vec![],
+            show_hidden: Self::DEFAULT_SHOW_HIDDEN,
+            mode: FileSelectMode::default(),
+            help_message: Self::DEFAULT_HELP_MESSAGE,
+            page_size: Self::DEFAULT_PAGE_SIZE,
+            vim_mode: Self::DEFAULT_VIM_MODE,
+            formatter: Self::DEFAULT_FORMATTER,
+            render_config: get_configuration(),
+        }
+    }
+
+    /// Sets the directory listed when the prompt is first rendered.
+    pub fn with_starting_path<P>(mut self, path: &'a P) -> Self
+    where
+        P: AsRef<Path> + ?Sized,
+    {
+        self.starting_path = Some(path.as_ref());
+        self
+    }
+
+    /// Sets the extensions of the files listed to the user, e.g. `&["rs", "toml"]`.
+    ///
+    /// Extensions are compared case-insensitively and may optionally start with a dot.
+    pub fn with_extensions(mut self, extensions: &[&'a str]) -> Self {
+        self.extensions = extensions.to_vec();
+        self
+    }
+
+    /// Sets whether files and directories whose names start with a dot are listed.
+    pub fn with_show_hidden(mut self, show_hidden: bool) -> Self {
+        self.show_hidden = show_hidden;
+        self
+    }
+
+    /// Sets whether the user can only select existing files or may also type the name of a new one.
+    pub fn with_mode(mut self, mode: FileSelectMode) -> Self {
+        self.mode = mode;
+        self
+    }
+
+    /// Sets the help message of the prompt.
+    pub fn with_help_message(mut self, message: &'a str) -> Self {
+        self.help_message = Some(message);
+        self
+    }
+
+    /// Removes the set help message.
+    pub fn without_help_message(mut self) -> Self {
+        self.help_message = None;
+        self
+    }
+
+    /// Sets the page size.
+    pub fn with_page_size(mut self, page_size: usize) -> Self {
+        self.page_size = page_size;
+        self
+    }
+
+    /// Enables or disables vim_mode.
+    pub fn with_vim_mode(mut self, vim_mode: bool) -> Self {
+        self.vim_mode = vim_mode;
+        self
+    }
+
+    /// Sets the formatter.
+    pub fn with_formatter(mut self, formatter: PathFormatter<'a>) -> Self {
+        self.formatter = formatter;
+        self
+    }
+
+    /// Sets the provided color theme to this prompt.
+    ///
+    /// Note: The default render config considers if the NO_COLOR environment variable
+    /// is set to decide whether to render the colored config or the empty one.
+    ///
+    /// When overriding the config in a prompt, NO_COLOR is no longer considered and your
+    /// config is treated as the only source of truth. If you want to customize colors
+    /// and still support NO_COLOR, you will have to do this on your end.
+    pub fn with_render_config(mut self, render_config: RenderConfig<'a>) -> Self {
+        self.render_config = render_config;
+        self
+    }
+
+    /// Parses the provided behavioral and rendering options and prompts
+    /// the CLI user for input according to the defined rules.
+    ///
+    /// This method is intended for flows where the user skipping/cancelling
+    /// the prompt - by pressing ESC - is considered normal behavior. In this case,
+    /// it does not return `Err(InquireError::OperationCanceled)`, but `Ok(None)`.
+    ///
+    /// Meanwhile, if the user does submit an answer, the method wraps the return
+    /// type with `Some`.
+    pub fn prompt_skippable(self) -> InquireResult<Option<PathBuf>> {
+        match self.prompt() {
+            Ok(answer) => Ok(Some(answer)),
+            Err(InquireError::OperationCanceled) => Ok(None),
+            Err(err) => Err(err),
+        }
+    }
+
+    /// Parses the provided behavioral and rendering options and prompts
+    /// the CLI user for input according to the defined rules.
+    pub fn prompt(self) -> InquireResult<PathBuf> {
+        let (input_reader, terminal) = get_default_terminal()?;
+        let mut backend = Backend::new(input_reader, terminal, self.render_config)?;
+        self.prompt_with_backend(&mut backend)
+    }
+
+    pub(crate) fn prompt_with_backend<B: FileSelectBackend>(
+        self,
+        backend: &mut B,
+    ) -> InquireResult<PathBuf> {
+        FileSelectPrompt::new(self)?.prompt(backend)
+    }
+}
@@ -0,0 +1,164 @@
+use std::{env, fs, io, path::PathBuf};
+
+use crate::{
+    error::InquireResult,
+    formatter::PathFormatter,
+    prompts::prompt::{ActionResult, Prompt},
+    ui::FileSelectBackend,
+    utils::paginate,
+    validator::ErrorMessage,
+    FileSelect, FileSelectMode,
+};
+
+use super::{
+    action::FileSelectPromptAction,
+    browser::{EntryKind, PathBrowser, PathBrowserOptions},
+    config::FileSelectConfig,
+};
+
+pub struct FileSelectPrompt<'a> {
+    message: &'a str,
+    config: FileSelectConfig,
+    browser: PathBrowser,
+    help_message: Option<&'a str>,
+    formatter: PathFormatter<'a>,
+    error: Option<ErrorMessage>,
+}
+
+impl<'a> FileSelectPrompt<'a> {
+    pub fn new(fo: FileSelect<'a>) -> InquireResult<Self> {
+        let starting_path = match fo.starting_path {
+            Some(path) => path.to_path_buf(),
+            None => env::current_dir()?,
+        };
+
+        let options = PathBrowserOptions {
+            show_hidden: fo.show_hidden,
+            extensions: fo.extensions.iter().map(|e| String::from(*e)).collect(),
+            allow_new: fo.mode == FileSelectMode::MayCreate,
+            directories_only: false,
+            list_current: false,
+        };
+
+        let browser = PathBrowser::new(fs::canonicalize(starting_path)?, options)?;
+
+        Ok(Self {
+            message: fo.message,
+            config: (&fo).into(),
+            browser,
+            help_message: fo.help_message,
+            formatter: fo.formatter,
+            error: None,
+        })
+    }
+
+    /// Converts the result of a navigation in the filesystem, displaying
+    /// an error message to the user when the directory could not be read.
+    fn navigation_result(&mut self, result: io::Result<ActionResult>) -> ActionResult {
+        match result {
+            Ok(ActionResult::Clean) => ActionResult::Clean,
+            Ok(ActionResult::NeedsRedraw) => {
+                self.error = None;
+                ActionResult::NeedsRedraw
+            }
+            Err(err) => {
+                self.error = Some(ErrorMessage::Custom(format!(
+                    "Could not read directory: {err}"
+                )));
+                ActionResult::NeedsRedraw
+            }
+        }
+    }
+}
+
+impl<'a, Backend> Prompt<Backend> for FileSelectPrompt<'a>
+where
+    Backend: FileSelectBackend,
+{
+    type Config = FileSelectConfig;
+    type InnerAction = FileSelectPromptAction;
+    type Output = PathBuf;
+
+    fn message(&self) -> &str {
+        self.message
+    }
+
+    fn config(&self) -> &FileSelectConfig {
+        &self.config
+    }
+
+    fn format_answer(&self, answer: &PathBuf) -> String {
+        (self.formatter)(answer)
+    }
+
+    fn submit(&mut self) -> InquireResult<Option<PathBuf>> {
+        let entry = match self.browser.highlighted() {
+            Some(entry) => entry,
+            None => return Ok(None),
+        };
+
+        match entry.kind {
+            EntryKind::File | EntryKind::New => Ok(Some(entry.path.clone())),
+            EntryKind::Current | EntryKind::Parent | EntryKind::Directory => {
+                let result = self.browser.enter_highlighted();
+                self.navigation_result(result);
+                Ok(None)
+            }
+        }
+    }
+
+    fn handle(&mut self, action: FileSelectPromptAction) -> InquireResult<ActionResult> {
+        let result = match action {
+            FileSelectPromptAction::MoveUp => self.browser.move_cursor_up(1, true),
+            FileSelectPromptAction::MoveDown => self.browser.move_cursor_down(1, true),
+            FileSelectPromptAction::PageUp => {
+                self.browser.move_cursor_up(self.config.page_size, false)
+            }
+            FileSelectPromptAction::PageDown => {
+                self.browser.move_cursor_down(self.config.page_size, false)
+            }
+            FileSelectPromptAction::MoveToStart => self.browser.move_cursor_up(usize::MAX, false),
+            FileSelectPromptAction::MoveToEnd => self.browser.move_cursor_down(usize::MAX, false),
+            FileSelectPromptAction::EnterDirectory => {
+                let result = self.browser.enter_highlighted();
+                self.navigation_result(result)
+            }
+            FileSelectPromptAction::GoToParent => {
+                let result = self.browser.go_to_parent();
+                self.navigation_result(result)
+            }
+            FileSelectPromptAction::FilterInput(input_action) => {
+                let result = self.browser.handle_filter(input_action);
+                self.navigation_result(result)
+            }
+        };
+
+        Ok(result)
+    }
+
+    fn render(&self, backend: &mut Backend) -> InquireResult<()> {
+        let prompt = &self.message;
+
+        if let Some(err) = &self.error {
+            backend.render_error_message(err)?;
+        }
+
+        backend.render_select_prompt(prompt, Some(self.browser.filter()))?;
+        backend.render_current_directory(self.browser.directory())?;
+
+        let entries = self.browser.visible_entries();
+        let page = paginate(
+            self.config.page_size,
+            &entries,
+            Some(self.browser.cursor_index()),
+        );
+
+        backend.render_options(page)?;
+
+        if let Some(help_message) = self.help_message {
+            backend.render_help_message(help_message)?;
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,205 @@
+use std::{
+    env, fs,
+    path::{Path, PathBuf},
+};
+
+use crate::{
+    test::fake_backend,
+    ui::{Key, KeyModifiers},
+    FileSelect, FileSelectMode,
+};
+
+/// Directory tree created under the system's temporary directory, removed on drop.
+///
+/// ```text
+/// .hidden
+/// Cargo.toml
+/// README.md
+/// docs/guide.md
+/// src/lib.rs
+/// src/main.rs
+/// ```
+struct TestTree(PathBuf);
+
+impl TestTree {
+    fn new(name: &str) -> Self {
+        let root = env::temp_dir().join(format!(
+            "inquire-fileselect-{}-{}",
+            name,
+            std::process::id()
+        ));
+        let _ = fs::remove_dir_all(&root);
+
+        fs::create_dir_all(root.join("docs")).unwrap();
+        fs::create_dir_all(root.join("src")).unwrap();
+        for file in [
+            ".hidden",
+            "Cargo.toml",
+            "README.md",
+            "docs/guide.md",
+            "src/lib.rs",
+            "src/main.rs",
+        ] {
+            fs::write(root.join(file), "").unwrap();
+        }
+
+        Self(fs::canonicalize(root).unwrap())
+    }
+
+    fn path(&self) -> &Path {
+        &self.0
+    }
+}
+
+impl Drop for TestTree {
+    fn drop(&mut self) {
+        let _ = fs::remove_dir_all(&self.0);
+    }
+}
+
+#[test]
+fn enter_opens_directories_and_selects_files() {
+    let tree = TestTree::new("enter");
+
+    // ../, docs/, src/, Cargo.toml, README.md
+    let mut backend = fake_backend(vec![
+        Key::Down(KeyModifiers::NONE),
+        Key::Down(KeyModifiers::NONE),
+        Key::Enter,
+        // ../, lib.rs, main.rs
+        Key::Down(KeyModifiers::NONE),
+        Key::Down(KeyModifiers::NONE),
+        Key::Enter,
+    ]);
+
+    let ans = FileSelect::new("Question")
+        .with_starting_path(tree.path())
+        .prompt_with_backend(&mut backend)
+        .unwrap();
+
+    assert_eq!(tree.path().join("src").join("main.rs"), ans);
+}
+
+#[test]
+fn typing_filters_entries() {
+    let tree = TestTree::new("filter");
+
+    let mut keys = Key::char_keys_from_str("read");
+    keys.push(Key::Enter);
+    let mut backend = fake_backend(keys);
+
+    let ans = FileSelect::new("Question")
+        .with_starting_path(tree.path())
+        .prompt_with_backend(&mut backend)
+        .unwrap();
+
+    assert_eq!(tree.path().join("README.md"), ans);
+}
+
+#[test]
+fn going_up_highlights_previous_directory() {
+    let tree = TestTree::new("parent");
+    let starting_path = tree.path().join("src");
+
+    let mut backend = fake_backend(vec![
+        Key::Left(KeyModifiers::NONE),
+        Key::Down(KeyModifiers::NONE),
+        Key::Enter,
+    ]);
+
+    let ans = FileSelect::new("Question")
+        .with_starting_path(&starting_path)
+        .prompt_with_backend(&mut backend)
+        .unwrap();
+
+    assert_eq!(tree.path().join("Cargo.toml"), ans);
+}
+
+#[test]
+fn backspace_on_empty_filter_goes_up() {
+    let tree = TestTree::new("backspace");
+    let starting_path = tree.path().join("docs");
+
+    let mut keys = Key::char_keys_from_str("g");
+    keys.push(Key::Backspace);
+    keys.push(Key::Backspace);
+    keys.extend(Key::char_keys_from_str("cargo"));
+    keys.push(Key::Enter);
+    let mut backend = fake_backend(keys);
+
+    let ans = FileSelect::new("Question")
+        .with_starting_path(&starting_path)
+        .prompt_with_backend(&mut backend)
+        .unwrap();
+
+    assert_eq!(tree.path().join("Cargo.toml"), ans);
+}
+
+#[test]
+fn only_files_with_listed_extensions_are_displayed() {
+    let tree = TestTree::new("extensions");
+
+    // ../, docs/, src/, README.md
+    let mut backend = fake_backend(vec![Key::End, Key::Enter]);
+
+    let ans = FileSelect::new("Question")
+        .with_starting_path(tree.path())
+        .with_extensions(&[".MD"])
+        .prompt_with_backend(&mut backend)
+        .unwrap();
+
+    assert_eq!(tree.path().join("README.md"), ans);
+}
+
+#[test]
+fn hidden_files_are_listed_when_enabled() {
+    let tree = TestTree::new("hidden");
+
+    let mut keys = Key::char_keys_from_str("hid");
+    keys.push(Key::Enter);
+    let mut backend = fake_backend(keys);
+
+    let ans = FileSelect::new("Question")
+        .with_starting_path(tree.path())
+        .with_show_hidden(true)
+        .prompt_with_backend(&mut backend)
+        .unwrap();
+
+    assert_eq!(tree.path().join(".hidden"), ans);
+}
+
+#[test]
+fn new_files_can_be_selected_when_allowed() {
+    let tree = TestTree::new("new");
+
+    let mut keys = Key::char_keys_from_str("notes.txt");
+    keys.push(Key::Enter);
+    let mut backend = fake_backend(keys);
+
+    let ans = FileSelect::new("Question")
+        .with_starting_path(tree.path())
+        .with_mode(FileSelectMode::MayCreate)
+        .prompt_with_backend(&mut backend)
+        .unwrap();
+
+    assert_eq!(tree.path().join("notes.txt"), ans);
+    assert!(!ans.exists());
+}
+
+#[test]
+fn new_files_can_not_be_selected_by_default() {
+    let tree = TestTree::new("must-exist");
+
+    let mut keys = Key::char_keys_from_str("cargo.tomlx");
+    keys.push(Key::Enter);
+    keys.push(Key::Backspace);
+    keys.push(Key::Enter);
+    let mut backend = fake_backend(keys);
+
+    let ans = FileSelect::new("Question")
+        .with_starting_path(tree.path())
+        .prompt_with_backend(&mut backend)
+        .unwrap();
+
+    assert_eq!(tree.path().join("Cargo.toml"), ans);
+}
@@ -0,0 +1,16 @@
+use crate::FuzzyFinder;
+
+/// Configuration settings used in the execution of a FuzzyFinderPrompt.
+#[derive(Copy, Clone, Debug)]
+pub struct FuzzyFinderConfig {
+    /// Maximum number of lines of the preview pane.
+    pub preview_height: usize,
+}
+
+impl<T> From<&FuzzyFinder<'_, T>> for FuzzyFinderConfig {
+    fn from(value: &FuzzyFinder<'_, T>) -> Self {
+        Self {
+            preview_height: value.preview_height,
+        }
+    }
+}
@@ -0,0 +1,310 @@
+mod action;
+mod config;
+mod prompt;
+#[cfg(test)]
+mod test;
+
+pub use action::*;
+use std::fmt::Display;
+
+use crate::{
+    config::get_configuration,
+    error::{InquireError, InquireResult},
+    formatter::OptionFormatter,
+    list_option::ListOption,
+    prompts::prompt::Prompt,
+    terminal::get_default_terminal,
+    type_aliases::{Previewer, Scorer},
+    ui::{Backend, FuzzyFinderBackend, RenderConfig},
+};
+
+use self::prompt::FuzzyFinderPrompt;
+
+#[cfg(feature = "fuzzy")]
+use fuzzy_matcher::{skim::SkimMatcherV2, FuzzyMatcher};
+#[cfg(feature = "fuzzy")]
+use once_cell::sync::Lazy;
+#[cfg(feature = "fuzzy")]
+static DEFAULT_MATCHER: Lazy<SkimMatcherV2> = Lazy::new(|| SkimMatcherV2::default().ignore_case());
+
+/// Prompt suitable for when you need the user to select one option among thousands, in the style of fzf.
+///
+/// Unlike [Select](crate::Select), this prompt takes over the whole terminal by switching to the alternate screen, using every available row to display the options that match the filter input. The characters of each option that match the filter are highlighted with the `option_match` style sheet of the [`RenderConfig`].
+///
+/// When a previewer is set, the content it generates for the highlighted option is displayed below the options, updated as the cursor moves. This allows for file or commit previews, for example.
+///
+/// Once the user submits or cancels the prompt, the main screen is restored and only the final answer is kept in the terminal history.
+///
+/// This prompt requires a prompt message and a **non-empty** `Vec` of options to be displayed to the user. The options can be of any type as long as they implement the `Display` trait.
+/// - If the list is empty, the prompt operation will fail with an `InquireError::InvalidConfiguration` error.
+///
+/// Fuzzy finder prompts provide several options of configuration:
+///
+/// - **Prompt message**: Required when creating the prompt.
+/// - **Options list**: Options displayed to the user. Must be **non-empty**.
+/// - **Starting filter input**: Sets the initial value of the filter section of the prompt.
+/// - **Help message**: Message displayed at the last line of the screen.
+/// - **Formatter**: Custom formatter in case you need to pre-process the user input before showing it as the final answer.
+///   - Prints the selected option string value by default.
+/// - **Scorer function**: Function that defines the order of options and if displayed as all.
+/// - **Previewer**: Function that generates the preview of the highlighted option.
+/// - **Preview height**: Maximum number of lines of the preview pane, 10 by default. The preview never takes more than half of the screen.
+///
+/// # Example
+///
+/// ```no_run
+/// use inquire::FuzzyFinder;
+///
+/// let files: Vec<String> = std::fs::read_dir(".")
+///     .unwrap()
+///     .filter_map(|entry| entry.ok())
+///     .map(|entry| entry.path().display().to_string())
+///     .collect();
+///
+/// let ans = FuzzyFinder::new("File:", files)
+///     .with_previewer(&|path| std::fs::read_to_string(path).unwrap_or_default())
+///     .prompt();
+///
+/// match ans {
+///     Ok(file) => println!("Opening {}", file),
+///     Err(_) => println!("No file was selected"),
+/// }
+/// ```
+#[derive(Clone)]
+pub struct FuzzyFinder<'a, T> {
+    /// Message to be presented to the user.
+    pub message: &'a str,
+
+    /// Options displayed to the user.
+    pub options: Vec<T>,
+
+    /// Help message to be presented to the user.
+    pub help_message: Option<&'a str>,
+
+    /// Starting filter input
+    pub starting_filter_input: Option<&'a str>,
+
+    /// Function called with the current user input to score the provided
+    /// options.
+    pub scorer: Scorer<'a, T>,
+
+    /// Function that formats the user input and presents it to the user as the final rendering of the prompt.
+    pub formatter: OptionFormatter<'a, T>,
+
+    /// Function that generates the preview of the highlighted option.
+    pub previewer: Option<Previewer<'a, T>>,
+
+    /// Maximum number of lines of the preview pane.
+    pub preview_height: usize,
+
+    /// RenderConfig to apply to the rendered interface.
+    ///
+    /// Note: The default render config considers if the NO_COLOR environment variable
+    /// is set to decide whether to render the colored config or the empty one.
+    ///
+    /// When overriding the config in a prompt, NO_COLOR is no longer considered and your
+    /// config is treated as the only source of truth. If you want to customize colors
+    /// and still support NO_COLOR, you will have to do this on your end.
+    pub render_config: RenderConfig<'a>,
+}
+
+impl<'a, T> FuzzyFinder<'a, T>
+where
+    T: Display,
+{
+    /// String formatter used by default in [FuzzyFinder](crate::FuzzyFinder) prompts.
+    /// Simply prints the string value contained in the selected option.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use inquire::list_option::ListOption;
+    /// use inquire::FuzzyFinder;
+    ///
+    /// let formatter = FuzzyFinder::<&str>::DEFAULT_FORMATTER;
+    /// assert_eq!(String::from("src/main.rs"), formatter(ListOption::new(0, &"src/main.rs")));
+    /// ```
+    pub const DEFAULT_FORMATTER: OptionFormatter<'a, T> = &|ans| ans.to_string();
+
+    /// Default scoring function, the same as the one used by [Select](crate::Select) prompts.
+    /// The return will be sorted in Descending order, leaving options with None as a score.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use inquire::FuzzyFinder;
+    ///
+    /// let scorer = FuzzyFinder::<&str>::DEFAULT_SCORER;
+    /// assert_eq!(None,     scorer("sa", &"New York",      "New York",      0));
+    /// assert_eq!(Some(49), scorer("sa", &"Sacramento",    "Sacramento",    1));
+    /// assert_eq!(Some(35), scorer("sa", &"Kansas",        "Kansas",        2));
+    /// ```
+    #[cfg(feature = "fuzzy")]
+    pub const DEFAULT_SCORER: Scorer<'a, T> =
+        &|input, _option, string_value, _idx| -> Option<i64> {
+            DEFAULT_MATCHER.fuzzy_match(string_value, input)
+        };
+
+    #[cfg(not(feature = "fuzzy"))]
+    pub const DEFAULT_SCORER: Scorer<'a, T> =
+        &|input, _option, string_value, _idx| -> Option<i64> {
+            let filter = input.to_lowercase();
+            match string_value.to_lowercase().contains(&filter) {
+                true => Some(0),
+                false => None,
+            }
+        };
+
+    /// Default maximum number of lines of the preview pane.
+    pub const DEFAULT_PREVIEW_HEIGHT: usize = 10;
+
+    /// Default help message.
+    pub const DEFAULT_HELP_MESSAGE: Option<&'a str> =
+        Some("↑↓ to move, enter to select, type to filter");
+
+    /// Creates a [FuzzyFinder] with the provided message and options, along with default configuration values.
+    pub fn new(message: &'a str, options: Vec<T>) -> Self {
+        Self {
+            message,
+            options,
+            help_message: Self::DEFAULT_HELP_MESSAGE,
+            starting_filter_input: None,
+            scorer: Self::DEFAULT_SCORER,
+            formatter: Self::DEFAULT_FORMATTER,
+            previewer: None,
+            preview_height: Self::DEFAULT_PREVIEW_HEIGHT,
+            render_config: get_configuration(),
+        }
+    }
+
+    /// Sets the help message of the prompt.
+    pub fn with_help_message(mut self, message: &'a str) -> Self {
+        self.help_message = Some(message);
+        self
+    }
+
+    /// Removes the set help message.
+    pub fn without_help_message(mut self) -> Self {
+        self.help_message = None;
+        self
+    }
+
+    /// Sets the starting filter input
+    pub fn with_starting_filter_input(mut self, starting_filter_input: &'a str) -> Self {
+        self.starting_filter_input = Some(starting_filter_input);
+        self
+    }
+
+    /// Sets the scoring function.
+    pub fn with_scorer(mut self, scorer: Scorer<'a, T>) -> Self {
+        self.scorer = scorer;
+        self
+    }
+
+    /// Sets the formatter.
+    pub fn with_formatter(mut self, formatter: OptionFormatter<'a, T>) -> Self {
+        self.formatter = formatter;
+        self
+    }
+
+    /// Sets the function that generates the preview of the highlighted option.
+    pub fn with_previewer(mut self, previewer: Previewer<'a, T>) -> Self {
+        self.previewer = Some(previewer);
+        self
+    }
+
+    /// Sets the maximum number of lines of the preview pane.
+    pub fn with_preview_height(mut self, preview_height: usize) -> Self {
+        self.preview_height = preview_height;
+        self
+    }
+
+    /// Sets the provided color theme to this prompt.
+    ///
+    /// Note: The default render config considers if the NO_COLOR environment variable
+    /// is set to decide whether to render the colored config or the empty one.
+    ///
+    /// When overriding the config in a prompt, NO_COLOR is no longer considered and your
+    /// config is treated as the only source of truth. If you want to customize colors
+    /// and still support NO_COLOR, you will have to do this on your end.
+    pub fn with_render_config(mut self, render_config: RenderConfig<'a>) -> Self {
+        self.render_config = render_config;
+        self
+    }
+
+    /// Parses the provided behavioral and rendering options and prompts
+    /// the CLI user for input according to the defined rules.
+    ///
+    /// Returns the owned object selected by the user.
+    pub fn prompt(self) -> InquireResult<T> {
+        self.raw_prompt().map(|op| op.value)
+    }
+
+    /// Parses the provided behavioral and rendering options and prompts
+    /// the CLI user for input according to the defined rules.
+    ///
+    /// This method is intended for flows where the user skipping/cancelling
+    /// the prompt - by pressing ESC - is considered normal behavior. In this case,
+    /// it does not return `Err(InquireError::OperationCanceled)`, but `Ok(None)`.
+    ///
+    /// Meanwhile, if the user does submit an answer, the method wraps the return
+    /// type with `Some`.
+    pub fn prompt_skippable(self) -> InquireResult<Option<T>> {
+        match self.prompt() {
+            Ok(answer) => Ok(Some(answer)),
+            Err(InquireError::OperationCanceled) => Ok(None),
+            Err(err) => Err(err),
+        }
+    }
+
+    /// Parses the provided behavioral and rendering options and prompts
+    /// the CLI user for input according to the defined rules.
+    ///
+    /// Returns a [`ListOption`](crate::list_option::ListOption) containing
+    /// the index of the selection and the owned object selected by the user.
+    pub fn raw_prompt(self) -> InquireResult<ListOption<T>> {
+        let (input_reader, terminal) = get_default_terminal()?;
+        let mut backend = Backend::new(input_reader, terminal, self.render_config)?;
+        self.prompt_with_backend(&mut backend)
+    }
+
+    pub(crate) fn prompt_with_backend<B: FuzzyFinderBackend>(
+        self,
+        backend: &mut B,
+    ) -> InquireResult<ListOption<T>> {
+        let prompt = FuzzyFinderPrompt::new(self)?;
+
+        backend.enter_full_screen()?;
+        prompt.prompt(backend)
+    }
+}
+
+/// Returns the indexes of the characters of `value` matched by `filter`,
+/// used to highlight them.
+#[cfg(feature = "fuzzy")]
+pub(super) fn matched_chars(filter: &str, value: &str) -> Vec<usize> {
+    DEFAULT_MATCHER
+        .fuzzy_indices(value, filter)
+        .map(|(_score, indices)| indices)
+        .unwrap_or_default()
+}
+
+/// Returns the indexes of the characters of `value` matched by `filter`,
+/// used to highlight them.
+#[cfg(not(feature = "fuzzy"))]
+pub(super) fn matched_chars(filter: &str, value: &str) -> Vec<usize> {
+    if filter.is_empty() {
+        return vec![];
+    }
+
+    let value = value.to_lowercase();
+    let filter = filter.to_lowercase();
+
+    match value.find(&filter) {
+        Some(start) => {
+            let start = value[..start].chars().count();
+            (start..start + filter.chars().count()).collect()
+        }
+        None => vec![],
+    }
+}
@@ -0,0 +1,265 @@
+use std::{
+    cell::Cell,
+    cmp::{min, Reverse},
+    fmt::Display,
+};
+
+use crate::{
+    error::InquireResult,
+    formatter::OptionFormatter,
+    input::{Input, InputActionResult},
+    list_option::ListOption,
+    prompts::prompt::{ActionResult, Prompt},
+    type_aliases::{Previewer, Scorer},
+    ui::FuzzyFinderBackend,
+    utils::paginate,
+    FuzzyFinder, InquireError,
+};
+
+use super::{action::FuzzyFinderPromptAction, config::FuzzyFinderConfig};
+
+pub struct FuzzyFinderPrompt<'a, T> {
+    message: &'a str,
+    config: FuzzyFinderConfig,
+    options: Vec<T>,
+    string_options: Vec<String>,
+    scored_options: Vec<usize>,
+    help_message: Option<&'a str>,
+    cursor_index: usize,
+    input: Input,
+    scorer: Scorer<'a, T>,
+    formatter: OptionFormatter<'a, T>,
+    previewer: Option<Previewer<'a, T>>,
+    // the page size depends on the screen height, which is only known
+    // when rendering, and is kept here for the page up/down actions
+    page_size: Cell<usize>,
+}
+
+impl<'a, T> FuzzyFinderPrompt<'a, T>
+where
+    T: Display,
+{
+    pub fn new(ff: FuzzyFinder<'a, T>) -> InquireResult<Self> {
+        if ff.options.is_empty() {
+            return Err(InquireError::InvalidConfiguration(
+                "Available options can not be empty".into(),
+            ));
+        }
+
+        let string_options = ff.options.iter().map(T::to_string).collect();
+        let scored_options = (0..ff.options.len()).collect();
+
+        Ok(Self {
+            message: ff.message,
+            config: (&ff).into(),
+            options: ff.options,
+            string_options,
+            scored_options,
+            help_message: ff.help_message,
+            cursor_index: 0,
+            input: Input::new_with(ff.starting_filter_input.unwrap_or_default()),
+            scorer: ff.scorer,
+            formatter: ff.formatter,
+            previewer: ff.previewer,
+            page_size: Cell::new(crate::config::DEFAULT_PAGE_SIZE),
+        })
+    }
+
+    fn move_cursor_up(&mut self, qty: usize, wrap: bool) -> ActionResult {
+        let new_position = if wrap {
+            let after_wrap = qty.saturating_sub(self.cursor_index);
+            self.cursor_index
+                .checked_sub(qty)
+                .unwrap_or_else(|| self.scored_options.len().saturating_sub(after_wrap))
+        } else {
+            self.cursor_index.saturating_sub(qty)
+        };
+
+        self.update_cursor_position(new_position)
+    }
+
+    fn move_cursor_down(&mut self, qty: usize, wrap: bool) -> ActionResult {
+        let mut new_position = self.cursor_index.saturating_add(qty);
+
+        if new_position >= self.scored_options.len() {
+            new_position = if self.scored_options.is_empty() {
+                0
+            } else if wrap {
+                new_position % self.scored_options.len()
+            } else {
+                self.scored_options.len().saturating_sub(1)
+            }
+        }
+
+        self.update_cursor_position(new_position)
+    }
+
+    fn update_cursor_position(&mut self, new_position: usize) -> ActionResult {
+        if new_position != self.cursor_index {
+            self.cursor_index = new_position;
+            ActionResult::NeedsRedraw
+        } else {
+            ActionResult::Clean
+        }
+    }
+
+    fn run_scorer(&mut self) {
+        let content = self.input.content();
+
+        let mut options = self
+            .options
+            .iter()
+            .enumerate()
+            .filter_map(|(i, opt)| {
+                (self.scorer)(content, opt, self.string_options.get(i).unwrap(), i)
+                    .map(|score| (i, score))
+            })
+            .collect::<Vec<(usize, i64)>>();
+
+        // stable sort, so that options with the same score keep their original order
+        options.sort_by_key(|(_idx, score)| Reverse(*score));
+
+        self.scored_options = options.iter().map(|(idx, _)| *idx).collect::<Vec<usize>>();
+        let _ = self.update_cursor_position(0);
+    }
+
+    /// Lays out the screen, returning the page size of the option list and
+    /// the height of the preview pane.
+    fn layout(&self, screen_height: usize) -> (usize, usize) {
+        // prompt, match counter and the empty line the cursor rests on
+        let mut reserved = 3;
+
+        if self.help_message.is_some() {
+            reserved += 1;
+        }
+
+        let preview_height = match self.previewer {
+            Some(_) => min(self.config.preview_height, screen_height / 2),
+            None => 0,
+        };
+
+        if self.previewer.is_some() {
+            // separator line between the options and the preview
+            reserved += preview_height + 1;
+        }
+
+        let page_size = screen_height.saturating_sub(reserved).max(1);
+
+        (page_size, preview_height)
+    }
+}
+
+impl<'a, Backend, T> Prompt<Backend> for FuzzyFinderPrompt<'a, T>
+where
+    Backend: FuzzyFinderBackend,
+    T: Display,
+{
+    type Config = FuzzyFinderConfig;
+    type InnerAction = FuzzyFinderPromptAction;
+    type Output = ListOption<T>;
+
+    fn message(&self) -> &str {
+        self.message
+    }
+
+    fn config(&self) -> &FuzzyFinderConfig {
+        &self.config
+    }
+
+    fn format_answer(&self, answer: &ListOption<T>) -> String {
+        (self.formatter)(answer.as_ref())
+    }
+
+    fn setup(&mut self) -> InquireResult<()> {
+        self.run_scorer();
+        Ok(())
+    }
+
+    fn submit(&mut self) -> InquireResult<Option<ListOption<T>>> {
+        let answer = match self.scored_options.get(self.cursor_index) {
+            Some(index) => {
+                let index = *index;
+                Some(ListOption::new(index, self.options.swap_remove(index)))
+            }
+            None => None,
+        };
+
+        Ok(answer)
+    }
+
+    fn handle(&mut self, action: FuzzyFinderPromptAction) -> InquireResult<ActionResult> {
+        let page_size = self.page_size.get();
+
+        let result = match action {
+            FuzzyFinderPromptAction::MoveUp => self.move_cursor_up(1, true),
+            FuzzyFinderPromptAction::MoveDown => self.move_cursor_down(1, true),
+            FuzzyFinderPromptAction::PageUp => self.move_cursor_up(page_size, false),
+            FuzzyFinderPromptAction::PageDown => self.move_cursor_down(page_size, false),
+            FuzzyFinderPromptAction::MoveToStart => self.move_cursor_up(usize::MAX, false),
+            FuzzyFinderPromptAction::MoveToEnd => self.move_cursor_down(usize::MAX, false),
+            FuzzyFinderPromptAction::FilterInput(input_action) => {
+                let result = self.input.handle(input_action);
+
+                if let InputActionResult::ContentChanged = result {
+                    self.run_scorer();
+                }
+
+                result.into()
+            }
+        };
+
+        Ok(result)
+    }
+
+    fn render(&self, backend: &mut Backend) -> InquireResult<()> {
+        let prompt = &self.message;
+
+        let (page_size, preview_height) = self.layout(backend.screen_size().height() as usize);
+        self.page_size.set(page_size);
+
+        backend.render_fuzzy_finder_prompt(
+            prompt,
+            &self.input,
+            self.scored_options.len(),
+            self.options.len(),
+        )?;
+
+        let choices = self
+            .scored_options
+            .iter()
+            .cloned()
+            .map(|i| ListOption::new(i, self.options.get(i).unwrap()))
+            .collect::<Vec<ListOption<&T>>>();
+
+        let page = paginate(page_size, &choices, Some(self.cursor_index));
+
+        let matched_chars = page
+            .content
+            .iter()
+            .map(|option| {
+                super::matched_chars(
+                    self.input.content(),
+                    self.string_options.get(option.index).unwrap(),
+                )
+            })
+            .collect::<Vec<Vec<usize>>>();
+
+        let highlighted = page
+            .cursor
+            .and_then(|cursor| page.content.get(cursor))
+            .map(|option| option.value);
+
+        backend.render_matched_options(page, &matched_chars)?;
+
+        if let Some(previewer) = self.previewer {
+            let preview = highlighted.map(previewer).unwrap_or_default();
+            backend.render_preview(&preview, preview_height)?;
+        }
+
+        if let Some(help_message) = self.help_message {
+            backend.render_help_message(help_message)?;
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,102 @@
+use crate::{
+    error::InquireResult,
+    list_option::ListOption,
+    ui::{
+        test::{FakeBackend, Token},
+        Key, KeyModifiers,
+    },
+    FuzzyFinder, InquireError,
+};
+
+fn files() -> Vec<&'static str> {
+    vec!["src/main.rs", "src/lib.rs", "README.md", "Cargo.toml"]
+}
+
+#[test]
+fn prompt_is_rendered_in_full_screen() -> InquireResult<()> {
+    let mut backend = FakeBackend::new(vec![Key::Enter]);
+
+    let ans = FuzzyFinder::new("File", files()).prompt_with_backend(&mut backend)?;
+
+    assert_eq!(ListOption::new(0, "src/main.rs"), ans);
+    assert!(backend.frames()[0].has_token(&Token::FullScreen));
+    assert!(backend.frames()[0].has_token(&Token::MatchCounter {
+        matched: 4,
+        total: 4
+    }));
+
+    Ok(())
+}
+
+#[test]
+fn matched_characters_are_reported_for_highlighting() -> InquireResult<()> {
+    let mut input = Key::char_keys_from_str("lib");
+    input.push(Key::Enter);
+    let mut backend = FakeBackend::new(input);
+
+    let ans = FuzzyFinder::new("File", files()).prompt_with_backend(&mut backend)?;
+
+    assert_eq!(ListOption::new(1, "src/lib.rs"), ans);
+    assert!(backend.frames()[3].has_token(&Token::MatchedOptions {
+        options: vec!["src/lib.rs".into()],
+        matched_chars: vec![vec![4, 5, 6]],
+        cursor: Some(0),
+    }));
+    assert!(backend.frames()[3].has_token(&Token::MatchCounter {
+        matched: 1,
+        total: 4
+    }));
+
+    Ok(())
+}
+
+#[test]
+fn page_size_fits_the_screen() -> InquireResult<()> {
+    let options = (0..30).collect::<Vec<i32>>();
+    let mut backend = FakeBackend::new(vec![Key::PageDown(KeyModifiers::NONE), Key::Enter]);
+
+    let ans = FuzzyFinder::new("Number", options).prompt_with_backend(&mut backend)?;
+
+    // 12 rows minus the prompt, counter, help message and cursor lines
+    assert_eq!(ListOption::new(8, 8), ans);
+
+    Ok(())
+}
+
+#[test]
+fn preview_follows_the_cursor() -> InquireResult<()> {
+    let mut backend = FakeBackend::new(vec![Key::Down(KeyModifiers::NONE), Key::Enter]);
+
+    let ans = FuzzyFinder::new("File", files())
+        .with_previewer(&|file| format!("contents of {file}\nsecond line"))
+        .with_preview_height(1)
+        .prompt_with_backend(&mut backend)?;
+
+    assert_eq!(ListOption::new(1, "src/lib.rs"), ans);
+    assert!(backend.frames()[0].has_token(&Token::Preview("contents of src/main.rs".into())));
+    assert!(backend.frames()[1].has_token(&Token::Preview("contents of src/lib.rs".into())));
+
+    Ok(())
+}
+
+#[test]
+fn enter_without_matches_does_not_submit() -> InquireResult<()> {
+    let mut input = Key::char_keys_from_str("zz");
+    input.extend([Key::Enter, Key::Backspace, Key::Backspace, Key::Enter]);
+    let mut backend = FakeBackend::new(input);
+
+    let ans = FuzzyFinder::new("File", files()).prompt_with_backend(&mut backend)?;
+
+    assert_eq!(ListOption::new(0, "src/main.rs"), ans);
+
+    Ok(())
+}
+
+#[test]
+fn empty_options_are_rejected() {
+    let mut backend = FakeBackend::new(vec![Key::Enter]);
+
+    let ans = FuzzyFinder::<&str>::new("File", vec![]).prompt_with_backend(&mut backend);
+
+    assert!(matches!(ans, Err(InquireError::InvalidConfiguration(_))));
+}
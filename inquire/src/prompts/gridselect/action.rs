@@ -0,0 +1,49 @@
+use crate::{
+    ui::{Key, KeyModifiers},
+    InnerAction,
+};
+
+use super::config::GridSelectConfig;
+
+/// Set of actions for a GridSelectPrompt.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum GridSelectPromptAction {
+    /// Moves the cursor to the option on the left.
+    MoveLeft,
+    /// Moves the cursor to the option on the right.
+    MoveRight,
+    /// Moves the cursor to the option above.
+    MoveUp,
+    /// Moves the cursor to the option below.
+    MoveDown,
+    /// Moves the cursor to the page above.
+    PageUp,
+    /// Moves the cursor to the page below.
+    PageDown,
+    /// Moves the cursor to the first option of the grid.
+    MoveToStart,
+    /// Moves the cursor to the last option of the grid.
+    MoveToEnd,
+}
+
+impl InnerAction for GridSelectPromptAction {
+    type Config = GridSelectConfig;
+
+    fn from_key(key: Key, _config: &GridSelectConfig) -> Option<Self> {
+        let action = match key {
+            Key::Left(KeyModifiers::NONE) | Key::Char('b', KeyModifiers::CONTROL) => Self::MoveLeft,
+            Key::Right(KeyModifiers::NONE) | Key::Char('f', KeyModifiers::CONTROL) => {
+                Self::MoveRight
+            }
+            Key::Up(KeyModifiers::NONE) | Key::Char('p', KeyModifiers::CONTROL) => Self::MoveUp,
+            Key::Down(KeyModifiers::NONE) | Key::Char('n', KeyModifiers::CONTROL) => Self::MoveDown,
+            Key::PageUp(_) => Self::PageUp,
+            Key::PageDown(_) => Self::PageDown,
+            Key::Home => Self::MoveToStart,
+            Key::End => Self::MoveToEnd,
+            _ => return None,
+        };
+
+        Some(action)
+    }
+}
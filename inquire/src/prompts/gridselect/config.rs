@@ -0,0 +1,19 @@
+use crate::GridSelect;
+
+/// Configuration settings used in the execution of a GridSelectPrompt.
+#[derive(Copy, Clone, Debug)]
+pub struct GridSelectConfig {
+    /// Number of columns of the grid, fitted to the terminal width when `None`.
+    pub columns: Option<usize>,
+    /// Number of rows displayed at once.
+    pub page_size: usize,
+}
+
+impl<T> From<&GridSelect<'_, T>> for GridSelectConfig {
+    fn from(value: &GridSelect<'_, T>) -> Self {
+        Self {
+            columns: value.columns,
+            page_size: value.page_size,
+        }
+    }
+}
@@ -0,0 +1,228 @@
+mod action;
+mod config;
+mod prompt;
+#[cfg(test)]
+mod test;
+
+pub use action::*;
+use std::fmt::Display;
+
+use crate::{
+    config::get_configuration,
+    error::{InquireError, InquireResult},
+    formatter::OptionFormatter,
+    list_option::ListOption,
+    prompts::prompt::Prompt,
+    terminal::get_default_terminal,
+    ui::{Backend, GridSelectBackend, RenderConfig},
+};
+
+use self::prompt::GridSelectPrompt;
+
+/// Prompt suitable for when you need the user to select one option among many short ones, such as emoji, single letters or icons.
+///
+/// Instead of a vertical list, the options are laid out in a grid, filling each row from left to right. The user can move through the grid with all four arrow keys and submit the highlighted option by pressing enter.
+///
+/// The number of columns can be set explicitly or, by default, fitted to the terminal width on each render, based on the width of the longest option.
+///
+/// This prompt requires a prompt message and a **non-empty** `Vec` of options to be displayed to the user. The options can be of any type as long as they implement the `Display` trait.
+/// - If the list is empty, the prompt operation will fail with an `InquireError::InvalidConfiguration` error.
+///
+/// Grid select prompts provide several options of configuration:
+///
+/// - **Prompt message**: Required when creating the prompt.
+/// - **Options list**: Options displayed to the user. Must be **non-empty**.
+/// - **Columns**: Number of columns of the grid, fitted to the terminal width by default. Must be greater than zero.
+/// - **Starting cursor**: Index of the cursor when the prompt is first rendered. Default is 0 (first option). If the index is out-of-range of the option list, the prompt will fail with an [`InquireError::InvalidConfiguration`] error.
+/// - **Help message**: Message displayed at the line below the prompt.
+/// - **Formatter**: Custom formatter in case you need to pre-process the user input before showing it as the final answer.
+///   - Prints the selected option string value by default.
+/// - **Page size**: Number of rows displayed at once, 7 by default.
+///
+/// # Example
+///
+/// ```no_run
+/// use inquire::GridSelect;
+///
+/// let reactions = vec!["👍", "👎", "😄", "🎉", "😕", "❤️", "🚀", "👀"];
+///
+/// let ans = GridSelect::new("Reaction:", reactions).with_columns(4).prompt();
+///
+/// match ans {
+///     Ok(reaction) => println!("You reacted with {}", reaction),
+///     Err(_) => println!("No reaction was chosen"),
+/// }
+/// ```
+///
+/// [`InquireError::InvalidConfiguration`]: crate::error::InquireError::InvalidConfiguration
+#[derive(Clone)]
+pub struct GridSelect<'a, T> {
+    /// Message to be presented to the user.
+    pub message: &'a str,
+
+    /// Options displayed to the user.
+    pub options: Vec<T>,
+
+    /// Number of columns of the grid. When `None`, the number of columns is
+    /// fitted to the terminal width.
+    pub columns: Option<usize>,
+
+    /// Help message to be presented to the user.
+    pub help_message: Option<&'a str>,
+
+    /// Number of rows displayed at once.
+    pub page_size: usize,
+
+    /// Starting cursor index of the selection.
+    pub starting_cursor: usize,
+
+    /// Function that formats the user input and presents it to the user as the final rendering of the prompt.
+    pub formatter: OptionFormatter<'a, T>,
+
+    /// RenderConfig to apply to the rendered interface.
+    ///
+    /// Note: The default render config considers if the NO_COLOR environment variable
+    /// is set to decide whether to render the colored config or the empty one.
+    ///
+    /// When overriding the config in a prompt, NO_COLOR is no longer considered and your
+    /// config is treated as the only source of truth. If you want to customize colors
+    /// and still support NO_COLOR, you will have to do this on your end.
+    pub render_config: RenderConfig<'a>,
+}
+
+impl<'a, T> GridSelect<'a, T>
+where
+    T: Display,
+{
+    /// String formatter used by default in [GridSelect](crate::GridSelect) prompts.
+    /// Simply prints the string value contained in the selected option.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use inquire::list_option::ListOption;
+    /// use inquire::GridSelect;
+    ///
+    /// let formatter = GridSelect::<&str>::DEFAULT_FORMATTER;
+    /// assert_eq!(String::from("A"), formatter(ListOption::new(0, &"A")));
+    /// ```
+    pub const DEFAULT_FORMATTER: OptionFormatter<'a, T> = &|ans| ans.to_string();
+
+    /// Default number of columns, fitted to the terminal width.
+    pub const DEFAULT_COLUMNS: Option<usize> = None;
+
+    /// Default page size, in rows.
+    pub const DEFAULT_PAGE_SIZE: usize = crate::config::DEFAULT_PAGE_SIZE;
+
+    /// Default starting cursor index.
+    pub const DEFAULT_STARTING_CURSOR: usize = 0;
+
+    /// Default help message.
+    pub const DEFAULT_HELP_MESSAGE: Option<&'a str> = Some("↑↓←→ to move, enter to select");
+
+    /// Creates a [GridSelect] with the provided message and options, along with default configuration values.
+    pub fn new(message: &'a str, options: Vec<T>) -> Self {
+        Self {
+            message,
+            options,
+            columns: Self::DEFAULT_COLUMNS,
+            help_message: Self::DEFAULT_HELP_MESSAGE,
+            page_size: Self::DEFAULT_PAGE_SIZE,
+            starting_cursor: Self::DEFAULT_STARTING_CURSOR,
+            formatter: Self::DEFAULT_FORMATTER,
+            render_config: get_configuration(),
+        }
+    }
+
+    /// Sets the number of columns of the grid, instead of fitting it to the terminal width.
+    pub fn with_columns(mut self, columns: usize) -> Self {
+        self.columns = Some(columns);
+        self
+    }
+
+    /// Sets the help message of the prompt.
+    pub fn with_help_message(mut self, message: &'a str) -> Self {
+        self.help_message = Some(message);
+        self
+    }
+
+    /// Removes the set help message.
+    pub fn without_help_message(mut self) -> Self {
+        self.help_message = None;
+        self
+    }
+
+    /// Sets the page size, in rows.
+    pub fn with_page_size(mut self, page_size: usize) -> Self {
+        self.page_size = page_size;
+        self
+    }
+
+    /// Sets the starting cursor index.
+    pub fn with_starting_cursor(mut self, starting_cursor: usize) -> Self {
+        self.starting_cursor = starting_cursor;
+        self
+    }
+
+    /// Sets the formatter.
+    pub fn with_formatter(mut self, formatter: OptionFormatter<'a, T>) -> Self {
+        self.formatter = formatter;
+        self
+    }
+
+    /// Sets the provided color theme to this prompt.
+    ///
+    /// Note: The default render config considers if the NO_COLOR environment variable
+    /// is set to decide whether to render the colored config or the empty one.
+    ///
+    /// When overriding the config in a prompt, NO_COLOR is no longer considered and your
+    /// config is treated as the only source of truth. If you want to customize colors
+    /// and still support NO_COLOR, you will have to do this on your end.
+    pub fn with_render_config(mut self, render_config: RenderConfig<'a>) -> Self {
+        self.render_config = render_config;
+        self
+    }
+
+    /// Parses the provided behavioral and rendering options and prompts
+    /// the CLI user for input according to the defined rules.
+    ///
+    /// Returns the owned object selected by the user.
+    pub fn prompt(self) -> InquireResult<T> {
+        self.raw_prompt().map(|op| op.value)
+    }
+
+    /// Parses the provided behavioral and rendering options and prompts
+    /// the CLI user for input according to the defined rules.
+    ///
+    /// This method is intended for flows where the user skipping/cancelling
+    /// the prompt - by pressing ESC - is considered normal behavior. In this case,
+    /// it does not return `Err(InquireError::OperationCanceled)`, but `Ok(None)`.
+    ///
+    /// Meanwhile, if the user does submit an answer, the method wraps the return
+    /// type with `Some`.
+    pub fn prompt_skippable(self) -> InquireResult<Option<T>> {
+        match self.prompt() {
+            Ok(answer) => Ok(Some(answer)),
+            Err(InquireError::OperationCanceled) => Ok(None),
+            Err(err) => Err(err),
+        }
+    }
+
+    /// Parses the provided behavioral and rendering options and prompts
+    /// the CLI user for input according to the defined rules.
+    ///
+    /// Returns a [`ListOption`](crate::list_option::ListOption) containing
+    /// the index of the selection and the owned object selected by the user.
+    pub fn raw_prompt(self) -> InquireResult<ListOption<T>> {
+        let (input_reader, terminal) = get_default_terminal()?;
+        let mut backend = Backend::new(input_reader, terminal, self.render_config)?;
+        self.prompt_with_backend(&mut backend)
+    }
+
+    pub(crate) fn prompt_with_backend<B: GridSelectBackend>(
+        self,
+        backend: &mut B,
+    ) -> InquireResult<ListOption<T>> {
+        GridSelectPrompt::new(self)?.prompt(backend)
+    }
+}
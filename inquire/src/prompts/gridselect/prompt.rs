@@ -0,0 +1,218 @@
+use std::{cell::Cell, cmp::min, fmt::Display};
+
+use unicode_width::UnicodeWidthStr;
+
+use crate::{
+    error::InquireResult,
+    formatter::OptionFormatter,
+    list_option::ListOption,
+    prompts::prompt::{ActionResult, Prompt},
+    ui::GridSelectBackend,
+    utils::{paginate, Page},
+    GridSelect, InquireError,
+};
+
+use super::{action::GridSelectPromptAction, config::GridSelectConfig};
+
+pub struct GridSelectPrompt<'a, T> {
+    message: &'a str,
+    config: GridSelectConfig,
+    options: Vec<T>,
+    label_width: usize,
+    help_message: Option<&'a str>,
+    cursor_index: usize,
+    formatter: OptionFormatter<'a, T>,
+    // when fitted to the terminal width, the number of columns is only
+    // known when rendering, and is kept here for the movement actions
+    columns: Cell<usize>,
+}
+
+impl<'a, T> GridSelectPrompt<'a, T>
+where
+    T: Display,
+{
+    pub fn new(gso: GridSelect<'a, T>) -> InquireResult<Self> {
+        if gso.options.is_empty() {
+            return Err(InquireError::InvalidConfiguration(
+                "Available options can not be empty".into(),
+            ));
+        }
+
+        if gso.columns == Some(0) {
+            return Err(InquireError::InvalidConfiguration(
+                "Number of columns must be greater than zero".into(),
+            ));
+        }
+
+        if gso.starting_cursor >= gso.options.len() {
+            return Err(InquireError::InvalidConfiguration(format!(
+                "Starting cursor index {} is out-of-bounds for length {} of options",
+                gso.starting_cursor,
+                gso.options.len()
+            )));
+        }
+
+        let label_width = gso
+            .options
+            .iter()
+            .map(|option| option.to_string().width())
+            .max()
+            .unwrap_or_default();
+
+        Ok(Self {
+            message: gso.message,
+            config: (&gso).into(),
+            columns: Cell::new(gso.columns.unwrap_or(1)),
+            options: gso.options,
+            label_width,
+            help_message: gso.help_message,
+            cursor_index: gso.starting_cursor,
+            formatter: gso.formatter,
+        })
+    }
+
+    fn fit_columns(&self, screen_width: usize) -> usize {
+        let columns = if let Some(columns) = self.config.columns {
+            columns
+        } else {
+            // each cell has a separator, the cursor prefix and another separator
+            // before the label, and each row starts with the scroll indicator
+            let cell_width = self.label_width + 3;
+            screen_width.saturating_sub(2) / cell_width
+        };
+
+        columns.clamp(1, self.options.len())
+    }
+
+    fn move_cursor_to(&mut self, new_position: usize) -> ActionResult {
+        if new_position != self.cursor_index && new_position < self.options.len() {
+            self.cursor_index = new_position;
+            ActionResult::NeedsRedraw
+        } else {
+            ActionResult::Clean
+        }
+    }
+
+    fn move_rows_up(&mut self, qty: usize) -> ActionResult {
+        let columns = self.columns.get();
+        let column = self.cursor_index % columns;
+
+        let new_position = self
+            .cursor_index
+            .checked_sub(qty.saturating_mul(columns))
+            .unwrap_or(column);
+
+        self.move_cursor_to(new_position)
+    }
+
+    fn move_rows_down(&mut self, qty: usize) -> ActionResult {
+        let columns = self.columns.get();
+        let last_row = (self.options.len() - 1) / columns;
+        let row = min(self.cursor_index / columns + qty, last_row);
+
+        // the last row might be incomplete, in which case the cursor
+        // goes to its last option
+        let new_position = min(
+            row * columns + self.cursor_index % columns,
+            self.options.len() - 1,
+        );
+
+        self.move_cursor_to(new_position)
+    }
+}
+
+impl<'a, Backend, T> Prompt<Backend> for GridSelectPrompt<'a, T>
+where
+    Backend: GridSelectBackend,
+    T: Display,
+{
+    type Config = GridSelectConfig;
+    type InnerAction = GridSelectPromptAction;
+    type Output = ListOption<T>;
+
+    fn message(&self) -> &str {
+        self.message
+    }
+
+    fn config(&self) -> &GridSelectConfig {
+        &self.config
+    }
+
+    fn format_answer(&self, answer: &ListOption<T>) -> String {
+        (self.formatter)(answer.as_ref())
+    }
+
+    fn submit(&mut self) -> InquireResult<Option<ListOption<T>>> {
+        let index = self.cursor_index;
+        let value = self.options.swap_remove(index);
+
+        Ok(Some(ListOption::new(index, value)))
+    }
+
+    fn handle(&mut self, action: GridSelectPromptAction) -> InquireResult<ActionResult> {
+        let columns = self.columns.get();
+        let column = self.cursor_index % columns;
+
+        let result = match action {
+            GridSelectPromptAction::MoveLeft if column > 0 => {
+                self.move_cursor_to(self.cursor_index - 1)
+            }
+            GridSelectPromptAction::MoveRight if column < columns - 1 => {
+                self.move_cursor_to(self.cursor_index + 1)
+            }
+            GridSelectPromptAction::MoveLeft | GridSelectPromptAction::MoveRight => {
+                ActionResult::Clean
+            }
+            GridSelectPromptAction::MoveUp => self.move_rows_up(1),
+            GridSelectPromptAction::MoveDown => self.move_rows_down(1),
+            GridSelectPromptAction::PageUp => self.move_rows_up(self.config.page_size),
+            GridSelectPromptAction::PageDown => self.move_rows_down(self.config.page_size),
+            GridSelectPromptAction::MoveToStart => self.move_cursor_to(0),
+            GridSelectPromptAction::MoveToEnd => self.move_cursor_to(self.options.len() - 1),
+        };
+
+        Ok(result)
+    }
+
+    fn render(&self, backend: &mut Backend) -> InquireResult<()> {
+        let prompt = &self.message;
+
+        let columns = self.fit_columns(backend.screen_size().width() as usize);
+        self.columns.set(columns);
+
+        backend.render_grid_select_prompt(prompt)?;
+
+        let row_count = (self.options.len() + columns - 1) / columns;
+        let rows = (0..row_count).collect::<Vec<usize>>();
+        let row_page = paginate(
+            self.config.page_size,
+            &rows,
+            Some(self.cursor_index / columns),
+        );
+
+        let first_row = row_page.content.first().copied().unwrap_or_default();
+        let start = first_row * columns;
+        let end = min(start + row_page.content.len() * columns, self.options.len());
+
+        let choices = (start..end)
+            .map(|i| ListOption::new(i, self.options.get(i).unwrap()))
+            .collect::<Vec<ListOption<&T>>>();
+
+        let page = Page {
+            first: row_page.first,
+            last: row_page.last,
+            content: &choices,
+            cursor: Some(self.cursor_index - start),
+            total: self.options.len(),
+            start,
+        };
+
+        backend.render_grid(page, columns, self.label_width)?;
+
+        if let Some(help_message) = self.help_message {
+            backend.render_help_message(help_message)?;
+        }
+
+        Ok(())
+    }
+}
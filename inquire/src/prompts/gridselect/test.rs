@@ -0,0 +1,114 @@
+use crate::{
+    error::InquireResult,
+    list_option::ListOption,
+    ui::{
+        test::{FakeBackend, Token},
+        Key, KeyModifiers,
+    },
+    GridSelect, InquireError,
+};
+
+fn letters(count: u8) -> Vec<char> {
+    (b'a'..b'a' + count).map(char::from).collect()
+}
+
+#[test]
+fn arrows_move_in_two_dimensions() -> InquireResult<()> {
+    let mut backend = FakeBackend::new(vec![
+        Key::Right(KeyModifiers::NONE),
+        Key::Down(KeyModifiers::NONE),
+        Key::Enter,
+    ]);
+
+    let ans = GridSelect::new("Letter", letters(8))
+        .with_columns(3)
+        .prompt_with_backend(&mut backend)?;
+
+    assert_eq!(ListOption::new(4, 'e'), ans);
+    assert!(backend.frames()[0].has_token(&Token::Grid {
+        options: letters(8).iter().map(char::to_string).collect(),
+        columns: 3,
+        cursor: Some(0),
+    }));
+
+    Ok(())
+}
+
+#[test]
+fn columns_are_fitted_to_terminal_width() -> InquireResult<()> {
+    let mut backend = FakeBackend::new(vec![Key::Down(KeyModifiers::NONE), Key::Enter]);
+
+    let ans = GridSelect::new("Letter", letters(26)).prompt_with_backend(&mut backend)?;
+
+    // 80 columns minus the scroll indicator and a margin, 4 columns per cell
+    assert_eq!(ListOption::new(19, 't'), ans);
+
+    Ok(())
+}
+
+#[test]
+fn moving_down_into_incomplete_row_stops_at_last_option() -> InquireResult<()> {
+    let mut backend = FakeBackend::new(vec![Key::Down(KeyModifiers::NONE), Key::Enter]);
+
+    let ans = GridSelect::new("Letter", letters(8))
+        .with_columns(3)
+        .with_starting_cursor(5)
+        .prompt_with_backend(&mut backend)?;
+
+    assert_eq!(ListOption::new(7, 'h'), ans);
+
+    Ok(())
+}
+
+#[test]
+fn edges_of_the_grid_do_not_wrap() -> InquireResult<()> {
+    let mut backend = FakeBackend::new(vec![
+        Key::Left(KeyModifiers::NONE),
+        Key::Up(KeyModifiers::NONE),
+        Key::Right(KeyModifiers::NONE),
+        Key::Right(KeyModifiers::NONE),
+        Key::Right(KeyModifiers::NONE),
+        Key::Enter,
+    ]);
+
+    let ans = GridSelect::new("Letter", letters(8))
+        .with_columns(3)
+        .prompt_with_backend(&mut backend)?;
+
+    assert_eq!(ListOption::new(2, 'c'), ans);
+    // only the two moves to the right changed the cursor
+    assert_eq!(4, backend.frames().len());
+
+    Ok(())
+}
+
+#[test]
+fn rows_are_paginated() -> InquireResult<()> {
+    let mut backend = FakeBackend::new(vec![Key::PageDown(KeyModifiers::NONE), Key::Enter]);
+
+    let ans = GridSelect::new("Letter", letters(10))
+        .with_columns(2)
+        .with_page_size(2)
+        .prompt_with_backend(&mut backend)?;
+
+    assert_eq!(ListOption::new(4, 'e'), ans);
+    assert!(backend.frames()[1].has_token(&Token::Grid {
+        options: vec!["c".into(), "d".into(), "e".into(), "f".into()],
+        columns: 2,
+        cursor: Some(2),
+    }));
+
+    Ok(())
+}
+
+#[test]
+fn invalid_configurations_are_rejected() {
+    let ans = GridSelect::new("Letter", letters(8))
+        .with_columns(0)
+        .prompt_with_backend(&mut FakeBackend::new(vec![Key::Enter]));
+    assert!(matches!(ans, Err(InquireError::InvalidConfiguration(_))));
+
+    let ans = GridSelect::<char>::new("Letter", vec![])
+        .prompt_with_backend(&mut FakeBackend::new(vec![Key::Enter]));
+    assert!(matches!(ans, Err(InquireError::InvalidConfiguration(_))));
+}
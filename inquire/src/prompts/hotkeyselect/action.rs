@@ -0,0 +1,26 @@
+use crate::{
+    ui::{Key, KeyModifiers},
+    InnerAction,
+};
+
+use super::config::HotkeySelectConfig;
+
+/// Set of actions for a HotkeySelectPrompt.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum HotkeySelectPromptAction {
+    /// Presses the hotkey of an option, already lowercased.
+    Press(char),
+}
+
+impl InnerAction for HotkeySelectPromptAction {
+    type Config = HotkeySelectConfig;
+
+    fn from_key(key: Key, _config: &HotkeySelectConfig) -> Option<Self> {
+        match key {
+            Key::Char(c, KeyModifiers::NONE | KeyModifiers::SHIFT) => {
+                Some(Self::Press(super::normalize_hotkey(c)))
+            }
+            _ => None,
+        }
+    }
+}
@@ -0,0 +1,10 @@
+use crate::HotkeySelect;
+
+/// Configuration settings used in the execution of a HotkeySelectPrompt.
+pub struct HotkeySelectConfig {}
+
+impl<T> From<&HotkeySelect<'_, T>> for HotkeySelectConfig {
+    fn from(_value: &HotkeySelect<'_, T>) -> Self {
+        Self {}
+    }
+}
@@ -0,0 +1,200 @@
+mod action;
+mod config;
+mod prompt;
+#[cfg(test)]
+mod test;
+
+pub use action::*;
+use std::fmt::Display;
+
+use crate::{
+    config::get_configuration,
+    error::{InquireError, InquireResult},
+    formatter::OptionFormatter,
+    list_option::ListOption,
+    prompts::prompt::Prompt,
+    terminal::get_default_terminal,
+    ui::{Backend, HotkeySelectBackend, RenderConfig},
+};
+
+use self::prompt::HotkeySelectPrompt;
+
+/// Prompt suitable for when the user picks one of a few actions repeatedly and wants to do it with a single key press, such as `[d]eploy  [r]ollback  [q]uit`.
+///
+/// Each option is bound to a single key, displayed between brackets in its label. Pressing that key submits the option immediately, without moving a cursor or pressing enter. Hotkeys are case-insensitive.
+///
+/// By default, the hotkey of each option is the first letter or digit of its label that is not already taken by a previous option, e.g. `[d]eploy` and `d[e]lete`. Hotkeys can also be set explicitly, in which case the key is displayed before the label when it is not part of it.
+///
+/// This prompt requires a prompt message and a **non-empty** `Vec` of options to be displayed to the user. The options can be of any type as long as they implement the `Display` trait.
+/// - If the list is empty, or a hotkey could not be assigned to every option, the prompt operation will fail with an `InquireError::InvalidConfiguration` error.
+///
+/// Hotkey select prompts provide several options of configuration:
+///
+/// - **Prompt message**: Required when creating the prompt.
+/// - **Options list**: Options displayed to the user. Must be **non-empty**.
+/// - **Hotkeys**: Key of each option, derived from the labels by default.
+/// - **Help message**: Message displayed at the line below the prompt.
+/// - **Formatter**: Custom formatter in case you need to pre-process the user input before showing it as the final answer.
+///   - Prints the selected option string value by default.
+///
+/// # Example
+///
+/// ```no_run
+/// use inquire::HotkeySelect;
+///
+/// let ans = HotkeySelect::new("Release:", vec!["deploy", "rollback", "quit"]).prompt();
+///
+/// match ans {
+///     Ok("deploy") => println!("Deploying..."),
+///     Ok("rollback") => println!("Rolling back..."),
+///     _ => println!("Bye"),
+/// }
+/// ```
+#[derive(Clone)]
+pub struct HotkeySelect<'a, T> {
+    /// Message to be presented to the user.
+    pub message: &'a str,
+
+    /// Options displayed to the user.
+    pub options: Vec<T>,
+
+    /// Key of each option, in the same order as the options. When empty,
+    /// the hotkeys are derived from the labels of the options.
+    pub hotkeys: Vec<char>,
+
+    /// Help message to be presented to the user.
+    pub help_message: Option<&'a str>,
+
+    /// Function that formats the user input and presents it to the user as the final rendering of the prompt.
+    pub formatter: OptionFormatter<'a, T>,
+
+    /// RenderConfig to apply to the rendered interface.
+    ///
+    /// Note: The default render config considers if the NO_COLOR environment variable
+    /// is set to decide whether to render the colored config or the empty one.
+    ///
+    /// When overriding the config in a prompt, NO_COLOR is no longer considered and your
+    /// config is treated as the only source of truth. If you want to customize colors
+    /// and still support NO_COLOR, you will have to do this on your end.
+    pub render_config: RenderConfig<'a>,
+}
+
+impl<'a, T> HotkeySelect<'a, T>
+where
+    T: Display,
+{
+    /// String formatter used by default in [HotkeySelect](crate::HotkeySelect) prompts.
+    /// Simply prints the string value contained in the selected option.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use inquire::list_option::ListOption;
+    /// use inquire::HotkeySelect;
+    ///
+    /// let formatter = HotkeySelect::<&str>::DEFAULT_FORMATTER;
+    /// assert_eq!(String::from("deploy"), formatter(ListOption::new(0, &"deploy")));
+    /// ```
+    pub const DEFAULT_FORMATTER: OptionFormatter<'a, T> = &|ans| ans.to_string();
+
+    /// Default help message.
+    pub const DEFAULT_HELP_MESSAGE: Option<&'a str> =
+        Some("press the key of an option to select it");
+
+    /// Creates a [HotkeySelect] with the provided message and options, along with default configuration values.
+    pub fn new(message: &'a str, options: Vec<T>) -> Self {
+        Self {
+            message,
+            options,
+            hotkeys: vec![],
+            help_message: Self::DEFAULT_HELP_MESSAGE,
+            formatter: Self::DEFAULT_FORMATTER,
+            render_config: get_configuration(),
+        }
+    }
+
+    /// Sets the key of each option, in the same order as the options.
+    pub fn with_hotkeys(mut self, hotkeys: &[char]) -> Self {
+        self.hotkeys = hotkeys.to_vec();
+        self
+    }
+
+    /// Sets the help message of the prompt.
+    pub fn with_help_message(mut self, message: &'a str) -> Self {
+        self.help_message = Some(message);
+        self
+    }
+
+    /// Removes the set help message.
+    pub fn without_help_message(mut self) -> Self {
+        self.help_message = None;
+        self
+    }
+
+    /// Sets the formatter.
+    pub fn with_formatter(mut self, formatter: OptionFormatter<'a, T>) -> Self {
+        self.formatter = formatter;
+        self
+    }
+
+    /// Sets the provided color theme to this prompt.
+    ///
+    /// Note: The default render config considers if the NO_COLOR environment variable
+    /// is set to decide whether to render the colored config or the empty one.
+    ///
+    /// When overriding the config in a prompt, NO_COLOR is no longer considered and your
+    /// config is treated as the only source of truth. If you want to customize colors
+    /// and still support NO_COLOR, you will have to do this on your end.
+    pub fn with_render_config(mut self, render_config: RenderConfig<'a>) -> Self {
+        self.render_config = render_config;
+        self
+    }
+
+    /// Parses the provided behavioral and rendering options and prompts
+    /// the CLI user for input according to the defined rules.
+    ///
+    /// Returns the owned object selected by the user.
+    pub fn prompt(self) -> InquireResult<T> {
+        self.raw_prompt().map(|op| op.value)
+    }
+
+    /// Parses the provided behavioral and rendering options and prompts
+    /// the CLI user for input according to the defined rules.
+    ///
+    /// This method is intended for flows where the user skipping/cancelling
+    /// the prompt - by pressing ESC - is considered normal behavior. In this case,
+    /// it does not return `Err(InquireError::OperationCanceled)`, but `Ok(None)`.
+    ///
+    /// Meanwhile, if the user does submit an answer, the method wraps the return
+    /// type with `Some`.
+    pub fn prompt_skippable(self) -> InquireResult<Option<T>> {
+        match self.prompt() {
+            Ok(answer) => Ok(Some(answer)),
+            Err(InquireError::OperationCanceled) => Ok(None),
+            Err(err) => Err(err),
+        }
+    }
+
+    /// Parses the provided behavioral and rendering options and prompts
+    /// the CLI user for input according to the defined rules.
+    ///
+    /// Returns a [`ListOption`](crate::list_option::ListOption) containing
+    /// the index of the selection and the owned object selected by the user.
+    pub fn raw_prompt(self) -> InquireResult<ListOption<T>> {
+        let (input_reader, terminal) = get_default_terminal()?;
+        let mut backend = Backend::new(input_reader, terminal, self.render_config)?;
+        self.prompt_with_backend(&mut backend)
+    }
+
+    pub(crate) fn prompt_with_backend<B: HotkeySelectBackend>(
+        self,
+        backend: &mut B,
+    ) -> InquireResult<ListOption<T>> {
+        HotkeySelectPrompt::new(self)?.prompt(backend)
+    }
+}
+
+/// Hotkeys are case-insensitive, so they are compared in lowercase.
+fn normalize_hotkey(c: char) -> char {
+    c.to_lowercase().next().unwrap_or(c)
+}
@@ -0,0 +1,198 @@
+use std::{cell::Cell, fmt::Display};
+
+use crate::{
+    error::InquireResult,
+    formatter::OptionFormatter,
+    list_option::ListOption,
+    prompts::{
+        action::{Action, InnerAction},
+        prompt::{ActionResult, Prompt},
+    },
+    ui::{HotkeySelectBackend, Key, KeyModifiers},
+    HotkeySelect, InquireError,
+};
+
+use super::{action::HotkeySelectPromptAction, config::HotkeySelectConfig, normalize_hotkey};
+
+pub struct HotkeySelectPrompt<'a, T> {
+    message: &'a str,
+    config: HotkeySelectConfig,
+    options: Vec<T>,
+    labels: Vec<String>,
+    hotkeys: Vec<char>,
+    help_message: Option<&'a str>,
+    formatter: OptionFormatter<'a, T>,
+    pressed: Cell<Option<usize>>,
+}
+
+impl<'a, T> HotkeySelectPrompt<'a, T>
+where
+    T: Display,
+{
+    pub fn new(hso: HotkeySelect<'a, T>) -> InquireResult<Self> {
+        if hso.options.is_empty() {
+            return Err(InquireError::InvalidConfiguration(
+                "Available options can not be empty".into(),
+            ));
+        }
+
+        let labels: Vec<String> = hso.options.iter().map(T::to_string).collect();
+
+        let hotkeys = if hso.hotkeys.is_empty() {
+            Self::derive_hotkeys(&labels)?
+        } else {
+            Self::check_hotkeys(&hso.hotkeys, labels.len())?
+        };
+
+        Ok(Self {
+            message: hso.message,
+            config: (&hso).into(),
+            options: hso.options,
+            labels,
+            hotkeys,
+            help_message: hso.help_message,
+            formatter: hso.formatter,
+            pressed: Cell::new(None),
+        })
+    }
+
+    /// Assigns to each label its first letter or digit not taken by a previous label.
+    fn derive_hotkeys(labels: &[String]) -> InquireResult<Vec<char>> {
+        let mut hotkeys: Vec<char> = Vec::with_capacity(labels.len());
+
+        for label in labels {
+            let hotkey = label
+                .chars()
+                .filter(|c| c.is_alphanumeric())
+                .map(normalize_hotkey)
+                .find(|c| !hotkeys.contains(c));
+
+            match hotkey {
+                Some(hotkey) => hotkeys.push(hotkey),
+                None => {
+                    return Err(InquireError::InvalidConfiguration(format!(
+                        "Could not assign a hotkey to option '{}'",
+                        label
+                    )))
+                }
+            }
+        }
+
+        Ok(hotkeys)
+    }
+
+    fn check_hotkeys(hotkeys: &[char], option_count: usize) -> InquireResult<Vec<char>> {
+        if hotkeys.len() != option_count {
+            return Err(InquireError::InvalidConfiguration(
+                "Number of hotkeys must match the number of options".into(),
+            ));
+        }
+
+        let hotkeys: Vec<char> = hotkeys.iter().copied().map(normalize_hotkey).collect();
+
+        for (idx, hotkey) in hotkeys.iter().enumerate() {
+            if hotkeys[..idx].contains(hotkey) {
+                return Err(InquireError::InvalidConfiguration(format!(
+                    "Hotkey '{}' is assigned to more than one option",
+                    hotkey
+                )));
+            }
+        }
+
+        Ok(hotkeys)
+    }
+
+    /// Splits the label around its hotkey, e.g. `r`, `o` and `llback` for
+    /// `r[o]llback`. When the hotkey is not part of the label, it is
+    /// displayed before it instead, e.g. `[x] exit`.
+    fn label_parts(label: &str, hotkey: char) -> (String, char, String) {
+        match label
+            .char_indices()
+            .find(|(_, c)| normalize_hotkey(*c) == hotkey)
+        {
+            Some((idx, c)) => (
+                label[..idx].to_owned(),
+                c,
+                label[idx + c.len_utf8()..].to_owned(),
+            ),
+            None => (String::new(), hotkey, format!(" {}", label)),
+        }
+    }
+}
+
+impl<'a, Backend, T> Prompt<Backend> for HotkeySelectPrompt<'a, T>
+where
+    Backend: HotkeySelectBackend,
+    T: Display,
+{
+    type Config = HotkeySelectConfig;
+    type InnerAction = HotkeySelectPromptAction;
+    type Output = ListOption<T>;
+
+    fn message(&self) -> &str {
+        self.message
+    }
+
+    fn config(&self) -> &HotkeySelectConfig {
+        &self.config
+    }
+
+    fn format_answer(&self, answer: &ListOption<T>) -> String {
+        (self.formatter)(answer.as_ref())
+    }
+
+    fn parse_key(&self, key: Key) -> Option<Action<HotkeySelectPromptAction>> {
+        // Options are submitted as soon as their hotkey is pressed, so the
+        // option is stashed here to be picked up by `submit`. Enter does
+        // nothing, as there is no cursor.
+        match key {
+            Key::Escape => return Some(Action::Cancel),
+            Key::Char('c', KeyModifiers::CONTROL) => return Some(Action::Interrupt),
+            _ => {}
+        }
+
+        match HotkeySelectPromptAction::from_key(key, &self.config)? {
+            HotkeySelectPromptAction::Press(c) => {
+                let index = self.hotkeys.iter().position(|hotkey| *hotkey == c)?;
+                self.pressed.set(Some(index));
+            }
+        }
+
+        Some(Action::Submit)
+    }
+
+    fn submit(&mut self) -> InquireResult<Option<ListOption<T>>> {
+        let answer = self.pressed.take().map(|index| {
+            let value = self.options.swap_remove(index);
+            ListOption::new(index, value)
+        });
+
+        Ok(answer)
+    }
+
+    fn handle(&mut self, action: HotkeySelectPromptAction) -> InquireResult<ActionResult> {
+        // hotkeys are handled as submissions on `parse_key`
+        match action {
+            HotkeySelectPromptAction::Press(_) => Ok(ActionResult::Clean),
+        }
+    }
+
+    fn render(&self, backend: &mut Backend) -> InquireResult<()> {
+        let prompt = &self.message;
+
+        let parts = self
+            .labels
+            .iter()
+            .zip(self.hotkeys.iter())
+            .map(|(label, hotkey)| Self::label_parts(label, *hotkey))
+            .collect::<Vec<(String, char, String)>>();
+
+        backend.render_hotkey_select_prompt(prompt, &parts)?;
+
+        if let Some(help_message) = self.help_message {
+            backend.render_help_message(help_message)?;
+        }
+
+        Ok(())
+    }
+}
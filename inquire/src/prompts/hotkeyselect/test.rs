@@ -0,0 +1,113 @@
+use crate::{
+    error::InquireResult,
+    ui::{
+        test::{FakeBackend, Token},
+        Key, KeyModifiers,
+    },
+    HotkeySelect, InquireError,
+};
+
+#[test]
+fn pressing_hotkey_submits_immediately() -> InquireResult<()> {
+    let mut backend = FakeBackend::new(vec![Key::Char('r', KeyModifiers::NONE)]);
+
+    let ans = HotkeySelect::new("Release", vec!["deploy", "rollback", "quit"])
+        .prompt_with_backend(&mut backend)?;
+
+    assert_eq!(1, ans.index);
+    assert_eq!("rollback", ans.value);
+    assert!(backend.frames()[0].has_token(&Token::Hotkeys(vec![
+        "[d]eploy".into(),
+        "[r]ollback".into(),
+        "[q]uit".into()
+    ])));
+    assert!(backend
+        .frames()
+        .last()
+        .unwrap()
+        .has_token(&Token::AnsweredPrompt("Release".into(), "rollback".into())));
+
+    Ok(())
+}
+
+#[test]
+fn unknown_keys_and_enter_are_ignored() -> InquireResult<()> {
+    let mut backend = FakeBackend::new(vec![
+        Key::Enter,
+        Key::Char('x', KeyModifiers::NONE),
+        Key::Down(KeyModifiers::NONE),
+        Key::Char('Q', KeyModifiers::SHIFT),
+    ]);
+
+    let ans = HotkeySelect::new("Release", vec!["deploy", "rollback", "quit"])
+        .prompt_with_backend(&mut backend)?;
+
+    assert_eq!("quit", ans.value);
+    assert_eq!(2, backend.frames().len());
+
+    Ok(())
+}
+
+#[test]
+fn taken_hotkeys_move_to_the_next_character() -> InquireResult<()> {
+    let mut backend = FakeBackend::new(vec![Key::Char('s', KeyModifiers::NONE)]);
+
+    let ans = HotkeySelect::new("Action", vec!["Deploy", "delete", "describe"])
+        .prompt_with_backend(&mut backend)?;
+
+    assert_eq!("describe", ans.value);
+    assert!(backend.frames()[0].has_token(&Token::Hotkeys(vec![
+        "[D]eploy".into(),
+        "d[e]lete".into(),
+        "de[s]cribe".into()
+    ])));
+
+    Ok(())
+}
+
+#[test]
+fn explicit_hotkeys_are_used() -> InquireResult<()> {
+    let mut backend = FakeBackend::new(vec![Key::Char('x', KeyModifiers::NONE)]);
+
+    let ans = HotkeySelect::new("Action", vec!["save", "exit"])
+        .with_hotkeys(&['S', 'x'])
+        .prompt_with_backend(&mut backend)?;
+
+    assert_eq!("exit", ans.value);
+    assert!(backend.frames()[0].has_token(&Token::Hotkeys(vec!["[s]ave".into(), "e[x]it".into()])));
+
+    let mut backend = FakeBackend::new(vec![Key::Char('1', KeyModifiers::NONE)]);
+
+    let ans = HotkeySelect::new("Action", vec!["save", "exit"])
+        .with_hotkeys(&['1', '2'])
+        .prompt_with_backend(&mut backend)?;
+
+    assert_eq!("save", ans.value);
+    assert!(
+        backend.frames()[0].has_token(&Token::Hotkeys(vec!["[1] save".into(), "[2] exit".into()]))
+    );
+
+    Ok(())
+}
+
+#[test]
+fn unassignable_hotkeys_are_rejected() {
+    let mut backend = FakeBackend::new(vec![]);
+
+    let ans =
+        HotkeySelect::new("Action", vec!["ab", "ba", "a-b"]).prompt_with_backend(&mut backend);
+
+    assert!(matches!(
+        ans,
+        Err(InquireError::InvalidConfiguration(msg)) if msg == "Could not assign a hotkey to option 'a-b'"
+    ));
+
+    let ans = HotkeySelect::new("Action", vec!["save", "exit"])
+        .with_hotkeys(&['s', 'S'])
+        .prompt_with_backend(&mut backend);
+
+    assert!(matches!(
+        ans,
+        Err(InquireError::InvalidConfiguration(msg)) if msg == "Hotkey 's' is assigned to more than one option"
+    ));
+}
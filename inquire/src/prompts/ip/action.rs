@@ -0,0 +1,23 @@
+use crate::{ui::Key, InnerAction, InputAction};
+
+use super::config::IpPromptConfig;
+
+/// Set of actions for an IpPrompt.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum IpPromptAction {
+    /// Action on the value text input handler.
+    ValueInput(InputAction),
+}
+
+impl InnerAction for IpPromptAction {
+    type Config = IpPromptConfig;
+
+    fn from_key(key: Key, _config: &IpPromptConfig) -> Option<Self> {
+        let action = match InputAction::from_key(key, &()) {
+            Some(action) => Self::ValueInput(action),
+            None => return None,
+        };
+
+        Some(action)
+    }
+}
@@ -0,0 +1,10 @@
+use crate::IpPrompt;
+
+/// Configuration settings used in the execution of an IpPrompt.
+pub struct IpPromptConfig {}
+
+impl<T> From<&IpPrompt<'_, T>> for IpPromptConfig {
+    fn from(_value: &IpPrompt<'_, T>) -> Self {
+        Self {}
+    }
+}
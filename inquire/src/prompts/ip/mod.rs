@@ -0,0 +1,263 @@
+mod action;
+mod config;
+mod network;
+mod prompt;
+#[cfg(test)]
+mod test;
+mod value;
+
+pub use action::*;
+pub use network::IpNetwork;
+pub use value::*;
+
+use crate::{
+    config::get_configuration,
+    error::{InquireError, InquireResult},
+    formatter::CustomTypeFormatter,
+    prompts::prompt::Prompt,
+    terminal::get_default_terminal,
+    ui::{Backend, CustomTypeBackend, RenderConfig},
+    validator::CustomTypeValidator,
+};
+
+use self::prompt::IpInputPrompt;
+
+/// Prompt suitable for IP addresses and networks, such as `192.168.0.1`, `2001:db8::1` or `10.0.0.0/8`.
+///
+/// The returned type defines which inputs are accepted:
+///
+/// - [`IpAddr`](std::net::IpAddr): IPv4 or IPv6 addresses.
+/// - [`Ipv4Addr`](std::net::Ipv4Addr): IPv4 addresses only.
+/// - [`Ipv6Addr`](std::net::Ipv6Addr): IPv6 addresses only.
+/// - [`IpNetwork`]: IPv4 or IPv6 networks in CIDR notation, i.e. an address followed by a prefix length.
+///
+/// The input is validated as the user types, octet by octet. Mistakes that can never become a valid value, such as an octet greater than 255 or an IPv6 group with more than 4 digits, are displayed right away in the error line above the prompt. Incomplete inputs are only rejected when the user submits them.
+///
+/// IP prompts provide several options of configuration:
+///
+/// - **Prompt message**: Required when creating the prompt.
+/// - **Starting input**: Initial value of the prompt's text input.
+/// - **Default value**: Default value returned when the user submits an empty response.
+/// - **Placeholder**: Short hint that describes the expected value of the input.
+/// - **Help message**: Message displayed at the line below the prompt.
+/// - **Formatter**: Custom formatter in case you need to pre-process the user input before showing it as the final answer.
+///   - Prints the value in its canonical notation by default, e.g. `2001:db8::1` for an input of `2001:0db8:0:0:0:0:0:1`.
+/// - **Validators**: Custom validators to the parsed value, displaying an error message if the value does not pass the requirements.
+///
+/// # Example
+///
+/// ```no_run
+/// use std::net::IpAddr;
+/// use inquire::{validator::Validation, IpPrompt};
+///
+/// let server = IpPrompt::<IpAddr>::new("Server address:")
+///     .with_placeholder("192.168.0.1")
+///     .with_validator(|ip: &IpAddr| {
+///         if ip.is_loopback() {
+///             Ok(Validation::Invalid("The server must be reachable from other hosts".into()))
+///         } else {
+///             Ok(Validation::Valid)
+///         }
+///     })
+///     .prompt();
+///
+/// match server {
+///     Ok(ip) => println!("Connecting to {}", ip),
+///     Err(_) => println!("Could not read the server address"),
+/// }
+/// ```
+#[derive(Clone)]
+pub struct IpPrompt<'a, T> {
+    /// Message to be presented to the user.
+    pub message: &'a str,
+
+    /// Initial value of the prompt's text input.
+    ///
+    /// If you want to set a default value for the prompt, returned when the user's submission is empty, see [`default`].
+    ///
+    /// [`default`]: Self::default
+    pub starting_input: Option<&'a str>,
+
+    /// Default value, returned when the user input is empty.
+    pub default: Option<T>,
+
+    /// Short hint that describes the expected value of the input.
+    pub placeholder: Option<&'a str>,
+
+    /// Help message to be presented to the user.
+    pub help_message: Option<&'a str>,
+
+    /// Function that formats the user input and presents it to the user as the final rendering of the prompt.
+    pub formatter: CustomTypeFormatter<'a, T>,
+
+    /// Function that formats the default value when it is displayed next to the prompt message.
+    pub default_value_formatter: CustomTypeFormatter<'a, T>,
+
+    /// Collection of validators to apply to the parsed value.
+    ///
+    /// Validators are executed in the order they are stored, stopping at and displaying to the user
+    /// only the first validation error that might appear.
+    ///
+    /// The possible error is displayed to the user one line above the prompt.
+    pub validators: Vec<Box<dyn CustomTypeValidator<T>>>,
+
+    /// RenderConfig to apply to the rendered interface.
+    ///
+    /// Note: The default render config considers if the NO_COLOR environment variable
+    /// is set to decide whether to render the colored config or the empty one.
+    ///
+    /// When overriding the config in a prompt, NO_COLOR is no longer considered and your
+    /// config is treated as the only source of truth. If you want to customize colors
+    /// and still support NO_COLOR, you will have to do this on your end.
+    pub render_config: RenderConfig<'a>,
+}
+
+impl<'a, T> IpPrompt<'a, T>
+where
+    T: IpValue,
+{
+    /// Default formatter, which prints the value in its canonical notation.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::net::Ipv6Addr;
+    /// use inquire::IpPrompt;
+    ///
+    /// let formatter = IpPrompt::<Ipv6Addr>::DEFAULT_FORMATTER;
+    /// assert_eq!(String::from("2001:db8::1"), formatter("2001:0db8:0:0:0:0:0:1".parse().unwrap()));
+    /// ```
+    pub const DEFAULT_FORMATTER: CustomTypeFormatter<'a, T> = &|val| val.to_string();
+
+    /// Default validators added to the [IpPrompt] prompt, none.
+    pub const DEFAULT_VALIDATORS: Vec<Box<dyn CustomTypeValidator<T>>> = vec![];
+
+    /// Creates an [IpPrompt] with the provided message, along with default configuration values.
+    pub fn new(message: &'a str) -> Self {
+        Self {
+            message,
+            starting_input: None,
+            default: None,
+            placeholder: None,
+            help_message: None,
+            formatter: Self::DEFAULT_FORMATTER,
+            default_value_formatter: Self::DEFAULT_FORMATTER,
+            validators: Self::DEFAULT_VALIDATORS,
+            render_config: get_configuration(),
+        }
+    }
+
+    /// Sets the initial value of the prompt's text input.
+    ///
+    /// If you want to set a default value for the prompt, returned when the user's submission is empty, see [`with_default`].
+    ///
+    /// [`with_default`]: Self::with_default
+    pub fn with_starting_input(mut self, message: &'a str) -> Self {
+        self.starting_input = Some(message);
+        self
+    }
+
+    /// Sets the default input.
+    pub fn with_default(mut self, default: T) -> Self {
+        self.default = Some(default);
+        self
+    }
+
+    /// Sets the placeholder.
+    pub fn with_placeholder(mut self, placeholder: &'a str) -> Self {
+        self.placeholder = Some(placeholder);
+        self
+    }
+
+    /// Sets the help message of the prompt.
+    pub fn with_help_message(mut self, message: &'a str) -> Self {
+        self.help_message = Some(message);
+        self
+    }
+
+    /// Sets the formatter.
+    pub fn with_formatter(mut self, formatter: CustomTypeFormatter<'a, T>) -> Self {
+        self.formatter = formatter;
+        self
+    }
+
+    /// Sets the formatter for default values.
+    pub fn with_default_value_formatter(mut self, formatter: CustomTypeFormatter<'a, T>) -> Self {
+        self.default_value_formatter = formatter;
+        self
+    }
+
+    /// Adds a validator to the collection of validators. You might want to use this feature
+    /// in case you need to require certain features from the parsed value, such as a private address.
+    ///
+    /// Validators are executed in the order they are stored, stopping at and displaying to the user
+    /// only the first validation error that might appear.
+    ///
+    /// The possible error is displayed to the user one line above the prompt.
+    pub fn with_validator<V>(mut self, validator: V) -> Self
+    where
+        V: CustomTypeValidator<T> + 'static,
+    {
+        self.validators.push(Box::new(validator));
+        self
+    }
+
+    /// Adds the validators to the collection of validators in the order they are given.
+    ///
+    /// Validators are executed in the order they are stored, stopping at and displaying to the user
+    /// only the first validation error that might appear.
+    ///
+    /// The possible error is displayed to the user one line above the prompt.
+    pub fn with_validators(mut self, validators: &[Box<dyn CustomTypeValidator<T>>]) -> Self {
+        for validator in validators {
+            #[allow(suspicious_double_ref_op)]
+            self.validators.push(validator.clone());
+        }
+        self
+    }
+
+    /// Sets the provided color theme to this prompt.
+    ///
+    /// Note: The default render config considers if the NO_COLOR environment variable
+    /// is set to decide whether to render the colored config or the empty one.
+    ///
+    /// When overriding the config in a prompt, NO_COLOR is no longer considered and your
+    /// config is treated as the only source of truth. If you want to customize colors
+    /// and still support NO_COLOR, you will have to do this on your end.
+    pub fn with_render_config(mut self, render_config: RenderConfig<'a>) -> Self {
+        self.render_config = render_config;
+        self
+    }
+
+    /// Parses the provided behavioral and rendering options and prompts
+    /// the CLI user for input according to the defined rules.
+    ///
+    /// This method is intended for flows where the user skipping/cancelling
+    /// the prompt - by pressing ESC - is considered normal behavior. In this case,
+    /// it does not return `Err(InquireError::OperationCanceled)`, but `Ok(None)`.
+    ///
+    /// Meanwhile, if the user does submit an answer, the method wraps the return
+    /// type with `Some`.
+    pub fn prompt_skippable(self) -> InquireResult<Option<T>> {
+        match self.prompt() {
+            Ok(answer) => Ok(Some(answer)),
+            Err(InquireError::OperationCanceled) => Ok(None),
+            Err(err) => Err(err),
+        }
+    }
+
+    /// Parses the provided behavioral and rendering options and prompts
+    /// the CLI user for input according to the defined rules.
+    pub fn prompt(self) -> InquireResult<T> {
+        let (input_reader, terminal) = get_default_terminal()?;
+        let mut backend = Backend::new(input_reader, terminal, self.render_config)?;
+        self.prompt_with_backend(&mut backend)
+    }
+
+    pub(crate) fn prompt_with_backend<B: CustomTypeBackend>(
+        self,
+        backend: &mut B,
+    ) -> InquireResult<T> {
+        IpInputPrompt::from(self).prompt(backend)
+    }
+}
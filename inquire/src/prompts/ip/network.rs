@@ -0,0 +1,59 @@
+use std::{fmt::Display, net::IpAddr};
+
+/// IP network in CIDR notation, such as `10.0.0.0/8` or `2001:db8::/32`,
+/// returned by [`IpPrompt`](crate::IpPrompt) prompts.
+///
+/// The address is kept exactly as typed by the user, which means the host
+/// bits are not required to be zero: `192.168.1.10/24` is a valid network.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct IpNetwork {
+    address: IpAddr,
+    prefix: u8,
+}
+
+impl IpNetwork {
+    /// Creates a network from an address and a prefix length.
+    ///
+    /// Returns `None` when the prefix length is greater than the amount of
+    /// bits of the address, i.e. 32 for IPv4 and 128 for IPv6.
+    ///
+    /// ```
+    /// use std::net::{IpAddr, Ipv4Addr};
+    /// use inquire::IpNetwork;
+    ///
+    /// let address = IpAddr::V4(Ipv4Addr::new(10, 0, 0, 0));
+    ///
+    /// assert_eq!("10.0.0.0/8", IpNetwork::new(address, 8).unwrap().to_string());
+    /// assert_eq!(None, IpNetwork::new(address, 33));
+    /// ```
+    pub fn new(address: IpAddr, prefix: u8) -> Option<Self> {
+        if prefix > max_prefix(&address) {
+            return None;
+        }
+
+        Some(Self { address, prefix })
+    }
+
+    /// Address of the network, as typed by the user.
+    pub fn address(&self) -> IpAddr {
+        self.address
+    }
+
+    /// Prefix length of the network, in bits.
+    pub fn prefix(&self) -> u8 {
+        self.prefix
+    }
+}
+
+impl Display for IpNetwork {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}/{}", self.address, self.prefix)
+    }
+}
+
+pub(super) fn max_prefix(address: &IpAddr) -> u8 {
+    match address {
+        IpAddr::V4(_) => 32,
+        IpAddr::V6(_) => 128,
+    }
+}
@@ -0,0 +1,153 @@
+use crate::{
+    error::InquireResult,
+    formatter::CustomTypeFormatter,
+    input::{Input, InputActionResult},
+    prompts::prompt::{ActionResult, Prompt},
+    ui::CustomTypeBackend,
+    validator::{CustomTypeValidator, ErrorMessage, Validation},
+    InquireError, IpPrompt,
+};
+
+use super::{action::IpPromptAction, config::IpPromptConfig, IpValue};
+
+pub struct IpInputPrompt<'a, T> {
+    message: &'a str,
+    config: IpPromptConfig,
+    error: Option<ErrorMessage>,
+    help_message: Option<&'a str>,
+    default: Option<T>,
+    input: Input,
+    formatter: CustomTypeFormatter<'a, T>,
+    default_value_formatter: CustomTypeFormatter<'a, T>,
+    validators: Vec<Box<dyn CustomTypeValidator<T>>>,
+}
+
+impl<'a, T> From<IpPrompt<'a, T>> for IpInputPrompt<'a, T>
+where
+    T: IpValue,
+{
+    fn from(ip: IpPrompt<'a, T>) -> Self {
+        let input = Input::new_with(ip.starting_input.unwrap_or_default());
+        let input = if let Some(placeholder) = ip.placeholder {
+            input.with_placeholder(placeholder)
+        } else {
+            input
+        };
+
+        let error = T::check_partial(input.content())
+            .err()
+            .map(ErrorMessage::Custom);
+
+        Self {
+            message: ip.message,
+            config: (&ip).into(),
+            error,
+            default: ip.default,
+            help_message: ip.help_message,
+            formatter: ip.formatter,
+            default_value_formatter: ip.default_value_formatter,
+            validators: ip.validators,
+            input,
+        }
+    }
+}
+
+impl<'a, T> IpInputPrompt<'a, T>
+where
+    T: IpValue,
+{
+    fn validate_current_answer(&self, value: &T) -> InquireResult<Validation> {
+        for validator in &self.validators {
+            match validator.validate(value) {
+                Ok(Validation::Valid) => {}
+                Ok(Validation::Invalid(msg)) => return Ok(Validation::Invalid(msg)),
+                Err(err) => return Err(InquireError::Custom(err)),
+            }
+        }
+
+        Ok(Validation::Valid)
+    }
+
+    fn get_final_answer(&self) -> Result<T, String> {
+        match self.default {
+            Some(val) if self.input.content().is_empty() => Ok(val),
+            _ => T::parse_ip(self.input.content()),
+        }
+    }
+}
+
+impl<'a, Backend, T> Prompt<Backend> for IpInputPrompt<'a, T>
+where
+    Backend: CustomTypeBackend,
+    T: IpValue,
+{
+    type Config = IpPromptConfig;
+    type InnerAction = IpPromptAction;
+    type Output = T;
+
+    fn message(&self) -> &str {
+        self.message
+    }
+
+    fn config(&self) -> &IpPromptConfig {
+        &self.config
+    }
+
+    fn format_answer(&self, answer: &T) -> String {
+        (self.formatter)(*answer)
+    }
+
+    fn submit(&mut self) -> InquireResult<Option<T>> {
+        let answer = match self.get_final_answer() {
+            Ok(answer) => match self.validate_current_answer(&answer)? {
+                Validation::Valid => Some(answer),
+                Validation::Invalid(msg) => {
+                    self.error = Some(msg);
+                    None
+                }
+            },
+            Err(message) => {
+                self.error = Some(message.into());
+                None
+            }
+        };
+
+        Ok(answer)
+    }
+
+    fn handle(&mut self, action: IpPromptAction) -> InquireResult<ActionResult> {
+        let result = match action {
+            IpPromptAction::ValueInput(input_action) => {
+                let result = self.input.handle(input_action);
+
+                if let InputActionResult::ContentChanged = result {
+                    self.error = T::check_partial(self.input.content())
+                        .err()
+                        .map(ErrorMessage::Custom);
+                }
+
+                result.into()
+            }
+        };
+
+        Ok(result)
+    }
+
+    fn render(&self, backend: &mut Backend) -> InquireResult<()> {
+        let prompt = &self.message;
+
+        if let Some(error) = &self.error {
+            backend.render_error_message(error)?;
+        }
+
+        let default_message = self.default.map(self.default_value_formatter);
+
+        backend.render_prompt(prompt, default_message.as_deref(), &self.input)?;
+
+        if let Some(message) = self.help_message {
+            backend.render_help_message(message)?;
+        }
+
+        Ok(())
+    }
+}
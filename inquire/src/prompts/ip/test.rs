@@ -0,0 +1,137 @@
+use std::net::{IpAddr, Ipv4Addr, Ipv6Addr};
+
+use crate::{
+    error::InquireResult,
+    ui::{
+        test::{FakeBackend, Token},
+        Key,
+    },
+    validator::{ErrorMessage, Validation},
+    IpNetwork, IpPrompt,
+};
+
+#[test]
+fn typed_address_is_parsed() -> InquireResult<()> {
+    let mut keys = Key::char_keys_from_str("192.168.0.1");
+    keys.push(Key::Enter);
+    let mut backend = FakeBackend::new(keys);
+
+    let ans = IpPrompt::<IpAddr>::new("Question").prompt_with_backend(&mut backend)?;
+
+    assert_eq!(IpAddr::V4(Ipv4Addr::new(192, 168, 0, 1)), ans);
+
+    Ok(())
+}
+
+#[test]
+fn ipv6_answer_is_canonical() -> InquireResult<()> {
+    let mut keys = Key::char_keys_from_str("2001:0db8:0:0:0:0:0:1");
+    keys.push(Key::Enter);
+    let mut backend = FakeBackend::new(keys);
+
+    let ans = IpPrompt::<Ipv6Addr>::new("Question").prompt_with_backend(&mut backend)?;
+
+    assert_eq!(Ipv6Addr::new(0x2001, 0xdb8, 0, 0, 0, 0, 0, 1), ans);
+    assert!(backend
+        .frames()
+        .last()
+        .unwrap()
+        .has_token(&Token::AnsweredPrompt(
+            "Question".into(),
+            "2001:db8::1".into()
+        )));
+
+    Ok(())
+}
+
+#[test]
+fn octets_are_validated_as_you_type() -> InquireResult<()> {
+    let mut keys = Key::char_keys_from_str("10.256");
+    keys.push(Key::Backspace);
+    keys.push(Key::Backspace);
+    keys.extend(Key::char_keys_from_str("0.0.1"));
+    keys.push(Key::Enter);
+    let mut backend = FakeBackend::new(keys);
+
+    let ans = IpPrompt::<Ipv4Addr>::new("Question").prompt_with_backend(&mut backend)?;
+
+    let error = Token::ErrorMessage(ErrorMessage::Custom("Octet 256 is greater than 255".into()));
+    assert!(!backend.frames()[5].has_token(&error));
+    assert!(backend.frames()[6].has_token(&error));
+    assert!(!backend.frames()[7].has_token(&error));
+    assert_eq!(Ipv4Addr::new(10, 20, 0, 1), ans);
+
+    Ok(())
+}
+
+#[test]
+fn incomplete_address_is_not_submitted() -> InquireResult<()> {
+    let mut keys = Key::char_keys_from_str("10.0.0");
+    keys.push(Key::Enter);
+    keys.extend(Key::char_keys_from_str(".1"));
+    keys.push(Key::Enter);
+    let mut backend = FakeBackend::new(keys);
+
+    let ans = IpPrompt::<Ipv4Addr>::new("Question").prompt_with_backend(&mut backend)?;
+
+    assert_eq!(Ipv4Addr::new(10, 0, 0, 1), ans);
+    assert!(
+        backend.frames()[7].has_token(&Token::ErrorMessage(ErrorMessage::Custom(
+            "Incomplete IPv4 address, e.g. 192.168.0.1".into()
+        )))
+    );
+
+    Ok(())
+}
+
+#[test]
+fn network_requires_prefix_length() -> InquireResult<()> {
+    let mut keys = Key::char_keys_from_str("10.0.0.0");
+    keys.push(Key::Enter);
+    keys.extend(Key::char_keys_from_str("/8"));
+    keys.push(Key::Enter);
+    let mut backend = FakeBackend::new(keys);
+
+    let ans = IpPrompt::<IpNetwork>::new("Question").prompt_with_backend(&mut backend)?;
+
+    assert_eq!(
+        IpNetwork::new(IpAddr::V4(Ipv4Addr::new(10, 0, 0, 0)), 8),
+        Some(ans)
+    );
+    assert!(
+        backend.frames()[9].has_token(&Token::ErrorMessage(ErrorMessage::Custom(
+            "Missing prefix length, e.g. 10.0.0.0/8".into()
+        )))
+    );
+
+    Ok(())
+}
+
+#[test]
+fn empty_input_returns_default_and_runs_validators() -> InquireResult<()> {
+    let mut keys = vec![Key::Enter];
+    keys.extend(Key::char_keys_from_str("10.0.0.1"));
+    keys.push(Key::Enter);
+    let mut backend = FakeBackend::new(keys);
+
+    let ans = IpPrompt::<IpAddr>::new("Question")
+        .with_default(IpAddr::V4(Ipv4Addr::LOCALHOST))
+        .with_validator(|ip: &IpAddr| {
+            if ip.is_loopback() {
+                Ok(Validation::Invalid("Loopback".into()))
+            } else {
+                Ok(Validation::Valid)
+            }
+        })
+        .prompt_with_backend(&mut backend)?;
+
+    assert_eq!(IpAddr::V4(Ipv4Addr::new(10, 0, 0, 1)), ans);
+    assert!(backend.frames()[0].has_token(&Token::DefaultValue("127.0.0.1".into())));
+    assert!(
+        backend.frames()[1].has_token(&Token::ErrorMessage(ErrorMessage::Custom(
+            "Loopback".into()
+        )))
+    );
+
+    Ok(())
+}
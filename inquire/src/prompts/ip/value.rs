@@ -0,0 +1,262 @@
+use std::{
+    fmt::Display,
+    net::{IpAddr, Ipv4Addr, Ipv6Addr},
+};
+
+use super::network::{max_prefix, IpNetwork};
+
+/// Values that can be entered in [`IpPrompt`](crate::IpPrompt) prompts.
+///
+/// Implementations are provided for [`IpAddr`], [`Ipv4Addr`], [`Ipv6Addr`] and
+/// [`IpNetwork`], the latter accepting addresses of both families in CIDR notation.
+pub trait IpValue: Copy + Display {
+    /// Checks an input that might still be incomplete, such as `192.168.`,
+    /// returning the reason why it can never become a valid value, e.g. an
+    /// octet greater than 255.
+    fn check_partial(input: &str) -> Result<(), String>;
+
+    /// Parses a complete input into a value.
+    fn parse_ip(input: &str) -> Result<Self, String>;
+}
+
+#[derive(Copy, Clone, PartialEq, Eq)]
+enum Family {
+    V4,
+    V6,
+    Any,
+}
+
+impl IpValue for Ipv4Addr {
+    fn check_partial(input: &str) -> Result<(), String> {
+        check_address(input.trim(), Family::V4)
+    }
+
+    fn parse_ip(input: &str) -> Result<Self, String> {
+        let input = input.trim();
+        check_address(input, Family::V4)?;
+
+        input
+            .parse()
+            .map_err(|_e| String::from("Incomplete IPv4 address, e.g. 192.168.0.1"))
+    }
+}
+
+impl IpValue for Ipv6Addr {
+    fn check_partial(input: &str) -> Result<(), String> {
+        check_address(input.trim(), Family::V6)
+    }
+
+    fn parse_ip(input: &str) -> Result<Self, String> {
+        let input = input.trim();
+        check_address(input, Family::V6)?;
+
+        input
+            .parse()
+            .map_err(|_e| String::from("Incomplete IPv6 address, e.g. 2001:db8::1"))
+    }
+}
+
+impl IpValue for IpAddr {
+    fn check_partial(input: &str) -> Result<(), String> {
+        check_address(input.trim(), Family::Any)
+    }
+
+    fn parse_ip(input: &str) -> Result<Self, String> {
+        if looks_like_v6(input) {
+            Ipv6Addr::parse_ip(input).map(IpAddr::V6)
+        } else {
+            Ipv4Addr::parse_ip(input).map(IpAddr::V4)
+        }
+    }
+}
+
+impl IpValue for IpNetwork {
+    fn check_partial(input: &str) -> Result<(), String> {
+        let input = input.trim();
+
+        match input.split_once('/') {
+            Some((address, prefix)) => {
+                check_address(address, Family::Any)?;
+                let max = if looks_like_v6(address) { 128 } else { 32 };
+                check_prefix(prefix, max)
+            }
+            None => check_address(input, Family::Any),
+        }
+    }
+
+    fn parse_ip(input: &str) -> Result<Self, String> {
+        let missing_prefix = || String::from("Missing prefix length, e.g. 10.0.0.0/8");
+
+        let (address, prefix) = input.trim().split_once('/').ok_or_else(missing_prefix)?;
+        let address = IpAddr::parse_ip(address)?;
+
+        check_prefix(prefix, max_prefix(&address))?;
+        let prefix = prefix.parse::<u8>().map_err(|_e| missing_prefix())?;
+
+        IpNetwork::new(address, prefix).ok_or_else(missing_prefix)
+    }
+}
+
+fn looks_like_v6(input: &str) -> bool {
+    input
+        .chars()
+        .any(|c| c == ':' || (c.is_ascii_hexdigit() && !c.is_ascii_digit()))
+}
+
+fn check_address(input: &str, family: Family) -> Result<(), String> {
+    let is_v6 = match family {
+        Family::V4 => false,
+        Family::V6 => true,
+        Family::Any => looks_like_v6(input),
+    };
+
+    if is_v6 {
+        check_v6(input)
+    } else if input.contains(':') {
+        Err(String::from("Expected an IPv4 address"))
+    } else {
+        check_v4(input)
+    }
+}
+
+fn check_v4(input: &str) -> Result<(), String> {
+    let octets = input.split('.').collect::<Vec<&str>>();
+
+    if octets.len() > 4 {
+        return Err(String::from("An IPv4 address has only 4 octets"));
+    }
+
+    for (i, octet) in octets.iter().enumerate() {
+        if let Some(c) = octet.chars().find(|c| !c.is_ascii_digit()) {
+            return Err(format!("Invalid character '{c}' in IPv4 address"));
+        }
+
+        if octet.is_empty() && i + 1 < octets.len() {
+            return Err(String::from("Empty octet in IPv4 address"));
+        }
+
+        if octet.len() > 3 {
+            return Err(format!("Octet {octet} has more than 3 digits"));
+        }
+
+        if octet.len() > 1 && octet.starts_with('0') {
+            return Err(format!("Octet {octet} has a leading zero"));
+        }
+
+        if octet.parse::<u16>().unwrap_or_default() > 255 {
+            return Err(format!("Octet {octet} is greater than 255"));
+        }
+    }
+
+    Ok(())
+}
+
+fn check_v6(input: &str) -> Result<(), String> {
+    if input.matches("::").count() > 1 {
+        return Err(String::from("'::' can only appear once in an IPv6 address"));
+    }
+
+    let groups = input.split(':').collect::<Vec<&str>>();
+
+    for (i, group) in groups.iter().enumerate() {
+        if group.contains('.') {
+            if i + 1 < groups.len() {
+                return Err(String::from(
+                    "An embedded IPv4 address must be at the end of the IPv6 address",
+                ));
+            }
+
+            return check_v4(group);
+        }
+
+        if let Some(c) = group.chars().find(|c| !c.is_ascii_hexdigit()) {
+            return Err(format!("Invalid character '{c}' in IPv6 address"));
+        }
+
+        if group.len() > 4 {
+            return Err(format!("Group {group} has more than 4 hexadecimal digits"));
+        }
+    }
+
+    if groups.iter().filter(|group| !group.is_empty()).count() > 8 {
+        return Err(String::from("An IPv6 address has at most 8 groups"));
+    }
+
+    Ok(())
+}
+
+fn check_prefix(prefix: &str, max: u8) -> Result<(), String> {
+    if let Some(c) = prefix.chars().find(|c| !c.is_ascii_digit()) {
+        return Err(format!("Invalid character '{c}' in prefix length"));
+    }
+
+    let within_bounds = prefix
+        .parse::<u8>()
+        .ok()
+        .filter(|prefix| *prefix <= max)
+        .is_some();
+
+    if !prefix.is_empty() && !within_bounds {
+        return Err(format!("Prefix length must be at most {max}"));
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod test {
+    use std::net::{IpAddr, Ipv4Addr, Ipv6Addr};
+
+    use super::IpValue;
+    use crate::IpNetwork;
+
+    #[test]
+    fn partial_inputs_are_checked_per_octet() {
+        assert!(Ipv4Addr::check_partial("").is_ok());
+        assert!(Ipv4Addr::check_partial("192.168.").is_ok());
+        assert_eq!(
+            Err(String::from("Octet 300 is greater than 255")),
+            Ipv4Addr::check_partial("10.300")
+        );
+        assert!(Ipv4Addr::check_partial("1..").is_err());
+        assert!(Ipv4Addr::check_partial("1.2.3.4.").is_err());
+        assert!(Ipv4Addr::check_partial("01").is_err());
+        assert!(Ipv4Addr::check_partial("fe80::").is_err());
+    }
+
+    #[test]
+    fn partial_ipv6_inputs_are_checked_per_group() {
+        assert!(Ipv6Addr::check_partial("2001:db8::").is_ok());
+        assert!(Ipv6Addr::check_partial("::ffff:10.0.").is_ok());
+        assert!(Ipv6Addr::check_partial("2001:db8g").is_err());
+        assert!(Ipv6Addr::check_partial("12345:").is_err());
+        assert!(Ipv6Addr::check_partial("1::2::").is_err());
+        assert!(IpAddr::check_partial("fe80::1").is_ok());
+    }
+
+    #[test]
+    fn complete_inputs_are_parsed() {
+        assert_eq!(
+            Ok(IpAddr::V4(Ipv4Addr::new(10, 0, 0, 1))),
+            IpAddr::parse_ip(" 10.0.0.1 ")
+        );
+        assert_eq!(Ok(IpAddr::V6(Ipv6Addr::LOCALHOST)), IpAddr::parse_ip("::1"));
+        assert!(Ipv4Addr::parse_ip("10.0.0").is_err());
+        assert!(Ipv6Addr::parse_ip("2001:db8").is_err());
+    }
+
+    #[test]
+    fn networks_require_a_valid_prefix() {
+        let network = IpNetwork::parse_ip("10.0.0.0/8").unwrap();
+        assert_eq!(IpAddr::V4(Ipv4Addr::new(10, 0, 0, 0)), network.address());
+        assert_eq!(8, network.prefix());
+
+        assert!(IpNetwork::parse_ip("2001:db8::/32").is_ok());
+        assert!(IpNetwork::parse_ip("2001:db8::/64").is_ok());
+        assert!(IpNetwork::parse_ip("10.0.0.0").is_err());
+        assert!(IpNetwork::parse_ip("10.0.0.0/").is_err());
+        assert!(IpNetwork::check_partial("10.0.0.0/33").is_err());
+        assert!(IpNetwork::check_partial("::/129").is_err());
+        assert!(IpNetwork::check_partial("::/120").is_ok());
+    }
+}
@@ -0,0 +1,24 @@
+use crate::{ui::Key, InnerAction};
+
+use super::config::KeyCaptureConfig;
+
+/// Set of actions for a KeyCapturePrompt.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum KeyCapturePromptAction {
+    /// Records the pressed key combination as the answer of the prompt.
+    Capture(Key),
+}
+
+impl InnerAction for KeyCapturePromptAction {
+    type Config = KeyCaptureConfig;
+
+    fn from_key(key: Key, _config: &KeyCaptureConfig) -> Option<Self> {
+        #[allow(deprecated)]
+        let action = match key {
+            Key::Any => return None,
+            key => Self::Capture(key),
+        };
+
+        Some(action)
+    }
+}
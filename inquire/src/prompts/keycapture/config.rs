@@ -0,0 +1,16 @@
+use crate::{ui::Key, KeyCapture};
+
+/// Configuration settings used in the execution of a KeyCapturePrompt.
+#[derive(Copy, Clone, Debug)]
+pub struct KeyCaptureConfig {
+    /// Key that cancels the prompt instead of being captured.
+    pub cancel_key: Key,
+}
+
+impl From<&KeyCapture<'_>> for KeyCaptureConfig {
+    fn from(value: &KeyCapture<'_>) -> Self {
+        Self {
+            cancel_key: value.cancel_key,
+        }
+    }
+}
@@ -0,0 +1,239 @@
+mod action;
+mod config;
+mod prompt;
+#[cfg(test)]
+mod test;
+
+pub use action::*;
+
+use crate::{
+    config::get_configuration,
+    error::{InquireError, InquireResult},
+    formatter::KeyFormatter,
+    prompts::prompt::Prompt,
+    terminal::get_default_terminal,
+    ui::{Backend, CustomTypeBackend, Key, RenderConfig},
+    validator::CustomTypeValidator,
+};
+
+use self::prompt::KeyCapturePrompt;
+
+/// Prompt that captures the next key combination pressed by the user, such as `Ctrl+Shift+K`, useful when letting users configure their own shortcuts.
+///
+/// As soon as a key is pressed, the prompt returns its [`Key`] representation. This includes the keys that are usually reserved by other prompts, such as enter, so that they can be captured as well.
+///
+/// Two keys can not be captured: the cancel key, escape by default, which cancels the prompt as usual; and `Ctrl+C`, which interrupts it. The cancel key can be reassigned when escape itself should be a valid answer.
+///
+/// Keep in mind that the key combinations available depend on the terminal and on the terminal back-end, e.g. `termion` and `console` do not report modifiers for most keys other than characters.
+///
+/// Key capture prompts provide several options of configuration:
+///
+/// - **Prompt message**: Required when creating the prompt.
+/// - **Cancel key**: Key that cancels the prompt instead of being captured, escape by default.
+/// - **Placeholder**: Short hint displayed while the prompt waits for a key.
+/// - **Help message**: Message displayed at the line below the prompt.
+/// - **Formatter**: Custom formatter in case you need to pre-process the user input before showing it as the final answer.
+///   - Prints the key combination by default, e.g. `Ctrl+Shift+K`.
+/// - **Validators**: Custom validators to the captured key, displaying an error message and waiting for another key if the key does not pass the requirements, e.g. when it is already bound.
+///
+/// # Example
+///
+/// ```no_run
+/// use inquire::{ui::{Key, KeyModifiers}, validator::Validation, KeyCapture};
+///
+/// let shortcut = KeyCapture::new("Shortcut to open the command palette:")
+///     .with_validator(|key: &Key| match key {
+///         Key::Char('q', KeyModifiers::CONTROL) => {
+///             Ok(Validation::Invalid("Ctrl+Q is already bound to quit".into()))
+///         }
+///         _ => Ok(Validation::Valid),
+///     })
+///     .prompt();
+///
+/// match shortcut {
+///     Ok(key) => println!("The command palette now opens with {}", key),
+///     Err(_) => println!("The shortcut was not changed"),
+/// }
+/// ```
+#[derive(Clone)]
+pub struct KeyCapture<'a> {
+    /// Message to be presented to the user.
+    pub message: &'a str,
+
+    /// Key that cancels the prompt instead of being captured.
+    pub cancel_key: Key,
+
+    /// Short hint displayed while the prompt waits for a key.
+    pub placeholder: Option<&'a str>,
+
+    /// Help message to be presented to the user.
+    pub help_message: Option<&'a str>,
+
+    /// Function that formats the captured key and presents it to the user as the final rendering of the prompt.
+    pub formatter: KeyFormatter<'a>,
+
+    /// Collection of validators to apply to the captured key.
+    ///
+    /// Validators are executed in the order they are stored, stopping at and displaying to the user
+    /// only the first validation error that might appear.
+    ///
+    /// The possible error is displayed to the user one line above the prompt.
+    pub validators: Vec<Box<dyn CustomTypeValidator<Key>>>,
+
+    /// RenderConfig to apply to the rendered interface.
+    ///
+    /// Note: The default render config considers if the NO_COLOR environment variable
+    /// is set to decide whether to render the colored config or the empty one.
+    ///
+    /// When overriding the config in a prompt, NO_COLOR is no longer considered and your
+    /// config is treated as the only source of truth. If you want to customize colors
+    /// and still support NO_COLOR, you will have to do this on your end.
+    pub render_config: RenderConfig<'a>,
+}
+
+impl<'a> KeyCapture<'a> {
+    /// Default formatter, which prints the key combination with the modifiers before the key name.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use inquire::{ui::{Key, KeyModifiers}, KeyCapture};
+    ///
+    /// let formatter = KeyCapture::DEFAULT_FORMATTER;
+    /// assert_eq!(String::from("Ctrl+K"), formatter(Key::Char('k', KeyModifiers::CONTROL)));
+    /// assert_eq!(String::from("Shift+K"), formatter(Key::Char('K', KeyModifiers::NONE)));
+    /// ```
+    pub const DEFAULT_FORMATTER: KeyFormatter<'a> = &|key| key.to_string();
+
+    /// Default key that cancels the prompt.
+    pub const DEFAULT_CANCEL_KEY: Key = Key::Escape;
+
+    /// Default validators added to the [KeyCapture] prompt, none.
+    pub const DEFAULT_VALIDATORS: Vec<Box<dyn CustomTypeValidator<Key>>> = vec![];
+
+    /// Default placeholder.
+    pub const DEFAULT_PLACEHOLDER: Option<&'a str> = Some("press a key combination");
+
+    /// Default help message.
+    pub const DEFAULT_HELP_MESSAGE: Option<&'a str> = Some("esc to cancel");
+
+    /// Creates a [KeyCapture] with the provided message, along with default configuration values.
+    pub fn new(message: &'a str) -> Self {
+        Self {
+            message,
+            cancel_key: Self::DEFAULT_CANCEL_KEY,
+            placeholder: Self::DEFAULT_PLACEHOLDER,
+            help_message: Self::DEFAULT_HELP_MESSAGE,
+            formatter: Self::DEFAULT_FORMATTER,
+            validators: Self::DEFAULT_VALIDATORS,
+            render_config: get_configuration(),
+        }
+    }
+
+    /// Sets the key that cancels the prompt instead of being captured.
+    ///
+    /// Remember to update the help message accordingly, as the default one mentions escape.
+    pub fn with_cancel_key(mut self, cancel_key: Key) -> Self {
+        self.cancel_key = cancel_key;
+        self
+    }
+
+    /// Sets the placeholder.
+    pub fn with_placeholder(mut self, placeholder: &'a str) -> Self {
+        self.placeholder = Some(placeholder);
+        self
+    }
+
+    /// Sets the help message of the prompt.
+    pub fn with_help_message(mut self, message: &'a str) -> Self {
+        self.help_message = Some(message);
+        self
+    }
+
+    /// Removes the set help message.
+    pub fn without_help_message(mut self) -> Self {
+        self.help_message = None;
+        self
+    }
+
+    /// Sets the formatter.
+    pub fn with_formatter(mut self, formatter: KeyFormatter<'a>) -> Self {
+        self.formatter = formatter;
+        self
+    }
+
+    /// Adds a validator to the collection of validators. You might want to use this feature
+    /// in case you need to reject certain keys, such as the ones already bound to other actions.
+    ///
+    /// Validators are executed in the order they are stored, stopping at and displaying to the user
+    /// only the first validation error that might appear.
+    ///
+    /// The possible error is displayed to the user one line above the prompt.
+    pub fn with_validator<V>(mut self, validator: V) -> Self
+    where
+        V: CustomTypeValidator<Key> + 'static,
+    {
+        self.validators.push(Box::new(validator));
+        self
+    }
+
+    /// Adds the validators to the collection of validators in the order they are given.
+    ///
+    /// Validators are executed in the order they are stored, stopping at and displaying to the user
+    /// only the first validation error that might appear.
+    ///
+    /// The possible error is displayed to the user one line above the prompt.
+    pub fn with_validators(mut self, validators: &[Box<dyn CustomTypeValidator<Key>>]) -> Self {
+        for validator in validators {
+            #[allow(suspicious_double_ref_op)]
+            self.validators.push(validator.clone());
+        }
+        self
+    }
+
+    /// Sets the provided color theme to this prompt.
+    ///
+    /// Note: The default render config considers if the NO_COLOR environment variable
+    /// is set to decide whether to render the colored config or the empty one.
+    ///
+    /// When overriding the config in a prompt, NO_COLOR is no longer considered and your
+    /// config is treated as the only source of truth. If you want to customize colors
+    /// and still support NO_COLOR, you will have to do this on your end.
+    pub fn with_render_config(mut self, render_config: RenderConfig<'a>) -> Self {
+        self.render_config = render_config;
+        self
+    }
+
+    /// Parses the provided behavioral and rendering options and prompts
+    /// the CLI user for input according to the defined rules.
+    ///
+    /// This method is intended for flows where the user skipping/cancelling
+    /// the prompt - by pressing the cancel key - is considered normal behavior.
+    /// In this case, it does not return `Err(InquireError::OperationCanceled)`,
+    /// but `Ok(None)`.
+    ///
+    /// Meanwhile, if the user does submit an answer, the method wraps the return
+    /// type with `Some`.
+    pub fn prompt_skippable(self) -> InquireResult<Option<Key>> {
+        match self.prompt() {
+            Ok(answer) => Ok(Some(answer)),
+            Err(InquireError::OperationCanceled) => Ok(None),
+            Err(err) => Err(err),
+        }
+    }
+
+    /// Parses the provided behavioral and rendering options and prompts
+    /// the CLI user for input according to the defined rules.
+    pub fn prompt(self) -> InquireResult<Key> {
+        let (input_reader, terminal) = get_default_terminal()?;
+        let mut backend = Backend::new(input_reader, terminal, self.render_config)?;
+        self.prompt_with_backend(&mut backend)
+    }
+
+    pub(crate) fn prompt_with_backend<B: CustomTypeBackend>(
+        self,
+        backend: &mut B,
+    ) -> InquireResult<Key> {
+        KeyCapturePrompt::from(self).prompt(backend)
+    }
+}
@@ -0,0 +1,140 @@
+use std::cell::Cell;
+
+use crate::{
+    error::InquireResult,
+    formatter::KeyFormatter,
+    input::Input,
+    prompts::prompt::{ActionResult, Prompt},
+    ui::{CustomTypeBackend, Key, KeyModifiers},
+    validator::{CustomTypeValidator, ErrorMessage, Validation},
+    Action, InnerAction, InquireError, KeyCapture,
+};
+
+use super::{action::KeyCapturePromptAction, config::KeyCaptureConfig};
+
+pub struct KeyCapturePrompt<'a> {
+    message: &'a str,
+    config: KeyCaptureConfig,
+    error: Option<ErrorMessage>,
+    help_message: Option<&'a str>,
+    input: Input,
+    captured: Cell<Option<Key>>,
+    formatter: KeyFormatter<'a>,
+    validators: Vec<Box<dyn CustomTypeValidator<Key>>>,
+}
+
+impl<'a> From<KeyCapture<'a>> for KeyCapturePrompt<'a> {
+    fn from(kc: KeyCapture<'a>) -> Self {
+        let input = match kc.placeholder {
+            Some(placeholder) => Input::new().with_placeholder(placeholder),
+            None => Input::new(),
+        };
+
+        Self {
+            message: kc.message,
+            config: (&kc).into(),
+            error: None,
+            help_message: kc.help_message,
+            input,
+            captured: Cell::new(None),
+            formatter: kc.formatter,
+            validators: kc.validators,
+        }
+    }
+}
+
+impl<'a> KeyCapturePrompt<'a> {
+    fn validate_current_answer(&self, value: &Key) -> InquireResult<Validation> {
+        for validator in &self.validators {
+            match validator.validate(value) {
+                Ok(Validation::Valid) => {}
+                Ok(Validation::Invalid(msg)) => return Ok(Validation::Invalid(msg)),
+                Err(err) => return Err(InquireError::Custom(err)),
+            }
+        }
+
+        Ok(Validation::Valid)
+    }
+}
+
+impl<'a, Backend> Prompt<Backend> for KeyCapturePrompt<'a>
+where
+    Backend: CustomTypeBackend,
+{
+    type Config = KeyCaptureConfig;
+    type InnerAction = KeyCapturePromptAction;
+    type Output = Key;
+
+    fn message(&self) -> &str {
+        self.message
+    }
+
+    fn config(&self) -> &KeyCaptureConfig {
+        &self.config
+    }
+
+    fn format_answer(&self, answer: &Key) -> String {
+        (self.formatter)(*answer)
+    }
+
+    fn parse_key(&self, key: Key) -> Option<Action<KeyCapturePromptAction>> {
+        // Every key other than the cancel key and Ctrl-C is a possible answer,
+        // including the ones shared by all prompts, such as enter. As the prompt
+        // is submitted as soon as a key is pressed, the key is stashed here to
+        // be picked up by `submit`.
+        if key == self.config.cancel_key {
+            return Some(Action::Cancel);
+        }
+
+        if key == Key::Char('c', KeyModifiers::CONTROL) {
+            return Some(Action::Interrupt);
+        }
+
+        match KeyCapturePromptAction::from_key(key, &self.config)? {
+            KeyCapturePromptAction::Capture(key) => self.captured.set(Some(key)),
+        }
+
+        Some(Action::Submit)
+    }
+
+    fn submit(&mut self) -> InquireResult<Option<Key>> {
+        let key = match self.captured.take() {
+            Some(key) => key,
+            None => return Ok(None),
+        };
+
+        let answer = match self.validate_current_answer(&key)? {
+            Validation::Valid => Some(key),
+            Validation::Invalid(msg) => {
+                self.error = Some(msg);
+                None
+            }
+        };
+
+        Ok(answer)
+    }
+
+    fn handle(&mut self, action: KeyCapturePromptAction) -> InquireResult<ActionResult> {
+        match action {
+            KeyCapturePromptAction::Capture(key) => self.captured.set(Some(key)),
+        }
+
+        Ok(ActionResult::Clean)
+    }
+
+    fn render(&self, backend: &mut Backend) -> InquireResult<()> {
+        let prompt = &self.message;
+
+        if let Some(error) = &self.error {
+            backend.render_error_message(error)?;
+        }
+
+        backend.render_prompt(prompt, None, &self.input)?;
+
+        if let Some(message) = self.help_message {
+            backend.render_help_message(message)?;
+        }
+
+        Ok(())
+    }
+}
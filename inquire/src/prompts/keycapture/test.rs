@@ -0,0 +1,98 @@
+use crate::{
+    error::InquireResult,
+    ui::{
+        test::{FakeBackend, Token},
+        Key, KeyModifiers,
+    },
+    validator::{ErrorMessage, Validation},
+    InquireError, KeyCapture,
+};
+
+#[test]
+fn next_key_is_captured() -> InquireResult<()> {
+    let key = Key::Char('K', KeyModifiers::CONTROL | KeyModifiers::SHIFT);
+    let mut backend = FakeBackend::new(vec![key]);
+
+    let ans = KeyCapture::new("Question").prompt_with_backend(&mut backend)?;
+
+    assert_eq!(key, ans);
+    assert!(backend.frames()[1].has_token(&Token::AnsweredPrompt(
+        "Question".into(),
+        "Ctrl+Shift+K".into()
+    )));
+
+    Ok(())
+}
+
+#[test]
+fn shared_keybindings_are_captured() -> InquireResult<()> {
+    let ans =
+        KeyCapture::new("Question").prompt_with_backend(&mut FakeBackend::new(vec![Key::Enter]))?;
+    assert_eq!(Key::Enter, ans);
+
+    let ans =
+        KeyCapture::new("Question").prompt_with_backend(&mut FakeBackend::new(vec![Key::Char(
+            'd',
+            KeyModifiers::CONTROL,
+        )]))?;
+    assert_eq!(Key::Char('d', KeyModifiers::CONTROL), ans);
+
+    Ok(())
+}
+
+#[test]
+fn escape_cancels_prompt() {
+    let mut backend = FakeBackend::new(vec![Key::Escape]);
+
+    let ans = KeyCapture::new("Question").prompt_with_backend(&mut backend);
+
+    assert!(matches!(ans, Err(InquireError::OperationCanceled)));
+}
+
+#[test]
+fn ctrl_c_interrupts_prompt() {
+    let mut backend = FakeBackend::new(vec![Key::Char('c', KeyModifiers::CONTROL)]);
+
+    let ans = KeyCapture::new("Question").prompt_with_backend(&mut backend);
+
+    assert!(matches!(ans, Err(InquireError::OperationInterrupted)));
+}
+
+#[test]
+fn escape_is_captured_with_custom_cancel_key() -> InquireResult<()> {
+    let cancel_key = Key::Char('x', KeyModifiers::CONTROL);
+
+    let ans = KeyCapture::new("Question")
+        .with_cancel_key(cancel_key)
+        .prompt_with_backend(&mut FakeBackend::new(vec![Key::Escape]))?;
+    assert_eq!(Key::Escape, ans);
+
+    let ans = KeyCapture::new("Question")
+        .with_cancel_key(cancel_key)
+        .prompt_with_backend(&mut FakeBackend::new(vec![cancel_key]));
+    assert!(matches!(ans, Err(InquireError::OperationCanceled)));
+
+    Ok(())
+}
+
+#[test]
+fn rejected_key_waits_for_another_one() -> InquireResult<()> {
+    let mut backend = FakeBackend::new(vec![
+        Key::Char('q', KeyModifiers::CONTROL),
+        Key::Char('p', KeyModifiers::CONTROL),
+    ]);
+
+    let ans = KeyCapture::new("Question")
+        .with_validator(|key: &Key| match key {
+            Key::Char('q', KeyModifiers::CONTROL) => Ok(Validation::Invalid("Bound".into())),
+            _ => Ok(Validation::Valid),
+        })
+        .prompt_with_backend(&mut backend)?;
+
+    assert_eq!(Key::Char('p', KeyModifiers::CONTROL), ans);
+    assert!(
+        backend.frames()[1].has_token(&Token::ErrorMessage(ErrorMessage::Custom("Bound".into())))
+    );
+
+    Ok(())
+}
@@ -0,0 +1,64 @@
+use crate::{
+    ui::{Key, KeyModifiers},
+    InnerAction, InputAction,
+};
+
+use super::config::ListEditorConfig;
+
+/// Set of actions for a ListEditorPrompt.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum ListEditorPromptAction {
+    /// Action on the text input handler of the item being edited.
+    ValueInput(InputAction),
+    /// Moves the cursor to the item above.
+    MoveUp,
+    /// Moves the cursor to the item below.
+    MoveDown,
+    /// Moves the cursor to the page above.
+    PageUp,
+    /// Moves the cursor to the page below.
+    PageDown,
+    /// Moves the cursor to the start of the list.
+    MoveToStart,
+    /// Moves the cursor to the end of the list.
+    MoveToEnd,
+    /// Swaps the highlighted item with the one above it.
+    MoveItemUp,
+    /// Swaps the highlighted item with the one below it.
+    MoveItemDown,
+    /// Starts typing a new item, added below the highlighted one.
+    AddItem,
+    /// Starts editing the highlighted item.
+    EditItem,
+    /// Removes the highlighted item.
+    RemoveItem,
+}
+
+impl InnerAction for ListEditorPromptAction {
+    type Config = ListEditorConfig;
+
+    fn from_key(key: Key, _config: &ListEditorConfig) -> Option<Self> {
+        let action = match key {
+            Key::Up(KeyModifiers::NONE) | Key::Char('p', KeyModifiers::CONTROL) => Self::MoveUp,
+            Key::PageUp(_) => Self::PageUp,
+            Key::Home => Self::MoveToStart,
+
+            Key::Down(KeyModifiers::NONE) | Key::Char('n', KeyModifiers::CONTROL) => Self::MoveDown,
+            Key::PageDown(_) => Self::PageDown,
+            Key::End => Self::MoveToEnd,
+
+            Key::Up(_) => Self::MoveItemUp,
+            Key::Down(_) => Self::MoveItemDown,
+
+            Key::Char('a', KeyModifiers::NONE) => Self::AddItem,
+            Key::Char('e', KeyModifiers::NONE) => Self::EditItem,
+            Key::Char('d', KeyModifiers::NONE) | Key::Delete(_) => Self::RemoveItem,
+            key => match InputAction::from_key(key, &()) {
+                Some(action) => Self::ValueInput(action),
+                None => return None,
+            },
+        };
+
+        Some(action)
+    }
+}
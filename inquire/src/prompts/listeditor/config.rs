@@ -0,0 +1,16 @@
+use crate::ListEditor;
+
+/// Configuration settings used in the execution of a ListEditorPrompt.
+#[derive(Copy, Clone, Debug)]
+pub struct ListEditorConfig {
+    /// Page size of the list of items.
+    pub page_size: usize,
+}
+
+impl From<&ListEditor<'_>> for ListEditorConfig {
+    fn from(value: &ListEditor<'_>) -> Self {
+        Self {
+            page_size: value.page_size,
+        }
+    }
+}
@@ -0,0 +1,234 @@
+mod action;
+mod config;
+mod prompt;
+#[cfg(test)]
+mod test;
+
+pub use action::*;
+
+use crate::{
+    config::get_configuration,
+    error::{InquireError, InquireResult},
+    formatter::ListFormatter,
+    prompts::prompt::Prompt,
+    terminal::get_default_terminal,
+    ui::{Backend, RenderConfig, SelectBackend},
+    validator::StringValidator,
+};
+
+use self::prompt::ListEditorPrompt;
+
+/// Prompt suitable for when you need the user to build or review a list of free-form values, such as hosts, paths or steps of a process, in a single interaction.
+///
+/// The items are displayed as a list the user can navigate with the arrow keys. Pressing `a` types a new item, added below the highlighted one, pressing `e` edits the highlighted item and pressing `d` or delete removes it. Like in [Order](crate::Order) prompts, holding shift while pressing the up and down arrows moves the highlighted item, reordering the list.
+///
+/// While an item is typed, enter saves it and escape discards the change, returning to the list. Enter on the list submits the items in their final order.
+///
+/// Typed items are trimmed and empty items are rejected.
+///
+/// List editor prompts provide several options of configuration:
+///
+/// - **Prompt message**: Required when creating the prompt.
+/// - **Initial items**: Items listed when the prompt is first rendered.
+/// - **Help message**: Message displayed at the line below the prompt.
+/// - **Validators**: Custom validators applied to each item before it is saved, displaying an error message if the item does not pass the requirements.
+/// - **Formatter**: Custom formatter in case you need to pre-process the user input before showing it as the final answer.
+///   - Prints the items separated by commas by default.
+/// - **Page size**: Number of items displayed at once, 7 by default.
+///
+/// # Example
+///
+/// ```no_run
+/// use inquire::{validator::Validation, ListEditor};
+///
+/// let hosts = ListEditor::new("Hosts:")
+///     .with_items(&["localhost", "10.0.0.2"])
+///     .with_validator(|host: &str| match host.contains(' ') {
+///         false => Ok(Validation::Valid),
+///         true => Ok(Validation::Invalid("Hosts can not contain spaces".into())),
+///     })
+///     .prompt();
+///
+/// match hosts {
+///     Ok(hosts) => println!("Deploying to {} hosts", hosts.len()),
+///     Err(_) => println!("The hosts could not be processed"),
+/// }
+/// ```
+#[derive(Clone)]
+pub struct ListEditor<'a> {
+    /// Message to be presented to the user.
+    pub message: &'a str,
+
+    /// Items listed when the prompt is first rendered.
+    pub items: Vec<String>,
+
+    /// Help message to be presented to the user.
+    pub help_message: Option<&'a str>,
+
+    /// Function that formats the user input and presents it to the user as the final rendering of the prompt.
+    pub formatter: ListFormatter<'a>,
+
+    /// Collection of validators to apply to each item before it is saved.
+    ///
+    /// Validators are executed in the order they are stored, stopping at and displaying to the user
+    /// only the first validation error that might appear.
+    ///
+    /// The possible error is displayed to the user one line above the prompt.
+    pub validators: Vec<Box<dyn StringValidator>>,
+
+    /// Page size of the items displayed to the user.
+    pub page_size: usize,
+
+    /// RenderConfig to apply to the rendered interface.
+    ///
+    /// Note: The default render config considers if the NO_COLOR environment variable
+    /// is set to decide whether to render the colored config or the empty one.
+    ///
+    /// When overriding the config in a prompt, NO_COLOR is no longer considered and your
+    /// config is treated as the only source of truth. If you want to customize colors
+    /// and still support NO_COLOR, you will have to do this on your end.
+    pub render_config: RenderConfig<'a>,
+}
+
+impl<'a> ListEditor<'a> {
+    /// Default formatter, set to print the items separated by commas.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use inquire::ListEditor;
+    ///
+    /// let formatter = ListEditor::DEFAULT_FORMATTER;
+    /// assert_eq!(String::from("a, b"), formatter(&[String::from("a"), String::from("b")]));
+    /// ```
+    pub const DEFAULT_FORMATTER: ListFormatter<'a> = &|items| items.join(", ");
+
+    /// Default page size, equal to the global default page size [config::DEFAULT_PAGE_SIZE]
+    pub const DEFAULT_PAGE_SIZE: usize = crate::config::DEFAULT_PAGE_SIZE;
+
+    /// Default validators added to the [ListEditor] prompt, none.
+    pub const DEFAULT_VALIDATORS: Vec<Box<dyn StringValidator>> = vec![];
+
+    /// Default help message.
+    pub const DEFAULT_HELP_MESSAGE: Option<&'a str> =
+        Some("a to add, e to edit, d to delete, shift+↑↓ to reorder, enter to submit");
+
+    /// Creates a [ListEditor] with the provided message, along with default configuration values.
+    pub fn new(message: &'a str) -> Self {
+        Self {
+            message,
+            items: vec![],
+            help_message: Self::DEFAULT_HELP_MESSAGE,
+            formatter: Self::DEFAULT_FORMATTER,
+            validators: Self::DEFAULT_VALIDATORS,
+            page_size: Self::DEFAULT_PAGE_SIZE,
+            render_config: get_configuration(),
+        }
+    }
+
+    /// Sets the items listed when the prompt is first rendered.
+    pub fn with_items(mut self, items: &[&str]) -> Self {
+        self.items = items.iter().map(|item| String::from(*item)).collect();
+        self
+    }
+
+    /// Sets the help message of the prompt.
+    pub fn with_help_message(mut self, message: &'a str) -> Self {
+        self.help_message = Some(message);
+        self
+    }
+
+    /// Removes the set help message.
+    pub fn without_help_message(mut self) -> Self {
+        self.help_message = None;
+        self
+    }
+
+    /// Sets the formatter.
+    pub fn with_formatter(mut self, formatter: ListFormatter<'a>) -> Self {
+        self.formatter = formatter;
+        self
+    }
+
+    /// Sets the page size
+    pub fn with_page_size(mut self, page_size: usize) -> Self {
+        self.page_size = page_size;
+        self
+    }
+
+    /// Adds a validator to the collection of validators. You might want to use this feature
+    /// in case you need to require certain features from each item, such as a maximum length.
+    ///
+    /// Validators are executed in the order they are stored, stopping at and displaying to the user
+    /// only the first validation error that might appear.
+    ///
+    /// The possible error is displayed to the user one line above the prompt.
+    pub fn with_validator<V>(mut self, validator: V) -> Self
+    where
+        V: StringValidator + 'static,
+    {
+        self.validators.push(Box::new(validator));
+        self
+    }
+
+    /// Adds the validators to the collection of validators in the order they are given.
+    ///
+    /// Validators are executed in the order they are stored, stopping at and displaying to the user
+    /// only the first validation error that might appear.
+    ///
+    /// The possible error is displayed to the user one line above the prompt.
+    pub fn with_validators(mut self, validators: &[Box<dyn StringValidator>]) -> Self {
+        for validator in validators {
+            #[allow(suspicious_double_ref_op)]
+            self.validators.push(validator.clone());
+        }
+        self
+    }
+
+    /// Sets the provided color theme to this prompt.
+    ///
+    /// Note: The default render config considers if the NO_COLOR environment variable
+    /// is set to decide whether to render the colored config or the empty one.
+    ///
+    /// When overriding the config in a prompt, NO_COLOR is no longer considered and your
+    /// config is treated as the only source of truth. If you want to customize colors
+    /// and still support NO_COLOR, you will have to do this on your end.
+    pub fn with_render_config(mut self, render_config: RenderConfig<'a>) -> Self {
+        self.render_config = render_config;
+        self
+    }
+
+    /// Parses the provided behavioral and rendering options and prompts
+    /// the CLI user for input according to the defined rules.
+    ///
+    /// This method is intended for flows where the user skipping/cancelling
+    /// the prompt - by pressing ESC - is considered normal behavior. In this case,
+    /// it does not return `Err(InquireError::OperationCanceled)`, but `Ok(None)`.
+    ///
+    /// Meanwhile, if the user does submit an answer, the method wraps the return
+    /// type with `Some`.
+    pub fn prompt_skippable(self) -> InquireResult<Option<Vec<String>>> {
+        match self.prompt() {
+            Ok(answer) => Ok(Some(answer)),
+            Err(InquireError::OperationCanceled) => Ok(None),
+            Err(err) => Err(err),
+        }
+    }
+
+    /// Parses the provided behavioral and rendering options and prompts
+    /// the CLI user for input according to the defined rules.
+    ///
+    /// Returns the items in the order they were left in by the user.
+    pub fn prompt(self) -> InquireResult<Vec<String>> {
+        let (input_reader, terminal) = get_default_terminal()?;
+        let mut backend = Backend::new(input_reader, terminal, self.render_config)?;
+        self.prompt_with_backend(&mut backend)
+    }
+
+    pub(crate) fn prompt_with_backend<B: SelectBackend>(
+        self,
+        backend: &mut B,
+    ) -> InquireResult<Vec<String>> {
+        ListEditorPrompt::from(self).prompt(backend)
+    }
+}
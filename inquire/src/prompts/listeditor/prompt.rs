@@ -0,0 +1,321 @@
+use crate::{
+    error::InquireResult,
+    formatter::ListFormatter,
+    input::Input,
+    list_option::ListOption,
+    prompts::{
+        action::Action,
+        prompt::{ActionResult, Prompt},
+    },
+    ui::{Key, SelectBackend},
+    utils::paginate,
+    validator::{ErrorMessage, StringValidator, Validation},
+    InnerAction, InputAction, InquireError, ListEditor,
+};
+
+use super::{action::ListEditorPromptAction, config::ListEditorConfig};
+
+/// Help message displayed while an item is typed.
+const EDITING_HELP_MESSAGE: &str = "enter to save, esc to discard";
+
+/// Item being typed by the user.
+struct Editing {
+    /// Position of the item in the list.
+    index: usize,
+    /// Whether the item is added at the position, instead of replacing
+    /// the one already there.
+    is_new: bool,
+    input: Input,
+}
+
+pub struct ListEditorPrompt<'a> {
+    message: &'a str,
+    config: ListEditorConfig,
+    items: Vec<String>,
+    help_message: Option<&'a str>,
+    cursor_index: usize,
+    editing: Option<Editing>,
+    formatter: ListFormatter<'a>,
+    validators: Vec<Box<dyn StringValidator>>,
+    error: Option<ErrorMessage>,
+}
+
+impl<'a> From<ListEditor<'a>> for ListEditorPrompt<'a> {
+    fn from(le: ListEditor<'a>) -> Self {
+        Self {
+            message: le.message,
+            config: (&le).into(),
+            items: le.items,
+            help_message: le.help_message,
+            cursor_index: 0,
+            editing: None,
+            formatter: le.formatter,
+            validators: le.validators,
+            error: None,
+        }
+    }
+}
+
+impl<'a> ListEditorPrompt<'a> {
+    fn move_cursor_up(&mut self, qty: usize, wrap: bool) -> ActionResult {
+        let new_position = if wrap {
+            let after_wrap = qty.saturating_sub(self.cursor_index);
+            self.cursor_index
+                .checked_sub(qty)
+                .unwrap_or_else(|| self.items.len().saturating_sub(after_wrap))
+        } else {
+            self.cursor_index.saturating_sub(qty)
+        };
+
+        self.update_cursor_position(new_position)
+    }
+
+    fn move_cursor_down(&mut self, qty: usize, wrap: bool) -> ActionResult {
+        let mut new_position = self.cursor_index.saturating_add(qty);
+
+        if new_position >= self.items.len() {
+            new_position = if self.items.is_empty() {
+                0
+            } else if wrap {
+                new_position % self.items.len()
+            } else {
+                self.items.len().saturating_sub(1)
+            }
+        }
+
+        self.update_cursor_position(new_position)
+    }
+
+    fn update_cursor_position(&mut self, new_position: usize) -> ActionResult {
+        if new_position != self.cursor_index {
+            self.cursor_index = new_position;
+            ActionResult::NeedsRedraw
+        } else {
+            ActionResult::Clean
+        }
+    }
+
+    /// Moves the highlighted item to the given position, shifting the
+    /// items in between. The cursor follows the moved item.
+    fn move_item_to(&mut self, new_position: usize) -> ActionResult {
+        let new_position = new_position.min(self.items.len().saturating_sub(1));
+
+        if new_position == self.cursor_index {
+            return ActionResult::Clean;
+        }
+
+        let item = self.items.remove(self.cursor_index);
+        self.items.insert(new_position, item);
+        self.cursor_index = new_position;
+
+        ActionResult::NeedsRedraw
+    }
+
+    fn add_item(&mut self) -> ActionResult {
+        let index = match self.items.is_empty() {
+            true => 0,
+            false => self.cursor_index + 1,
+        };
+
+        self.editing = Some(Editing {
+            index,
+            is_new: true,
+            input: Input::new(),
+        });
+
+        ActionResult::NeedsRedraw
+    }
+
+    fn edit_item(&mut self) -> ActionResult {
+        match self.items.get(self.cursor_index) {
+            Some(item) => {
+                self.editing = Some(Editing {
+                    index: self.cursor_index,
+                    is_new: false,
+                    input: Input::new_with(item),
+                });
+                ActionResult::NeedsRedraw
+            }
+            None => ActionResult::Clean,
+        }
+    }
+
+    fn remove_item(&mut self) -> ActionResult {
+        if self.cursor_index >= self.items.len() {
+            return ActionResult::Clean;
+        }
+
+        self.items.remove(self.cursor_index);
+        self.cursor_index = self.cursor_index.min(self.items.len().saturating_sub(1));
+
+        ActionResult::NeedsRedraw
+    }
+
+    fn validate_item(&self, item: &str) -> InquireResult<Validation> {
+        if item.is_empty() {
+            return Ok(Validation::Invalid("Items can not be empty".into()));
+        }
+
+        for validator in &self.validators {
+            match validator.validate(item) {
+                Ok(Validation::Valid) => {}
+                Ok(Validation::Invalid(msg)) => return Ok(Validation::Invalid(msg)),
+                Err(err) => return Err(InquireError::Custom(err)),
+            }
+        }
+
+        Ok(Validation::Valid)
+    }
+
+    /// Saves the item being typed, if it passes validation.
+    fn save_item(&mut self) -> InquireResult<()> {
+        let (index, is_new, item) = match &self.editing {
+            Some(editing) => (
+                editing.index,
+                editing.is_new,
+                editing.input.content().trim().to_string(),
+            ),
+            None => return Ok(()),
+        };
+
+        if let Validation::Invalid(msg) = self.validate_item(&item)? {
+            self.error = Some(msg);
+            return Ok(());
+        }
+
+        match is_new {
+            true => self.items.insert(index, item),
+            false => self.items[index] = item,
+        }
+
+        self.cursor_index = index;
+        self.editing = None;
+        self.error = None;
+
+        Ok(())
+    }
+}
+
+impl<'a, Backend> Prompt<Backend> for ListEditorPrompt<'a>
+where
+    Backend: SelectBackend,
+{
+    type Config = ListEditorConfig;
+    type InnerAction = ListEditorPromptAction;
+    type Output = Vec<String>;
+
+    fn message(&self) -> &str {
+        self.message
+    }
+
+    fn config(&self) -> &ListEditorConfig {
+        &self.config
+    }
+
+    fn format_answer(&self, answer: &Vec<String>) -> String {
+        (self.formatter)(answer)
+    }
+
+    fn parse_key(&self, key: Key) -> Option<Action<ListEditorPromptAction>> {
+        let action = Action::from_key(key, &self.config)?;
+
+        // while an item is typed, keys such as the ones that add or remove
+        // items edit the item instead
+        match (&self.editing, action) {
+            (Some(_), Action::Inner(_)) => InputAction::from_key(key, &())
+                .map(|a| Action::Inner(ListEditorPromptAction::ValueInput(a))),
+            (_, action) => Some(action),
+        }
+    }
+
+    fn pre_cancel(&mut self) -> InquireResult<bool> {
+        if self.editing.is_some() {
+            self.editing = None;
+            self.error = None;
+            return Ok(false);
+        }
+
+        Ok(true)
+    }
+
+    fn submit(&mut self) -> InquireResult<Option<Vec<String>>> {
+        if self.editing.is_some() {
+            self.save_item()?;
+            return Ok(None);
+        }
+
+        Ok(Some(std::mem::take(&mut self.items)))
+    }
+
+    fn handle(&mut self, action: ListEditorPromptAction) -> InquireResult<ActionResult> {
+        if let Some(editing) = &mut self.editing {
+            let result = match action {
+                ListEditorPromptAction::ValueInput(input_action) => {
+                    editing.input.handle(input_action).into()
+                }
+                _ => ActionResult::Clean,
+            };
+
+            return Ok(result);
+        }
+
+        let result = match action {
+            ListEditorPromptAction::MoveUp => self.move_cursor_up(1, true),
+            ListEditorPromptAction::MoveDown => self.move_cursor_down(1, true),
+            ListEditorPromptAction::PageUp => self.move_cursor_up(self.config.page_size, false),
+            ListEditorPromptAction::PageDown => self.move_cursor_down(self.config.page_size, false),
+            ListEditorPromptAction::MoveToStart => self.move_cursor_up(usize::MAX, false),
+            ListEditorPromptAction::MoveToEnd => self.move_cursor_down(usize::MAX, false),
+            ListEditorPromptAction::MoveItemUp => match self.cursor_index {
+                0 => ActionResult::Clean,
+                cursor => self.move_item_to(cursor - 1),
+            },
+            ListEditorPromptAction::MoveItemDown => self.move_item_to(self.cursor_index + 1),
+            ListEditorPromptAction::AddItem => self.add_item(),
+            ListEditorPromptAction::EditItem => self.edit_item(),
+            ListEditorPromptAction::RemoveItem => self.remove_item(),
+            ListEditorPromptAction::ValueInput(_) => ActionResult::Clean,
+        };
+
+        Ok(result)
+    }
+
+    fn render(&self, backend: &mut Backend) -> InquireResult<()> {
+        let prompt = &self.message;
+
+        if let Some(error) = &self.error {
+            backend.render_error_message(error)?;
+        }
+
+        backend.render_select_prompt(prompt, self.editing.as_ref().map(|e| &e.input))?;
+
+        // the item being added is not listed yet, so the cursor is
+        // only displayed while browsing the list
+        let choices = self
+            .items
+            .iter()
+            .enumerate()
+            .map(|(i, item)| ListOption::new(i, item.as_str()))
+            .collect::<Vec<ListOption<&str>>>();
+
+        let cursor = match self.editing {
+            Some(Editing { is_new: true, .. }) => None,
+            _ => Some(self.cursor_index),
+        };
+
+        let page = paginate(self.config.page_size, &choices, cursor);
+
+        backend.render_options(page)?;
+
+        let help_message = match self.editing {
+            Some(_) => self.help_message.map(|_| EDITING_HELP_MESSAGE),
+            None => self.help_message,
+        };
+
+        if let Some(help_message) = help_message {
+            backend.render_help_message(help_message)?;
+        }
+
+        Ok(())
+    }
+}
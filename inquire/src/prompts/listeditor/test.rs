@@ -0,0 +1,118 @@
+use crate::{
+    test::fake_backend,
+    ui::{Key, KeyModifiers},
+    validator::Validation,
+    InquireError, ListEditor,
+};
+
+fn items() -> Vec<&'static str> {
+    vec!["a", "b", "c"]
+}
+
+#[test]
+fn submitting_right_away_returns_initial_items() {
+    let mut backend = fake_backend(vec![Key::Enter]);
+
+    let ans = ListEditor::new("Question")
+        .with_items(&items())
+        .prompt_with_backend(&mut backend)
+        .unwrap();
+
+    assert_eq!(vec!["a", "b", "c"], ans);
+}
+
+#[test]
+fn items_are_added_below_the_cursor() {
+    let mut input = vec![Key::Char('a', KeyModifiers::NONE)];
+    input.extend(Key::char_keys_from_str("  first "));
+    input.push(Key::Enter);
+    input.push(Key::Char('a', KeyModifiers::NONE));
+    input.extend(Key::char_keys_from_str("second"));
+    input.push(Key::Enter);
+    input.push(Key::Up(KeyModifiers::NONE));
+    input.push(Key::Char('a', KeyModifiers::NONE));
+    input.extend(Key::char_keys_from_str("between"));
+    input.push(Key::Enter);
+    input.push(Key::Enter);
+    let mut backend = fake_backend(input);
+
+    let ans = ListEditor::new("Question")
+        .prompt_with_backend(&mut backend)
+        .unwrap();
+
+    assert_eq!(vec!["first", "between", "second"], ans);
+}
+
+#[test]
+fn items_can_be_edited_removed_and_reordered() {
+    let mut input = vec![
+        Key::Down(KeyModifiers::NONE),
+        Key::Char('e', KeyModifiers::NONE),
+        Key::Backspace,
+    ];
+    // letters that trigger actions on the list are typed while editing
+    input.extend(Key::char_keys_from_str("dea"));
+    input.extend([
+        Key::Enter,
+        Key::Down(KeyModifiers::SHIFT),
+        Key::Home,
+        Key::Char('d', KeyModifiers::NONE),
+        Key::Enter,
+    ]);
+    let mut backend = fake_backend(input);
+
+    let ans = ListEditor::new("Question")
+        .with_items(&items())
+        .prompt_with_backend(&mut backend)
+        .unwrap();
+
+    assert_eq!(vec!["c", "dea"], ans);
+}
+
+#[test]
+fn escape_discards_typed_item() {
+    let mut input = vec![Key::Char('e', KeyModifiers::NONE)];
+    input.extend(Key::char_keys_from_str("xyz"));
+    input.extend([Key::Escape, Key::Char('a', KeyModifiers::NONE)]);
+    input.extend(Key::char_keys_from_str("new"));
+    input.extend([Key::Escape, Key::Enter]);
+    let mut backend = fake_backend(input);
+
+    let ans = ListEditor::new("Question")
+        .with_items(&items())
+        .prompt_with_backend(&mut backend)
+        .unwrap();
+
+    assert_eq!(vec!["a", "b", "c"], ans);
+}
+
+#[test]
+fn invalid_items_are_not_saved() {
+    let mut input = vec![Key::Char('a', KeyModifiers::NONE), Key::Enter];
+    input.extend(Key::char_keys_from_str("toolong"));
+    input.push(Key::Enter);
+    input.extend(std::iter::repeat(Key::Backspace).take(3));
+    input.extend([Key::Enter, Key::Enter]);
+    let mut backend = fake_backend(input);
+
+    let ans = ListEditor::new("Question")
+        .with_validator(|item: &str| match item.len() {
+            0..=4 => Ok(Validation::Valid),
+            _ => Ok(Validation::Invalid("Too long".into())),
+        })
+        .prompt_with_backend(&mut backend)
+        .unwrap();
+
+    assert_eq!(vec!["tool"], ans);
+}
+
+#[test]
+fn escape_on_the_list_cancels_the_prompt() {
+    let mut backend = fake_backend(vec![Key::Char('d', KeyModifiers::NONE), Key::Escape]);
+
+    let ans = ListEditor::new("Question")
+        .with_items(&items())
+        .prompt_with_backend(&mut backend);
+
+    assert!(matches!(ans, Err(InquireError::OperationCanceled)));
+}
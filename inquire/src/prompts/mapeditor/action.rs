@@ -0,0 +1,57 @@
+use crate::{
+    ui::{Key, KeyModifiers},
+    InnerAction, InputAction,
+};
+
+use super::config::MapEditorConfig;
+
+/// Set of actions for a MapEditorPrompt.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum MapEditorPromptAction {
+    /// Action on the text input handler of the key or value being typed.
+    ValueInput(InputAction),
+    /// Moves the cursor to the entry above.
+    MoveUp,
+    /// Moves the cursor to the entry below.
+    MoveDown,
+    /// Moves the cursor to the page above.
+    PageUp,
+    /// Moves the cursor to the page below.
+    PageDown,
+    /// Moves the cursor to the start of the list.
+    MoveToStart,
+    /// Moves the cursor to the end of the list.
+    MoveToEnd,
+    /// Starts typing a new entry, added below the highlighted one.
+    AddEntry,
+    /// Starts editing the value of the highlighted entry.
+    EditEntry,
+    /// Removes the highlighted entry.
+    RemoveEntry,
+}
+
+impl InnerAction for MapEditorPromptAction {
+    type Config = MapEditorConfig;
+
+    fn from_key(key: Key, _config: &MapEditorConfig) -> Option<Self> {
+        let action = match key {
+            Key::Up(_) | Key::Char('p', KeyModifiers::CONTROL) => Self::MoveUp,
+            Key::PageUp(_) => Self::PageUp,
+            Key::Home => Self::MoveToStart,
+
+            Key::Down(_) | Key::Char('n', KeyModifiers::CONTROL) => Self::MoveDown,
+            Key::PageDown(_) => Self::PageDown,
+            Key::End => Self::MoveToEnd,
+
+            Key::Char('a', KeyModifiers::NONE) => Self::AddEntry,
+            Key::Char('e', KeyModifiers::NONE) => Self::EditEntry,
+            Key::Char('d', KeyModifiers::NONE) | Key::Delete(_) => Self::RemoveEntry,
+            key => match InputAction::from_key(key, &()) {
+                Some(action) => Self::ValueInput(action),
+                None => return None,
+            },
+        };
+
+        Some(action)
+    }
+}
@@ -0,0 +1,16 @@
+use crate::MapEditor;
+
+/// Configuration settings used in the execution of a MapEditorPrompt.
+#[derive(Copy, Clone, Debug)]
+pub struct MapEditorConfig {
+    /// Page size of the list of entries.
+    pub page_size: usize,
+}
+
+impl From<&MapEditor<'_>> for MapEditorConfig {
+    fn from(value: &MapEditor<'_>) -> Self {
+        Self {
+            page_size: value.page_size,
+        }
+    }
+}
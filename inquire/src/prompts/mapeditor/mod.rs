@@ -0,0 +1,291 @@
+mod action;
+mod config;
+mod prompt;
+#[cfg(test)]
+mod test;
+
+pub use action::*;
+use std::collections::HashMap;
+
+use crate::{
+    config::get_configuration,
+    error::{InquireError, InquireResult},
+    formatter::MapFormatter,
+    prompts::prompt::Prompt,
+    terminal::get_default_terminal,
+    ui::{Backend, RenderConfig, SelectBackend},
+    validator::StringValidator,
+};
+
+use self::prompt::MapEditorPrompt;
+
+/// Prompt suitable for when you need the user to build a map of string keys to string values, such as environment variables, labels or annotations.
+///
+/// The entries are displayed as a list of `key=value` lines the user can navigate with the arrow keys. Pressing `a` adds a new entry below the highlighted one, asking for its key and then for its value, pressing `e` edits the value of the highlighted entry and pressing `d` or delete removes it.
+///
+/// While a key or value is typed, enter saves it and escape discards the entry being typed, returning to the list. Enter on the list submits the entries.
+///
+/// Keys are trimmed and empty keys are rejected, as well as keys that were already added. Values are kept as typed and can be empty.
+///
+/// Map editor prompts provide several options of configuration:
+///
+/// - **Prompt message**: Required when creating the prompt.
+/// - **Initial entries**: Entries listed when the prompt is first rendered. Repeated keys are ignored after their first entry.
+/// - **Help message**: Message displayed at the line below the prompt.
+/// - **Key validators**: Custom validators applied to each key before it is saved, displaying an error message if the key does not pass the requirements.
+/// - **Value validators**: Custom validators applied to each value before it is saved, displaying an error message if the value does not pass the requirements.
+/// - **Formatter**: Custom formatter in case you need to pre-process the user input before showing it as the final answer.
+///   - Prints the entries as `key=value` pairs separated by commas by default.
+/// - **Page size**: Number of entries displayed at once, 7 by default.
+///
+/// # Example
+///
+/// ```no_run
+/// use inquire::{validator::Validation, MapEditor};
+///
+/// let env = MapEditor::new("Environment:")
+///     .with_entries(&[("RUST_LOG", "info")])
+///     .with_key_validator(|key: &str| match key.chars().all(|c| c.is_ascii_uppercase() || c == '_') {
+///         true => Ok(Validation::Valid),
+///         false => Ok(Validation::Invalid("Use uppercase letters and underscores".into())),
+///     })
+///     .prompt();
+///
+/// match env {
+///     Ok(env) => println!("Setting {} variables", env.len()),
+///     Err(_) => println!("The environment could not be processed"),
+/// }
+/// ```
+#[derive(Clone)]
+pub struct MapEditor<'a> {
+    /// Message to be presented to the user.
+    pub message: &'a str,
+
+    /// Entries listed when the prompt is first rendered.
+    pub entries: Vec<(String, String)>,
+
+    /// Help message to be presented to the user.
+    pub help_message: Option<&'a str>,
+
+    /// Function that formats the user input and presents it to the user as the final rendering of the prompt.
+    pub formatter: MapFormatter<'a>,
+
+    /// Collection of validators to apply to each key before it is saved.
+    ///
+    /// Validators are executed in the order they are stored, stopping at and displaying to the user
+    /// only the first validation error that might appear.
+    ///
+    /// The possible error is displayed to the user one line above the prompt.
+    pub key_validators: Vec<Box<dyn StringValidator>>,
+
+    /// Collection of validators to apply to each value before it is saved.
+    ///
+    /// Validators are executed in the order they are stored, stopping at and displaying to the user
+    /// only the first validation error that might appear.
+    ///
+    /// The possible error is displayed to the user one line above the prompt.
+    pub value_validators: Vec<Box<dyn StringValidator>>,
+
+    /// Page size of the entries displayed to the user.
+    pub page_size: usize,
+
+    /// RenderConfig to apply to the rendered interface.
+    ///
+    /// Note: The default render config considers if the NO_COLOR environment variable
+    /// is set to decide whether to render the colored config or the empty one.
+    ///
+    /// When overriding the config in a prompt, NO_COLOR is no longer considered and your
+    /// config is treated as the only source of truth. If you want to customize colors
+    /// and still support NO_COLOR, you will have to do this on your end.
+    pub render_config: RenderConfig<'a>,
+}
+
+impl<'a> MapEditor<'a> {
+    /// Default formatter, set to print the entries as `key=value` pairs separated by commas.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use inquire::MapEditor;
+    ///
+    /// let formatter = MapEditor::DEFAULT_FORMATTER;
+    /// assert_eq!(String::from("app=web, tier=db"), formatter(&[("app", "web"), ("tier", "db")]));
+    /// ```
+    pub const DEFAULT_FORMATTER: MapFormatter<'a> = &|entries| {
+        entries
+            .iter()
+            .map(|(key, value)| format!("{key}={value}"))
+            .collect::<Vec<String>>()
+            .join(", ")
+    };
+
+    /// Default page size, equal to the global default page size [config::DEFAULT_PAGE_SIZE]
+    pub const DEFAULT_PAGE_SIZE: usize = crate::config::DEFAULT_PAGE_SIZE;
+
+    /// Default validators added to the [MapEditor] prompt, none.
+    pub const DEFAULT_VALIDATORS: Vec<Box<dyn StringValidator>> = vec![];
+
+    /// Default help message.
+    pub const DEFAULT_HELP_MESSAGE: Option<&'a str> =
+        Some("a to add, e to edit value, d to delete, enter to submit");
+
+    /// Creates a [MapEditor] with the provided message, along with default configuration values.
+    pub fn new(message: &'a str) -> Self {
+        Self {
+            message,
+            entries: vec![],
+            help_message: Self::DEFAULT_HELP_MESSAGE,
+            formatter: Self::DEFAULT_FORMATTER,
+            key_validators: Self::DEFAULT_VALIDATORS,
+            value_validators: Self::DEFAULT_VALIDATORS,
+            page_size: Self::DEFAULT_PAGE_SIZE,
+            render_config: get_configuration(),
+        }
+    }
+
+    /// Sets the entries listed when the prompt is first rendered.
+    pub fn with_entries(mut self, entries: &[(&str, &str)]) -> Self {
+        self.entries = entries
+            .iter()
+            .map(|(key, value)| (String::from(*key), String::from(*value)))
+            .collect();
+        self
+    }
+
+    /// Sets the help message of the prompt.
+    pub fn with_help_message(mut self, message: &'a str) -> Self {
+        self.help_message = Some(message);
+        self
+    }
+
+    /// Removes the set help message.
+    pub fn without_help_message(mut self) -> Self {
+        self.help_message = None;
+        self
+    }
+
+    /// Sets the formatter.
+    pub fn with_formatter(mut self, formatter: MapFormatter<'a>) -> Self {
+        self.formatter = formatter;
+        self
+    }
+
+    /// Sets the page size
+    pub fn with_page_size(mut self, page_size: usize) -> Self {
+        self.page_size = page_size;
+        self
+    }
+
+    /// Adds a validator to the collection of key validators. You might want to use this feature
+    /// in case you need to require certain features from each key, such as a naming convention.
+    ///
+    /// Validators are executed in the order they are stored, stopping at and displaying to the user
+    /// only the first validation error that might appear.
+    ///
+    /// The possible error is displayed to the user one line above the prompt.
+    pub fn with_key_validator<V>(mut self, validator: V) -> Self
+    where
+        V: StringValidator + 'static,
+    {
+        self.key_validators.push(Box::new(validator));
+        self
+    }
+
+    /// Adds the validators to the collection of key validators in the order they are given.
+    ///
+    /// Validators are executed in the order they are stored, stopping at and displaying to the user
+    /// only the first validation error that might appear.
+    ///
+    /// The possible error is displayed to the user one line above the prompt.
+    pub fn with_key_validators(mut self, validators: &[Box<dyn StringValidator>]) -> Self {
+        for validator in validators {
+            #[allow(suspicious_double_ref_op)]
+            self.key_validators.push(validator.clone());
+        }
+        self
+    }
+
+    /// Adds a validator to the collection of value validators. You might want to use this feature
+    /// in case you need to require certain features from each value, such as a maximum length.
+    ///
+    /// Validators are executed in the order they are stored, stopping at and displaying to the user
+    /// only the first validation error that might appear.
+    ///
+    /// The possible error is displayed to the user one line above the prompt.
+    pub fn with_value_validator<V>(mut self, validator: V) -> Self
+    where
+        V: StringValidator + 'static,
+    {
+        self.value_validators.push(Box::new(validator));
+        self
+    }
+
+    /// Adds the validators to the collection of value validators in the order they are given.
+    ///
+    /// Validators are executed in the order they are stored, stopping at and displaying to the user
+    /// only the first validation error that might appear.
+    ///
+    /// The possible error is displayed to the user one line above the prompt.
+    pub fn with_value_validators(mut self, validators: &[Box<dyn StringValidator>]) -> Self {
+        for validator in validators {
+            #[allow(suspicious_double_ref_op)]
+            self.value_validators.push(validator.clone());
+        }
+        self
+    }
+
+    /// Sets the provided color theme to this prompt.
+    ///
+    /// Note: The default render config considers if the NO_COLOR environment variable
+    /// is set to decide whether to render the colored config or the empty one.
+    ///
+    /// When overriding the config in a prompt, NO_COLOR is no longer considered and your
+    /// config is treated as the only source of truth. If you want to customize colors
+    /// and still support NO_COLOR, you will have to do this on your end.
+    pub fn with_render_config(mut self, render_config: RenderConfig<'a>) -> Self {
+        self.render_config = render_config;
+        self
+    }
+
+    /// Parses the provided behavioral and rendering options and prompts
+    /// the CLI user for input according to the defined rules.
+    ///
+    /// This method is intended for flows where the user skipping/cancelling
+    /// the prompt - by pressing ESC - is considered normal behavior. In this case,
+    /// it does not return `Err(InquireError::OperationCanceled)`, but `Ok(None)`.
+    ///
+    /// Meanwhile, if the user does submit an answer, the method wraps the return
+    /// type with `Some`.
+    pub fn prompt_skippable(self) -> InquireResult<Option<HashMap<String, String>>> {
+        match self.prompt() {
+            Ok(answer) => Ok(Some(answer)),
+            Err(InquireError::OperationCanceled) => Ok(None),
+            Err(err) => Err(err),
+        }
+    }
+
+    /// Parses the provided behavioral and rendering options and prompts
+    /// the CLI user for input according to the defined rules.
+    pub fn prompt(self) -> InquireResult<HashMap<String, String>> {
+        self.raw_prompt()
+            .map(|entries| entries.into_iter().collect())
+    }
+
+    /// Parses the provided behavioral and rendering options and prompts
+    /// the CLI user for input according to the defined rules.
+    ///
+    /// Returns the entries as `(key, value)` pairs, in the order they
+    /// are listed in the prompt.
+    pub fn raw_prompt(self) -> InquireResult<Vec<(String, String)>> {
+        let (input_reader, terminal) = get_default_terminal()?;
+        let mut backend = Backend::new(input_reader, terminal, self.render_config)?;
+        self.prompt_with_backend(&mut backend)
+    }
+
+    pub(crate) fn prompt_with_backend<B: SelectBackend>(
+        self,
+        backend: &mut B,
+    ) -> InquireResult<Vec<(String, String)>> {
+        MapEditorPrompt::from(self).prompt(backend)
+    }
+}
@@ -0,0 +1,352 @@
+use crate::{
+    error::InquireResult,
+    formatter::MapFormatter,
+    input::Input,
+    list_option::ListOption,
+    prompts::{
+        action::Action,
+        prompt::{ActionResult, Prompt},
+    },
+    ui::{Key, SelectBackend},
+    utils::paginate,
+    validator::{ErrorMessage, StringValidator, Validation},
+    InnerAction, InputAction, InquireError, MapEditor,
+};
+
+use super::{action::MapEditorPromptAction, config::MapEditorConfig};
+
+/// Help message displayed while the key of a new entry is typed.
+const KEY_HELP_MESSAGE: &str = "enter to type the value, esc to discard";
+
+/// Help message displayed while the value of an entry is typed.
+const VALUE_HELP_MESSAGE: &str = "enter to save, esc to discard";
+
+/// Entry being typed by the user.
+struct Editing {
+    /// Position of the entry in the list.
+    index: usize,
+    /// Whether the entry is added at the position, instead of replacing
+    /// the one already there.
+    is_new: bool,
+    /// Key of the entry, once it was typed.
+    key: Option<String>,
+    input: Input,
+}
+
+pub struct MapEditorPrompt<'a> {
+    message: &'a str,
+    config: MapEditorConfig,
+    entries: Vec<(String, String)>,
+    help_message: Option<&'a str>,
+    cursor_index: usize,
+    editing: Option<Editing>,
+    formatter: MapFormatter<'a>,
+    key_validators: Vec<Box<dyn StringValidator>>,
+    value_validators: Vec<Box<dyn StringValidator>>,
+    error: Option<ErrorMessage>,
+}
+
+impl<'a> From<MapEditor<'a>> for MapEditorPrompt<'a> {
+    fn from(me: MapEditor<'a>) -> Self {
+        let mut entries: Vec<(String, String)> = vec![];
+        for (key, value) in me.entries.iter() {
+            if !entries.iter().any(|(k, _)| k == key) {
+                entries.push((key.clone(), value.clone()));
+            }
+        }
+
+        Self {
+            message: me.message,
+            config: (&me).into(),
+            entries,
+            help_message: me.help_message,
+            cursor_index: 0,
+            editing: None,
+            formatter: me.formatter,
+            key_validators: me.key_validators,
+            value_validators: me.value_validators,
+            error: None,
+        }
+    }
+}
+
+impl<'a> MapEditorPrompt<'a> {
+    fn move_cursor_up(&mut self, qty: usize, wrap: bool) -> ActionResult {
+        let new_position = if wrap {
+            let after_wrap = qty.saturating_sub(self.cursor_index);
+            self.cursor_index
+                .checked_sub(qty)
+                .unwrap_or_else(|| self.entries.len().saturating_sub(after_wrap))
+        } else {
+            self.cursor_index.saturating_sub(qty)
+        };
+
+        self.update_cursor_position(new_position)
+    }
+
+    fn move_cursor_down(&mut self, qty: usize, wrap: bool) -> ActionResult {
+        let mut new_position = self.cursor_index.saturating_add(qty);
+
+        if new_position >= self.entries.len() {
+            new_position = if self.entries.is_empty() {
+                0
+            } else if wrap {
+                new_position % self.entries.len()
+            } else {
+                self.entries.len().saturating_sub(1)
+            }
+        }
+
+        self.update_cursor_position(new_position)
+    }
+
+    fn update_cursor_position(&mut self, new_position: usize) -> ActionResult {
+        if new_position != self.cursor_index {
+            self.cursor_index = new_position;
+            ActionResult::NeedsRedraw
+        } else {
+            ActionResult::Clean
+        }
+    }
+
+    fn add_entry(&mut self) -> ActionResult {
+        let index = match self.entries.is_empty() {
+            true => 0,
+            false => self.cursor_index + 1,
+        };
+
+        self.editing = Some(Editing {
+            index,
+            is_new: true,
+            key: None,
+            input: Input::new(),
+        });
+
+        ActionResult::NeedsRedraw
+    }
+
+    fn edit_entry(&mut self) -> ActionResult {
+        match self.entries.get(self.cursor_index) {
+            Some((key, value)) => {
+                self.editing = Some(Editing {
+                    index: self.cursor_index,
+                    is_new: false,
+                    key: Some(key.clone()),
+                    input: Input::new_with(value),
+                });
+                ActionResult::NeedsRedraw
+            }
+            None => ActionResult::Clean,
+        }
+    }
+
+    fn remove_entry(&mut self) -> ActionResult {
+        if self.cursor_index >= self.entries.len() {
+            return ActionResult::Clean;
+        }
+
+        self.entries.remove(self.cursor_index);
+        self.cursor_index = self.cursor_index.min(self.entries.len().saturating_sub(1));
+
+        ActionResult::NeedsRedraw
+    }
+
+    fn validate_key(&self, key: &str) -> InquireResult<Validation> {
+        if key.is_empty() {
+            return Ok(Validation::Invalid("Keys can not be empty".into()));
+        }
+
+        if self.entries.iter().any(|(k, _)| k == key) {
+            return Ok(Validation::Invalid(
+                format!("'{key}' was already added").into(),
+            ));
+        }
+
+        Self::run_validators(&self.key_validators, key)
+    }
+
+    fn run_validators(
+        validators: &[Box<dyn StringValidator>],
+        input: &str,
+    ) -> InquireResult<Validation> {
+        for validator in validators {
+            match validator.validate(input) {
+                Ok(Validation::Valid) => {}
+                Ok(Validation::Invalid(msg)) => return Ok(Validation::Invalid(msg)),
+                Err(err) => return Err(InquireError::Custom(err)),
+            }
+        }
+
+        Ok(Validation::Valid)
+    }
+
+    /// Saves the key or value being typed, if it passes validation. Once
+    /// a key is saved, the user moves on to typing its value.
+    fn save_input(&mut self) -> InquireResult<()> {
+        let editing = match &self.editing {
+            Some(editing) => editing,
+            None => return Ok(()),
+        };
+
+        let validation = match &editing.key {
+            None => self.validate_key(editing.input.content().trim())?,
+            Some(_) => Self::run_validators(&self.value_validators, editing.input.content())?,
+        };
+
+        if let Validation::Invalid(msg) = validation {
+            self.error = Some(msg);
+            return Ok(());
+        }
+
+        self.error = None;
+
+        let editing = self.editing.as_mut().unwrap();
+        match editing.key.take() {
+            None => {
+                editing.key = Some(editing.input.content().trim().to_string());
+                editing.input = Input::new();
+            }
+            Some(key) => {
+                let entry = (key, editing.input.content().to_string());
+                match editing.is_new {
+                    true => self.entries.insert(editing.index, entry),
+                    false => self.entries[editing.index] = entry,
+                }
+
+                self.cursor_index = editing.index;
+                self.editing = None;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl<'a, Backend> Prompt<Backend> for MapEditorPrompt<'a>
+where
+    Backend: SelectBackend,
+{
+    type Config = MapEditorConfig;
+    type InnerAction = MapEditorPromptAction;
+    type Output = Vec<(String, String)>;
+
+    fn message(&self) -> &str {
+        self.message
+    }
+
+    fn config(&self) -> &MapEditorConfig {
+        &self.config
+    }
+
+    fn format_answer(&self, answer: &Vec<(String, String)>) -> String {
+        let refs = answer
+            .iter()
+            .map(|(key, value)| (key.as_str(), value.as_str()))
+            .collect::<Vec<(&str, &str)>>();
+        (self.formatter)(&refs)
+    }
+
+    fn parse_key(&self, key: Key) -> Option<Action<MapEditorPromptAction>> {
+        let action = Action::from_key(key, &self.config)?;
+
+        // while an entry is typed, keys such as the ones that add or remove
+        // entries edit the entry instead
+        match (&self.editing, action) {
+            (Some(_), Action::Inner(_)) => InputAction::from_key(key, &())
+                .map(|a| Action::Inner(MapEditorPromptAction::ValueInput(a))),
+            (_, action) => Some(action),
+        }
+    }
+
+    fn pre_cancel(&mut self) -> InquireResult<bool> {
+        if self.editing.is_some() {
+            self.editing = None;
+            self.error = None;
+            return Ok(false);
+        }
+
+        Ok(true)
+    }
+
+    fn submit(&mut self) -> InquireResult<Option<Vec<(String, String)>>> {
+        if self.editing.is_some() {
+            self.save_input()?;
+            return Ok(None);
+        }
+
+        Ok(Some(std::mem::take(&mut self.entries)))
+    }
+
+    fn handle(&mut self, action: MapEditorPromptAction) -> InquireResult<ActionResult> {
+        if let Some(editing) = &mut self.editing {
+            let result = match action {
+                MapEditorPromptAction::ValueInput(input_action) => {
+                    editing.input.handle(input_action).into()
+                }
+                _ => ActionResult::Clean,
+            };
+
+            return Ok(result);
+        }
+
+        let result = match action {
+            MapEditorPromptAction::MoveUp => self.move_cursor_up(1, true),
+            MapEditorPromptAction::MoveDown => self.move_cursor_down(1, true),
+            MapEditorPromptAction::PageUp => self.move_cursor_up(self.config.page_size, false),
+            MapEditorPromptAction::PageDown => self.move_cursor_down(self.config.page_size, false),
+            MapEditorPromptAction::MoveToStart => self.move_cursor_up(usize::MAX, false),
+            MapEditorPromptAction::MoveToEnd => self.move_cursor_down(usize::MAX, false),
+            MapEditorPromptAction::AddEntry => self.add_entry(),
+            MapEditorPromptAction::EditEntry => self.edit_entry(),
+            MapEditorPromptAction::RemoveEntry => self.remove_entry(),
+            MapEditorPromptAction::ValueInput(_) => ActionResult::Clean,
+        };
+
+        Ok(result)
+    }
+
+    fn render(&self, backend: &mut Backend) -> InquireResult<()> {
+        if let Some(error) = &self.error {
+            backend.render_error_message(error)?;
+        }
+
+        // the key of the entry is displayed next to the message while
+        // its value is typed
+        let prompt = match &self.editing {
+            Some(Editing { key: Some(key), .. }) => format!("{} {key}=", self.message),
+            _ => self.message.to_string(),
+        };
+
+        backend.render_select_prompt(&prompt, self.editing.as_ref().map(|e| &e.input))?;
+
+        let choices = self
+            .entries
+            .iter()
+            .enumerate()
+            .map(|(i, (key, value))| ListOption::new(i, format!("{key}={value}")))
+            .collect::<Vec<ListOption<String>>>();
+
+        // the entry being added is not listed yet, so the cursor is
+        // only displayed while browsing the list
+        let cursor = match self.editing {
+            Some(Editing { is_new: true, .. }) => None,
+            _ => Some(self.cursor_index),
+        };
+
+        let page = paginate(self.config.page_size, &choices, cursor);
+
+        backend.render_options(page)?;
+
+        let help_message = match &self.editing {
+            Some(Editing { key: None, .. }) => self.help_message.map(|_| KEY_HELP_MESSAGE),
+            Some(_) => self.help_message.map(|_| VALUE_HELP_MESSAGE),
+            None => self.help_message,
+        };
+
+        if let Some(help_message) = help_message {
+            backend.render_help_message(help_message)?;
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,113 @@
+use crate::{
+    test::fake_backend,
+    ui::{Key, KeyModifiers},
+    validator::Validation,
+    MapEditor,
+};
+
+fn type_entry(input: &mut Vec<Key>, key: &str, value: &str) {
+    input.push(Key::Char('a', KeyModifiers::NONE));
+    input.extend(Key::char_keys_from_str(key));
+    input.push(Key::Enter);
+    input.extend(Key::char_keys_from_str(value));
+    input.push(Key::Enter);
+}
+
+#[test]
+fn entries_are_added_with_key_and_value() {
+    let mut input = vec![];
+    type_entry(&mut input, " app ", "web");
+    type_entry(&mut input, "tier", "");
+    input.push(Key::Enter);
+    let mut backend = fake_backend(input);
+
+    let ans = MapEditor::new("Labels:")
+        .prompt_with_backend(&mut backend)
+        .unwrap();
+
+    assert_eq!(
+        vec![
+            (String::from("app"), String::from("web")),
+            (String::from("tier"), String::new()),
+        ],
+        ans
+    );
+}
+
+#[test]
+fn duplicated_keys_are_rejected() {
+    let mut input = vec![Key::Char('a', KeyModifiers::NONE)];
+    input.extend(Key::char_keys_from_str("app"));
+    input.push(Key::Enter);
+    input.push(Key::Backspace);
+    input.extend(Key::char_keys_from_str("x"));
+    input.push(Key::Enter);
+    input.extend(Key::char_keys_from_str("api"));
+    input.extend([Key::Enter, Key::Enter]);
+    let mut backend = fake_backend(input);
+
+    let ans = MapEditor::new("Labels:")
+        .with_entries(&[("app", "web"), ("app", "ignored")])
+        .prompt_with_backend(&mut backend)
+        .unwrap();
+
+    assert_eq!(
+        vec![
+            (String::from("app"), String::from("web")),
+            (String::from("apx"), String::from("api")),
+        ],
+        ans
+    );
+}
+
+#[test]
+fn values_are_edited_and_entries_removed() {
+    let mut input = vec![
+        Key::Char('e', KeyModifiers::NONE),
+        Key::Backspace,
+        Key::Backspace,
+        Key::Backspace,
+    ];
+    input.extend(Key::char_keys_from_str("dev"));
+    input.extend([
+        Key::Enter,
+        Key::Down(KeyModifiers::NONE),
+        Key::Char('d', KeyModifiers::NONE),
+        Key::Enter,
+    ]);
+    let mut backend = fake_backend(input);
+
+    let ans = MapEditor::new("Environment:")
+        .with_entries(&[("STAGE", "prd"), ("DEBUG", "1")])
+        .prompt_with_backend(&mut backend)
+        .unwrap();
+
+    assert_eq!(vec![(String::from("STAGE"), String::from("dev"))], ans);
+}
+
+#[test]
+fn keys_and_values_have_their_own_validators() {
+    let mut input = vec![];
+    type_entry(&mut input, "lower", "");
+    input.push(Key::Escape);
+    type_entry(&mut input, "UPPER", "12345");
+    input.push(Key::Backspace);
+    input.extend([Key::Enter, Key::Enter]);
+    let mut backend = fake_backend(input);
+
+    let ans = MapEditor::new("Environment:")
+        .with_key_validator(
+            |key: &str| match key.chars().all(|c| c.is_ascii_uppercase()) {
+                true => Ok(Validation::Valid),
+                false => Ok(Validation::Invalid("Keys must be uppercase".into())),
+            },
+        )
+        .with_value_validator(|value: &str| match value.len() {
+            0..=4 => Ok(Validation::Valid),
+            _ => Ok(Validation::Invalid("Too long".into())),
+        })
+        .prompt_with_backend(&mut backend)
+        .unwrap();
+
+    assert_eq!(vec![(String::from("UPPER"), String::from("1234"))], ans);
+}
@@ -0,0 +1,63 @@
+use crate::{
+    ui::{Key, KeyModifiers},
+    InnerAction,
+};
+
+use super::config::MenuConfig;
+
+/// Set of actions for a MenuPrompt.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum MenuPromptAction {
+    /// Moves the cursor to the entry above.
+    MoveUp,
+    /// Moves the cursor to the entry below.
+    MoveDown,
+    /// Moves the cursor to the page above.
+    PageUp,
+    /// Moves the cursor to the page below.
+    PageDown,
+    /// Moves the cursor to the start of the list.
+    MoveToStart,
+    /// Moves the cursor to the end of the list.
+    MoveToEnd,
+    /// Opens the submenu of the highlighted entry, if any.
+    EnterSubmenu,
+    /// Goes back to the parent menu, if any.
+    GoBack,
+}
+
+impl InnerAction for MenuPromptAction {
+    type Config = MenuConfig;
+
+    fn from_key(key: Key, config: &MenuConfig) -> Option<Self> {
+        if config.vim_mode {
+            let action = match key {
+                Key::Char('k', KeyModifiers::NONE) => Some(Self::MoveUp),
+                Key::Char('j', KeyModifiers::NONE) => Some(Self::MoveDown),
+                Key::Char('l', KeyModifiers::NONE) => Some(Self::EnterSubmenu),
+                Key::Char('h', KeyModifiers::NONE) => Some(Self::GoBack),
+                _ => None,
+            };
+
+            if action.is_some() {
+                return action;
+            }
+        }
+
+        let action = match key {
+            Key::Up(KeyModifiers::NONE) | Key::Char('p', KeyModifiers::CONTROL) => Self::MoveUp,
+            Key::PageUp(_) => Self::PageUp,
+            Key::Home => Self::MoveToStart,
+
+            Key::Down(KeyModifiers::NONE) | Key::Char('n', KeyModifiers::CONTROL) => Self::MoveDown,
+            Key::PageDown(_) => Self::PageDown,
+            Key::End => Self::MoveToEnd,
+
+            Key::Right(KeyModifiers::NONE) => Self::EnterSubmenu,
+            Key::Left(KeyModifiers::NONE) => Self::GoBack,
+            _ => return None,
+        };
+
+        Some(action)
+    }
+}
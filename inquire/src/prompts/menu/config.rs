@@ -0,0 +1,19 @@
+use crate::Menu;
+
+/// Configuration settings used in the execution of a MenuPrompt.
+#[derive(Copy, Clone, Debug)]
+pub struct MenuConfig {
+    /// Whether to use vim-style keybindings.
+    pub vim_mode: bool,
+    /// Page size of the list of entries.
+    pub page_size: usize,
+}
+
+impl<T> From<&Menu<'_, T>> for MenuConfig {
+    fn from(value: &Menu<'_, T>) -> Self {
+        Self {
+            vim_mode: value.vim_mode,
+            page_size: value.page_size,
+        }
+    }
+}
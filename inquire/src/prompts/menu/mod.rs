@@ -0,0 +1,215 @@
+mod action;
+mod config;
+mod prompt;
+#[cfg(test)]
+mod test;
+
+pub use action::*;
+
+use std::fmt::Display;
+
+use crate::{
+    config::get_configuration,
+    error::{InquireError, InquireResult},
+    formatter::MenuFormatter,
+    prompts::prompt::Prompt,
+    terminal::get_default_terminal,
+    ui::{Backend, MenuBackend, RenderConfig},
+    TreeNode,
+};
+
+use self::prompt::MenuPrompt;
+
+/// Prompt suitable for when you need the user to navigate through nested menus, such as settings screens or command hierarchies.
+///
+/// The prompt receives a list of [`TreeNode`]s as the entries of the root menu. Entries with children open a submenu when selected, with enter or the right arrow, replacing the displayed list with the children of the entry. The left arrow or ESC go back up to the parent menu, and the current path is displayed as a breadcrumb next to the prompt message.
+///
+/// Pressing enter on an entry without children submits the prompt, which returns the full path of selected entries, from the root menu down to the chosen one. Pressing ESC on the root menu cancels the prompt.
+///
+/// This prompt requires a prompt message and a **non-empty** `Vec` of root entries. The values can be of any type as long as they implement the `Display` trait.
+/// - If the list is empty, the prompt operation will fail with an `InquireError::InvalidConfiguration` error.
+///
+/// Menu prompts provide several options of configuration:
+///
+/// - **Prompt message**: Required when creating the prompt.
+/// - **Entries**: Entries of the root menu. Must be **non-empty**.
+/// - **Help message**: Message displayed at the line below the prompt.
+/// - **Formatter**: Custom formatter in case you need to pre-process the user input before showing it as the final answer.
+///   - Prints the selected path, separated by `›`, by default.
+/// - **Page size**: Number of entries displayed at once, 7 by default.
+///
+/// # Example
+///
+/// ```no_run
+/// use inquire::{Menu, TreeNode};
+///
+/// let entries = vec![
+///     TreeNode::new("Settings").with_children(vec![
+///         TreeNode::new("Network"),
+///         TreeNode::new("Display"),
+///     ]),
+///     TreeNode::new("Quit"),
+/// ];
+///
+/// let ans = Menu::new("What do you want to do?", entries).prompt();
+///
+/// match ans {
+///     Ok(path) => println!("Opening {}", path.join(" › ")),
+///     Err(_) => println!("There was an error, please try again"),
+/// }
+/// ```
+#[derive(Clone)]
+pub struct Menu<'a, T> {
+    /// Message to be presented to the user.
+    pub message: &'a str,
+
+    /// Entries of the root menu. Entries with children open a submenu.
+    pub entries: Vec<TreeNode<T>>,
+
+    /// Help message to be presented to the user.
+    pub help_message: Option<&'a str>,
+
+    /// Page size of the entries displayed to the user.
+    pub page_size: usize,
+
+    /// Whether vim mode is enabled. When enabled, the user can
+    /// navigate through the entries using hjkl.
+    pub vim_mode: bool,
+
+    /// Function that formats the user input and presents it to the user as the final rendering of the prompt.
+    pub formatter: MenuFormatter<'a, T>,
+
+    /// RenderConfig to apply to the rendered interface.
+    ///
+    /// Note: The default render config considers if the NO_COLOR environment variable
+    /// is set to decide whether to render the colored config or the empty one.
+    ///
+    /// When overriding the config in a prompt, NO_COLOR is no longer considered and your
+    /// config is treated as the only source of truth. If you want to customize colors
+    /// and still support NO_COLOR, you will have to do this on your end.
+    pub render_config: RenderConfig<'a>,
+}
+
+impl<'a, T> Menu<'a, T>
+where
+    T: Display,
+{
+    /// String formatter used by default in [Menu](crate::Menu) prompts.
+    /// Prints the values of the selected path, separated by `›`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use inquire::Menu;
+    ///
+    /// let formatter = Menu::<&str>::DEFAULT_FORMATTER;
+    /// assert_eq!(String::from("Settings › Network"), formatter(&["Settings", "Network"]));
+    /// ```
+    pub const DEFAULT_FORMATTER: MenuFormatter<'a, T> = &|path| {
+        path.iter()
+            .map(T::to_string)
+            .collect::<Vec<String>>()
+            .join(" › ")
+    };
+
+    /// Default page size.
+    pub const DEFAULT_PAGE_SIZE: usize = crate::config::DEFAULT_PAGE_SIZE;
+
+    /// Default value of vim mode.
+    pub const DEFAULT_VIM_MODE: bool = crate::config::DEFAULT_VIM_MODE;
+
+    /// Default help message.
+    pub const DEFAULT_HELP_MESSAGE: Option<&'a str> =
+        Some("↑↓ to move, enter to select, ← or esc to go back");
+
+    /// Creates a [Menu] with the provided message and root entries, along with default configuration values.
+    pub fn new(message: &'a str, entries: Vec<TreeNode<T>>) -> Self {
+        Self {
+            message,
+            entries,
+            help_message: Self::DEFAULT_HELP_MESSAGE,
+            page_size: Self::DEFAULT_PAGE_SIZE,
+            vim_mode: Self::DEFAULT_VIM_MODE,
+            formatter: Self::DEFAULT_FORMATTER,
+            render_config: get_configuration(),
+        }
+    }
+
+    /// Sets the help message of the prompt.
+    pub fn with_help_message(mut self, message: &'a str) -> Self {
+        self.help_message = Some(message);
+        self
+    }
+
+    /// Removes the set help message.
+    pub fn without_help_message(mut self) -> Self {
+        self.help_message = None;
+        self
+    }
+
+    /// Sets the page size.
+    pub fn with_page_size(mut self, page_size: usize) -> Self {
+        self.page_size = page_size;
+        self
+    }
+
+    /// Enables or disables vim_mode.
+    pub fn with_vim_mode(mut self, vim_mode: bool) -> Self {
+        self.vim_mode = vim_mode;
+        self
+    }
+
+    /// Sets the formatter.
+    pub fn with_formatter(mut self, formatter: MenuFormatter<'a, T>) -> Self {
+        self.formatter = formatter;
+        self
+    }
+
+    /// Sets the provided color theme to this prompt.
+    ///
+    /// Note: The default render config considers if the NO_COLOR environment variable
+    /// is set to decide whether to render the colored config or the empty one.
+    ///
+    /// When overriding the config in a prompt, NO_COLOR is no longer considered and your
+    /// config is treated as the only source of truth. If you want to customize colors
+    /// and still support NO_COLOR, you will have to do this on your end.
+    pub fn with_render_config(mut self, render_config: RenderConfig<'a>) -> Self {
+        self.render_config = render_config;
+        self
+    }
+
+    /// Parses the provided behavioral and rendering options and prompts
+    /// the CLI user for input according to the defined rules.
+    ///
+    /// This method is intended for flows where the user skipping/cancelling
+    /// the prompt - by pressing ESC - is considered normal behavior. In this case,
+    /// it does not return `Err(InquireError::OperationCanceled)`, but `Ok(None)`.
+    ///
+    /// Meanwhile, if the user does submit an answer, the method wraps the return
+    /// type with `Some`.
+    pub fn prompt_skippable(self) -> InquireResult<Option<Vec<T>>> {
+        match self.prompt() {
+            Ok(answer) => Ok(Some(answer)),
+            Err(InquireError::OperationCanceled) => Ok(None),
+            Err(err) => Err(err),
+        }
+    }
+
+    /// Parses the provided behavioral and rendering options and prompts
+    /// the CLI user for input according to the defined rules.
+    ///
+    /// Returns the owned values of the selected path, from the entry of the
+    /// root menu down to the chosen entry.
+    pub fn prompt(self) -> InquireResult<Vec<T>> {
+        let (input_reader, terminal) = get_default_terminal()?;
+        let mut backend = Backend::new(input_reader, terminal, self.render_config)?;
+        self.prompt_with_backend(&mut backend)
+    }
+
+    pub(crate) fn prompt_with_backend<B: MenuBackend>(
+        self,
+        backend: &mut B,
+    ) -> InquireResult<Vec<T>> {
+        MenuPrompt::new(self)?.prompt(backend)
+    }
+}
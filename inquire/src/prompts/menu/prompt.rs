@@ -0,0 +1,220 @@
+use std::fmt::Display;
+
+use crate::{
+    error::InquireResult,
+    formatter::MenuFormatter,
+    list_option::ListOption,
+    prompts::prompt::{ActionResult, Prompt},
+    ui::MenuBackend,
+    utils::paginate,
+    InquireError, Menu, TreeNode,
+};
+
+use super::{action::MenuPromptAction, config::MenuConfig};
+
+pub struct MenuPrompt<'a, T> {
+    message: &'a str,
+    config: MenuConfig,
+    entries: Vec<TreeNode<T>>,
+    // index of the opened entry at each level, from the root menu down to the
+    // parent of the current one, restored as the cursor when going back
+    path: Vec<usize>,
+    help_message: Option<&'a str>,
+    cursor_index: usize,
+    formatter: MenuFormatter<'a, T>,
+}
+
+impl<'a, T> MenuPrompt<'a, T>
+where
+    T: Display,
+{
+    pub fn new(mo: Menu<'a, T>) -> InquireResult<Self> {
+        if mo.entries.is_empty() {
+            return Err(InquireError::InvalidConfiguration(
+                "Available options can not be empty".into(),
+            ));
+        }
+
+        Ok(Self {
+            message: mo.message,
+            config: (&mo).into(),
+            entries: mo.entries,
+            path: vec![],
+            help_message: mo.help_message,
+            cursor_index: 0,
+            formatter: mo.formatter,
+        })
+    }
+
+    fn current_entries(&self) -> &[TreeNode<T>] {
+        self.path
+            .iter()
+            .fold(&self.entries, |entries, idx| &entries[*idx].children)
+    }
+
+    fn breadcrumb(&self) -> Vec<String> {
+        let mut entries = &self.entries;
+        let mut breadcrumb = vec![];
+
+        for idx in &self.path {
+            breadcrumb.push(entries[*idx].value.to_string());
+            entries = &entries[*idx].children;
+        }
+
+        breadcrumb
+    }
+
+    fn enter_submenu(&mut self) -> ActionResult {
+        if self.current_entries()[self.cursor_index].is_leaf() {
+            return ActionResult::Clean;
+        }
+
+        self.path.push(self.cursor_index);
+        self.cursor_index = 0;
+
+        ActionResult::NeedsRedraw
+    }
+
+    fn go_back(&mut self) -> ActionResult {
+        match self.path.pop() {
+            Some(idx) => {
+                self.cursor_index = idx;
+                ActionResult::NeedsRedraw
+            }
+            None => ActionResult::Clean,
+        }
+    }
+
+    fn move_cursor_up(&mut self, qty: usize, wrap: bool) -> ActionResult {
+        let len = self.current_entries().len();
+
+        let new_position = if wrap {
+            let after_wrap = qty.saturating_sub(self.cursor_index);
+            self.cursor_index
+                .checked_sub(qty)
+                .unwrap_or_else(|| len.saturating_sub(after_wrap))
+        } else {
+            self.cursor_index.saturating_sub(qty)
+        };
+
+        self.update_cursor_position(new_position)
+    }
+
+    fn move_cursor_down(&mut self, qty: usize, wrap: bool) -> ActionResult {
+        let len = self.current_entries().len();
+        let mut new_position = self.cursor_index.saturating_add(qty);
+
+        if new_position >= len {
+            new_position = if wrap {
+                new_position % len
+            } else {
+                len.saturating_sub(1)
+            }
+        }
+
+        self.update_cursor_position(new_position)
+    }
+
+    fn update_cursor_position(&mut self, new_position: usize) -> ActionResult {
+        if new_position != self.cursor_index {
+            self.cursor_index = new_position;
+            ActionResult::NeedsRedraw
+        } else {
+            ActionResult::Clean
+        }
+    }
+}
+
+impl<'a, Backend, T> Prompt<Backend> for MenuPrompt<'a, T>
+where
+    Backend: MenuBackend,
+    T: Display,
+{
+    type Config = MenuConfig;
+    type InnerAction = MenuPromptAction;
+    type Output = Vec<T>;
+
+    fn message(&self) -> &str {
+        self.message
+    }
+
+    fn config(&self) -> &MenuConfig {
+        &self.config
+    }
+
+    fn format_answer(&self, answer: &Vec<T>) -> String {
+        (self.formatter)(answer)
+    }
+
+    fn pre_cancel(&mut self) -> InquireResult<bool> {
+        if self.path.is_empty() {
+            return Ok(true);
+        }
+
+        self.go_back();
+        Ok(false)
+    }
+
+    fn submit(&mut self) -> InquireResult<Option<Vec<T>>> {
+        if !self.current_entries()[self.cursor_index].is_leaf() {
+            self.enter_submenu();
+            return Ok(None);
+        }
+
+        let mut entries = std::mem::take(&mut self.entries);
+        let mut answer = vec![];
+
+        for idx in self.path.iter().chain(std::iter::once(&self.cursor_index)) {
+            let entry = entries.swap_remove(*idx);
+            answer.push(entry.value);
+            entries = entry.children;
+        }
+
+        Ok(Some(answer))
+    }
+
+    fn handle(&mut self, action: MenuPromptAction) -> InquireResult<ActionResult> {
+        let result = match action {
+            MenuPromptAction::MoveUp => self.move_cursor_up(1, true),
+            MenuPromptAction::MoveDown => self.move_cursor_down(1, true),
+            MenuPromptAction::PageUp => self.move_cursor_up(self.config.page_size, false),
+            MenuPromptAction::PageDown => self.move_cursor_down(self.config.page_size, false),
+            MenuPromptAction::MoveToStart => self.move_cursor_up(usize::MAX, false),
+            MenuPromptAction::MoveToEnd => self.move_cursor_down(usize::MAX, false),
+            MenuPromptAction::EnterSubmenu => self.enter_submenu(),
+            MenuPromptAction::GoBack => self.go_back(),
+        };
+
+        Ok(result)
+    }
+
+    fn render(&self, backend: &mut Backend) -> InquireResult<()> {
+        let prompt = &self.message;
+
+        backend.render_menu_prompt(prompt, &self.breadcrumb())?;
+
+        let entries = self.current_entries();
+
+        let choices = entries
+            .iter()
+            .enumerate()
+            .map(|(i, entry)| ListOption::new(i, &entry.value))
+            .collect::<Vec<ListOption<&T>>>();
+
+        let page = paginate(self.config.page_size, &choices, Some(self.cursor_index));
+
+        let submenus = page
+            .content
+            .iter()
+            .map(|option| !entries[option.index].is_leaf())
+            .collect::<Vec<bool>>();
+
+        backend.render_menu_entries(page, &submenus)?;
+
+        if let Some(help_message) = self.help_message {
+            backend.render_help_message(help_message)?;
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,127 @@
+use crate::{
+    error::InquireResult,
+    ui::{
+        test::{FakeBackend, Token},
+        Key, KeyModifiers,
+    },
+    InquireError, Menu, TreeNode,
+};
+
+fn entries() -> Vec<TreeNode<&'static str>> {
+    vec![
+        TreeNode::new("Settings").with_children(vec![
+            TreeNode::new("Network").with_children(vec!["Wi-Fi".into(), "Ethernet".into()]),
+            TreeNode::new("Display"),
+        ]),
+        TreeNode::new("Help"),
+    ]
+}
+
+fn down() -> Key {
+    Key::Down(KeyModifiers::NONE)
+}
+
+#[test]
+fn leaf_on_root_menu_is_returned() -> InquireResult<()> {
+    let mut backend = FakeBackend::new(vec![down(), Key::Enter]);
+
+    let ans = Menu::new("Menu", entries()).prompt_with_backend(&mut backend)?;
+
+    assert_eq!(vec!["Help"], ans);
+
+    Ok(())
+}
+
+#[test]
+fn enter_drills_into_submenus_and_returns_full_path() -> InquireResult<()> {
+    let mut backend = FakeBackend::new(vec![Key::Enter, Key::Enter, down(), Key::Enter]);
+
+    let ans = Menu::new("Menu", entries()).prompt_with_backend(&mut backend)?;
+
+    assert_eq!(vec!["Settings", "Network", "Ethernet"], ans);
+    assert!(backend
+        .frames()
+        .last()
+        .unwrap()
+        .has_token(&Token::AnsweredPrompt(
+            "Menu".into(),
+            "Settings › Network › Ethernet".into()
+        )));
+
+    Ok(())
+}
+
+#[test]
+fn breadcrumb_shows_the_current_path() -> InquireResult<()> {
+    let mut backend =
+        FakeBackend::new(vec![Key::Enter, Key::Right(KeyModifiers::NONE), Key::Enter]);
+
+    Menu::new("Menu", entries()).prompt_with_backend(&mut backend)?;
+
+    assert!(backend.frames()[0].has_token(&Token::Breadcrumb(vec![])));
+    assert!(backend.frames()[0].has_token(&Token::MenuEntries {
+        options: vec!["Settings".into(), "Help".into()],
+        submenus: vec![true, false],
+        cursor: Some(0),
+    }));
+    assert!(backend.frames()[1].has_token(&Token::Breadcrumb(vec!["Settings".into()])));
+    assert!(backend.frames()[2].has_token(&Token::Breadcrumb(vec![
+        "Settings".into(),
+        "Network".into()
+    ])));
+
+    Ok(())
+}
+
+#[test]
+fn left_goes_back_and_restores_the_cursor() -> InquireResult<()> {
+    let mut backend = FakeBackend::new(vec![
+        Key::Enter,
+        Key::Left(KeyModifiers::NONE),
+        down(),
+        Key::Enter,
+    ]);
+
+    let ans = Menu::new("Menu", entries()).prompt_with_backend(&mut backend)?;
+
+    assert_eq!(vec!["Help"], ans);
+    assert!(backend.frames()[2].has_token(&Token::MenuEntries {
+        options: vec!["Settings".into(), "Help".into()],
+        submenus: vec![true, false],
+        cursor: Some(0),
+    }));
+
+    Ok(())
+}
+
+#[test]
+fn esc_goes_back_before_canceling() -> InquireResult<()> {
+    let mut backend = FakeBackend::new(vec![
+        Key::Enter,
+        Key::Enter,
+        Key::Escape,
+        down(),
+        Key::Enter,
+    ]);
+
+    let ans = Menu::new("Menu", entries()).prompt_with_backend(&mut backend)?;
+
+    assert_eq!(vec!["Settings", "Display"], ans);
+
+    let mut backend = FakeBackend::new(vec![Key::Enter, Key::Escape, Key::Escape]);
+
+    let ans = Menu::new("Menu", entries()).prompt_with_backend(&mut backend);
+
+    assert!(matches!(ans, Err(InquireError::OperationCanceled)));
+
+    Ok(())
+}
+
+#[test]
+fn empty_entries_are_rejected() {
+    let mut backend = FakeBackend::new(vec![]);
+
+    let ans = Menu::<&str>::new("Menu", vec![]).prompt_with_backend(&mut backend);
+
+    assert!(matches!(ans, Err(InquireError::InvalidConfiguration(_))));
+}
@@ -1,29 +1,121 @@
 mod action;
+mod checkboxmatrix;
+mod colorselect;
+mod commandpalette;
 mod confirm;
+mod currency;
 mod custom_type;
 #[cfg(feature = "date")]
 mod dateselect;
+#[cfg(feature = "date")]
+mod datetimeselect;
+mod directoryselect;
+mod durationinput;
 #[cfg(feature = "editor")]
 mod editor;
+mod email;
+mod fileselect;
+mod fuzzyfinder;
+mod gridselect;
+mod hotkeyselect;
+mod ip;
+mod keycapture;
+mod listeditor;
+mod mapeditor;
+mod menu;
+#[cfg(feature = "date")]
+mod monthselect;
 mod multiselect;
+mod number;
+mod numberrange;
 mod one_liners;
+mod order;
+mod pagedselect;
 mod password;
+mod percentage;
+mod phonenumber;
 mod prompt;
+mod rating;
 mod select;
+mod slider;
+mod spinner;
+mod tableeditor;
+mod tableselect;
+mod tags;
 #[cfg(test)]
 pub(crate) mod test;
 mod text;
+mod textarea;
+#[cfg(feature = "date")]
+mod timeselect;
+mod toggle;
+mod transfer;
+mod treeselect;
+mod typetoconfirm;
+#[cfg(feature = "url")]
+mod urlinput;
+#[cfg(feature = "semver")]
+mod version;
+#[cfg(feature = "date")]
+mod weekselect;
+mod wizard;
 
 pub use action::*;
+pub use checkboxmatrix::*;
+pub use colorselect::*;
+pub use commandpalette::*;
 pub use confirm::*;
+pub use currency::*;
 pub use custom_type::*;
 #[cfg(feature = "date")]
 pub use dateselect::*;
+#[cfg(feature = "date")]
+pub use datetimeselect::*;
+pub use directoryselect::*;
+pub use durationinput::*;
 #[cfg(feature = "editor")]
 pub use editor::*;
+pub use email::*;
+pub use fileselect::*;
+pub use fuzzyfinder::*;
+pub use gridselect::*;
+pub use hotkeyselect::*;
+pub use ip::*;
+pub use keycapture::*;
+pub use listeditor::*;
+pub use mapeditor::*;
+pub use menu::*;
+#[cfg(feature = "date")]
+pub use monthselect::*;
 pub use multiselect::*;
+pub use number::*;
+pub use numberrange::*;
 #[cfg(feature = "one-liners")]
 pub use one_liners::*;
+pub use order::*;
+pub use pagedselect::*;
 pub use password::*;
+pub use percentage::*;
+pub use phonenumber::*;
+pub use rating::*;
 pub use select::*;
+pub use slider::*;
+pub use spinner::*;
+pub use tableeditor::*;
+pub use tableselect::*;
+pub use tags::*;
 pub use text::*;
+pub use textarea::*;
+#[cfg(feature = "date")]
+pub use timeselect::*;
+pub use toggle::*;
+pub use transfer::*;
+pub use treeselect::*;
+pub use typetoconfirm::*;
+#[cfg(feature = "url")]
+pub use urlinput::*;
+#[cfg(feature = "semver")]
+pub use version::*;
+#[cfg(feature = "date")]
+pub use weekselect::*;
+pub use wizard::*;
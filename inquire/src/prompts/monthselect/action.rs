@@ -0,0 +1,67 @@
+use crate::{
+    ui::{Key, KeyModifiers},
+    InnerAction,
+};
+
+use super::config::MonthSelectConfig;
+
+/// Set of actions for a MonthSelectPrompt.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+#[allow(clippy::enum_variant_names)]
+pub enum MonthSelectPromptAction {
+    /// Move month cursor to the previous month.
+    GoToPrevMonth,
+    /// Move month cursor to the next month.
+    GoToNextMonth,
+    /// Move month cursor to the month above, three months before the selected one.
+    GoToPrevQuarter,
+    /// Move month cursor to the month below, three months after the selected one.
+    GoToNextQuarter,
+    /// Move month cursor to the previous year.
+    GoToPrevYear,
+    /// Move month cursor to the next year.
+    GoToNextYear,
+}
+
+impl InnerAction for MonthSelectPromptAction {
+    type Config = MonthSelectConfig;
+
+    fn from_key(key: Key, _: &MonthSelectConfig) -> Option<Self> {
+        let action = match key {
+            Key::Left(KeyModifiers::NONE) // standard
+            | Key::Char('b', KeyModifiers::CONTROL) // emacs
+            | Key::Char('h', KeyModifiers::NONE) // vim
+            => Self::GoToPrevMonth,
+
+            Key::Right(KeyModifiers::NONE) // standard
+            | Key::Char('f', KeyModifiers::CONTROL) // emacs
+            | Key::Char('l', KeyModifiers::NONE) // vim
+            => Self::GoToNextMonth,
+
+            Key::Up(KeyModifiers::NONE) // standard
+            | Key::Char('p', KeyModifiers::CONTROL) // emacs
+            | Key::Char('k', KeyModifiers::NONE) // vim
+            => Self::GoToPrevQuarter,
+
+            Key::Down(KeyModifiers::NONE) // standard
+            | Key::Char('n', KeyModifiers::CONTROL) // emacs
+            | Key::Char('j', KeyModifiers::NONE) // vim
+            | Key::Tab
+            => Self::GoToNextQuarter,
+
+            Key::PageUp(_) // standard
+            | Key::Char('[' | '{', _) // alternative when page up is not available
+            | Key::Left(_) | Key::Up(_) // alternative 2, when the arrows above with no modifiers are not matched
+            => Self::GoToPrevYear,
+
+            Key::PageDown(_) // standard
+            | Key::Char(']' | '}', _) // alternative when page down is not available
+            | Key::Right(_) | Key::Down(_) // alternative 2, when the arrows above with no modifiers are not matched
+            => Self::GoToNextYear,
+
+            _ => return None,
+        };
+
+        Some(action)
+    }
+}
@@ -0,0 +1,28 @@
+use chrono::{Datelike, NaiveDate};
+
+use crate::MonthSelect;
+
+/// Configuration settings used in the execution of a MonthSelectPrompt.
+#[derive(Copy, Clone, Debug)]
+pub struct MonthSelectConfig {
+    /// Min month allowed to be selected, pinned to its first day.
+    pub min_month: Option<NaiveDate>,
+
+    /// Max month allowed to be selected, pinned to its first day.
+    pub max_month: Option<NaiveDate>,
+}
+
+impl From<&MonthSelect<'_>> for MonthSelectConfig {
+    fn from(value: &MonthSelect<'_>) -> Self {
+        Self {
+            min_month: value.min_date.map(first_day_of_month),
+            max_month: value.max_date.map(first_day_of_month),
+        }
+    }
+}
+
+/// Returns the first day of the month of the given date.
+pub(crate) fn first_day_of_month(date: NaiveDate) -> NaiveDate {
+    date.with_day(1)
+        .expect("unexpected invalid date when pinning it to the first day of the month")
+}
@@ -0,0 +1,279 @@
+mod action;
+mod config;
+mod prompt;
+#[cfg(test)]
+#[cfg(feature = "crossterm")]
+mod test;
+
+pub use action::*;
+
+use chrono::{Datelike, NaiveDate};
+
+use crate::{
+    config::get_configuration,
+    date_utils::get_current_date,
+    error::{InquireError, InquireResult},
+    formatter::DateFormatter,
+    prompts::prompt::Prompt,
+    terminal::get_default_terminal,
+    ui::{date::MonthSelectBackend, Backend, RenderConfig},
+    validator::DateValidator,
+};
+
+use self::prompt::MonthSelectPrompt;
+
+/// Prompt that allows user to select a month of a year, without a day, from an interactive grid of months. Available via the `date` feature.
+///
+/// A lighter alternative to [DateSelect](crate::DateSelect) for inputs such as billing periods and report ranges. The months of the selected year are displayed in a grid of four rows of three months each, below the year, and the user navigates through them by pressing the keyboard arrows.
+///
+/// More specifically:
+/// - Left and right arrows move to the previous and next months, crossing to the adjacent years when needed.
+/// - Up and down arrows move to the month above or below the selected one, three months before or after it.
+/// - Page up and page down, or the arrows when pressed with `ctrl`, move to the previous and next years.
+///
+/// Finally, the user selects a month by pressing the space or enter keys.
+///
+/// The answer is returned as a `NaiveDate` pinned to the first day of the selected month, or as a `(year, month)` tuple when using [MonthSelect::prompt_year_month].
+///
+/// `MonthSelect` prompts provide several options of configuration:
+///
+/// - **Prompt message**: Required when creating the prompt.
+/// - **Default value**: Month selected when the grid is displayed, and the one returned if the user submits without any previous actions. Current month by default.
+/// - **Help message**: Message displayed at the line below the prompt.
+/// - **Formatter**: Custom formatter in case you need to pre-process the user input before showing it as the final answer.
+///   - Formats to "Month Year" by default.
+/// - **Validators**: Custom validators to the first day of the user's selected month, displaying an error message if it does not pass the requirements.
+/// - **Min and max date**: Inclusive boundaries of allowed months, only their month and year are considered. If any boundary is set, the user will not be able to move past them.
+///
+/// # Example
+///
+/// ```no_run
+/// use chrono::NaiveDate;
+/// use inquire::MonthSelect;
+///
+/// let period = MonthSelect::new("Billing period:")
+///     .with_min_date(NaiveDate::from_ymd_opt(2023, 1, 1).unwrap())
+///     .with_max_date(NaiveDate::from_ymd_opt(2024, 6, 1).unwrap())
+///     .with_default(NaiveDate::from_ymd_opt(2024, 6, 1).unwrap())
+///     .prompt_year_month();
+///
+/// match period {
+///     Ok((year, month)) => println!("Generating invoices of {:02}/{}", month, year),
+///     Err(_) => println!("There was an error in the system."),
+/// }
+/// ```
+#[derive(Clone)]
+pub struct MonthSelect<'a> {
+    /// Message to be presented to the user.
+    pub message: &'a str,
+
+    /// Starting month to be selected, any day of the month can be used.
+    pub starting_date: NaiveDate,
+
+    /// Min month allowed to be selected, any day of the month can be used.
+    pub min_date: Option<NaiveDate>,
+
+    /// Max month allowed to be selected, any day of the month can be used.
+    pub max_date: Option<NaiveDate>,
+
+    /// Help message to be presented to the user.
+    pub help_message: Option<&'a str>,
+
+    /// Function that formats the user input and presents it to the user as the final rendering of the prompt.
+    pub formatter: DateFormatter<'a>,
+
+    /// Collection of validators to apply to the user input.
+    ///
+    /// Validators are executed in the order they are stored, stopping at and displaying to the user
+    /// only the first validation error that might appear.
+    ///
+    /// The possible error is displayed to the user one line above the prompt.
+    pub validators: Vec<Box<dyn DateValidator>>,
+
+    /// RenderConfig to apply to the rendered interface.
+    ///
+    /// Note: The default render config considers if the NO_COLOR environment variable
+    /// is set to decide whether to render the colored config or the empty one.
+    ///
+    /// When overriding the config in a prompt, NO_COLOR is no longer considered and your
+    /// config is treated as the only source of truth. If you want to customize colors
+    /// and still support NO_COLOR, you will have to do this on your end.
+    pub render_config: RenderConfig<'a>,
+}
+
+impl<'a> MonthSelect<'a> {
+    /// Default formatter, printing the name of the month followed by the year.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chrono::NaiveDate;
+    /// use inquire::MonthSelect;
+    ///
+    /// let formatter = MonthSelect::DEFAULT_FORMATTER;
+    /// assert_eq!(
+    ///     String::from("July 2021"),
+    ///     formatter(NaiveDate::from_ymd_opt(2021, 7, 1).unwrap()),
+    /// );
+    /// ```
+    pub const DEFAULT_FORMATTER: DateFormatter<'a> = &|val| val.format("%B %Y").to_string();
+
+    /// Default help message.
+    pub const DEFAULT_HELP_MESSAGE: Option<&'a str> =
+        Some("arrows to move, [] move years, enter to select");
+
+    /// Default validators added to the [MonthSelect] prompt, none.
+    pub const DEFAULT_VALIDATORS: Vec<Box<dyn DateValidator>> = vec![];
+
+    /// Default min date.
+    pub const DEFAULT_MIN_DATE: Option<NaiveDate> = None;
+
+    /// Default max date.
+    pub const DEFAULT_MAX_DATE: Option<NaiveDate> = None;
+
+    /// Creates a [MonthSelect] with the provided message, along with default configuration values.
+    pub fn new(message: &'a str) -> Self {
+        Self {
+            message,
+            starting_date: get_current_date(),
+            min_date: Self::DEFAULT_MIN_DATE,
+            max_date: Self::DEFAULT_MAX_DATE,
+            help_message: Self::DEFAULT_HELP_MESSAGE,
+            formatter: Self::DEFAULT_FORMATTER,
+            validators: Self::DEFAULT_VALIDATORS,
+            render_config: get_configuration(),
+        }
+    }
+
+    /// Sets the help message of the prompt.
+    pub fn with_help_message(mut self, message: &'a str) -> Self {
+        self.help_message = Some(message);
+        self
+    }
+
+    /// Removes the set help message.
+    pub fn without_help_message(mut self) -> Self {
+        self.help_message = None;
+        self
+    }
+
+    /// Sets the default month of the prompt. Equivalent to [MonthSelect::with_starting_date](MonthSelect::with_starting_date).
+    pub fn with_default(self, default: NaiveDate) -> Self {
+        self.with_starting_date(default)
+    }
+
+    /// Sets the min date, of which only the month and year are considered.
+    pub fn with_min_date(mut self, min_date: NaiveDate) -> Self {
+        self.min_date = Some(min_date);
+        self
+    }
+
+    /// Sets the max date, of which only the month and year are considered.
+    pub fn with_max_date(mut self, max_date: NaiveDate) -> Self {
+        self.max_date = Some(max_date);
+        self
+    }
+
+    /// Sets the starting month, of which only the month and year are considered.
+    /// Equivalent to [MonthSelect::with_default](MonthSelect::with_default).
+    pub fn with_starting_date(mut self, starting_date: NaiveDate) -> Self {
+        self.starting_date = starting_date;
+        self
+    }
+
+    /// Adds a validator to the collection of validators. You might want to use this feature
+    /// in case you need to limit the user to specific choices, such as not allowing months
+    /// that were already closed.
+    ///
+    /// Validators receive the first day of the selected month.
+    ///
+    /// Validators are executed in the order they are stored, stopping at and displaying to the user
+    /// only the first validation error that might appear.
+    ///
+    /// The possible error is displayed to the user one line above the prompt.
+    pub fn with_validator<V>(mut self, validator: V) -> Self
+    where
+        V: DateValidator + 'static,
+    {
+        self.validators.push(Box::new(validator));
+        self
+    }
+
+    /// Adds the validators to the collection of validators in the order they are given.
+    /// You might want to use this feature in case you need to limit the user to specific
+    /// choices, such as not allowing months that were already closed.
+    ///
+    /// Validators receive the first day of the selected month.
+    ///
+    /// Validators are executed in the order they are stored, stopping at and displaying to the user
+    /// only the first validation error that might appear.
+    ///
+    /// The possible error is displayed to the user one line above the prompt.
+    pub fn with_validators(mut self, validators: &[Box<dyn DateValidator>]) -> Self {
+        for validator in validators {
+            self.validators.push(validator.clone());
+        }
+        self
+    }
+
+    /// Sets the formatter.
+    pub fn with_formatter(mut self, formatter: DateFormatter<'a>) -> Self {
+        self.formatter = formatter;
+        self
+    }
+
+    /// Sets the provided color theme to this prompt.
+    ///
+    /// Note: The default render config considers if the NO_COLOR environment variable
+    /// is set to decide whether to render the colored config or the empty one.
+    ///
+    /// When overriding the config in a prompt, NO_COLOR is no longer considered and your
+    /// config is treated as the only source of truth. If you want to customize colors
+    /// and still support NO_COLOR, you will have to do this on your end.
+    pub fn with_render_config(mut self, render_config: RenderConfig<'a>) -> Self {
+        self.render_config = render_config;
+        self
+    }
+
+    /// Parses the provided behavioral and rendering options and prompts
+    /// the CLI user for input according to the defined rules.
+    ///
+    /// This method is intended for flows where the user skipping/cancelling
+    /// the prompt - by pressing ESC - is considered normal behavior. In this case,
+    /// it does not return `Err(InquireError::OperationCanceled)`, but `Ok(None)`.
+    ///
+    /// Meanwhile, if the user does submit an answer, the method wraps the return
+    /// type with `Some`.
+    pub fn prompt_skippable(self) -> InquireResult<Option<NaiveDate>> {
+        match self.prompt() {
+            Ok(answer) => Ok(Some(answer)),
+            Err(InquireError::OperationCanceled) => Ok(None),
+            Err(err) => Err(err),
+        }
+    }
+
+    /// Parses the provided behavioral and rendering options and prompts
+    /// the CLI user for input according to the defined rules.
+    ///
+    /// Returns the first day of the month selected by the user.
+    pub fn prompt(self) -> InquireResult<NaiveDate> {
+        let (input_reader, terminal) = get_default_terminal()?;
+        let mut backend = Backend::new(input_reader, terminal, self.render_config)?;
+        self.prompt_with_backend(&mut backend)
+    }
+
+    /// Parses the provided behavioral and rendering options and prompts
+    /// the CLI user for input according to the defined rules.
+    ///
+    /// Returns the year and the month, from 1 to 12, selected by the user.
+    pub fn prompt_year_month(self) -> InquireResult<(i32, u32)> {
+        self.prompt().map(|date| (date.year(), date.month()))
+    }
+
+    pub(crate) fn prompt_with_backend<B: MonthSelectBackend>(
+        self,
+        backend: &mut B,
+    ) -> InquireResult<NaiveDate> {
+        MonthSelectPrompt::new(self)?.prompt(backend)
+    }
+}
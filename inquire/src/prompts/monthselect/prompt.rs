@@ -0,0 +1,174 @@
+use std::cmp::{max, min, Ordering};
+
+use chrono::{Months, NaiveDate};
+
+use crate::{
+    date_utils::get_current_date,
+    error::InquireResult,
+    formatter::DateFormatter,
+    prompts::prompt::{ActionResult, Prompt},
+    ui::date::MonthSelectBackend,
+    validator::{DateValidator, ErrorMessage, Validation},
+    InquireError, MonthSelect,
+};
+
+use super::{
+    action::MonthSelectPromptAction,
+    config::{first_day_of_month, MonthSelectConfig},
+};
+
+pub struct MonthSelectPrompt<'a> {
+    message: &'a str,
+    config: MonthSelectConfig,
+    current_month: NaiveDate,
+    help_message: Option<&'a str>,
+    formatter: DateFormatter<'a>,
+    validators: Vec<Box<dyn DateValidator>>,
+    error: Option<ErrorMessage>,
+}
+
+impl<'a> MonthSelectPrompt<'a> {
+    pub fn new(ms: MonthSelect<'a>) -> InquireResult<Self> {
+        let config: MonthSelectConfig = (&ms).into();
+        let starting_month = first_day_of_month(ms.starting_date);
+
+        if let Some(min_month) = config.min_month {
+            if min_month > starting_month {
+                return Err(InquireError::InvalidConfiguration(
+                    "Min date can not be greater than starting date".into(),
+                ));
+            }
+        }
+        if let Some(max_month) = config.max_month {
+            if max_month < starting_month {
+                return Err(InquireError::InvalidConfiguration(
+                    "Max date can not be smaller than starting date".into(),
+                ));
+            }
+        }
+
+        Ok(Self {
+            message: ms.message,
+            current_month: starting_month,
+            config,
+            help_message: ms.help_message,
+            formatter: ms.formatter,
+            validators: ms.validators,
+            error: None,
+        })
+    }
+
+    fn update_month(&mut self, new_month: NaiveDate) -> ActionResult {
+        if self.current_month == new_month {
+            return ActionResult::Clean;
+        }
+
+        self.current_month = new_month;
+        if let Some(min_month) = self.config.min_month {
+            self.current_month = max(self.current_month, min_month);
+        }
+        if let Some(max_month) = self.config.max_month {
+            self.current_month = min(self.current_month, max_month);
+        }
+
+        ActionResult::NeedsRedraw
+    }
+
+    fn validate_current_answer(&self) -> InquireResult<Validation> {
+        for validator in &self.validators {
+            match validator.validate(self.cur_answer()) {
+                Ok(Validation::Valid) => {}
+                Ok(Validation::Invalid(msg)) => return Ok(Validation::Invalid(msg)),
+                Err(err) => return Err(InquireError::Custom(err)),
+            }
+        }
+
+        Ok(Validation::Valid)
+    }
+
+    fn cur_answer(&self) -> NaiveDate {
+        self.current_month
+    }
+}
+
+impl<'a, B> Prompt<B> for MonthSelectPrompt<'a>
+where
+    B: MonthSelectBackend,
+{
+    type Config = MonthSelectConfig;
+    type InnerAction = MonthSelectPromptAction;
+    type Output = NaiveDate;
+
+    fn message(&self) -> &str {
+        self.message
+    }
+
+    fn format_answer(&self, answer: &NaiveDate) -> String {
+        (self.formatter)(*answer)
+    }
+
+    fn config(&self) -> &MonthSelectConfig {
+        &self.config
+    }
+
+    fn submit(&mut self) -> InquireResult<Option<NaiveDate>> {
+        let answer = match self.validate_current_answer()? {
+            Validation::Valid => Some(self.cur_answer()),
+            Validation::Invalid(msg) => {
+                self.error = Some(msg);
+                None
+            }
+        };
+
+        Ok(answer)
+    }
+
+    fn handle(&mut self, action: MonthSelectPromptAction) -> InquireResult<ActionResult> {
+        let qty = match action {
+            MonthSelectPromptAction::GoToPrevMonth => -1,
+            MonthSelectPromptAction::GoToNextMonth => 1,
+            MonthSelectPromptAction::GoToPrevQuarter => -3,
+            MonthSelectPromptAction::GoToNextQuarter => 3,
+            MonthSelectPromptAction::GoToPrevYear => -12,
+            MonthSelectPromptAction::GoToNextYear => 12,
+        };
+
+        Ok(self.update_month(shift_months(self.current_month, qty)))
+    }
+
+    fn render(&self, backend: &mut B) -> InquireResult<()> {
+        let prompt = &self.message;
+
+        if let Some(err) = &self.error {
+            backend.render_error_message(err)?;
+        }
+
+        backend.render_month_prompt(prompt)?;
+
+        backend.render_months(
+            self.current_month,
+            first_day_of_month(get_current_date()),
+            self.config.min_month,
+            self.config.max_month,
+        )?;
+
+        if let Some(help_message) = self.help_message {
+            backend.render_help_message(help_message)?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Shifts the given first day of a month by the given quantity of months,
+/// saturating at the min and max representable months.
+fn shift_months(month: NaiveDate, qty: i32) -> NaiveDate {
+    match qty.cmp(&0) {
+        Ordering::Greater | Ordering::Equal => month
+            .checked_add_months(Months::new(qty as u32))
+            .unwrap_or_else(|| first_day_of_month(NaiveDate::MAX)),
+        Ordering::Less => month
+            .checked_sub_months(Months::new((-qty) as u32))
+            .unwrap_or_else(|| first_day_of_month(NaiveDate::MIN)),
+    }
+}
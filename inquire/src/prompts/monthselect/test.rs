@@ -0,0 +1,166 @@
+use crate::{
+    error::InquireResult,
+    ui::{
+        test::{FakeBackend, Token},
+        Key, KeyModifiers,
+    },
+    validator::{ErrorMessage, Validation},
+    InquireError, MonthSelect,
+};
+use chrono::{Datelike, NaiveDate};
+
+fn date(year: i32, month: u32, day: u32) -> NaiveDate {
+    NaiveDate::from_ymd_opt(year, month, day).unwrap()
+}
+
+fn default<'a>() -> MonthSelect<'a> {
+    MonthSelect::new("Question?").with_default(date(2023, 11, 17))
+}
+
+macro_rules! month_test {
+    ($name:ident,$input:expr,$output:expr) => {
+        month_test! {$name, $input, $output, default()}
+    };
+
+    ($name:ident,$input:expr,$output:expr,$prompt:expr) => {
+        #[test]
+        fn $name() -> InquireResult<()> {
+            let mut backend = FakeBackend::new($input);
+
+            let ans = $prompt.prompt_with_backend(&mut backend)?;
+
+            assert_eq!($output, ans);
+
+            Ok(())
+        }
+    };
+}
+
+month_test!(
+    default_is_pinned_to_first_day,
+    vec![Key::Enter],
+    date(2023, 11, 1)
+);
+
+month_test!(
+    current_month_by_default,
+    vec![Key::Enter],
+    crate::date_utils::get_current_date().with_day(1).unwrap(),
+    MonthSelect::new("Question?")
+);
+
+month_test!(
+    arrows_cross_year_boundaries,
+    vec![
+        Key::Right(KeyModifiers::NONE),
+        Key::Right(KeyModifiers::NONE),
+        Key::Enter
+    ],
+    date(2024, 1, 1)
+);
+
+month_test!(
+    up_and_down_move_by_quarters,
+    vec![
+        Key::Down(KeyModifiers::NONE),
+        Key::Down(KeyModifiers::NONE),
+        Key::Up(KeyModifiers::NONE),
+        Key::Enter
+    ],
+    date(2024, 2, 1)
+);
+
+month_test!(
+    page_keys_move_by_years,
+    vec![
+        Key::PageUp(KeyModifiers::NONE),
+        Key::PageUp(KeyModifiers::NONE),
+        Key::Char(']', KeyModifiers::NONE),
+        Key::Enter
+    ],
+    date(2022, 11, 1)
+);
+
+month_test!(
+    movement_is_clamped_to_min_and_max_months,
+    vec![Key::PageUp(KeyModifiers::NONE), Key::Enter,],
+    date(2023, 3, 1),
+    default().with_min_date(date(2023, 3, 31))
+);
+
+month_test!(
+    max_month_includes_whole_month,
+    vec![
+        Key::Right(KeyModifiers::NONE),
+        Key::Right(KeyModifiers::NONE),
+        Key::Enter,
+    ],
+    date(2023, 12, 1),
+    default().with_max_date(date(2023, 12, 1))
+);
+
+#[test]
+fn renders_the_selected_month() -> InquireResult<()> {
+    let mut backend = FakeBackend::new(vec![Key::Left(KeyModifiers::NONE), Key::Enter]);
+
+    let ans = default().prompt_with_backend(&mut backend)?;
+
+    assert_eq!((2023, 10), (ans.year(), ans.month()));
+
+    let frames = backend.frames();
+    assert!(frames[1].has_token(&Token::Months {
+        selected_month: date(2023, 10, 1),
+        current_month: crate::date_utils::get_current_date().with_day(1).unwrap(),
+        min_month: None,
+        max_month: None,
+    }));
+
+    Ok(())
+}
+
+#[test]
+fn validators_receive_first_day_of_month() -> InquireResult<()> {
+    let mut backend = FakeBackend::new(vec![Key::Enter, Key::Left(KeyModifiers::NONE), Key::Enter]);
+
+    let validator = |d: NaiveDate| match d.day() == 1 && d.month() == 11 {
+        true => Ok(Validation::Invalid("November is closed".into())),
+        false => Ok(Validation::Valid),
+    };
+
+    let ans = default()
+        .with_validator(validator)
+        .prompt_with_backend(&mut backend)?;
+
+    assert_eq!(date(2023, 10, 1), ans);
+    assert!(
+        backend.frames()[1].has_token(&Token::ErrorMessage(ErrorMessage::Custom(
+            "November is closed".into()
+        )))
+    );
+
+    Ok(())
+}
+
+#[test]
+fn starting_month_before_min_month_is_invalid() {
+    let mut backend = FakeBackend::new(vec![Key::Enter]);
+
+    let ans = default()
+        .with_min_date(date(2023, 12, 1))
+        .prompt_with_backend(&mut backend);
+
+    assert!(matches!(ans, Err(InquireError::InvalidConfiguration(_))));
+}
+
+#[test]
+fn starting_month_equal_to_min_month_is_valid() -> InquireResult<()> {
+    let mut backend = FakeBackend::new(vec![Key::Enter]);
+
+    let ans = default()
+        .with_min_date(date(2023, 11, 30))
+        .prompt_with_backend(&mut backend)?;
+
+    assert_eq!(date(2023, 11, 1), ans);
+
+    Ok(())
+}
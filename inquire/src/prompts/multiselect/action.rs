@@ -1,6 +1,6 @@
 use crate::{
-    ui::{Key, KeyModifiers},
-    InnerAction, InputAction,
+    ui::{Key, KeyModifiers, MouseEvent, MouseEventKind},
+    InnerAction, InputAction, ListKeymap,
 };
 
 use super::config::MultiSelectConfig;
@@ -24,16 +24,66 @@ pub enum MultiSelectPromptAction {
     MoveToEnd,
     /// Toggles the selection of the current option.
     ToggleCurrentOption,
-    /// Selects all options.
+    /// Moves the cursor to the option at the given position of the current
+    /// page, when it is clicked.
+    JumpToOption(usize),
+    /// Moves the cursor to the option at the given position of the current
+    /// page and toggles its selection, when its checkbox is clicked.
+    ToggleOption(usize),
+    /// Moves the cursor to the option above, selecting every option between
+    /// it and the position where the range selection started.
+    SelectRangeUp,
+    /// Moves the cursor to the option below, selecting every option between
+    /// it and the position where the range selection started.
+    SelectRangeDown,
+    /// Selects all options matching the current filter, keeping the other
+    /// selected ones.
     SelectAll,
+    /// Selects all options, whether they match the current filter or not.
+    SelectEverything,
     /// Deselects all options.
     ClearSelections,
+    /// Inverts the selection of the options matching the current filter.
+    InvertSelection,
+    /// Toggles the selection of all options of the group of the current option.
+    ToggleGroup,
+    /// Focuses the filter input, when using the vim keymap.
+    FocusFilter,
+    /// Leaves the filter input, when using the vim keymap.
+    UnfocusFilter,
+    /// Clears the filter input without cancelling the prompt.
+    ClearFilter,
 }
 
 impl InnerAction for MultiSelectPromptAction {
     type Config = MultiSelectConfig;
 
     fn from_key(key: Key, config: &MultiSelectConfig) -> Option<Self> {
+        if config.keymap == ListKeymap::Vim {
+            let action = match key {
+                Key::Char('k', KeyModifiers::NONE) => Some(Self::MoveUp),
+                Key::Char('j', KeyModifiers::NONE) => Some(Self::MoveDown),
+                Key::Char('g', KeyModifiers::NONE) => Some(Self::MoveToStart),
+                Key::Char('G', _) => Some(Self::MoveToEnd),
+                Key::Char('u', KeyModifiers::CONTROL) => Some(Self::PageUp),
+                Key::Char('d', KeyModifiers::CONTROL) => Some(Self::PageDown),
+                Key::Char('/', KeyModifiers::NONE) => Some(Self::FocusFilter),
+                _ => None,
+            };
+
+            if action.is_some() {
+                return action;
+            }
+        }
+
+        if config.invert_selection && key == Key::Char('r', KeyModifiers::CONTROL) {
+            return Some(Self::InvertSelection);
+        }
+
+        if config.group_toggling && key == Key::Tab {
+            return Some(Self::ToggleGroup);
+        }
+
         if config.vim_mode {
             let action = match key {
                 Key::Char('h', KeyModifiers::NONE) => Some(Self::ClearSelections),
@@ -49,20 +99,49 @@ impl InnerAction for MultiSelectPromptAction {
         }
 
         let action = match key {
-            Key::Up(KeyModifiers::NONE) | Key::Char('p', KeyModifiers::CONTROL) => Self::MoveUp,
+            Key::Up(KeyModifiers::NONE)
+            | Key::Char('p', KeyModifiers::CONTROL)
+            | Key::Mouse(MouseEvent {
+                kind: MouseEventKind::ScrollUp,
+                ..
+            }) => Self::MoveUp,
             Key::PageUp(_) => Self::PageUp,
             Key::Home => Self::MoveToStart,
 
-            Key::Down(KeyModifiers::NONE) | Key::Char('n', KeyModifiers::CONTROL) => Self::MoveDown,
+            Key::Down(KeyModifiers::NONE)
+            | Key::Char('n', KeyModifiers::CONTROL)
+            | Key::Mouse(MouseEvent {
+                kind: MouseEventKind::ScrollDown,
+                ..
+            }) => Self::MoveDown,
             Key::PageDown(_) => Self::PageDown,
             Key::End => Self::MoveToEnd,
 
+            Key::Up(KeyModifiers::SHIFT) => Self::SelectRangeUp,
+            Key::Down(KeyModifiers::SHIFT) => Self::SelectRangeDown,
+
+            Key::Mouse(MouseEvent {
+                kind: MouseEventKind::Click | MouseEventKind::DoubleClick,
+                option: Some(position),
+                on_checkbox: true,
+                ..
+            }) => Self::ToggleOption(position),
+            Key::Mouse(MouseEvent {
+                kind: MouseEventKind::Click,
+                option: Some(position),
+                ..
+            }) => Self::JumpToOption(position),
+
+            Key::Char('u', KeyModifiers::CONTROL) => Self::ClearFilter,
+
             Key::Char(' ', KeyModifiers::NONE) => Self::ToggleCurrentOption,
             Key::Right(KeyModifiers::NONE) => Self::SelectAll,
+            Key::Right(KeyModifiers::SHIFT) => Self::SelectEverything,
             Key::Left(KeyModifiers::NONE) => Self::ClearSelections,
+            // with the vim keymap, the filter input is only edited while focused
             key => match InputAction::from_key(key, &()) {
-                Some(action) => Self::FilterInput(action),
-                None => return None,
+                Some(action) if config.keymap == ListKeymap::Default => Self::FilterInput(action),
+                _ => return None,
             },
         };
 
@@ -1,4 +1,4 @@
-use crate::MultiSelect;
+use crate::{ListKeymap, MultiSelect};
 
 /// Configuration settings used in the execution of a MultiSelectPrompt.
 #[derive(Copy, Clone, Debug)]
@@ -7,10 +7,30 @@ pub struct MultiSelectConfig {
     pub vim_mode: bool,
     /// Page size of the list of options.
     pub page_size: usize,
+    /// Whether to fit the page size to the height of the terminal.
+    pub auto_page_size: bool,
     /// Whether to keep the filter text when an option is selected.
     pub keep_filter: bool,
+    /// Whether to return the selected options in the order they were picked.
+    pub keep_pick_order: bool,
+    /// Whether `Ctrl+r` inverts the selection of the filtered options.
+    pub invert_selection: bool,
+    /// Whether tab toggles all options of the group of the highlighted option.
+    pub group_toggling: bool,
     /// Whether to reset the cursor to the first option on filter input change.
     pub reset_cursor: bool,
+    /// Whether the cursor skips disabled options.
+    pub skip_disabled: bool,
+    /// Whether the characters matched by the filter input are highlighted.
+    pub highlight_matches: bool,
+    /// Key bindings used to navigate the options.
+    pub keymap: ListKeymap,
+    /// Whether moving past the last option wraps around to the first one.
+    pub wrap_around: bool,
+    /// Minimum number of selected options to submit the answer.
+    pub min_selections: Option<usize>,
+    /// Maximum number of selected options.
+    pub max_selections: Option<usize>,
 }
 
 impl<T> From<&MultiSelect<'_, T>> for MultiSelectConfig {
@@ -18,8 +38,18 @@ impl<T> From<&MultiSelect<'_, T>> for MultiSelectConfig {
         Self {
             vim_mode: value.vim_mode,
             page_size: value.page_size,
+            auto_page_size: value.auto_page_size,
             keep_filter: value.keep_filter,
+            keep_pick_order: value.keep_pick_order,
+            invert_selection: value.invert_selection,
+            group_toggling: value.group_toggling,
             reset_cursor: value.reset_cursor,
+            skip_disabled: value.skip_disabled,
+            highlight_matches: value.highlight_matches,
+            keymap: value.keymap,
+            wrap_around: value.wrap_around,
+            min_selections: value.min_selections,
+            max_selections: value.max_selections,
         }
     }
 }
@@ -0,0 +1,56 @@
+use std::fmt::Display;
+
+/// When a [MultiSelect](crate::MultiSelect) prompt offers to create a new entry
+/// from the current filter input, see
+/// [`MultiSelect::prompt_with_create`](crate::MultiSelect::prompt_with_create).
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum CreateEntryMode {
+    /// The entry can only be created when no option matches the filter input.
+    NoMatches,
+    /// The entry can be created whenever the filter input is not empty and
+    /// not equal to one of the options.
+    Always,
+}
+
+/// Answer of a [MultiSelect](crate::MultiSelect) prompt that allows new entries to be
+/// created, returned by [`MultiSelect::prompt_with_create`](crate::MultiSelect::prompt_with_create).
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct MultiSelectAnswer<T> {
+    /// Pre-existing options selected by the user, in the order they were provided.
+    pub selected: Vec<T>,
+    /// Entries created and kept selected by the user, in creation order.
+    pub created: Vec<String>,
+}
+
+impl<T> MultiSelectAnswer<T> {
+    pub(super) fn map<U, F>(self, f: F) -> MultiSelectAnswer<U>
+    where
+        F: FnMut(T) -> U,
+    {
+        MultiSelectAnswer {
+            selected: self.selected.into_iter().map(f).collect(),
+            created: self.created,
+        }
+    }
+}
+
+/// Entry of the rendered option list. Created entries are listed after the
+/// provided options, followed by the row that creates a new entry.
+pub(super) enum Entry<'a, T> {
+    Option(&'a T),
+    Created(&'a str),
+    Create(&'a str),
+}
+
+impl<'a, T> Display for Entry<'a, T>
+where
+    T: Display,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Option(value) => value.fmt(f),
+            Self::Created(value) => value.fmt(f),
+            Self::Create(value) => write!(f, "create: {value}"),
+        }
+    }
+}
@@ -1,24 +1,30 @@
 mod action;
 mod config;
+mod create;
 mod prompt;
 #[cfg(test)]
 #[cfg(feature = "crossterm")]
 mod test;
 
 pub use action::*;
+pub use create::{CreateEntryMode, MultiSelectAnswer};
 
-use std::fmt::Display;
+use std::{
+    collections::{BTreeMap, BTreeSet},
+    fmt::Display,
+};
 
 use crate::{
-    config::get_configuration,
+    config::{get_configuration, get_wrap_around},
     error::{InquireError, InquireResult},
     formatter::MultiOptionFormatter,
     list_option::ListOption,
-    prompts::prompt::Prompt,
+    prompts::{prompt::Prompt, select::OptionLoader},
     terminal::get_default_terminal,
     type_aliases::Scorer,
-    ui::{Backend, MultiSelectBackend, RenderConfig},
+    ui::{Backend, MultiSelectBackend, RenderConfig, Styled},
     validator::MultiOptionValidator,
+    ListKeymap,
 };
 
 use self::prompt::MultiSelectPrompt;
@@ -31,10 +37,16 @@ use once_cell::sync::Lazy;
 static DEFAULT_MATCHER: Lazy<SkimMatcherV2> = Lazy::new(|| SkimMatcherV2::default().ignore_case());
 /// Prompt suitable for when you need the user to select many options (including none if applicable) among a list of them.
 ///
-/// The user can select (or deselect) the current highlighted option by pressing space, clean all selections by pressing the left arrow and select all options by pressing the right arrow.
+/// The user can select (or deselect) the current highlighted option by pressing space, clean all selections by pressing the left arrow and select all options matching the current filter by pressing the right arrow. Pressing shift and the right arrow selects all options, whether they match the filter or not.
+///
+/// With the `mouse` feature, the scroll wheel moves the cursor, clicking an option highlights it, clicking its checkbox toggles it and double-clicking it submits the prompt.
+///
+/// Holding shift while moving with the up and down arrows selects a range of options, from the one highlighted when the range selection started to the one under the cursor.
+///
+/// While the options are filtered, pressing `Ctrl+u` or the first press of escape clears the filter instead of cancelling the prompt, and the help message hints at it.
 ///
 /// This prompt requires a prompt message and a **non-empty** `Vec` of options to be displayed to the user. The options can be of any type as long as they implement the `Display` trait. It is required that the `Vec` is moved to the prompt, as the prompt will return the ownership of the `Vec` after the user submits, with only the selected options inside it.
-/// - If the list is empty, the prompt operation will fail with an `InquireError::InvalidConfiguration` error.
+/// - If the list is empty, the prompt operation will fail with an `InquireError::InvalidConfiguration` error, unless a loader is set.
 ///
 /// The options are paginated in order to provide a smooth experience to the user, with the default page size being 7. The user can move from the options and the pages will be updated accordingly, including moving from the last to the first options (or vice-versa).
 ///
@@ -50,10 +62,30 @@ static DEFAULT_MATCHER: Lazy<SkimMatcherV2> = Lazy::new(|| SkimMatcherV2::defaul
 ///   - Prints the selected options string value, joined using a comma as the separator, by default.
 /// - **Validator**: Custom validator to make sure a given submitted input pass the specified requirements, e.g. not allowing 0 selected options or limiting the number of options that the user is allowed to select.
 ///   - No validators are on by default.
+/// - **Selection limits**: Minimum and maximum number of options the user has to select. Options can not be toggled past the maximum and the answer can not be submitted outside of the limits, displaying a hint as an error message in both cases. The limits are checked before the validator. If the minimum is greater than the maximum, the prompt will fail with an [`InquireError::InvalidConfiguration`] error.
 /// - **Page size**: Number of options displayed at once, 7 by default.
+/// - **Auto page size flag**: Whether the page size is fitted to the height of the terminal instead, following its resizes. Defaults to false.
 /// - **Display option indexes**: On long lists, it might be helpful to display the indexes of the options to the user. Via the `RenderConfig`, you can set the display mode of the indexes as a prefix of an option. The default configuration is `None`, to not render any index when displaying the options.
-/// - **Scorer function**: Function that defines the order of options and if displayed as all.
+/// - **Scorer function**: Function that defines the order of options and if displayed as all. Options are ranked by descending score, and options with the same score keep their original order.
+///   - Fuzzy matches the value and description of the options by default, when the `fuzzy` feature is enabled, and filters them by substring otherwise.
+/// - **Highlight matches flag**: Whether the characters of the options matched by the filter input are highlighted with the `option_match` style sheet. Defaults to true.
+/// - **Wrap around flag**: Whether moving past the last option wraps around to the first one and vice versa, instead of stopping at the ends of the list. Defaults to true, unless changed globally with [`set_global_wrap_around`](crate::set_global_wrap_around).
+/// - **Keymap**: Key bindings used to navigate the options, such as the modal vim-like ones. See [`ListKeymap`].
+/// - **Invert selection flag**: Whether `Ctrl+r` inverts the selection of the options matching the current filter, checking the unchecked ones and vice versa. Defaults to false.
+/// - **Keep pick order flag**: Whether the selected options are returned in the order they were picked by the user, instead of the order of the option list. Defaults to false.
 /// - **Keep filter flag**: Whether the current filter input should be cleared or not after a selection is made. Defaults to true.
+/// - **Option groups**: Groups of options listed under non-selectable headers, such as "Recent" or "All regions", which are skipped by the cursor. Options not added to any group are listed first, followed by the groups in the order they were added. While filtering, options are kept under their headers, and groups without any matching option are hidden. If any of the indexes is out-of-range of the option list, or added to more than one group, the prompt will fail with an [`InquireError::InvalidConfiguration`] error.
+/// - **Group toggling flag**: Whether pressing tab toggles all enabled options of the group of the highlighted option, whose header is then prefixed by a checkbox reflecting whether none, some or all of them are selected. The checkbox of partially selected groups is rendered with the `partial_checkbox` component. Defaults to false.
+/// - **Option values**: Options can carry a value along with the label displayed to the user by wrapping them in an [`OptionItem`](crate::list_option::OptionItem), so that the answer does not have to be mapped back to the value by its index.
+/// - **Option keywords**: Hidden search terms of the options, such as aliases, IDs or tags, which are matched by the filter input but not displayed. If any of the indexes is out-of-range of the option list, the prompt will fail with an [`InquireError::InvalidConfiguration`] error.
+/// - **Separators**: Lines rendered between the options, with the `option_separator` component, for a lightweight grouping without headers. They are skipped by the cursor and hidden while filtering. If any of the indexes is out-of-range of the option list, the prompt will fail with an [`InquireError::InvalidConfiguration`] error.
+/// - **Option icons**: Styled prefixes rendered before the values of the options, such as emojis or colored bullets, which are not searched when filtering. If any of the indexes is out-of-range of the option list, the prompt will fail with an [`InquireError::InvalidConfiguration`] error.
+/// - **Option descriptions**: Descriptions displayed in the line below the options, with the `option_description` style sheet, and searched along with them when filtering. If any of the indexes is out-of-range of the option list, the prompt will fail with an [`InquireError::InvalidConfiguration`] error.
+/// - **Locked options**: Indexes of options that are selected and can not be unselected, such as the required components of an installer. Their checkbox is rendered with the `locked_checkbox` component, and they are kept when clearing or inverting the selection. If any of the indexes is out-of-range of the option list, the prompt will fail with an [`InquireError::InvalidConfiguration`] error.
+/// - **Disabled options**: Indexes of options that are displayed but can not be selected, optionally along with the reason why. They are rendered with the `disabled_option` style sheet, skipped by the cursor and left out when selecting all options. If [`with_skip_disabled`](MultiSelect::with_skip_disabled) is set to false, the cursor stops at them and toggling one displays its reason as an error. If any of the indexes is out-of-range of the option list, the prompt will fail with an [`InquireError::InvalidConfiguration`] error.
+/// - **Loader**: Source of options appended to the list while the prompt is open, such as results fetched from a remote API. A loading indicator is displayed below the options until the loader is finished. See [`OptionLoader`] for more details.
+///
+/// New entries can be created on the fly by prompting with [`prompt_with_create`](MultiSelect::prompt_with_create), which offers a `create: <filter>` row to add the current filter input to the selected answers.
 ///
 /// # Example
 ///
@@ -77,6 +109,10 @@ pub struct MultiSelect<'a, T> {
     /// Page size of the options displayed to the user.
     pub page_size: usize,
 
+    /// Whether the page size is fitted to the height of the terminal,
+    /// overriding `page_size`.
+    pub auto_page_size: bool,
+
     /// Whether vim mode is enabled. When enabled, the user can
     /// navigate through the options using hjkl.
     pub vim_mode: bool,
@@ -96,14 +132,77 @@ pub struct MultiSelect<'a, T> {
     /// Defaults to true.
     pub filter_input_enabled: bool,
 
-    /// Function called with the current user input to score the provided
+    /// Groups of options listed under headers, with the indexes of the
+    /// options in each group.
+    pub groups: Vec<(&'a str, Vec<usize>)>,
+
+    /// Descriptions displayed in the line below the options, mapped by the
+    /// indexes of the options.
+    pub descriptions: BTreeMap<usize, &'a str>,
+
+    /// Icons rendered before the values of the options, mapped by the
+    /// indexes of the options.
+    pub icons: BTreeMap<usize, Styled<&'a str>>,
+
+    /// Hidden search keywords of the options, mapped by the indexes of the
     /// options.
+    pub keywords: BTreeMap<usize, Vec<&'a str>>,
+
+    /// Indexes of the options preceded by a separator line.
+    pub separators: BTreeSet<usize>,
+
+    /// Indexes of the options that are selected and can not be unselected.
+    pub locked_options: BTreeSet<usize>,
+
+    /// Indexes of the options that can not be selected, mapped to the
+    /// reason displayed next to them, if any.
+    pub disabled_options: BTreeMap<usize, Option<&'a str>>,
+
+    /// Whether the cursor skips disabled options. When false, the cursor
+    /// can highlight them, but toggling one displays an error instead.
+    ///
+    /// Defaults to true.
+    pub skip_disabled: bool,
+
+    /// Whether the characters of the options matched by the filter input
+    /// are highlighted with the `option_match` style sheet.
+    ///
+    /// Defaults to true.
+    pub highlight_matches: bool,
+
+    /// Source of options appended to the list while the prompt is open.
+    pub loader: Option<OptionLoader<T>>,
+
+    /// Message displayed below the options while the loader is not finished.
+    pub loading_message: &'a str,
+
+    /// Function called with the current user input to score the provided
+    /// options. Options are ranked by their scores, ties keeping their
+    /// original order.
     /// The list of options is sorted in descending order (highest score first)
     pub scorer: Scorer<'a, T>,
 
     /// Whether the current filter typed by the user is kept or cleaned after a selection is made.
     pub keep_filter: bool,
 
+    /// Whether the selected options are returned in the order they were
+    /// picked, instead of the order of the option list.
+    pub keep_pick_order: bool,
+
+    /// Whether `Ctrl+r` inverts the selection of the options matching the
+    /// current filter.
+    pub invert_selection: bool,
+
+    /// Whether tab toggles all options of the group of the highlighted option.
+    pub group_toggling: bool,
+
+    /// Key bindings used to navigate the options.
+    pub keymap: ListKeymap,
+
+    /// Whether moving past the last option wraps around to the first one
+    /// and vice versa.
+    pub wrap_around: bool,
+
     /// Function that formats the user input and presents it to the user as the final rendering of the prompt.
     pub formatter: MultiOptionFormatter<'a, T>,
 
@@ -112,6 +211,12 @@ pub struct MultiSelect<'a, T> {
     /// In case of error, the message is displayed one line above the prompt.
     pub validator: Option<Box<dyn MultiOptionValidator<T>>>,
 
+    /// Minimum number of options the user has to select to submit the answer.
+    pub min_selections: Option<usize>,
+
+    /// Maximum number of options the user is allowed to select.
+    pub max_selections: Option<usize>,
+
     /// RenderConfig to apply to the rendered interface.
     ///
     /// Note: The default render config considers if the NO_COLOR environment variable
@@ -196,6 +301,9 @@ where
     /// Default page size, equal to the global default page size [config::DEFAULT_PAGE_SIZE]
     pub const DEFAULT_PAGE_SIZE: usize = crate::config::DEFAULT_PAGE_SIZE;
 
+    /// Default behavior of fitting the page size to the terminal, disabled.
+    pub const DEFAULT_AUTO_PAGE_SIZE: bool = false;
+
     /// Default value of vim mode, equal to the global default value [config::DEFAULT_PAGE_SIZE]
     pub const DEFAULT_VIM_MODE: bool = crate::config::DEFAULT_VIM_MODE;
 
@@ -213,6 +321,25 @@ where
     /// Default behavior of keeping or cleaning the current filter value.
     pub const DEFAULT_KEEP_FILTER: bool = true;
 
+    /// Default behavior of ordering the answer by the option list.
+    pub const DEFAULT_KEEP_PICK_ORDER: bool = false;
+
+    /// Default behavior of the invert selection key binding, disabled.
+    pub const DEFAULT_INVERT_SELECTION: bool = false;
+
+    /// Default behavior of the group toggling key binding, disabled.
+    pub const DEFAULT_GROUP_TOGGLING: bool = false;
+
+    /// Default behaviour of the cursor on disabled options.
+    /// Defaults to true, skipping them.
+    pub const DEFAULT_SKIP_DISABLED: bool = true;
+
+    /// Default value of whether matched characters are highlighted.
+    pub const DEFAULT_HIGHLIGHT_MATCHES: bool = true;
+
+    /// Default message displayed while options are being loaded.
+    pub const DEFAULT_LOADING_MESSAGE: &'a str = "loading…";
+
     /// Default help message.
     pub const DEFAULT_HELP_MESSAGE: Option<&'a str> =
         Some("↑↓ to move, space to select one, → to all, ← to none, type to filter");
@@ -225,15 +352,34 @@ where
             default: None,
             help_message: Self::DEFAULT_HELP_MESSAGE,
             page_size: Self::DEFAULT_PAGE_SIZE,
+            auto_page_size: Self::DEFAULT_AUTO_PAGE_SIZE,
             vim_mode: Self::DEFAULT_VIM_MODE,
             starting_cursor: Self::DEFAULT_STARTING_CURSOR,
             starting_filter_input: None,
             reset_cursor: Self::DEFAULT_RESET_CURSOR,
             filter_input_enabled: Self::DEFAULT_FILTER_INPUT_ENABLED,
             keep_filter: Self::DEFAULT_KEEP_FILTER,
+            keep_pick_order: Self::DEFAULT_KEEP_PICK_ORDER,
+            invert_selection: Self::DEFAULT_INVERT_SELECTION,
+            group_toggling: Self::DEFAULT_GROUP_TOGGLING,
+            keymap: ListKeymap::default(),
+            wrap_around: get_wrap_around(),
+            groups: vec![],
+            descriptions: BTreeMap::new(),
+            icons: BTreeMap::new(),
+            keywords: BTreeMap::new(),
+            separators: BTreeSet::new(),
+            locked_options: BTreeSet::new(),
+            disabled_options: BTreeMap::new(),
+            skip_disabled: Self::DEFAULT_SKIP_DISABLED,
+            highlight_matches: Self::DEFAULT_HIGHLIGHT_MATCHES,
+            loader: None,
+            loading_message: Self::DEFAULT_LOADING_MESSAGE,
             scorer: Self::DEFAULT_SCORER,
             formatter: Self::DEFAULT_FORMATTER,
             validator: None,
+            min_selections: None,
+            max_selections: None,
             render_config: get_configuration(),
         }
     }
@@ -256,13 +402,33 @@ where
         self
     }
 
+    /// Sets whether the page size is fitted to the height of the terminal,
+    /// leaving room for the other lines of the prompt, instead of using the
+    /// fixed page size.
+    ///
+    /// The descriptions, separators and group headers listed along with the
+    /// options are counted, while options too long for the width of the
+    /// terminal are counted as a single line even though they wrap.
+    ///
+    /// The page size is computed again whenever the prompt is rendered,
+    /// which the `crossterm` backend does as soon as the terminal is resized,
+    /// and the other backends on the next key press.
+    pub fn with_auto_page_size(mut self, auto_page_size: bool) -> Self {
+        self.auto_page_size = auto_page_size;
+        self
+    }
+
     /// Enables or disables vim_mode.
     pub fn with_vim_mode(mut self, vim_mode: bool) -> Self {
         self.vim_mode = vim_mode;
         self
     }
 
-    /// Sets the keep filter behavior.
+    /// Sets whether the filter input is kept after toggling, selecting or
+    /// clearing options, instead of being cleared.
+    ///
+    /// Keeping it suits toggling many matches of a single query, while
+    /// clearing it suits picking a few unrelated options.
     pub fn with_keep_filter(mut self, keep_filter: bool) -> Self {
         self.keep_filter = keep_filter;
         self
@@ -293,10 +459,26 @@ where
         self
     }
 
+    /// Sets the minimum number of options the user has to select to submit the answer.
+    pub fn with_min_selections(mut self, min: usize) -> Self {
+        self.min_selections = Some(min);
+        self
+    }
+
+    /// Sets the maximum number of options the user is allowed to select.
+    ///
+    /// Once it is reached, toggling another option displays a hint instead of
+    /// selecting it.
+    pub fn with_max_selections(mut self, max: usize) -> Self {
+        self.max_selections = Some(max);
+        self
+    }
+
     /// Sets the indexes to be selected by default.
     ///
     /// The values should be valid indexes for the given option list. Any
-    /// numbers larger than the option list or duplicates will be ignored.
+    /// numbers larger than the option list or duplicates will be ignored,
+    /// as well as the indexes of disabled options.
     pub fn with_default(mut self, default: &'a [usize]) -> Self {
         self.default = Some(default.to_vec());
         self
@@ -345,6 +527,165 @@ where
         self
     }
 
+    /// Adds a group of options, listed in the provided order under the header.
+    ///
+    /// Groups are listed in the order they are added, after the options that
+    /// do not belong to any group.
+    pub fn with_group(mut self, header: &'a str, indexes: &[usize]) -> Self {
+        self.groups.push((header, indexes.to_vec()));
+        self
+    }
+
+    /// Sets the description displayed in the line below the option at the provided index.
+    pub fn with_description(mut self, index: usize, description: &'a str) -> Self {
+        self.descriptions.insert(index, description);
+        self
+    }
+
+    /// Sets hidden keywords of the option at the provided index, such as
+    /// aliases, IDs or tags, which are searched when filtering but not displayed.
+    ///
+    /// For example, an option labeled `N. Virginia` with the `us-east-1`
+    /// keyword is matched by typing `us-east`.
+    pub fn with_keywords(mut self, index: usize, keywords: &[&'a str]) -> Self {
+        self.keywords
+            .entry(index)
+            .or_default()
+            .extend_from_slice(keywords);
+        self
+    }
+
+    /// Inserts a separator line before the option at the provided index.
+    ///
+    /// Separators are skipped by the cursor and hidden while filtering.
+    pub fn with_separator(mut self, index: usize) -> Self {
+        self.separators.insert(index);
+        self
+    }
+
+    /// Sets the icon rendered before the value of the option at the provided index.
+    ///
+    /// Icons are not part of the option values, so they are neither searched
+    /// when filtering nor passed to the formatter.
+    pub fn with_icon(mut self, index: usize, icon: Styled<&'a str>) -> Self {
+        self.icons.insert(index, icon);
+        self
+    }
+
+    /// Sets the descriptions of the options, in the same order as the option list.
+    ///
+    /// Empty descriptions are not displayed.
+    pub fn with_descriptions(mut self, descriptions: &[&'a str]) -> Self {
+        for (index, description) in descriptions.iter().enumerate() {
+            if !description.is_empty() {
+                self.descriptions.insert(index, description);
+            }
+        }
+        self
+    }
+
+    /// Locks the options at the provided indexes, which are selected and can
+    /// not be unselected.
+    pub fn with_locked_options(mut self, indexes: &[usize]) -> Self {
+        self.locked_options.extend(indexes);
+        self
+    }
+
+    /// Disables the options at the provided indexes, which are still
+    /// displayed but can not be selected.
+    pub fn with_disabled_options(mut self, indexes: &[usize]) -> Self {
+        for index in indexes {
+            self.disabled_options.insert(*index, None);
+        }
+        self
+    }
+
+    /// Disables the option at the provided index, displaying the reason it
+    /// can not be selected next to it.
+    pub fn with_disabled_option(mut self, index: usize, reason: &'a str) -> Self {
+        self.disabled_options.insert(index, Some(reason));
+        self
+    }
+
+    /// Sets whether the cursor skips disabled options. Defaults to true.
+    ///
+    /// When false, the cursor stops at disabled options, and trying to toggle one
+    /// of them displays the reason it can not be selected as an error message.
+    pub fn with_skip_disabled(mut self, skip_disabled: bool) -> Self {
+        self.skip_disabled = skip_disabled;
+        self
+    }
+
+    /// Sets whether the characters of the options matched by the filter input
+    /// are highlighted. Defaults to true.
+    ///
+    /// Highlights are computed by the built-in fuzzy matcher when the `fuzzy`
+    /// feature is enabled, or as a case-insensitive substring otherwise, so
+    /// they might be worth disabling when using a custom scorer.
+    pub fn with_highlight_matches(mut self, highlight_matches: bool) -> Self {
+        self.highlight_matches = highlight_matches;
+        self
+    }
+
+    /// Sets whether the selected options are returned in the order they were
+    /// picked by the user, instead of the order of the option list.
+    ///
+    /// Options selected by default come first, in the order of the list, and
+    /// options unselected and selected again are ordered by their last pick.
+    /// Created entries are ordered by their own picks as well.
+    pub fn with_keep_pick_order(mut self, keep_pick_order: bool) -> Self {
+        self.keep_pick_order = keep_pick_order;
+        self
+    }
+
+    /// Sets whether `Ctrl+r` inverts the selection of the options matching the
+    /// current filter, checking the unchecked ones and vice versa.
+    ///
+    /// Disabled options are left untouched.
+    pub fn with_invert_selection(mut self, invert_selection: bool) -> Self {
+        self.invert_selection = invert_selection;
+        self
+    }
+
+    /// Sets whether pressing tab toggles all enabled options of the group of
+    /// the highlighted option, selecting them unless all of them already are.
+    ///
+    /// Group headers are then prefixed by a checkbox reflecting whether none,
+    /// some or all of the options of the group are selected.
+    pub fn with_group_toggling(mut self, group_toggling: bool) -> Self {
+        self.group_toggling = group_toggling;
+        self
+    }
+
+    /// Sets whether moving past the last option wraps around to the first one
+    /// and vice versa, instead of stopping at the ends of the list.
+    pub fn with_wrap_around(mut self, wrap_around: bool) -> Self {
+        self.wrap_around = wrap_around;
+        self
+    }
+
+    /// Sets the key bindings used to navigate the options.
+    pub fn with_keymap(mut self, keymap: ListKeymap) -> Self {
+        self.keymap = keymap;
+        self
+    }
+
+    /// Sets the source of options appended to the list while the prompt is open.
+    ///
+    /// The options are polled while waiting for key presses with a timeout,
+    /// which the `console` backend does not support, failing the prompt with
+    /// an `InquireError::InvalidConfiguration` error.
+    pub fn with_loader(mut self, loader: OptionLoader<T>) -> Self {
+        self.loader = Some(loader);
+        self
+    }
+
+    /// Sets the message displayed below the options while they are being loaded.
+    pub fn with_loading_message(mut self, loading_message: &'a str) -> Self {
+        self.loading_message = loading_message;
+        self
+    }
+
     /// Sets the provided color theme to this prompt.
     ///
     /// Note: The default render config considers if the NO_COLOR environment variable
@@ -417,10 +758,54 @@ where
         self.prompt_with_backend(&mut backend)
     }
 
+    /// Parses the provided behavioral and rendering options and prompts
+    /// the CLI user for input according to the defined rules, offering a
+    /// `create: <filter>` row at the end of the list according to the provided
+    /// mode.
+    ///
+    /// Pressing space or enter on this row adds the current filter input to the
+    /// list as a new selected entry, which can be unselected like any other option.
+    /// The validator, if any, is only applied to the pre-existing options.
+    ///
+    /// Returns the pre-existing options and the created entries selected by the user.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use inquire::{CreateEntryMode, MultiSelect};
+    ///
+    /// let labels = vec!["bug", "docs", "feature"];
+    ///
+    /// let ans = MultiSelect::new("Labels:", labels).prompt_with_create(CreateEntryMode::NoMatches);
+    ///
+    /// if let Ok(ans) = ans {
+    ///     println!("Existing labels: {:?}", ans.selected);
+    ///     println!("New labels: {:?}", ans.created);
+    /// }
+    /// ```
+    pub fn prompt_with_create(self, mode: CreateEntryMode) -> InquireResult<MultiSelectAnswer<T>> {
+        let (input_reader, terminal) = get_default_terminal()?;
+        let mut backend = Backend::new(input_reader, terminal, self.render_config)?;
+        self.prompt_with_create_with_backend(mode, &mut backend)
+    }
+
     pub(crate) fn prompt_with_backend<B: MultiSelectBackend>(
         self,
         backend: &mut B,
     ) -> InquireResult<Vec<ListOption<T>>> {
-        MultiSelectPrompt::new(self)?.prompt(backend)
+        MultiSelectPrompt::new(self)?
+            .prompt(backend)
+            .map(|answer| answer.selected)
+    }
+
+    pub(crate) fn prompt_with_create_with_backend<B: MultiSelectBackend>(
+        self,
+        mode: CreateEntryMode,
+        backend: &mut B,
+    ) -> InquireResult<MultiSelectAnswer<T>> {
+        MultiSelectPrompt::new(self)?
+            .with_create_mode(mode)
+            .prompt(backend)
+            .map(|answer| answer.map(|op| op.value))
     }
 }
@@ -1,34 +1,80 @@
-use std::{cmp::Reverse, collections::BTreeSet, fmt::Display};
+use std::{
+    cell::Cell,
+    cmp::Reverse,
+    collections::{BTreeMap, BTreeSet},
+    fmt::Display,
+    ops::Range,
+    time::Duration,
+};
 
 use crate::{
     error::InquireResult,
     formatter::MultiOptionFormatter,
     input::{Input, InputActionResult},
     list_option::ListOption,
-    prompts::prompt::{ActionResult, Prompt},
+    prompts::{
+        action::Action,
+        fuzzyfinder::matched_chars,
+        prompt::{ActionResult, Prompt},
+        select::{OptionGroups, OptionLoader, LOADER_POLL_INTERVAL},
+    },
     type_aliases::Scorer,
-    ui::MultiSelectBackend,
-    utils::paginate,
+    ui::{Key, KeyModifiers, MouseEvent, MouseEventKind, MultiSelectBackend, OptionDetails},
+    utils::{closest_enabled, paginate},
     validator::{ErrorMessage, MultiOptionValidator, Validation},
-    InquireError, MultiSelect,
+    InnerAction, InputAction, InquireError, ListKeymap, MultiSelect,
+};
+
+use super::{
+    action::MultiSelectPromptAction,
+    config::MultiSelectConfig,
+    create::{CreateEntryMode, Entry, MultiSelectAnswer},
 };
 
-use super::{action::MultiSelectPromptAction, config::MultiSelectConfig};
+/// Error message displayed when toggling a disabled option without a reason.
+const DISABLED_OPTION_ERROR: &str = "This option can not be selected";
+
+/// Error message displayed when unselecting a locked option.
+const LOCKED_OPTION_ERROR: &str = "This option can not be unselected";
+
+/// Hint appended to the help message while the options are filtered.
+const CLEAR_FILTER_HELP_MESSAGE: &str = "esc to clear filter";
 
 pub struct MultiSelectPrompt<'a, T> {
     message: &'a str,
     config: MultiSelectConfig,
     options: Vec<T>,
+    separators: BTreeSet<usize>,
     string_options: Vec<String>,
+    search_strings: Vec<String>,
     help_message: Option<&'a str>,
     cursor_index: usize,
     checked: BTreeSet<usize>,
+    // indexes in the order they were checked, repeated when an option is
+    // checked again after being unchecked
+    picks: Vec<usize>,
+    details: OptionDetails<'a>,
     input: Option<Input>,
+    // whether keys are typed into the filter input, when using the vim keymap
+    filter_focused: bool,
+    // position where the range selection started, unset by any other action
+    range_anchor: Option<usize>,
     scored_options: Vec<usize>,
+    groups: OptionGroups<'a>,
+    // unset once every option has been loaded
+    loader: Option<OptionLoader<T>>,
+    loading_message: &'a str,
     scorer: Scorer<'a, T>,
     formatter: MultiOptionFormatter<'a, T>,
     validator: Option<Box<dyn MultiOptionValidator<T>>>,
     error: Option<ErrorMessage>,
+    create_mode: Option<CreateEntryMode>,
+    // entries created by the user, whose indexes in `checked` come right
+    // after the ones of the provided options
+    created: Vec<String>,
+    // when fitted to the terminal height, the page size is only known once
+    // the prompt is rendered
+    page_size: Cell<usize>,
 }
 
 impl<'a, T> MultiSelectPrompt<'a, T>
@@ -36,11 +82,19 @@ where
     T: Display,
 {
     pub fn new(mso: MultiSelect<'a, T>) -> InquireResult<Self> {
-        if mso.options.is_empty() {
+        if mso.options.is_empty() && mso.loader.is_none() {
             return Err(InquireError::InvalidConfiguration(
                 "Available options can not be empty".into(),
             ));
         }
+        if let (Some(min), Some(max)) = (mso.min_selections, mso.max_selections) {
+            if min > max {
+                return Err(InquireError::InvalidConfiguration(format!(
+                    "Minimum of {min} selections is greater than the maximum of {max}"
+                )));
+            }
+        }
+
         if let Some(default) = &mso.default {
             for i in default {
                 if i >= &mso.options.len() {
@@ -53,18 +107,90 @@ where
             }
         }
 
+        if let Some(index) = mso
+            .disabled_options
+            .keys()
+            .find(|i| **i >= mso.options.len())
+        {
+            return Err(InquireError::InvalidConfiguration(format!(
+                "Index {} of disabled option is out-of-bounds for length {} of options",
+                index,
+                &mso.options.len()
+            )));
+        }
+
+        if let Some(index) = mso.locked_options.iter().find(|i| **i >= mso.options.len()) {
+            return Err(InquireError::InvalidConfiguration(format!(
+                "Index {} of locked option is out-of-bounds for length {} of options",
+                index,
+                &mso.options.len()
+            )));
+        }
+
+        if let Some(index) = mso.keywords.keys().find(|i| **i >= mso.options.len()) {
+            return Err(InquireError::InvalidConfiguration(format!(
+                "Index {} of option keywords is out-of-bounds for length {} of options",
+                index,
+                &mso.options.len()
+            )));
+        }
+
+        if let Some(index) = mso.separators.iter().find(|i| **i >= mso.options.len()) {
+            return Err(InquireError::InvalidConfiguration(format!(
+                "Index {} of separator is out-of-bounds for length {} of options",
+                index,
+                &mso.options.len()
+            )));
+        }
+
+        if let Some(index) = mso.icons.keys().find(|i| **i >= mso.options.len()) {
+            return Err(InquireError::InvalidConfiguration(format!(
+                "Index {} of option icon is out-of-bounds for length {} of options",
+                index,
+                &mso.options.len()
+            )));
+        }
+
+        if let Some(index) = mso.descriptions.keys().find(|i| **i >= mso.options.len()) {
+            return Err(InquireError::InvalidConfiguration(format!(
+                "Index {} of option description is out-of-bounds for length {} of options",
+                index,
+                &mso.options.len()
+            )));
+        }
+
         let string_options = mso.options.iter().map(T::to_string).collect();
-        let scored_options = (0..mso.options.len()).collect();
-        let checked_options = mso
+        // descriptions and keywords are searched along with the values of the options
+        let search_strings = mso
+            .options
+            .iter()
+            .enumerate()
+            .map(|(i, option)| {
+                let mut search_string = option.to_string();
+                let description = mso.descriptions.get(&i).into_iter();
+                let keywords = mso.keywords.get(&i).into_iter().flatten();
+
+                for term in description.chain(keywords) {
+                    search_string.push(' ');
+                    search_string.push_str(term);
+                }
+
+                search_string
+            })
+            .collect();
+        let groups = OptionGroups::new(&mso.groups, mso.options.len())?;
+        let scored_options = groups.order.clone();
+        let mut checked_options: BTreeSet<usize> = mso
             .default
             .as_ref()
             .map(|d| {
                 d.iter()
                     .cloned()
-                    .filter(|i| *i < mso.options.len())
+                    .filter(|i| *i < mso.options.len() && !mso.disabled_options.contains_key(i))
                     .collect()
             })
             .unwrap_or_default();
+        checked_options.extend(&mso.locked_options);
 
         let input = match mso.filter_input_enabled {
             true => Some(Input::new_with(
@@ -77,45 +203,154 @@ where
             message: mso.message,
             config: (&mso).into(),
             options: mso.options,
+            separators: mso.separators.clone(),
             string_options,
+            search_strings,
             scored_options,
             help_message: mso.help_message,
             cursor_index: mso.starting_cursor,
             input,
+            filter_focused: false,
+            range_anchor: None,
             scorer: mso.scorer,
             formatter: mso.formatter,
             validator: mso.validator,
             error: None,
+            picks: checked_options.iter().copied().collect(),
             checked: checked_options,
+            details: OptionDetails {
+                locked: mso.locked_options,
+                disabled: mso.disabled_options,
+                descriptions: mso.descriptions,
+                icons: mso.icons,
+                separators: mso.separators,
+                groups: groups.headers.clone(),
+                matched: BTreeMap::new(),
+                group_checkboxes: mso.group_toggling,
+                shortcuts: false,
+            },
+            groups,
+            loader: mso.loader,
+            loading_message: mso.loading_message,
+            create_mode: None,
+            created: vec![],
+            page_size: Cell::new(mso.page_size),
         })
     }
 
+    pub fn with_create_mode(mut self, mode: CreateEntryMode) -> Self {
+        self.create_mode = Some(mode);
+        self
+    }
+
+    /// Entry that can be created from the current filter input, if any.
+    fn create_candidate(&self) -> Option<&str> {
+        let mode = self.create_mode?;
+        let content = self.input.as_ref()?.content().trim();
+
+        let already_exists = self.string_options.iter().any(|o| o == content)
+            || self.created.iter().any(|c| c == content);
+
+        if content.is_empty() || already_exists {
+            return None;
+        }
+
+        match mode {
+            CreateEntryMode::Always => Some(content),
+            CreateEntryMode::NoMatches if self.scored_options.is_empty() => Some(content),
+            CreateEntryMode::NoMatches => None,
+        }
+    }
+
+    /// Number of entries in the option list, including the created ones
+    /// and the row to create a new one.
+    fn entry_count(&self) -> usize {
+        let create_row = usize::from(self.create_candidate().is_some());
+        self.scored_options.len() + self.created.len() + create_row
+    }
+
+    fn is_cursor_on_create_row(&self) -> bool {
+        self.create_candidate().is_some()
+            && self.cursor_index == self.scored_options.len() + self.created.len()
+    }
+
+    fn create_entry(&mut self) -> ActionResult {
+        let entry = match self.create_candidate() {
+            Some(entry) => entry.to_string(),
+            None => return ActionResult::Clean,
+        };
+
+        if let Some(error) = self.max_selections_error(self.checked.len() + 1) {
+            self.error = Some(error);
+            return ActionResult::NeedsRedraw;
+        }
+
+        self.created.push(entry);
+        self.checked
+            .insert(self.options.len() + self.created.len() - 1);
+        self.picks.push(self.options.len() + self.created.len() - 1);
+
+        if let Some(input) = self.input.as_mut() {
+            input.clear();
+        }
+        self.run_scorer();
+        self.clamp_cursor();
+
+        ActionResult::NeedsRedraw
+    }
+
+    fn is_filter_active(&self) -> bool {
+        matches!(&self.input, Some(input) if !input.is_empty())
+    }
+
+    /// Clears the filter input, returning whether there was anything to clear.
+    fn clear_filter(&mut self) -> bool {
+        match self.input.as_mut() {
+            Some(input) if !input.is_empty() => input.clear(),
+            _ => return false,
+        }
+
+        self.run_scorer();
+        self.clamp_cursor();
+        true
+    }
+
+    fn clamp_cursor(&mut self) {
+        if self.entry_count() <= self.cursor_index {
+            let _ = self.update_cursor_position(self.entry_count().saturating_sub(1));
+        }
+    }
+
     fn move_cursor_up(&mut self, qty: usize, wrap: bool) -> ActionResult {
         let new_position = if wrap {
             let after_wrap = qty.saturating_sub(self.cursor_index);
             self.cursor_index
                 .checked_sub(qty)
-                .unwrap_or_else(|| self.scored_options.len().saturating_sub(after_wrap))
+                .unwrap_or_else(|| self.entry_count().saturating_sub(after_wrap))
         } else {
             self.cursor_index.saturating_sub(qty)
         };
 
+        let new_position = self.skip_disabled(new_position, true, wrap);
         self.update_cursor_position(new_position)
     }
 
     fn move_cursor_down(&mut self, qty: usize, wrap: bool) -> ActionResult {
         let mut new_position = self.cursor_index.saturating_add(qty);
 
-        if new_position >= self.scored_options.len() {
-            new_position = if self.scored_options.is_empty() {
+        let entry_count = self.entry_count();
+
+        if new_position >= entry_count {
+            new_position = if entry_count == 0 {
                 0
             } else if wrap {
-                new_position % self.scored_options.len()
+                new_position % entry_count
             } else {
-                self.scored_options.len().saturating_sub(1)
+                entry_count.saturating_sub(1)
             }
         }
 
+        let new_position = self.skip_disabled(new_position, false, wrap);
         self.update_cursor_position(new_position)
     }
 
@@ -128,18 +363,96 @@ where
         }
     }
 
+    /// Error message explaining why the option at the given position of the
+    /// list can not be selected, if it is disabled.
+    fn disabled_error(&self, position: usize) -> Option<ErrorMessage> {
+        let index = self.scored_options.get(position)?;
+        let reason = self
+            .details
+            .disabled
+            .get(index)?
+            .unwrap_or(DISABLED_OPTION_ERROR);
+
+        Some(ErrorMessage::Custom(reason.to_string()))
+    }
+
+    /// Moves the given cursor position out of disabled options, if the cursor
+    /// is set to skip them, keeping it in place when there are none left.
+    fn skip_disabled(&self, position: usize, upwards: bool, wrap: bool) -> usize {
+        if !self.config.skip_disabled {
+            return position;
+        }
+
+        closest_enabled(position, self.entry_count(), upwards, wrap, |p| {
+            self.disabled_error(p).is_none()
+        })
+        .unwrap_or(self.cursor_index)
+    }
+
+    /// Position in the list of the entry at the given position of the current page.
+    fn page_target(&self, position: usize) -> Option<usize> {
+        let entries = (0..self.entry_count()).collect::<Vec<usize>>();
+        let page = paginate(self.page_size.get(), &entries, Some(self.cursor_index));
+
+        page.content.get(position).copied()
+    }
+
+    /// Index of the option or created entry at the given position of the list.
+    fn entry_index(&self, position: usize) -> Option<usize> {
+        let created_idx = position
+            .checked_sub(self.scored_options.len())
+            .filter(|i| *i < self.created.len())
+            .map(|i| self.options.len() + i);
+
+        self.scored_options.get(position).copied().or(created_idx)
+    }
+
+    /// Moves the cursor by one entry, selecting the enabled options between
+    /// the new position and the one where the range selection started.
+    fn select_range(&mut self, upwards: bool) -> ActionResult {
+        let anchor = *self.range_anchor.get_or_insert(self.cursor_index);
+
+        let moved = match upwards {
+            true => self.move_cursor_up(1, false),
+            false => self.move_cursor_down(1, false),
+        };
+
+        let range = anchor.min(self.cursor_index)..=anchor.max(self.cursor_index);
+        let indexes = range.filter_map(|p| self.entry_index(p)).collect();
+
+        moved.merge(self.select_all(indexes))
+    }
+
     fn toggle_cursor_selection(&mut self) -> ActionResult {
-        let idx = match self.scored_options.get(self.cursor_index) {
+        if let Some(error) = self.disabled_error(self.cursor_index) {
+            self.error = Some(error);
+            return ActionResult::NeedsRedraw;
+        }
+
+        if self.is_cursor_on_create_row() {
+            return self.create_entry();
+        }
+
+        let idx = match self.entry_index(self.cursor_index) {
             Some(val) => val,
             None => return ActionResult::Clean,
         };
 
-        if self.checked.contains(idx) {
-            self.checked.remove(idx);
+        if self.details.locked.contains(&idx) {
+            self.error = Some(ErrorMessage::Custom(LOCKED_OPTION_ERROR.into()));
+            return ActionResult::NeedsRedraw;
+        } else if self.checked.contains(&idx) {
+            self.checked.remove(&idx);
+        } else if let Some(error) = self.max_selections_error(self.checked.len() + 1) {
+            self.error = Some(error);
+            return ActionResult::NeedsRedraw;
         } else {
-            self.checked.insert(*idx);
+            self.checked.insert(idx);
+            self.picks.push(idx);
         }
 
+        self.error = None;
+
         ActionResult::NeedsRedraw
     }
 
@@ -159,8 +472,11 @@ where
 
         match action {
             MultiSelectPromptAction::ToggleCurrentOption
+            | MultiSelectPromptAction::ToggleOption(_)
             | MultiSelectPromptAction::SelectAll
-            | MultiSelectPromptAction::ClearSelections => {
+            | MultiSelectPromptAction::SelectEverything
+            | MultiSelectPromptAction::ClearSelections
+            | MultiSelectPromptAction::InvertSelection => {
                 input_ref.clear();
                 self.run_scorer();
                 ActionResult::NeedsRedraw
@@ -169,6 +485,111 @@ where
         }
     }
 
+    /// Indexes of the entries created by the user.
+    fn created_indexes(&self) -> Range<usize> {
+        self.options.len()..self.options.len() + self.created.len()
+    }
+
+    /// Selects the enabled options at the given indexes, keeping the other
+    /// selected options.
+    fn select_all(&mut self, indexes: Vec<usize>) -> ActionResult {
+        let mut checked = self.checked.clone();
+        checked.extend(
+            indexes
+                .into_iter()
+                .filter(|idx| !self.details.disabled.contains_key(idx)),
+        );
+
+        if let Some(error) = self.max_selections_error(checked.len()) {
+            self.error = Some(error);
+            return ActionResult::NeedsRedraw;
+        }
+
+        self.picks.extend(checked.difference(&self.checked));
+        self.checked = checked;
+        ActionResult::NeedsRedraw
+    }
+
+    /// Toggles the enabled options of the group of the highlighted option,
+    /// selecting them unless all of them already are.
+    fn toggle_group(&mut self) -> ActionResult {
+        let header = match self
+            .scored_options
+            .get(self.cursor_index)
+            .and_then(|idx| self.details.groups.get(idx))
+        {
+            Some(header) => *header,
+            None => return ActionResult::Clean,
+        };
+
+        let members = self.details.group_members(header).collect::<Vec<usize>>();
+
+        if members.iter().all(|idx| self.checked.contains(idx)) {
+            for idx in members {
+                if !self.details.locked.contains(&idx) {
+                    self.checked.remove(&idx);
+                }
+            }
+
+            return ActionResult::NeedsRedraw;
+        }
+
+        self.select_all(members)
+    }
+
+    /// Inverts the selection of the enabled options matching the current
+    /// filter, along with the created entries.
+    fn invert_selection(&mut self) -> ActionResult {
+        let visible = self
+            .scored_options
+            .iter()
+            .copied()
+            .filter(|idx| {
+                !self.details.disabled.contains_key(idx) && !self.details.locked.contains(idx)
+            })
+            .chain(self.created_indexes())
+            .collect::<Vec<usize>>();
+
+        let checked = visible
+            .iter()
+            .filter(|idx| self.checked.contains(idx))
+            .count();
+        let selected = self.checked.len() - checked + (visible.len() - checked);
+
+        if let Some(error) = self.max_selections_error(selected) {
+            self.error = Some(error);
+            return ActionResult::NeedsRedraw;
+        }
+
+        for idx in visible {
+            if !self.checked.remove(&idx) {
+                self.checked.insert(idx);
+                self.picks.push(idx);
+            }
+        }
+
+        ActionResult::NeedsRedraw
+    }
+
+    /// Hint displayed when the given number of selected options exceeds the maximum.
+    fn max_selections_error(&self, selected: usize) -> Option<ErrorMessage> {
+        let max = self.config.max_selections?;
+
+        (selected > max)
+            .then(|| ErrorMessage::Custom(format!("You can select at most {}", selections(max))))
+    }
+
+    /// Hint displayed when the selected options are outside of the limits.
+    fn selection_limits_error(&self) -> Option<ErrorMessage> {
+        match self.config.min_selections {
+            Some(min) if self.checked.len() < min => Some(ErrorMessage::Custom(format!(
+                "Select at least {}",
+                selections(min)
+            ))),
+            _ => self.max_selections_error(self.checked.len()),
+        }
+    }
+
     fn validate_current_answer(&self) -> InquireResult<Validation> {
         if let Some(validator) = &self.validator {
             let selected_options = self
@@ -188,54 +609,194 @@ where
         }
     }
 
-    fn get_final_answer(&mut self) -> Vec<ListOption<T>> {
+    fn get_final_answer(&mut self) -> MultiSelectAnswer<ListOption<T>> {
         let mut answer = vec![];
+        let mut created = vec![];
 
         // by iterating in descending order, we can safely
         // swap remove because the elements to the right
         // that we did not remove will not matter anymore.
         for index in self.checked.iter().rev() {
             let index = *index;
+
+            if let Some(created_idx) = index.checked_sub(self.options.len()) {
+                created.push((index, std::mem::take(&mut self.created[created_idx])));
+                continue;
+            }
+
             let value = self.options.swap_remove(index);
             let lo = ListOption::new(index, value);
             answer.push(lo);
         }
         answer.reverse();
+        created.reverse();
+
+        if self.config.keep_pick_order {
+            // options checked more than once are ordered by their last pick
+            let picked_at = self
+                .picks
+                .iter()
+                .enumerate()
+                .map(|(at, idx)| (*idx, at))
+                .collect::<BTreeMap<usize, usize>>();
 
-        answer
+            answer.sort_by_key(|option| picked_at.get(&option.index).copied());
+            created.sort_by_key(|(index, _)| picked_at.get(index).copied());
+        }
+
+        MultiSelectAnswer {
+            selected: answer,
+            created: created.into_iter().map(|(_, entry)| entry).collect(),
+        }
     }
 
-    fn run_scorer(&mut self) {
-        let content = match &self.input {
-            Some(input) => input.content(),
+    /// Appends the options received by the loader, keeping the cursor on
+    /// the highlighted entry.
+    fn load_options(&mut self) {
+        let (options, finished) = match &self.loader {
+            Some(loader) => loader.poll(),
             None => return,
         };
 
+        if finished {
+            self.loader = None;
+        }
+
+        if options.is_empty() {
+            return;
+        }
+
+        let highlighted = self.scored_options.get(self.cursor_index).copied();
+        let listed = self.scored_options.len();
+        let on_entry = self.cursor_index < self.entry_count();
+
+        // the positions of the listed entries may change
+        self.range_anchor = None;
+
+        // created entries are indexed right after the options
+        let loaded = options.len();
+        let len = self.options.len();
+        let shift = |i: &usize| if *i >= len { i + loaded } else { *i };
+        self.checked = self.checked.iter().map(shift).collect();
+        self.picks = self.picks.iter().map(shift).collect();
+
+        for option in options {
+            let string_option = option.to_string();
+            self.search_strings.push(string_option.clone());
+            self.string_options.push(string_option);
+            self.options.push(option);
+            self.groups.push();
+        }
+
+        self.scored_options = match &self.input {
+            Some(input) => self.score_options(input.content()),
+            None => self.groups.order.clone(),
+        };
+        self.update_matches();
+
+        let new_position = match highlighted {
+            Some(index) => self
+                .scored_options
+                .iter()
+                .position(|i| *i == index)
+                .unwrap_or(self.cursor_index),
+            // entries listed after the options, such as the created ones
+            None if on_entry => self.cursor_index - listed + self.scored_options.len(),
+            None => self.cursor_index,
+        };
+
+        let new_position = new_position.min(self.entry_count().saturating_sub(1));
+        let _ = self.update_cursor_position(self.skip_disabled(new_position, false, false));
+    }
+
+    /// Indexes of the options matching the filter, sorted by their scores.
+    fn score_options(&self, content: &str) -> Vec<usize> {
         let mut options = self
-            .options
+            .groups
+            .order
             .iter()
-            .enumerate()
+            .map(|i| (*i, self.options.get(*i).unwrap()))
             .filter_map(|(i, opt)| {
-                (self.scorer)(content, opt, self.string_options.get(i).unwrap(), i)
+                (self.scorer)(content, opt, self.search_strings.get(i).unwrap(), i)
                     .map(|score| (i, score))
             })
             .collect::<Vec<(usize, i64)>>();
 
-        options.sort_unstable_by_key(|(_idx, score)| Reverse(*score));
+        match self.groups.is_empty() {
+            true => options.sort_by_key(|(_idx, score)| Reverse(*score)),
+            false => self.groups.sort(&mut options),
+        }
 
-        let new_scored_options = options.iter().map(|(idx, _)| *idx).collect::<Vec<usize>>();
+        options.iter().map(|(idx, _)| *idx).collect()
+    }
 
-        if self.scored_options == new_scored_options {
-            return;
+    /// Finds the characters of the listed options matched by the filter
+    /// input, which are highlighted when rendering them.
+    fn update_matches(&mut self) {
+        self.details.matched.clear();
+
+        let content = match &self.input {
+            Some(input) if self.config.highlight_matches && !input.is_empty() => input.content(),
+            _ => return,
+        };
+
+        for index in &self.scored_options {
+            let matched = matched_chars(content, &self.options[*index].to_string());
+
+            if !matched.is_empty() {
+                self.details.matched.insert(*index, matched);
+            }
         }
+    }
+
+    /// Number of options fitting in the terminal along with the other lines
+    /// of the prompt, the descriptions, separators and group headers listed
+    /// with them taking lines of their own.
+    fn fit_page_size<D>(&self, screen_height: usize, choices: &[ListOption<D>]) -> usize {
+        // prompt and the empty line the cursor rests on
+        let mut reserved = 2;
+
+        reserved += usize::from(self.help_message.is_some());
+        reserved += usize::from(self.error.is_some());
+        reserved += usize::from(self.loader.is_some());
+
+        let height = screen_height.saturating_sub(reserved).max(1);
+        self.details
+            .fit_page_size(height, choices, Some(self.cursor_index))
+    }
+
+    /// Hides the separators while filtering, as the options they separate
+    /// are no longer listed next to each other.
+    fn update_separators(&mut self) {
+        self.details.separators = match &self.input {
+            Some(input) if !input.is_empty() => BTreeSet::new(),
+            _ => self.separators.clone(),
+        };
+    }
+
+    fn run_scorer(&mut self) {
+        let new_scored_options = match &self.input {
+            Some(input) => self.score_options(input.content()),
+            None => return,
+        };
+
+        let changed = self.scored_options != new_scored_options;
 
         self.scored_options = new_scored_options;
+        self.update_matches();
+        self.update_separators();
+
+        if !changed {
+            return;
+        }
 
         if self.config.reset_cursor {
             let _ = self.update_cursor_position(0);
-        } else if self.scored_options.len() <= self.cursor_index {
-            let _ = self.update_cursor_position(self.scored_options.len().saturating_sub(1));
+        } else {
+            self.clamp_cursor();
         }
+
+        let _ = self.update_cursor_position(self.skip_disabled(self.cursor_index, false, false));
     }
 }
 
@@ -246,7 +807,7 @@ where
 {
     type Config = MultiSelectConfig;
     type InnerAction = MultiSelectPromptAction;
-    type Output = Vec<ListOption<T>>;
+    type Output = MultiSelectAnswer<ListOption<T>>;
 
     fn message(&self) -> &str {
         self.message
@@ -256,17 +817,92 @@ where
         &self.config
     }
 
-    fn format_answer(&self, answer: &Vec<ListOption<T>>) -> String {
-        let refs: Vec<ListOption<&T>> = answer.iter().map(ListOption::as_ref).collect();
-        (self.formatter)(&refs)
+    fn format_answer(&self, answer: &MultiSelectAnswer<ListOption<T>>) -> String {
+        let refs: Vec<ListOption<&T>> = answer.selected.iter().map(ListOption::as_ref).collect();
+        let formatted = (self.formatter)(&refs);
+
+        if answer.created.is_empty() {
+            return formatted;
+        }
+
+        let created = answer.created.join(", ");
+        match formatted.is_empty() {
+            true => created,
+            false => format!("{formatted}, {created}"),
+        }
+    }
+
+    fn parse_key(&self, key: Key) -> Option<Action<MultiSelectPromptAction>> {
+        if self.filter_focused {
+            return match key {
+                Key::Enter | Key::Escape => {
+                    Some(Action::Inner(MultiSelectPromptAction::UnfocusFilter))
+                }
+                _ => InputAction::from_key(key, &())
+                    .map(|a| Action::Inner(MultiSelectPromptAction::FilterInput(a)))
+                    .or_else(|| Action::from_key(key, &self.config)),
+            };
+        }
+
+        match key {
+            // Ctrl+d cancels the prompt unless it is claimed by the vim keymap
+            Key::Char('d', KeyModifiers::CONTROL) if self.config.keymap == ListKeymap::Vim => {
+                Some(Action::Inner(MultiSelectPromptAction::PageDown))
+            }
+            // the first click of a double click already moved the cursor,
+            // while double clicks on checkboxes toggle them instead
+            Key::Mouse(MouseEvent {
+                kind: MouseEventKind::DoubleClick,
+                option: Some(_),
+                on_checkbox: false,
+                ..
+            }) => Some(Action::Submit),
+            _ => Action::from_key(key, &self.config),
+        }
     }
 
     fn setup(&mut self) -> InquireResult<()> {
+        self.load_options();
         self.run_scorer();
+
+        let _ = self.update_cursor_position(self.skip_disabled(self.cursor_index, false, false));
         Ok(())
     }
 
-    fn submit(&mut self) -> InquireResult<Option<Vec<ListOption<T>>>> {
+    fn captures_mouse(&self) -> bool {
+        true
+    }
+
+    fn tick_interval(&self) -> Option<Duration> {
+        self.loader.as_ref().map(|_| LOADER_POLL_INTERVAL)
+    }
+
+    fn tick(&mut self) -> InquireResult<Option<Action<MultiSelectPromptAction>>> {
+        self.load_options();
+
+        Ok(None)
+    }
+
+    fn pre_cancel(&mut self) -> InquireResult<bool> {
+        // the first press of escape clears the filter, if any
+        if self.clear_filter() {
+            return Ok(false);
+        }
+
+        Ok(true)
+    }
+
+    fn submit(&mut self) -> InquireResult<Option<MultiSelectAnswer<ListOption<T>>>> {
+        if self.is_cursor_on_create_row() {
+            self.create_entry();
+            return Ok(None);
+        }
+
+        if let Some(error) = self.selection_limits_error() {
+            self.error = Some(error);
+            return Ok(None);
+        }
+
         let answer = match self.validate_current_answer()? {
             Validation::Valid => Some(self.get_final_answer()),
             Validation::Invalid(msg) => {
@@ -279,33 +915,68 @@ where
     }
 
     fn handle(&mut self, action: MultiSelectPromptAction) -> InquireResult<ActionResult> {
+        if !matches!(
+            action,
+            MultiSelectPromptAction::SelectRangeUp | MultiSelectPromptAction::SelectRangeDown
+        ) {
+            self.range_anchor = None;
+        }
+
         let result = match action {
-            MultiSelectPromptAction::MoveUp => self.move_cursor_up(1, true),
-            MultiSelectPromptAction::MoveDown => self.move_cursor_down(1, true),
-            MultiSelectPromptAction::PageUp => self.move_cursor_up(self.config.page_size, false),
-            MultiSelectPromptAction::PageDown => {
-                self.move_cursor_down(self.config.page_size, false)
-            }
+            MultiSelectPromptAction::MoveUp => self.move_cursor_up(1, self.config.wrap_around),
+            MultiSelectPromptAction::MoveDown => self.move_cursor_down(1, self.config.wrap_around),
+            MultiSelectPromptAction::PageUp => self.move_cursor_up(self.page_size.get(), false),
+            MultiSelectPromptAction::PageDown => self.move_cursor_down(self.page_size.get(), false),
             MultiSelectPromptAction::MoveToStart => self.move_cursor_up(usize::MAX, false),
             MultiSelectPromptAction::MoveToEnd => self.move_cursor_down(usize::MAX, false),
             MultiSelectPromptAction::ToggleCurrentOption => self.toggle_cursor_selection(),
-            MultiSelectPromptAction::SelectAll => {
-                self.checked.clear();
-                for idx in &self.scored_options {
-                    self.checked.insert(*idx);
+            MultiSelectPromptAction::JumpToOption(position) => match self.page_target(position) {
+                Some(new_position) => self.update_cursor_position(new_position),
+                None => ActionResult::Clean,
+            },
+            MultiSelectPromptAction::ToggleOption(position) => match self.page_target(position) {
+                Some(new_position) => {
+                    let moved = self.update_cursor_position(new_position);
+                    moved.merge(self.toggle_cursor_selection())
                 }
-                ActionResult::NeedsRedraw
+                None => ActionResult::Clean,
+            },
+            MultiSelectPromptAction::SelectRangeUp => self.select_range(true),
+            MultiSelectPromptAction::SelectRangeDown => self.select_range(false),
+            MultiSelectPromptAction::SelectAll => {
+                let indexes = self.scored_options.iter().copied();
+                self.select_all(indexes.chain(self.created_indexes()).collect())
             }
+            MultiSelectPromptAction::SelectEverything => self.select_all(
+                (0..self.options.len())
+                    .chain(self.created_indexes())
+                    .collect(),
+            ),
             MultiSelectPromptAction::ClearSelections => {
-                self.checked.clear();
+                self.checked.clone_from(&self.details.locked);
                 ActionResult::NeedsRedraw
             }
+            MultiSelectPromptAction::InvertSelection => self.invert_selection(),
+            MultiSelectPromptAction::ToggleGroup => self.toggle_group(),
+            MultiSelectPromptAction::FocusFilter => {
+                self.filter_focused = self.input.is_some();
+                ActionResult::Clean
+            }
+            MultiSelectPromptAction::UnfocusFilter => {
+                self.filter_focused = false;
+                ActionResult::Clean
+            }
+            MultiSelectPromptAction::ClearFilter => match self.clear_filter() {
+                true => ActionResult::NeedsRedraw,
+                false => ActionResult::Clean,
+            },
             MultiSelectPromptAction::FilterInput(input_action) => match self.input.as_mut() {
                 Some(input) => {
                     let result = input.handle(input_action);
 
                     if let InputActionResult::ContentChanged = result {
                         self.run_scorer();
+                        self.clamp_cursor();
                     }
 
                     result.into()
@@ -326,23 +997,68 @@ where
             backend.render_error_message(err)?;
         }
 
-        backend.render_multiselect_prompt(prompt, self.input.as_ref())?;
+        backend.render_multiselect_prompt(
+            prompt,
+            self.input.as_ref(),
+            self.checked.len(),
+            match self.is_filter_active() {
+                true => Some(self.scored_options.len() + self.created.len()),
+                false => None,
+            },
+            self.options.len() + self.created.len(),
+        )?;
 
-        let choices = self
+        let mut choices = self
             .scored_options
             .iter()
             .cloned()
-            .map(|i| ListOption::new(i, self.options.get(i).unwrap()))
-            .collect::<Vec<ListOption<&T>>>();
+            .map(|i| ListOption::new(i, Entry::Option(self.options.get(i).unwrap())))
+            .collect::<Vec<ListOption<Entry<'_, T>>>>();
 
-        let page = paginate(self.config.page_size, &choices, Some(self.cursor_index));
+        for (i, created) in self.created.iter().enumerate() {
+            choices.push(ListOption::new(
+                self.options.len() + i,
+                Entry::Created(created),
+            ));
+        }
 
-        backend.render_options(page, &self.checked)?;
+        if let Some(candidate) = self.create_candidate() {
+            choices.push(ListOption::new(
+                self.options.len() + self.created.len(),
+                Entry::Create(candidate),
+            ));
+        }
 
-        if let Some(help_message) = self.help_message {
-            backend.render_help_message(help_message)?;
+        if self.config.auto_page_size {
+            let screen_height = backend.screen_size().height() as usize;
+            let list_height = screen_height.saturating_sub(backend.scroll_indicators_height());
+            self.page_size
+                .set(self.fit_page_size(list_height, &choices));
+        }
+
+        let page = paginate(self.page_size.get(), &choices, Some(self.cursor_index));
+
+        backend.render_options(page, &self.checked, &self.details)?;
+
+        if self.loader.is_some() {
+            backend.render_loading_indicator(self.loading_message)?;
+        }
+
+        match self.help_message {
+            Some(help_message) if self.is_filter_active() => backend
+                .render_help_message(&format!("{help_message}, {CLEAR_FILTER_HELP_MESSAGE}"))?,
+            Some(help_message) => backend.render_help_message(help_message)?,
+            None => {}
         }
 
         Ok(())
     }
 }
+
+/// Number of selections with the noun agreeing with it, such as "1 option".
+fn selections(count: usize) -> String {
+    match count {
+        1 => String::from("1 option"),
+        count => format!("{count} options"),
+    }
+}
@@ -1,9 +1,11 @@
+use std::sync::mpsc;
+
 use crate::{
     formatter::MultiOptionFormatter,
-    list_option::ListOption,
+    list_option::{ListOption, OptionItem},
     test::fake_backend,
-    ui::{Key, KeyModifiers},
-    MultiSelect,
+    ui::{Key, KeyModifiers, MouseEvent, MouseEventKind},
+    CreateEntryMode, ListKeymap, MultiSelect, MultiSelectAnswer, OptionLoader,
 };
 
 #[test]
@@ -255,3 +257,502 @@ fn keep_filter_false_should_reset_option_list() {
     let expected_answer = vec![ListOption::new(0, 1), ListOption::new(2, 3)];
     assert_eq!(expected_answer, ans);
 }
+
+#[test]
+fn entry_is_created_when_filter_matches_nothing() {
+    let mut keys = vec![Key::Char(' ', KeyModifiers::NONE)];
+    keys.extend(Key::char_keys_from_str("perf"));
+    keys.extend([Key::Enter, Key::Enter]);
+    let mut backend = fake_backend(keys);
+
+    let options = vec!["bug", "docs"];
+
+    let ans = MultiSelect::new("Question", options)
+        .prompt_with_create_with_backend(CreateEntryMode::NoMatches, &mut backend)
+        .unwrap();
+
+    assert_eq!(
+        MultiSelectAnswer {
+            selected: vec!["bug"],
+            created: vec![String::from("perf")],
+        },
+        ans
+    );
+}
+
+#[test]
+fn create_row_is_offered_according_to_mode() {
+    let mut keys = Key::char_keys_from_str("doc");
+    keys.extend([Key::Down(KeyModifiers::NONE), Key::Enter]);
+    let mut backend = fake_backend(keys);
+
+    let options = vec!["bug", "docs"];
+
+    let ans = MultiSelect::new("Question", options.clone())
+        .prompt_with_create_with_backend(CreateEntryMode::NoMatches, &mut backend)
+        .unwrap();
+
+    assert!(ans.selected.is_empty());
+    assert!(ans.created.is_empty());
+
+    let mut keys = Key::char_keys_from_str("doc");
+    keys.extend([Key::Down(KeyModifiers::NONE), Key::Enter, Key::Enter]);
+    let mut backend = fake_backend(keys);
+
+    let ans = MultiSelect::new("Question", options)
+        .prompt_with_create_with_backend(CreateEntryMode::Always, &mut backend)
+        .unwrap();
+
+    assert_eq!(vec![String::from("doc")], ans.created);
+}
+
+#[test]
+fn created_entry_can_be_unselected() {
+    let mut keys = Key::char_keys_from_str("perf");
+    keys.extend([
+        Key::Char(' ', KeyModifiers::NONE),
+        Key::Up(KeyModifiers::NONE),
+        Key::Char(' ', KeyModifiers::NONE),
+        Key::Enter,
+    ]);
+    let mut backend = fake_backend(keys);
+
+    let options = vec!["bug", "docs"];
+
+    let ans = MultiSelect::new("Question", options)
+        .prompt_with_create_with_backend(CreateEntryMode::NoMatches, &mut backend)
+        .unwrap();
+
+    assert!(ans.selected.is_empty());
+    assert!(ans.created.is_empty());
+}
+
+#[test]
+fn disabled_options_are_skipped_and_left_out_of_select_all() {
+    let mut backend = fake_backend(vec![
+        Key::Right(KeyModifiers::NONE),
+        Key::Down(KeyModifiers::NONE),
+        Key::Char(' ', KeyModifiers::NONE),
+        Key::Enter,
+    ]);
+
+    let options = vec![1, 2, 3, 4];
+
+    let ans = MultiSelect::new("Question", options)
+        .with_disabled_options(&[1])
+        .prompt_with_backend(&mut backend)
+        .unwrap();
+
+    assert_eq!(vec![ListOption::new(0, 1), ListOption::new(3, 4)], ans);
+}
+
+#[test]
+fn disabled_options_can_not_be_toggled_or_selected_by_default() {
+    let mut backend = fake_backend(vec![
+        Key::Down(KeyModifiers::NONE),
+        Key::Char(' ', KeyModifiers::NONE),
+        Key::Enter,
+    ]);
+
+    let options = vec![1, 2, 3];
+
+    let ans = MultiSelect::new("Question", options)
+        .with_default(&[0, 1])
+        .with_disabled_option(1, "already installed")
+        .with_skip_disabled(false)
+        .prompt_with_backend(&mut backend)
+        .unwrap();
+
+    assert_eq!(vec![ListOption::new(0, 1)], ans);
+}
+
+#[test]
+fn descriptions_are_searched_when_filtering() {
+    let mut keys = Key::char_keys_from_str("mumbai");
+    keys.extend([Key::Char(' ', KeyModifiers::NONE), Key::Enter]);
+    let mut backend = fake_backend(keys);
+
+    let options = vec!["us-east-1", "eu-west-1", "ap-south-1"];
+
+    let ans = MultiSelect::new("Regions", options)
+        .with_description(0, "N. Virginia")
+        .with_description(2, "Mumbai")
+        .prompt_with_backend(&mut backend)
+        .unwrap();
+
+    assert_eq!(vec![ListOption::new(2, "ap-south-1")], ans);
+}
+
+#[test]
+fn grouped_options_are_listed_by_group() {
+    let mut backend = fake_backend(vec![
+        Key::Char(' ', KeyModifiers::NONE),
+        Key::Down(KeyModifiers::NONE),
+        Key::Down(KeyModifiers::NONE),
+        Key::Char(' ', KeyModifiers::NONE),
+        Key::Enter,
+    ]);
+
+    let options = vec!["a", "b", "c", "d"];
+
+    let ans = MultiSelect::new("Question", options)
+        .with_group("Recent", &[2])
+        .with_group("All", &[0, 1, 3])
+        .prompt_with_backend(&mut backend)
+        .unwrap();
+
+    assert_eq!(vec![ListOption::new(1, "b"), ListOption::new(2, "c")], ans);
+}
+
+#[test]
+fn loaded_options_are_appended_to_the_list() {
+    let mut backend = fake_backend(vec![
+        Key::Char(' ', KeyModifiers::NONE),
+        Key::Down(KeyModifiers::NONE),
+        Key::Char(' ', KeyModifiers::NONE),
+        Key::Enter,
+    ]);
+
+    let (sender, receiver) = mpsc::channel();
+    sender.send("b").unwrap();
+    drop(sender);
+
+    let ans = MultiSelect::new("Question", vec!["a"])
+        .with_loader(OptionLoader::new(receiver))
+        .prompt_with_backend(&mut backend)
+        .unwrap();
+
+    assert_eq!(vec![ListOption::new(0, "a"), ListOption::new(1, "b")], ans);
+}
+
+#[test]
+fn vim_keymap_types_into_focused_filter() {
+    let mut backend = fake_backend(vec![
+        Key::Char('G', KeyModifiers::SHIFT),
+        Key::Char(' ', KeyModifiers::NONE),
+        Key::Char('/', KeyModifiers::NONE),
+        Key::Char('j', KeyModifiers::NONE),
+        Key::Enter,
+        Key::Char(' ', KeyModifiers::NONE),
+        Key::Enter,
+    ]);
+
+    let options = vec!["Germany", "Japan", "Brazil"];
+
+    let ans = MultiSelect::new("Countries", options)
+        .with_keymap(ListKeymap::Vim)
+        .prompt_with_backend(&mut backend)
+        .unwrap();
+
+    assert_eq!(
+        vec![ListOption::new(1, "Japan"), ListOption::new(2, "Brazil")],
+        ans
+    );
+}
+
+#[test]
+fn toggling_past_max_selections_is_blocked() {
+    let mut backend = fake_backend(vec![
+        Key::Char(' ', KeyModifiers::NONE),
+        Key::Down(KeyModifiers::NONE),
+        Key::Char(' ', KeyModifiers::NONE),
+        Key::Down(KeyModifiers::NONE),
+        Key::Char(' ', KeyModifiers::NONE),
+        Key::Right(KeyModifiers::NONE),
+        Key::Enter,
+    ]);
+
+    let ans = MultiSelect::new("Question", vec!["a", "b", "c"])
+        .with_max_selections(2)
+        .prompt_with_backend(&mut backend)
+        .unwrap();
+
+    assert_eq!(vec![ListOption::new(0, "a"), ListOption::new(1, "b")], ans);
+}
+
+#[test]
+fn submitting_below_min_selections_is_refused() {
+    let mut backend = fake_backend(vec![
+        Key::Char(' ', KeyModifiers::NONE),
+        Key::Enter,
+        Key::Down(KeyModifiers::NONE),
+        Key::Char(' ', KeyModifiers::NONE),
+        Key::Enter,
+    ]);
+
+    let ans = MultiSelect::new("Question", vec!["a", "b", "c"])
+        .with_min_selections(2)
+        .prompt_with_backend(&mut backend)
+        .unwrap();
+
+    assert_eq!(vec![ListOption::new(0, "a"), ListOption::new(1, "b")], ans);
+}
+
+#[test]
+fn invert_selection_only_affects_filtered_options() {
+    let mut backend = fake_backend(vec![
+        Key::Char(' ', KeyModifiers::NONE),
+        Key::Char('p', KeyModifiers::NONE),
+        Key::Char('r', KeyModifiers::NONE),
+        Key::Char('o', KeyModifiers::NONE),
+        Key::Char('d', KeyModifiers::NONE),
+        Key::Char(' ', KeyModifiers::NONE),
+        Key::Char('r', KeyModifiers::CONTROL),
+        Key::Enter,
+    ]);
+
+    let options = vec!["dev-api", "prod-api", "prod-db", "prod-cache"];
+
+    let ans = MultiSelect::new("Deployments", options)
+        .with_invert_selection(true)
+        .prompt_with_backend(&mut backend)
+        .unwrap();
+
+    assert_eq!(
+        vec![
+            ListOption::new(0, "dev-api"),
+            ListOption::new(2, "prod-db"),
+            ListOption::new(3, "prod-cache"),
+        ],
+        ans
+    );
+}
+
+#[test]
+fn select_all_only_affects_filtered_options() {
+    let mut backend = fake_backend(vec![
+        Key::Char(' ', KeyModifiers::NONE),
+        Key::Char('p', KeyModifiers::NONE),
+        Key::Char('r', KeyModifiers::NONE),
+        Key::Char('o', KeyModifiers::NONE),
+        Key::Char('d', KeyModifiers::NONE),
+        Key::Right(KeyModifiers::NONE),
+        Key::Enter,
+    ]);
+
+    let options = vec!["dev-api", "prod-api", "dev-db", "prod-db"];
+
+    let ans = MultiSelect::new("Deployments", options)
+        .prompt_with_backend(&mut backend)
+        .unwrap();
+
+    assert_eq!(
+        vec![
+            ListOption::new(0, "dev-api"),
+            ListOption::new(1, "prod-api"),
+            ListOption::new(3, "prod-db"),
+        ],
+        ans
+    );
+}
+
+#[test]
+fn shift_right_selects_options_outside_of_filter() {
+    let mut backend = fake_backend(vec![
+        Key::Char('p', KeyModifiers::NONE),
+        Key::Char('r', KeyModifiers::NONE),
+        Key::Char('o', KeyModifiers::NONE),
+        Key::Char('d', KeyModifiers::NONE),
+        Key::Right(KeyModifiers::SHIFT),
+        Key::Enter,
+    ]);
+
+    let options = vec!["dev-api", "prod-api"];
+
+    let ans = MultiSelect::new("Deployments", options)
+        .prompt_with_backend(&mut backend)
+        .unwrap();
+
+    assert_eq!(
+        vec![
+            ListOption::new(0, "dev-api"),
+            ListOption::new(1, "prod-api")
+        ],
+        ans
+    );
+}
+
+#[test]
+fn answer_keeps_pick_order() {
+    let mut backend = fake_backend(vec![
+        Key::End,
+        Key::Char(' ', KeyModifiers::NONE),
+        Key::Home,
+        Key::Char(' ', KeyModifiers::NONE),
+        Key::Down(KeyModifiers::NONE),
+        Key::Char(' ', KeyModifiers::NONE),
+        Key::Up(KeyModifiers::NONE),
+        Key::Char(' ', KeyModifiers::NONE),
+        Key::Char(' ', KeyModifiers::NONE),
+        Key::Enter,
+    ]);
+
+    let ans = MultiSelect::new("Pipeline", vec!["lint", "test", "build"])
+        .with_keep_pick_order(true)
+        .prompt_with_backend(&mut backend)
+        .unwrap();
+
+    assert_eq!(
+        vec![
+            ListOption::new(2, "build"),
+            ListOption::new(1, "test"),
+            ListOption::new(0, "lint"),
+        ],
+        ans
+    );
+}
+
+#[test]
+fn locked_options_can_not_be_unselected() {
+    let mut backend = fake_backend(vec![
+        Key::Char(' ', KeyModifiers::NONE),
+        Key::Down(KeyModifiers::NONE),
+        Key::Char(' ', KeyModifiers::NONE),
+        Key::Left(KeyModifiers::NONE),
+        Key::Down(KeyModifiers::NONE),
+        Key::Char(' ', KeyModifiers::NONE),
+        Key::Enter,
+    ]);
+
+    let ans = MultiSelect::new("Components", vec!["core", "docs", "examples"])
+        .with_locked_options(&[0])
+        .prompt_with_backend(&mut backend)
+        .unwrap();
+
+    assert_eq!(
+        vec![ListOption::new(0, "core"), ListOption::new(2, "examples")],
+        ans
+    );
+}
+
+#[test]
+fn tab_toggles_group_of_highlighted_option() {
+    let mut backend = fake_backend(vec![
+        Key::Down(KeyModifiers::NONE),
+        Key::Down(KeyModifiers::NONE),
+        Key::Char(' ', KeyModifiers::NONE),
+        Key::Tab,
+        Key::Down(KeyModifiers::NONE),
+        Key::Tab,
+        Key::Tab,
+        Key::Enter,
+    ]);
+
+    let ans = MultiSelect::new("Question", vec!["a", "b", "c", "d", "e"])
+        .with_group("Backend", &[1, 2])
+        .with_group("Frontend", &[3, 4])
+        .with_group_toggling(true)
+        .prompt_with_backend(&mut backend)
+        .unwrap();
+
+    assert_eq!(vec![ListOption::new(1, "b"), ListOption::new(2, "c")], ans);
+}
+
+#[test]
+fn option_items_carry_their_values() {
+    let mut backend = fake_backend(vec![
+        Key::Char('i', KeyModifiers::NONE),
+        Key::Char('r', KeyModifiers::NONE),
+        Key::Char('e', KeyModifiers::NONE),
+        Key::Char(' ', KeyModifiers::NONE),
+        Key::Enter,
+    ]);
+
+    let regions = vec![
+        OptionItem::new("N. Virginia", "us-east-1"),
+        OptionItem::new("Ireland", "eu-west-1"),
+    ];
+
+    let ans = MultiSelect::new("Regions", regions)
+        .prompt_with_backend(&mut backend)
+        .unwrap();
+
+    assert_eq!(
+        vec!["eu-west-1"],
+        ans.into_iter()
+            .map(|option| option.value.into_value())
+            .collect::<Vec<_>>()
+    );
+}
+
+#[test]
+/// Ctrl+u clears the filter, keeping the options selected while filtered
+fn ctrl_u_clears_filter() {
+    let options = vec!["Banana", "Apple", "Cherry"];
+
+    let mut backend = fake_backend(vec![
+        Key::Char('c', KeyModifiers::NONE),
+        Key::Char('h', KeyModifiers::NONE),
+        Key::Char(' ', KeyModifiers::NONE),
+        Key::Char('u', KeyModifiers::CONTROL),
+        Key::Char(' ', KeyModifiers::NONE),
+        Key::Enter,
+    ]);
+
+    let ans = MultiSelect::new("Question", options)
+        .with_keep_filter(true)
+        .prompt_with_backend(&mut backend)
+        .unwrap();
+
+    assert_eq!(
+        vec![ListOption::new(0, "Banana"), ListOption::new(2, "Cherry")],
+        ans
+    );
+}
+
+#[test]
+/// Shift and the arrows select the options between the start of the range and the cursor
+fn shift_arrows_select_range() {
+    let options = vec!["a", "b", "c", "d", "e"];
+
+    let mut backend = fake_backend(vec![
+        Key::Down(KeyModifiers::NONE),
+        Key::Down(KeyModifiers::SHIFT),
+        Key::Down(KeyModifiers::SHIFT),
+        Key::Down(KeyModifiers::SHIFT),
+        Key::Up(KeyModifiers::SHIFT),
+        Key::Enter,
+    ]);
+
+    let ans = MultiSelect::new("Question", options)
+        .prompt_with_backend(&mut backend)
+        .unwrap();
+
+    assert_eq!(
+        vec![
+            ListOption::new(1, "b"),
+            ListOption::new(2, "c"),
+            ListOption::new(3, "d"),
+            ListOption::new(4, "e"),
+        ],
+        ans
+    );
+}
+
+#[test]
+/// Clicking a checkbox toggles its option, while clicking elsewhere only highlights it
+fn mouse_clicks_on_checkboxes_toggle_options() {
+    let options = vec!["a", "b", "c"];
+    let click = |option, on_checkbox| {
+        Key::Mouse(MouseEvent {
+            option: Some(option),
+            on_checkbox,
+            ..MouseEvent::new(MouseEventKind::Click, 0, 0)
+        })
+    };
+
+    let mut backend = fake_backend(vec![
+        click(2, true),
+        click(1, false),
+        Key::Char(' ', KeyModifiers::NONE),
+        click(2, true),
+        click(0, true),
+        Key::Enter,
+    ]);
+
+    let ans = MultiSelect::new("Question", options)
+        .prompt_with_backend(&mut backend)
+        .unwrap();
+
+    assert_eq!(vec![ListOption::new(0, "a"), ListOption::new(1, "b")], ans);
+}
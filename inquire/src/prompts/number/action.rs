@@ -0,0 +1,37 @@
+use crate::{
+    ui::{Key, KeyModifiers},
+    InnerAction, InputAction,
+};
+
+use super::config::NumberConfig;
+
+/// Set of actions for a NumberPrompt.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum NumberPromptAction {
+    /// Action on the value text input handler.
+    ValueInput(InputAction),
+    /// Increments the current value by one step.
+    Increment,
+    /// Decrements the current value by one step.
+    Decrement,
+}
+
+impl InnerAction for NumberPromptAction {
+    type Config = NumberConfig;
+
+    fn from_key(key: Key, _config: &NumberConfig) -> Option<Self> {
+        let action = match key {
+            Key::Up(KeyModifiers::NONE) | Key::Char('p', KeyModifiers::CONTROL) => Self::Increment,
+            Key::Down(KeyModifiers::NONE) | Key::Char('n', KeyModifiers::CONTROL) => {
+                Self::Decrement
+            }
+
+            key => match InputAction::from_key(key, &()) {
+                Some(action) => Self::ValueInput(action),
+                None => return None,
+            },
+        };
+
+        Some(action)
+    }
+}
@@ -0,0 +1,10 @@
+use crate::Number;
+
+/// Configuration settings used in the execution of a NumberPrompt.
+pub struct NumberConfig {}
+
+impl<T> From<&Number<'_, T>> for NumberConfig {
+    fn from(_value: &Number<'_, T>) -> Self {
+        Self {}
+    }
+}
@@ -0,0 +1,299 @@
+mod action;
+mod config;
+mod prompt;
+mod step;
+#[cfg(test)]
+mod test;
+
+pub use action::*;
+pub use step::*;
+
+use std::str::FromStr;
+
+use crate::{
+    config::get_configuration,
+    error::{InquireError, InquireResult},
+    formatter::CustomTypeFormatter,
+    parser::CustomTypeParser,
+    prompts::prompt::Prompt,
+    terminal::get_default_terminal,
+    ui::{Backend, CustomTypeBackend, RenderConfig},
+    validator::CustomTypeValidator,
+};
+
+use self::prompt::NumberPrompt;
+
+/// Prompt suitable for numeric inputs where the user might want to step through the values, such as port numbers, counts and timeouts.
+///
+/// It behaves like a [`CustomType`](crate::CustomType) prompt, where the user can type the number directly, with the addition that the Up and Down arrows increment and decrement the current value by a configurable step.
+///
+/// When stepping from an empty input, the prompt starts from the default value. If there is none, it starts from the minimum value, the maximum value or zero, in this order of preference.
+///
+/// Number prompts provide several options of configuration:
+///
+/// - **Prompt message**: Required when creating the prompt.
+/// - **Starting input**: Initial value of the prompt's text input.
+/// - **Default value**: Default value returned when the user submits an empty response.
+/// - **Step**: Amount added or subtracted when pressing the Up or Down arrows. Defaults to `1`.
+/// - **Min and max values**: Inclusive bounds of the accepted values. Stepping is clamped to these bounds and typed values outside of them are rejected on submission.
+/// - **Placeholder**: Short hint that describes the expected value of the input.
+/// - **Help message**: Message displayed at the line below the prompt.
+/// - **Formatter**: Custom formatter in case you need to pre-process the user input before showing it as the final answer.
+/// - **Parser**: Custom parser for user inputs, `str::parse` by default.
+/// - **Validators**: Custom validators to the parsed value, displaying an error message if the value does not pass the requirements.
+/// - **Error message**: Error message to display when a value could not be parsed from the input.
+///
+/// # Example
+///
+/// ```no_run
+/// use inquire::Number;
+///
+/// let port = Number::<u16>::new("Port:")
+///     .with_default(8080)
+///     .with_min(1024)
+///     .with_step(10)
+///     .with_help_message("↑↓ to change the value by 10")
+///     .prompt();
+///
+/// match port {
+///     Ok(port) => println!("Listening on port {}", port),
+///     Err(_) => println!("Could not read the port number"),
+/// }
+/// ```
+#[derive(Clone)]
+pub struct Number<'a, T> {
+    /// Message to be presented to the user.
+    pub message: &'a str,
+
+    /// Initial value of the prompt's text input.
+    ///
+    /// If you want to set a default value for the prompt, returned when the user's submission is empty, see [`default`].
+    ///
+    /// [`default`]: Self::default
+    pub starting_input: Option<&'a str>,
+
+    /// Default value, returned when the user input is empty.
+    pub default: Option<T>,
+
+    /// Amount added or subtracted from the current value when the user presses the Up or Down arrows.
+    pub step: T,
+
+    /// Minimum value (inclusive) accepted by the prompt.
+    pub min: Option<T>,
+
+    /// Maximum value (inclusive) accepted by the prompt.
+    pub max: Option<T>,
+
+    /// Short hint that describes the expected value of the input.
+    pub placeholder: Option<&'a str>,
+
+    /// Help message to be presented to the user.
+    pub help_message: Option<&'a str>,
+
+    /// Function that formats the user input and presents it to the user as the final rendering of the prompt.
+    pub formatter: CustomTypeFormatter<'a, T>,
+
+    /// Function that formats the default value and the min/max bounds when displayed to the user.
+    pub default_value_formatter: CustomTypeFormatter<'a, T>,
+
+    /// Function that parses the user input and returns the result value.
+    pub parser: CustomTypeParser<'a, T>,
+
+    /// Collection of validators to apply to the user input.
+    ///
+    /// Validators are executed in the order they are stored, stopping at and displaying to the user
+    /// only the first validation error that might appear.
+    ///
+    /// The possible error is displayed to the user one line above the prompt.
+    pub validators: Vec<Box<dyn CustomTypeValidator<T>>>,
+
+    /// Error message displayed when value could not be parsed from input.
+    pub error_message: String,
+
+    /// RenderConfig to apply to the rendered interface.
+    ///
+    /// Note: The default render config considers if the NO_COLOR environment variable
+    /// is set to decide whether to render the colored config or the empty one.
+    ///
+    /// When overriding the config in a prompt, NO_COLOR is no longer considered and your
+    /// config is treated as the only source of truth. If you want to customize colors
+    /// and still support NO_COLOR, you will have to do this on your end.
+    pub render_config: RenderConfig<'a>,
+}
+
+impl<'a, T> Number<'a, T>
+where
+    T: Steppable + FromStr + ToString,
+{
+    /// Default validators added to the [Number] prompt, none.
+    pub const DEFAULT_VALIDATORS: Vec<Box<dyn CustomTypeValidator<T>>> = vec![];
+
+    /// Default error message displayed when parsing fails.
+    pub const DEFAULT_ERROR_MESSAGE: &'a str = "Please type a valid number";
+
+    /// Creates a [Number] with the provided message and default configuration values.
+    pub fn new(message: &'a str) -> Self {
+        Self {
+            message,
+            starting_input: None,
+            default: None,
+            step: T::DEFAULT_STEP,
+            min: None,
+            max: None,
+            placeholder: None,
+            help_message: None,
+            formatter: &|val| val.to_string(),
+            default_value_formatter: &|val| val.to_string(),
+            parser: &|a| a.parse::<T>().map_err(|_e| ()),
+            validators: Self::DEFAULT_VALIDATORS,
+            error_message: String::from(Self::DEFAULT_ERROR_MESSAGE),
+            render_config: get_configuration(),
+        }
+    }
+
+    /// Sets the initial value of the prompt's text input.
+    ///
+    /// If you want to set a default value for the prompt, returned when the user's submission is empty, see [`with_default`].
+    ///
+    /// [`with_default`]: Self::with_default
+    pub fn with_starting_input(mut self, message: &'a str) -> Self {
+        self.starting_input = Some(message);
+        self
+    }
+
+    /// Sets the default input.
+    pub fn with_default(mut self, default: T) -> Self {
+        self.default = Some(default);
+        self
+    }
+
+    /// Sets the step used when incrementing or decrementing the value.
+    pub fn with_step(mut self, step: T) -> Self {
+        self.step = step;
+        self
+    }
+
+    /// Sets the minimum value (inclusive).
+    pub fn with_min(mut self, min: T) -> Self {
+        self.min = Some(min);
+        self
+    }
+
+    /// Sets the maximum value (inclusive).
+    pub fn with_max(mut self, max: T) -> Self {
+        self.max = Some(max);
+        self
+    }
+
+    /// Sets the placeholder.
+    pub fn with_placeholder(mut self, placeholder: &'a str) -> Self {
+        self.placeholder = Some(placeholder);
+        self
+    }
+
+    /// Sets the help message of the prompt.
+    pub fn with_help_message(mut self, message: &'a str) -> Self {
+        self.help_message = Some(message);
+        self
+    }
+
+    /// Sets the formatter.
+    pub fn with_formatter(mut self, formatter: CustomTypeFormatter<'a, T>) -> Self {
+        self.formatter = formatter;
+        self
+    }
+
+    /// Sets the formatter for default values and bounds.
+    pub fn with_default_value_formatter(mut self, formatter: CustomTypeFormatter<'a, T>) -> Self {
+        self.default_value_formatter = formatter;
+        self
+    }
+
+    /// Sets the parser.
+    pub fn with_parser(mut self, parser: CustomTypeParser<'a, T>) -> Self {
+        self.parser = parser;
+        self
+    }
+
+    /// Adds a validator to the collection of validators. You might want to use this feature
+    /// in case you need to require certain features from the parsed user's answer.
+    ///
+    /// Validators are executed in the order they are stored, stopping at and displaying to the user
+    /// only the first validation error that might appear.
+    ///
+    /// The possible error is displayed to the user one line above the prompt.
+    pub fn with_validator<V>(mut self, validator: V) -> Self
+    where
+        V: CustomTypeValidator<T> + 'static,
+    {
+        self.validators.push(Box::new(validator));
+        self
+    }
+
+    /// Adds the validators to the collection of validators in the order they are given.
+    /// You might want to use this feature in case you need to require certain features
+    /// from the parsed user's answer.
+    ///
+    /// Validators are executed in the order they are stored, stopping at and displaying to the user
+    /// only the first validation error that might appear.
+    ///
+    /// The possible error is displayed to the user one line above the prompt.
+    pub fn with_validators(mut self, validators: &[Box<dyn CustomTypeValidator<T>>]) -> Self {
+        for validator in validators {
+            #[allow(suspicious_double_ref_op)]
+            self.validators.push(validator.clone());
+        }
+        self
+    }
+
+    /// Sets a custom error message displayed when a submission could not be parsed to a value.
+    pub fn with_error_message(mut self, error_message: &'a str) -> Self {
+        self.error_message = String::from(error_message);
+        self
+    }
+
+    /// Sets the provided color theme to this prompt.
+    ///
+    /// Note: The default render config considers if the NO_COLOR environment variable
+    /// is set to decide whether to render the colored config or the empty one.
+    ///
+    /// When overriding the config in a prompt, NO_COLOR is no longer considered and your
+    /// config is treated as the only source of truth. If you want to customize colors
+    /// and still support NO_COLOR, you will have to do this on your end.
+    pub fn with_render_config(mut self, render_config: RenderConfig<'a>) -> Self {
+        self.render_config = render_config;
+        self
+    }
+
+    /// Parses the provided behavioral and rendering options and prompts
+    /// the CLI user for input according to the defined rules.
+    ///
+    /// This method is intended for flows where the user skipping/cancelling
+    /// the prompt - by pressing ESC - is considered normal behavior. In this case,
+    /// it does not return `Err(InquireError::OperationCanceled)`, but `Ok(None)`.
+    ///
+    /// Meanwhile, if the user does submit an answer, the method wraps the return
+    /// type with `Some`.
+    pub fn prompt_skippable(self) -> InquireResult<Option<T>> {
+        match self.prompt() {
+            Ok(answer) => Ok(Some(answer)),
+            Err(InquireError::OperationCanceled) => Ok(None),
+            Err(err) => Err(err),
+        }
+    }
+
+    /// Parses the provided behavioral and rendering options and prompts
+    /// the CLI user for input according to the defined rules.
+    pub fn prompt(self) -> InquireResult<T> {
+        let (input_reader, terminal) = get_default_terminal()?;
+        let mut backend = Backend::new(input_reader, terminal, self.render_config)?;
+        self.prompt_with_backend(&mut backend)
+    }
+
+    pub(crate) fn prompt_with_backend<B: CustomTypeBackend>(
+        self,
+        backend: &mut B,
+    ) -> InquireResult<T> {
+        NumberPrompt::new(self)?.prompt(backend)
+    }
+}
@@ -0,0 +1,240 @@
+use crate::{
+    error::InquireResult,
+    formatter::CustomTypeFormatter,
+    input::Input,
+    parser::CustomTypeParser,
+    prompts::prompt::{ActionResult, Prompt},
+    ui::CustomTypeBackend,
+    validator::{CustomTypeValidator, ErrorMessage, Validation},
+    InquireError, Number,
+};
+
+use super::{action::NumberPromptAction, config::NumberConfig, Steppable};
+
+pub struct NumberPrompt<'a, T> {
+    message: &'a str,
+    config: NumberConfig,
+    error: Option<ErrorMessage>,
+    help_message: Option<&'a str>,
+    default: Option<T>,
+    step: T,
+    min: Option<T>,
+    max: Option<T>,
+    input: Input,
+    formatter: CustomTypeFormatter<'a, T>,
+    default_value_formatter: CustomTypeFormatter<'a, T>,
+    validators: Vec<Box<dyn CustomTypeValidator<T>>>,
+    parser: CustomTypeParser<'a, T>,
+    error_message: String,
+}
+
+impl<'a, T> NumberPrompt<'a, T>
+where
+    T: Steppable + ToString,
+{
+    pub fn new(no: Number<'a, T>) -> InquireResult<Self> {
+        if no.step.partial_cmp(&T::ZERO) != Some(std::cmp::Ordering::Greater) {
+            return Err(InquireError::InvalidConfiguration(
+                "Step must be greater than zero".into(),
+            ));
+        }
+
+        if let (Some(min), Some(max)) = (no.min, no.max) {
+            if min > max {
+                return Err(InquireError::InvalidConfiguration(
+                    "Min value can not be greater than max value".into(),
+                ));
+            }
+        }
+
+        if let Some(default) = no.default {
+            let below_min = no.min.map(|min| default < min).unwrap_or(false);
+            let above_max = no.max.map(|max| default > max).unwrap_or(false);
+
+            if below_min || above_max {
+                return Err(InquireError::InvalidConfiguration(
+                    "Default value must be within the min and max bounds".into(),
+                ));
+            }
+        }
+
+        let input = Input::new_with(no.starting_input.unwrap_or_default());
+        let input = if let Some(placeholder) = no.placeholder {
+            input.with_placeholder(placeholder)
+        } else {
+            input
+        };
+
+        Ok(Self {
+            message: no.message,
+            config: (&no).into(),
+            error: None,
+            default: no.default,
+            step: no.step,
+            min: no.min,
+            max: no.max,
+            help_message: no.help_message,
+            formatter: no.formatter,
+            default_value_formatter: no.default_value_formatter,
+            validators: no.validators,
+            parser: no.parser,
+            input,
+            error_message: no.error_message,
+        })
+    }
+
+    /// Value used as the base when stepping, the parsed input or,
+    /// when the input is empty, the first of default, min, max or zero.
+    fn current_value(&self) -> Option<T> {
+        if self.input.content().is_empty() {
+            let value = self.default.or(self.min).or(self.max).unwrap_or(T::ZERO);
+            return Some(value);
+        }
+
+        (self.parser)(self.input.content()).ok()
+    }
+
+    fn clamp(&self, value: T) -> T {
+        match (self.min, self.max) {
+            (Some(min), _) if value < min => min,
+            (_, Some(max)) if value > max => max,
+            _ => value,
+        }
+    }
+
+    fn step_value(&mut self, increment: bool) -> ActionResult {
+        let value = match self.current_value() {
+            Some(value) => value,
+            None => return ActionResult::Clean,
+        };
+
+        let new_value = match increment {
+            true => value.step_up(self.step),
+            false => value.step_down(self.step),
+        };
+        let new_value = self.clamp(new_value).to_string();
+
+        if new_value == self.input.content() {
+            return ActionResult::Clean;
+        }
+
+        self.input = Input::new_with(new_value);
+        ActionResult::NeedsRedraw
+    }
+
+    fn validate_current_answer(&self, value: &T) -> InquireResult<Validation> {
+        if let Some(min) = self.min {
+            if *value < min {
+                return Ok(Validation::Invalid(
+                    format!(
+                        "Value must be greater than or equal to {}",
+                        (self.default_value_formatter)(min)
+                    )
+                    .into(),
+                ));
+            }
+        }
+
+        if let Some(max) = self.max {
+            if *value > max {
+                return Ok(Validation::Invalid(
+                    format!(
+                        "Value must be less than or equal to {}",
+                        (self.default_value_formatter)(max)
+                    )
+                    .into(),
+                ));
+            }
+        }
+
+        for validator in &self.validators {
+            match validator.validate(value) {
+                Ok(Validation::Valid) => {}
+                Ok(Validation::Invalid(msg)) => return Ok(Validation::Invalid(msg)),
+                Err(err) => return Err(InquireError::Custom(err)),
+            }
+        }
+
+        Ok(Validation::Valid)
+    }
+
+    fn get_final_answer(&self) -> Result<T, String> {
+        match self.default {
+            Some(val) if self.input.content().is_empty() => return Ok(val),
+            _ => {}
+        }
+
+        match (self.parser)(self.input.content()) {
+            Ok(val) => Ok(val),
+            Err(_) => Err(self.error_message.clone()),
+        }
+    }
+}
+
+impl<'a, Backend, T> Prompt<Backend> for NumberPrompt<'a, T>
+where
+    Backend: CustomTypeBackend,
+    T: Steppable + ToString,
+{
+    type Config = NumberConfig;
+    type InnerAction = NumberPromptAction;
+    type Output = T;
+
+    fn message(&self) -> &str {
+        self.message
+    }
+
+    fn config(&self) -> &NumberConfig {
+        &self.config
+    }
+
+    fn format_answer(&self, answer: &T) -> String {
+        (self.formatter)(*answer)
+    }
+
+    fn submit(&mut self) -> InquireResult<Option<T>> {
+        let answer = match self.get_final_answer() {
+            Ok(answer) => match self.validate_current_answer(&answer)? {
+                Validation::Valid => Some(answer),
+                Validation::Invalid(msg) => {
+                    self.error = Some(msg);
+                    None
+                }
+            },
+            Err(message) => {
+                self.error = Some(message.into());
+                None
+            }
+        };
+
+        Ok(answer)
+    }
+
+    fn handle(&mut self, action: NumberPromptAction) -> InquireResult<ActionResult> {
+        let result = match action {
+            NumberPromptAction::ValueInput(input_action) => self.input.handle(input_action).into(),
+            NumberPromptAction::Increment => self.step_value(true),
+            NumberPromptAction::Decrement => self.step_value(false),
+        };
+
+        Ok(result)
+    }
+
+    fn render(&self, backend: &mut Backend) -> InquireResult<()> {
+        let prompt = &self.message;
+
+        if let Some(error) = &self.error {
+            backend.render_error_message(error)?;
+        }
+
+        let default_message = self.default.map(self.default_value_formatter);
+
+        backend.render_prompt(prompt, default_message.as_deref(), &self.input)?;
+
+        if let Some(message) = self.help_message {
+            backend.render_help_message(message)?;
+        }
+
+        Ok(())
+    }
+}
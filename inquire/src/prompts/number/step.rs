@@ -0,0 +1,129 @@
+/// Numeric types that can be incremented and decremented by [`Number`](crate::Number) prompts.
+///
+/// Implementations are provided for all primitive integer and floating point types.
+/// Integer implementations saturate at the numeric bounds of the type instead of
+/// overflowing, while floating point implementations round the result to the
+/// precision of the step, so that stepping `0.1` three times results in `0.3`.
+pub trait Steppable: Copy + PartialOrd {
+    /// Default step used when none is provided, i.e. `1`.
+    const DEFAULT_STEP: Self;
+
+    /// Zero value of the type, used as the starting point when there
+    /// is nothing else to step from.
+    const ZERO: Self;
+
+    /// Returns the value incremented by `step`.
+    fn step_up(self, step: Self) -> Self;
+
+    /// Returns the value decremented by `step`.
+    fn step_down(self, step: Self) -> Self;
+
+    /// Returns the value as a `f64`, possibly losing precision.
+    ///
+    /// Used to calculate proportions between values, such as the position
+    /// of the handle in a [`Slider`](crate::Slider) prompt.
+    fn to_f64(self) -> f64;
+}
+
+macro_rules! impl_steppable_for_int {
+    ($($t:ty),*) => {
+        $(
+            impl Steppable for $t {
+                const DEFAULT_STEP: Self = 1;
+                const ZERO: Self = 0;
+
+                fn step_up(self, step: Self) -> Self {
+                    self.saturating_add(step)
+                }
+
+                fn step_down(self, step: Self) -> Self {
+                    self.saturating_sub(step)
+                }
+
+                fn to_f64(self) -> f64 {
+                    self as f64
+                }
+            }
+        )*
+    };
+}
+
+impl_steppable_for_int!(i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize);
+
+impl Steppable for f32 {
+    const DEFAULT_STEP: Self = 1.0;
+    const ZERO: Self = 0.0;
+
+    fn step_up(self, step: Self) -> Self {
+        round_to_step_precision(f64::from(self + step), &step.to_string()) as f32
+    }
+
+    fn step_down(self, step: Self) -> Self {
+        round_to_step_precision(f64::from(self - step), &step.to_string()) as f32
+    }
+
+    fn to_f64(self) -> f64 {
+        f64::from(self)
+    }
+}
+
+impl Steppable for f64 {
+    const DEFAULT_STEP: Self = 1.0;
+    const ZERO: Self = 0.0;
+
+    fn step_up(self, step: Self) -> Self {
+        round_to_step_precision(self + step, &step.to_string())
+    }
+
+    fn step_down(self, step: Self) -> Self {
+        round_to_step_precision(self - step, &step.to_string())
+    }
+
+    fn to_f64(self) -> f64 {
+        self
+    }
+}
+
+/// Rounds `value` to the same amount of decimal places of the string
+/// representation of the step, getting rid of floating point artifacts
+/// such as `0.30000000000000004`.
+fn round_to_step_precision(value: f64, step: &str) -> f64 {
+    let decimals = step
+        .split_once('.')
+        .map(|(_, decimals)| decimals.len())
+        .unwrap_or(0)
+        .min(15);
+
+    let factor = 10f64.powi(decimals as i32);
+    let rounded = (value * factor).round() / factor;
+
+    if rounded.is_finite() {
+        rounded
+    } else {
+        value
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::Steppable;
+
+    #[test]
+    fn integers_saturate_at_bounds() {
+        assert_eq!(255u8, 250u8.step_up(10));
+        assert_eq!(0u8, 5u8.step_down(10));
+        assert_eq!(i32::MIN, (i32::MIN + 1).step_down(5));
+    }
+
+    #[test]
+    fn floats_are_rounded_to_step_precision() {
+        let mut value = 0.0f64;
+        for _ in 0..3 {
+            value = value.step_up(0.1);
+        }
+        assert_eq!("0.3", value.to_string());
+
+        assert_eq!("1.25", 1.5f32.step_down(0.25).to_string());
+        assert_eq!("3", 2.0f64.step_up(1.0).to_string());
+    }
+}
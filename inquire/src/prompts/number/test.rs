@@ -0,0 +1,111 @@
+use crate::{
+    error::InquireResult,
+    ui::{
+        test::{FakeBackend, Token},
+        Key, KeyModifiers,
+    },
+    validator::ErrorMessage,
+    InquireError, Number,
+};
+
+#[test]
+fn typed_number_is_parsed() -> InquireResult<()> {
+    let mut keys = Key::char_keys_from_str("42");
+    keys.push(Key::Enter);
+    let mut backend = FakeBackend::new(keys);
+
+    let ans = Number::<u32>::new("Question").prompt_with_backend(&mut backend)?;
+
+    assert_eq!(42, ans);
+
+    Ok(())
+}
+
+#[test]
+fn arrows_step_from_default_value() -> InquireResult<()> {
+    let mut backend = FakeBackend::new(vec![
+        Key::Up(KeyModifiers::NONE),
+        Key::Up(KeyModifiers::NONE),
+        Key::Down(KeyModifiers::NONE),
+        Key::Enter,
+    ]);
+
+    let ans = Number::<i32>::new("Question")
+        .with_default(10)
+        .with_step(5)
+        .prompt_with_backend(&mut backend)?;
+
+    assert_eq!(15, ans);
+
+    Ok(())
+}
+
+#[test]
+fn arrows_step_from_typed_value() -> InquireResult<()> {
+    let mut keys = Key::char_keys_from_str("1.5");
+    keys.push(Key::Up(KeyModifiers::NONE));
+    keys.push(Key::Enter);
+    let mut backend = FakeBackend::new(keys);
+
+    let ans = Number::<f64>::new("Question")
+        .with_step(0.1)
+        .prompt_with_backend(&mut backend)?;
+
+    assert_eq!("1.6", ans.to_string());
+
+    Ok(())
+}
+
+#[test]
+fn stepping_is_clamped_to_bounds() -> InquireResult<()> {
+    let mut backend = FakeBackend::new(vec![
+        Key::Down(KeyModifiers::NONE),
+        Key::Down(KeyModifiers::NONE),
+        Key::Down(KeyModifiers::NONE),
+        Key::Enter,
+    ]);
+
+    let ans = Number::<u8>::new("Question")
+        .with_min(3)
+        .with_max(10)
+        .with_default(5)
+        .prompt_with_backend(&mut backend)?;
+
+    assert_eq!(3, ans);
+
+    Ok(())
+}
+
+#[test]
+fn out_of_bounds_input_is_rejected() -> InquireResult<()> {
+    let mut keys = Key::char_keys_from_str("11");
+    keys.push(Key::Enter);
+    keys.push(Key::Backspace);
+    keys.push(Key::Enter);
+    let mut backend = FakeBackend::new(keys);
+
+    let ans = Number::<u8>::new("Question")
+        .with_max(10)
+        .prompt_with_backend(&mut backend)?;
+
+    assert_eq!(1, ans);
+    assert!(
+        backend.frames()[3].has_token(&Token::ErrorMessage(ErrorMessage::Custom(
+            "Value must be less than or equal to 10".into()
+        )))
+    );
+
+    Ok(())
+}
+
+#[test]
+fn min_greater_than_max_is_invalid_configuration() {
+    let mut backend = FakeBackend::new(vec![Key::Enter]);
+
+    let ans = Number::<u8>::new("Question")
+        .with_min(10)
+        .with_max(3)
+        .prompt_with_backend(&mut backend);
+
+    assert!(matches!(ans, Err(InquireError::InvalidConfiguration(_))));
+}
@@ -0,0 +1,40 @@
+use crate::{
+    ui::{Key, KeyModifiers},
+    InnerAction, InputAction,
+};
+
+use super::config::NumberRangeConfig;
+
+/// Set of actions for a NumberRangePrompt.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum NumberRangePromptAction {
+    /// Action on the text input handler of the focused field.
+    ValueInput(InputAction),
+    /// Increments the value of the focused field by one step.
+    Increment,
+    /// Decrements the value of the focused field by one step.
+    Decrement,
+    /// Moves the focus between the lower and upper bound fields.
+    ToggleFocus,
+}
+
+impl InnerAction for NumberRangePromptAction {
+    type Config = NumberRangeConfig;
+
+    fn from_key(key: Key, _config: &NumberRangeConfig) -> Option<Self> {
+        let action = match key {
+            Key::Tab => Self::ToggleFocus,
+            Key::Up(KeyModifiers::NONE) | Key::Char('p', KeyModifiers::CONTROL) => Self::Increment,
+            Key::Down(KeyModifiers::NONE) | Key::Char('n', KeyModifiers::CONTROL) => {
+                Self::Decrement
+            }
+
+            key => match InputAction::from_key(key, &()) {
+                Some(action) => Self::ValueInput(action),
+                None => return None,
+            },
+        };
+
+        Some(action)
+    }
+}
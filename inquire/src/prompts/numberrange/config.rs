@@ -0,0 +1,10 @@
+use crate::NumberRange;
+
+/// Configuration settings used in the execution of a NumberRangePrompt.
+pub struct NumberRangeConfig {}
+
+impl<T> From<&NumberRange<'_, T>> for NumberRangeConfig {
+    fn from(_value: &NumberRange<'_, T>) -> Self {
+        Self {}
+    }
+}
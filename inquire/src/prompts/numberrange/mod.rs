@@ -0,0 +1,296 @@
+mod action;
+mod config;
+mod prompt;
+#[cfg(test)]
+mod test;
+
+pub use action::*;
+
+use std::str::FromStr;
+
+use crate::{
+    config::get_configuration,
+    error::{InquireError, InquireResult},
+    formatter::CustomTypeFormatter,
+    parser::CustomTypeParser,
+    prompts::prompt::Prompt,
+    terminal::get_default_terminal,
+    ui::{Backend, NumberRangeBackend, RenderConfig},
+    validator::CustomTypeValidator,
+    Steppable,
+};
+
+use self::prompt::NumberRangePrompt;
+
+/// Prompt suitable for collecting a numeric range in a single interaction, such as a price filter.
+///
+/// The prompt displays two linked fields, one for the lower bound and one for the upper bound of the range. The user switches between them with Tab and, as in a [`Number`](crate::Number) prompt, can either type the values directly or step through them with the Up and Down arrows.
+///
+/// On submission, both values are parsed and the prompt checks that the lower bound is not greater than the upper bound, and that both are within the allowed min and max values, if any. Pressing enter while the upper bound is still empty moves the focus to it instead.
+///
+/// The answer is returned as a `(lower, upper)` tuple.
+///
+/// Number range prompts provide several options of configuration:
+///
+/// - **Prompt message**: Required when creating the prompt.
+/// - **Default value**: Default range, whose bounds are used when the corresponding field is empty.
+/// - **Step**: Amount added or subtracted when pressing the Up or Down arrows. Defaults to `1`.
+/// - **Min and max values**: Inclusive bounds of the accepted values, for both fields. Stepping is clamped to these bounds and typed values outside of them are rejected on submission.
+/// - **Placeholders**: Short hints displayed in the empty fields, `min` and `max` by default.
+/// - **Help message**: Message displayed at the line below the prompt.
+/// - **Formatter**: Custom formatter in case you need to pre-process the user input before showing it as the final answer.
+///   - Prints both bounds separated by a dash by default.
+/// - **Parser**: Custom parser for the values of each field, `str::parse` by default.
+/// - **Validators**: Custom validators to the parsed range, displaying an error message if the range does not pass the requirements.
+/// - **Error message**: Error message to display when a value could not be parsed from a field.
+///
+/// # Example
+///
+/// ```no_run
+/// use inquire::NumberRange;
+///
+/// let range = NumberRange::<u32>::new("Price range:")
+///     .with_min(0)
+///     .with_max(10_000)
+///     .with_step(50)
+///     .prompt();
+///
+/// match range {
+///     Ok((lower, upper)) => println!("Looking for prices between {} and {}", lower, upper),
+///     Err(_) => println!("Could not read the price range"),
+/// }
+/// ```
+#[derive(Clone)]
+pub struct NumberRange<'a, T> {
+    /// Message to be presented to the user.
+    pub message: &'a str,
+
+    /// Default range, whose bounds are used when the corresponding field is empty.
+    pub default: Option<(T, T)>,
+
+    /// Amount added or subtracted from the focused value when the user presses the Up or Down arrows.
+    pub step: T,
+
+    /// Minimum value (inclusive) accepted by the prompt.
+    pub min: Option<T>,
+
+    /// Maximum value (inclusive) accepted by the prompt.
+    pub max: Option<T>,
+
+    /// Short hints displayed in the empty lower and upper bound fields.
+    pub placeholders: (&'a str, &'a str),
+
+    /// Help message to be presented to the user.
+    pub help_message: Option<&'a str>,
+
+    /// Function that formats the user input and presents it to the user as the final rendering of the prompt.
+    pub formatter: CustomTypeFormatter<'a, (T, T)>,
+
+    /// Function that formats the default value and the min/max bounds when displayed to the user.
+    pub default_value_formatter: CustomTypeFormatter<'a, T>,
+
+    /// Function that parses the value of each field.
+    pub parser: CustomTypeParser<'a, T>,
+
+    /// Collection of validators to apply to the parsed range.
+    ///
+    /// Validators are executed in the order they are stored, stopping at and displaying to the user
+    /// only the first validation error that might appear.
+    ///
+    /// The possible error is displayed to the user one line above the prompt.
+    pub validators: Vec<Box<dyn CustomTypeValidator<(T, T)>>>,
+
+    /// Error message displayed when a value could not be parsed from a field.
+    pub error_message: String,
+
+    /// RenderConfig to apply to the rendered interface.
+    ///
+    /// Note: The default render config considers if the NO_COLOR environment variable
+    /// is set to decide whether to render the colored config or the empty one.
+    ///
+    /// When overriding the config in a prompt, NO_COLOR is no longer considered and your
+    /// config is treated as the only source of truth. If you want to customize colors
+    /// and still support NO_COLOR, you will have to do this on your end.
+    pub render_config: RenderConfig<'a>,
+}
+
+impl<'a, T> NumberRange<'a, T>
+where
+    T: Steppable + FromStr + ToString,
+{
+    /// Default validators added to the [NumberRange] prompt, none.
+    pub const DEFAULT_VALIDATORS: Vec<Box<dyn CustomTypeValidator<(T, T)>>> = vec![];
+
+    /// Default placeholders of the lower and upper bound fields.
+    pub const DEFAULT_PLACEHOLDERS: (&'a str, &'a str) = ("min", "max");
+
+    /// Default help message.
+    pub const DEFAULT_HELP_MESSAGE: Option<&'a str> =
+        Some("tab to switch fields, ↑↓ to change the value");
+
+    /// Default error message displayed when parsing fails.
+    pub const DEFAULT_ERROR_MESSAGE: &'a str = "Please type a valid number";
+
+    /// Creates a [NumberRange] with the provided message and default configuration values.
+    pub fn new(message: &'a str) -> Self {
+        Self {
+            message,
+            default: None,
+            step: T::DEFAULT_STEP,
+            min: None,
+            max: None,
+            placeholders: Self::DEFAULT_PLACEHOLDERS,
+            help_message: Self::DEFAULT_HELP_MESSAGE,
+            formatter: &|(lower, upper)| format!("{} - {}", lower.to_string(), upper.to_string()),
+            default_value_formatter: &|val| val.to_string(),
+            parser: &|a| a.parse::<T>().map_err(|_e| ()),
+            validators: Self::DEFAULT_VALIDATORS,
+            error_message: String::from(Self::DEFAULT_ERROR_MESSAGE),
+            render_config: get_configuration(),
+        }
+    }
+
+    /// Sets the default range.
+    pub fn with_default(mut self, lower: T, upper: T) -> Self {
+        self.default = Some((lower, upper));
+        self
+    }
+
+    /// Sets the step used when incrementing or decrementing the values.
+    pub fn with_step(mut self, step: T) -> Self {
+        self.step = step;
+        self
+    }
+
+    /// Sets the minimum value (inclusive).
+    pub fn with_min(mut self, min: T) -> Self {
+        self.min = Some(min);
+        self
+    }
+
+    /// Sets the maximum value (inclusive).
+    pub fn with_max(mut self, max: T) -> Self {
+        self.max = Some(max);
+        self
+    }
+
+    /// Sets the placeholders of the lower and upper bound fields.
+    pub fn with_placeholders(mut self, lower: &'a str, upper: &'a str) -> Self {
+        self.placeholders = (lower, upper);
+        self
+    }
+
+    /// Sets the help message of the prompt.
+    pub fn with_help_message(mut self, message: &'a str) -> Self {
+        self.help_message = Some(message);
+        self
+    }
+
+    /// Removes the set help message.
+    pub fn without_help_message(mut self) -> Self {
+        self.help_message = None;
+        self
+    }
+
+    /// Sets the formatter.
+    pub fn with_formatter(mut self, formatter: CustomTypeFormatter<'a, (T, T)>) -> Self {
+        self.formatter = formatter;
+        self
+    }
+
+    /// Sets the formatter for default values and bounds.
+    pub fn with_default_value_formatter(mut self, formatter: CustomTypeFormatter<'a, T>) -> Self {
+        self.default_value_formatter = formatter;
+        self
+    }
+
+    /// Sets the parser.
+    pub fn with_parser(mut self, parser: CustomTypeParser<'a, T>) -> Self {
+        self.parser = parser;
+        self
+    }
+
+    /// Adds a validator to the collection of validators. You might want to use this feature
+    /// in case you need to require certain features from the parsed range.
+    ///
+    /// Validators are executed in the order they are stored, stopping at and displaying to the user
+    /// only the first validation error that might appear.
+    ///
+    /// The possible error is displayed to the user one line above the prompt.
+    pub fn with_validator<V>(mut self, validator: V) -> Self
+    where
+        V: CustomTypeValidator<(T, T)> + 'static,
+    {
+        self.validators.push(Box::new(validator));
+        self
+    }
+
+    /// Adds the validators to the collection of validators in the order they are given.
+    /// You might want to use this feature in case you need to require certain features
+    /// from the parsed range.
+    ///
+    /// Validators are executed in the order they are stored, stopping at and displaying to the user
+    /// only the first validation error that might appear.
+    ///
+    /// The possible error is displayed to the user one line above the prompt.
+    pub fn with_validators(mut self, validators: &[Box<dyn CustomTypeValidator<(T, T)>>]) -> Self {
+        for validator in validators {
+            #[allow(suspicious_double_ref_op)]
+            self.validators.push(validator.clone());
+        }
+        self
+    }
+
+    /// Sets a custom error message displayed when a field could not be parsed to a value.
+    pub fn with_error_message(mut self, error_message: &'a str) -> Self {
+        self.error_message = String::from(error_message);
+        self
+    }
+
+    /// Sets the provided color theme to this prompt.
+    ///
+    /// Note: The default render config considers if the NO_COLOR environment variable
+    /// is set to decide whether to render the colored config or the empty one.
+    ///
+    /// When overriding the config in a prompt, NO_COLOR is no longer considered and your
+    /// config is treated as the only source of truth. If you want to customize colors
+    /// and still support NO_COLOR, you will have to do this on your end.
+    pub fn with_render_config(mut self, render_config: RenderConfig<'a>) -> Self {
+        self.render_config = render_config;
+        self
+    }
+
+    /// Parses the provided behavioral and rendering options and prompts
+    /// the CLI user for input according to the defined rules.
+    ///
+    /// This method is intended for flows where the user skipping/cancelling
+    /// the prompt - by pressing ESC - is considered normal behavior. In this case,
+    /// it does not return `Err(InquireError::OperationCanceled)`, but `Ok(None)`.
+    ///
+    /// Meanwhile, if the user does submit an answer, the method wraps the return
+    /// type with `Some`.
+    pub fn prompt_skippable(self) -> InquireResult<Option<(T, T)>> {
+        match self.prompt() {
+            Ok(answer) => Ok(Some(answer)),
+            Err(InquireError::OperationCanceled) => Ok(None),
+            Err(err) => Err(err),
+        }
+    }
+
+    /// Parses the provided behavioral and rendering options and prompts
+    /// the CLI user for input according to the defined rules.
+    ///
+    /// Returns the `(lower, upper)` bounds of the range.
+    pub fn prompt(self) -> InquireResult<(T, T)> {
+        let (input_reader, terminal) = get_default_terminal()?;
+        let mut backend = Backend::new(input_reader, terminal, self.render_config)?;
+        self.prompt_with_backend(&mut backend)
+    }
+
+    pub(crate) fn prompt_with_backend<B: NumberRangeBackend>(
+        self,
+        backend: &mut B,
+    ) -> InquireResult<(T, T)> {
+        NumberRangePrompt::new(self)?.prompt(backend)
+    }
+}
@@ -0,0 +1,332 @@
+use crate::{
+    error::InquireResult,
+    formatter::CustomTypeFormatter,
+    input::Input,
+    parser::CustomTypeParser,
+    prompts::prompt::{ActionResult, Prompt},
+    ui::NumberRangeBackend,
+    validator::{CustomTypeValidator, ErrorMessage, Validation},
+    InquireError, NumberRange, Steppable,
+};
+
+use super::{action::NumberRangePromptAction, config::NumberRangeConfig};
+
+pub struct NumberRangePrompt<'a, T> {
+    message: &'a str,
+    config: NumberRangeConfig,
+    error: Option<ErrorMessage>,
+    help_message: Option<&'a str>,
+    default: Option<(T, T)>,
+    step: T,
+    min: Option<T>,
+    max: Option<T>,
+    lower: Input,
+    upper: Input,
+    upper_focused: bool,
+    formatter: CustomTypeFormatter<'a, (T, T)>,
+    default_value_formatter: CustomTypeFormatter<'a, T>,
+    validators: Vec<Box<dyn CustomTypeValidator<(T, T)>>>,
+    parser: CustomTypeParser<'a, T>,
+    error_message: String,
+}
+
+impl<'a, T> NumberRangePrompt<'a, T>
+where
+    T: Steppable + ToString,
+{
+    pub fn new(nr: NumberRange<'a, T>) -> InquireResult<Self> {
+        if nr.step.partial_cmp(&T::ZERO) != Some(std::cmp::Ordering::Greater) {
+            return Err(InquireError::InvalidConfiguration(
+                "Step must be greater than zero".into(),
+            ));
+        }
+
+        if let (Some(min), Some(max)) = (nr.min, nr.max) {
+            if min > max {
+                return Err(InquireError::InvalidConfiguration(
+                    "Min value can not be greater than max value".into(),
+                ));
+            }
+        }
+
+        if let Some((lower, upper)) = nr.default {
+            let below_min = nr.min.map(|min| lower < min).unwrap_or(false);
+            let above_max = nr.max.map(|max| upper > max).unwrap_or(false);
+
+            if lower > upper || below_min || above_max {
+                return Err(InquireError::InvalidConfiguration(
+                    "Default range must be ordered and within the min and max bounds".into(),
+                ));
+            }
+        }
+
+        let (lower_placeholder, upper_placeholder) = nr.placeholders;
+
+        Ok(Self {
+            message: nr.message,
+            config: (&nr).into(),
+            error: None,
+            help_message: nr.help_message,
+            default: nr.default,
+            step: nr.step,
+            min: nr.min,
+            max: nr.max,
+            lower: Input::new().with_placeholder(lower_placeholder),
+            upper: Input::new().with_placeholder(upper_placeholder),
+            upper_focused: false,
+            formatter: nr.formatter,
+            default_value_formatter: nr.default_value_formatter,
+            validators: nr.validators,
+            parser: nr.parser,
+            error_message: nr.error_message,
+        })
+    }
+
+    fn focused_input(&mut self) -> &mut Input {
+        match self.upper_focused {
+            true => &mut self.upper,
+            false => &mut self.lower,
+        }
+    }
+
+    fn field(&self, upper: bool) -> (&Input, Option<T>) {
+        match upper {
+            true => (&self.upper, self.default.map(|(_, upper)| upper)),
+            false => (&self.lower, self.default.map(|(lower, _)| lower)),
+        }
+    }
+
+    /// Value of a field, falling back to the bound of the default range
+    /// when it is empty.
+    fn field_value(&self, upper: bool) -> Option<T> {
+        let (input, default) = self.field(upper);
+
+        match input.content().is_empty() {
+            true => default,
+            false => (self.parser)(input.content()).ok(),
+        }
+    }
+
+    /// Value used as the base when stepping the focused field. When the field
+    /// and the default range are both empty, the lower field starts from the
+    /// min, max or zero, and the upper field from the lower value, max, min or zero.
+    fn current_value(&self) -> Option<T> {
+        let (input, default) = self.field(self.upper_focused);
+
+        if !input.content().is_empty() || default.is_some() {
+            return self.field_value(self.upper_focused);
+        }
+
+        let value = match self.upper_focused {
+            false => self.min.or(self.max).unwrap_or(T::ZERO),
+            true => self
+                .field_value(false)
+                .or(self.max)
+                .or(self.min)
+                .unwrap_or(T::ZERO),
+        };
+
+        Some(value)
+    }
+
+    /// Clamps the value of the focused field to the min and max bounds and
+    /// to the value of the other field, keeping the range ordered.
+    fn clamp(&self, value: T) -> T {
+        let (min, max) = match self.upper_focused {
+            true => (self.field_value(false).or(self.min), self.max),
+            false => (self.min, self.field_value(true).or(self.max)),
+        };
+
+        match (min, max) {
+            (Some(min), _) if value < min => min,
+            (_, Some(max)) if value > max => max,
+            _ => value,
+        }
+    }
+
+    fn step_value(&mut self, increment: bool) -> ActionResult {
+        let value = match self.current_value() {
+            Some(value) => value,
+            None => return ActionResult::Clean,
+        };
+
+        let new_value = match increment {
+            true => value.step_up(self.step),
+            false => value.step_down(self.step),
+        };
+        let new_value = self.clamp(new_value).to_string();
+
+        let input = self.focused_input();
+        if new_value == input.content() {
+            return ActionResult::Clean;
+        }
+
+        let placeholder = input.placeholder().map(String::from);
+        let mut new_input = Input::new_with(new_value);
+        if let Some(placeholder) = placeholder {
+            new_input = new_input.with_placeholder(&placeholder);
+        }
+        *input = new_input;
+
+        ActionResult::NeedsRedraw
+    }
+
+    fn get_field_value(&self, upper: bool) -> Result<T, String> {
+        match self.field_value(upper) {
+            Some(val) => Ok(val),
+            None => Err(self.error_message.clone()),
+        }
+    }
+
+    fn validate_bounds(&self, value: T) -> Option<String> {
+        if let Some(min) = self.min {
+            if value < min {
+                return Some(format!(
+                    "Values must be greater than or equal to {}",
+                    (self.default_value_formatter)(min)
+                ));
+            }
+        }
+
+        if let Some(max) = self.max {
+            if value > max {
+                return Some(format!(
+                    "Values must be less than or equal to {}",
+                    (self.default_value_formatter)(max)
+                ));
+            }
+        }
+
+        None
+    }
+
+    /// Parses and validates both fields, moving the focus to the
+    /// field that caused the error, if any.
+    fn get_final_answer(&mut self) -> Result<(T, T), String> {
+        let mut values = [T::ZERO; 2];
+
+        for (idx, upper) in [false, true].iter().copied().enumerate() {
+            let value =
+                self.get_field_value(upper)
+                    .and_then(|value| match self.validate_bounds(value) {
+                        Some(message) => Err(message),
+                        None => Ok(value),
+                    });
+
+            match value {
+                Ok(value) => values[idx] = value,
+                Err(message) => {
+                    self.upper_focused = upper;
+                    return Err(message);
+                }
+            }
+        }
+
+        let [lower, upper] = values;
+
+        if lower > upper {
+            return Err("The lower bound must be less than or equal to the upper bound".into());
+        }
+
+        Ok((lower, upper))
+    }
+
+    fn validate_current_answer(&self, value: &(T, T)) -> InquireResult<Validation> {
+        for validator in &self.validators {
+            match validator.validate(value) {
+                Ok(Validation::Valid) => {}
+                Ok(Validation::Invalid(msg)) => return Ok(Validation::Invalid(msg)),
+                Err(err) => return Err(InquireError::Custom(err)),
+            }
+        }
+
+        Ok(Validation::Valid)
+    }
+}
+
+impl<'a, Backend, T> Prompt<Backend> for NumberRangePrompt<'a, T>
+where
+    Backend: NumberRangeBackend,
+    T: Steppable + ToString,
+{
+    type Config = NumberRangeConfig;
+    type InnerAction = NumberRangePromptAction;
+    type Output = (T, T);
+
+    fn message(&self) -> &str {
+        self.message
+    }
+
+    fn config(&self) -> &NumberRangeConfig {
+        &self.config
+    }
+
+    fn format_answer(&self, answer: &(T, T)) -> String {
+        (self.formatter)(*answer)
+    }
+
+    fn submit(&mut self) -> InquireResult<Option<(T, T)>> {
+        // enter on the lower bound moves on to the upper bound, unless it
+        // can already be submitted
+        if !self.upper_focused && self.upper.is_empty() && self.default.is_none() {
+            self.upper_focused = true;
+            return Ok(None);
+        }
+
+        let answer = match self.get_final_answer() {
+            Ok(answer) => match self.validate_current_answer(&answer)? {
+                Validation::Valid => Some(answer),
+                Validation::Invalid(msg) => {
+                    self.error = Some(msg);
+                    None
+                }
+            },
+            Err(message) => {
+                self.error = Some(message.into());
+                None
+            }
+        };
+
+        Ok(answer)
+    }
+
+    fn handle(&mut self, action: NumberRangePromptAction) -> InquireResult<ActionResult> {
+        let result = match action {
+            NumberRangePromptAction::ValueInput(input_action) => {
+                self.focused_input().handle(input_action).into()
+            }
+            NumberRangePromptAction::Increment => self.step_value(true),
+            NumberRangePromptAction::Decrement => self.step_value(false),
+            NumberRangePromptAction::ToggleFocus => {
+                self.upper_focused = !self.upper_focused;
+                ActionResult::NeedsRedraw
+            }
+        };
+
+        Ok(result)
+    }
+
+    fn render(&self, backend: &mut Backend) -> InquireResult<()> {
+        let prompt = &self.message;
+
+        if let Some(error) = &self.error {
+            backend.render_error_message(error)?;
+        }
+
+        let default_message = self.default.map(self.formatter);
+
+        backend.render_number_range_prompt(
+            prompt,
+            default_message.as_deref(),
+            &self.lower,
+            &self.upper,
+            self.upper_focused,
+        )?;
+
+        if let Some(message) = self.help_message {
+            backend.render_help_message(message)?;
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,140 @@
+use crate::{
+    error::InquireResult,
+    ui::{
+        test::{FakeBackend, Token},
+        Key, KeyModifiers,
+    },
+    validator::{ErrorMessage, Validation},
+    InquireError, NumberRange,
+};
+
+#[test]
+fn typed_bounds_are_parsed() -> InquireResult<()> {
+    let mut keys = Key::char_keys_from_str("10");
+    keys.push(Key::Tab);
+    keys.extend(Key::char_keys_from_str("50"));
+    keys.push(Key::Enter);
+    let mut backend = FakeBackend::new(keys);
+
+    let ans = NumberRange::<u32>::new("Price").prompt_with_backend(&mut backend)?;
+
+    assert_eq!((10, 50), ans);
+    assert!(backend
+        .frames()
+        .last()
+        .unwrap()
+        .has_token(&Token::AnsweredPrompt("Price".into(), "10 - 50".into())));
+
+    Ok(())
+}
+
+#[test]
+fn enter_on_lower_bound_moves_to_upper_bound() -> InquireResult<()> {
+    let mut keys = Key::char_keys_from_str("10");
+    keys.push(Key::Enter);
+    keys.extend(Key::char_keys_from_str("50"));
+    keys.push(Key::Enter);
+    let mut backend = FakeBackend::new(keys);
+
+    let ans = NumberRange::<u32>::new("Price").prompt_with_backend(&mut backend)?;
+
+    assert_eq!((10, 50), ans);
+    assert!(backend.frames()[3].has_token(&Token::NumberRange {
+        lower: "10".into(),
+        upper: "".into(),
+        upper_focused: true,
+    }));
+
+    Ok(())
+}
+
+#[test]
+fn lower_bound_greater_than_upper_bound_is_rejected() -> InquireResult<()> {
+    let mut keys = Key::char_keys_from_str("50");
+    keys.push(Key::Tab);
+    keys.extend(Key::char_keys_from_str("10"));
+    keys.push(Key::Enter);
+    keys.push(Key::Tab);
+    keys.push(Key::Backspace);
+    keys.push(Key::Backspace);
+    keys.push(Key::Char('5', KeyModifiers::NONE));
+    keys.push(Key::Enter);
+    let mut backend = FakeBackend::new(keys);
+
+    let ans = NumberRange::<u32>::new("Price").prompt_with_backend(&mut backend)?;
+
+    assert_eq!((5, 10), ans);
+    assert!(
+        backend.frames()[6].has_token(&Token::ErrorMessage(ErrorMessage::Custom(
+            "The lower bound must be less than or equal to the upper bound".into()
+        )))
+    );
+
+    Ok(())
+}
+
+#[test]
+fn stepping_is_clamped_to_the_other_bound() -> InquireResult<()> {
+    let mut backend = FakeBackend::new(vec![
+        Key::Up(KeyModifiers::NONE),
+        Key::Up(KeyModifiers::NONE),
+        Key::Up(KeyModifiers::NONE),
+        Key::Tab,
+        Key::Down(KeyModifiers::NONE),
+        Key::Down(KeyModifiers::NONE),
+        Key::Enter,
+    ]);
+
+    let ans = NumberRange::<i32>::new("Price")
+        .with_default(0, 20)
+        .with_step(10)
+        .with_max(25)
+        .prompt_with_backend(&mut backend)?;
+
+    // the upper bound steps from its default and can not go below the lower bound
+    assert_eq!((20, 20), ans);
+
+    Ok(())
+}
+
+#[test]
+fn out_of_bounds_values_are_rejected() -> InquireResult<()> {
+    let mut keys = Key::char_keys_from_str("1");
+    keys.push(Key::Tab);
+    keys.extend(Key::char_keys_from_str("200"));
+    keys.push(Key::Enter);
+    keys.push(Key::Backspace);
+    keys.push(Key::Enter);
+    let mut backend = FakeBackend::new(keys);
+
+    let ans = NumberRange::<u8>::new("Percentage")
+        .with_max(100)
+        .with_validator(|&(lower, upper): &(u8, u8)| match upper - lower {
+            0 => Ok(Validation::Invalid("The range can not be empty".into())),
+            _ => Ok(Validation::Valid),
+        })
+        .prompt_with_backend(&mut backend)?;
+
+    assert_eq!((1, 20), ans);
+    assert!(
+        backend.frames()[6].has_token(&Token::ErrorMessage(ErrorMessage::Custom(
+            "Values must be less than or equal to 100".into()
+        )))
+    );
+
+    Ok(())
+}
+
+#[test]
+fn invalid_configurations_are_rejected() {
+    let ans = NumberRange::<u32>::new("Price")
+        .with_min(10)
+        .with_max(5)
+        .prompt_with_backend(&mut FakeBackend::new(vec![Key::Enter]));
+    assert!(matches!(ans, Err(InquireError::InvalidConfiguration(_))));
+
+    let ans = NumberRange::<u32>::new("Price")
+        .with_default(20, 10)
+        .prompt_with_backend(&mut FakeBackend::new(vec![Key::Enter]));
+    assert!(matches!(ans, Err(InquireError::InvalidConfiguration(_))));
+}
@@ -0,0 +1,69 @@
+use crate::{
+    ui::{Key, KeyModifiers},
+    InnerAction,
+};
+
+use super::config::OrderConfig;
+
+/// Set of actions for an OrderPrompt.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum OrderPromptAction {
+    /// Moves the cursor to the option above.
+    MoveUp,
+    /// Moves the cursor to the option below.
+    MoveDown,
+    /// Moves the cursor to the page above.
+    PageUp,
+    /// Moves the cursor to the page below.
+    PageDown,
+    /// Moves the cursor to the start of the list.
+    MoveToStart,
+    /// Moves the cursor to the end of the list.
+    MoveToEnd,
+    /// Swaps the highlighted option with the one above it.
+    MoveOptionUp,
+    /// Swaps the highlighted option with the one below it.
+    MoveOptionDown,
+    /// Moves the highlighted option to the start of the list.
+    MoveOptionToStart,
+    /// Moves the highlighted option to the end of the list.
+    MoveOptionToEnd,
+}
+
+impl InnerAction for OrderPromptAction {
+    type Config = OrderConfig;
+
+    fn from_key(key: Key, config: &OrderConfig) -> Option<Self> {
+        if config.vim_mode {
+            let action = match key {
+                Key::Char('k', KeyModifiers::NONE) => Some(Self::MoveUp),
+                Key::Char('j', KeyModifiers::NONE) => Some(Self::MoveDown),
+                Key::Char('K', _) => Some(Self::MoveOptionUp),
+                Key::Char('J', _) => Some(Self::MoveOptionDown),
+                _ => None,
+            };
+
+            if action.is_some() {
+                return action;
+            }
+        }
+
+        let action = match key {
+            Key::Up(KeyModifiers::NONE) | Key::Char('p', KeyModifiers::CONTROL) => Self::MoveUp,
+            Key::PageUp(KeyModifiers::NONE) => Self::PageUp,
+            Key::Home => Self::MoveToStart,
+
+            Key::Down(KeyModifiers::NONE) | Key::Char('n', KeyModifiers::CONTROL) => Self::MoveDown,
+            Key::PageDown(KeyModifiers::NONE) => Self::PageDown,
+            Key::End => Self::MoveToEnd,
+
+            Key::Up(_) => Self::MoveOptionUp,
+            Key::Down(_) => Self::MoveOptionDown,
+            Key::PageUp(_) => Self::MoveOptionToStart,
+            Key::PageDown(_) => Self::MoveOptionToEnd,
+            _ => return None,
+        };
+
+        Some(action)
+    }
+}
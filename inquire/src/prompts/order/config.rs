@@ -0,0 +1,19 @@
+use crate::Order;
+
+/// Configuration settings used in the execution of an OrderPrompt.
+#[derive(Copy, Clone, Debug)]
+pub struct OrderConfig {
+    /// Whether to use vim-style keybindings.
+    pub vim_mode: bool,
+    /// Page size of the list of options.
+    pub page_size: usize,
+}
+
+impl<T> From<&Order<'_, T>> for OrderConfig {
+    fn from(value: &Order<'_, T>) -> Self {
+        Self {
+            vim_mode: value.vim_mode,
+            page_size: value.page_size,
+        }
+    }
+}
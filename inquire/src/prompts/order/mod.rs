@@ -0,0 +1,222 @@
+mod action;
+mod config;
+mod prompt;
+#[cfg(test)]
+#[cfg(feature = "crossterm")]
+mod test;
+
+pub use action::*;
+
+use std::fmt::Display;
+
+use crate::{
+    config::get_configuration,
+    error::{InquireError, InquireResult},
+    formatter::MultiOptionFormatter,
+    list_option::ListOption,
+    prompts::prompt::Prompt,
+    terminal::get_default_terminal,
+    ui::{Backend, RenderConfig, SelectBackend},
+};
+
+use self::prompt::OrderPrompt;
+
+/// Prompt suitable for when you need the user to rank a list of options, such as when defining priorities.
+///
+/// The options are displayed in the order they are given. The user moves the cursor with the arrow keys and moves the highlighted option up or down by holding a modifier key, such as shift, while pressing the arrow keys. Holding a modifier while pressing page up or page down moves the highlighted option to the start or to the end of the list. Pressing enter submits the current ordering.
+///
+/// This prompt requires a prompt message and a **non-empty** `Vec` of options to be displayed to the user. The options can be of any type as long as they implement the `Display` trait.
+/// - If the list is empty, the prompt operation will fail with an `InquireError::InvalidConfiguration` error.
+///
+/// Order prompts provide several options of configuration:
+///
+/// - **Prompt message**: Required when creating the prompt.
+/// - **Options list**: Options displayed to the user, in their starting order. Must be **non-empty**.
+/// - **Help message**: Message displayed at the line below the prompt.
+/// - **Formatter**: Custom formatter in case you need to pre-process the user input before showing it as the final answer.
+///   - Prints the string value of all options, in their final order, separated by commas.
+/// - **Page size**: Number of options displayed at once, 7 by default.
+/// - **Display option indexes**: Via the `RenderConfig`, you can display the current position of each option as a prefix, which is useful to show the rank of each option.
+///
+/// # Example
+///
+/// ```no_run
+/// use inquire::Order;
+///
+/// let features = vec!["Dark mode", "Offline support", "Export to PDF", "Plugins"];
+///
+/// let ans = Order::new("Rank the features by priority:", features).prompt();
+///
+/// match ans {
+///     Ok(ranking) => println!("Top priority: {}", ranking[0]),
+///     Err(_) => println!("There was an error, please try again"),
+/// }
+/// ```
+#[derive(Clone)]
+pub struct Order<'a, T> {
+    /// Message to be presented to the user.
+    pub message: &'a str,
+
+    /// Options displayed to the user, in their starting order.
+    pub options: Vec<T>,
+
+    /// Help message to be presented to the user.
+    pub help_message: Option<&'a str>,
+
+    /// Page size of the options displayed to the user.
+    pub page_size: usize,
+
+    /// Whether vim mode is enabled. When enabled, the user can
+    /// navigate through the options using jk and move them using JK.
+    pub vim_mode: bool,
+
+    /// Function that formats the user input and presents it to the user as the final rendering of the prompt.
+    pub formatter: MultiOptionFormatter<'a, T>,
+
+    /// RenderConfig to apply to the rendered interface.
+    ///
+    /// Note: The default render config considers if the NO_COLOR environment variable
+    /// is set to decide whether to render the colored config or the empty one.
+    ///
+    /// When overriding the config in a prompt, NO_COLOR is no longer considered and your
+    /// config is treated as the only source of truth. If you want to customize colors
+    /// and still support NO_COLOR, you will have to do this on your end.
+    pub render_config: RenderConfig<'a>,
+}
+
+impl<'a, T> Order<'a, T>
+where
+    T: Display,
+{
+    /// String formatter used by default in [Order](crate::Order) prompts.
+    /// Prints the string value of all options, in their final order, separated by commas.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use inquire::list_option::ListOption;
+    /// use inquire::Order;
+    ///
+    /// let formatter = Order::<&str>::DEFAULT_FORMATTER;
+    ///
+    /// let ans = vec![ListOption::new(2, &"Plugins"), ListOption::new(0, &"Dark mode")];
+    /// assert_eq!(String::from("Plugins, Dark mode"), formatter(&ans));
+    /// ```
+    pub const DEFAULT_FORMATTER: MultiOptionFormatter<'a, T> = &|ans| {
+        ans.iter()
+            .map(|opt| opt.to_string())
+            .collect::<Vec<String>>()
+            .join(", ")
+    };
+
+    /// Default page size.
+    pub const DEFAULT_PAGE_SIZE: usize = crate::config::DEFAULT_PAGE_SIZE;
+
+    /// Default value of vim mode.
+    pub const DEFAULT_VIM_MODE: bool = crate::config::DEFAULT_VIM_MODE;
+
+    /// Default help message.
+    pub const DEFAULT_HELP_MESSAGE: Option<&'a str> =
+        Some("↑↓ to move, shift+↑↓ to reorder, enter to submit");
+
+    /// Creates an [Order] with the provided message and options, along with default configuration values.
+    pub fn new(message: &'a str, options: Vec<T>) -> Self {
+        Self {
+            message,
+            options,
+            help_message: Self::DEFAULT_HELP_MESSAGE,
+            page_size: Self::DEFAULT_PAGE_SIZE,
+            vim_mode: Self::DEFAULT_VIM_MODE,
+            formatter: Self::DEFAULT_FORMATTER,
+            render_config: get_configuration(),
+        }
+    }
+
+    /// Sets the help message of the prompt.
+    pub fn with_help_message(mut self, message: &'a str) -> Self {
+        self.help_message = Some(message);
+        self
+    }
+
+    /// Removes the set help message.
+    pub fn without_help_message(mut self) -> Self {
+        self.help_message = None;
+        self
+    }
+
+    /// Sets the page size.
+    pub fn with_page_size(mut self, page_size: usize) -> Self {
+        self.page_size = page_size;
+        self
+    }
+
+    /// Enables or disables vim_mode.
+    pub fn with_vim_mode(mut self, vim_mode: bool) -> Self {
+        self.vim_mode = vim_mode;
+        self
+    }
+
+    /// Sets the formatter.
+    pub fn with_formatter(mut self, formatter: MultiOptionFormatter<'a, T>) -> Self {
+        self.formatter = formatter;
+        self
+    }
+
+    /// Sets the provided color theme to this prompt.
+    ///
+    /// Note: The default render config considers if the NO_COLOR environment variable
+    /// is set to decide whether to render the colored config or the empty one.
+    ///
+    /// When overriding the config in a prompt, NO_COLOR is no longer considered and your
+    /// config is treated as the only source of truth. If you want to customize colors
+    /// and still support NO_COLOR, you will have to do this on your end.
+    pub fn with_render_config(mut self, render_config: RenderConfig<'a>) -> Self {
+        self.render_config = render_config;
+        self
+    }
+
+    /// Parses the provided behavioral and rendering options and prompts
+    /// the CLI user for input according to the defined rules.
+    ///
+    /// Returns the owned options in the order defined by the user.
+    pub fn prompt(self) -> InquireResult<Vec<T>> {
+        self.raw_prompt()
+            .map(|options| options.into_iter().map(|op| op.value).collect())
+    }
+
+    /// Parses the provided behavioral and rendering options and prompts
+    /// the CLI user for input according to the defined rules.
+    ///
+    /// This method is intended for flows where the user skipping/cancelling
+    /// the prompt - by pressing ESC - is considered normal behavior. In this case,
+    /// it does not return `Err(InquireError::OperationCanceled)`, but `Ok(None)`.
+    ///
+    /// Meanwhile, if the user does submit an answer, the method wraps the return
+    /// type with `Some`.
+    pub fn prompt_skippable(self) -> InquireResult<Option<Vec<T>>> {
+        match self.prompt() {
+            Ok(answer) => Ok(Some(answer)),
+            Err(InquireError::OperationCanceled) => Ok(None),
+            Err(err) => Err(err),
+        }
+    }
+
+    /// Parses the provided behavioral and rendering options and prompts
+    /// the CLI user for input according to the defined rules.
+    ///
+    /// Returns a vector of [`ListOption`](crate::list_option::ListOption)s, in the order
+    /// defined by the user, each of them containing the original index of the option and
+    /// the owned object.
+    pub fn raw_prompt(self) -> InquireResult<Vec<ListOption<T>>> {
+        let (input_reader, terminal) = get_default_terminal()?;
+        let mut backend = Backend::new(input_reader, terminal, self.render_config)?;
+        self.prompt_with_backend(&mut backend)
+    }
+
+    pub(crate) fn prompt_with_backend<B: SelectBackend>(
+        self,
+        backend: &mut B,
+    ) -> InquireResult<Vec<ListOption<T>>> {
+        OrderPrompt::new(self)?.prompt(backend)
+    }
+}
@@ -0,0 +1,178 @@
+use std::fmt::Display;
+
+use crate::{
+    error::InquireResult,
+    formatter::MultiOptionFormatter,
+    list_option::ListOption,
+    prompts::prompt::{ActionResult, Prompt},
+    ui::SelectBackend,
+    utils::paginate,
+    InquireError, Order,
+};
+
+use super::{action::OrderPromptAction, config::OrderConfig};
+
+pub struct OrderPrompt<'a, T> {
+    message: &'a str,
+    config: OrderConfig,
+    /// Options in their current order, each one with its original index.
+    options: Vec<ListOption<T>>,
+    help_message: Option<&'a str>,
+    cursor_index: usize,
+    formatter: MultiOptionFormatter<'a, T>,
+}
+
+impl<'a, T> OrderPrompt<'a, T>
+where
+    T: Display,
+{
+    pub fn new(mo: Order<'a, T>) -> InquireResult<Self> {
+        if mo.options.is_empty() {
+            return Err(InquireError::InvalidConfiguration(
+                "Available options can not be empty".into(),
+            ));
+        }
+
+        let config = (&mo).into();
+
+        let options = mo
+            .options
+            .into_iter()
+            .enumerate()
+            .map(|(index, value)| ListOption::new(index, value))
+            .collect();
+
+        Ok(Self {
+            message: mo.message,
+            config,
+            options,
+            help_message: mo.help_message,
+            cursor_index: 0,
+            formatter: mo.formatter,
+        })
+    }
+
+    fn move_cursor_up(&mut self, qty: usize, wrap: bool) -> ActionResult {
+        let new_position = if wrap {
+            let after_wrap = qty.saturating_sub(self.cursor_index);
+            self.cursor_index
+                .checked_sub(qty)
+                .unwrap_or_else(|| self.options.len().saturating_sub(after_wrap))
+        } else {
+            self.cursor_index.saturating_sub(qty)
+        };
+
+        self.update_cursor_position(new_position)
+    }
+
+    fn move_cursor_down(&mut self, qty: usize, wrap: bool) -> ActionResult {
+        let mut new_position = self.cursor_index.saturating_add(qty);
+
+        if new_position >= self.options.len() {
+            new_position = if wrap {
+                new_position % self.options.len()
+            } else {
+                self.options.len().saturating_sub(1)
+            }
+        }
+
+        self.update_cursor_position(new_position)
+    }
+
+    fn update_cursor_position(&mut self, new_position: usize) -> ActionResult {
+        if new_position != self.cursor_index {
+            self.cursor_index = new_position;
+            ActionResult::NeedsRedraw
+        } else {
+            ActionResult::Clean
+        }
+    }
+
+    /// Moves the highlighted option to the given position, shifting the
+    /// options in between. The cursor follows the moved option.
+    fn move_option_to(&mut self, new_position: usize) -> ActionResult {
+        let new_position = new_position.min(self.options.len().saturating_sub(1));
+
+        if new_position == self.cursor_index {
+            return ActionResult::Clean;
+        }
+
+        let option = self.options.remove(self.cursor_index);
+        self.options.insert(new_position, option);
+        self.cursor_index = new_position;
+
+        ActionResult::NeedsRedraw
+    }
+}
+
+impl<'a, Backend, T> Prompt<Backend> for OrderPrompt<'a, T>
+where
+    Backend: SelectBackend,
+    T: Display,
+{
+    type Config = OrderConfig;
+    type InnerAction = OrderPromptAction;
+    type Output = Vec<ListOption<T>>;
+
+    fn message(&self) -> &str {
+        self.message
+    }
+
+    fn config(&self) -> &OrderConfig {
+        &self.config
+    }
+
+    fn format_answer(&self, answer: &Vec<ListOption<T>>) -> String {
+        let refs: Vec<ListOption<&T>> = answer.iter().map(ListOption::as_ref).collect();
+        (self.formatter)(&refs)
+    }
+
+    fn submit(&mut self) -> InquireResult<Option<Vec<ListOption<T>>>> {
+        Ok(Some(std::mem::take(&mut self.options)))
+    }
+
+    fn handle(&mut self, action: OrderPromptAction) -> InquireResult<ActionResult> {
+        let result = match action {
+            OrderPromptAction::MoveUp => self.move_cursor_up(1, true),
+            OrderPromptAction::MoveDown => self.move_cursor_down(1, true),
+            OrderPromptAction::PageUp => self.move_cursor_up(self.config.page_size, false),
+            OrderPromptAction::PageDown => self.move_cursor_down(self.config.page_size, false),
+            OrderPromptAction::MoveToStart => self.move_cursor_up(usize::MAX, false),
+            OrderPromptAction::MoveToEnd => self.move_cursor_down(usize::MAX, false),
+            OrderPromptAction::MoveOptionUp => match self.cursor_index {
+                0 => ActionResult::Clean,
+                cursor => self.move_option_to(cursor - 1),
+            },
+            OrderPromptAction::MoveOptionDown => self.move_option_to(self.cursor_index + 1),
+            OrderPromptAction::MoveOptionToStart => self.move_option_to(0),
+            OrderPromptAction::MoveOptionToEnd => self.move_option_to(usize::MAX),
+        };
+
+        Ok(result)
+    }
+
+    fn render(&self, backend: &mut Backend) -> InquireResult<()> {
+        let prompt = &self.message;
+
+        backend.render_select_prompt(prompt, None)?;
+
+        // options are rendered with their current position, so that
+        // index prefixes display the rank of each option
+        let choices = self
+            .options
+            .iter()
+            .enumerate()
+            .map(|(position, option)| ListOption::new(position, &option.value))
+            .collect::<Vec<ListOption<&T>>>();
+
+        let page = paginate(self.config.page_size, &choices, Some(self.cursor_index));
+
+        backend.render_options(page)?;
+
+        if let Some(help_message) = self.help_message {
+            backend.render_help_message(help_message)?;
+        }
+
+        Ok(())
+    }
+}
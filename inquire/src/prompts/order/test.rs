@@ -0,0 +1,127 @@
+use crate::{
+    list_option::ListOption,
+    test::fake_backend,
+    ui::{Key, KeyModifiers},
+    InquireError, Order,
+};
+
+fn options() -> Vec<&'static str> {
+    vec!["a", "b", "c", "d"]
+}
+
+#[test]
+fn submitting_right_away_keeps_original_order() {
+    let mut backend = fake_backend(vec![Key::Enter]);
+
+    let ans = Order::new("Question", options())
+        .prompt_with_backend(&mut backend)
+        .unwrap();
+
+    let expected: Vec<ListOption<&str>> = options()
+        .into_iter()
+        .enumerate()
+        .map(|(i, v)| ListOption::new(i, v))
+        .collect();
+    assert_eq!(expected, ans);
+}
+
+#[test]
+fn shift_arrows_move_highlighted_option() {
+    let mut backend = fake_backend(vec![
+        Key::Down(KeyModifiers::SHIFT),
+        Key::Down(KeyModifiers::SHIFT),
+        Key::Down(KeyModifiers::NONE),
+        Key::Up(KeyModifiers::SHIFT),
+        Key::Enter,
+    ]);
+
+    let ans = Order::new("Question", options())
+        .prompt_with_backend(&mut backend)
+        .unwrap();
+
+    assert_eq!(
+        vec![
+            ListOption::new(1, "b"),
+            ListOption::new(2, "c"),
+            ListOption::new(3, "d"),
+            ListOption::new(0, "a"),
+        ],
+        ans
+    );
+}
+
+#[test]
+fn moving_options_does_not_wrap() {
+    let mut backend = fake_backend(vec![
+        Key::Up(KeyModifiers::SHIFT),
+        Key::End,
+        Key::Down(KeyModifiers::ALT),
+        Key::Enter,
+    ]);
+
+    let ans = Order::new("Question", options())
+        .prompt_with_backend(&mut backend)
+        .unwrap();
+
+    let expected: Vec<ListOption<&str>> = options()
+        .into_iter()
+        .enumerate()
+        .map(|(i, v)| ListOption::new(i, v))
+        .collect();
+    assert_eq!(expected, ans);
+}
+
+#[test]
+fn modified_page_keys_move_option_to_the_edges() {
+    let mut backend = fake_backend(vec![
+        Key::Down(KeyModifiers::NONE),
+        Key::PageDown(KeyModifiers::SHIFT),
+        Key::Up(KeyModifiers::NONE),
+        Key::PageUp(KeyModifiers::SHIFT),
+        Key::Enter,
+    ]);
+
+    let ans = Order::new("Question", options())
+        .prompt_with_backend(&mut backend)
+        .unwrap();
+
+    assert_eq!(
+        vec![
+            ListOption::new(3, "d"),
+            ListOption::new(0, "a"),
+            ListOption::new(2, "c"),
+            ListOption::new(1, "b"),
+        ],
+        ans
+    );
+}
+
+#[test]
+fn vim_mode_moves_options_with_uppercase_keys() {
+    let mut backend = fake_backend(vec![
+        Key::Char('j', KeyModifiers::NONE),
+        Key::Char('j', KeyModifiers::NONE),
+        Key::Char('K', KeyModifiers::SHIFT),
+        Key::Char('K', KeyModifiers::SHIFT),
+        Key::Enter,
+    ]);
+
+    let ans = Order::new("Question", options())
+        .with_vim_mode(true)
+        .prompt_with_backend(&mut backend)
+        .unwrap();
+
+    assert_eq!(
+        vec!["c", "a", "b", "d"],
+        ans.into_iter().map(|op| op.value).collect::<Vec<&str>>()
+    );
+}
+
+#[test]
+fn empty_options_is_invalid_configuration() {
+    let mut backend = fake_backend(vec![Key::Enter]);
+
+    let ans = Order::<&str>::new("Question", vec![]).prompt_with_backend(&mut backend);
+
+    assert!(matches!(ans, Err(InquireError::InvalidConfiguration(_))));
+}
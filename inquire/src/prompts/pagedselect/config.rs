@@ -0,0 +1,20 @@
+use crate::PagedSelect;
+
+/// Configuration settings used in the execution of a PagedSelectPrompt.
+#[derive(Copy, Clone, Debug)]
+pub struct PagedSelectConfig {
+    /// Whether to use vim-style keybindings.
+    pub vim_mode: bool,
+    /// Page size of the list of options, also used as the number of
+    /// options requested to the provider at a time.
+    pub page_size: usize,
+}
+
+impl<T> From<&PagedSelect<'_, T>> for PagedSelectConfig {
+    fn from(value: &PagedSelect<'_, T>) -> Self {
+        Self {
+            vim_mode: value.vim_mode,
+            page_size: value.page_size,
+        }
+    }
+}
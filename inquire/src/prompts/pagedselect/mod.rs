@@ -0,0 +1,261 @@
+mod action;
+mod config;
+mod prompt;
+mod provider;
+#[cfg(test)]
+#[cfg(feature = "crossterm")]
+mod test;
+
+pub use action::*;
+pub use provider::OptionProvider;
+
+use std::fmt::Display;
+
+use crate::{
+    config::get_configuration,
+    error::{InquireError, InquireResult},
+    formatter::OptionFormatter,
+    list_option::ListOption,
+    prompts::prompt::Prompt,
+    terminal::get_default_terminal,
+    ui::{Backend, RenderConfig, SelectBackend},
+};
+
+use self::prompt::PagedSelectPrompt;
+
+/// Prompt suitable for when you need the user to select one option among a huge list of them, such as the results of a remote API, which are fetched a page at a time.
+///
+/// Options are requested to an [`OptionProvider`] on demand, based on the current filter input and the position of the cursor. The prompt only fetches the first page of options when it starts or the filter changes, and the next ones as the user scrolls down the list, so the full list is never materialized.
+///
+/// The user can select and submit the current highlighted option by pressing enter. Typing filters the options, which is left to the provider, as the prompt never sees the options that were not fetched.
+///
+/// This prompt requires a prompt message and an option provider, options are formatted with their [`Display`](std::fmt::Display) implementation.
+///
+/// - **Prompt message**: Required when creating the prompt.
+/// - **Option provider**: Required when creating the prompt. Source of the options matching the filter input. If the provider fails, the prompt fails with an [`InquireError::Custom`] error.
+/// - **Starting filter input**: Sets the initial value of the filter section of the prompt.
+/// - **Help message**: Message displayed at the line below the prompt.
+/// - **Formatter**: Custom formatter in case you need to pre-process the user input before showing it as the final answer.
+///   - Prints the selected option string value by default.
+/// - **Page size**: Number of options displayed at once, and requested to the provider at a time, 7 by default.
+///
+/// Since the total number of options is not known in advance, moving to the end of the list moves the cursor to the last option fetched so far, and the cursor only wraps around the list once every option has been fetched.
+///
+/// # Example
+///
+/// ```no_run
+/// use inquire::{CustomUserError, PagedSelect};
+///
+/// let provider = |filter: &str, offset: usize, limit: usize| -> Result<Vec<String>, CustomUserError> {
+///     // request the page of results from a remote API
+///     let results = (offset..offset + limit)
+///         .map(|i| format!("{filter}-{i}"))
+///         .collect();
+///
+///     Ok(results)
+/// };
+///
+/// let repository = PagedSelect::new("Repository:", provider).prompt();
+///
+/// match repository {
+///     Ok(choice) => println!("Cloning {}", choice),
+///     Err(_) => println!("There was an error, please try again"),
+/// }
+/// ```
+///
+/// [`InquireError::Custom`]: crate::error::InquireError::Custom
+#[derive(Clone)]
+pub struct PagedSelect<'a, T> {
+    /// Message to be presented to the user.
+    pub message: &'a str,
+
+    /// Source of the options matching the filter input.
+    pub provider: Box<dyn OptionProvider<T>>,
+
+    /// Help message to be presented to the user.
+    pub help_message: Option<&'a str>,
+
+    /// Page size of the options displayed to the user, also used as the
+    /// number of options requested to the provider at a time.
+    pub page_size: usize,
+
+    /// Whether vim mode is enabled. When enabled, the user can
+    /// navigate through the options using jk.
+    pub vim_mode: bool,
+
+    /// Starting filter input
+    pub starting_filter_input: Option<&'a str>,
+
+    /// Function that formats the user input and presents it to the user as the final rendering of the prompt.
+    pub formatter: OptionFormatter<'a, T>,
+
+    /// RenderConfig to apply to the rendered interface.
+    ///
+    /// Note: The default render config considers if the NO_COLOR environment variable
+    /// is set to decide whether to render the colored config or the empty one.
+    ///
+    /// When overriding the config in a prompt, NO_COLOR is no longer considered and your
+    /// config is treated as the only source of truth. If you want to customize colors
+    /// and still support NO_COLOR, you will have to do this on your end.
+    pub render_config: RenderConfig<'a>,
+}
+
+impl<'a, T> PagedSelect<'a, T>
+where
+    T: Display,
+{
+    /// String formatter used by default in [PagedSelect](crate::PagedSelect) prompts.
+    /// Simply prints the string value contained in the selected option.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use inquire::list_option::ListOption;
+    /// use inquire::PagedSelect;
+    ///
+    /// let formatter = PagedSelect::<&str>::DEFAULT_FORMATTER;
+    /// assert_eq!(String::from("First option"), formatter(ListOption::new(0, &"First option")));
+    /// ```
+    pub const DEFAULT_FORMATTER: OptionFormatter<'a, T> = &|ans| ans.to_string();
+
+    /// Default page size.
+    pub const DEFAULT_PAGE_SIZE: usize = crate::config::DEFAULT_PAGE_SIZE;
+
+    /// Default value of vim mode.
+    pub const DEFAULT_VIM_MODE: bool = crate::config::DEFAULT_VIM_MODE;
+
+    /// Default help message.
+    pub const DEFAULT_HELP_MESSAGE: Option<&'a str> =
+        Some("↑↓ to move, enter to select, type to filter");
+
+    /// Creates a [PagedSelect] with the provided message and option provider, along with default configuration values.
+    pub fn new<P>(message: &'a str, provider: P) -> Self
+    where
+        P: OptionProvider<T> + 'static,
+    {
+        Self {
+            message,
+            provider: Box::new(provider),
+            help_message: Self::DEFAULT_HELP_MESSAGE,
+            page_size: Self::DEFAULT_PAGE_SIZE,
+            vim_mode: Self::DEFAULT_VIM_MODE,
+            starting_filter_input: None,
+            formatter: Self::DEFAULT_FORMATTER,
+            render_config: get_configuration(),
+        }
+    }
+
+    /// Sets the help message of the prompt.
+    pub fn with_help_message(mut self, message: &'a str) -> Self {
+        self.help_message = Some(message);
+        self
+    }
+
+    /// Removes the set help message.
+    pub fn without_help_message(mut self) -> Self {
+        self.help_message = None;
+        self
+    }
+
+    /// Sets the page size, which is also the number of options requested to the provider at a time.
+    pub fn with_page_size(mut self, page_size: usize) -> Self {
+        self.page_size = page_size;
+        self
+    }
+
+    /// Enables or disables vim_mode.
+    pub fn with_vim_mode(mut self, vim_mode: bool) -> Self {
+        self.vim_mode = vim_mode;
+        self
+    }
+
+    /// Sets the starting filter input
+    pub fn with_starting_filter_input(mut self, starting_filter_input: &'a str) -> Self {
+        self.starting_filter_input = Some(starting_filter_input);
+        self
+    }
+
+    /// Sets the formatter.
+    pub fn with_formatter(mut self, formatter: OptionFormatter<'a, T>) -> Self {
+        self.formatter = formatter;
+        self
+    }
+
+    /// Sets the provided color theme to this prompt.
+    ///
+    /// Note: The default render config considers if the NO_COLOR environment variable
+    /// is set to decide whether to render the colored config or the empty one.
+    ///
+    /// When overriding the config in a prompt, NO_COLOR is no longer considered and your
+    /// config is treated as the only source of truth. If you want to customize colors
+    /// and still support NO_COLOR, you will have to do this on your end.
+    pub fn with_render_config(mut self, render_config: RenderConfig<'a>) -> Self {
+        self.render_config = render_config;
+        self
+    }
+
+    /// Parses the provided behavioral and rendering options and prompts
+    /// the CLI user for input according to the defined rules.
+    ///
+    /// This method is intended for flows where the user skipping/cancelling
+    /// the prompt - by pressing ESC - is considered normal behavior. In this case,
+    /// it does not return `Err(InquireError::OperationCanceled)`, but `Ok(None)`.
+    ///
+    /// Meanwhile, if the user does submit an answer, the method wraps the return
+    /// type with `Some`.
+    pub fn prompt_skippable(self) -> InquireResult<Option<T>> {
+        match self.prompt() {
+            Ok(answer) => Ok(Some(answer)),
+            Err(InquireError::OperationCanceled) => Ok(None),
+            Err(err) => Err(err),
+        }
+    }
+
+    /// Parses the provided behavioral and rendering options and prompts
+    /// the CLI user for input according to the defined rules.
+    ///
+    /// Returns the owned object selected by the user.
+    pub fn prompt(self) -> InquireResult<T> {
+        self.raw_prompt().map(|op| op.value)
+    }
+
+    /// Parses the provided behavioral and rendering options and prompts
+    /// the CLI user for input according to the defined rules.
+    ///
+    /// This method is intended for flows where the user skipping/cancelling
+    /// the prompt - by pressing ESC - is considered normal behavior. In this case,
+    /// it does not return `Err(InquireError::OperationCanceled)`, but `Ok(None)`.
+    ///
+    /// Meanwhile, if the user does submit an answer, the method wraps the return
+    /// type with `Some`.
+    ///
+    /// Returns a [`ListOption`](crate::list_option::ListOption) containing
+    /// the position of the selected option among the ones matching the filter
+    /// and the owned object selected by the user.
+    pub fn raw_prompt_skippable(self) -> InquireResult<Option<ListOption<T>>> {
+        match self.raw_prompt() {
+            Ok(answer) => Ok(Some(answer)),
+            Err(InquireError::OperationCanceled) => Ok(None),
+            Err(err) => Err(err),
+        }
+    }
+
+    /// Parses the provided behavioral and rendering options and prompts
+    /// the CLI user for input according to the defined rules.
+    ///
+    /// Returns a [`ListOption`](crate::list_option::ListOption) containing
+    /// the position of the selected option among the ones matching the filter
+    /// and the owned object selected by the user.
+    pub fn raw_prompt(self) -> InquireResult<ListOption<T>> {
+        let (input_reader, terminal) = get_default_terminal()?;
+        let mut backend = Backend::new(input_reader, terminal, self.render_config)?;
+        self.prompt_with_backend(&mut backend)
+    }
+
+    pub(crate) fn prompt_with_backend<B: SelectBackend>(
+        self,
+        backend: &mut B,
+    ) -> InquireResult<ListOption<T>> {
+        PagedSelectPrompt::new(self)?.prompt(backend)
+    }
+}
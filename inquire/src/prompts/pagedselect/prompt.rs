@@ -0,0 +1,205 @@
+use std::fmt::Display;
+
+use crate::{
+    error::InquireResult,
+    formatter::OptionFormatter,
+    input::{Input, InputActionResult},
+    list_option::ListOption,
+    prompts::prompt::{ActionResult, Prompt},
+    ui::SelectBackend,
+    utils::paginate,
+    PagedSelect,
+};
+
+use super::{action::PagedSelectPromptAction, config::PagedSelectConfig, provider::OptionProvider};
+
+pub struct PagedSelectPrompt<'a, T> {
+    message: &'a str,
+    config: PagedSelectConfig,
+    provider: Box<dyn OptionProvider<T>>,
+    // options fetched so far for the current filter
+    options: Vec<T>,
+    // whether every option matching the current filter was fetched
+    exhausted: bool,
+    help_message: Option<&'a str>,
+    cursor_index: usize,
+    input: Input,
+    formatter: OptionFormatter<'a, T>,
+}
+
+impl<'a, T> PagedSelectPrompt<'a, T>
+where
+    T: Display,
+{
+    pub fn new(so: PagedSelect<'a, T>) -> InquireResult<Self> {
+        Ok(Self {
+            message: so.message,
+            config: (&so).into(),
+            provider: so.provider,
+            options: vec![],
+            exhausted: false,
+            help_message: so.help_message,
+            cursor_index: 0,
+            input: Input::new_with(so.starting_filter_input.unwrap_or_default()),
+            formatter: so.formatter,
+        })
+    }
+
+    /// Requests pages to the provider until the given number of options is
+    /// fetched, or there are no more options matching the filter.
+    fn fetch_until(&mut self, len: usize) -> InquireResult<()> {
+        let limit = self.config.page_size.max(1);
+
+        while !self.exhausted && self.options.len() < len {
+            let page = self
+                .provider
+                .fetch(self.input.content(), self.options.len(), limit)?;
+
+            self.exhausted = page.len() < limit;
+            self.options.extend(page);
+        }
+
+        Ok(())
+    }
+
+    /// Fetches the options displayed around the cursor, along with the next
+    /// page, so that moving down does not wait for the provider.
+    fn fetch_around_cursor(&mut self) -> InquireResult<()> {
+        let len = self.cursor_index + self.config.page_size * 2;
+        self.fetch_until(len)
+    }
+
+    fn reset_options(&mut self) -> InquireResult<()> {
+        self.options.clear();
+        self.exhausted = false;
+        self.cursor_index = 0;
+
+        self.fetch_around_cursor()
+    }
+
+    fn move_cursor_up(&mut self, qty: usize, wrap: bool) -> ActionResult {
+        // the last option is only known after every option is fetched
+        let new_position = match self.cursor_index.checked_sub(qty) {
+            Some(position) => position,
+            None if wrap && self.exhausted => self.options.len().saturating_sub(1),
+            None => 0,
+        };
+
+        self.update_cursor_position(new_position)
+    }
+
+    fn move_cursor_down(&mut self, qty: usize, wrap: bool) -> InquireResult<ActionResult> {
+        let new_position = self.cursor_index.saturating_add(qty);
+        self.fetch_until(new_position.saturating_add(1))?;
+
+        let new_position = if new_position < self.options.len() {
+            new_position
+        } else if wrap && self.exhausted {
+            0
+        } else {
+            self.options.len().saturating_sub(1)
+        };
+
+        let result = self.update_cursor_position(new_position);
+        self.fetch_around_cursor()?;
+
+        Ok(result)
+    }
+
+    fn update_cursor_position(&mut self, new_position: usize) -> ActionResult {
+        if new_position != self.cursor_index {
+            self.cursor_index = new_position;
+            ActionResult::NeedsRedraw
+        } else {
+            ActionResult::Clean
+        }
+    }
+}
+
+impl<'a, Backend, T> Prompt<Backend> for PagedSelectPrompt<'a, T>
+where
+    Backend: SelectBackend,
+    T: Display,
+{
+    type Config = PagedSelectConfig;
+    type InnerAction = PagedSelectPromptAction;
+    type Output = ListOption<T>;
+
+    fn message(&self) -> &str {
+        self.message
+    }
+
+    fn config(&self) -> &PagedSelectConfig {
+        &self.config
+    }
+
+    fn format_answer(&self, answer: &ListOption<T>) -> String {
+        (self.formatter)(answer.as_ref())
+    }
+
+    fn setup(&mut self) -> InquireResult<()> {
+        self.reset_options()
+    }
+
+    fn submit(&mut self) -> InquireResult<Option<ListOption<T>>> {
+        if self.cursor_index >= self.options.len() {
+            return Ok(None);
+        }
+
+        let value = self.options.swap_remove(self.cursor_index);
+
+        Ok(Some(ListOption::new(self.cursor_index, value)))
+    }
+
+    fn handle(&mut self, action: PagedSelectPromptAction) -> InquireResult<ActionResult> {
+        let page_size = self.config.page_size;
+
+        let result = match action {
+            PagedSelectPromptAction::MoveUp => self.move_cursor_up(1, true),
+            PagedSelectPromptAction::MoveDown => self.move_cursor_down(1, true)?,
+            PagedSelectPromptAction::PageUp => self.move_cursor_up(page_size, false),
+            PagedSelectPromptAction::PageDown => self.move_cursor_down(page_size, false)?,
+            PagedSelectPromptAction::MoveToStart => self.move_cursor_up(usize::MAX, false),
+            PagedSelectPromptAction::MoveToEnd => {
+                let last = self.options.len().saturating_sub(1);
+                let result = self.update_cursor_position(last);
+                self.fetch_around_cursor()?;
+                result
+            }
+            PagedSelectPromptAction::FilterInput(input_action) => {
+                let result = self.input.handle(input_action);
+
+                if let InputActionResult::ContentChanged = result {
+                    self.reset_options()?;
+                }
+
+                result.into()
+            }
+        };
+
+        Ok(result)
+    }
+
+    fn render(&self, backend: &mut Backend) -> InquireResult<()> {
+        let prompt = &self.message;
+
+        backend.render_select_prompt(prompt, Some(&self.input))?;
+
+        let choices = self
+            .options
+            .iter()
+            .enumerate()
+            .map(|(i, option)| ListOption::new(i, option))
+            .collect::<Vec<ListOption<&T>>>();
+
+        let page = paginate(self.config.page_size, &choices, Some(self.cursor_index));
+
+        backend.render_options(page)?;
+
+        if let Some(help_message) = self.help_message {
+            backend.render_help_message(help_message)?;
+        }
+
+        Ok(())
+    }
+}
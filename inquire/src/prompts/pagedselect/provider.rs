@@ -0,0 +1,71 @@
+use dyn_clone::DynClone;
+
+use crate::CustomUserError;
+
+/// Source of the options of a [PagedSelect](crate::PagedSelect) prompt,
+/// which are requested a page at a time as the user filters and scrolls
+/// through the list.
+///
+/// The prompt only keeps the options fetched for the current filter, so
+/// lists backed by remote APIs never need to be fully loaded. Whenever the
+/// filter changes, the fetched options are discarded and the first page of
+/// the new filter is requested.
+///
+/// Providers are called synchronously while the prompt handles a key press,
+/// so slow sources might want to cache their responses.
+///
+/// # Example
+///
+/// ```
+/// use inquire::{CustomUserError, OptionProvider};
+///
+/// #[derive(Clone)]
+/// struct Numbers;
+///
+/// impl OptionProvider<u32> for Numbers {
+///     fn fetch(&mut self, filter: &str, offset: usize, limit: usize) -> Result<Vec<u32>, CustomUserError> {
+///         let page = (1..=1_000_000)
+///             .filter(|n: &u32| n.to_string().contains(filter))
+///             .skip(offset)
+///             .take(limit)
+///             .collect();
+///
+///         Ok(page)
+///     }
+/// }
+///
+/// assert_eq!(vec![17, 27], Numbers.fetch("7", 1, 2).unwrap());
+/// ```
+pub trait OptionProvider<T>: DynClone {
+    /// Returns up to `limit` options matching the filter, skipping the first
+    /// `offset` ones, which were already fetched.
+    ///
+    /// Returning fewer options than `limit` signals that there are no more
+    /// options matching the filter.
+    fn fetch(
+        &mut self,
+        filter: &str,
+        offset: usize,
+        limit: usize,
+    ) -> Result<Vec<T>, CustomUserError>;
+}
+
+impl<T> Clone for Box<dyn OptionProvider<T>> {
+    fn clone(&self) -> Self {
+        dyn_clone::clone_box(&**self)
+    }
+}
+
+impl<T, F> OptionProvider<T> for F
+where
+    F: Fn(&str, usize, usize) -> Result<Vec<T>, CustomUserError> + Clone,
+{
+    fn fetch(
+        &mut self,
+        filter: &str,
+        offset: usize,
+        limit: usize,
+    ) -> Result<Vec<T>, CustomUserError> {
+        (self)(filter, offset, limit)
+    }
+}
@@ -0,0 +1,126 @@
+use std::{cell::RefCell, rc::Rc};
+
+use crate::{
+    list_option::ListOption,
+    test::fake_backend,
+    ui::{Key, KeyModifiers},
+    CustomUserError, OptionProvider, PagedSelect,
+};
+
+/// Provider of the numbers from 0 to 999 containing the filter, recording
+/// the offset of every request.
+#[derive(Clone, Default)]
+struct Numbers {
+    requests: Rc<RefCell<Vec<(String, usize)>>>,
+}
+
+impl OptionProvider<usize> for Numbers {
+    fn fetch(
+        &mut self,
+        filter: &str,
+        offset: usize,
+        limit: usize,
+    ) -> Result<Vec<usize>, CustomUserError> {
+        self.requests
+            .borrow_mut()
+            .push((filter.to_string(), offset));
+
+        let page = (0..1000)
+            .filter(|n: &usize| n.to_string().contains(filter))
+            .skip(offset)
+            .take(limit)
+            .collect();
+
+        Ok(page)
+    }
+}
+
+#[test]
+fn pages_are_fetched_as_the_cursor_moves() {
+    let mut backend = fake_backend(vec![
+        Key::PageDown(KeyModifiers::NONE),
+        Key::PageDown(KeyModifiers::NONE),
+        Key::Down(KeyModifiers::NONE),
+        Key::Enter,
+    ]);
+
+    let provider = Numbers::default();
+
+    let ans = PagedSelect::new("Number", provider.clone())
+        .with_page_size(5)
+        .prompt_with_backend(&mut backend)
+        .unwrap();
+
+    assert_eq!(ListOption::new(11, 11), ans);
+
+    let offsets = provider
+        .requests
+        .borrow()
+        .iter()
+        .map(|(_, offset)| *offset)
+        .collect::<Vec<usize>>();
+    assert_eq!(vec![0, 5, 10, 15, 20], offsets);
+}
+
+#[test]
+fn options_are_fetched_again_when_filter_changes() {
+    let mut backend = fake_backend(vec![
+        Key::Down(KeyModifiers::NONE),
+        Key::Char('7', KeyModifiers::NONE),
+        Key::Down(KeyModifiers::NONE),
+        Key::Enter,
+    ]);
+
+    let provider = Numbers::default();
+
+    let ans = PagedSelect::new("Number", provider.clone())
+        .with_page_size(2)
+        .prompt_with_backend(&mut backend)
+        .unwrap();
+
+    assert_eq!(ListOption::new(1, 17), ans);
+    assert_eq!(
+        Some(&(String::from("7"), 0)),
+        provider
+            .requests
+            .borrow()
+            .iter()
+            .find(|(filter, _)| filter == "7")
+    );
+}
+
+#[test]
+fn cursor_wraps_once_every_option_is_fetched() {
+    let mut backend = fake_backend(vec![Key::Up(KeyModifiers::NONE), Key::Enter]);
+
+    let ans = PagedSelect::new("Number", Numbers::default())
+        .with_starting_filter_input("99")
+        .with_page_size(10)
+        .prompt_with_backend(&mut backend)
+        .unwrap();
+
+    assert_eq!(ListOption::new(18, 999), ans);
+}
+
+#[test]
+fn cursor_does_not_wrap_while_options_are_left() {
+    let mut backend = fake_backend(vec![Key::Up(KeyModifiers::NONE), Key::Enter]);
+
+    let ans = PagedSelect::new("Number", Numbers::default())
+        .prompt_with_backend(&mut backend)
+        .unwrap();
+
+    assert_eq!(ListOption::new(0, 0), ans);
+}
+
+#[test]
+fn provider_errors_are_returned() {
+    let provider = |_: &str, _: usize, _: usize| -> Result<Vec<String>, CustomUserError> {
+        Err("offline".into())
+    };
+
+    let ans = PagedSelect::new("Repository", provider)
+        .prompt_with_backend(&mut fake_backend(vec![Key::Enter]));
+
+    assert!(matches!(ans, Err(crate::InquireError::Custom(_))));
+}
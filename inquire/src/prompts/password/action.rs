@@ -12,19 +12,29 @@ pub enum PasswordPromptAction {
     ValueInput(InputAction),
     /// Toggles the display mode between plain text and the initial one.
     ToggleDisplayMode,
+    /// Reverts the last edit made to the input, when it is displayed in full.
+    Undo,
+    /// Reapplies the last edit reverted, when the input is displayed in full.
+    Redo,
 }
 
 impl InnerAction for PasswordPromptAction {
     type Config = PasswordConfig;
 
     fn from_key(key: Key, config: &PasswordConfig) -> Option<Self> {
+        if let Some(action) = config.keymap.yank_action(key) {
+            return Some(Self::ValueInput(action));
+        }
+
         let action = match key {
             Key::Char('r' | 'R', m)
                 if m.contains(KeyModifiers::CONTROL) && config.enable_display_toggle =>
             {
                 Self::ToggleDisplayMode
             }
-            key => match InputAction::from_key(key, &()) {
+            key if key == config.undo_keys.undo => Self::Undo,
+            key if key == config.undo_keys.redo => Self::Redo,
+            key => match config.keymap.action(key) {
                 Some(action) => Self::ValueInput(action),
                 None => return None,
             },
@@ -1,4 +1,4 @@
-use crate::{Password, PasswordDisplayMode};
+use crate::{InputKeymap, Password, PasswordDisplayMode, UndoKeys};
 
 /// Configuration settings used in the execution of a PasswordPrompt.
 #[derive(Copy, Clone, Debug)]
@@ -7,6 +7,10 @@ pub struct PasswordConfig {
     pub enable_display_toggle: bool,
     /// The initial display mode of the password.
     pub display_mode: PasswordDisplayMode,
+    /// Key bindings to undo and redo the edits made to the input.
+    pub undo_keys: UndoKeys,
+    /// Preset of key bindings used to edit the input.
+    pub keymap: InputKeymap,
 }
 
 impl From<&Password<'_>> for PasswordConfig {
@@ -14,6 +18,8 @@ impl From<&Password<'_>> for PasswordConfig {
         Self {
             enable_display_toggle: value.enable_display_toggle,
             display_mode: value.display_mode,
+            undo_keys: value.undo_keys,
+            keymap: value.keymap,
         }
     }
 }
@@ -13,8 +13,10 @@ use crate::{
     formatter::StringFormatter,
     prompts::prompt::Prompt,
     terminal::get_default_terminal,
+    type_aliases::OnChange,
     ui::{Backend, PasswordBackend, RenderConfig},
     validator::StringValidator,
+    InputKeymap, UndoKeys,
 };
 
 use self::prompt::PasswordPrompt;
@@ -50,10 +52,13 @@ pub enum PasswordDisplayMode {
 /// - **Confirmation**: By default, the password will have a confirmation flow where the user will be asked for the input twice and the two responses will be compared. If they differ, an error message is shown and the user is prompted again.
 ///   - By default, a "Confirmation:" message is shown for the confirmation prompts, but this can be modified by setting a custom confirmation message only shown the second time, using the `with_custom_confirmation_message()` method.
 ///   - If confirmation is not desired, it can be turned off using the `without_confirmation()` method.
+/// - **Undo keys**: Key bindings to undo and redo the edits made to the input while it is displayed in full, `Ctrl+z` and `Ctrl+y` by default. See [`UndoKeys`].
+/// - **Keymap**: Preset of key bindings used to edit the input, such as the GNU readline ones. See [`InputKeymap`].
 /// - **Help message**: Message displayed at the line below the prompt.
 /// - **Formatter**: Custom formatter in case you need to pre-process the user input before showing it as the final answer.
 ///   - By default, it prints eight asterisk characters: `********`.
 /// - **Validators**: Custom validators to make sure a given submitted input pass the specified requirements, e.g. not allowing empty inputs or requiring special characters.
+/// - **On change hook**: Function called with the current input, either the password or its confirmation, whenever it changes, e.g. to display a live strength meter. See [`OnChange`].
 ///   - No validators are on by default.
 ///
 /// Remember that for CLI applications it is standard to not allow use any display modes other than `Hidden` and to not allow the user to see the text input in any way. _Use the customization options at your discretion_.
@@ -110,6 +115,12 @@ pub struct Password<'a> {
     /// Whether to ask for input twice to see if the provided passwords are the same.
     pub enable_confirmation: bool,
 
+    /// Key bindings to undo and redo the edits made to the input while it is displayed in full.
+    pub undo_keys: UndoKeys,
+
+    /// Preset of key bindings used to edit the input, in addition to the default ones.
+    pub keymap: InputKeymap,
+
     /// Collection of validators to apply to the user input.
     ///
     /// Validators are executed in the order they are stored, stopping at and displaying to the user
@@ -118,6 +129,9 @@ pub struct Password<'a> {
     /// The possible error is displayed to the user one line above the prompt.
     pub validators: Vec<Box<dyn StringValidator>>,
 
+    /// Function called with the current input whenever it changes.
+    pub on_change: Option<OnChange<'a>>,
+
     /// RenderConfig to apply to the rendered interface.
     ///
     /// Note: The default render config considers if the NO_COLOR environment variable
@@ -157,9 +171,12 @@ impl<'a> Password<'a> {
             enable_confirmation: Self::DEFAULT_ENABLE_CONFIRMATION,
             enable_display_toggle: Self::DEFAULT_ENABLE_DISPLAY_TOGGLE,
             display_mode: Self::DEFAULT_DISPLAY_MODE,
+            undo_keys: UndoKeys::default(),
+            keymap: InputKeymap::default(),
             help_message: Self::DEFAULT_HELP_MESSAGE,
             formatter: Self::DEFAULT_FORMATTER,
             validators: Self::DEFAULT_VALIDATORS,
+            on_change: None,
             render_config: get_configuration(),
         }
     }
@@ -200,6 +217,26 @@ impl<'a> Password<'a> {
         self
     }
 
+    /// Sets the key bindings to undo and redo the edits made to the input
+    /// while it is displayed in full.
+    pub fn with_undo_keys(mut self, undo_keys: UndoKeys) -> Self {
+        self.undo_keys = undo_keys;
+        self
+    }
+
+    /// Sets the preset of key bindings used to edit the input, such as
+    /// [`InputKeymap::Readline`].
+    pub fn with_keymap(mut self, keymap: InputKeymap) -> Self {
+        self.keymap = keymap;
+        self
+    }
+
+    /// Sets the function called with the current input whenever it changes.
+    pub fn with_on_change(mut self, on_change: OnChange<'a>) -> Self {
+        self.on_change = Some(on_change);
+        self
+    }
+
     /// Sets the formatter.
     pub fn with_formatter(mut self, formatter: StringFormatter<'a>) -> Self {
         self.formatter = formatter;
@@ -1,8 +1,9 @@
 use crate::{
     error::InquireResult,
     formatter::StringFormatter,
-    input::Input,
+    input::{clean_paste, EditHistory, Input},
     prompts::prompt::{ActionResult, Prompt},
+    type_aliases::OnChange,
     ui::PasswordBackend,
     validator::{ErrorMessage, StringValidator, Validation},
     InquireError, Password, PasswordDisplayMode,
@@ -27,11 +28,14 @@ pub struct PasswordPrompt<'a> {
     config: PasswordConfig,
     help_message: Option<&'a str>,
     input: Input,
+    // edits of the input being typed, either the password or its confirmation
+    edits: EditHistory,
     current_mode: PasswordDisplayMode,
     confirmation: Option<PasswordConfirmation<'a>>, // if `None`, confirmation is disabled, `Some(_)` confirmation is enabled
     confirmation_stage: bool,
     formatter: StringFormatter<'a>,
     validators: Vec<Box<dyn StringValidator>>,
+    on_change: Option<OnChange<'a>>,
     error: Option<ErrorMessage>,
 }
 
@@ -57,7 +61,9 @@ impl<'a> From<Password<'a>> for PasswordPrompt<'a> {
             confirmation_stage: false,
             formatter: so.formatter,
             validators: so.validators,
+            on_change: so.on_change,
             input: Input::new(),
+            edits: EditHistory::default(),
             error: None,
         }
     }
@@ -70,14 +76,35 @@ impl<'a> From<&'a str> for Password<'a> {
 }
 
 impl<'a> PasswordPrompt<'a> {
-    fn active_input_mut(&mut self) -> &mut Input {
+    /// Content of the input being typed, either the password or its
+    /// confirmation.
+    fn active_content(&self) -> &str {
+        match &self.confirmation {
+            Some(c) if self.confirmation_stage => c.input.content(),
+            _ => self.input.content(),
+        }
+    }
+
+    /// Calls the on change hook if the input being typed differs from its
+    /// previous content, which is only recorded when there is a hook.
+    fn notify_change(&self, previous: Option<String>) {
+        if let (Some(on_change), Some(previous)) = (self.on_change, previous) {
+            if previous != self.active_content() {
+                on_change(self.active_content());
+            }
+        }
+    }
+
+    /// Input being typed, either the password or its confirmation, along with
+    /// the history of its edits.
+    fn active_input_mut(&mut self) -> (&mut Input, &mut EditHistory) {
         if let Some(c) = &mut self.confirmation {
             if self.confirmation_stage {
-                return &mut c.input;
+                return (&mut c.input, &mut self.edits);
             }
         }
 
-        &mut self.input
+        (&mut self.input, &mut self.edits)
     }
 
     fn toggle_display_mode(&mut self) -> ActionResult {
@@ -161,6 +188,7 @@ where
             if self.confirmation_stage {
                 confirmation.input.clear();
                 self.confirmation_stage = false;
+                self.edits = EditHistory::default();
                 return Ok(false);
             }
         }
@@ -168,11 +196,24 @@ where
         Ok(true)
     }
 
+    fn handle_paste(&mut self, text: &str) -> InquireResult<ActionResult> {
+        // passwords can not hold line breaks, which can not be typed either
+        let text = clean_paste(text, "");
+        let previous = self.on_change.map(|_| self.active_content().to_owned());
+
+        let (input, edits) = self.active_input_mut();
+        let result = edits.paste(input, &text);
+        self.notify_change(previous);
+
+        Ok(result.into())
+    }
+
     fn submit(&mut self) -> InquireResult<Option<String>> {
         if let Validation::Invalid(msg) = self.validate_current_answer()? {
             self.error = Some(msg);
             if self.config.display_mode == PasswordDisplayMode::Hidden {
                 self.input.clear();
+                self.edits = EditHistory::default();
             }
             return Ok(None);
         }
@@ -192,17 +233,47 @@ where
             }
         };
 
+        // the input being typed is either cleared or switched
+        if result.is_none() {
+            self.edits = EditHistory::default();
+        }
+
         Ok(result)
     }
 
     fn handle(&mut self, action: PasswordPromptAction) -> InquireResult<ActionResult> {
+        let previous = self.on_change.map(|_| self.active_content().to_owned());
+
         let result = match action {
             PasswordPromptAction::ValueInput(input_action) => {
-                self.active_input_mut().handle(input_action).into()
+                let (input, edits) = self.active_input_mut();
+                edits.handle(input, input_action).into()
             }
             PasswordPromptAction::ToggleDisplayMode => self.toggle_display_mode(),
+            // hidden and masked inputs are not revealed by undoing their edits
+            PasswordPromptAction::Undo | PasswordPromptAction::Redo
+                if self.current_mode != PasswordDisplayMode::Full =>
+            {
+                ActionResult::Clean
+            }
+            PasswordPromptAction::Undo => {
+                let (input, edits) = self.active_input_mut();
+                match edits.undo(input) {
+                    true => ActionResult::NeedsRedraw,
+                    false => ActionResult::Clean,
+                }
+            }
+            PasswordPromptAction::Redo => {
+                let (input, edits) = self.active_input_mut();
+                match edits.redo(input) {
+                    true => ActionResult::NeedsRedraw,
+                    false => ActionResult::Clean,
+                }
+            }
         };
 
+        self.notify_change(previous);
+
         Ok(result)
     }
 
@@ -254,3 +254,50 @@ password_test!(
     "anor",
     Password::new("").with_display_mode(crate::PasswordDisplayMode::Masked)
 );
+
+password_test!(
+    undo_applies_in_full_display_mode,
+    {
+        let mut events: Vec<Key> = text_to_events!("secret");
+        events.push(Key::Char('z', KeyModifiers::CONTROL));
+        events.append(&mut text_to_events!("pass"));
+        events.push(Key::Enter);
+        events
+    },
+    "pass",
+    Password::new("")
+        .without_confirmation()
+        .with_display_mode(crate::PasswordDisplayMode::Full)
+);
+
+password_test!(
+    undo_is_ignored_in_hidden_display_mode,
+    {
+        let mut events: Vec<Key> = text_to_events!("secret");
+        events.push(Key::Char('z', KeyModifiers::CONTROL));
+        events.append(&mut text_to_events!("pass"));
+        events.push(Key::Enter);
+        events
+    },
+    "secretpass",
+    Password::new("").without_confirmation()
+);
+
+#[test]
+fn on_change_is_called_with_the_input_being_typed() {
+    let changes = std::cell::RefCell::new(vec![]);
+    let on_change = |input: &str| changes.borrow_mut().push(input.to_string());
+
+    let mut events: Vec<Key> = text_to_events!("ab\nab\n");
+    events.insert(1, Key::Char('r', KeyModifiers::CONTROL));
+    let mut backend = crate::prompts::test::fake_backend(events);
+
+    let ans = Password::new("")
+        .with_display_toggle_enabled()
+        .with_on_change(&on_change)
+        .prompt_with_backend(&mut backend)
+        .unwrap();
+
+    assert_eq!("ab", ans);
+    assert_eq!(vec!["a", "ab", "a", "ab"], *changes.borrow());
+}
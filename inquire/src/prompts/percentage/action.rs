@@ -0,0 +1,37 @@
+use crate::{
+    ui::{Key, KeyModifiers},
+    InnerAction, InputAction,
+};
+
+use super::config::PercentageConfig;
+
+/// Set of actions for a PercentagePrompt.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum PercentagePromptAction {
+    /// Action on the value text input handler.
+    ValueInput(InputAction),
+    /// Increments the current value by one step.
+    Increment,
+    /// Decrements the current value by one step.
+    Decrement,
+}
+
+impl InnerAction for PercentagePromptAction {
+    type Config = PercentageConfig;
+
+    fn from_key(key: Key, _config: &PercentageConfig) -> Option<Self> {
+        let action = match key {
+            Key::Up(KeyModifiers::NONE) | Key::Char('p', KeyModifiers::CONTROL) => Self::Increment,
+            Key::Down(KeyModifiers::NONE) | Key::Char('n', KeyModifiers::CONTROL) => {
+                Self::Decrement
+            }
+
+            key => match InputAction::from_key(key, &()) {
+                Some(action) => Self::ValueInput(action),
+                None => return None,
+            },
+        };
+
+        Some(action)
+    }
+}
@@ -0,0 +1,10 @@
+use crate::Percentage;
+
+/// Configuration settings used in the execution of a PercentagePrompt.
+pub struct PercentageConfig {}
+
+impl<T> From<&Percentage<'_, T>> for PercentageConfig {
+    fn from(_value: &Percentage<'_, T>) -> Self {
+        Self {}
+    }
+}
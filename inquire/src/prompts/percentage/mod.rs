@@ -0,0 +1,302 @@
+mod action;
+mod config;
+mod prompt;
+#[cfg(test)]
+mod test;
+mod value;
+
+pub use action::*;
+pub use value::*;
+
+use crate::{
+    config::get_configuration,
+    error::{InquireError, InquireResult},
+    formatter::CustomTypeFormatter,
+    prompts::prompt::Prompt,
+    terminal::get_default_terminal,
+    ui::{Backend, PercentageBackend, RenderConfig},
+    validator::CustomTypeValidator,
+};
+
+use self::prompt::PercentagePrompt;
+
+/// Prompt suitable for percentages, such as discounts, rollout ratios or thresholds.
+///
+/// It behaves like a [`Number`](crate::Number) prompt restricted to values between 0% and 100%. The user can type the value, optionally followed by `%`, or change it with the Up and Down arrows, in which case the value is clamped to the bounds. A bar below the input previews the current value.
+///
+/// Regardless of how the value is returned, it is always typed, displayed and validated between 0 and 100. The [scale](PercentageScale) defines whether the returned value is normalized to the 0–1 range, e.g. `0.25` for 25%, or kept in the 0–100 range.
+///
+/// Percentage prompts provide several options of configuration:
+///
+/// - **Prompt message**: Required when creating the prompt.
+/// - **Starting input**: Initial value of the prompt's text input.
+/// - **Default value**: Default value, between 0 and 100, returned when the user submits an empty response.
+/// - **Step**: Amount added or subtracted when pressing the Up or Down arrows. Defaults to `1`.
+/// - **Scale**: Range of the returned value, [`PercentageScale::Percent`] by default.
+/// - **Bar width**: Amount of cells used to render the bar preview. Defaults to `20`.
+/// - **Placeholder**: Short hint that describes the expected value of the input.
+/// - **Help message**: Message displayed at the line below the bar.
+/// - **Formatter**: Custom formatter in case you need to pre-process the user input before showing it as the final answer.
+///   - Prints the value followed by `%` by default, e.g. `25%`.
+/// - **Validators**: Custom validators to the value between 0 and 100, displaying an error message if the value does not pass the requirements.
+/// - **Error message**: Error message to display when a value could not be parsed from the input.
+///
+/// # Example
+///
+/// ```no_run
+/// use inquire::{Percentage, PercentageScale};
+///
+/// let ratio = Percentage::<f64>::new("Rollout:")
+///     .with_default(10.0)
+///     .with_step(5.0)
+///     .with_scale(PercentageScale::Fraction)
+///     .prompt();
+///
+/// match ratio {
+///     Ok(ratio) => println!("Rolling out to {:.0} of 1000 hosts", ratio * 1000.0),
+///     Err(_) => println!("Could not read the rollout ratio"),
+/// }
+/// ```
+#[derive(Clone)]
+pub struct Percentage<'a, T> {
+    /// Message to be presented to the user.
+    pub message: &'a str,
+
+    /// Initial value of the prompt's text input.
+    ///
+    /// If you want to set a default value for the prompt, returned when the user's submission is empty, see [`default`].
+    ///
+    /// [`default`]: Self::default
+    pub starting_input: Option<&'a str>,
+
+    /// Default value between 0 and 100, returned when the user input is empty.
+    pub default: Option<T>,
+
+    /// Amount added or subtracted from the current value when the user presses the Up or Down arrows.
+    pub step: T,
+
+    /// Range of the returned value.
+    pub scale: PercentageScale,
+
+    /// Amount of cells used to render the bar preview.
+    pub bar_width: usize,
+
+    /// Short hint that describes the expected value of the input.
+    pub placeholder: Option<&'a str>,
+
+    /// Help message to be presented to the user.
+    pub help_message: Option<&'a str>,
+
+    /// Function that formats the value between 0 and 100 and presents it to the user as the final rendering of the prompt.
+    pub formatter: CustomTypeFormatter<'a, T>,
+
+    /// Collection of validators to apply to the value between 0 and 100.
+    ///
+    /// Validators are executed in the order they are stored, stopping at and displaying to the user
+    /// only the first validation error that might appear.
+    ///
+    /// The possible error is displayed to the user one line above the prompt.
+    pub validators: Vec<Box<dyn CustomTypeValidator<T>>>,
+
+    /// Error message displayed when value could not be parsed from input.
+    pub error_message: String,
+
+    /// RenderConfig to apply to the rendered interface.
+    ///
+    /// Note: The default render config considers if the NO_COLOR environment variable
+    /// is set to decide whether to render the colored config or the empty one.
+    ///
+    /// When overriding the config in a prompt, NO_COLOR is no longer considered and your
+    /// config is treated as the only source of truth. If you want to customize colors
+    /// and still support NO_COLOR, you will have to do this on your end.
+    pub render_config: RenderConfig<'a>,
+}
+
+impl<'a, T> Percentage<'a, T>
+where
+    T: PercentageValue,
+{
+    /// Default formatter, which prints the value followed by `%`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use inquire::Percentage;
+    ///
+    /// let formatter = Percentage::<f64>::DEFAULT_FORMATTER;
+    /// assert_eq!(String::from("12.5%"), formatter(12.5));
+    /// ```
+    pub const DEFAULT_FORMATTER: CustomTypeFormatter<'a, T> = &|val| format!("{}%", val);
+
+    /// Default scale of the returned value.
+    pub const DEFAULT_SCALE: PercentageScale = PercentageScale::Percent;
+
+    /// Default width of the bar preview.
+    pub const DEFAULT_BAR_WIDTH: usize = 20;
+
+    /// Default validators added to the [Percentage] prompt, none.
+    pub const DEFAULT_VALIDATORS: Vec<Box<dyn CustomTypeValidator<T>>> = vec![];
+
+    /// Default error message displayed when parsing fails.
+    pub const DEFAULT_ERROR_MESSAGE: &'a str = "Please type a valid percentage";
+
+    /// Creates a [Percentage] with the provided message and default configuration values.
+    pub fn new(message: &'a str) -> Self {
+        Self {
+            message,
+            starting_input: None,
+            default: None,
+            step: T::DEFAULT_STEP,
+            scale: Self::DEFAULT_SCALE,
+            bar_width: Self::DEFAULT_BAR_WIDTH,
+            placeholder: None,
+            help_message: None,
+            formatter: Self::DEFAULT_FORMATTER,
+            validators: Self::DEFAULT_VALIDATORS,
+            error_message: String::from(Self::DEFAULT_ERROR_MESSAGE),
+            render_config: get_configuration(),
+        }
+    }
+
+    /// Sets the initial value of the prompt's text input.
+    ///
+    /// If you want to set a default value for the prompt, returned when the user's submission is empty, see [`with_default`].
+    ///
+    /// [`with_default`]: Self::with_default
+    pub fn with_starting_input(mut self, message: &'a str) -> Self {
+        self.starting_input = Some(message);
+        self
+    }
+
+    /// Sets the default value, between 0 and 100.
+    pub fn with_default(mut self, default: T) -> Self {
+        self.default = Some(default);
+        self
+    }
+
+    /// Sets the step used when incrementing or decrementing the value.
+    pub fn with_step(mut self, step: T) -> Self {
+        self.step = step;
+        self
+    }
+
+    /// Sets the range of the returned value.
+    pub fn with_scale(mut self, scale: PercentageScale) -> Self {
+        self.scale = scale;
+        self
+    }
+
+    /// Sets the width of the bar preview.
+    pub fn with_bar_width(mut self, bar_width: usize) -> Self {
+        self.bar_width = bar_width;
+        self
+    }
+
+    /// Sets the placeholder.
+    pub fn with_placeholder(mut self, placeholder: &'a str) -> Self {
+        self.placeholder = Some(placeholder);
+        self
+    }
+
+    /// Sets the help message of the prompt.
+    pub fn with_help_message(mut self, message: &'a str) -> Self {
+        self.help_message = Some(message);
+        self
+    }
+
+    /// Sets the formatter.
+    pub fn with_formatter(mut self, formatter: CustomTypeFormatter<'a, T>) -> Self {
+        self.formatter = formatter;
+        self
+    }
+
+    /// Adds a validator to the collection of validators. You might want to use this feature
+    /// in case you need to require certain features from the parsed user's answer.
+    ///
+    /// Validators are executed in the order they are stored, stopping at and displaying to the user
+    /// only the first validation error that might appear.
+    ///
+    /// The possible error is displayed to the user one line above the prompt.
+    pub fn with_validator<V>(mut self, validator: V) -> Self
+    where
+        V: CustomTypeValidator<T> + 'static,
+    {
+        self.validators.push(Box::new(validator));
+        self
+    }
+
+    /// Adds the validators to the collection of validators in the order they are given.
+    /// You might want to use this feature in case you need to require certain features
+    /// from the parsed user's answer.
+    ///
+    /// Validators are executed in the order they are stored, stopping at and displaying to the user
+    /// only the first validation error that might appear.
+    ///
+    /// The possible error is displayed to the user one line above the prompt.
+    pub fn with_validators(mut self, validators: &[Box<dyn CustomTypeValidator<T>>]) -> Self {
+        for validator in validators {
+            #[allow(suspicious_double_ref_op)]
+            self.validators.push(validator.clone());
+        }
+        self
+    }
+
+    /// Sets a custom error message displayed when a submission could not be parsed to a value.
+    pub fn with_error_message(mut self, error_message: &'a str) -> Self {
+        self.error_message = String::from(error_message);
+        self
+    }
+
+    /// Sets the provided color theme to this prompt.
+    ///
+    /// Note: The default render config considers if the NO_COLOR environment variable
+    /// is set to decide whether to render the colored config or the empty one.
+    ///
+    /// When overriding the config in a prompt, NO_COLOR is no longer considered and your
+    /// config is treated as the only source of truth. If you want to customize colors
+    /// and still support NO_COLOR, you will have to do this on your end.
+    pub fn with_render_config(mut self, render_config: RenderConfig<'a>) -> Self {
+        self.render_config = render_config;
+        self
+    }
+
+    /// Parses the provided behavioral and rendering options and prompts
+    /// the CLI user for input according to the defined rules.
+    ///
+    /// This method is intended for flows where the user skipping/cancelling
+    /// the prompt - by pressing ESC - is considered normal behavior. In this case,
+    /// it does not return `Err(InquireError::OperationCanceled)`, but `Ok(None)`.
+    ///
+    /// Meanwhile, if the user does submit an answer, the method wraps the return
+    /// type with `Some`.
+    pub fn prompt_skippable(self) -> InquireResult<Option<T>> {
+        match self.prompt() {
+            Ok(answer) => Ok(Some(answer)),
+            Err(InquireError::OperationCanceled) => Ok(None),
+            Err(err) => Err(err),
+        }
+    }
+
+    /// Parses the provided behavioral and rendering options and prompts
+    /// the CLI user for input according to the defined rules.
+    ///
+    /// Returns the value normalized according to the [scale](PercentageScale).
+    pub fn prompt(self) -> InquireResult<T> {
+        let (input_reader, terminal) = get_default_terminal()?;
+        let mut backend = Backend::new(input_reader, terminal, self.render_config)?;
+        self.prompt_with_backend(&mut backend)
+    }
+
+    pub(crate) fn prompt_with_backend<B: PercentageBackend>(
+        self,
+        backend: &mut B,
+    ) -> InquireResult<T> {
+        let scale = self.scale;
+        let value = PercentagePrompt::new(self)?.prompt(backend)?;
+
+        match scale {
+            PercentageScale::Fraction => Ok(value.to_fraction()),
+            PercentageScale::Percent => Ok(value),
+        }
+    }
+}
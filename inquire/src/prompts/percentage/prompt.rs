@@ -0,0 +1,224 @@
+use crate::{
+    error::InquireResult,
+    formatter::CustomTypeFormatter,
+    input::Input,
+    prompts::prompt::{ActionResult, Prompt},
+    ui::PercentageBackend,
+    validator::{CustomTypeValidator, ErrorMessage, Validation},
+    InquireError, Percentage,
+};
+
+use super::{action::PercentagePromptAction, config::PercentageConfig, PercentageValue};
+
+pub struct PercentagePrompt<'a, T> {
+    message: &'a str,
+    config: PercentageConfig,
+    error: Option<ErrorMessage>,
+    help_message: Option<&'a str>,
+    default: Option<T>,
+    step: T,
+    bar_width: usize,
+    input: Input,
+    formatter: CustomTypeFormatter<'a, T>,
+    validators: Vec<Box<dyn CustomTypeValidator<T>>>,
+    error_message: String,
+}
+
+impl<'a, T> PercentagePrompt<'a, T>
+where
+    T: PercentageValue,
+{
+    pub fn new(po: Percentage<'a, T>) -> InquireResult<Self> {
+        if po.step.partial_cmp(&T::ZERO) != Some(std::cmp::Ordering::Greater) {
+            return Err(InquireError::InvalidConfiguration(
+                "Step must be greater than zero".into(),
+            ));
+        }
+
+        if let Some(default) = po.default {
+            if !Self::in_bounds(default) {
+                return Err(InquireError::InvalidConfiguration(
+                    "Default value must be between 0 and 100".into(),
+                ));
+            }
+        }
+
+        let input = Input::new_with(po.starting_input.unwrap_or_default());
+        let input = if let Some(placeholder) = po.placeholder {
+            input.with_placeholder(placeholder)
+        } else {
+            input
+        };
+
+        Ok(Self {
+            message: po.message,
+            config: (&po).into(),
+            error: None,
+            help_message: po.help_message,
+            default: po.default,
+            step: po.step,
+            bar_width: po.bar_width,
+            input,
+            formatter: po.formatter,
+            validators: po.validators,
+            error_message: po.error_message,
+        })
+    }
+
+    fn in_bounds(value: T) -> bool {
+        value >= T::ZERO && value <= T::HUNDRED
+    }
+
+    fn parse(input: &str) -> Option<T> {
+        let input = input.trim();
+        let input = input.strip_suffix('%').unwrap_or(input).trim_end();
+
+        input.parse::<T>().ok()
+    }
+
+    /// Value previewed in the bar and used as the base when stepping, the
+    /// parsed input or, when the input is empty, the default value or zero.
+    fn current_value(&self) -> Option<T> {
+        if self.input.content().is_empty() {
+            return Some(self.default.unwrap_or(T::ZERO));
+        }
+
+        Self::parse(self.input.content())
+    }
+
+    fn step_value(&mut self, increment: bool) -> ActionResult {
+        let value = match self.current_value() {
+            Some(value) => value,
+            None => return ActionResult::Clean,
+        };
+
+        let new_value = match increment {
+            true => value.step_up(self.step),
+            false => value.step_down(self.step),
+        };
+        let new_value = match new_value {
+            v if v < T::ZERO => T::ZERO,
+            v if v > T::HUNDRED => T::HUNDRED,
+            v => v,
+        };
+        let new_value = new_value.to_string();
+
+        if new_value == self.input.content() {
+            return ActionResult::Clean;
+        }
+
+        self.input = Input::new_with(new_value);
+        ActionResult::NeedsRedraw
+    }
+
+    /// Number of filled cells of the bar preview.
+    fn filled_cells(&self) -> usize {
+        let value = match self.current_value() {
+            Some(value) => value.to_f64().clamp(0.0, 100.0),
+            None => 0.0,
+        };
+
+        (value / 100.0 * self.bar_width as f64).round() as usize
+    }
+
+    fn validate_current_answer(&self, value: &T) -> InquireResult<Validation> {
+        if !Self::in_bounds(*value) {
+            return Ok(Validation::Invalid(
+                "Percentage must be between 0% and 100%".into(),
+            ));
+        }
+
+        for validator in &self.validators {
+            match validator.validate(value) {
+                Ok(Validation::Valid) => {}
+                Ok(Validation::Invalid(msg)) => return Ok(Validation::Invalid(msg)),
+                Err(err) => return Err(InquireError::Custom(err)),
+            }
+        }
+
+        Ok(Validation::Valid)
+    }
+
+    fn get_final_answer(&self) -> Result<T, String> {
+        match self.default {
+            Some(val) if self.input.content().is_empty() => return Ok(val),
+            _ => {}
+        }
+
+        match Self::parse(self.input.content()) {
+            Some(val) => Ok(val),
+            None => Err(self.error_message.clone()),
+        }
+    }
+}
+
+impl<'a, Backend, T> Prompt<Backend> for PercentagePrompt<'a, T>
+where
+    Backend: PercentageBackend,
+    T: PercentageValue,
+{
+    type Config = PercentageConfig;
+    type InnerAction = PercentagePromptAction;
+    type Output = T;
+
+    fn message(&self) -> &str {
+        self.message
+    }
+
+    fn config(&self) -> &PercentageConfig {
+        &self.config
+    }
+
+    fn format_answer(&self, answer: &T) -> String {
+        (self.formatter)(*answer)
+    }
+
+    fn submit(&mut self) -> InquireResult<Option<T>> {
+        let answer = match self.get_final_answer() {
+            Ok(answer) => match self.validate_current_answer(&answer)? {
+                Validation::Valid => Some(answer),
+                Validation::Invalid(msg) => {
+                    self.error = Some(msg);
+                    None
+                }
+            },
+            Err(message) => {
+                self.error = Some(message.into());
+                None
+            }
+        };
+
+        Ok(answer)
+    }
+
+    fn handle(&mut self, action: PercentagePromptAction) -> InquireResult<ActionResult> {
+        let result = match action {
+            PercentagePromptAction::ValueInput(input_action) => {
+                self.input.handle(input_action).into()
+            }
+            PercentagePromptAction::Increment => self.step_value(true),
+            PercentagePromptAction::Decrement => self.step_value(false),
+        };
+
+        Ok(result)
+    }
+
+    fn render(&self, backend: &mut Backend) -> InquireResult<()> {
+        let prompt = &self.message;
+
+        if let Some(error) = &self.error {
+            backend.render_error_message(error)?;
+        }
+
+        let default_message = self.default.map(self.formatter);
+
+        backend.render_prompt(prompt, default_message.as_deref(), &self.input)?;
+        backend.render_percentage_bar(self.filled_cells(), self.bar_width)?;
+
+        if let Some(message) = self.help_message {
+            backend.render_help_message(message)?;
+        }
+
+        Ok(())
+    }
+}
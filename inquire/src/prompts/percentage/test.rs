@@ -0,0 +1,118 @@
+use crate::{
+    error::InquireResult,
+    input::Input,
+    ui::{
+        test::{FakeBackend, Token},
+        Key, KeyModifiers,
+    },
+    validator::ErrorMessage,
+    Percentage, PercentageScale,
+};
+
+fn bar(filled: usize) -> Token {
+    Token::PercentageBar { filled, width: 20 }
+}
+
+#[test]
+fn typed_value_is_returned_and_previewed() -> InquireResult<()> {
+    let mut input = Key::char_keys_from_str("25%");
+    input.push(Key::Enter);
+    let mut backend = FakeBackend::new(input);
+
+    let ans = Percentage::<f64>::new("Discount").prompt_with_backend(&mut backend)?;
+
+    assert_eq!(25.0, ans);
+    assert!(backend.frames()[0].has_token(&bar(0)));
+    assert!(backend.frames()[2].has_token(&bar(5)));
+    assert!(backend
+        .frames()
+        .last()
+        .unwrap()
+        .has_token(&Token::AnsweredPrompt("Discount".into(), "25%".into())));
+
+    Ok(())
+}
+
+#[test]
+fn fraction_scale_normalizes_the_value() -> InquireResult<()> {
+    let mut input = Key::char_keys_from_str("12.5");
+    input.push(Key::Enter);
+    let mut backend = FakeBackend::new(input);
+
+    let ans = Percentage::<f32>::new("Ratio")
+        .with_scale(PercentageScale::Fraction)
+        .prompt_with_backend(&mut backend)?;
+
+    assert_eq!(0.125, ans);
+    assert!(backend
+        .frames()
+        .last()
+        .unwrap()
+        .has_token(&Token::AnsweredPrompt("Ratio".into(), "12.5%".into())));
+
+    Ok(())
+}
+
+#[test]
+fn arrows_step_from_default_and_clamp() -> InquireResult<()> {
+    let mut input = vec![Key::Up(KeyModifiers::NONE); 3];
+    input.push(Key::Enter);
+    let mut backend = FakeBackend::new(input);
+
+    let ans = Percentage::<f64>::new("Rollout")
+        .with_default(90.0)
+        .with_step(5.0)
+        .prompt_with_backend(&mut backend)?;
+
+    assert_eq!(100.0, ans);
+    assert!(backend.frames()[1].has_token(&Token::Input(Input::new_with("95"))));
+    assert!(backend.frames()[2].has_token(&bar(20)));
+
+    let mut backend = FakeBackend::new(vec![Key::Down(KeyModifiers::NONE), Key::Enter]);
+
+    let ans = Percentage::<f64>::new("Rollout").prompt_with_backend(&mut backend)?;
+
+    assert_eq!(0.0, ans);
+
+    Ok(())
+}
+
+#[test]
+fn out_of_bounds_values_are_rejected() -> InquireResult<()> {
+    let mut input = Key::char_keys_from_str("150");
+    input.push(Key::Enter);
+    input.push(Key::Backspace);
+    input.push(Key::Backspace);
+    input.push(Key::Enter);
+    let mut backend = FakeBackend::new(input);
+
+    let ans = Percentage::<f64>::new("Discount").prompt_with_backend(&mut backend)?;
+
+    assert_eq!(1.0, ans);
+    assert!(backend.frames()[3].has_token(&bar(20)));
+    assert!(
+        backend.frames()[4].has_token(&Token::ErrorMessage(ErrorMessage::Custom(
+            "Percentage must be between 0% and 100%".into()
+        )))
+    );
+
+    Ok(())
+}
+
+#[test]
+fn invalid_input_is_rejected() -> InquireResult<()> {
+    let mut input = Key::char_keys_from_str("ten");
+    input.push(Key::Enter);
+    let mut backend = FakeBackend::new(input);
+
+    let ans = Percentage::<f64>::new("Discount").prompt_with_backend(&mut backend);
+
+    assert!(ans.is_err());
+    assert!(
+        backend.frames()[4].has_token(&Token::ErrorMessage(ErrorMessage::Custom(
+            "Please type a valid percentage".into()
+        )))
+    );
+
+    Ok(())
+}
@@ -0,0 +1,39 @@
+use std::{fmt::Display, str::FromStr};
+
+use crate::Steppable;
+
+/// Floating point types returned by [`Percentage`](crate::Percentage) prompts.
+///
+/// Implementations are provided for `f32` and `f64`.
+pub trait PercentageValue: Steppable + FromStr + Display {
+    /// Value of 100%, the upper bound of the prompt.
+    const HUNDRED: Self;
+
+    /// Divides the value by 100, normalizing it to the 0–1 range.
+    fn to_fraction(self) -> Self;
+}
+
+impl PercentageValue for f32 {
+    const HUNDRED: Self = 100.0;
+
+    fn to_fraction(self) -> Self {
+        self / 100.0
+    }
+}
+
+impl PercentageValue for f64 {
+    const HUNDRED: Self = 100.0;
+
+    fn to_fraction(self) -> Self {
+        self / 100.0
+    }
+}
+
+/// Range of the values returned by [`Percentage`](crate::Percentage) prompts.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum PercentageScale {
+    /// Values between 0 and 1, e.g. `0.25` for 25%.
+    Fraction,
+    /// Values between 0 and 100, e.g. `25.0` for 25%.
+    Percent,
+}
@@ -0,0 +1,31 @@
+use crate::{
+    ui::{Key, KeyModifiers},
+    InnerAction,
+};
+
+use super::config::PhoneNumberConfig;
+
+/// Set of actions for a PhoneNumberPrompt.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum PhoneNumberPromptAction {
+    /// Fills the next slot of the mask with a digit.
+    TypeDigit(char),
+    /// Removes the last digit.
+    DeleteDigit,
+}
+
+impl InnerAction for PhoneNumberPromptAction {
+    type Config = PhoneNumberConfig;
+
+    fn from_key(key: Key, _config: &PhoneNumberConfig) -> Option<Self> {
+        let action = match key {
+            Key::Char(c, m) if c.is_ascii_digit() && !m.contains(KeyModifiers::CONTROL) => {
+                Self::TypeDigit(c)
+            }
+            Key::Backspace => Self::DeleteDigit,
+            _ => return None,
+        };
+
+        Some(action)
+    }
+}
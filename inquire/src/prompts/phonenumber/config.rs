@@ -0,0 +1,10 @@
+use crate::PhoneNumber;
+
+/// Configuration settings used in the execution of a PhoneNumberPrompt.
+pub struct PhoneNumberConfig {}
+
+impl From<&PhoneNumber<'_>> for PhoneNumberConfig {
+    fn from(_value: &PhoneNumber<'_>) -> Self {
+        Self {}
+    }
+}
@@ -0,0 +1,52 @@
+/// Character of a mask that is filled by a digit, every other character being
+/// a literal.
+pub(super) const DIGIT_SLOT: char = '_';
+
+/// Number of digits that fit in the mask.
+pub(super) fn slot_count(mask: &str) -> usize {
+    mask.chars().filter(|c| *c == DIGIT_SLOT).count()
+}
+
+/// Splits the mask filled with the digits into the part before the cursor and
+/// the remaining slots.
+///
+/// Literals right after the last digit belong to the first part, so that the
+/// cursor skips over them and rests on the next slot, e.g. `(555) ` and `___-____`.
+pub(super) fn split(mask: &str, digits: &str) -> (String, String) {
+    let mut digits = digits.chars();
+    let mut filled = String::new();
+
+    for (idx, c) in mask.char_indices() {
+        if c != DIGIT_SLOT {
+            filled.push(c);
+        } else if let Some(digit) = digits.next() {
+            filled.push(digit);
+        } else {
+            return (filled, mask[idx..].to_string());
+        }
+    }
+
+    (filled, String::new())
+}
+
+/// Mask filled with the digits, without the literals after the last digit
+/// unless every slot is filled.
+pub(super) fn format(mask: &str, digits: &str) -> String {
+    let mut digits = digits.chars();
+    let mut formatted = String::new();
+    let mut end = 0;
+
+    for c in mask.chars() {
+        if c != DIGIT_SLOT {
+            formatted.push(c);
+        } else if let Some(digit) = digits.next() {
+            formatted.push(digit);
+            end = formatted.len();
+        } else {
+            formatted.truncate(end);
+            break;
+        }
+    }
+
+    formatted
+}
@@ -0,0 +1,229 @@
+mod action;
+mod config;
+mod mask;
+mod prompt;
+#[cfg(test)]
+mod test;
+
+pub use action::*;
+
+use crate::{
+    config::get_configuration,
+    error::{InquireError, InquireResult},
+    formatter::StringFormatter,
+    prompts::prompt::Prompt,
+    terminal::get_default_terminal,
+    ui::{Backend, MaskBackend, RenderConfig},
+    validator::StringValidator,
+};
+
+use self::prompt::PhoneNumberPrompt;
+
+/// Prompt suitable for phone numbers, displaying a mask such as `(___) ___-____` that is filled as the user types.
+///
+/// Each `_` of the mask is a slot for a digit, while every other character is a literal displayed as is. Only digits are accepted, filling the slots from left to right, and the cursor automatically skips over the literals. Backspace removes the last digit.
+///
+/// The prompt returns the raw digits, e.g. `5551234567`, while the final answer is displayed with the mask applied, e.g. `(555) 123-4567`.
+///
+/// By default all slots of the mask must be filled. For variable-length formats, such as [E.164](PhoneNumber::E164_MASK) numbers, a minimum number of digits can be set instead.
+///
+/// Phone number prompts provide several options of configuration:
+///
+/// - **Prompt message**: Required when creating the prompt.
+/// - **Mask**: Format of the number, `(___) ___-____` by default. Must have at least one slot.
+/// - **Minimum digits**: Number of digits required to submit the prompt, every slot of the mask by default.
+/// - **Default value**: Digits returned when the user submits an empty response.
+/// - **Help message**: Message displayed at the line below the prompt.
+/// - **Formatter**: Custom formatter in case you need to pre-process the user input before showing it as the final answer.
+///   - Receives the number with the mask applied and prints it as is by default.
+/// - **Validators**: Custom validators to the raw digits, displaying an error message if they do not pass the requirements.
+///
+/// # Example
+///
+/// ```no_run
+/// use inquire::PhoneNumber;
+///
+/// let ans = PhoneNumber::new("Phone:")
+///     .with_mask(PhoneNumber::E164_MASK)
+///     .with_min_digits(8)
+///     .prompt();
+///
+/// match ans {
+///     Ok(digits) => println!("Sending a code to +{}", digits),
+///     Err(_) => println!("Could not read the phone number"),
+/// }
+/// ```
+#[derive(Clone)]
+pub struct PhoneNumber<'a> {
+    /// Message to be presented to the user.
+    pub message: &'a str,
+
+    /// Format of the number, where each `_` is a slot for a digit.
+    pub mask: &'a str,
+
+    /// Number of digits required to submit the prompt. When `None`, every
+    /// slot of the mask must be filled.
+    pub min_digits: Option<usize>,
+
+    /// Default digits, returned when the user input is empty.
+    pub default: Option<&'a str>,
+
+    /// Help message to be presented to the user.
+    pub help_message: Option<&'a str>,
+
+    /// Function that formats the user input and presents it to the user as the final rendering of the prompt.
+    pub formatter: StringFormatter<'a>,
+
+    /// Collection of validators to apply to the raw digits.
+    ///
+    /// Validators are executed in the order they are stored, stopping at and displaying to the user
+    /// only the first validation error that might appear.
+    ///
+    /// The possible error is displayed to the user one line above the prompt.
+    pub validators: Vec<Box<dyn StringValidator>>,
+
+    /// RenderConfig to apply to the rendered interface.
+    ///
+    /// Note: The default render config considers if the NO_COLOR environment variable
+    /// is set to decide whether to render the colored config or the empty one.
+    ///
+    /// When overriding the config in a prompt, NO_COLOR is no longer considered and your
+    /// config is treated as the only source of truth. If you want to customize colors
+    /// and still support NO_COLOR, you will have to do this on your end.
+    pub render_config: RenderConfig<'a>,
+}
+
+impl<'a> PhoneNumber<'a> {
+    /// Default mask, for numbers of the North American Numbering Plan.
+    pub const DEFAULT_MASK: &'static str = "(___) ___-____";
+
+    /// Mask for numbers in the E.164 format, i.e. a `+` followed by the
+    /// country code and the subscriber number, with at most 15 digits.
+    pub const E164_MASK: &'static str = "+_______________";
+
+    /// Default formatter, which prints the number with the mask applied.
+    pub const DEFAULT_FORMATTER: StringFormatter<'a> = &|ans| ans.to_owned();
+
+    /// Default validators added to the [PhoneNumber] prompt, none.
+    pub const DEFAULT_VALIDATORS: Vec<Box<dyn StringValidator>> = vec![];
+
+    /// Creates a [PhoneNumber] with the provided message, along with default configuration values.
+    pub fn new(message: &'a str) -> Self {
+        Self {
+            message,
+            mask: Self::DEFAULT_MASK,
+            min_digits: None,
+            default: None,
+            help_message: None,
+            formatter: Self::DEFAULT_FORMATTER,
+            validators: Self::DEFAULT_VALIDATORS,
+            render_config: get_configuration(),
+        }
+    }
+
+    /// Sets the mask, where each `_` is a slot for a digit.
+    pub fn with_mask(mut self, mask: &'a str) -> Self {
+        self.mask = mask;
+        self
+    }
+
+    /// Sets the number of digits required to submit the prompt.
+    pub fn with_min_digits(mut self, min_digits: usize) -> Self {
+        self.min_digits = Some(min_digits);
+        self
+    }
+
+    /// Sets the default digits.
+    pub fn with_default(mut self, default: &'a str) -> Self {
+        self.default = Some(default);
+        self
+    }
+
+    /// Sets the help message of the prompt.
+    pub fn with_help_message(mut self, message: &'a str) -> Self {
+        self.help_message = Some(message);
+        self
+    }
+
+    /// Sets the formatter.
+    pub fn with_formatter(mut self, formatter: StringFormatter<'a>) -> Self {
+        self.formatter = formatter;
+        self
+    }
+
+    /// Adds a validator to the collection of validators. You might want to use this feature
+    /// in case you need to require certain features from the number, such as a country code.
+    ///
+    /// Validators are executed in the order they are stored, stopping at and displaying to the user
+    /// only the first validation error that might appear.
+    ///
+    /// The possible error is displayed to the user one line above the prompt.
+    pub fn with_validator<V>(mut self, validator: V) -> Self
+    where
+        V: StringValidator + 'static,
+    {
+        self.validators.push(Box::new(validator));
+        self
+    }
+
+    /// Adds the validators to the collection of validators in the order they are given.
+    ///
+    /// Validators are executed in the order they are stored, stopping at and displaying to the user
+    /// only the first validation error that might appear.
+    ///
+    /// The possible error is displayed to the user one line above the prompt.
+    pub fn with_validators(mut self, validators: &[Box<dyn StringValidator>]) -> Self {
+        for validator in validators {
+            #[allow(suspicious_double_ref_op)]
+            self.validators.push(validator.clone());
+        }
+        self
+    }
+
+    /// Sets the provided color theme to this prompt.
+    ///
+    /// Note: The default render config considers if the NO_COLOR environment variable
+    /// is set to decide whether to render the colored config or the empty one.
+    ///
+    /// When overriding the config in a prompt, NO_COLOR is no longer considered and your
+    /// config is treated as the only source of truth. If you want to customize colors
+    /// and still support NO_COLOR, you will have to do this on your end.
+    pub fn with_render_config(mut self, render_config: RenderConfig<'a>) -> Self {
+        self.render_config = render_config;
+        self
+    }
+
+    /// Parses the provided behavioral and rendering options and prompts
+    /// the CLI user for input according to the defined rules.
+    ///
+    /// This method is intended for flows where the user skipping/cancelling
+    /// the prompt - by pressing ESC - is considered normal behavior. In this case,
+    /// it does not return `Err(InquireError::OperationCanceled)`, but `Ok(None)`.
+    ///
+    /// Meanwhile, if the user does submit an answer, the method wraps the return
+    /// type with `Some`.
+    pub fn prompt_skippable(self) -> InquireResult<Option<String>> {
+        match self.prompt() {
+            Ok(answer) => Ok(Some(answer)),
+            Err(InquireError::OperationCanceled) => Ok(None),
+            Err(err) => Err(err),
+        }
+    }
+
+    /// Parses the provided behavioral and rendering options and prompts
+    /// the CLI user for input according to the defined rules.
+    ///
+    /// Returns the raw digits typed by the user, without the literals of the mask.
+    pub fn prompt(self) -> InquireResult<String> {
+        let (input_reader, terminal) = get_default_terminal()?;
+        let mut backend = Backend::new(input_reader, terminal, self.render_config)?;
+        self.prompt_with_backend(&mut backend)
+    }
+
+    pub(crate) fn prompt_with_backend<B: MaskBackend>(
+        self,
+        backend: &mut B,
+    ) -> InquireResult<String> {
+        PhoneNumberPrompt::new(self)?.prompt(backend)
+    }
+}
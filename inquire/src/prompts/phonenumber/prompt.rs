@@ -0,0 +1,165 @@
+use crate::{
+    error::InquireResult,
+    formatter::StringFormatter,
+    prompts::prompt::{ActionResult, Prompt},
+    ui::MaskBackend,
+    validator::{ErrorMessage, StringValidator, Validation},
+    InquireError, PhoneNumber,
+};
+
+use super::{action::PhoneNumberPromptAction, config::PhoneNumberConfig, mask};
+
+pub struct PhoneNumberPrompt<'a> {
+    message: &'a str,
+    config: PhoneNumberConfig,
+    error: Option<ErrorMessage>,
+    help_message: Option<&'a str>,
+    mask: &'a str,
+    slots: usize,
+    min_digits: usize,
+    default: Option<&'a str>,
+    digits: String,
+    formatter: StringFormatter<'a>,
+    validators: Vec<Box<dyn StringValidator>>,
+}
+
+impl<'a> PhoneNumberPrompt<'a> {
+    pub fn new(po: PhoneNumber<'a>) -> InquireResult<Self> {
+        let slots = mask::slot_count(po.mask);
+
+        if slots == 0 {
+            return Err(InquireError::InvalidConfiguration(format!(
+                "Mask must have at least one '{}' slot",
+                mask::DIGIT_SLOT
+            )));
+        }
+
+        let min_digits = po.min_digits.unwrap_or(slots);
+
+        if min_digits > slots {
+            return Err(InquireError::InvalidConfiguration(format!(
+                "Minimum of {} digits is greater than the {} slots of the mask",
+                min_digits, slots
+            )));
+        }
+
+        if let Some(default) = po.default {
+            if default.len() > slots || !default.chars().all(|c| c.is_ascii_digit()) {
+                return Err(InquireError::InvalidConfiguration(format!(
+                    "Default value must have at most {} digits and nothing else",
+                    slots
+                )));
+            }
+        }
+
+        Ok(Self {
+            message: po.message,
+            config: (&po).into(),
+            error: None,
+            help_message: po.help_message,
+            mask: po.mask,
+            slots,
+            min_digits,
+            default: po.default,
+            digits: String::new(),
+            formatter: po.formatter,
+            validators: po.validators,
+        })
+    }
+
+    fn validate_current_answer(&self, digits: &str) -> InquireResult<Validation> {
+        if digits.len() < self.min_digits {
+            let message = if self.min_digits == self.slots {
+                format!("Phone number must have {} digits", self.min_digits)
+            } else {
+                format!("Phone number must have at least {} digits", self.min_digits)
+            };
+
+            return Ok(Validation::Invalid(message.into()));
+        }
+
+        for validator in &self.validators {
+            match validator.validate(digits) {
+                Ok(Validation::Valid) => {}
+                Ok(Validation::Invalid(msg)) => return Ok(Validation::Invalid(msg)),
+                Err(err) => return Err(InquireError::Custom(err)),
+            }
+        }
+
+        Ok(Validation::Valid)
+    }
+}
+
+impl<'a, Backend> Prompt<Backend> for PhoneNumberPrompt<'a>
+where
+    Backend: MaskBackend,
+{
+    type Config = PhoneNumberConfig;
+    type InnerAction = PhoneNumberPromptAction;
+    type Output = String;
+
+    fn message(&self) -> &str {
+        self.message
+    }
+
+    fn config(&self) -> &PhoneNumberConfig {
+        &self.config
+    }
+
+    fn format_answer(&self, answer: &String) -> String {
+        (self.formatter)(&mask::format(self.mask, answer))
+    }
+
+    fn submit(&mut self) -> InquireResult<Option<String>> {
+        if self.digits.is_empty() {
+            if let Some(default) = self.default {
+                return Ok(Some(default.to_owned()));
+            }
+        }
+
+        let answer = match self.validate_current_answer(&self.digits)? {
+            Validation::Valid => Some(std::mem::take(&mut self.digits)),
+            Validation::Invalid(msg) => {
+                self.error = Some(msg);
+                None
+            }
+        };
+
+        Ok(answer)
+    }
+
+    fn handle(&mut self, action: PhoneNumberPromptAction) -> InquireResult<ActionResult> {
+        let result = match action {
+            PhoneNumberPromptAction::TypeDigit(digit) if self.digits.len() < self.slots => {
+                self.digits.push(digit);
+                ActionResult::NeedsRedraw
+            }
+            PhoneNumberPromptAction::TypeDigit(_) => ActionResult::Clean,
+            PhoneNumberPromptAction::DeleteDigit => match self.digits.pop() {
+                Some(_) => ActionResult::NeedsRedraw,
+                None => ActionResult::Clean,
+            },
+        };
+
+        Ok(result)
+    }
+
+    fn render(&self, backend: &mut Backend) -> InquireResult<()> {
+        let prompt = &self.message;
+
+        if let Some(error) = &self.error {
+            backend.render_error_message(error)?;
+        }
+
+        let default_message = self.default.map(|default| mask::format(self.mask, default));
+        let (filled, remaining) = mask::split(self.mask, &self.digits);
+
+        backend.render_masked_prompt(prompt, default_message.as_deref(), &filled, &remaining)?;
+
+        if let Some(message) = self.help_message {
+            backend.render_help_message(message)?;
+        }
+
+        Ok(())
+    }
+}
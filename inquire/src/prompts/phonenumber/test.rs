@@ -0,0 +1,163 @@
+use crate::{
+    error::InquireResult,
+    ui::{
+        test::{FakeBackend, Token},
+        Key,
+    },
+    validator::{ErrorMessage, Validation},
+    PhoneNumber,
+};
+
+use super::mask;
+
+fn masked(filled: &str, remaining: &str) -> Token {
+    Token::Masked {
+        filled: filled.into(),
+        remaining: remaining.into(),
+    }
+}
+
+#[test]
+fn digits_fill_the_mask_and_are_returned_raw() -> InquireResult<()> {
+    let mut input = Key::char_keys_from_str("5551234567");
+    input.push(Key::Enter);
+    let mut backend = FakeBackend::new(input);
+
+    let ans = PhoneNumber::new("Phone").prompt_with_backend(&mut backend)?;
+
+    assert_eq!("5551234567", ans);
+    assert!(backend.frames()[0].has_token(&masked("(", "___) ___-____")));
+    assert!(backend.frames()[3].has_token(&masked("(555) ", "___-____")));
+    assert!(backend
+        .frames()
+        .last()
+        .unwrap()
+        .has_token(&Token::AnsweredPrompt(
+            "Phone".into(),
+            "(555) 123-4567".into()
+        )));
+
+    Ok(())
+}
+
+#[test]
+fn literals_and_extra_digits_are_ignored() -> InquireResult<()> {
+    let mut input = Key::char_keys_from_str("(555) 123-45678");
+    input.push(Key::Enter);
+    let mut backend = FakeBackend::new(input);
+
+    let ans = PhoneNumber::new("Phone").prompt_with_backend(&mut backend)?;
+
+    assert_eq!("5551234567", ans);
+
+    Ok(())
+}
+
+#[test]
+fn backspace_deletes_last_digit() -> InquireResult<()> {
+    let mut input = Key::char_keys_from_str("5554");
+    input.push(Key::Backspace);
+    input.push(Key::Backspace);
+    input.extend(Key::char_keys_from_str("51234567"));
+    input.push(Key::Enter);
+    let mut backend = FakeBackend::new(input);
+
+    let ans = PhoneNumber::new("Phone").prompt_with_backend(&mut backend)?;
+
+    assert_eq!("5551234567", ans);
+    assert!(backend.frames()[6].has_token(&masked("(55", "_) ___-____")));
+
+    Ok(())
+}
+
+#[test]
+fn incomplete_number_is_rejected() -> InquireResult<()> {
+    let mut input = Key::char_keys_from_str("555123");
+    input.push(Key::Enter);
+    input.extend(Key::char_keys_from_str("4567"));
+    input.push(Key::Enter);
+    let mut backend = FakeBackend::new(input);
+
+    let ans = PhoneNumber::new("Phone").prompt_with_backend(&mut backend)?;
+
+    assert_eq!("5551234567", ans);
+    assert!(
+        backend.frames()[7].has_token(&Token::ErrorMessage(ErrorMessage::Custom(
+            "Phone number must have 10 digits".into()
+        )))
+    );
+
+    Ok(())
+}
+
+#[test]
+fn min_digits_and_validators_are_applied() -> InquireResult<()> {
+    let mut input = Key::char_keys_from_str("0123");
+    input.push(Key::Enter);
+    for _ in 0..4 {
+        input.push(Key::Backspace);
+    }
+    input.extend(Key::char_keys_from_str("4930123456"));
+    input.push(Key::Enter);
+    let mut backend = FakeBackend::new(input);
+
+    let ans = PhoneNumber::new("Phone")
+        .with_mask(PhoneNumber::E164_MASK)
+        .with_min_digits(4)
+        .with_validator(|digits: &str| {
+            if digits.starts_with('0') {
+                Ok(Validation::Invalid(
+                    "Country codes never start with 0".into(),
+                ))
+            } else {
+                Ok(Validation::Valid)
+            }
+        })
+        .prompt_with_backend(&mut backend)?;
+
+    assert_eq!("4930123456", ans);
+    assert!(
+        backend.frames()[5].has_token(&Token::ErrorMessage(ErrorMessage::Custom(
+            "Country codes never start with 0".into()
+        )))
+    );
+    assert!(backend
+        .frames()
+        .last()
+        .unwrap()
+        .has_token(&Token::AnsweredPrompt("Phone".into(), "+4930123456".into())));
+
+    Ok(())
+}
+
+#[test]
+fn empty_input_submits_default() -> InquireResult<()> {
+    let mut backend = FakeBackend::new(vec![Key::Enter]);
+
+    let ans = PhoneNumber::new("Phone")
+        .with_default("5550000000")
+        .prompt_with_backend(&mut backend)?;
+
+    assert_eq!("5550000000", ans);
+    assert!(backend.frames()[0].has_token(&Token::DefaultValue("(555) 000-0000".into())));
+
+    Ok(())
+}
+
+#[test]
+fn mask_is_split_at_the_next_slot() {
+    assert_eq!(
+        ("(".into(), "___) ___-____".into()),
+        mask::split("(___) ___-____", "")
+    );
+    assert_eq!(
+        ("(555) 1".into(), "__-____".into()),
+        mask::split("(___) ___-____", "5551")
+    );
+    assert_eq!(("12-3".into(), String::new()), mask::split("__-_", "123"));
+
+    assert_eq!("", mask::format("(___) ___-____", ""));
+    assert_eq!("(555", mask::format("(___) ___-____", "555"));
+    assert_eq!("(555) 1", mask::format("(___) ___-____", "5551"));
+    assert_eq!("x12x", mask::format("x__x", "12"));
+}
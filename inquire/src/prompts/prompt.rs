@@ -1,6 +1,13 @@
 //! Definitions of common behavior shared amongst all different prompt types.
 
-use crate::{error::InquireResult, input::InputActionResult, ui::CommonBackend, InquireError};
+use std::time::Duration;
+
+use crate::{
+    error::InquireResult,
+    input::InputActionResult,
+    ui::{CommonBackend, InputEvent, Key, KeyModifiers},
+    InquireError,
+};
 
 use super::action::{Action, InnerAction};
 
@@ -69,12 +76,80 @@ where
     /// * `answer` - Answer returned by the prompt.
     fn format_answer(&self, answer: &Self::Output) -> String;
 
+    /// Parses a key event into a prompt action.
+    ///
+    /// The default implementation applies the keybindings shared by all
+    /// prompts, such as enter to submit and escape to cancel, deferring any
+    /// other key to the prompt's inner action. Prompts that need to reassign
+    /// one of the shared keybindings, e.g. a multi-line input where enter
+    /// inserts a line break, can reimplement this method.
+    fn parse_key(&self, key: Key) -> Option<Action<Self::InnerAction>> {
+        Action::from_key(key, self.config())
+    }
+
+    /// Hook called when the user pastes text, reported at once by the
+    /// terminals supporting bracketed paste.
+    ///
+    /// The default implementation handles each character as if it was typed,
+    /// skipping line breaks and other control characters, so that pasting
+    /// multiple lines never submits the prompt. Prompts with a text input
+    /// should reimplement this method to insert the text as a single edit,
+    /// handling its line breaks as they see fit.
+    fn handle_paste(&mut self, text: &str) -> InquireResult<ActionResult> {
+        let mut result = ActionResult::Clean;
+
+        for c in text.chars().filter(|c| !c.is_control()) {
+            if let Some(Action::Inner(action)) = self.parse_key(Key::Char(c, KeyModifiers::NONE)) {
+                result = result.merge(self.handle(action)?);
+            }
+        }
+
+        Ok(result)
+    }
+
     /// Hook called when a prompt is first started, before the first
     /// draw happens.
     fn setup(&mut self) -> InquireResult<()> {
         Ok(())
     }
 
+    /// Whether the prompt reads mouse events, capturing the mouse while it is
+    /// open.
+    ///
+    /// Capturing the mouse disables the text selection of the terminal, so
+    /// the default implementation returns false, leaving it to the prompts
+    /// handling [`Key::Mouse`].
+    fn captures_mouse(&self) -> bool {
+        false
+    }
+
+    /// Maximum time to wait for a key press before calling [`tick`](Self::tick).
+    ///
+    /// The default implementation returns `None`, waiting indefinitely.
+    fn tick_interval(&self) -> Option<Duration> {
+        None
+    }
+
+    /// Whether the prompt relies on its ticks, e.g. to load options or to
+    /// count down, rather than only for visual effects.
+    ///
+    /// Such prompts fail with [`InquireError::InvalidConfiguration`] on the
+    /// terminal back-ends that can not wait for a key press with a timeout,
+    /// instead of being stuck until a key is pressed. The default
+    /// implementation returns true whenever there is a tick interval.
+    fn requires_ticks(&self) -> bool {
+        self.tick_interval().is_some()
+    }
+
+    /// Hook called when no key was pressed during the tick interval, for
+    /// prompts that change over time, e.g. a countdown. The prompt is
+    /// re-rendered after every tick.
+    ///
+    /// Returns an action to be performed, as if it came from a key event.
+    fn tick(&mut self) -> InquireResult<Option<Action<Self::InnerAction>>> {
+        Ok(None)
+    }
+
     /// Hook called when an input to cancel the prompt is triggered.
     ///
     /// Returns whether the prompt can be terminated.
@@ -118,50 +193,96 @@ where
     fn prompt(mut self, backend: &mut Backend) -> InquireResult<Self::Output> {
         self.setup()?;
 
-        let mut last_handle = ActionResult::NeedsRedraw;
-        let final_answer = loop {
-            if last_handle.needs_redraw() {
-                backend.frame_setup()?;
-                self.render(backend)?;
-                backend.frame_finish(false)?;
-                last_handle = ActionResult::Clean;
-            }
+        let captures_mouse = self.captures_mouse();
+        if captures_mouse {
+            backend.set_mouse_capture(true)?;
+        }
 
-            let key = backend.read_key()?;
-            let action = Action::from_key(key, self.config());
+        let result = run(&mut self, backend);
 
-            if let Some(action) = action {
-                last_handle = match action {
-                    Action::Submit => {
-                        if let Some(answer) = self.submit()? {
-                            break answer;
-                        }
-                        ActionResult::NeedsRedraw
-                    }
-                    Action::Cancel => {
-                        let pre_cancel_result = self.pre_cancel()?;
+        if captures_mouse {
+            backend.set_mouse_capture(false)?;
+        }
 
-                        if pre_cancel_result {
-                            backend.frame_setup()?;
-                            backend.render_canceled_prompt(self.message())?;
-                            backend.frame_finish(true)?;
-                            return Err(InquireError::OperationCanceled);
-                        }
+        result
+    }
+}
 
-                        ActionResult::NeedsRedraw
-                    }
-                    Action::Interrupt => return Err(InquireError::OperationInterrupted),
-                    Action::Inner(inner_action) => self.handle(inner_action)?,
-                };
+/// Event loop of a prompt, reading the user input until the prompt is
+/// either submitted or canceled.
+fn run<P, Backend>(prompt: &mut P, backend: &mut Backend) -> InquireResult<P::Output>
+where
+    P: Prompt<Backend>,
+    Backend: CommonBackend,
+{
+    let mut last_handle = ActionResult::NeedsRedraw;
+    let final_answer = loop {
+        if last_handle.needs_redraw() {
+            backend.frame_setup()?;
+            prompt.render(backend)?;
+            backend.frame_finish(false)?;
+            last_handle = ActionResult::Clean;
+        }
+
+        let event = match prompt.tick_interval() {
+            Some(interval) if backend.supports_timeout() => backend.poll_event(interval)?,
+            Some(_) if prompt.requires_ticks() => {
+                return Err(InquireError::InvalidConfiguration(
+                    "The terminal back-end can not wait for key presses with a timeout, \
+                     which the prompt relies on"
+                        .into(),
+                ))
             }
+            _ => Some(backend.read_event()?),
         };
 
-        let formatted = self.format_answer(&final_answer);
+        let action = match event {
+            Some(InputEvent::Key(key)) => prompt.parse_key(key),
+            Some(InputEvent::Paste(text)) => {
+                last_handle = last_handle.merge(prompt.handle_paste(&text)?);
+                None
+            }
+            Some(InputEvent::Resize) => {
+                last_handle = ActionResult::NeedsRedraw;
+                None
+            }
+            None => {
+                last_handle = ActionResult::NeedsRedraw;
+                prompt.tick()?
+            }
+        };
 
-        backend.frame_setup()?;
-        backend.render_prompt_with_answer(self.message(), &formatted)?;
-        backend.frame_finish(true)?;
+        if let Some(action) = action {
+            last_handle = match action {
+                Action::Submit => {
+                    if let Some(answer) = prompt.submit()? {
+                        break answer;
+                    }
+                    ActionResult::NeedsRedraw
+                }
+                Action::Cancel => {
+                    let pre_cancel_result = prompt.pre_cancel()?;
 
-        Ok(final_answer)
-    }
+                    if pre_cancel_result {
+                        backend.frame_setup()?;
+                        backend.render_canceled_prompt(prompt.message())?;
+                        backend.frame_finish(true)?;
+                        return Err(InquireError::OperationCanceled);
+                    }
+
+                    ActionResult::NeedsRedraw
+                }
+                Action::Interrupt => return Err(InquireError::OperationInterrupted),
+                Action::Inner(inner_action) => prompt.handle(inner_action)?,
+            };
+        }
+    };
+
+    let formatted = prompt.format_answer(&final_answer);
+
+    backend.frame_setup()?;
+    backend.render_prompt_with_answer(prompt.message(), &formatted)?;
+    backend.frame_finish(true)?;
+
+    Ok(final_answer)
 }
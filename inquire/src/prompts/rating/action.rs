@@ -0,0 +1,56 @@
+use crate::{
+    ui::{Key, KeyModifiers},
+    InnerAction,
+};
+
+use super::config::RatingConfig;
+
+/// Set of actions for a RatingPrompt.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum RatingPromptAction {
+    /// Decrements the current score by one.
+    Decrement,
+    /// Increments the current score by one.
+    Increment,
+    /// Sets the current score to the provided value, if within the scale.
+    SetScore(u8),
+    /// Sets the current score to the lowest value of the scale, i.e. one.
+    MoveToMin,
+    /// Sets the current score to the highest value of the scale.
+    MoveToMax,
+    /// Clears the current score, marking the rating as skipped.
+    Skip,
+}
+
+impl InnerAction for RatingPromptAction {
+    type Config = RatingConfig;
+
+    fn from_key(key: Key, config: &RatingConfig) -> Option<Self> {
+        let action = match key {
+            Key::Left(KeyModifiers::NONE) // standard
+            | Key::Down(KeyModifiers::NONE)
+            | Key::Char('b', KeyModifiers::CONTROL) // emacs
+            | Key::Char('h', KeyModifiers::NONE) // vim
+            => Self::Decrement,
+
+            Key::Right(KeyModifiers::NONE) // standard
+            | Key::Up(KeyModifiers::NONE)
+            | Key::Char('f', KeyModifiers::CONTROL) // emacs
+            | Key::Char('l', KeyModifiers::NONE) // vim
+            => Self::Increment,
+
+            Key::Home => Self::MoveToMin,
+            Key::End => Self::MoveToMax,
+
+            Key::Char(c, KeyModifiers::NONE) if c.is_ascii_digit() => {
+                Self::SetScore(c as u8 - b'0')
+            }
+
+            Key::Backspace | Key::Delete(KeyModifiers::NONE) if config.allow_skip => Self::Skip,
+
+            _ => return None,
+        };
+
+        Some(action)
+    }
+}
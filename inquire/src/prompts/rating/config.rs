@@ -0,0 +1,16 @@
+use crate::Rating;
+
+/// Configuration settings used in the execution of a RatingPrompt.
+#[derive(Copy, Clone, Debug)]
+pub struct RatingConfig {
+    /// Whether the user can submit the prompt without a score.
+    pub allow_skip: bool,
+}
+
+impl From<&Rating<'_>> for RatingConfig {
+    fn from(value: &Rating<'_>) -> Self {
+        Self {
+            allow_skip: value.allow_skip,
+        }
+    }
+}
@@ -0,0 +1,217 @@
+mod action;
+mod config;
+mod prompt;
+#[cfg(test)]
+mod test;
+
+pub use action::*;
+
+use crate::{
+    config::get_configuration,
+    error::{InquireError, InquireResult},
+    formatter::CustomTypeFormatter,
+    prompts::prompt::Prompt,
+    terminal::get_default_terminal,
+    ui::{Backend, RatingBackend, RenderConfig},
+};
+
+use self::prompt::RatingPrompt;
+
+/// Prompt that asks the user for a score in a small scale, rendered as a row of symbols such as `★★★☆☆`.
+///
+/// The user increases and decreases the score with the Right and Left arrows, or types a digit to set the score directly. `Home` and `End` set the lowest and highest scores. Pressing enter submits the current score, which ranges from `1` to the scale size.
+///
+/// Optionally, the prompt can allow the user to skip the rating. In this case the score may also be `0`, which represents a skipped rating and is selected by pressing backspace, delete, `0` or by moving below the lowest score.
+///
+/// Rating prompts provide several options of configuration:
+///
+/// - **Prompt message**: Required when creating the prompt.
+/// - **Scale size**: Highest score, i.e. the amount of symbols rendered, 5 by default.
+/// - **Starting value**: Score selected when the prompt is first rendered, 1 by default.
+/// - **Symbols**: Symbols rendered for filled and empty positions of the scale, `★` and `☆` by default.
+/// - **Allow skip**: Whether the user can submit a score of `0`, false by default.
+/// - **Help message**: Message displayed at the line below the prompt.
+/// - **Formatter**: Custom formatter in case you need to pre-process the user input before showing it as the final answer.
+///   - Displays the score along with the scale size, e.g. `3/5`, or `Skipped`, by default.
+///
+/// If the scale size is zero or the starting value is not a valid score, the prompt operation will fail with an `InquireError::InvalidConfiguration` error.
+///
+/// # Example
+///
+/// ```no_run
+/// use inquire::Rating;
+///
+/// let ans = Rating::new("How would you rate your experience?")
+///     .with_starting_value(3)
+///     .with_allow_skip(true)
+///     .prompt();
+///
+/// match ans {
+///     Ok(0) => println!("Maybe next time!"),
+///     Ok(score) => println!("Thanks for rating us {} stars!", score),
+///     Err(_) => println!("There was an error, please try again"),
+/// }
+/// ```
+#[derive(Clone)]
+pub struct Rating<'a> {
+    /// Message to be presented to the user.
+    pub message: &'a str,
+
+    /// Highest score of the scale, i.e. the amount of symbols rendered.
+    pub max: u8,
+
+    /// Score selected when the prompt is first rendered.
+    pub starting_value: u8,
+
+    /// Symbol rendered for each position up to the current score.
+    pub filled_symbol: &'a str,
+
+    /// Symbol rendered for each position above the current score.
+    pub empty_symbol: &'a str,
+
+    /// Whether the user can skip the rating, submitting a score of `0`.
+    pub allow_skip: bool,
+
+    /// Help message to be presented to the user.
+    pub help_message: Option<&'a str>,
+
+    /// Function that formats the user input and presents it to the user as the final rendering of the prompt.
+    ///
+    /// When `None`, the score is displayed along with the scale size, e.g. `3/5`.
+    pub formatter: Option<CustomTypeFormatter<'a, u8>>,
+
+    /// RenderConfig to apply to the rendered interface.
+    ///
+    /// Note: The default render config considers if the NO_COLOR environment variable
+    /// is set to decide whether to render the colored config or the empty one.
+    ///
+    /// When overriding the config in a prompt, NO_COLOR is no longer considered and your
+    /// config is treated as the only source of truth. If you want to customize colors
+    /// and still support NO_COLOR, you will have to do this on your end.
+    pub render_config: RenderConfig<'a>,
+}
+
+impl<'a> Rating<'a> {
+    /// Default scale size.
+    pub const DEFAULT_MAX: u8 = 5;
+
+    /// Default starting value.
+    pub const DEFAULT_STARTING_VALUE: u8 = 1;
+
+    /// Default symbol for filled positions of the scale.
+    pub const DEFAULT_FILLED_SYMBOL: &'a str = "★";
+
+    /// Default symbol for empty positions of the scale.
+    pub const DEFAULT_EMPTY_SYMBOL: &'a str = "☆";
+
+    /// Default value of whether the user can skip the rating.
+    pub const DEFAULT_ALLOW_SKIP: bool = false;
+
+    /// Default help message.
+    pub const DEFAULT_HELP_MESSAGE: Option<&'a str> = Some("←→ to rate, enter to submit");
+
+    /// Creates a [Rating] with the provided message, along with default configuration values.
+    pub fn new(message: &'a str) -> Self {
+        Self {
+            message,
+            max: Self::DEFAULT_MAX,
+            starting_value: Self::DEFAULT_STARTING_VALUE,
+            filled_symbol: Self::DEFAULT_FILLED_SYMBOL,
+            empty_symbol: Self::DEFAULT_EMPTY_SYMBOL,
+            allow_skip: Self::DEFAULT_ALLOW_SKIP,
+            help_message: Self::DEFAULT_HELP_MESSAGE,
+            formatter: None,
+            render_config: get_configuration(),
+        }
+    }
+
+    /// Sets the highest score of the scale.
+    pub fn with_max(mut self, max: u8) -> Self {
+        self.max = max;
+        self
+    }
+
+    /// Sets the score selected when the prompt is first rendered.
+    pub fn with_starting_value(mut self, starting_value: u8) -> Self {
+        self.starting_value = starting_value;
+        self
+    }
+
+    /// Sets the symbols rendered for filled and empty positions of the scale, respectively.
+    pub fn with_symbols(mut self, filled_symbol: &'a str, empty_symbol: &'a str) -> Self {
+        self.filled_symbol = filled_symbol;
+        self.empty_symbol = empty_symbol;
+        self
+    }
+
+    /// Sets whether the user can skip the rating, submitting a score of `0`.
+    pub fn with_allow_skip(mut self, allow_skip: bool) -> Self {
+        self.allow_skip = allow_skip;
+        self
+    }
+
+    /// Sets the help message of the prompt.
+    pub fn with_help_message(mut self, message: &'a str) -> Self {
+        self.help_message = Some(message);
+        self
+    }
+
+    /// Removes the set help message.
+    pub fn without_help_message(mut self) -> Self {
+        self.help_message = None;
+        self
+    }
+
+    /// Sets the formatter.
+    pub fn with_formatter(mut self, formatter: CustomTypeFormatter<'a, u8>) -> Self {
+        self.formatter = Some(formatter);
+        self
+    }
+
+    /// Sets the provided color theme to this prompt.
+    ///
+    /// Note: The default render config considers if the NO_COLOR environment variable
+    /// is set to decide whether to render the colored config or the empty one.
+    ///
+    /// When overriding the config in a prompt, NO_COLOR is no longer considered and your
+    /// config is treated as the only source of truth. If you want to customize colors
+    /// and still support NO_COLOR, you will have to do this on your end.
+    pub fn with_render_config(mut self, render_config: RenderConfig<'a>) -> Self {
+        self.render_config = render_config;
+        self
+    }
+
+    /// Parses the provided behavioral and rendering options and prompts
+    /// the CLI user for input according to the defined rules.
+    ///
+    /// This method is intended for flows where the user skipping/cancelling
+    /// the prompt - by pressing ESC - is considered normal behavior. In this case,
+    /// it does not return `Err(InquireError::OperationCanceled)`, but `Ok(None)`.
+    ///
+    /// Meanwhile, if the user does submit an answer, the method wraps the return
+    /// type with `Some`.
+    pub fn prompt_skippable(self) -> InquireResult<Option<u8>> {
+        match self.prompt() {
+            Ok(answer) => Ok(Some(answer)),
+            Err(InquireError::OperationCanceled) => Ok(None),
+            Err(err) => Err(err),
+        }
+    }
+
+    /// Parses the provided behavioral and rendering options and prompts
+    /// the CLI user for input according to the defined rules.
+    ///
+    /// Returns the score submitted by the user, which is `0` when the rating was skipped.
+    pub fn prompt(self) -> InquireResult<u8> {
+        let (input_reader, terminal) = get_default_terminal()?;
+        let mut backend = Backend::new(input_reader, terminal, self.render_config)?;
+        self.prompt_with_backend(&mut backend)
+    }
+
+    pub(crate) fn prompt_with_backend<B: RatingBackend>(
+        self,
+        backend: &mut B,
+    ) -> InquireResult<u8> {
+        RatingPrompt::new(self)?.prompt(backend)
+    }
+}
@@ -0,0 +1,130 @@
+use crate::{
+    error::InquireResult,
+    formatter::CustomTypeFormatter,
+    prompts::prompt::{ActionResult, Prompt},
+    ui::RatingBackend,
+    InquireError, Rating,
+};
+
+use super::{action::RatingPromptAction, config::RatingConfig};
+
+pub struct RatingPrompt<'a> {
+    message: &'a str,
+    config: RatingConfig,
+    value: u8,
+    max: u8,
+    filled_symbol: &'a str,
+    empty_symbol: &'a str,
+    help_message: Option<&'a str>,
+    formatter: Option<CustomTypeFormatter<'a, u8>>,
+}
+
+impl<'a> RatingPrompt<'a> {
+    pub fn new(ro: Rating<'a>) -> InquireResult<Self> {
+        if ro.max == 0 {
+            return Err(InquireError::InvalidConfiguration(
+                "Scale size must be greater than zero".into(),
+            ));
+        }
+
+        if ro.starting_value > ro.max {
+            return Err(InquireError::InvalidConfiguration(format!(
+                "Starting value {} is greater than the scale size {}",
+                ro.starting_value, ro.max
+            )));
+        }
+
+        if ro.starting_value == 0 && !ro.allow_skip {
+            return Err(InquireError::InvalidConfiguration(
+                "Starting value can only be zero when skipping is allowed".into(),
+            ));
+        }
+
+        Ok(Self {
+            message: ro.message,
+            config: (&ro).into(),
+            value: ro.starting_value,
+            max: ro.max,
+            filled_symbol: ro.filled_symbol,
+            empty_symbol: ro.empty_symbol,
+            help_message: ro.help_message,
+            formatter: ro.formatter,
+        })
+    }
+
+    fn min(&self) -> u8 {
+        match self.config.allow_skip {
+            true => 0,
+            false => 1,
+        }
+    }
+
+    fn set_value(&mut self, value: u8) -> ActionResult {
+        if value == self.value || value < self.min() || value > self.max {
+            return ActionResult::Clean;
+        }
+
+        self.value = value;
+        ActionResult::NeedsRedraw
+    }
+}
+
+impl<'a, Backend> Prompt<Backend> for RatingPrompt<'a>
+where
+    Backend: RatingBackend,
+{
+    type Config = RatingConfig;
+    type InnerAction = RatingPromptAction;
+    type Output = u8;
+
+    fn message(&self) -> &str {
+        self.message
+    }
+
+    fn config(&self) -> &RatingConfig {
+        &self.config
+    }
+
+    fn format_answer(&self, answer: &u8) -> String {
+        match (self.formatter, answer) {
+            (Some(formatter), _) => formatter(*answer),
+            (None, 0) => String::from("Skipped"),
+            (None, _) => format!("{}/{}", answer, self.max),
+        }
+    }
+
+    fn submit(&mut self) -> InquireResult<Option<u8>> {
+        Ok(Some(self.value))
+    }
+
+    fn handle(&mut self, action: RatingPromptAction) -> InquireResult<ActionResult> {
+        let result = match action {
+            RatingPromptAction::Decrement => self.set_value(self.value.saturating_sub(1)),
+            RatingPromptAction::Increment => self.set_value(self.value.saturating_add(1)),
+            RatingPromptAction::SetScore(value) => self.set_value(value),
+            RatingPromptAction::MoveToMin => self.set_value(1),
+            RatingPromptAction::MoveToMax => self.set_value(self.max),
+            RatingPromptAction::Skip => self.set_value(0),
+        };
+
+        Ok(result)
+    }
+
+    fn render(&self, backend: &mut Backend) -> InquireResult<()> {
+        let prompt = &self.message;
+
+        backend.render_rating_prompt(
+            prompt,
+            self.filled_symbol,
+            self.empty_symbol,
+            self.value,
+            self.max,
+        )?;
+
+        if let Some(message) = self.help_message {
+            backend.render_help_message(message)?;
+        }
+
+        Ok(())
+    }
+}
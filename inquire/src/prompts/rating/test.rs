@@ -0,0 +1,129 @@
+use crate::{
+    error::InquireResult,
+    ui::{
+        test::{FakeBackend, Token},
+        Key, KeyModifiers,
+    },
+    InquireError, Rating,
+};
+
+#[test]
+fn enter_submits_starting_value() -> InquireResult<()> {
+    let mut backend = FakeBackend::new(vec![Key::Enter]);
+
+    let ans = Rating::new("Question").prompt_with_backend(&mut backend)?;
+
+    assert_eq!(1, ans);
+    assert!(backend.frames()[0].has_token(&Token::Rating { value: 1, max: 5 }));
+    assert!(backend.frames()[1].has_token(&Token::AnsweredPrompt("Question".into(), "1/5".into())));
+
+    Ok(())
+}
+
+#[test]
+fn arrows_change_score_within_scale() -> InquireResult<()> {
+    let mut backend = FakeBackend::new(vec![
+        Key::Left(KeyModifiers::NONE),
+        Key::Right(KeyModifiers::NONE),
+        Key::Right(KeyModifiers::NONE),
+        Key::Right(KeyModifiers::NONE),
+        Key::Right(KeyModifiers::NONE),
+        Key::Enter,
+    ]);
+
+    let ans = Rating::new("Question")
+        .with_max(3)
+        .prompt_with_backend(&mut backend)?;
+
+    assert_eq!(3, ans);
+    // initial render, two redraws and the answer, as moving
+    // below the lowest score or above the highest is a no-op
+    assert_eq!(4, backend.frames().len());
+
+    Ok(())
+}
+
+#[test]
+fn digits_set_score_directly() -> InquireResult<()> {
+    let mut backend = FakeBackend::new(vec![
+        Key::Char('4', KeyModifiers::NONE),
+        Key::Char('9', KeyModifiers::NONE),
+        Key::Char('0', KeyModifiers::NONE),
+        Key::Enter,
+    ]);
+
+    let ans = Rating::new("Question").prompt_with_backend(&mut backend)?;
+
+    assert_eq!(4, ans);
+
+    Ok(())
+}
+
+#[test]
+fn skip_is_only_available_when_allowed() -> InquireResult<()> {
+    let keys = vec![Key::Backspace, Key::Left(KeyModifiers::NONE), Key::Enter];
+
+    let ans = Rating::new("Question")
+        .with_starting_value(2)
+        .prompt_with_backend(&mut FakeBackend::new(keys.clone()))?;
+    assert_eq!(1, ans);
+
+    let mut backend = FakeBackend::new(keys);
+    let ans = Rating::new("Question")
+        .with_starting_value(2)
+        .with_allow_skip(true)
+        .prompt_with_backend(&mut backend)?;
+    assert_eq!(0, ans);
+    assert!(backend
+        .frames()
+        .last()
+        .unwrap()
+        .has_token(&Token::AnsweredPrompt("Question".into(), "Skipped".into())));
+
+    Ok(())
+}
+
+#[test]
+fn custom_formatter_is_used_as_answer() -> InquireResult<()> {
+    let mut backend = FakeBackend::new(vec![Key::End, Key::Enter]);
+
+    let ans = Rating::new("Question")
+        .with_max(10)
+        .with_formatter(&|score| format!("{score} points"))
+        .prompt_with_backend(&mut backend)?;
+
+    assert_eq!(10, ans);
+    assert!(backend.frames()[2].has_token(&Token::AnsweredPrompt(
+        "Question".into(),
+        "10 points".into()
+    )));
+
+    Ok(())
+}
+
+#[test]
+fn invalid_configurations_are_rejected() {
+    let no_scale = Rating::new("Question")
+        .with_max(0)
+        .prompt_with_backend(&mut FakeBackend::new(vec![Key::Enter]));
+    assert!(matches!(
+        no_scale,
+        Err(InquireError::InvalidConfiguration(_))
+    ));
+
+    let above_max = Rating::new("Question")
+        .with_starting_value(6)
+        .prompt_with_backend(&mut FakeBackend::new(vec![Key::Enter]));
+    assert!(matches!(
+        above_max,
+        Err(InquireError::InvalidConfiguration(_))
+    ));
+
+    let zero_without_skip = Rating::new("Question")
+        .with_starting_value(0)
+        .prompt_with_backend(&mut FakeBackend::new(vec![Key::Enter]));
+    assert!(matches!(
+        zero_without_skip,
+        Err(InquireError::InvalidConfiguration(_))
+    ));
+}
@@ -1,6 +1,6 @@
 use crate::{
-    ui::{Key, KeyModifiers},
-    InnerAction, InputAction,
+    ui::{Key, KeyModifiers, MouseEvent, MouseEventKind},
+    InnerAction, InputAction, ListKeymap, NumericShortcuts,
 };
 
 use super::config::SelectConfig;
@@ -14,6 +14,12 @@ pub enum SelectPromptAction {
     MoveUp,
     /// Moves the cursor to the option below.
     MoveDown,
+    /// Moves the cursor to the option on the left, when the options are
+    /// laid out in columns.
+    MoveLeft,
+    /// Moves the cursor to the option on the right, when the options are
+    /// laid out in columns.
+    MoveRight,
     /// Moves the cursor to the page above.
     PageUp,
     /// Moves the cursor to the page below.
@@ -22,12 +28,47 @@ pub enum SelectPromptAction {
     MoveToStart,
     /// Moves the cursor to the end of the list.
     MoveToEnd,
+    /// Moves the cursor to the option at the given position of the current
+    /// page, when numeric shortcuts are enabled or the option is clicked.
+    JumpToOption(usize),
+    /// Focuses the filter input, when using the vim keymap.
+    FocusFilter,
+    /// Leaves the filter input, when using the vim keymap.
+    UnfocusFilter,
+    /// Clears the filter input without cancelling the prompt.
+    ClearFilter,
+    /// Switches to the next custom sort order of the options.
+    CycleSortOrder,
 }
 
 impl InnerAction for SelectPromptAction {
     type Config = SelectConfig;
 
     fn from_key(key: Key, config: &SelectConfig) -> Option<Self> {
+        if config.numeric_shortcuts != NumericShortcuts::Disabled {
+            if let Key::Char(c @ '1'..='9', KeyModifiers::NONE) = key {
+                let position = (c as usize) - ('1' as usize);
+                return Some(Self::JumpToOption(position));
+            }
+        }
+
+        if config.keymap == ListKeymap::Vim {
+            let action = match key {
+                Key::Char('k', KeyModifiers::NONE) => Some(Self::MoveUp),
+                Key::Char('j', KeyModifiers::NONE) => Some(Self::MoveDown),
+                Key::Char('g', KeyModifiers::NONE) => Some(Self::MoveToStart),
+                Key::Char('G', _) => Some(Self::MoveToEnd),
+                Key::Char('u', KeyModifiers::CONTROL) => Some(Self::PageUp),
+                Key::Char('d', KeyModifiers::CONTROL) => Some(Self::PageDown),
+                Key::Char('/', KeyModifiers::NONE) => Some(Self::FocusFilter),
+                _ => None,
+            };
+
+            if action.is_some() {
+                return action;
+            }
+        }
+
         if config.vim_mode {
             let action = match key {
                 Key::Char('k', KeyModifiers::NONE) => Some(Self::MoveUp),
@@ -40,18 +81,50 @@ impl InnerAction for SelectPromptAction {
             }
         }
 
+        if config.columns {
+            let action = match key {
+                Key::Left(KeyModifiers::NONE) => Some(Self::MoveLeft),
+                Key::Right(KeyModifiers::NONE) => Some(Self::MoveRight),
+                _ => None,
+            };
+
+            if action.is_some() {
+                return action;
+            }
+        }
+
         let action = match key {
-            Key::Up(KeyModifiers::NONE) | Key::Char('p', KeyModifiers::CONTROL) => Self::MoveUp,
+            Key::Up(KeyModifiers::NONE)
+            | Key::Char('p', KeyModifiers::CONTROL)
+            | Key::Mouse(MouseEvent {
+                kind: MouseEventKind::ScrollUp,
+                ..
+            }) => Self::MoveUp,
             Key::PageUp(_) => Self::PageUp,
             Key::Home => Self::MoveToStart,
 
-            Key::Down(KeyModifiers::NONE) | Key::Char('n', KeyModifiers::CONTROL) => Self::MoveDown,
+            Key::Down(KeyModifiers::NONE)
+            | Key::Char('n', KeyModifiers::CONTROL)
+            | Key::Mouse(MouseEvent {
+                kind: MouseEventKind::ScrollDown,
+                ..
+            }) => Self::MoveDown,
             Key::PageDown(_) => Self::PageDown,
             Key::End => Self::MoveToEnd,
 
+            Key::Mouse(MouseEvent {
+                kind: MouseEventKind::Click,
+                option: Some(position),
+                ..
+            }) => Self::JumpToOption(position),
+
+            Key::Char('u', KeyModifiers::CONTROL) => Self::ClearFilter,
+            Key::Char('s', KeyModifiers::CONTROL) => Self::CycleSortOrder,
+
+            // with the vim keymap, the filter input is only edited while focused
             key => match InputAction::from_key(key, &()) {
-                Some(action) => Self::FilterInput(action),
-                None => return None,
+                Some(action) if config.keymap == ListKeymap::Default => Self::FilterInput(action),
+                _ => return None,
             },
         };
 
@@ -1,4 +1,4 @@
-use crate::Select;
+use crate::{ListKeymap, NumericShortcuts, Select};
 
 /// Configuration settings used in the execution of a SelectPrompt.
 #[derive(Copy, Clone, Debug)]
@@ -7,8 +7,26 @@ pub struct SelectConfig {
     pub vim_mode: bool,
     /// Page size of the list of options.
     pub page_size: usize,
+    /// Whether to fit the page size to the height of the terminal.
+    pub auto_page_size: bool,
+    /// Whether the options are laid out in columns fitted to the terminal width.
+    pub columns: bool,
     /// Whether to reset the cursor to the first option on filter input change.
     pub reset_cursor: bool,
+    /// Whether the cursor skips disabled options.
+    pub skip_disabled: bool,
+    /// Whether typing moves the cursor to the next option starting with the typed character.
+    pub jump_to_letter: bool,
+    /// Whether the characters matched by the filter input are highlighted.
+    pub highlight_matches: bool,
+    /// Whether the digit keys are shortcuts to the first options of the page.
+    pub numeric_shortcuts: NumericShortcuts,
+    /// Maximum number of lines of the preview pane.
+    pub preview_height: usize,
+    /// Key bindings used to navigate the options.
+    pub keymap: ListKeymap,
+    /// Whether moving past the last option wraps around to the first one.
+    pub wrap_around: bool,
 }
 
 impl<T> From<&Select<'_, T>> for SelectConfig {
@@ -16,7 +34,16 @@ impl<T> From<&Select<'_, T>> for SelectConfig {
         Self {
             vim_mode: value.vim_mode,
             page_size: value.page_size,
+            auto_page_size: value.auto_page_size,
+            columns: value.columns,
             reset_cursor: value.reset_cursor,
+            skip_disabled: value.skip_disabled,
+            jump_to_letter: value.jump_to_letter,
+            highlight_matches: value.highlight_matches,
+            numeric_shortcuts: value.numeric_shortcuts,
+            preview_height: value.preview_height,
+            keymap: value.keymap,
+            wrap_around: value.wrap_around,
         }
     }
 }
@@ -0,0 +1,81 @@
+use std::{cmp::Reverse, collections::BTreeMap};
+
+use crate::{error::InquireResult, InquireError};
+
+/// Groups of options listed under section headers, shared by the
+/// [Select](crate::Select) and [MultiSelect](crate::MultiSelect) prompts.
+pub(crate) struct OptionGroups<'a> {
+    /// Indexes of the options in the order they are listed: ungrouped
+    /// options first, followed by the groups in the order they were added.
+    pub order: Vec<usize>,
+    /// Header of the group of each grouped option.
+    pub headers: BTreeMap<usize, &'a str>,
+    // position of the group of each option, used to keep the options
+    // in their groups when sorting them by score
+    ranks: Vec<Option<usize>>,
+}
+
+impl<'a> OptionGroups<'a> {
+    pub fn new(groups: &[(&'a str, Vec<usize>)], len: usize) -> InquireResult<Self> {
+        let mut ranks = vec![None; len];
+        let mut headers = BTreeMap::new();
+
+        for (rank, (header, indexes)) in groups.iter().enumerate() {
+            for index in indexes.iter().copied() {
+                match ranks.get(index) {
+                    None => {
+                        return Err(InquireError::InvalidConfiguration(format!(
+                            "Index {index} of group {header:?} is out-of-bounds for length {len} of options"
+                        )))
+                    }
+                    Some(Some(_)) => {
+                        return Err(InquireError::InvalidConfiguration(format!(
+                            "Option {index} can not belong to more than one group"
+                        )))
+                    }
+                    Some(None) => {}
+                }
+
+                ranks[index] = Some(rank);
+                headers.insert(index, *header);
+            }
+        }
+
+        let mut order = (0..len)
+            .filter(|i| ranks[*i].is_none())
+            .collect::<Vec<usize>>();
+        for (_, indexes) in groups {
+            order.extend(indexes);
+        }
+
+        Ok(Self {
+            order,
+            headers,
+            ranks,
+        })
+    }
+
+    /// Lists a new option after the other ones that do not belong to any group.
+    pub fn push(&mut self) {
+        let ungrouped = self.ranks.iter().filter(|rank| rank.is_none()).count();
+
+        self.order.insert(ungrouped, self.ranks.len());
+        self.ranks.push(None);
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.headers.is_empty()
+    }
+
+    /// Sorts the scored options by their group and then by score, options
+    /// with the same score keeping the order they are listed in.
+    pub fn sort(&self, options: &mut [(usize, i64)]) {
+        options.sort_by_key(|(idx, score)| (self.ranks[*idx], Reverse(*score)));
+    }
+
+    /// Moves the options to their groups, keeping the order they are listed
+    /// in within each group.
+    pub fn sort_by_group(&self, options: &mut [(usize, i64)]) {
+        options.sort_by_key(|(idx, _score)| self.ranks[*idx]);
+    }
+}
@@ -0,0 +1,100 @@
+use std::{
+    sync::{
+        mpsc::{self, Receiver, Sender, TryRecvError},
+        Arc, Mutex,
+    },
+    thread,
+    time::Duration,
+};
+
+/// Interval between each poll of the loader while the prompt is open.
+pub(crate) const LOADER_POLL_INTERVAL: Duration = Duration::from_millis(100);
+
+/// Source of options loaded while a [Select](crate::Select) or
+/// [MultiSelect](crate::MultiSelect) prompt is open.
+///
+/// Options are received through a channel, which is polled by the prompt,
+/// appending them to the list as they arrive. The loading finishes once
+/// every sender of the channel is dropped.
+///
+/// Cloned loaders share the same channel, so each option is only received
+/// by one of them.
+///
+/// # Example
+///
+/// ```no_run
+/// use inquire::{OptionLoader, Select};
+///
+/// let loader = OptionLoader::spawn(|sender| {
+///     for page in 1..=3 {
+///         // fetch the page of results from a remote API
+///         std::thread::sleep(std::time::Duration::from_millis(500));
+///
+///         for i in 1..=10 {
+///             let _ = sender.send(format!("repository #{}", (page - 1) * 10 + i));
+///         }
+///     }
+/// });
+///
+/// let repository = Select::new("Repository:", vec![])
+///     .with_loader(loader)
+///     .prompt();
+/// ```
+pub struct OptionLoader<T> {
+    receiver: Arc<Mutex<Receiver<T>>>,
+}
+
+impl<T> OptionLoader<T> {
+    /// Creates a loader that receives the options sent through the channel.
+    pub fn new(receiver: Receiver<T>) -> Self {
+        Self {
+            receiver: Arc::new(Mutex::new(receiver)),
+        }
+    }
+
+    /// Creates a loader that runs the provided function in a background
+    /// thread, receiving the options it sends.
+    pub fn spawn<F>(load: F) -> Self
+    where
+        F: FnOnce(Sender<T>) + Send + 'static,
+        T: Send + 'static,
+    {
+        let (sender, receiver) = mpsc::channel();
+        thread::spawn(move || load(sender));
+
+        Self::new(receiver)
+    }
+
+    /// Returns the options received since the last poll, along with whether
+    /// the loading is finished.
+    pub(crate) fn poll(&self) -> (Vec<T>, bool) {
+        let receiver = match self.receiver.lock() {
+            Ok(receiver) => receiver,
+            Err(_err) => return (vec![], true),
+        };
+
+        let mut options = vec![];
+
+        loop {
+            match receiver.try_recv() {
+                Ok(option) => options.push(option),
+                Err(TryRecvError::Empty) => return (options, false),
+                Err(TryRecvError::Disconnected) => return (options, true),
+            }
+        }
+    }
+}
+
+impl<T> Clone for OptionLoader<T> {
+    fn clone(&self) -> Self {
+        Self {
+            receiver: Arc::clone(&self.receiver),
+        }
+    }
+}
+
+impl<T> From<Receiver<T>> for OptionLoader<T> {
+    fn from(receiver: Receiver<T>) -> Self {
+        Self::new(receiver)
+    }
+}
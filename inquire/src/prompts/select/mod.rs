@@ -1,22 +1,33 @@
 mod action;
 mod config;
+mod group;
+mod loader;
+mod other;
 mod prompt;
 #[cfg(test)]
 #[cfg(feature = "crossterm")]
 mod test;
 
 pub use action::*;
-use std::fmt::Display;
+pub(crate) use group::OptionGroups;
+pub use loader::OptionLoader;
+pub(crate) use loader::LOADER_POLL_INTERVAL;
+pub use other::SelectOrOther;
+use std::{
+    collections::{BTreeMap, BTreeSet},
+    fmt::Display,
+};
 
 use crate::{
-    config::get_configuration,
+    config::{get_configuration, get_wrap_around},
     error::{InquireError, InquireResult},
     formatter::OptionFormatter,
+    history::History,
     list_option::ListOption,
     prompts::prompt::Prompt,
     terminal::get_default_terminal,
-    type_aliases::Scorer,
-    ui::{Backend, RenderConfig, SelectBackend},
+    type_aliases::{Comparator, Previewer, Scorer},
+    ui::{Backend, RenderConfig, SelectBackend, Styled},
 };
 
 use self::prompt::SelectPrompt;
@@ -27,29 +38,94 @@ use fuzzy_matcher::{skim::SkimMatcherV2, FuzzyMatcher};
 use once_cell::sync::Lazy;
 #[cfg(feature = "fuzzy")]
 static DEFAULT_MATCHER: Lazy<SkimMatcherV2> = Lazy::new(|| SkimMatcherV2::default().ignore_case());
+
+/// Set of key bindings used to navigate the options of [`Select`] and
+/// [`MultiSelect`](crate::MultiSelect) prompts.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub enum ListKeymap {
+    /// Arrow keys move the cursor and typed characters filter the options.
+    #[default]
+    Default,
+
+    /// Modal key bindings inspired by vim: `j` and `k` move the cursor, `g`
+    /// and `G` jump to the start and end of the list, `Ctrl+u` and `Ctrl+d`
+    /// move a page up or down and `/` focuses the filter input, which is
+    /// left by pressing enter or escape.
+    ///
+    /// Characters are only typed into the filter input while it is focused.
+    Vim,
+}
+
+/// Defines whether the digit keys of a [`Select`] prompt are shortcuts to
+/// the first nine options of the current page, which are then prefixed by
+/// their digits, from `1)` to `9)`.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub enum NumericShortcuts {
+    /// Digits are typed into the filter input.
+    #[default]
+    Disabled,
+
+    /// Pressing a digit moves the cursor to the corresponding option.
+    Jump,
+
+    /// Pressing a digit submits the corresponding option right away.
+    Submit,
+}
 /// Prompt suitable for when you need the user to select one option among many.
 ///
 /// The user can select and submit the current highlighted option by pressing enter.
 ///
+/// With the `mouse` feature, the scroll wheel moves the cursor, clicking an option highlights it and double-clicking it submits it.
+///
+/// While the options are filtered, pressing `Ctrl+u` or the first press of escape clears the filter instead of cancelling the prompt, and the help message hints at it.
+///
 /// This prompt requires a prompt message and a **non-empty** `Vec` of options to be displayed to the user. The options can be of any type as long as they implement the `Display` trait. It is required that the `Vec` is moved to the prompt, as the prompt will return the selected option (`Vec` element) after the user submits.
-/// - If the list is empty, the prompt operation will fail with an `InquireError::InvalidConfiguration` error.
+/// - If the list is empty, the prompt operation will fail with an `InquireError::InvalidConfiguration` error, unless a loader is set.
 ///
 /// This prompt does not support custom validators because of its nature. A submission always selects exactly one of the options. If this option was not supposed to be selected or is invalid in some way, it probably should not be included in the options list.
 ///
 /// The options are paginated in order to provide a smooth experience to the user, with the default page size being 7. The user can move from the options and the pages will be updated accordingly, including moving from the last to the first options (or vice-versa).
 ///
+/// For long lists of short options, such as countries or languages, [`with_columns`](Select::with_columns) lays them out in multiple columns fitted to the terminal width, with the left and right arrows moving across the columns. [`GridSelect`](crate::GridSelect) does the same with a fixed number of columns, when the other features of this prompt are not needed.
+///
+/// For options made of several fields, such as containers or pull requests, [`TableSelect`](crate::TableSelect) aligns them in columns, optionally labeled by a header row that stays visible while the list scrolls.
+///
 /// Like all others, this prompt also allows you to customize several aspects of it:
 ///
 /// - **Prompt message**: Required when creating the prompt.
 /// - **Options list**: Options displayed to the user. Must be **non-empty**.
 /// - **Starting cursor**: Index of the cursor when the prompt is first rendered. Default is 0 (first option). If the index is out-of-range of the option list, the prompt will fail with an [`InquireError::InvalidConfiguration`] error.
+///   - Can also be set by value, with [`with_starting_selection`](Select::with_starting_selection), or by a predicate, with [`with_starting_selection_by`](Select::with_starting_selection_by).
 /// - **Starting filter input**: Sets the initial value of the filter section of the prompt.
+/// - **Wrap around flag**: Whether moving past the last option wraps around to the first one and vice versa, instead of stopping at the ends of the list. Defaults to true, unless changed globally with [`set_global_wrap_around`](crate::set_global_wrap_around).
+/// - **Keymap**: Key bindings used to navigate the options, such as the modal vim-like ones. See [`ListKeymap`].
+/// - **Jump to letter flag**: Whether typing a character moves the cursor to the next option starting with it, like native list boxes, instead of filtering the options. Defaults to false.
 /// - **Help message**: Message displayed at the line below the prompt.
 /// - **Formatter**: Custom formatter in case you need to pre-process the user input before showing it as the final answer.
 ///   - Prints the selected option string value by default.
 /// - **Page size**: Number of options displayed at once, 7 by default.
+/// - **Auto page size flag**: Whether the page size is fitted to the height of the terminal instead, following its resizes. Defaults to false.
+/// - **Columns flag**: Whether the options are laid out in as many columns as fit in the width of the terminal, the page size then counting rows of options. Defaults to false.
 /// - **Display option indexes**: On long lists, it might be helpful to display the indexes of the options to the user. Via the `RenderConfig`, you can set the display mode of the indexes as a prefix of an option. The default configuration is `None`, to not render any index when displaying the options.
-/// - **Scorer function**: Function that defines the order of options and if displayed as all.
+/// - **Scorer function**: Function that defines the order of options and if displayed as all. Options are ranked by descending score, and options with the same score keep their original order.
+///   - Fuzzy matches the value and description of the options by default, when the `fuzzy` feature is enabled, and filters them by substring otherwise.
+/// - **Sort orders**: Custom orders of the options, such as alphabetical or by date, defined by comparator functions and labeled in the prompt line. Pressing `Ctrl+s` cycles through them and back to the original order, or to the order by score while filtering. Options are kept under their group headers, and separators are hidden while a custom order is selected.
+/// - **Highlight matches flag**: Whether the characters of the options matched by the filter input are highlighted with the `option_match` style sheet. Defaults to true.
+/// - **Numeric shortcuts**: Whether pressing a digit from 1 to 9 moves the cursor to, or submits, the corresponding option of the current page, in which case digits can not be typed into the filter input. Disabled by default. See [`NumericShortcuts`].
+/// - **Previewer**: Function that generates the preview of the highlighted option, displayed in a pane below the list and updated as the cursor moves.
+/// - **Preview height**: Maximum number of lines of the preview pane, 10 by default.
+/// - **Option groups**: Groups of options listed under non-selectable headers, such as "Recent" or "All regions", which are skipped by the cursor. Options not added to any group are listed first, followed by the groups in the order they were added. While filtering, options are kept under their headers, and groups without any matching option are hidden. If any of the indexes is out-of-range of the option list, or added to more than one group, the prompt will fail with an [`InquireError::InvalidConfiguration`] error.
+/// - **Option values**: Options can carry a value along with the label displayed to the user by wrapping them in an [`OptionItem`](crate::list_option::OptionItem), so that the answer does not have to be mapped back to the value by its index.
+/// - **Option keywords**: Hidden search terms of the options, such as aliases, IDs or tags, which are matched by the filter input but not displayed. If any of the indexes is out-of-range of the option list, the prompt will fail with an [`InquireError::InvalidConfiguration`] error.
+/// - **Separators**: Lines rendered between the options, with the `option_separator` component, for a lightweight grouping without headers. They are skipped by the cursor and hidden while filtering. If any of the indexes is out-of-range of the option list, the prompt will fail with an [`InquireError::InvalidConfiguration`] error.
+/// - **Option icons**: Styled prefixes rendered before the values of the options, such as emojis or colored bullets, which are not searched when filtering. If any of the indexes is out-of-range of the option list, the prompt will fail with an [`InquireError::InvalidConfiguration`] error.
+/// - **Option descriptions**: Descriptions displayed in the line below the options, with the `option_description` style sheet, and searched along with them when filtering. If any of the indexes is out-of-range of the option list, the prompt will fail with an [`InquireError::InvalidConfiguration`] error.
+/// - **Disabled options**: Indexes of options that are displayed but can not be selected, optionally along with the reason why. They are rendered with the `disabled_option` style sheet and skipped by the cursor, unless [`with_skip_disabled`](Select::with_skip_disabled) is set to false, in which case submitting one of them displays its reason as an error. If any of the indexes is out-of-range of the option list, or if every option is disabled, the prompt will fail with an [`InquireError::InvalidConfiguration`] error.
+///
+/// - **Loader**: Source of options appended to the list while the prompt is open, such as results fetched from a remote API. A loading indicator is displayed below the options until the loader is finished. See [`OptionLoader`] for more details.
+/// - **History**: Store of the recently selected options, matched by their string values. They are listed first in their groups, the most recent at the top, and ranked above the other options with the same score while filtering. The selected option is recorded in the history on submit. See [`History`] for more details.
+///
+/// An "other" option can be appended to the list by prompting with [`prompt_with_other`](Select::prompt_with_other). When the user selects it, the prompt turns into a text input where a custom answer can be typed, pressing ESC going back to the list.
 ///
 /// # Example
 ///
@@ -83,6 +159,16 @@ pub struct Select<'a, T> {
     /// Page size of the options displayed to the user.
     pub page_size: usize,
 
+    /// Whether the page size is fitted to the height of the terminal,
+    /// overriding `page_size`.
+    pub auto_page_size: bool,
+
+    /// Whether the options are laid out in columns fitted to the width of
+    /// the terminal, instead of one per line.
+    ///
+    /// Defaults to false.
+    pub columns: bool,
+
     /// Whether vim mode is enabled. When enabled, the user can
     /// navigate through the options using hjkl.
     pub vim_mode: bool,
@@ -90,6 +176,10 @@ pub struct Select<'a, T> {
     /// Starting cursor index of the selection.
     pub starting_cursor: usize,
 
+    /// Index of the option highlighted when the prompt starts, wherever it
+    /// is listed, taking precedence over the starting cursor.
+    pub starting_selection: Option<usize>,
+
     /// Starting filter input
     pub starting_filter_input: Option<&'a str>,
 
@@ -102,13 +192,83 @@ pub struct Select<'a, T> {
     /// Defaults to true.
     pub filter_input_enabled: bool,
 
-    /// Function called with the current user input to score the provided
+    /// Whether typing a character moves the cursor to the next option
+    /// starting with it, instead of filtering the options.
+    ///
+    /// Defaults to false.
+    pub jump_to_letter: bool,
+
+    /// Key bindings used to navigate the options.
+    pub keymap: ListKeymap,
+
+    /// Whether moving past the last option wraps around to the first one
+    /// and vice versa.
+    pub wrap_around: bool,
+
+    /// Groups of options listed under headers, with the indexes of the
+    /// options in each group.
+    pub groups: Vec<(&'a str, Vec<usize>)>,
+
+    /// Descriptions displayed in the line below the options, mapped by the
+    /// indexes of the options.
+    pub descriptions: BTreeMap<usize, &'a str>,
+
+    /// Icons rendered before the values of the options, mapped by the
+    /// indexes of the options.
+    pub icons: BTreeMap<usize, Styled<&'a str>>,
+
+    /// Hidden search keywords of the options, mapped by the indexes of the
     /// options.
+    pub keywords: BTreeMap<usize, Vec<&'a str>>,
+
+    /// Indexes of the options preceded by a separator line.
+    pub separators: BTreeSet<usize>,
+
+    /// Indexes of the options that can not be selected, mapped to the
+    /// reason displayed next to them, if any.
+    pub disabled_options: BTreeMap<usize, Option<&'a str>>,
+
+    /// Whether the cursor skips disabled options. When false, the cursor
+    /// can highlight them, but submitting one displays an error instead.
+    ///
+    /// Defaults to true.
+    pub skip_disabled: bool,
+
+    /// Whether the characters of the options matched by the filter input
+    /// are highlighted with the `option_match` style sheet.
+    ///
+    /// Defaults to true.
+    pub highlight_matches: bool,
+
+    /// Whether the digit keys are shortcuts to the first options of the page.
+    pub numeric_shortcuts: NumericShortcuts,
+
+    /// Function called with the current user input to score the provided
+    /// options. Options are ranked by their scores, ties keeping their
+    /// original order.
     pub scorer: Scorer<'a, T>,
 
+    /// Custom sort orders the user can cycle through, along with their labels.
+    pub sort_orders: Vec<(&'a str, Comparator<'a, T>)>,
+
     /// Function that formats the user input and presents it to the user as the final rendering of the prompt.
     pub formatter: OptionFormatter<'a, T>,
 
+    /// Source of options appended to the list while the prompt is open.
+    pub loader: Option<OptionLoader<T>>,
+
+    /// Message displayed below the options while the loader is not finished.
+    pub loading_message: &'a str,
+
+    /// Store of the recently selected options, which are listed first.
+    pub history: Option<Box<dyn History>>,
+
+    /// Function that generates the preview of the highlighted option.
+    pub previewer: Option<Previewer<'a, T>>,
+
+    /// Maximum number of lines of the preview pane.
+    pub preview_height: usize,
+
     /// RenderConfig to apply to the rendered interface.
     ///
     /// Note: The default render config considers if the NO_COLOR environment variable
@@ -181,6 +341,12 @@ where
     /// Default page size.
     pub const DEFAULT_PAGE_SIZE: usize = crate::config::DEFAULT_PAGE_SIZE;
 
+    /// Default behavior of fitting the page size to the terminal, disabled.
+    pub const DEFAULT_AUTO_PAGE_SIZE: bool = false;
+
+    /// Default layout of the options, one per line.
+    pub const DEFAULT_COLUMNS: bool = false;
+
     /// Default value of vim mode.
     pub const DEFAULT_VIM_MODE: bool = crate::config::DEFAULT_VIM_MODE;
 
@@ -195,6 +361,22 @@ where
     /// Defaults to true.
     pub const DEFAULT_FILTER_INPUT_ENABLED: bool = true;
 
+    /// Default value of whether typing jumps to the options starting with the typed character.
+    pub const DEFAULT_JUMP_TO_LETTER: bool = false;
+
+    /// Default behaviour of the cursor on disabled options.
+    /// Defaults to true, skipping them.
+    pub const DEFAULT_SKIP_DISABLED: bool = true;
+
+    /// Default value of whether matched characters are highlighted.
+    pub const DEFAULT_HIGHLIGHT_MATCHES: bool = true;
+
+    /// Default message displayed while options are being loaded.
+    pub const DEFAULT_LOADING_MESSAGE: &'a str = "loading…";
+
+    /// Default maximum number of lines of the preview pane.
+    pub const DEFAULT_PREVIEW_HEIGHT: usize = 10;
+
     /// Default help message.
     pub const DEFAULT_HELP_MESSAGE: Option<&'a str> =
         Some("↑↓ to move, enter to select, type to filter");
@@ -206,12 +388,33 @@ where
             options,
             help_message: Self::DEFAULT_HELP_MESSAGE,
             page_size: Self::DEFAULT_PAGE_SIZE,
+            auto_page_size: Self::DEFAULT_AUTO_PAGE_SIZE,
+            columns: Self::DEFAULT_COLUMNS,
             vim_mode: Self::DEFAULT_VIM_MODE,
             starting_cursor: Self::DEFAULT_STARTING_CURSOR,
+            starting_selection: None,
             reset_cursor: Self::DEFAULT_RESET_CURSOR,
             filter_input_enabled: Self::DEFAULT_FILTER_INPUT_ENABLED,
+            jump_to_letter: Self::DEFAULT_JUMP_TO_LETTER,
+            keymap: ListKeymap::default(),
+            wrap_around: get_wrap_around(),
+            groups: vec![],
+            descriptions: BTreeMap::new(),
+            icons: BTreeMap::new(),
+            keywords: BTreeMap::new(),
+            separators: BTreeSet::new(),
+            disabled_options: BTreeMap::new(),
+            skip_disabled: Self::DEFAULT_SKIP_DISABLED,
+            highlight_matches: Self::DEFAULT_HIGHLIGHT_MATCHES,
+            numeric_shortcuts: NumericShortcuts::default(),
             scorer: Self::DEFAULT_SCORER,
+            sort_orders: vec![],
             formatter: Self::DEFAULT_FORMATTER,
+            loader: None,
+            loading_message: Self::DEFAULT_LOADING_MESSAGE,
+            history: None,
+            previewer: None,
+            preview_height: Self::DEFAULT_PREVIEW_HEIGHT,
             render_config: get_configuration(),
             starting_filter_input: None,
         }
@@ -235,6 +438,39 @@ where
         self
     }
 
+    /// Sets whether the page size is fitted to the height of the terminal,
+    /// leaving room for the other lines of the prompt, instead of using the
+    /// fixed page size.
+    ///
+    /// The descriptions, separators and group headers listed along with the
+    /// options are counted, while options too long for the width of the
+    /// terminal are counted as a single line even though they wrap.
+    ///
+    /// The page size is computed again whenever the prompt is rendered,
+    /// which the `crossterm` backend does as soon as the terminal is resized,
+    /// and the other backends on the next key press.
+    pub fn with_auto_page_size(mut self, auto_page_size: bool) -> Self {
+        self.auto_page_size = auto_page_size;
+        self
+    }
+
+    /// Sets whether the options are laid out in as many columns as fit in
+    /// the width of the terminal, filling the rows from left to right, instead
+    /// of one per line.
+    ///
+    /// The left and right arrows then move the cursor across the columns, and
+    /// no longer move the cursor of the filter input, while the page size,
+    /// fixed or fitted to the terminal, counts rows of options. Descriptions,
+    /// separators, group headers and option indexes are not rendered in this
+    /// layout, so it suits long lists of short options.
+    ///
+    /// Like the page size, the number of columns is computed again whenever
+    /// the prompt is rendered.
+    pub fn with_columns(mut self, columns: bool) -> Self {
+        self.columns = columns;
+        self
+    }
+
     /// Enables or disables vim_mode.
     pub fn with_vim_mode(mut self, vim_mode: bool) -> Self {
         self.vim_mode = vim_mode;
@@ -247,12 +483,82 @@ where
         self
     }
 
+    /// Adds a custom sort order, labeled in the prompt line while it is
+    /// selected. Pressing `Ctrl+s` cycles through the sort orders in the
+    /// order they were added, and then back to the original one.
+    pub fn with_sort_order(mut self, label: &'a str, comparator: Comparator<'a, T>) -> Self {
+        self.sort_orders.push((label, comparator));
+        self
+    }
+
     /// Sets the formatter.
     pub fn with_formatter(mut self, formatter: OptionFormatter<'a, T>) -> Self {
         self.formatter = formatter;
         self
     }
 
+    /// Sets whether the characters of the options matched by the filter input
+    /// are highlighted. Defaults to true.
+    ///
+    /// Highlights are computed by the built-in fuzzy matcher when the `fuzzy`
+    /// feature is enabled, or as a case-insensitive substring otherwise, so
+    /// they might be worth disabling when using a custom scorer.
+    pub fn with_highlight_matches(mut self, highlight_matches: bool) -> Self {
+        self.highlight_matches = highlight_matches;
+        self
+    }
+
+    /// Sets whether the digit keys are shortcuts to the first nine options of
+    /// the current page, which are then prefixed by their digits instead of
+    /// the `option_index_prefix` of the render config.
+    ///
+    /// While enabled, digits can not be typed into the filter input.
+    pub fn with_numeric_shortcuts(mut self, numeric_shortcuts: NumericShortcuts) -> Self {
+        self.numeric_shortcuts = numeric_shortcuts;
+        self
+    }
+
+    /// Sets the source of options appended to the list while the prompt is open.
+    ///
+    /// The options are polled while waiting for key presses with a timeout,
+    /// which the `console` backend does not support, failing the prompt with
+    /// an `InquireError::InvalidConfiguration` error.
+    pub fn with_loader(mut self, loader: OptionLoader<T>) -> Self {
+        self.loader = Some(loader);
+        self
+    }
+
+    /// Sets the message displayed below the options while they are being loaded.
+    pub fn with_loading_message(mut self, loading_message: &'a str) -> Self {
+        self.loading_message = loading_message;
+        self
+    }
+
+    /// Sets the store of the recently selected options, which are listed
+    /// first and recorded on submit.
+    ///
+    /// The prompt owns the history, so pass an `Rc<RefCell<_>>` of it to see
+    /// the recorded selections afterwards.
+    pub fn with_history<H>(mut self, history: H) -> Self
+    where
+        H: History + 'static,
+    {
+        self.history = Some(Box::new(history));
+        self
+    }
+
+    /// Sets the function that generates the preview of the highlighted option.
+    pub fn with_previewer(mut self, previewer: Previewer<'a, T>) -> Self {
+        self.previewer = Some(previewer);
+        self
+    }
+
+    /// Sets the maximum number of lines of the preview pane.
+    pub fn with_preview_height(mut self, preview_height: usize) -> Self {
+        self.preview_height = preview_height;
+        self
+    }
+
     /// Sets the starting cursor index.
     ///
     /// This index might be overridden if the `reset_cursor` option is set to true (default)
@@ -262,6 +568,31 @@ where
         self
     }
 
+    /// Highlights the first option equal to the provided value when the
+    /// prompt starts, even when groups or the history list it elsewhere.
+    ///
+    /// The starting cursor is left unchanged if no option is equal to it.
+    pub fn with_starting_selection(self, value: &T) -> Self
+    where
+        T: PartialEq,
+    {
+        self.with_starting_selection_by(|option| option == value)
+    }
+
+    /// Highlights the first option matching the provided predicate when the
+    /// prompt starts, even when groups or the history list it elsewhere.
+    ///
+    /// The starting cursor is left unchanged if no option matches it.
+    pub fn with_starting_selection_by<F>(mut self, predicate: F) -> Self
+    where
+        F: Fn(&T) -> bool,
+    {
+        if let Some(index) = self.options.iter().position(predicate) {
+            self.starting_selection = Some(index);
+        }
+        self
+    }
+
     /// Sets the starting filter input
     pub fn with_starting_filter_input(mut self, starting_filter_input: &'a str) -> Self {
         self.starting_filter_input = Some(starting_filter_input);
@@ -287,6 +618,110 @@ where
         self
     }
 
+    /// Sets whether moving past the last option wraps around to the first one
+    /// and vice versa, instead of stopping at the ends of the list.
+    pub fn with_wrap_around(mut self, wrap_around: bool) -> Self {
+        self.wrap_around = wrap_around;
+        self
+    }
+
+    /// Sets the key bindings used to navigate the options.
+    pub fn with_keymap(mut self, keymap: ListKeymap) -> Self {
+        self.keymap = keymap;
+        self
+    }
+
+    /// Sets whether typing a character moves the cursor to the next option
+    /// starting with it, case-insensitively, instead of filtering the options.
+    ///
+    /// When enabled, the filter input is disabled, as in [`without_filtering`](Self::without_filtering).
+    pub fn with_jump_to_letter(mut self, jump_to_letter: bool) -> Self {
+        self.jump_to_letter = jump_to_letter;
+        self
+    }
+
+    /// Adds a group of options, listed in the provided order under the header.
+    ///
+    /// Groups are listed in the order they are added, after the options that
+    /// do not belong to any group.
+    pub fn with_group(mut self, header: &'a str, indexes: &[usize]) -> Self {
+        self.groups.push((header, indexes.to_vec()));
+        self
+    }
+
+    /// Sets the description displayed in the line below the option at the provided index.
+    pub fn with_description(mut self, index: usize, description: &'a str) -> Self {
+        self.descriptions.insert(index, description);
+        self
+    }
+
+    /// Sets hidden keywords of the option at the provided index, such as
+    /// aliases, IDs or tags, which are searched when filtering but not displayed.
+    ///
+    /// For example, an option labeled `N. Virginia` with the `us-east-1`
+    /// keyword is matched by typing `us-east`.
+    pub fn with_keywords(mut self, index: usize, keywords: &[&'a str]) -> Self {
+        self.keywords
+            .entry(index)
+            .or_default()
+            .extend_from_slice(keywords);
+        self
+    }
+
+    /// Inserts a separator line before the option at the provided index.
+    ///
+    /// Separators are skipped by the cursor and hidden while filtering.
+    pub fn with_separator(mut self, index: usize) -> Self {
+        self.separators.insert(index);
+        self
+    }
+
+    /// Sets the icon rendered before the value of the option at the provided index.
+    ///
+    /// Icons are not part of the option values, so they are neither searched
+    /// when filtering nor passed to the formatter.
+    pub fn with_icon(mut self, index: usize, icon: Styled<&'a str>) -> Self {
+        self.icons.insert(index, icon);
+        self
+    }
+
+    /// Sets the descriptions of the options, in the same order as the option list.
+    ///
+    /// Empty descriptions are not displayed.
+    pub fn with_descriptions(mut self, descriptions: &[&'a str]) -> Self {
+        for (index, description) in descriptions.iter().enumerate() {
+            if !description.is_empty() {
+                self.descriptions.insert(index, description);
+            }
+        }
+        self
+    }
+
+    /// Disables the options at the provided indexes, which are still
+    /// displayed but can not be selected.
+    pub fn with_disabled_options(mut self, indexes: &[usize]) -> Self {
+        for index in indexes {
+            self.disabled_options.insert(*index, None);
+        }
+        self
+    }
+
+    /// Disables the option at the provided index, displaying the reason it
+    /// can not be selected next to it.
+    pub fn with_disabled_option(mut self, index: usize, reason: &'a str) -> Self {
+        self.disabled_options.insert(index, Some(reason));
+        self
+    }
+
+    /// Sets whether the cursor skips disabled options. Defaults to true.
+    ///
+    /// When false, the cursor stops at disabled options, and trying to submit one
+    /// of them displays the reason it can not be selected as an error message.
+    pub fn with_skip_disabled(mut self, skip_disabled: bool) -> Self {
+        self.skip_disabled = skip_disabled;
+        self
+    }
+
     /// Sets the provided color theme to this prompt.
     ///
     /// Note: The default render config considers if the NO_COLOR environment variable
@@ -336,10 +771,50 @@ where
         self.prompt_with_backend(&mut backend)
     }
 
+    /// Parses the provided behavioral and rendering options and prompts
+    /// the CLI user for input according to the defined rules, appending
+    /// an option with the provided label to the list.
+    ///
+    /// When this option is selected, the user is asked to type a custom answer
+    /// instead, which is returned as [`SelectOrOther::Other`].
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use inquire::{Select, SelectOrOther};
+    ///
+    /// let options = vec!["Email", "Phone"];
+    ///
+    /// match Select::new("How did you hear about us?", options).prompt_with_other("Other (specify)") {
+    ///     Ok(SelectOrOther::Selected(channel)) => println!("Through {}", channel),
+    ///     Ok(SelectOrOther::Other(channel)) => println!("Through {}, noted", channel),
+    ///     Err(_) => println!("There was an error, please try again"),
+    /// }
+    /// ```
+    pub fn prompt_with_other(self, other_label: &'a str) -> InquireResult<SelectOrOther<T>> {
+        let (input_reader, terminal) = get_default_terminal()?;
+        let mut backend = Backend::new(input_reader, terminal, self.render_config)?;
+        self.prompt_with_other_with_backend(other_label, &mut backend)
+    }
+
     pub(crate) fn prompt_with_backend<B: SelectBackend>(
         self,
         backend: &mut B,
     ) -> InquireResult<ListOption<T>> {
-        SelectPrompt::new(self)?.prompt(backend)
+        match SelectPrompt::new(self)?.prompt(backend)? {
+            SelectOrOther::Selected(answer) => Ok(answer),
+            SelectOrOther::Other(_) => unreachable!("no other option was added to the list"),
+        }
+    }
+
+    pub(crate) fn prompt_with_other_with_backend<B: SelectBackend>(
+        self,
+        other_label: &'a str,
+        backend: &mut B,
+    ) -> InquireResult<SelectOrOther<T>> {
+        SelectPrompt::new(self)?
+            .with_other_option(other_label)
+            .prompt(backend)
+            .map(|answer| answer.map(|op| op.value))
     }
 }
@@ -0,0 +1,42 @@
+use std::fmt::Display;
+
+/// Answer of a [Select](crate::Select) prompt that offers an "other" option,
+/// returned by [`Select::prompt_with_other`](crate::Select::prompt_with_other).
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum SelectOrOther<T> {
+    /// One of the provided options was selected.
+    Selected(T),
+    /// The "other" option was selected and the user typed a custom answer.
+    Other(String),
+}
+
+impl<T> SelectOrOther<T> {
+    pub(super) fn map<U, F>(self, f: F) -> SelectOrOther<U>
+    where
+        F: FnOnce(T) -> U,
+    {
+        match self {
+            Self::Selected(value) => SelectOrOther::Selected(f(value)),
+            Self::Other(value) => SelectOrOther::Other(value),
+        }
+    }
+}
+
+/// Entry of the rendered option list, which is either one of the provided
+/// options or the label of the "other" option, appended to the list.
+pub(super) enum Entry<'a, T> {
+    Option(&'a T),
+    Other(&'a str),
+}
+
+impl<'a, T> Display for Entry<'a, T>
+where
+    T: Display,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Option(value) => value.fmt(f),
+            Self::Other(label) => label.fmt(f),
+        }
+    }
+}
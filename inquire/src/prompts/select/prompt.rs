@@ -1,30 +1,84 @@
-use std::{cmp::Reverse, fmt::Display};
+use std::{
+    cell::Cell,
+    cmp::Reverse,
+    collections::{BTreeMap, BTreeSet},
+    fmt::Display,
+    time::Duration,
+};
 
 use crate::{
     error::InquireResult,
     formatter::OptionFormatter,
+    history::History,
     input::{Input, InputActionResult},
     list_option::ListOption,
-    prompts::prompt::{ActionResult, Prompt},
-    type_aliases::Scorer,
-    ui::SelectBackend,
-    utils::paginate,
-    InquireError, Select,
+    prompts::{
+        action::Action,
+        fuzzyfinder::matched_chars,
+        prompt::{ActionResult, Prompt},
+    },
+    type_aliases::{Comparator, Previewer, Scorer},
+    ui::{Key, KeyModifiers, MouseEvent, MouseEventKind, OptionDetails, SelectBackend},
+    utils::{closest_enabled, paginate, paginate_rows, Page},
+    validator::ErrorMessage,
+    InnerAction, InputAction, InquireError, ListKeymap, NumericShortcuts, Select,
+};
+
+use super::{
+    action::SelectPromptAction,
+    config::SelectConfig,
+    group::OptionGroups,
+    loader::{OptionLoader, LOADER_POLL_INTERVAL},
+    other::{Entry, SelectOrOther},
 };
 
-use super::{action::SelectPromptAction, config::SelectConfig};
+/// Error message displayed when submitting a disabled option without a reason.
+const DISABLED_OPTION_ERROR: &str = "This option can not be selected";
+
+/// Hint appended to the help message while the options are filtered.
+const CLEAR_FILTER_HELP_MESSAGE: &str = "esc to clear filter";
 
 pub struct SelectPrompt<'a, T> {
     message: &'a str,
     config: SelectConfig,
     options: Vec<T>,
-    string_options: Vec<String>,
+    separators: BTreeSet<usize>,
+    search_strings: Vec<String>,
     scored_options: Vec<usize>,
+    groups: OptionGroups<'a>,
+    history: Option<Box<dyn History>>,
+    // entries of the history, used to rank the options
+    recent: Vec<String>,
+    // position in the history of each option, if it was recently selected
+    recency: Vec<Option<usize>>,
     help_message: Option<&'a str>,
     cursor_index: usize,
     input: Option<Input>,
+    // whether keys are typed into the filter input, when using the vim keymap
+    filter_focused: bool,
+    details: OptionDetails<'a>,
+    error: Option<ErrorMessage>,
     scorer: Scorer<'a, T>,
+    sort_orders: Vec<(&'a str, Comparator<'a, T>)>,
+    // index of the custom sort order applied to the options, if any
+    sort_order: Option<usize>,
     formatter: OptionFormatter<'a, T>,
+    previewer: Option<Previewer<'a, T>>,
+    // unset once every option has been loaded
+    loader: Option<OptionLoader<T>>,
+    loading_message: &'a str,
+    other_option: Option<&'a str>,
+    // set when the "other" option is selected, holding the custom answer
+    other_input: Option<Input>,
+    // position in the page of the option whose numeric shortcut was pressed,
+    // stashed by `parse_key` to be picked up by `submit`
+    shortcut: Cell<Option<usize>>,
+    // when fitted to the terminal height, the page size is only known once
+    // the prompt is rendered
+    page_size: Cell<usize>,
+    // when laid out in columns, the number of columns is only known once the
+    // prompt is rendered, and is kept here for the movement actions
+    columns: Cell<usize>,
 }
 
 impl<'a, T> SelectPrompt<'a, T>
@@ -32,13 +86,14 @@ where
     T: Display,
 {
     pub fn new(so: Select<'a, T>) -> InquireResult<Self> {
-        if so.options.is_empty() {
+        if so.options.is_empty() && so.loader.is_none() {
             return Err(InquireError::InvalidConfiguration(
                 "Available options can not be empty".into(),
             ));
         }
 
-        if so.starting_cursor >= so.options.len() {
+        // with a loader, the list may start empty with the cursor at the top
+        if so.starting_cursor >= so.options.len().max(1) {
             return Err(InquireError::InvalidConfiguration(format!(
                 "Starting cursor index {} is out-of-bounds for length {} of options",
                 so.starting_cursor,
@@ -46,28 +101,161 @@ where
             )));
         }
 
-        let string_options = so.options.iter().map(T::to_string).collect();
-        let scored_options = (0..so.options.len()).collect();
+        if let Some(index) = so.disabled_options.keys().find(|i| **i >= so.options.len()) {
+            return Err(InquireError::InvalidConfiguration(format!(
+                "Index {} of disabled option is out-of-bounds for length {} of options",
+                index,
+                &so.options.len()
+            )));
+        }
+
+        if so.disabled_options.len() == so.options.len() && so.loader.is_none() {
+            return Err(InquireError::InvalidConfiguration(
+                "At least one option must be enabled".into(),
+            ));
+        }
+
+        if let Some(index) = so.keywords.keys().find(|i| **i >= so.options.len()) {
+            return Err(InquireError::InvalidConfiguration(format!(
+                "Index {} of option keywords is out-of-bounds for length {} of options",
+                index,
+                &so.options.len()
+            )));
+        }
+
+        if let Some(index) = so.separators.iter().find(|i| **i >= so.options.len()) {
+            return Err(InquireError::InvalidConfiguration(format!(
+                "Index {} of separator is out-of-bounds for length {} of options",
+                index,
+                &so.options.len()
+            )));
+        }
+
+        if let Some(index) = so.icons.keys().find(|i| **i >= so.options.len()) {
+            return Err(InquireError::InvalidConfiguration(format!(
+                "Index {} of option icon is out-of-bounds for length {} of options",
+                index,
+                &so.options.len()
+            )));
+        }
+
+        if let Some(index) = so.descriptions.keys().find(|i| **i >= so.options.len()) {
+            return Err(InquireError::InvalidConfiguration(format!(
+                "Index {} of option description is out-of-bounds for length {} of options",
+                index,
+                &so.options.len()
+            )));
+        }
+
+        // descriptions and keywords are searched along with the values of the options
+        let search_strings = so
+            .options
+            .iter()
+            .enumerate()
+            .map(|(i, option)| {
+                let mut search_string = option.to_string();
+                let description = so.descriptions.get(&i).into_iter();
+                let keywords = so.keywords.get(&i).into_iter().flatten();
+
+                for term in description.chain(keywords) {
+                    search_string.push(' ');
+                    search_string.push_str(term);
+                }
 
-        let input = match so.filter_input_enabled {
+                search_string
+            })
+            .collect();
+        let groups = OptionGroups::new(&so.groups, so.options.len())?;
+        let scored_options = groups.order.clone();
+
+        let recent = so
+            .history
+            .as_ref()
+            .map(|history| history.entries())
+            .unwrap_or_default();
+        let recency = so
+            .options
+            .iter()
+            .map(|option| recency_rank(&recent, option))
+            .collect();
+
+        let input = match so.filter_input_enabled && !so.jump_to_letter {
             true => Some(Input::new_with(
                 so.starting_filter_input.unwrap_or_default(),
             )),
             false => None,
         };
 
-        Ok(Self {
+        let starting_selection = so.starting_selection;
+
+        let mut prompt = Self {
             message: so.message,
             config: (&so).into(),
             options: so.options,
-            string_options,
+            separators: so.separators.clone(),
+            search_strings,
             scored_options,
+            history: so.history,
+            recent,
+            recency,
             help_message: so.help_message,
             cursor_index: so.starting_cursor,
             input,
+            filter_focused: false,
+            details: OptionDetails {
+                locked: BTreeSet::new(),
+                disabled: so.disabled_options,
+                descriptions: so.descriptions,
+                icons: so.icons,
+                separators: so.separators,
+                groups: groups.headers.clone(),
+                matched: BTreeMap::new(),
+                group_checkboxes: false,
+                shortcuts: so.numeric_shortcuts != NumericShortcuts::Disabled,
+            },
+            groups,
+            error: None,
             scorer: so.scorer,
+            sort_orders: so.sort_orders,
+            sort_order: None,
             formatter: so.formatter,
-        })
+            previewer: so.previewer,
+            loader: so.loader,
+            loading_message: so.loading_message,
+            other_option: None,
+            other_input: None,
+            shortcut: Cell::new(None),
+            page_size: Cell::new(so.page_size),
+            columns: Cell::new(1),
+        };
+
+        prompt.scored_options = prompt.unfiltered_order();
+
+        // the selection is an index of the options, while the cursor is a
+        // position in the list, which groups and the history reorder
+        if let Some(position) = starting_selection.and_then(|index| {
+            prompt
+                .scored_options
+                .iter()
+                .position(|&option| option == index)
+        }) {
+            prompt.cursor_index = position;
+        }
+
+        Ok(prompt)
+    }
+
+    pub fn with_other_option(mut self, label: &'a str) -> Self {
+        self.other_option = Some(label);
+        self
+    }
+
+    /// Number of entries in the option list, including the "other" option.
+    fn entry_count(&self) -> usize {
+        match self.other_option {
+            Some(_) => self.scored_options.len() + 1,
+            None => self.scored_options.len(),
+        }
     }
 
     fn move_cursor_up(&mut self, qty: usize, wrap: bool) -> ActionResult {
@@ -75,39 +263,155 @@ where
             let after_wrap = qty.saturating_sub(self.cursor_index);
             self.cursor_index
                 .checked_sub(qty)
-                .unwrap_or_else(|| self.scored_options.len().saturating_sub(after_wrap))
+                .unwrap_or_else(|| self.entry_count().saturating_sub(after_wrap))
         } else {
             self.cursor_index.saturating_sub(qty)
         };
 
+        let new_position = self.skip_disabled(new_position, true, wrap);
         self.update_cursor_position(new_position)
     }
 
     fn move_cursor_down(&mut self, qty: usize, wrap: bool) -> ActionResult {
         let mut new_position = self.cursor_index.saturating_add(qty);
 
-        if new_position >= self.scored_options.len() {
-            new_position = if self.scored_options.is_empty() {
+        let entry_count = self.entry_count();
+
+        if new_position >= entry_count {
+            new_position = if entry_count == 0 {
                 0
             } else if wrap {
-                new_position % self.scored_options.len()
+                new_position % entry_count
             } else {
-                self.scored_options.len().saturating_sub(1)
+                entry_count.saturating_sub(1)
             }
         }
 
+        let new_position = self.skip_disabled(new_position, false, wrap);
         self.update_cursor_position(new_position)
     }
 
+    /// Moves the cursor by whole rows, when the options are laid out in
+    /// columns, staying in the same column.
+    fn move_rows_up(&mut self, qty: usize) -> ActionResult {
+        let columns = self.columns.get();
+        let column = self.cursor_index % columns;
+
+        let new_position = self
+            .cursor_index
+            .checked_sub(qty.saturating_mul(columns))
+            .unwrap_or(column);
+
+        let new_position = self.skip_disabled(new_position, true, false);
+        self.update_cursor_position(new_position)
+    }
+
+    fn move_rows_down(&mut self, qty: usize) -> ActionResult {
+        let columns = self.columns.get();
+        let last = self.entry_count().saturating_sub(1);
+        let row = (self.cursor_index / columns)
+            .saturating_add(qty)
+            .min(last / columns);
+
+        // the last row might be incomplete, in which case the cursor
+        // goes to its last option
+        let new_position = (row * columns + self.cursor_index % columns).min(last);
+
+        let new_position = self.skip_disabled(new_position, false, false);
+        self.update_cursor_position(new_position)
+    }
+
+    /// Page of the entries around the cursor, made of whole rows when the
+    /// options are laid out in columns.
+    fn page<'e, D>(&self, entries: &'e [D]) -> Page<'e, D> {
+        match self.columns.get() {
+            1 => paginate(self.page_size.get(), entries, Some(self.cursor_index)),
+            columns => paginate_rows(
+                self.page_size.get(),
+                columns,
+                entries,
+                Some(self.cursor_index),
+            ),
+        }
+    }
+
+    /// Position in the list of the entry at the given position of the current
+    /// page, targeted by a numeric shortcut.
+    fn shortcut_target(&self, position: usize) -> Option<usize> {
+        let entries = (0..self.entry_count()).collect::<Vec<usize>>();
+        let page = self.page(&entries);
+
+        page.content.get(position).copied()
+    }
+
+    fn jump_to_option(&mut self, position: usize) -> ActionResult {
+        match self.shortcut_target(position) {
+            Some(new_position) => self.update_cursor_position(new_position),
+            None => ActionResult::Clean,
+        }
+    }
+
+    /// Moves the cursor to the next option starting with the typed character,
+    /// wrapping around the list.
+    fn jump_to_letter(&mut self, letter: char) -> ActionResult {
+        let letter = letter.to_lowercase().collect::<String>();
+        let count = self.scored_options.len();
+
+        let new_position = (1..=count)
+            .map(|offset| (self.cursor_index + offset) % count)
+            .find(|position| {
+                let index = self.scored_options[*position];
+                let skipped =
+                    self.config.skip_disabled && self.details.disabled.contains_key(&index);
+
+                !skipped
+                    && self.search_strings[index]
+                        .to_lowercase()
+                        .starts_with(&letter)
+            });
+
+        match new_position {
+            Some(new_position) => self.update_cursor_position(new_position),
+            None => ActionResult::Clean,
+        }
+    }
+
     fn update_cursor_position(&mut self, new_position: usize) -> ActionResult {
         if new_position != self.cursor_index {
             self.cursor_index = new_position;
+            self.error = None;
             ActionResult::NeedsRedraw
         } else {
             ActionResult::Clean
         }
     }
 
+    /// Error message explaining why the option at the given position of the
+    /// list can not be selected, if it is disabled.
+    fn disabled_error(&self, position: usize) -> Option<ErrorMessage> {
+        let index = self.scored_options.get(position)?;
+        let reason = self
+            .details
+            .disabled
+            .get(index)?
+            .unwrap_or(DISABLED_OPTION_ERROR);
+
+        Some(ErrorMessage::Custom(reason.to_string()))
+    }
+
+    /// Moves the given cursor position out of disabled options, if the cursor
+    /// is set to skip them, keeping it in place when there are none left.
+    fn skip_disabled(&self, position: usize, upwards: bool, wrap: bool) -> usize {
+        if !self.config.skip_disabled {
+            return position;
+        }
+
+        closest_enabled(position, self.entry_count(), upwards, wrap, |p| {
+            self.disabled_error(p).is_none()
+        })
+        .unwrap_or(self.cursor_index)
+    }
+
     fn has_answer_highlighted(&mut self) -> bool {
         self.scored_options.get(self.cursor_index).is_some()
     }
@@ -119,40 +423,238 @@ where
         let index = *self.scored_options.get(self.cursor_index).unwrap();
         let value = self.options.swap_remove(index);
 
+        if let Some(history) = self.history.as_mut() {
+            history.record(&value.to_string());
+        }
+
         ListOption::new(index, value)
     }
 
-    fn run_scorer(&mut self) {
-        let content = match &self.input {
-            Some(input) => input.content(),
+    /// Appends the options received by the loader, keeping the cursor on
+    /// the highlighted entry.
+    fn load_options(&mut self) {
+        let (options, finished) = match &self.loader {
+            Some(loader) => loader.poll(),
             None => return,
         };
 
+        if finished {
+            self.loader = None;
+        }
+
+        if options.is_empty() {
+            return;
+        }
+
+        let highlighted = self.scored_options.get(self.cursor_index).copied();
+        let listed = self.scored_options.len();
+        let on_entry = self.cursor_index < self.entry_count();
+
+        for option in options {
+            self.recency.push(recency_rank(&self.recent, &option));
+            self.search_strings.push(option.to_string());
+            self.options.push(option);
+            self.groups.push();
+        }
+
+        self.scored_options = match &self.input {
+            Some(input) => self.score_options(input.content()),
+            None => self.unfiltered_order(),
+        };
+        self.update_matches();
+
+        let new_position = match highlighted {
+            Some(index) => self
+                .scored_options
+                .iter()
+                .position(|i| *i == index)
+                .unwrap_or(self.cursor_index),
+            // entries listed after the options, such as the "other" option
+            None if on_entry => self.cursor_index - listed + self.scored_options.len(),
+            None => self.cursor_index,
+        };
+
+        let new_position = new_position.min(self.entry_count().saturating_sub(1));
+        let _ = self.update_cursor_position(self.skip_disabled(new_position, false, false));
+    }
+
+    /// Indexes of the options matching the filter, sorted by their scores.
+    fn score_options(&self, content: &str) -> Vec<usize> {
         let mut options = self
-            .options
+            .groups
+            .order
             .iter()
-            .enumerate()
+            .map(|i| (*i, self.options.get(*i).unwrap()))
             .filter_map(|(i, opt)| {
-                (self.scorer)(content, opt, self.string_options.get(i).unwrap(), i)
+                (self.scorer)(content, opt, self.search_strings.get(i).unwrap(), i)
                     .map(|score| (i, score))
             })
             .collect::<Vec<(usize, i64)>>();
 
-        options.sort_unstable_by_key(|(_idx, score)| Reverse(*score));
+        self.sort_options(&mut options);
+
+        options.iter().map(|(idx, _)| *idx).collect()
+    }
+
+    /// Indexes of the options in the order they are listed while not filtered.
+    fn unfiltered_order(&self) -> Vec<usize> {
+        let mut options = self
+            .groups
+            .order
+            .iter()
+            .map(|i| (*i, 0))
+            .collect::<Vec<(usize, i64)>>();
+
+        self.sort_options(&mut options);
 
-        let new_scored_options = options.iter().map(|(idx, _)| *idx).collect::<Vec<usize>>();
+        options.iter().map(|(idx, _)| *idx).collect()
+    }
 
-        if self.scored_options == new_scored_options {
+    /// Sorts the scored options by descending score, the recently selected
+    /// ones first among the options with the same score, unless a custom
+    /// sort order is selected.
+    fn sort_options(&self, options: &mut [(usize, i64)]) {
+        if let Some((_label, comparator)) = self.sort_order.map(|order| self.sort_orders[order]) {
+            options.sort_by(|(a, _), (b, _)| comparator(&self.options[*a], &self.options[*b]));
+            self.groups.sort_by_group(options);
             return;
         }
 
+        if !self.recent.is_empty() {
+            options.sort_by_key(|(idx, _score)| self.recency[*idx].unwrap_or(usize::MAX));
+        }
+
+        match self.groups.is_empty() {
+            true => options.sort_by_key(|(_idx, score)| Reverse(*score)),
+            false => self.groups.sort(options),
+        }
+    }
+
+    /// Finds the characters of the listed options matched by the filter
+    /// input, which are highlighted when rendering them.
+    fn update_matches(&mut self) {
+        self.details.matched.clear();
+
+        let content = match &self.input {
+            Some(input) if self.config.highlight_matches && !input.is_empty() => input.content(),
+            _ => return,
+        };
+
+        for index in &self.scored_options {
+            let matched = matched_chars(content, &self.options[*index].to_string());
+
+            if !matched.is_empty() {
+                self.details.matched.insert(*index, matched);
+            }
+        }
+    }
+
+    /// Number of options fitting in the terminal along with the other lines
+    /// of the prompt, the descriptions, separators and group headers listed
+    /// with them taking lines of their own.
+    ///
+    /// When the options are laid out in columns, the page size counts the
+    /// rows fitting in the terminal instead.
+    fn fit_page_size<D>(&self, screen_height: usize, choices: &[ListOption<D>]) -> usize {
+        // prompt and the empty line the cursor rests on
+        let mut reserved = 2;
+
+        reserved += usize::from(self.help_message.is_some());
+        reserved += usize::from(self.error.is_some());
+        reserved += usize::from(self.loader.is_some());
+
+        if self.previewer.is_some() {
+            // separator line between the options and the preview
+            reserved += self.config.preview_height + 1;
+        }
+
+        let height = screen_height.saturating_sub(reserved).max(1);
+
+        if self.columns.get() > 1 {
+            return height;
+        }
+
+        self.details
+            .fit_page_size(height, choices, Some(self.cursor_index))
+    }
+
+    /// Hides the separators while filtering or sorting, as the options they
+    /// separate are no longer listed next to each other.
+    fn update_separators(&mut self) {
+        self.details.separators = match self.is_filter_active() || self.sort_order.is_some() {
+            true => BTreeSet::new(),
+            false => self.separators.clone(),
+        };
+    }
+
+    /// Switches to the next custom sort order, or back to the original one
+    /// after the last, keeping the cursor on the highlighted option.
+    fn cycle_sort_order(&mut self) -> ActionResult {
+        if self.sort_orders.is_empty() {
+            return ActionResult::Clean;
+        }
+
+        self.sort_order = match self.sort_order {
+            Some(order) if order + 1 < self.sort_orders.len() => Some(order + 1),
+            Some(_) => None,
+            None => Some(0),
+        };
+
+        let highlighted = self.scored_options.get(self.cursor_index).copied();
+
+        self.scored_options = match &self.input {
+            Some(input) => self.score_options(input.content()),
+            None => self.unfiltered_order(),
+        };
+        self.update_separators();
+
+        if let Some(position) =
+            highlighted.and_then(|index| self.scored_options.iter().position(|i| *i == index))
+        {
+            self.cursor_index = position;
+        }
+
+        ActionResult::NeedsRedraw
+    }
+
+    fn is_filter_active(&self) -> bool {
+        matches!(&self.input, Some(input) if !input.is_empty())
+    }
+
+    /// Clears the filter input, returning whether there was anything to clear.
+    fn clear_filter(&mut self) -> bool {
+        match self.input.as_mut() {
+            Some(input) if !input.is_empty() => input.clear(),
+            _ => return false,
+        }
+
+        self.run_scorer();
+        true
+    }
+
+    fn run_scorer(&mut self) {
+        let new_scored_options = match &self.input {
+            Some(input) => self.score_options(input.content()),
+            None => return,
+        };
+
+        let changed = self.scored_options != new_scored_options;
+
         self.scored_options = new_scored_options;
+        self.update_matches();
+        self.update_separators();
+
+        if !changed {
+            return;
+        }
 
         if self.config.reset_cursor {
             let _ = self.update_cursor_position(0);
-        } else if self.scored_options.len() <= self.cursor_index {
-            let _ = self.update_cursor_position(self.scored_options.len().saturating_sub(1));
+        } else if self.entry_count() <= self.cursor_index {
+            let _ = self.update_cursor_position(self.entry_count().saturating_sub(1));
         }
+
+        let _ = self.update_cursor_position(self.skip_disabled(self.cursor_index, false, false));
     }
 }
 
@@ -163,7 +665,7 @@ where
 {
     type Config = SelectConfig;
     type InnerAction = SelectPromptAction;
-    type Output = ListOption<T>;
+    type Output = SelectOrOther<ListOption<T>>;
 
     fn message(&self) -> &str {
         self.message
@@ -173,18 +675,122 @@ where
         &self.config
     }
 
-    fn format_answer(&self, answer: &ListOption<T>) -> String {
-        (self.formatter)(answer.as_ref())
+    fn format_answer(&self, answer: &SelectOrOther<ListOption<T>>) -> String {
+        match answer {
+            SelectOrOther::Selected(answer) => (self.formatter)(answer.as_ref()),
+            SelectOrOther::Other(answer) => answer.clone(),
+        }
+    }
+
+    fn parse_key(&self, key: Key) -> Option<Action<SelectPromptAction>> {
+        let typed = || {
+            InputAction::from_key(key, &())
+                .map(|a| Action::Inner(SelectPromptAction::FilterInput(a)))
+        };
+
+        if self.other_input.is_none() && self.filter_focused {
+            return match key {
+                Key::Enter | Key::Escape => Some(Action::Inner(SelectPromptAction::UnfocusFilter)),
+                _ => typed().or_else(|| Action::from_key(key, &self.config)),
+            };
+        }
+
+        let action = match key {
+            // Ctrl+d cancels the prompt unless it is claimed by the vim keymap
+            Key::Char('d', KeyModifiers::CONTROL)
+                if self.other_input.is_none() && self.config.keymap == ListKeymap::Vim =>
+            {
+                Some(Action::Inner(SelectPromptAction::PageDown))
+            }
+            // the first click of a double click already moved the cursor
+            Key::Mouse(MouseEvent {
+                kind: MouseEventKind::DoubleClick,
+                option: Some(_),
+                ..
+            }) if self.other_input.is_none() => Some(Action::Submit),
+            _ => Action::from_key(key, &self.config),
+        };
+
+        // while the custom answer is typed, keys such as the vim mode ones
+        // edit the answer instead of moving the cursor
+        match (&self.other_input, action) {
+            (Some(_), None | Some(Action::Inner(_))) => typed(),
+            (None, Some(Action::Inner(SelectPromptAction::JumpToOption(position))))
+                if self.config.numeric_shortcuts == NumericShortcuts::Submit
+                    && !matches!(key, Key::Mouse(_)) =>
+            {
+                self.shortcut.set(Some(position));
+                Some(Action::Submit)
+            }
+            (_, action) => action,
+        }
     }
 
     fn setup(&mut self) -> InquireResult<()> {
+        self.load_options();
         self.run_scorer();
+
+        let _ = self.update_cursor_position(self.skip_disabled(self.cursor_index, false, false));
         Ok(())
     }
 
-    fn submit(&mut self) -> InquireResult<Option<ListOption<T>>> {
+    fn captures_mouse(&self) -> bool {
+        true
+    }
+
+    fn tick_interval(&self) -> Option<Duration> {
+        self.loader.as_ref().map(|_| LOADER_POLL_INTERVAL)
+    }
+
+    fn tick(&mut self) -> InquireResult<Option<Action<SelectPromptAction>>> {
+        self.load_options();
+
+        Ok(None)
+    }
+
+    fn pre_cancel(&mut self) -> InquireResult<bool> {
+        if self.other_input.is_some() {
+            self.other_input = None;
+            return Ok(false);
+        }
+
+        // the first press of escape clears the filter, if any
+        if self.clear_filter() {
+            return Ok(false);
+        }
+
+        Ok(true)
+    }
+
+    fn submit(&mut self) -> InquireResult<Option<SelectOrOther<ListOption<T>>>> {
+        if let Some(input) = &self.other_input {
+            let answer = match input.content().trim() {
+                "" => None,
+                content => Some(SelectOrOther::Other(content.to_string())),
+            };
+
+            return Ok(answer);
+        }
+
+        if let Some(position) = self.shortcut.take() {
+            match self.shortcut_target(position) {
+                Some(new_position) => self.cursor_index = new_position,
+                None => return Ok(None),
+            }
+        }
+
+        if self.other_option.is_some() && self.cursor_index == self.scored_options.len() {
+            self.other_input = Some(Input::new());
+            return Ok(None);
+        }
+
+        if let Some(error) = self.disabled_error(self.cursor_index) {
+            self.error = Some(error);
+            return Ok(None);
+        }
+
         let answer = match self.has_answer_highlighted() {
-            true => Some(self.get_final_answer()),
+            true => Some(SelectOrOther::Selected(self.get_final_answer())),
             false => None,
         };
 
@@ -192,13 +798,50 @@ where
     }
 
     fn handle(&mut self, action: SelectPromptAction) -> InquireResult<ActionResult> {
+        if let Some(input) = self.other_input.as_mut() {
+            let result = match action {
+                SelectPromptAction::FilterInput(input_action) => input.handle(input_action).into(),
+                _ => ActionResult::Clean,
+            };
+
+            return Ok(result);
+        }
+
+        let columns = self.columns.get();
+        let column = self.cursor_index % columns;
+
         let result = match action {
-            SelectPromptAction::MoveUp => self.move_cursor_up(1, true),
-            SelectPromptAction::MoveDown => self.move_cursor_down(1, true),
-            SelectPromptAction::PageUp => self.move_cursor_up(self.config.page_size, false),
-            SelectPromptAction::PageDown => self.move_cursor_down(self.config.page_size, false),
+            SelectPromptAction::MoveLeft if column > 0 => self.move_cursor_up(1, false),
+            SelectPromptAction::MoveRight if column + 1 < columns => {
+                self.move_cursor_down(1, false)
+            }
+            SelectPromptAction::MoveLeft | SelectPromptAction::MoveRight => ActionResult::Clean,
+            SelectPromptAction::MoveUp if columns > 1 => self.move_rows_up(1),
+            SelectPromptAction::MoveDown if columns > 1 => self.move_rows_down(1),
+            SelectPromptAction::PageUp if columns > 1 => self.move_rows_up(self.page_size.get()),
+            SelectPromptAction::PageDown if columns > 1 => {
+                self.move_rows_down(self.page_size.get())
+            }
+            SelectPromptAction::MoveUp => self.move_cursor_up(1, self.config.wrap_around),
+            SelectPromptAction::MoveDown => self.move_cursor_down(1, self.config.wrap_around),
+            SelectPromptAction::PageUp => self.move_cursor_up(self.page_size.get(), false),
+            SelectPromptAction::PageDown => self.move_cursor_down(self.page_size.get(), false),
             SelectPromptAction::MoveToStart => self.move_cursor_up(usize::MAX, false),
             SelectPromptAction::MoveToEnd => self.move_cursor_down(usize::MAX, false),
+            SelectPromptAction::JumpToOption(position) => self.jump_to_option(position),
+            SelectPromptAction::FocusFilter => {
+                self.filter_focused = self.input.is_some();
+                ActionResult::Clean
+            }
+            SelectPromptAction::UnfocusFilter => {
+                self.filter_focused = false;
+                ActionResult::Clean
+            }
+            SelectPromptAction::ClearFilter => match self.clear_filter() {
+                true => ActionResult::NeedsRedraw,
+                false => ActionResult::Clean,
+            },
+            SelectPromptAction::CycleSortOrder => self.cycle_sort_order(),
 
             SelectPromptAction::FilterInput(input_action) => match self.input.as_mut() {
                 Some(input) => {
@@ -210,7 +853,10 @@ where
 
                     result.into()
                 }
-                None => ActionResult::Clean,
+                None => match input_action {
+                    InputAction::Write(c) if self.config.jump_to_letter => self.jump_to_letter(c),
+                    _ => ActionResult::Clean,
+                },
             },
         };
 
@@ -220,23 +866,90 @@ where
     fn render(&self, backend: &mut Backend) -> InquireResult<()> {
         let prompt = &self.message;
 
-        backend.render_select_prompt(prompt, self.input.as_ref())?;
+        if let Some(input) = &self.other_input {
+            backend.render_select_prompt(prompt, Some(input))?;
+            return Ok(());
+        }
+
+        if let Some(err) = &self.error {
+            backend.render_error_message(err)?;
+        }
+
+        let sort_order = self.sort_order.map(|order| self.sort_orders[order].0);
+        let filter_count = match self.is_filter_active() {
+            true => Some((self.scored_options.len(), self.options.len())),
+            false => None,
+        };
 
-        let choices = self
+        match (filter_count, sort_order) {
+            (None, None) => backend.render_select_prompt(prompt, self.input.as_ref())?,
+            _ => backend.render_select_header(
+                prompt,
+                self.input.as_ref(),
+                filter_count,
+                sort_order,
+            )?,
+        }
+
+        let mut choices = self
             .scored_options
             .iter()
             .cloned()
-            .map(|i| ListOption::new(i, self.options.get(i).unwrap()))
-            .collect::<Vec<ListOption<&T>>>();
+            .map(|i| ListOption::new(i, Entry::Option(self.options.get(i).unwrap())))
+            .collect::<Vec<ListOption<Entry<'_, T>>>>();
+
+        if let Some(label) = self.other_option {
+            choices.push(ListOption::new(self.options.len(), Entry::Other(label)));
+        }
+
+        if self.config.columns {
+            let screen_width = backend.screen_size().width() as usize;
+            self.columns
+                .set(self.details.fit_columns(screen_width, &choices));
+        }
+
+        if self.config.auto_page_size {
+            let screen_height = backend.screen_size().height() as usize;
+            let list_height = screen_height.saturating_sub(backend.scroll_indicators_height());
+            self.page_size
+                .set(self.fit_page_size(list_height, &choices));
+        }
+
+        let page = self.page(&choices);
+
+        backend.render_options_with_details(page, &self.details, self.columns.get())?;
 
-        let page = paginate(self.config.page_size, &choices, Some(self.cursor_index));
+        if self.loader.is_some() {
+            backend.render_loading_indicator(self.loading_message)?;
+        }
+
+        if let Some(previewer) = self.previewer {
+            let preview = self
+                .scored_options
+                .get(self.cursor_index)
+                .map(|i| previewer(&self.options[*i]))
+                .unwrap_or_default();
 
-        backend.render_options(page)?;
+            backend.render_preview(&preview, self.config.preview_height)?;
+        }
 
-        if let Some(help_message) = self.help_message {
-            backend.render_help_message(help_message)?;
+        match self.help_message {
+            Some(help_message) if self.is_filter_active() => backend
+                .render_help_message(&format!("{help_message}, {CLEAR_FILTER_HELP_MESSAGE}"))?,
+            Some(help_message) => backend.render_help_message(help_message)?,
+            None => {}
         }
 
         Ok(())
     }
 }
+
+/// Position in the history of the given option, matched by its string value.
+fn recency_rank<T: Display>(recent: &[String], option: &T) -> Option<usize> {
+    if recent.is_empty() {
+        return None;
+    }
+
+    let label = option.to_string();
+    recent.iter().position(|entry| *entry == label)
+}
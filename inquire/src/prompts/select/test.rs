@@ -1,9 +1,13 @@
+use std::{cell::RefCell, rc::Rc, sync::mpsc};
+
 use crate::{
     formatter::OptionFormatter,
+    history::History,
     list_option::ListOption,
     test::fake_backend,
-    ui::{Key, KeyModifiers},
-    Select,
+    type_aliases::Scorer,
+    ui::{Key, KeyModifiers, MouseEvent, MouseEventKind, Styled},
+    InquireError, ListKeymap, NumericShortcuts, OptionLoader, Select, SelectOrOther,
 };
 
 #[test]
@@ -142,3 +146,664 @@ fn chars_do_not_affect_prompt_without_filtering() {
 
     assert_eq!(ListOption::new(0, "Banana"), ans);
 }
+
+#[test]
+fn option_is_selected_when_other_option_is_available() {
+    let mut backend = fake_backend(vec![Key::Down(KeyModifiers::NONE), Key::Enter]);
+
+    let options = vec!["Email", "Phone"];
+
+    let ans = Select::new("Question", options)
+        .prompt_with_other_with_backend("Other", &mut backend)
+        .unwrap();
+
+    assert_eq!(SelectOrOther::Selected("Phone"), ans);
+}
+
+#[test]
+fn other_option_turns_into_text_input() {
+    let mut keys = vec![Key::Up(KeyModifiers::NONE), Key::Enter];
+    keys.extend(Key::char_keys_from_str("jk Radio"));
+    keys.push(Key::Enter);
+    let mut backend = fake_backend(keys);
+
+    let options = vec!["Email", "Phone"];
+
+    let ans = Select::new("Question", options)
+        .with_vim_mode(true)
+        .prompt_with_other_with_backend("Other", &mut backend)
+        .unwrap();
+
+    assert_eq!(SelectOrOther::Other(String::from("jk Radio")), ans);
+}
+
+#[test]
+fn other_option_is_kept_when_filtering() {
+    let mut keys = Key::char_keys_from_str("zzz");
+    keys.extend([Key::Enter, Key::Enter]);
+    keys.extend(Key::char_keys_from_str("Radio"));
+    keys.push(Key::Enter);
+    let mut backend = fake_backend(keys);
+
+    let options = vec!["Email", "Phone"];
+
+    let ans = Select::new("Question", options)
+        .prompt_with_other_with_backend("Other", &mut backend)
+        .unwrap();
+
+    assert_eq!(SelectOrOther::Other(String::from("Radio")), ans);
+}
+
+#[test]
+fn escape_goes_back_from_other_input_and_empty_answer_is_rejected() {
+    let mut keys = vec![Key::Up(KeyModifiers::NONE), Key::Enter, Key::Enter];
+    keys.extend(Key::char_keys_from_str("Radio"));
+    keys.extend([Key::Escape, Key::Down(KeyModifiers::NONE), Key::Enter]);
+    let mut backend = fake_backend(keys);
+
+    let options = vec!["Email", "Phone"];
+
+    let ans = Select::new("Question", options)
+        .prompt_with_other_with_backend("Other", &mut backend)
+        .unwrap();
+
+    assert_eq!(SelectOrOther::Selected("Email"), ans);
+}
+
+#[test]
+fn cursor_skips_disabled_options() {
+    let mut backend = fake_backend(vec![
+        Key::Down(KeyModifiers::NONE),
+        Key::Down(KeyModifiers::NONE),
+        Key::Enter,
+    ]);
+
+    let options = vec![1, 2, 3, 4, 5];
+
+    let ans = Select::new("Question", options)
+        .with_disabled_options(&[0, 2])
+        .with_disabled_option(3, "out of stock")
+        .prompt_with_backend(&mut backend)
+        .unwrap();
+
+    assert_eq!(ListOption::new(1, 2), ans);
+}
+
+#[test]
+fn disabled_option_is_not_submitted_when_not_skipped() {
+    let mut backend = fake_backend(vec![
+        Key::Down(KeyModifiers::NONE),
+        Key::Enter,
+        Key::Down(KeyModifiers::NONE),
+        Key::Enter,
+    ]);
+
+    let options = vec![1, 2, 3];
+
+    let ans = Select::new("Question", options)
+        .with_disabled_option(1, "out of stock")
+        .with_skip_disabled(false)
+        .prompt_with_backend(&mut backend)
+        .unwrap();
+
+    assert_eq!(ListOption::new(2, 3), ans);
+}
+
+#[test]
+fn invalid_disabled_options_are_rejected() {
+    let out_of_bounds = Select::new("Question", vec![1, 2, 3])
+        .with_disabled_options(&[3])
+        .prompt_with_backend(&mut fake_backend(vec![Key::Enter]));
+
+    let all_disabled = Select::new("Question", vec![1, 2, 3])
+        .with_disabled_options(&[0, 1, 2])
+        .prompt_with_backend(&mut fake_backend(vec![Key::Enter]));
+
+    assert!(matches!(
+        out_of_bounds,
+        Err(InquireError::InvalidConfiguration(_))
+    ));
+    assert!(matches!(
+        all_disabled,
+        Err(InquireError::InvalidConfiguration(_))
+    ));
+}
+
+#[test]
+fn descriptions_are_searched_when_filtering() {
+    let mut keys = Key::char_keys_from_str("ireland");
+    keys.push(Key::Enter);
+    let mut backend = fake_backend(keys);
+
+    let options = vec!["us-east-1", "eu-west-1", "ap-south-1"];
+
+    let ans = Select::new("Region", options)
+        .with_descriptions(&["N. Virginia", "Ireland", "Mumbai"])
+        .prompt_with_backend(&mut backend)
+        .unwrap();
+
+    assert_eq!(ListOption::new(1, "eu-west-1"), ans);
+}
+
+#[test]
+fn icons_are_not_searched_when_filtering() {
+    let mut backend = fake_backend(vec![Key::Char('c', KeyModifiers::NONE), Key::Enter]);
+
+    let ans = Select::new("Animal", vec!["zebra", "cat"])
+        .with_icon(0, Styled::new("c"))
+        .prompt_with_backend(&mut backend)
+        .unwrap();
+
+    assert_eq!(ListOption::new(1, "cat"), ans);
+}
+
+#[test]
+fn out_of_bounds_icon_is_rejected() {
+    let ans = Select::new("Question", vec![1, 2, 3])
+        .with_icon(3, Styled::new("*"))
+        .prompt_with_backend(&mut fake_backend(vec![Key::Enter]));
+
+    assert!(matches!(ans, Err(InquireError::InvalidConfiguration(_))));
+}
+
+#[test]
+fn out_of_bounds_description_is_rejected() {
+    let ans = Select::new("Question", vec![1, 2, 3])
+        .with_description(3, "Four")
+        .prompt_with_backend(&mut fake_backend(vec![Key::Enter]));
+
+    assert!(matches!(ans, Err(InquireError::InvalidConfiguration(_))));
+}
+
+#[test]
+fn grouped_options_are_listed_by_group() {
+    let mut backend = fake_backend(vec![Key::Down(KeyModifiers::NONE), Key::Enter]);
+
+    let options = vec!["a", "b", "c", "d"];
+
+    let ans = Select::new("Question", options)
+        .with_group("Recent", &[3, 1])
+        .with_group("All", &[0, 2])
+        .prompt_with_backend(&mut backend)
+        .unwrap();
+
+    assert_eq!(ListOption::new(1, "b"), ans);
+}
+
+#[test]
+fn groups_are_kept_when_filtering() {
+    let mut keys = Key::char_keys_from_str("west");
+    keys.push(Key::Enter);
+    let mut backend = fake_backend(keys);
+
+    let options = vec!["us-east-1", "us-west-2", "eu-west-1"];
+
+    let ans = Select::new("Region", options)
+        .with_group("Recent", &[2])
+        .with_group("All regions", &[0, 1])
+        .prompt_with_backend(&mut backend)
+        .unwrap();
+
+    assert_eq!(ListOption::new(2, "eu-west-1"), ans);
+}
+
+#[test]
+fn option_in_more_than_one_group_is_rejected() {
+    let ans = Select::new("Question", vec![1, 2, 3])
+        .with_group("Recent", &[0])
+        .with_group("All", &[0, 1, 2])
+        .prompt_with_backend(&mut fake_backend(vec![Key::Enter]));
+
+    assert!(matches!(ans, Err(InquireError::InvalidConfiguration(_))));
+}
+
+#[test]
+fn previewer_is_called_with_highlighted_option() {
+    let mut backend = fake_backend(vec![Key::Down(KeyModifiers::NONE), Key::Enter]);
+
+    let previewed = RefCell::new(vec![]);
+    let previewer = |option: &&str| {
+        previewed.borrow_mut().push(String::from(*option));
+        format!("contents of {option}")
+    };
+
+    let options = vec!["Cargo.toml", "README.md"];
+
+    let ans = Select::new("File", options)
+        .with_previewer(&previewer)
+        .prompt_with_backend(&mut backend)
+        .unwrap();
+
+    assert_eq!(ListOption::new(1, "README.md"), ans);
+    assert_eq!("Cargo.toml", previewed.borrow()[0]);
+    assert_eq!(
+        Some("README.md"),
+        previewed.borrow().last().map(String::as_str)
+    );
+}
+
+#[test]
+fn loaded_options_are_appended_to_the_list() {
+    let mut backend = fake_backend(vec![
+        Key::Down(KeyModifiers::NONE),
+        Key::Down(KeyModifiers::NONE),
+        Key::Enter,
+    ]);
+
+    let (sender, receiver) = mpsc::channel();
+    sender.send("c").unwrap();
+    sender.send("d").unwrap();
+    drop(sender);
+
+    let ans = Select::new("Question", vec!["a", "b"])
+        .with_loader(OptionLoader::new(receiver))
+        .prompt_with_backend(&mut backend)
+        .unwrap();
+
+    assert_eq!(ListOption::new(2, "c"), ans);
+}
+
+#[test]
+fn empty_options_are_allowed_with_loader() {
+    let mut backend = fake_backend(vec![Key::Down(KeyModifiers::NONE), Key::Enter]);
+
+    let (sender, receiver) = mpsc::channel();
+    sender.send(1).unwrap();
+    sender.send(2).unwrap();
+
+    let ans = Select::new("Question", vec![])
+        .with_loader(receiver.into())
+        .prompt_with_backend(&mut backend)
+        .unwrap();
+
+    assert_eq!(ListOption::new(1, 2), ans);
+}
+
+#[test]
+fn options_with_same_score_keep_their_order() {
+    let mut backend = fake_backend(vec![
+        Key::Char('a', KeyModifiers::NONE),
+        Key::Down(KeyModifiers::NONE),
+        Key::Down(KeyModifiers::NONE),
+        Key::Enter,
+    ]);
+
+    // longer options rank higher, ties being kept in the original order
+    let scorer: Scorer<'_, &str> = &|input, _option, string_value, _idx| {
+        string_value
+            .contains(input)
+            .then_some(string_value.len() as i64)
+    };
+
+    let options = vec!["ab", "abc", "xa", "bar", "a"];
+
+    let ans = Select::new("Question", options)
+        .with_scorer(scorer)
+        .prompt_with_backend(&mut backend)
+        .unwrap();
+
+    assert_eq!(ListOption::new(0, "ab"), ans);
+}
+
+#[test]
+fn numeric_shortcut_jumps_to_option() {
+    let mut backend = fake_backend(vec![
+        Key::Char('3', KeyModifiers::NONE),
+        Key::Up(KeyModifiers::NONE),
+        Key::Enter,
+    ]);
+
+    let ans = Select::new("Question", vec!["a", "b", "c", "d"])
+        .with_numeric_shortcuts(NumericShortcuts::Jump)
+        .prompt_with_backend(&mut backend)
+        .unwrap();
+
+    assert_eq!(ListOption::new(1, "b"), ans);
+}
+
+#[test]
+fn numeric_shortcut_submits_option_of_current_page() {
+    let mut backend = fake_backend(vec![
+        Key::End,
+        Key::Char('9', KeyModifiers::NONE),
+        Key::Char('2', KeyModifiers::NONE),
+    ]);
+
+    let options = (0..10).collect();
+
+    let ans = Select::new("Question", options)
+        .with_page_size(3)
+        .with_numeric_shortcuts(NumericShortcuts::Submit)
+        .prompt_with_backend(&mut backend)
+        .unwrap();
+
+    assert_eq!(ListOption::new(8, 8), ans);
+}
+
+#[test]
+fn typing_jumps_to_next_option_starting_with_letter() {
+    let mut backend = fake_backend(vec![
+        Key::Char('b', KeyModifiers::NONE),
+        Key::Char('B', KeyModifiers::NONE),
+        Key::Char('b', KeyModifiers::NONE),
+        Key::Char('z', KeyModifiers::NONE),
+        Key::Enter,
+    ]);
+
+    let options = vec!["Argentina", "Belgium", "Brazil", "Chile", "Bolivia"];
+
+    let ans = Select::new("Country", options)
+        .with_jump_to_letter(true)
+        .prompt_with_backend(&mut backend)
+        .unwrap();
+
+    assert_eq!(ListOption::new(4, "Bolivia"), ans);
+}
+
+#[test]
+fn vim_keymap_moves_cursor_without_filtering() {
+    let mut backend = fake_backend(vec![
+        Key::Char('j', KeyModifiers::NONE),
+        Key::Char('j', KeyModifiers::NONE),
+        Key::Char('k', KeyModifiers::NONE),
+        Key::Char('G', KeyModifiers::SHIFT),
+        Key::Char('g', KeyModifiers::NONE),
+        Key::Char('x', KeyModifiers::NONE),
+        Key::Char('d', KeyModifiers::CONTROL),
+        Key::Enter,
+    ]);
+
+    let options = (0..10).collect();
+
+    let ans = Select::new("Question", options)
+        .with_page_size(3)
+        .with_keymap(ListKeymap::Vim)
+        .prompt_with_backend(&mut backend)
+        .unwrap();
+
+    assert_eq!(ListOption::new(3, 3), ans);
+}
+
+#[test]
+fn vim_keymap_types_into_focused_filter() {
+    let mut backend = fake_backend(vec![
+        Key::Char('/', KeyModifiers::NONE),
+        Key::Char('j', KeyModifiers::NONE),
+        Key::Escape,
+        Key::Char('j', KeyModifiers::NONE),
+        Key::Enter,
+    ]);
+
+    let options = vec!["Germany", "Japan", "Jamaica", "Brazil"];
+
+    let ans = Select::new("Country", options)
+        .with_keymap(ListKeymap::Vim)
+        .prompt_with_backend(&mut backend)
+        .unwrap();
+
+    assert_eq!(ListOption::new(2, "Jamaica"), ans);
+}
+
+#[test]
+fn starting_selection_is_set_by_value() {
+    let options = vec!["Argentina", "Brazil", "Chile"];
+
+    let ans = Select::new("Country", options.clone())
+        .with_starting_selection(&"Brazil")
+        .prompt_with_backend(&mut fake_backend(vec![Key::Enter]))
+        .unwrap();
+    assert_eq!(ListOption::new(1, "Brazil"), ans);
+
+    let ans = Select::new("Country", options)
+        .with_starting_selection_by(|country| country.starts_with('C'))
+        .prompt_with_backend(&mut fake_backend(vec![Key::Enter]))
+        .unwrap();
+    assert_eq!(ListOption::new(2, "Chile"), ans);
+}
+
+#[test]
+fn separators_are_skipped_by_the_cursor() {
+    let mut backend = fake_backend(vec![Key::Down(KeyModifiers::NONE), Key::Enter]);
+
+    let ans = Select::new("Question", vec!["a", "b", "c"])
+        .with_separator(1)
+        .prompt_with_backend(&mut backend)
+        .unwrap();
+
+    assert_eq!(ListOption::new(1, "b"), ans);
+
+    let out_of_bounds = Select::new("Question", vec!["a", "b", "c"])
+        .with_separator(3)
+        .prompt_with_backend(&mut fake_backend(vec![Key::Enter]));
+
+    assert!(matches!(
+        out_of_bounds,
+        Err(InquireError::InvalidConfiguration(_))
+    ));
+}
+
+#[test]
+fn cursor_stops_at_the_ends_without_wrap_around() {
+    let mut backend = fake_backend(vec![
+        Key::Up(KeyModifiers::NONE),
+        Key::Down(KeyModifiers::NONE),
+        Key::Enter,
+    ]);
+
+    let ans = Select::new("Question", vec!["a", "b", "c"])
+        .with_wrap_around(false)
+        .prompt_with_backend(&mut backend)
+        .unwrap();
+
+    assert_eq!(ListOption::new(1, "b"), ans);
+}
+
+#[test]
+fn keywords_are_searched_when_filtering() {
+    let mut backend = fake_backend(vec![
+        Key::Char('e', KeyModifiers::NONE),
+        Key::Char('u', KeyModifiers::NONE),
+        Key::Char('-', KeyModifiers::NONE),
+        Key::Enter,
+    ]);
+
+    let ans = Select::new("Region", vec!["N. Virginia", "Ireland", "Mumbai"])
+        .with_keywords(0, &["us-east-1"])
+        .with_keywords(1, &["eu-west-1", "dublin"])
+        .prompt_with_backend(&mut backend)
+        .unwrap();
+
+    assert_eq!(ListOption::new(1, "Ireland"), ans);
+}
+
+#[test]
+/// Esc clears the filter before cancelling the prompt
+fn escape_clears_filter_before_cancelling() {
+    let options = vec!["Banana", "Apple", "Cherry"];
+
+    let mut backend = fake_backend(vec![
+        Key::Char('c', KeyModifiers::NONE),
+        Key::Escape,
+        Key::Enter,
+    ]);
+
+    let ans = Select::new("Question", options)
+        .prompt_with_backend(&mut backend)
+        .unwrap();
+
+    assert_eq!(ListOption::new(0, "Banana"), ans);
+}
+
+#[derive(Clone)]
+struct SharedHistory(Rc<RefCell<Vec<String>>>);
+
+impl History for SharedHistory {
+    fn entries(&self) -> Vec<String> {
+        self.0.borrow().clone()
+    }
+
+    fn record(&mut self, entry: &str) {
+        self.0.borrow_mut().insert(0, entry.to_string());
+    }
+}
+
+#[test]
+/// Recently selected options are listed first, and the answer is recorded
+fn recently_selected_options_are_listed_first() {
+    let entries = Rc::new(RefCell::new(vec![
+        String::from("Cherry"),
+        String::from("Apple"),
+    ]));
+    let options = vec!["Banana", "Apple", "Cherry", "Durian"];
+
+    let mut backend = fake_backend(vec![Key::Down(KeyModifiers::NONE), Key::Enter]);
+
+    let ans = Select::new("Question", options)
+        .with_history(SharedHistory(Rc::clone(&entries)))
+        .prompt_with_backend(&mut backend)
+        .unwrap();
+
+    assert_eq!(ListOption::new(1, "Apple"), ans);
+    assert_eq!(vec!["Apple", "Cherry", "Apple"], *entries.borrow());
+}
+
+#[test]
+fn starting_selection_follows_reordered_options() {
+    let ans = Select::new("Question", vec!["Other A", "Recent A", "Other B"])
+        .with_group("Recent", &[1])
+        .with_group("Other", &[0, 2])
+        .with_starting_selection(&"Recent A")
+        .prompt_with_backend(&mut fake_backend(vec![Key::Enter]))
+        .unwrap();
+    assert_eq!(ListOption::new(1, "Recent A"), ans);
+
+    let entries = Rc::new(RefCell::new(vec![String::from("c")]));
+    let ans = Select::new("Question", vec!["a", "b", "c"])
+        .with_history(SharedHistory(entries))
+        .with_starting_selection(&"a")
+        .prompt_with_backend(&mut fake_backend(vec![Key::Enter]))
+        .unwrap();
+    assert_eq!(ListOption::new(0, "a"), ans);
+}
+
+#[test]
+/// Clicking an option highlights it, and double-clicking it submits it
+fn mouse_clicks_highlight_and_submit() {
+    let options = vec!["Banana", "Apple", "Cherry"];
+    let click = |kind, option| {
+        Key::Mouse(MouseEvent {
+            option: Some(option),
+            ..MouseEvent::new(kind, 0, 0)
+        })
+    };
+
+    let mut backend = fake_backend(vec![
+        click(MouseEventKind::Click, 2),
+        Key::Mouse(MouseEvent::new(MouseEventKind::ScrollUp, 0, 0)),
+        click(MouseEventKind::Click, 1),
+        click(MouseEventKind::DoubleClick, 1),
+    ]);
+
+    let ans = Select::new("Question", options)
+        .prompt_with_backend(&mut backend)
+        .unwrap();
+
+    assert_eq!(ListOption::new(1, "Apple"), ans);
+}
+
+#[test]
+#[cfg(feature = "mouse")]
+/// The mouse is only captured while the prompt is open
+fn mouse_is_captured_while_prompt_is_open() {
+    let mut backend = fake_backend(vec![Key::Enter]);
+
+    Select::new("Question", vec!["a", "b"])
+        .prompt_with_backend(&mut backend)
+        .unwrap();
+
+    let output = String::from_utf8(backend.terminal_mut().get_buffer_content()).unwrap();
+    let enabled = output
+        .find("\x1b[?1000h")
+        .expect("mouse capture not enabled");
+    let disabled = output
+        .rfind("\x1b[?1000l")
+        .expect("mouse capture not disabled");
+    assert!(enabled < disabled);
+}
+
+#[test]
+/// Ctrl+s cycles the custom sort orders, keeping the cursor on the
+/// highlighted option
+fn ctrl_s_cycles_sort_orders() {
+    let options = vec!["Banana", "Durian", "Apple", "Cherry"];
+    let cycle = Key::Char('s', KeyModifiers::CONTROL);
+    let select = || {
+        Select::new("Question", options.clone())
+            .with_sort_order("A-Z", &|a, b| a.cmp(b))
+            .with_sort_order("Z-A", &|a, b| b.cmp(a))
+    };
+
+    let mut backend = fake_backend(vec![cycle, Key::Down(KeyModifiers::NONE), Key::Enter]);
+    let ans = select().prompt_with_backend(&mut backend).unwrap();
+    assert_eq!(ListOption::new(3, "Cherry"), ans);
+
+    let mut backend = fake_backend(vec![cycle, cycle, Key::Home, Key::Enter]);
+    let ans = select().prompt_with_backend(&mut backend).unwrap();
+    assert_eq!(ListOption::new(1, "Durian"), ans);
+
+    let mut backend = fake_backend(vec![cycle, cycle, cycle, Key::Home, Key::Enter]);
+    let ans = select().prompt_with_backend(&mut backend).unwrap();
+    assert_eq!(ListOption::new(0, "Banana"), ans);
+}
+
+#[test]
+fn auto_page_size_leaves_room_for_the_lines_listed_with_the_options() {
+    use crate::ui::OptionDetails;
+
+    let choices = ListOption::from_list(vec!["a", "b", "c", "d", "e", "f"]);
+    let mut details = OptionDetails::default();
+
+    assert_eq!(6, details.fit_page_size(6, &choices, Some(0)));
+
+    details
+        .groups
+        .extend([(0, "First"), (1, "First"), (2, "Second")]);
+    details.descriptions.insert(1, "described");
+    details.separators.insert(3);
+
+    // two headers and a description listed with the first three options
+    assert_eq!(3, details.fit_page_size(6, &choices, Some(0)));
+    assert_eq!(1, details.fit_page_size(1, &choices, Some(1)));
+}
+
+#[test]
+/// With the options laid out in columns, the left and right arrows move
+/// across the columns instead of the cursor of the filter input
+fn arrows_move_across_columns() {
+    let right = Key::Right(KeyModifiers::NONE);
+    let left = Key::Left(KeyModifiers::NONE);
+    let mut backend = fake_backend(vec![right, right, left, right, Key::Enter]);
+
+    let ans = Select::new("Question", vec!["a", "b", "c", "d"])
+        .with_columns(true)
+        .prompt_with_backend(&mut backend)
+        .unwrap();
+
+    assert_eq!(ListOption::new(2, "c"), ans);
+}
+
+#[test]
+fn columns_are_fitted_to_the_width() {
+    use crate::ui::OptionDetails;
+
+    let choices = ListOption::from_list(vec!["one", "two", "three", "four", "five"]);
+    let mut details = OptionDetails::default();
+
+    // the scroll indicator and a margin, then 8 columns per cell
+    assert_eq!(3, details.fit_columns(26, &choices));
+    assert_eq!(5, details.fit_columns(200, &choices));
+    assert_eq!(1, details.fit_columns(4, &choices));
+
+    details.icons.insert(2, Styled::new("*"));
+    assert_eq!(2, details.fit_columns(26, &choices));
+}
@@ -0,0 +1,53 @@
+use crate::{
+    ui::{Key, KeyModifiers},
+    InnerAction,
+};
+
+use super::config::SliderConfig;
+
+/// Set of actions for a SliderPrompt.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum SliderPromptAction {
+    /// Decrements the current value by one step.
+    Decrement,
+    /// Increments the current value by one step.
+    Increment,
+    /// Decrements the current value by a page, i.e. a tenth of the slider.
+    DecrementPage,
+    /// Increments the current value by a page, i.e. a tenth of the slider.
+    IncrementPage,
+    /// Moves the handle to the minimum value.
+    MoveToMin,
+    /// Moves the handle to the maximum value.
+    MoveToMax,
+}
+
+impl InnerAction for SliderPromptAction {
+    type Config = SliderConfig;
+
+    fn from_key(key: Key, _config: &SliderConfig) -> Option<Self> {
+        let action = match key {
+            Key::Left(KeyModifiers::NONE) // standard
+            | Key::Down(KeyModifiers::NONE)
+            | Key::Char('b', KeyModifiers::CONTROL) // emacs
+            | Key::Char('h', KeyModifiers::NONE) // vim
+            => Self::Decrement,
+
+            Key::Right(KeyModifiers::NONE) // standard
+            | Key::Up(KeyModifiers::NONE)
+            | Key::Char('f', KeyModifiers::CONTROL) // emacs
+            | Key::Char('l', KeyModifiers::NONE) // vim
+            => Self::Increment,
+
+            Key::PageDown(_) | Key::Left(_) => Self::DecrementPage,
+            Key::PageUp(_) | Key::Right(_) => Self::IncrementPage,
+
+            Key::Home => Self::MoveToMin,
+            Key::End => Self::MoveToMax,
+
+            _ => return None,
+        };
+
+        Some(action)
+    }
+}
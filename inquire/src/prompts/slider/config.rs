@@ -0,0 +1,14 @@
+use crate::Slider;
+
+/// Configuration settings used in the execution of a SliderPrompt.
+#[derive(Copy, Clone, Debug)]
+pub struct SliderConfig {
+    /// Amount of cells used to render the bar of the slider.
+    pub width: usize,
+}
+
+impl<T> From<&Slider<'_, T>> for SliderConfig {
+    fn from(value: &Slider<'_, T>) -> Self {
+        Self { width: value.width }
+    }
+}
@@ -0,0 +1,229 @@
+mod action;
+mod config;
+mod prompt;
+#[cfg(test)]
+mod test;
+
+pub use action::*;
+
+use crate::{
+    config::get_configuration,
+    error::{InquireError, InquireResult},
+    formatter::CustomTypeFormatter,
+    prompts::prompt::Prompt,
+    terminal::get_default_terminal,
+    ui::{Backend, RenderConfig, SliderBackend},
+    validator::CustomTypeValidator,
+    Steppable,
+};
+
+use self::prompt::SliderPrompt;
+
+/// Prompt that allows the user to pick a value from a bounded numeric range by moving the handle of a horizontal bar.
+///
+/// It is suitable for settings such as volume, brightness or quality levels, where the range is known beforehand and a [`Select`](crate::Select) prompt with one option per value would be unwieldy.
+///
+/// The user moves the handle with the Left and Right arrows, by one step at a time. `PageUp` and `PageDown`, as well as the arrows pressed with a modifier, move the handle by ten steps at a time, while `Home` and `End` move it to the minimum and maximum values.
+///
+/// Slider prompts provide several options of configuration:
+///
+/// - **Prompt message**: Required when creating the prompt.
+/// - **Min and max values**: Inclusive bounds of the slider, required when creating the prompt.
+/// - **Starting value**: Value selected when the prompt is displayed. Defaults to the minimum value.
+/// - **Step**: Amount added or subtracted on each movement of the handle. Defaults to `1`.
+/// - **Width**: Amount of cells used to render the bar. Defaults to `20`.
+/// - **Help message**: Message displayed at the line below the prompt.
+/// - **Formatter**: Custom formatter for the value displayed next to the bar and as the final answer, e.g. to display percentages.
+/// - **Validators**: Custom validators to the selected value, displaying an error message if the value does not pass the requirements.
+///
+/// # Example
+///
+/// ```no_run
+/// use inquire::Slider;
+///
+/// let volume = Slider::new("Volume:", 0, 100)
+///     .with_starting_value(50)
+///     .with_step(5)
+///     .with_formatter(&|v| format!("{}%", v))
+///     .prompt();
+///
+/// match volume {
+///     Ok(volume) => println!("Volume set to {}%", volume),
+///     Err(_) => println!("Could not read the volume"),
+/// }
+/// ```
+#[derive(Clone)]
+pub struct Slider<'a, T> {
+    /// Message to be presented to the user.
+    pub message: &'a str,
+
+    /// Minimum value (inclusive) of the slider.
+    pub min: T,
+
+    /// Maximum value (inclusive) of the slider.
+    pub max: T,
+
+    /// Value selected when the prompt is displayed, the minimum value if not set.
+    pub starting_value: Option<T>,
+
+    /// Amount added or subtracted from the current value on each movement of the handle.
+    pub step: T,
+
+    /// Amount of cells used to render the bar of the slider.
+    pub width: usize,
+
+    /// Help message to be presented to the user.
+    pub help_message: Option<&'a str>,
+
+    /// Function that formats the current value, displayed next to the bar
+    /// and as the final rendering of the prompt.
+    pub formatter: CustomTypeFormatter<'a, T>,
+
+    /// Collection of validators to apply to the selected value.
+    ///
+    /// Validators are executed in the order they are stored, stopping at and displaying to the user
+    /// only the first validation error that might appear.
+    ///
+    /// The possible error is displayed to the user one line above the prompt.
+    pub validators: Vec<Box<dyn CustomTypeValidator<T>>>,
+
+    /// RenderConfig to apply to the rendered interface.
+    ///
+    /// Note: The default render config considers if the NO_COLOR environment variable
+    /// is set to decide whether to render the colored config or the empty one.
+    ///
+    /// When overriding the config in a prompt, NO_COLOR is no longer considered and your
+    /// config is treated as the only source of truth. If you want to customize colors
+    /// and still support NO_COLOR, you will have to do this on your end.
+    pub render_config: RenderConfig<'a>,
+}
+
+impl<'a, T> Slider<'a, T>
+where
+    T: Steppable + ToString,
+{
+    /// Default width of the bar, in cells.
+    pub const DEFAULT_WIDTH: usize = 20;
+
+    /// Default validators added to the [Slider] prompt, none.
+    pub const DEFAULT_VALIDATORS: Vec<Box<dyn CustomTypeValidator<T>>> = vec![];
+
+    /// Creates a [Slider] with the provided message and bounds, along with default configuration values.
+    pub fn new(message: &'a str, min: T, max: T) -> Self {
+        Self {
+            message,
+            min,
+            max,
+            starting_value: None,
+            step: T::DEFAULT_STEP,
+            width: Self::DEFAULT_WIDTH,
+            help_message: None,
+            formatter: &|val| val.to_string(),
+            validators: Self::DEFAULT_VALIDATORS,
+            render_config: get_configuration(),
+        }
+    }
+
+    /// Sets the value selected when the prompt is displayed.
+    pub fn with_starting_value(mut self, starting_value: T) -> Self {
+        self.starting_value = Some(starting_value);
+        self
+    }
+
+    /// Sets the step used when moving the handle.
+    pub fn with_step(mut self, step: T) -> Self {
+        self.step = step;
+        self
+    }
+
+    /// Sets the amount of cells used to render the bar.
+    pub fn with_width(mut self, width: usize) -> Self {
+        self.width = width;
+        self
+    }
+
+    /// Sets the help message of the prompt.
+    pub fn with_help_message(mut self, message: &'a str) -> Self {
+        self.help_message = Some(message);
+        self
+    }
+
+    /// Sets the formatter.
+    pub fn with_formatter(mut self, formatter: CustomTypeFormatter<'a, T>) -> Self {
+        self.formatter = formatter;
+        self
+    }
+
+    /// Adds a validator to the collection of validators. You might want to use this feature
+    /// in case you need to require certain features from the selected value.
+    ///
+    /// Validators are executed in the order they are stored, stopping at and displaying to the user
+    /// only the first validation error that might appear.
+    ///
+    /// The possible error is displayed to the user one line above the prompt.
+    pub fn with_validator<V>(mut self, validator: V) -> Self
+    where
+        V: CustomTypeValidator<T> + 'static,
+    {
+        self.validators.push(Box::new(validator));
+        self
+    }
+
+    /// Adds the validators to the collection of validators in the order they are given.
+    /// You might want to use this feature in case you need to require certain features
+    /// from the selected value.
+    ///
+    /// Validators are executed in the order they are stored, stopping at and displaying to the user
+    /// only the first validation error that might appear.
+    ///
+    /// The possible error is displayed to the user one line above the prompt.
+    pub fn with_validators(mut self, validators: &[Box<dyn CustomTypeValidator<T>>]) -> Self {
+        for validator in validators {
+            #[allow(suspicious_double_ref_op)]
+            self.validators.push(validator.clone());
+        }
+        self
+    }
+
+    /// Sets the provided color theme to this prompt.
+    ///
+    /// Note: The default render config considers if the NO_COLOR environment variable
+    /// is set to decide whether to render the colored config or the empty one.
+    ///
+    /// When overriding the config in a prompt, NO_COLOR is no longer considered and your
+    /// config is treated as the only source of truth. If you want to customize colors
+    /// and still support NO_COLOR, you will have to do this on your end.
+    pub fn with_render_config(mut self, render_config: RenderConfig<'a>) -> Self {
+        self.render_config = render_config;
+        self
+    }
+
+    /// Parses the provided behavioral and rendering options and prompts
+    /// the CLI user for input according to the defined rules.
+    ///
+    /// This method is intended for flows where the user skipping/cancelling
+    /// the prompt - by pressing ESC - is considered normal behavior. In this case,
+    /// it does not return `Err(InquireError::OperationCanceled)`, but `Ok(None)`.
+    ///
+    /// Meanwhile, if the user does submit an answer, the method wraps the return
+    /// type with `Some`.
+    pub fn prompt_skippable(self) -> InquireResult<Option<T>> {
+        match self.prompt() {
+            Ok(answer) => Ok(Some(answer)),
+            Err(InquireError::OperationCanceled) => Ok(None),
+            Err(err) => Err(err),
+        }
+    }
+
+    /// Parses the provided behavioral and rendering options and prompts
+    /// the CLI user for input according to the defined rules.
+    pub fn prompt(self) -> InquireResult<T> {
+        let (input_reader, terminal) = get_default_terminal()?;
+        let mut backend = Backend::new(input_reader, terminal, self.render_config)?;
+        self.prompt_with_backend(&mut backend)
+    }
+
+    pub(crate) fn prompt_with_backend<B: SliderBackend>(self, backend: &mut B) -> InquireResult<T> {
+        SliderPrompt::new(self)?.prompt(backend)
+    }
+}
@@ -0,0 +1,190 @@
+use std::cmp::Ordering;
+
+use crate::{
+    error::InquireResult,
+    formatter::CustomTypeFormatter,
+    prompts::prompt::{ActionResult, Prompt},
+    ui::SliderBackend,
+    validator::{CustomTypeValidator, ErrorMessage, Validation},
+    InquireError, Slider, Steppable,
+};
+
+use super::{action::SliderPromptAction, config::SliderConfig};
+
+/// Amount of steps the handle moves on page movements.
+const PAGE_STEPS: usize = 10;
+
+pub struct SliderPrompt<'a, T> {
+    message: &'a str,
+    config: SliderConfig,
+    value: T,
+    min: T,
+    max: T,
+    step: T,
+    help_message: Option<&'a str>,
+    formatter: CustomTypeFormatter<'a, T>,
+    validators: Vec<Box<dyn CustomTypeValidator<T>>>,
+    error: Option<ErrorMessage>,
+}
+
+impl<'a, T> SliderPrompt<'a, T>
+where
+    T: Steppable,
+{
+    pub fn new(so: Slider<'a, T>) -> InquireResult<Self> {
+        if so.min.partial_cmp(&so.max) != Some(Ordering::Less) {
+            return Err(InquireError::InvalidConfiguration(
+                "Min value must be less than max value".into(),
+            ));
+        }
+
+        if so.step.partial_cmp(&T::ZERO) != Some(Ordering::Greater) {
+            return Err(InquireError::InvalidConfiguration(
+                "Step must be greater than zero".into(),
+            ));
+        }
+
+        if so.width < 2 {
+            return Err(InquireError::InvalidConfiguration(
+                "Slider width must be at least 2".into(),
+            ));
+        }
+
+        let value = so.starting_value.unwrap_or(so.min);
+        if value < so.min || value > so.max {
+            return Err(InquireError::InvalidConfiguration(
+                "Starting value must be within the min and max bounds".into(),
+            ));
+        }
+
+        Ok(Self {
+            message: so.message,
+            config: (&so).into(),
+            value,
+            min: so.min,
+            max: so.max,
+            step: so.step,
+            help_message: so.help_message,
+            formatter: so.formatter,
+            validators: so.validators,
+            error: None,
+        })
+    }
+
+    fn move_by(&mut self, steps: usize, increment: bool) -> ActionResult {
+        let mut value = self.value;
+
+        for _ in 0..steps {
+            value = match increment {
+                true => value.step_up(self.step),
+                false => value.step_down(self.step),
+            };
+        }
+
+        self.update_value(value)
+    }
+
+    fn update_value(&mut self, value: T) -> ActionResult {
+        let value = match value {
+            v if v < self.min => self.min,
+            v if v > self.max => self.max,
+            v => v,
+        };
+
+        if value == self.value {
+            return ActionResult::Clean;
+        }
+
+        self.value = value;
+        ActionResult::NeedsRedraw
+    }
+
+    /// Index of the cell where the handle is rendered, from `0` to `width - 1`.
+    fn handle_position(&self) -> usize {
+        let range = self.max.to_f64() - self.min.to_f64();
+        let ratio = (self.value.to_f64() - self.min.to_f64()) / range;
+        let last_cell = (self.config.width - 1) as f64;
+
+        (ratio.clamp(0.0, 1.0) * last_cell).round() as usize
+    }
+
+    fn validate_current_answer(&self) -> InquireResult<Validation> {
+        for validator in &self.validators {
+            match validator.validate(&self.value) {
+                Ok(Validation::Valid) => {}
+                Ok(Validation::Invalid(msg)) => return Ok(Validation::Invalid(msg)),
+                Err(err) => return Err(InquireError::Custom(err)),
+            }
+        }
+
+        Ok(Validation::Valid)
+    }
+}
+
+impl<'a, Backend, T> Prompt<Backend> for SliderPrompt<'a, T>
+where
+    Backend: SliderBackend,
+    T: Steppable,
+{
+    type Config = SliderConfig;
+    type InnerAction = SliderPromptAction;
+    type Output = T;
+
+    fn message(&self) -> &str {
+        self.message
+    }
+
+    fn config(&self) -> &SliderConfig {
+        &self.config
+    }
+
+    fn format_answer(&self, answer: &T) -> String {
+        (self.formatter)(*answer)
+    }
+
+    fn submit(&mut self) -> InquireResult<Option<T>> {
+        let answer = match self.validate_current_answer()? {
+            Validation::Valid => Some(self.value),
+            Validation::Invalid(msg) => {
+                self.error = Some(msg);
+                None
+            }
+        };
+
+        Ok(answer)
+    }
+
+    fn handle(&mut self, action: SliderPromptAction) -> InquireResult<ActionResult> {
+        let result = match action {
+            SliderPromptAction::Decrement => self.move_by(1, false),
+            SliderPromptAction::Increment => self.move_by(1, true),
+            SliderPromptAction::DecrementPage => self.move_by(PAGE_STEPS, false),
+            SliderPromptAction::IncrementPage => self.move_by(PAGE_STEPS, true),
+            SliderPromptAction::MoveToMin => self.update_value(self.min),
+            SliderPromptAction::MoveToMax => self.update_value(self.max),
+        };
+
+        Ok(result)
+    }
+
+    fn render(&self, backend: &mut Backend) -> InquireResult<()> {
+        let prompt = &self.message;
+
+        if let Some(error) = &self.error {
+            backend.render_error_message(error)?;
+        }
+
+        backend.render_slider_prompt(
+            prompt,
+            self.handle_position(),
+            self.config.width,
+            &(self.formatter)(self.value),
+        )?;
+
+        if let Some(message) = self.help_message {
+            backend.render_help_message(message)?;
+        }
+
+        Ok(())
+    }
+}
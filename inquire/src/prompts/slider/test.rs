@@ -0,0 +1,105 @@
+use crate::{
+    error::InquireResult,
+    ui::{
+        test::{FakeBackend, Token},
+        Key, KeyModifiers,
+    },
+    InquireError, Slider,
+};
+
+#[test]
+fn enter_returns_starting_value() -> InquireResult<()> {
+    let mut backend = FakeBackend::new(vec![Key::Enter]);
+
+    let ans = Slider::new("Question", 0, 100)
+        .with_starting_value(40)
+        .prompt_with_backend(&mut backend)?;
+
+    assert_eq!(40, ans);
+
+    Ok(())
+}
+
+#[test]
+fn arrows_move_handle_by_step() -> InquireResult<()> {
+    let mut backend = FakeBackend::new(vec![
+        Key::Right(KeyModifiers::NONE),
+        Key::Right(KeyModifiers::NONE),
+        Key::Left(KeyModifiers::NONE),
+        Key::Enter,
+    ]);
+
+    let ans = Slider::new("Question", 0, 100)
+        .with_step(5)
+        .prompt_with_backend(&mut backend)?;
+
+    assert_eq!(5, ans);
+
+    Ok(())
+}
+
+#[test]
+fn movement_is_clamped_to_bounds() -> InquireResult<()> {
+    let mut backend = FakeBackend::new(vec![
+        Key::PageUp(KeyModifiers::NONE),
+        Key::PageUp(KeyModifiers::NONE),
+        Key::Enter,
+    ]);
+
+    let ans = Slider::new("Question", 0.0, 1.0)
+        .with_step(0.1)
+        .prompt_with_backend(&mut backend)?;
+
+    assert_eq!(1.0, ans);
+
+    Ok(())
+}
+
+#[test]
+fn home_and_end_jump_to_bounds() -> InquireResult<()> {
+    let mut backend = FakeBackend::new(vec![Key::End, Key::Enter]);
+
+    let ans = Slider::new("Question", 10u8, 20u8).prompt_with_backend(&mut backend)?;
+
+    assert_eq!(20, ans);
+
+    let mut backend = FakeBackend::new(vec![Key::Home, Key::Enter]);
+
+    let ans = Slider::new("Question", 10u8, 20u8)
+        .with_starting_value(15)
+        .prompt_with_backend(&mut backend)?;
+
+    assert_eq!(10, ans);
+
+    Ok(())
+}
+
+#[test]
+fn handle_position_and_formatted_value_are_rendered() -> InquireResult<()> {
+    let mut backend = FakeBackend::new(vec![Key::Right(KeyModifiers::NONE), Key::Enter]);
+
+    Slider::new("Question", 0, 100)
+        .with_starting_value(45)
+        .with_step(5)
+        .with_width(11)
+        .with_formatter(&|v| format!("{v}%"))
+        .prompt_with_backend(&mut backend)?;
+
+    assert!(backend.frames()[1].has_token(&Token::Slider {
+        position: 5,
+        width: 11,
+        value: "50%".into(),
+    }));
+    assert!(backend.frames()[2].has_token(&Token::AnsweredPrompt("Question".into(), "50%".into())));
+
+    Ok(())
+}
+
+#[test]
+fn min_not_less_than_max_is_invalid_configuration() {
+    let mut backend = FakeBackend::new(vec![Key::Enter]);
+
+    let ans = Slider::new("Question", 5, 5).prompt_with_backend(&mut backend);
+
+    assert!(matches!(ans, Err(InquireError::InvalidConfiguration(_))));
+}
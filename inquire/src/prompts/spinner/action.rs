@@ -0,0 +1,18 @@
+use crate::{ui::Key, InnerAction};
+
+use super::config::SpinnerConfig;
+
+/// Set of actions for a SpinnerPrompt.
+///
+/// The spinner does not accept any input while the task runs, so there are
+/// no actions other than the interrupt shared by all prompts.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum SpinnerPromptAction {}
+
+impl InnerAction for SpinnerPromptAction {
+    type Config = SpinnerConfig;
+
+    fn from_key(_key: Key, _config: &SpinnerConfig) -> Option<Self> {
+        None
+    }
+}
@@ -0,0 +1,18 @@
+use std::time::Duration;
+
+use crate::Spinner;
+
+/// Configuration settings used in the execution of a SpinnerPrompt.
+#[derive(Copy, Clone, Debug)]
+pub struct SpinnerConfig {
+    /// Time between two frames of the animation.
+    pub interval: Duration,
+}
+
+impl From<&Spinner<'_>> for SpinnerConfig {
+    fn from(value: &Spinner<'_>) -> Self {
+        Self {
+            interval: value.interval,
+        }
+    }
+}
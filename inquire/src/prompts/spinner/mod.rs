@@ -0,0 +1,192 @@
+mod action;
+mod config;
+mod progress;
+mod prompt;
+#[cfg(test)]
+mod test;
+
+pub use action::*;
+pub use progress::*;
+
+use std::{thread, time::Duration};
+
+use crate::{
+    config::get_configuration,
+    error::{InquireError, InquireResult},
+    prompts::prompt::Prompt,
+    terminal::get_default_terminal,
+    ui::{Backend, RenderConfig, SpinnerBackend},
+};
+
+use self::prompt::SpinnerPrompt;
+
+/// Animated spinner displayed while a task runs, e.g. validating credentials between two prompts.
+///
+/// The task is run on a separate thread, while the spinner is rendered by the same backend as the other prompts, so that it doesn't fight with them for the control of the cursor. Once the task finishes, the spinner is replaced by the message followed by the finish message, just like the final answer of a prompt, and the value returned by the task is returned by [`run`](Spinner::run).
+///
+/// The task receives a [`Progress`] handle, which can be used to replace the displayed message and to display a progress bar.
+///
+/// The spinner does not accept any input. As the task can not be stopped, pressing ESC does nothing, while Ctrl-C interrupts the spinner only once the task finishes.
+///
+/// Spinners provide several options of configuration:
+///
+/// - **Message**: Required when creating the spinner.
+/// - **Frames**: Frames of the animation, cycled through while the task runs. Must be **non-empty**.
+/// - **Interval**: Time between two frames of the animation, 80 milliseconds by default.
+/// - **Finish message**: Displayed after the message once the task finishes, `done` by default.
+/// - **Help message**: Message displayed at the line below the spinner.
+///
+/// The animation relies on reading keys with a timeout, which is supported by
+/// the crossterm and termion backends. On the console backend, the spinner
+/// fails with an `InquireError::InvalidConfiguration` error.
+///
+/// # Example
+///
+/// ```no_run
+/// use std::{thread, time::Duration};
+///
+/// use inquire::{Password, Spinner, Text};
+///
+/// let user = Text::new("Username:").prompt()?;
+/// let password = Password::new("Password:").without_confirmation().prompt()?;
+///
+/// let valid = Spinner::new("Validating credentials…").run(|_progress| {
+///     thread::sleep(Duration::from_secs(2));
+///     !user.is_empty() && !password.is_empty()
+/// })?;
+///
+/// println!("Valid: {}", valid);
+/// # Ok::<(), inquire::InquireError>(())
+/// ```
+#[derive(Clone)]
+pub struct Spinner<'a> {
+    /// Message to be presented to the user.
+    pub message: &'a str,
+
+    /// Frames of the animation.
+    pub frames: &'a [&'a str],
+
+    /// Time between two frames of the animation.
+    pub interval: Duration,
+
+    /// Message displayed after the message once the task finishes.
+    pub finish_message: &'a str,
+
+    /// Help message to be presented to the user.
+    pub help_message: Option<&'a str>,
+
+    /// RenderConfig to apply to the rendered interface.
+    ///
+    /// Note: The default render config considers if the NO_COLOR environment variable
+    /// is set to decide whether to render the colored config or the empty one.
+    ///
+    /// When overriding the config in a prompt, NO_COLOR is no longer considered and your
+    /// config is treated as the only source of truth. If you want to customize colors
+    /// and still support NO_COLOR, you will have to do this on your end.
+    pub render_config: RenderConfig<'a>,
+}
+
+impl<'a> Spinner<'a> {
+    /// Default frames of the animation.
+    pub const DEFAULT_FRAMES: &'static [&'static str] =
+        &["⠋", "⠙", "⠹", "⠸", "⠼", "⠴", "⠦", "⠧", "⠇", "⠏"];
+
+    /// Default time between two frames of the animation.
+    pub const DEFAULT_INTERVAL: Duration = Duration::from_millis(80);
+
+    /// Default finish message.
+    pub const DEFAULT_FINISH_MESSAGE: &'static str = "done";
+
+    /// Default help message.
+    pub const DEFAULT_HELP_MESSAGE: Option<&'a str> = None;
+
+    /// Creates a [Spinner] with the provided message, along with default configuration values.
+    pub fn new(message: &'a str) -> Self {
+        Self {
+            message,
+            frames: Self::DEFAULT_FRAMES,
+            interval: Self::DEFAULT_INTERVAL,
+            finish_message: Self::DEFAULT_FINISH_MESSAGE,
+            help_message: Self::DEFAULT_HELP_MESSAGE,
+            render_config: get_configuration(),
+        }
+    }
+
+    /// Sets the frames of the animation.
+    pub fn with_frames(mut self, frames: &'a [&'a str]) -> Self {
+        self.frames = frames;
+        self
+    }
+
+    /// Sets the time between two frames of the animation.
+    pub fn with_interval(mut self, interval: Duration) -> Self {
+        self.interval = interval;
+        self
+    }
+
+    /// Sets the message displayed after the message once the task finishes.
+    pub fn with_finish_message(mut self, message: &'a str) -> Self {
+        self.finish_message = message;
+        self
+    }
+
+    /// Sets the help message of the spinner.
+    pub fn with_help_message(mut self, message: &'a str) -> Self {
+        self.help_message = Some(message);
+        self
+    }
+
+    /// Removes the set help message.
+    pub fn without_help_message(mut self) -> Self {
+        self.help_message = None;
+        self
+    }
+
+    /// Sets the provided color theme to this prompt.
+    ///
+    /// Note: The default render config considers if the NO_COLOR environment variable
+    /// is set to decide whether to render the colored config or the empty one.
+    ///
+    /// When overriding the config in a prompt, NO_COLOR is no longer considered and your
+    /// config is treated as the only source of truth. If you want to customize colors
+    /// and still support NO_COLOR, you will have to do this on your end.
+    pub fn with_render_config(mut self, render_config: RenderConfig<'a>) -> Self {
+        self.render_config = render_config;
+        self
+    }
+
+    /// Runs the task on a separate thread, displaying the spinner until
+    /// it finishes.
+    ///
+    /// Returns the value returned by the task. If the task panics, the
+    /// panic is propagated to the caller.
+    pub fn run<T, F>(self, task: F) -> InquireResult<T>
+    where
+        T: Send,
+        F: FnOnce(&Progress) -> T + Send,
+    {
+        let (input_reader, terminal) = get_default_terminal()?;
+        let mut backend = Backend::new(input_reader, terminal, self.render_config)?;
+        self.run_with_backend(&mut backend, task)
+    }
+
+    pub(crate) fn run_with_backend<B, T, F>(self, backend: &mut B, task: F) -> InquireResult<T>
+    where
+        B: SpinnerBackend,
+        T: Send,
+        F: FnOnce(&Progress) -> T + Send,
+    {
+        if self.frames.is_empty() {
+            return Err(InquireError::InvalidConfiguration(
+                "Spinner frames can not be empty".into(),
+            ));
+        }
+
+        let progress = Progress::default();
+
+        thread::scope(|scope| {
+            let task = scope.spawn(|| task(&progress));
+            SpinnerPrompt::new(&self, &progress, task).prompt(backend)
+        })
+    }
+}
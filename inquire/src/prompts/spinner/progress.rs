@@ -0,0 +1,60 @@
+use std::sync::Mutex;
+
+/// Handle passed to the task run by a [`Spinner`](crate::Spinner), used to
+/// report its progress while the spinner is displayed.
+///
+/// # Example
+///
+/// ```no_run
+/// use inquire::Spinner;
+///
+/// let files = vec!["a.txt", "b.txt", "c.txt"];
+///
+/// Spinner::new("Uploading files").run(|progress| {
+///     for (i, file) in files.iter().enumerate() {
+///         progress.set_message(format!("Uploading {}", file));
+///         progress.set_position(i as u64, files.len() as u64);
+///         // upload the file
+///     }
+/// })?;
+/// # Ok::<(), inquire::InquireError>(())
+/// ```
+#[derive(Debug, Default)]
+pub struct Progress {
+    state: Mutex<ProgressState>,
+}
+
+#[derive(Clone, Debug, Default)]
+pub(super) struct ProgressState {
+    pub message: Option<String>,
+    pub position: Option<(u64, u64)>,
+}
+
+impl Progress {
+    /// Replaces the message displayed next to the spinner.
+    pub fn set_message<S: Into<String>>(&self, message: S) {
+        self.update(|state| state.message = Some(message.into()));
+    }
+
+    /// Sets how many of the total units of work are done, displaying a
+    /// progress bar next to the message.
+    pub fn set_position(&self, done: u64, total: u64) {
+        self.update(|state| state.position = Some((done.min(total), total)));
+    }
+
+    pub(super) fn state(&self) -> ProgressState {
+        match self.state.lock() {
+            Ok(state) => state.clone(),
+            // the state is always left consistent, even if the task
+            // panicked while holding the lock
+            Err(poisoned) => poisoned.into_inner().clone(),
+        }
+    }
+
+    fn update<F: FnOnce(&mut ProgressState)>(&self, f: F) {
+        match self.state.lock() {
+            Ok(mut state) => f(&mut state),
+            Err(poisoned) => f(&mut poisoned.into_inner()),
+        }
+    }
+}
@@ -0,0 +1,121 @@
+use std::{panic, thread::ScopedJoinHandle, time::Duration};
+
+use crate::{
+    error::InquireResult,
+    prompts::prompt::{ActionResult, Prompt},
+    ui::{Key, KeyModifiers, SpinnerBackend},
+    Action, Spinner,
+};
+
+use super::{action::SpinnerPromptAction, config::SpinnerConfig, progress::Progress};
+
+pub struct SpinnerPrompt<'a, 'p, 'scope, T> {
+    message: &'a str,
+    config: SpinnerConfig,
+    frames: &'a [&'a str],
+    frame_index: usize,
+    finish_message: &'a str,
+    help_message: Option<&'a str>,
+    progress: &'p Progress,
+    task: Option<ScopedJoinHandle<'scope, T>>,
+}
+
+impl<'a, 'p, 'scope, T> SpinnerPrompt<'a, 'p, 'scope, T> {
+    pub fn new(
+        so: &Spinner<'a>,
+        progress: &'p Progress,
+        task: ScopedJoinHandle<'scope, T>,
+    ) -> Self {
+        Self {
+            message: so.message,
+            config: so.into(),
+            frames: so.frames,
+            frame_index: 0,
+            finish_message: so.finish_message,
+            help_message: so.help_message,
+            progress,
+            task: Some(task),
+        }
+    }
+}
+
+impl<'a, 'p, 'scope, Backend, T> Prompt<Backend> for SpinnerPrompt<'a, 'p, 'scope, T>
+where
+    Backend: SpinnerBackend,
+{
+    type Config = SpinnerConfig;
+    type InnerAction = SpinnerPromptAction;
+    type Output = T;
+
+    fn message(&self) -> &str {
+        self.message
+    }
+
+    fn config(&self) -> &SpinnerConfig {
+        &self.config
+    }
+
+    fn format_answer(&self, _answer: &T) -> String {
+        self.finish_message.to_string()
+    }
+
+    fn parse_key(&self, key: Key) -> Option<Action<SpinnerPromptAction>> {
+        // the prompt is submitted when the task finishes, and can not be
+        // canceled as the task can not be stopped, so only Ctrl-C is handled
+        match key {
+            Key::Char('c', KeyModifiers::CONTROL) => Some(Action::Interrupt),
+            _ => None,
+        }
+    }
+
+    fn tick_interval(&self) -> Option<Duration> {
+        Some(self.config.interval)
+    }
+
+    fn tick(&mut self) -> InquireResult<Option<Action<SpinnerPromptAction>>> {
+        let finished = self
+            .task
+            .as_ref()
+            .map(ScopedJoinHandle::is_finished)
+            .unwrap_or_default();
+
+        if finished {
+            return Ok(Some(Action::Submit));
+        }
+
+        self.frame_index = (self.frame_index + 1) % self.frames.len();
+
+        Ok(None)
+    }
+
+    fn submit(&mut self) -> InquireResult<Option<T>> {
+        let answer = match self.task.take() {
+            Some(task) => match task.join() {
+                Ok(answer) => Some(answer),
+                Err(payload) => panic::resume_unwind(payload),
+            },
+            None => None,
+        };
+
+        Ok(answer)
+    }
+
+    fn handle(&mut self, action: SpinnerPromptAction) -> InquireResult<ActionResult> {
+        match action {}
+    }
+
+    fn render(&self, backend: &mut Backend) -> InquireResult<()> {
+        let state = self.progress.state();
+
+        let frame = self.frames[self.frame_index];
+        let message = state.message.as_deref().unwrap_or(self.message);
+
+        backend.render_spinner(frame, message, state.position)?;
+
+        if let Some(help_message) = self.help_message {
+            backend.render_help_message(help_message)?;
+        }
+
+        Ok(())
+    }
+}
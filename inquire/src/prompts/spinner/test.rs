@@ -0,0 +1,121 @@
+use std::{thread, time::Duration};
+
+use crate::{
+    error::InquireResult,
+    ui::{
+        test::{FakeBackend, Token},
+        Key, KeyModifiers,
+    },
+    InquireError, Spinner,
+};
+
+fn spinner(message: &str) -> Spinner<'_> {
+    Spinner::new(message).with_interval(Duration::from_millis(5))
+}
+
+#[test]
+fn value_returned_by_the_task_is_returned() -> InquireResult<()> {
+    let mut backend = FakeBackend::new(vec![]);
+
+    let ans = spinner("Validating").run_with_backend(&mut backend, |_| 42)?;
+
+    assert_eq!(42, ans);
+    assert!(backend
+        .frames()
+        .last()
+        .unwrap()
+        .has_token(&Token::AnsweredPrompt("Validating".into(), "done".into())));
+
+    Ok(())
+}
+
+#[test]
+fn frames_are_cycled_while_the_task_runs() -> InquireResult<()> {
+    let mut backend = FakeBackend::new(vec![]);
+
+    spinner("Validating")
+        .with_frames(&["-", "+"])
+        .run_with_backend(&mut backend, |_| thread::sleep(Duration::from_millis(50)))?;
+
+    let frame = |frame: &str| Token::Spinner {
+        frame: frame.into(),
+        message: "Validating".into(),
+        position: None,
+    };
+
+    assert!(backend.frames()[0].has_token(&frame("-")));
+    assert!(backend.frames()[1].has_token(&frame("+")));
+    assert!(backend.frames()[2].has_token(&frame("-")));
+
+    Ok(())
+}
+
+#[test]
+fn task_reports_its_progress() -> InquireResult<()> {
+    let mut backend = FakeBackend::new(vec![]);
+
+    spinner("Uploading")
+        .with_frames(&["-"])
+        .run_with_backend(&mut backend, |progress| {
+            progress.set_message("Uploading a.txt");
+            progress.set_position(1, 2);
+            thread::sleep(Duration::from_millis(50));
+        })?;
+
+    assert!(backend
+        .frames()
+        .iter()
+        .any(|frame| frame.has_token(&Token::Spinner {
+            frame: "-".into(),
+            message: "Uploading a.txt".into(),
+            position: Some((1, 2)),
+        })));
+    assert!(backend
+        .frames()
+        .last()
+        .unwrap()
+        .has_token(&Token::AnsweredPrompt("Uploading".into(), "done".into())));
+
+    Ok(())
+}
+
+#[test]
+fn keys_other_than_ctrl_c_are_ignored() -> InquireResult<()> {
+    let mut backend = FakeBackend::new(vec![Key::Enter, Key::Escape]);
+
+    let ans = spinner("Validating").run_with_backend(&mut backend, |_| {
+        thread::sleep(Duration::from_millis(20));
+        "ok"
+    })?;
+
+    assert_eq!("ok", ans);
+
+    let mut backend = FakeBackend::new(vec![Key::Char('c', KeyModifiers::CONTROL)]);
+
+    let ans = spinner("Validating").run_with_backend(&mut backend, |_| "ok");
+
+    assert!(matches!(ans, Err(InquireError::OperationInterrupted)));
+
+    Ok(())
+}
+
+#[test]
+fn empty_frames_are_rejected() {
+    let mut backend = FakeBackend::new(vec![]);
+
+    let ans = spinner("Validating")
+        .with_frames(&[])
+        .run_with_backend(&mut backend, |_| ());
+
+    assert!(matches!(ans, Err(InquireError::InvalidConfiguration(_))));
+}
+
+#[test]
+fn backends_without_timeouts_are_rejected() {
+    let mut backend = FakeBackend::new(vec![]);
+    backend.supports_timeout = false;
+
+    let ans = spinner("Validating").run_with_backend(&mut backend, |_| 42);
+
+    assert!(matches!(ans, Err(InquireError::InvalidConfiguration(_))));
+}
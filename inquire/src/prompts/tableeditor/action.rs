@@ -0,0 +1,50 @@
+use crate::{
+    ui::{Key, KeyModifiers},
+    InnerAction, InputAction,
+};
+
+use super::config::TableEditorConfig;
+
+/// Set of actions for a TableEditorPrompt.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum TableEditorPromptAction {
+    /// Action on the text input handler of the focused cell.
+    ValueInput(InputAction),
+    /// Moves the focus to the cell above.
+    MoveUp,
+    /// Moves the focus to the cell below.
+    MoveDown,
+    /// Moves the focus to the next cell, crossing to the next row at the
+    /// end of a row and adding a new one after the last cell.
+    NextCell,
+    /// Moves the focus to the previous cell, crossing to the previous row
+    /// at the start of a row.
+    PrevCell,
+    /// Adds an empty row below the focused one.
+    AddRow,
+    /// Removes the focused row.
+    RemoveRow,
+}
+
+impl InnerAction for TableEditorPromptAction {
+    type Config = TableEditorConfig;
+
+    fn from_key(key: Key, _config: &TableEditorConfig) -> Option<Self> {
+        let action = match key {
+            Key::Up(KeyModifiers::NONE) => Self::MoveUp,
+            Key::Down(KeyModifiers::NONE) => Self::MoveDown,
+
+            Key::Tab | Key::Right(KeyModifiers::SHIFT) => Self::NextCell,
+            Key::Left(KeyModifiers::SHIFT) => Self::PrevCell,
+
+            Key::Char('n', KeyModifiers::CONTROL) => Self::AddRow,
+            Key::Char('x', KeyModifiers::CONTROL) => Self::RemoveRow,
+            key => match InputAction::from_key(key, &()) {
+                Some(action) => Self::ValueInput(action),
+                None => return None,
+            },
+        };
+
+        Some(action)
+    }
+}
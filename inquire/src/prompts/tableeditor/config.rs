@@ -0,0 +1,16 @@
+use crate::TableEditor;
+
+/// Configuration settings used in the execution of a TableEditorPrompt.
+#[derive(Copy, Clone, Debug)]
+pub struct TableEditorConfig {
+    /// Page size of the rows displayed to the user.
+    pub page_size: usize,
+}
+
+impl From<&TableEditor<'_>> for TableEditorConfig {
+    fn from(value: &TableEditor<'_>) -> Self {
+        Self {
+            page_size: value.page_size,
+        }
+    }
+}
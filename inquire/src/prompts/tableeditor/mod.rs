@@ -0,0 +1,322 @@
+mod action;
+mod config;
+mod prompt;
+#[cfg(test)]
+mod test;
+
+pub use action::*;
+
+use std::{convert::TryFrom, fmt::Display};
+
+use crate::{
+    config::get_configuration,
+    error::{InquireError, InquireResult},
+    formatter::TableFormatter,
+    prompts::prompt::Prompt,
+    terminal::get_default_terminal,
+    ui::{Backend, RenderConfig, TableEditorBackend},
+    validator::{CustomTypeValidator, Validation},
+    TableRow,
+};
+
+use self::prompt::TableEditorPrompt;
+
+/// Prompt suitable for when you need the user to enter a small set of structured records, such as port mappings or CSV-like fixtures, as rows of a table with fixed columns.
+///
+/// The table is displayed with a header of column names and one line per row, with a focused cell the user types into directly. Tab moves the focus to the next cell, crossing to the next row at the end of a row, and adds a new row when pressed on the last cell. Shift and the left and right arrows move between cells as well, while the up and down arrows move between rows.
+///
+/// Pressing `ctrl+n` adds an empty row below the focused one and `ctrl+x` removes the focused row. Enter submits the rows.
+///
+/// Cells are trimmed and rows whose cells are all empty are ignored when submitting, so that the row added by tabbing past the last cell is not part of the answer.
+///
+/// This prompt requires a prompt message and a **non-empty** list of column names, otherwise the prompt operation will fail with an [`InquireError::InvalidConfiguration`] error.
+///
+/// Table editor prompts provide several options of configuration:
+///
+/// - **Prompt message**: Required when creating the prompt.
+/// - **Columns**: Names of the columns, displayed as the header of the table. Must be **non-empty**.
+/// - **Initial rows**: Rows listed when the prompt is first rendered. Each row must have one cell per column.
+/// - **Help message**: Message displayed at the line below the prompt.
+/// - **Validators**: Custom validators applied to the cells of each row when submitting, displaying an error message and focusing the first row that does not pass the requirements.
+/// - **Formatter**: Custom formatter in case you need to pre-process the user input before showing it as the final answer.
+///   - Prints the number of rows by default.
+/// - **Page size**: Number of rows displayed at once, 7 by default.
+///
+/// Besides the rows of cells returned by [TableEditor::prompt], rows can be converted to a type of your own with [TableEditor::prompt_as], as long as it implements `TryFrom<Vec<String>>`. Rows that fail the conversion are treated as invalid, displaying the conversion error to the user.
+///
+/// # Example
+///
+/// ```no_run
+/// use inquire::TableEditor;
+///
+/// let ports = TableEditor::new("Port mappings:", &["host", "container"])
+///     .with_rows(&[("8080", "80")])
+///     .prompt();
+///
+/// match ports {
+///     Ok(ports) => println!("Publishing {} ports", ports.len()),
+///     Err(_) => println!("The port mappings could not be processed"),
+/// }
+/// ```
+///
+/// [`InquireError::InvalidConfiguration`]: crate::error::InquireError::InvalidConfiguration
+#[derive(Clone)]
+pub struct TableEditor<'a> {
+    /// Message to be presented to the user.
+    pub message: &'a str,
+
+    /// Names of the columns of the table.
+    pub columns: Vec<&'a str>,
+
+    /// Rows listed when the prompt is first rendered.
+    pub rows: Vec<Vec<String>>,
+
+    /// Help message to be presented to the user.
+    pub help_message: Option<&'a str>,
+
+    /// Function that formats the user input and presents it to the user as the final rendering of the prompt.
+    pub formatter: TableFormatter<'a>,
+
+    /// Collection of validators to apply to the cells of each row when submitting.
+    ///
+    /// Validators are executed in the order they are stored, stopping at and displaying to the user
+    /// only the first validation error that might appear.
+    ///
+    /// The possible error is displayed to the user one line above the prompt.
+    pub validators: Vec<Box<dyn CustomTypeValidator<[String]>>>,
+
+    /// Page size of the rows displayed to the user.
+    pub page_size: usize,
+
+    /// RenderConfig to apply to the rendered interface.
+    ///
+    /// Note: The default render config considers if the NO_COLOR environment variable
+    /// is set to decide whether to render the colored config or the empty one.
+    ///
+    /// When overriding the config in a prompt, NO_COLOR is no longer considered and your
+    /// config is treated as the only source of truth. If you want to customize colors
+    /// and still support NO_COLOR, you will have to do this on your end.
+    pub render_config: RenderConfig<'a>,
+}
+
+impl<'a> TableEditor<'a> {
+    /// Default formatter, set to print the number of rows.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use inquire::TableEditor;
+    ///
+    /// let formatter = TableEditor::DEFAULT_FORMATTER;
+    /// assert_eq!(String::from("1 row"), formatter(&[vec![String::from("a")]]));
+    /// assert_eq!(String::from("0 rows"), formatter(&[]));
+    /// ```
+    pub const DEFAULT_FORMATTER: TableFormatter<'a> = &|rows| match rows.len() {
+        1 => String::from("1 row"),
+        len => format!("{len} rows"),
+    };
+
+    /// Default page size, equal to the global default page size [config::DEFAULT_PAGE_SIZE]
+    pub const DEFAULT_PAGE_SIZE: usize = crate::config::DEFAULT_PAGE_SIZE;
+
+    /// Default validators added to the [TableEditor] prompt, none.
+    pub const DEFAULT_VALIDATORS: Vec<Box<dyn CustomTypeValidator<[String]>>> = vec![];
+
+    /// Default help message.
+    pub const DEFAULT_HELP_MESSAGE: Option<&'a str> = Some(
+        "tab to move between cells, ctrl+n to add a row, ctrl+x to remove it, enter to submit",
+    );
+
+    /// Creates a [TableEditor] with the provided message and column names, along with default configuration values.
+    pub fn new(message: &'a str, columns: &[&'a str]) -> Self {
+        Self {
+            message,
+            columns: columns.to_vec(),
+            rows: vec![],
+            help_message: Self::DEFAULT_HELP_MESSAGE,
+            formatter: Self::DEFAULT_FORMATTER,
+            validators: Self::DEFAULT_VALIDATORS,
+            page_size: Self::DEFAULT_PAGE_SIZE,
+            render_config: get_configuration(),
+        }
+    }
+
+    /// Sets the rows listed when the prompt is first rendered.
+    ///
+    /// Rows can be of any type implementing [TableRow], such as tuples, arrays or vectors.
+    pub fn with_rows<R>(mut self, rows: &[R]) -> Self
+    where
+        R: TableRow,
+    {
+        self.rows = rows.iter().map(TableRow::cells).collect();
+        self
+    }
+
+    /// Sets the help message of the prompt.
+    pub fn with_help_message(mut self, message: &'a str) -> Self {
+        self.help_message = Some(message);
+        self
+    }
+
+    /// Removes the set help message.
+    pub fn without_help_message(mut self) -> Self {
+        self.help_message = None;
+        self
+    }
+
+    /// Sets the formatter.
+    pub fn with_formatter(mut self, formatter: TableFormatter<'a>) -> Self {
+        self.formatter = formatter;
+        self
+    }
+
+    /// Sets the page size
+    pub fn with_page_size(mut self, page_size: usize) -> Self {
+        self.page_size = page_size;
+        self
+    }
+
+    /// Adds a validator to the collection of validators. You might want to use this feature
+    /// in case you need to require certain features from each row, such as numeric cells.
+    ///
+    /// Validators receive the trimmed cells of each row, in column order.
+    ///
+    /// Validators are executed in the order they are stored, stopping at and displaying to the user
+    /// only the first validation error that might appear.
+    ///
+    /// The possible error is displayed to the user one line above the prompt.
+    pub fn with_validator<V>(mut self, validator: V) -> Self
+    where
+        V: CustomTypeValidator<[String]> + 'static,
+    {
+        self.validators.push(Box::new(validator));
+        self
+    }
+
+    /// Adds the validators to the collection of validators in the order they are given.
+    ///
+    /// Validators are executed in the order they are stored, stopping at and displaying to the user
+    /// only the first validation error that might appear.
+    ///
+    /// The possible error is displayed to the user one line above the prompt.
+    pub fn with_validators(
+        mut self,
+        validators: &[Box<dyn CustomTypeValidator<[String]>>],
+    ) -> Self {
+        for validator in validators {
+            #[allow(suspicious_double_ref_op)]
+            self.validators.push(validator.clone());
+        }
+        self
+    }
+
+    /// Sets the provided color theme to this prompt.
+    ///
+    /// Note: The default render config considers if the NO_COLOR environment variable
+    /// is set to decide whether to render the colored config or the empty one.
+    ///
+    /// When overriding the config in a prompt, NO_COLOR is no longer considered and your
+    /// config is treated as the only source of truth. If you want to customize colors
+    /// and still support NO_COLOR, you will have to do this on your end.
+    pub fn with_render_config(mut self, render_config: RenderConfig<'a>) -> Self {
+        self.render_config = render_config;
+        self
+    }
+
+    /// Parses the provided behavioral and rendering options and prompts
+    /// the CLI user for input according to the defined rules.
+    ///
+    /// This method is intended for flows where the user skipping/cancelling
+    /// the prompt - by pressing ESC - is considered normal behavior. In this case,
+    /// it does not return `Err(InquireError::OperationCanceled)`, but `Ok(None)`.
+    ///
+    /// Meanwhile, if the user does submit an answer, the method wraps the return
+    /// type with `Some`.
+    pub fn prompt_skippable(self) -> InquireResult<Option<Vec<Vec<String>>>> {
+        match self.prompt() {
+            Ok(answer) => Ok(Some(answer)),
+            Err(InquireError::OperationCanceled) => Ok(None),
+            Err(err) => Err(err),
+        }
+    }
+
+    /// Parses the provided behavioral and rendering options and prompts
+    /// the CLI user for input according to the defined rules.
+    ///
+    /// Returns the trimmed cells of each submitted row, in column order.
+    pub fn prompt(self) -> InquireResult<Vec<Vec<String>>> {
+        let (input_reader, terminal) = get_default_terminal()?;
+        let mut backend = Backend::new(input_reader, terminal, self.render_config)?;
+        self.prompt_with_backend(&mut backend)
+    }
+
+    /// Parses the provided behavioral and rendering options and prompts
+    /// the CLI user for input according to the defined rules.
+    ///
+    /// Returns the submitted rows converted to the given type. Rows that
+    /// fail the conversion are not accepted, displaying the conversion
+    /// error to the user.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use std::convert::TryFrom;
+    /// use inquire::TableEditor;
+    ///
+    /// struct PortMapping {
+    ///     host: u16,
+    ///     container: u16,
+    /// }
+    ///
+    /// impl TryFrom<Vec<String>> for PortMapping {
+    ///     type Error = String;
+    ///
+    ///     fn try_from(cells: Vec<String>) -> Result<Self, Self::Error> {
+    ///         let port = |cell: &str| cell.parse().map_err(|_err| format!("'{}' is not a port", cell));
+    ///
+    ///         Ok(Self {
+    ///             host: port(&cells[0])?,
+    ///             container: port(&cells[1])?,
+    ///         })
+    ///     }
+    /// }
+    ///
+    /// let ports: Vec<PortMapping> = TableEditor::new("Port mappings:", &["host", "container"])
+    ///     .prompt_as()
+    ///     .unwrap();
+    /// ```
+    pub fn prompt_as<R>(self) -> InquireResult<Vec<R>>
+    where
+        R: TryFrom<Vec<String>> + 'static,
+        R::Error: Display,
+    {
+        let (input_reader, terminal) = get_default_terminal()?;
+        let mut backend = Backend::new(input_reader, terminal, self.render_config)?;
+        self.prompt_as_with_backend(&mut backend)
+    }
+
+    pub(crate) fn prompt_with_backend<B: TableEditorBackend>(
+        self,
+        backend: &mut B,
+    ) -> InquireResult<Vec<Vec<String>>> {
+        TableEditorPrompt::new(self)?.prompt(backend)
+    }
+
+    pub(crate) fn prompt_as_with_backend<R, B>(self, backend: &mut B) -> InquireResult<Vec<R>>
+    where
+        R: TryFrom<Vec<String>> + 'static,
+        R::Error: Display,
+        B: TableEditorBackend,
+    {
+        let rows = self
+            .with_validator(|row: &[String]| match R::try_from(row.to_vec()) {
+                Ok(_) => Ok(Validation::Valid),
+                Err(err) => Ok(Validation::Invalid(err.to_string().into())),
+            })
+            .prompt_with_backend(backend)?;
+
+        rows.into_iter()
+            .map(|row| R::try_from(row).map_err(|err| InquireError::Custom(err.to_string().into())))
+            .collect()
+    }
+}
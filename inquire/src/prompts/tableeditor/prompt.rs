@@ -0,0 +1,245 @@
+use crate::{
+    error::InquireResult,
+    formatter::TableFormatter,
+    input::{Input, InputActionResult},
+    list_option::ListOption,
+    prompts::prompt::{ActionResult, Prompt},
+    ui::TableEditorBackend,
+    utils::paginate,
+    validator::{CustomTypeValidator, ErrorMessage, Validation},
+    InquireError, TableEditor,
+};
+
+use super::{action::TableEditorPromptAction, config::TableEditorConfig};
+
+pub struct TableEditorPrompt<'a> {
+    message: &'a str,
+    config: TableEditorConfig,
+    columns: Vec<&'a str>,
+    rows: Vec<Vec<String>>,
+    help_message: Option<&'a str>,
+    cursor_row: usize,
+    cursor_column: usize,
+    // text input of the focused cell, whose content is kept in sync with the cell
+    input: Input,
+    formatter: TableFormatter<'a>,
+    validators: Vec<Box<dyn CustomTypeValidator<[String]>>>,
+    error: Option<ErrorMessage>,
+}
+
+impl<'a> TableEditorPrompt<'a> {
+    pub fn new(te: TableEditor<'a>) -> InquireResult<Self> {
+        if te.columns.is_empty() {
+            return Err(InquireError::InvalidConfiguration(
+                "Columns can not be empty".into(),
+            ));
+        }
+
+        for (i, row) in te.rows.iter().enumerate() {
+            if row.len() != te.columns.len() {
+                return Err(InquireError::InvalidConfiguration(format!(
+                    "Row {} has {} cells, expected {}",
+                    i,
+                    row.len(),
+                    te.columns.len()
+                )));
+            }
+        }
+
+        let config = (&te).into();
+
+        let mut rows = te.rows;
+        if rows.is_empty() {
+            rows.push(vec![String::new(); te.columns.len()]);
+        }
+
+        let input = Input::new_with(&rows[0][0]);
+
+        Ok(Self {
+            message: te.message,
+            config,
+            columns: te.columns,
+            rows,
+            help_message: te.help_message,
+            cursor_row: 0,
+            cursor_column: 0,
+            input,
+            formatter: te.formatter,
+            validators: te.validators,
+            error: None,
+        })
+    }
+
+    fn empty_row(&self) -> Vec<String> {
+        vec![String::new(); self.columns.len()]
+    }
+
+    /// Moves the focus to the given cell, loading its content into the text input.
+    fn focus(&mut self, row: usize, column: usize) -> ActionResult {
+        if row == self.cursor_row && column == self.cursor_column {
+            return ActionResult::Clean;
+        }
+
+        self.cursor_row = row;
+        self.cursor_column = column;
+        self.input = Input::new_with(&self.rows[row][column]);
+
+        ActionResult::NeedsRedraw
+    }
+
+    fn next_cell(&mut self) -> ActionResult {
+        if self.cursor_column + 1 < self.columns.len() {
+            return self.focus(self.cursor_row, self.cursor_column + 1);
+        }
+
+        if self.cursor_row + 1 == self.rows.len() {
+            let row = self.empty_row();
+            self.rows.push(row);
+        }
+
+        self.focus(self.cursor_row + 1, 0)
+    }
+
+    fn prev_cell(&mut self) -> ActionResult {
+        match (self.cursor_row, self.cursor_column) {
+            (0, 0) => ActionResult::Clean,
+            (row, 0) => self.focus(row - 1, self.columns.len() - 1),
+            (row, column) => self.focus(row, column - 1),
+        }
+    }
+
+    fn add_row(&mut self) -> ActionResult {
+        let row = self.empty_row();
+        self.rows.insert(self.cursor_row + 1, row);
+
+        self.focus(self.cursor_row + 1, 0)
+    }
+
+    fn remove_row(&mut self) -> ActionResult {
+        self.rows.remove(self.cursor_row);
+        if self.rows.is_empty() {
+            let row = self.empty_row();
+            self.rows.push(row);
+        }
+
+        self.cursor_row = self.cursor_row.min(self.rows.len() - 1);
+        self.input = Input::new_with(&self.rows[self.cursor_row][self.cursor_column]);
+
+        ActionResult::NeedsRedraw
+    }
+
+    fn validate_row(&self, row: &[String]) -> InquireResult<Validation> {
+        for validator in &self.validators {
+            match validator.validate(row) {
+                Ok(Validation::Valid) => {}
+                Ok(Validation::Invalid(msg)) => return Ok(Validation::Invalid(msg)),
+                Err(err) => return Err(InquireError::Custom(err)),
+            }
+        }
+
+        Ok(Validation::Valid)
+    }
+}
+
+impl<'a, Backend> Prompt<Backend> for TableEditorPrompt<'a>
+where
+    Backend: TableEditorBackend,
+{
+    type Config = TableEditorConfig;
+    type InnerAction = TableEditorPromptAction;
+    type Output = Vec<Vec<String>>;
+
+    fn message(&self) -> &str {
+        self.message
+    }
+
+    fn config(&self) -> &TableEditorConfig {
+        &self.config
+    }
+
+    fn format_answer(&self, answer: &Vec<Vec<String>>) -> String {
+        (self.formatter)(answer)
+    }
+
+    fn submit(&mut self) -> InquireResult<Option<Vec<Vec<String>>>> {
+        let mut answer = vec![];
+
+        for (index, row) in self.rows.iter().enumerate() {
+            let row = row
+                .iter()
+                .map(|cell| cell.trim().to_string())
+                .collect::<Vec<String>>();
+
+            // rows left blank, such as the one added after the last cell, are ignored
+            if row.iter().all(String::is_empty) {
+                continue;
+            }
+
+            if let Validation::Invalid(msg) = self.validate_row(&row)? {
+                self.error = Some(msg);
+                let _ = self.focus(index, 0);
+                return Ok(None);
+            }
+
+            answer.push(row);
+        }
+
+        Ok(Some(answer))
+    }
+
+    fn handle(&mut self, action: TableEditorPromptAction) -> InquireResult<ActionResult> {
+        let result = match action {
+            TableEditorPromptAction::ValueInput(input_action) => {
+                let result = self.input.handle(input_action);
+
+                if let InputActionResult::ContentChanged = result {
+                    self.rows[self.cursor_row][self.cursor_column] =
+                        self.input.content().to_string();
+                }
+
+                result.into()
+            }
+            TableEditorPromptAction::MoveUp => match self.cursor_row {
+                0 => ActionResult::Clean,
+                row => self.focus(row - 1, self.cursor_column),
+            },
+            TableEditorPromptAction::MoveDown => match self.cursor_row + 1 < self.rows.len() {
+                true => self.focus(self.cursor_row + 1, self.cursor_column),
+                false => ActionResult::Clean,
+            },
+            TableEditorPromptAction::NextCell => self.next_cell(),
+            TableEditorPromptAction::PrevCell => self.prev_cell(),
+            TableEditorPromptAction::AddRow => self.add_row(),
+            TableEditorPromptAction::RemoveRow => self.remove_row(),
+        };
+
+        Ok(result)
+    }
+
+    fn render(&self, backend: &mut Backend) -> InquireResult<()> {
+        let prompt = &self.message;
+
+        if let Some(error) = &self.error {
+            backend.render_error_message(error)?;
+        }
+
+        backend.render_table_editor_prompt(prompt)?;
+
+        let rows = self
+            .rows
+            .iter()
+            .enumerate()
+            .map(|(i, row)| ListOption::new(i, row))
+            .collect::<Vec<ListOption<&Vec<String>>>>();
+
+        let page = paginate(self.config.page_size, &rows, Some(self.cursor_row));
+
+        backend.render_table_editor(&self.columns, page, self.cursor_column, &self.input)?;
+
+        if let Some(help_message) = self.help_message {
+            backend.render_help_message(help_message)?;
+        }
+
+        Ok(())
+    }
+}
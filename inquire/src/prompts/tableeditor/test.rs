@@ -0,0 +1,172 @@
+use std::convert::TryFrom;
+
+use crate::{
+    test::fake_backend,
+    ui::{
+        test::{FakeBackend, Token},
+        Key, KeyModifiers,
+    },
+    validator::{ErrorMessage, Validation},
+    InquireError, TableEditor,
+};
+
+fn ports<'a>() -> TableEditor<'a> {
+    TableEditor::new("Ports", &["host", "container"])
+}
+
+#[test]
+fn tab_moves_between_cells_and_adds_rows() {
+    let mut input = Key::char_keys_from_str("8080");
+    input.push(Key::Tab);
+    input.extend(Key::char_keys_from_str("80 "));
+    input.push(Key::Tab);
+    input.extend(Key::char_keys_from_str(" 443"));
+    input.push(Key::Tab);
+    input.extend(Key::char_keys_from_str("443"));
+    // the row added after the last cell is left blank
+    input.extend([Key::Tab, Key::Enter]);
+    let mut backend = fake_backend(input);
+
+    let ans = ports().prompt_with_backend(&mut backend).unwrap();
+
+    assert_eq!(vec![vec!["8080", "80"], vec!["443", "443"]], ans);
+}
+
+#[test]
+fn initial_rows_are_edited_in_place() {
+    let mut input = vec![
+        Key::Down(KeyModifiers::NONE),
+        Key::Right(KeyModifiers::SHIFT),
+        Key::Backspace,
+    ];
+    input.extend(Key::char_keys_from_str("1"));
+    input.push(Key::Enter);
+    let mut backend = fake_backend(input);
+
+    let ans = ports()
+        .with_rows(&[("8080", "80"), ("5432", "5432")])
+        .prompt_with_backend(&mut backend)
+        .unwrap();
+
+    assert_eq!(vec![vec!["8080", "80"], vec!["5432", "5431"]], ans);
+}
+
+#[test]
+fn rows_can_be_added_and_removed() {
+    let mut input = vec![Key::Char('n', KeyModifiers::CONTROL)];
+    input.extend(Key::char_keys_from_str("22"));
+    input.push(Key::Tab);
+    input.extend(Key::char_keys_from_str("2222"));
+    input.extend([
+        Key::Left(KeyModifiers::SHIFT),
+        Key::Left(KeyModifiers::SHIFT),
+        Key::Char('x', KeyModifiers::CONTROL),
+        Key::Enter,
+    ]);
+    let mut backend = fake_backend(input);
+
+    let ans = ports()
+        .with_rows(&[["8080", "80"]])
+        .prompt_with_backend(&mut backend)
+        .unwrap();
+
+    assert_eq!(vec![vec!["22", "2222"]], ans);
+}
+
+#[test]
+fn invalid_rows_are_focused_with_the_error() {
+    let mut input = vec![Key::Enter, Key::Tab, Key::Backspace];
+    input.extend(Key::char_keys_from_str("3"));
+    input.push(Key::Enter);
+    let mut backend = FakeBackend::new(input);
+
+    let ans = ports()
+        .with_rows(&[("8080", "80"), ("5432", "x")])
+        .with_validator(
+            |row: &[String]| match row.iter().all(|cell| cell.parse::<u16>().is_ok()) {
+                true => Ok(Validation::Valid),
+                false => Ok(Validation::Invalid("Ports must be numbers".into())),
+            },
+        )
+        .prompt_with_backend(&mut backend)
+        .unwrap();
+
+    assert_eq!(vec![vec!["8080", "80"], vec!["5432", "3"]], ans);
+
+    let frame = &backend.frames()[1];
+    assert!(frame.has_token(&Token::ErrorMessage(ErrorMessage::Custom(
+        "Ports must be numbers".into()
+    ))));
+    assert!(frame.has_token(&Token::Table {
+        rows: vec![
+            vec![String::from("8080"), String::from("80")],
+            vec![String::from("5432"), String::from("x")],
+        ],
+        cursor: Some((1, 0)),
+    }));
+}
+
+#[derive(Debug, PartialEq)]
+struct PortMapping {
+    host: u16,
+    container: u16,
+}
+
+impl TryFrom<Vec<String>> for PortMapping {
+    type Error = String;
+
+    fn try_from(cells: Vec<String>) -> Result<Self, Self::Error> {
+        let port = |cell: &str| {
+            cell.parse()
+                .map_err(|_err| format!("'{cell}' is not a port"))
+        };
+
+        Ok(Self {
+            host: port(&cells[0])?,
+            container: port(&cells[1])?,
+        })
+    }
+}
+
+#[test]
+fn rows_are_converted_to_custom_types() {
+    let mut input = vec![Key::Enter, Key::Backspace, Key::Backspace, Key::Backspace];
+    input.extend(Key::char_keys_from_str("22"));
+    input.push(Key::Enter);
+    let mut backend = FakeBackend::new(input);
+
+    let ans: Vec<PortMapping> = ports()
+        .with_rows(&[("ssh", "22")])
+        .prompt_as_with_backend(&mut backend)
+        .unwrap();
+
+    assert_eq!(
+        vec![PortMapping {
+            host: 22,
+            container: 22
+        }],
+        ans
+    );
+    assert!(
+        backend.frames()[1].has_token(&Token::ErrorMessage(ErrorMessage::Custom(
+            "'ssh' is not a port".into()
+        )))
+    );
+}
+
+#[test]
+fn rows_with_wrong_number_of_cells_are_invalid() {
+    let mut backend = fake_backend(vec![Key::Enter]);
+
+    let ans = ports()
+        .with_rows(&[vec!["8080"]])
+        .prompt_with_backend(&mut backend);
+
+    assert!(matches!(ans, Err(InquireError::InvalidConfiguration(_))));
+
+    let mut backend = fake_backend(vec![Key::Enter]);
+
+    let ans = TableEditor::new("Ports", &[]).prompt_with_backend(&mut backend);
+
+    assert!(matches!(ans, Err(InquireError::InvalidConfiguration(_))));
+}
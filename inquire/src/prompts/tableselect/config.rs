@@ -0,0 +1,22 @@
+use crate::TableSelect;
+
+/// Configuration settings used in the execution of a TableSelectPrompt.
+#[derive(Copy, Clone, Debug)]
+pub struct TableSelectConfig {
+    /// Whether to use vim-style keybindings.
+    pub vim_mode: bool,
+    /// Page size of the list of rows.
+    pub page_size: usize,
+    /// Whether to reset the cursor to the first row on filter input change.
+    pub reset_cursor: bool,
+}
+
+impl<T> From<&TableSelect<'_, T>> for TableSelectConfig {
+    fn from(value: &TableSelect<'_, T>) -> Self {
+        Self {
+            vim_mode: value.vim_mode,
+            page_size: value.page_size,
+            reset_cursor: value.reset_cursor,
+        }
+    }
+}
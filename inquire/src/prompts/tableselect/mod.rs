@@ -0,0 +1,345 @@
+mod action;
+mod config;
+mod prompt;
+mod row;
+#[cfg(test)]
+#[cfg(feature = "crossterm")]
+mod test;
+
+pub use action::*;
+pub use row::*;
+
+use crate::{
+    config::get_configuration,
+    error::{InquireError, InquireResult},
+    formatter::OptionFormatter,
+    list_option::ListOption,
+    prompts::prompt::Prompt,
+    terminal::get_default_terminal,
+    type_aliases::Scorer,
+    ui::{Backend, RenderConfig, TableSelectBackend},
+};
+
+use self::prompt::TableSelectPrompt;
+
+#[cfg(feature = "fuzzy")]
+use fuzzy_matcher::{skim::SkimMatcherV2, FuzzyMatcher};
+#[cfg(feature = "fuzzy")]
+use once_cell::sync::Lazy;
+#[cfg(feature = "fuzzy")]
+static DEFAULT_MATCHER: Lazy<SkimMatcherV2> = Lazy::new(|| SkimMatcherV2::default().ignore_case());
+
+/// Prompt suitable for when you need the user to select one row of structured data, such as a list of containers with their names, images and statuses.
+///
+/// Each option is a [`TableRow`], made of one cell per column. The columns are aligned automatically, based on the widest cell of each column, and can optionally be labeled by a header row rendered above the options. Typing filters the rows, matching the input against the content of all columns.
+///
+/// The user can select and submit the current highlighted row by pressing enter.
+///
+/// This prompt requires a prompt message and a **non-empty** `Vec` of rows to be displayed to the user.
+/// - If the list is empty, the prompt operation will fail with an `InquireError::InvalidConfiguration` error.
+///
+/// Table selects provide several options of configuration:
+///
+/// - **Prompt message**: Required when creating the prompt.
+/// - **Options list**: Rows displayed to the user. Must be **non-empty**.
+/// - **Headers**: Labels of the columns, rendered above the rows. The header row is not part of the paginated list, so it stays visible while the rows scroll underneath it. No header row is rendered by default.
+/// - **Starting cursor**: Index of the cursor when the prompt is first rendered. Default is 0 (first row). If the index is out-of-range of the option list, the prompt will fail with an [`InquireError::InvalidConfiguration`] error.
+/// - **Starting filter input**: Sets the initial value of the filter section of the prompt.
+/// - **Help message**: Message displayed at the line below the prompt.
+/// - **Formatter**: Custom formatter in case you need to pre-process the user input before showing it as the final answer.
+///   - Prints the content of the first column of the selected row by default.
+/// - **Page size**: Number of rows displayed at once, 7 by default.
+/// - **Scorer function**: Function that defines the order of rows and if displayed as all. It receives the content of all cells of the row, separated by spaces, as the string value.
+///
+/// # Example
+///
+/// ```no_run
+/// use inquire::TableSelect;
+///
+/// let containers = vec![
+///     ["web", "nginx:1.25", "running"],
+///     ["db", "postgres:16", "running"],
+///     ["cache", "redis:7", "exited"],
+/// ];
+///
+/// let ans = TableSelect::new("Which container do you want to inspect?", containers)
+///     .with_headers(&["NAME", "IMAGE", "STATUS"])
+///     .prompt();
+///
+/// match ans {
+///     Ok([name, _, _]) => println!("Inspecting {}", name),
+///     Err(_) => println!("There was an error, please try again"),
+/// }
+/// ```
+///
+/// [`InquireError::InvalidConfiguration`]: crate::error::InquireError::InvalidConfiguration
+#[derive(Clone)]
+pub struct TableSelect<'a, T> {
+    /// Message to be presented to the user.
+    pub message: &'a str,
+
+    /// Labels of the columns, rendered above the rows. No header row is rendered when empty.
+    pub headers: Vec<&'a str>,
+
+    /// Rows displayed to the user.
+    pub options: Vec<T>,
+
+    /// Help message to be presented to the user.
+    pub help_message: Option<&'a str>,
+
+    /// Page size of the rows displayed to the user.
+    pub page_size: usize,
+
+    /// Whether vim mode is enabled. When enabled, the user can
+    /// navigate through the rows using jk.
+    pub vim_mode: bool,
+
+    /// Starting cursor index of the selection.
+    pub starting_cursor: usize,
+
+    /// Starting filter input
+    pub starting_filter_input: Option<&'a str>,
+
+    /// Reset cursor position to first row on filter input change.
+    /// Defaults to true.
+    pub reset_cursor: bool,
+
+    /// Whether to allow the rows to be filtered by user input or not.
+    ///
+    /// Defaults to true.
+    pub filter_input_enabled: bool,
+
+    /// Function called with the current user input to score the provided
+    /// rows.
+    pub scorer: Scorer<'a, T>,
+
+    /// Function that formats the user input and presents it to the user as the final rendering of the prompt.
+    pub formatter: OptionFormatter<'a, T>,
+
+    /// RenderConfig to apply to the rendered interface.
+    ///
+    /// Note: The default render config considers if the NO_COLOR environment variable
+    /// is set to decide whether to render the colored config or the empty one.
+    ///
+    /// When overriding the config in a prompt, NO_COLOR is no longer considered and your
+    /// config is treated as the only source of truth. If you want to customize colors
+    /// and still support NO_COLOR, you will have to do this on your end.
+    pub render_config: RenderConfig<'a>,
+}
+
+impl<'a, T> TableSelect<'a, T>
+where
+    T: TableRow,
+{
+    /// String formatter used by default in [TableSelect](crate::TableSelect) prompts.
+    /// Prints the content of the first column of the selected row.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use inquire::list_option::ListOption;
+    /// use inquire::TableSelect;
+    ///
+    /// let formatter = TableSelect::<[&str; 2]>::DEFAULT_FORMATTER;
+    /// assert_eq!(String::from("web"), formatter(ListOption::new(0, &["web", "running"])));
+    /// ```
+    pub const DEFAULT_FORMATTER: OptionFormatter<'a, T> =
+        &|ans| ans.value.cells().into_iter().next().unwrap_or_default();
+
+    /// Default scoring function, which will create a score for the current row using the input value.
+    /// The return will be sorted in Descending order, leaving rows with None as a score.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use inquire::TableSelect;
+    ///
+    /// let scorer = TableSelect::<[&str; 2]>::DEFAULT_SCORER;
+    /// assert_eq!(None,      scorer("redis", &["web", "nginx"],   "web nginx",   0));
+    /// assert_eq!(Some(103), scorer("redis", &["cache", "redis"], "cache redis", 1));
+    /// ```
+    #[cfg(feature = "fuzzy")]
+    pub const DEFAULT_SCORER: Scorer<'a, T> =
+        &|input, _option, string_value, _idx| -> Option<i64> {
+            DEFAULT_MATCHER.fuzzy_match(string_value, input)
+        };
+
+    #[cfg(not(feature = "fuzzy"))]
+    pub const DEFAULT_SCORER: Scorer<'a, T> =
+        &|input, _option, string_value, _idx| -> Option<i64> {
+            let filter = input.to_lowercase();
+            match string_value.to_lowercase().contains(&filter) {
+                true => Some(0),
+                false => None,
+            }
+        };
+
+    /// Default page size.
+    pub const DEFAULT_PAGE_SIZE: usize = crate::config::DEFAULT_PAGE_SIZE;
+
+    /// Default value of vim mode.
+    pub const DEFAULT_VIM_MODE: bool = crate::config::DEFAULT_VIM_MODE;
+
+    /// Default starting cursor index.
+    pub const DEFAULT_STARTING_CURSOR: usize = 0;
+
+    /// Default cursor behaviour on filter input change.
+    /// Defaults to true.
+    pub const DEFAULT_RESET_CURSOR: bool = true;
+
+    /// Default filter input enabled behaviour.
+    /// Defaults to true.
+    pub const DEFAULT_FILTER_INPUT_ENABLED: bool = true;
+
+    /// Default help message.
+    pub const DEFAULT_HELP_MESSAGE: Option<&'a str> =
+        Some("↑↓ to move, enter to select, type to filter");
+
+    /// Creates a [TableSelect] with the provided message and rows, along with default configuration values.
+    pub fn new(message: &'a str, options: Vec<T>) -> Self {
+        Self {
+            message,
+            headers: vec![],
+            options,
+            help_message: Self::DEFAULT_HELP_MESSAGE,
+            page_size: Self::DEFAULT_PAGE_SIZE,
+            vim_mode: Self::DEFAULT_VIM_MODE,
+            starting_cursor: Self::DEFAULT_STARTING_CURSOR,
+            reset_cursor: Self::DEFAULT_RESET_CURSOR,
+            filter_input_enabled: Self::DEFAULT_FILTER_INPUT_ENABLED,
+            scorer: Self::DEFAULT_SCORER,
+            formatter: Self::DEFAULT_FORMATTER,
+            render_config: get_configuration(),
+            starting_filter_input: None,
+        }
+    }
+
+    /// Sets the labels of the columns, rendered above the rows.
+    pub fn with_headers(mut self, headers: &[&'a str]) -> Self {
+        self.headers = headers.to_vec();
+        self
+    }
+
+    /// Sets the help message of the prompt.
+    pub fn with_help_message(mut self, message: &'a str) -> Self {
+        self.help_message = Some(message);
+        self
+    }
+
+    /// Removes the set help message.
+    pub fn without_help_message(mut self) -> Self {
+        self.help_message = None;
+        self
+    }
+
+    /// Sets the page size.
+    pub fn with_page_size(mut self, page_size: usize) -> Self {
+        self.page_size = page_size;
+        self
+    }
+
+    /// Enables or disables vim_mode.
+    pub fn with_vim_mode(mut self, vim_mode: bool) -> Self {
+        self.vim_mode = vim_mode;
+        self
+    }
+
+    /// Sets the scoring function.
+    pub fn with_scorer(mut self, scorer: Scorer<'a, T>) -> Self {
+        self.scorer = scorer;
+        self
+    }
+
+    /// Sets the formatter.
+    pub fn with_formatter(mut self, formatter: OptionFormatter<'a, T>) -> Self {
+        self.formatter = formatter;
+        self
+    }
+
+    /// Sets the starting cursor index.
+    ///
+    /// This index might be overridden if the `reset_cursor` option is set to true (default)
+    /// and starting_filter_input is set to something other than None.
+    pub fn with_starting_cursor(mut self, starting_cursor: usize) -> Self {
+        self.starting_cursor = starting_cursor;
+        self
+    }
+
+    /// Sets the starting filter input
+    pub fn with_starting_filter_input(mut self, starting_filter_input: &'a str) -> Self {
+        self.starting_filter_input = Some(starting_filter_input);
+        self
+    }
+
+    /// Sets the reset_cursor behaviour. Defaults to true.
+    ///
+    /// When there's an input change that results in a different list of rows being displayed,
+    /// whether by filtering or re-ordering, the cursor will be reset to highlight the first row.
+    pub fn with_reset_cursor(mut self, reset_cursor: bool) -> Self {
+        self.reset_cursor = reset_cursor;
+        self
+    }
+
+    /// Disables the filter input, which means the user will not be able to filter the rows
+    /// by typing.
+    pub fn without_filtering(mut self) -> Self {
+        self.filter_input_enabled = false;
+        self
+    }
+
+    /// Sets the provided color theme to this prompt.
+    ///
+    /// Note: The default render config considers if the NO_COLOR environment variable
+    /// is set to decide whether to render the colored config or the empty one.
+    ///
+    /// When overriding the config in a prompt, NO_COLOR is no longer considered and your
+    /// config is treated as the only source of truth. If you want to customize colors
+    /// and still support NO_COLOR, you will have to do this on your end.
+    pub fn with_render_config(mut self, render_config: RenderConfig<'a>) -> Self {
+        self.render_config = render_config;
+        self
+    }
+
+    /// Parses the provided behavioral and rendering options and prompts
+    /// the CLI user for input according to the defined rules.
+    ///
+    /// Returns the owned row selected by the user.
+    pub fn prompt(self) -> InquireResult<T> {
+        self.raw_prompt().map(|op| op.value)
+    }
+
+    /// Parses the provided behavioral and rendering options and prompts
+    /// the CLI user for input according to the defined rules.
+    ///
+    /// This method is intended for flows where the user skipping/cancelling
+    /// the prompt - by pressing ESC - is considered normal behavior. In this case,
+    /// it does not return `Err(InquireError::OperationCanceled)`, but `Ok(None)`.
+    ///
+    /// Meanwhile, if the user does submit an answer, the method wraps the return
+    /// type with `Some`.
+    pub fn prompt_skippable(self) -> InquireResult<Option<T>> {
+        match self.prompt() {
+            Ok(answer) => Ok(Some(answer)),
+            Err(InquireError::OperationCanceled) => Ok(None),
+            Err(err) => Err(err),
+        }
+    }
+
+    /// Parses the provided behavioral and rendering options and prompts
+    /// the CLI user for input according to the defined rules.
+    ///
+    /// Returns a [`ListOption`](crate::list_option::ListOption) containing
+    /// the index of the selection and the owned row selected by the user.
+    pub fn raw_prompt(self) -> InquireResult<ListOption<T>> {
+        let (input_reader, terminal) = get_default_terminal()?;
+        let mut backend = Backend::new(input_reader, terminal, self.render_config)?;
+        self.prompt_with_backend(&mut backend)
+    }
+
+    pub(crate) fn prompt_with_backend<B: TableSelectBackend>(
+        self,
+        backend: &mut B,
+    ) -> InquireResult<ListOption<T>> {
+        TableSelectPrompt::new(self)?.prompt(backend)
+    }
+}
@@ -0,0 +1,308 @@
+use std::cmp::Reverse;
+
+use unicode_width::UnicodeWidthStr;
+
+use crate::{
+    error::InquireResult,
+    formatter::OptionFormatter,
+    input::{Input, InputActionResult},
+    list_option::ListOption,
+    prompts::prompt::{ActionResult, Prompt},
+    type_aliases::Scorer,
+    ui::TableSelectBackend,
+    utils::paginate,
+    InquireError, TableSelect,
+};
+
+use super::{action::TableSelectPromptAction, config::TableSelectConfig, row::TableRow};
+
+/// Separator rendered between the columns of the table.
+const COLUMN_SEPARATOR: &str = "  ";
+
+pub struct TableSelectPrompt<'a, T> {
+    message: &'a str,
+    config: TableSelectConfig,
+    options: Vec<T>,
+    header: Option<String>,
+    aligned_rows: Vec<String>,
+    string_options: Vec<String>,
+    scored_options: Vec<usize>,
+    help_message: Option<&'a str>,
+    cursor_index: usize,
+    input: Option<Input>,
+    scorer: Scorer<'a, T>,
+    formatter: OptionFormatter<'a, T>,
+}
+
+impl<'a, T> TableSelectPrompt<'a, T>
+where
+    T: TableRow,
+{
+    pub fn new(to: TableSelect<'a, T>) -> InquireResult<Self> {
+        if to.options.is_empty() {
+            return Err(InquireError::InvalidConfiguration(
+                "Available options can not be empty".into(),
+            ));
+        }
+
+        if to.starting_cursor >= to.options.len() {
+            return Err(InquireError::InvalidConfiguration(format!(
+                "Starting cursor index {} is out-of-bounds for length {} of options",
+                to.starting_cursor,
+                &to.options.len()
+            )));
+        }
+
+        let cells = to
+            .options
+            .iter()
+            .map(TableRow::cells)
+            .collect::<Vec<Vec<String>>>();
+        let headers = to
+            .headers
+            .iter()
+            .map(|header| String::from(*header))
+            .collect::<Vec<String>>();
+
+        let widths = column_widths(&headers, &cells);
+
+        let header = match headers.is_empty() {
+            true => None,
+            false => Some(align_row(&headers, &widths)),
+        };
+        let aligned_rows = cells.iter().map(|row| align_row(row, &widths)).collect();
+        let string_options = cells.iter().map(|row| row.join(" ")).collect();
+        let scored_options = (0..to.options.len()).collect();
+
+        let input = match to.filter_input_enabled {
+            true => Some(Input::new_with(
+                to.starting_filter_input.unwrap_or_default(),
+            )),
+            false => None,
+        };
+
+        Ok(Self {
+            message: to.message,
+            config: (&to).into(),
+            options: to.options,
+            header,
+            aligned_rows,
+            string_options,
+            scored_options,
+            help_message: to.help_message,
+            cursor_index: to.starting_cursor,
+            input,
+            scorer: to.scorer,
+            formatter: to.formatter,
+        })
+    }
+
+    fn move_cursor_up(&mut self, qty: usize, wrap: bool) -> ActionResult {
+        let new_position = if wrap {
+            let after_wrap = qty.saturating_sub(self.cursor_index);
+            self.cursor_index
+                .checked_sub(qty)
+                .unwrap_or_else(|| self.scored_options.len().saturating_sub(after_wrap))
+        } else {
+            self.cursor_index.saturating_sub(qty)
+        };
+
+        self.update_cursor_position(new_position)
+    }
+
+    fn move_cursor_down(&mut self, qty: usize, wrap: bool) -> ActionResult {
+        let mut new_position = self.cursor_index.saturating_add(qty);
+
+        if new_position >= self.scored_options.len() {
+            new_position = if self.scored_options.is_empty() {
+                0
+            } else if wrap {
+                new_position % self.scored_options.len()
+            } else {
+                self.scored_options.len().saturating_sub(1)
+            }
+        }
+
+        self.update_cursor_position(new_position)
+    }
+
+    fn update_cursor_position(&mut self, new_position: usize) -> ActionResult {
+        if new_position != self.cursor_index {
+            self.cursor_index = new_position;
+            ActionResult::NeedsRedraw
+        } else {
+            ActionResult::Clean
+        }
+    }
+
+    fn has_answer_highlighted(&mut self) -> bool {
+        self.scored_options.get(self.cursor_index).is_some()
+    }
+
+    fn get_final_answer(&mut self) -> ListOption<T> {
+        // should only be called after current cursor index is validated
+        // on has_answer_highlighted
+
+        let index = *self.scored_options.get(self.cursor_index).unwrap();
+        let value = self.options.swap_remove(index);
+
+        ListOption::new(index, value)
+    }
+
+    fn run_scorer(&mut self) {
+        let content = match &self.input {
+            Some(input) => input.content(),
+            None => return,
+        };
+
+        let mut options = self
+            .options
+            .iter()
+            .enumerate()
+            .filter_map(|(i, opt)| {
+                (self.scorer)(content, opt, self.string_options.get(i).unwrap(), i)
+                    .map(|score| (i, score))
+            })
+            .collect::<Vec<(usize, i64)>>();
+
+        options.sort_by_key(|(_idx, score)| Reverse(*score));
+
+        let new_scored_options = options.iter().map(|(idx, _)| *idx).collect::<Vec<usize>>();
+
+        if self.scored_options == new_scored_options {
+            return;
+        }
+
+        self.scored_options = new_scored_options;
+
+        if self.config.reset_cursor {
+            let _ = self.update_cursor_position(0);
+        } else if self.scored_options.len() <= self.cursor_index {
+            let _ = self.update_cursor_position(self.scored_options.len().saturating_sub(1));
+        }
+    }
+}
+
+impl<'a, Backend, T> Prompt<Backend> for TableSelectPrompt<'a, T>
+where
+    Backend: TableSelectBackend,
+    T: TableRow,
+{
+    type Config = TableSelectConfig;
+    type InnerAction = TableSelectPromptAction;
+    type Output = ListOption<T>;
+
+    fn message(&self) -> &str {
+        self.message
+    }
+
+    fn config(&self) -> &TableSelectConfig {
+        &self.config
+    }
+
+    fn format_answer(&self, answer: &ListOption<T>) -> String {
+        (self.formatter)(answer.as_ref())
+    }
+
+    fn setup(&mut self) -> InquireResult<()> {
+        self.run_scorer();
+        Ok(())
+    }
+
+    fn submit(&mut self) -> InquireResult<Option<ListOption<T>>> {
+        let answer = match self.has_answer_highlighted() {
+            true => Some(self.get_final_answer()),
+            false => None,
+        };
+
+        Ok(answer)
+    }
+
+    fn handle(&mut self, action: TableSelectPromptAction) -> InquireResult<ActionResult> {
+        let result = match action {
+            TableSelectPromptAction::MoveUp => self.move_cursor_up(1, true),
+            TableSelectPromptAction::MoveDown => self.move_cursor_down(1, true),
+            TableSelectPromptAction::PageUp => self.move_cursor_up(self.config.page_size, false),
+            TableSelectPromptAction::PageDown => {
+                self.move_cursor_down(self.config.page_size, false)
+            }
+            TableSelectPromptAction::MoveToStart => self.move_cursor_up(usize::MAX, false),
+            TableSelectPromptAction::MoveToEnd => self.move_cursor_down(usize::MAX, false),
+
+            TableSelectPromptAction::FilterInput(input_action) => match self.input.as_mut() {
+                Some(input) => {
+                    let result = input.handle(input_action);
+
+                    if let InputActionResult::ContentChanged = result {
+                        self.run_scorer();
+                    }
+
+                    result.into()
+                }
+                None => ActionResult::Clean,
+            },
+        };
+
+        Ok(result)
+    }
+
+    fn render(&self, backend: &mut Backend) -> InquireResult<()> {
+        let prompt = &self.message;
+
+        backend.render_select_prompt(prompt, self.input.as_ref())?;
+
+        if let Some(header) = &self.header {
+            backend.render_table_header(header, self.options.len())?;
+        }
+
+        let choices = self
+            .scored_options
+            .iter()
+            .cloned()
+            .map(|i| ListOption::new(i, self.aligned_rows.get(i).unwrap()))
+            .collect::<Vec<ListOption<&String>>>();
+
+        let page = paginate(self.config.page_size, &choices, Some(self.cursor_index));
+
+        backend.render_options(page)?;
+
+        if let Some(help_message) = self.help_message {
+            backend.render_help_message(help_message)?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Computes the width of each column, which is the widest among its header and cells.
+pub(super) fn column_widths(headers: &[String], rows: &[Vec<String>]) -> Vec<usize> {
+    let mut widths = headers.iter().map(|h| h.width()).collect::<Vec<usize>>();
+
+    for row in rows {
+        for (idx, cell) in row.iter().enumerate() {
+            match widths.get_mut(idx) {
+                Some(width) => *width = (*width).max(cell.width()),
+                None => widths.push(cell.width()),
+            }
+        }
+    }
+
+    widths
+}
+
+/// Pads each cell to the width of its column, leaving the last one unpadded
+/// to avoid trailing whitespace.
+pub(super) fn align_row(cells: &[String], widths: &[usize]) -> String {
+    let columns = cells.len().min(widths.len());
+
+    cells
+        .iter()
+        .zip(widths)
+        .enumerate()
+        .map(|(idx, (cell, width))| match idx + 1 == columns {
+            true => cell.clone(),
+            false => format!("{}{}", cell, " ".repeat(width.saturating_sub(cell.width()))),
+        })
+        .collect::<Vec<String>>()
+        .join(COLUMN_SEPARATOR)
+}
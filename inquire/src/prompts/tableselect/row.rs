@@ -0,0 +1,81 @@
+use std::fmt::Display;
+
+/// Row displayed in a [`TableSelect`](crate::TableSelect) prompt, made of one cell per column.
+///
+/// It is implemented for vectors, arrays and tuples of up to six elements whose
+/// items implement [`Display`]. Implement it for your own types to display them
+/// as rows without converting them beforehand.
+///
+/// # Example
+///
+/// ```
+/// use inquire::TableRow;
+///
+/// struct Container {
+///     name: String,
+///     image: String,
+///     running: bool,
+/// }
+///
+/// impl TableRow for Container {
+///     fn cells(&self) -> Vec<String> {
+///         let status = match self.running {
+///             true => "running",
+///             false => "stopped",
+///         };
+///
+///         vec![self.name.clone(), self.image.clone(), status.to_string()]
+///     }
+/// }
+///
+/// let container = Container {
+///     name: String::from("web"),
+///     image: String::from("nginx:1.25"),
+///     running: true,
+/// };
+///
+/// assert_eq!(vec!["web", "nginx:1.25", "running"], container.cells());
+/// assert_eq!(vec!["db", "5432"], ("db", 5432).cells());
+/// ```
+pub trait TableRow {
+    /// Returns the content of each cell of the row, in column order.
+    fn cells(&self) -> Vec<String>;
+}
+
+impl<S> TableRow for Vec<S>
+where
+    S: Display,
+{
+    fn cells(&self) -> Vec<String> {
+        self.iter().map(ToString::to_string).collect()
+    }
+}
+
+impl<S, const N: usize> TableRow for [S; N]
+where
+    S: Display,
+{
+    fn cells(&self) -> Vec<String> {
+        self.iter().map(ToString::to_string).collect()
+    }
+}
+
+macro_rules! impl_table_row_for_tuple {
+    ($($name:ident: $idx:tt),+) => {
+        impl<$($name),+> TableRow for ($($name,)+)
+        where
+            $($name: Display),+
+        {
+            fn cells(&self) -> Vec<String> {
+                vec![$(self.$idx.to_string()),+]
+            }
+        }
+    };
+}
+
+impl_table_row_for_tuple!(A: 0);
+impl_table_row_for_tuple!(A: 0, B: 1);
+impl_table_row_for_tuple!(A: 0, B: 1, C: 2);
+impl_table_row_for_tuple!(A: 0, B: 1, C: 2, D: 3);
+impl_table_row_for_tuple!(A: 0, B: 1, C: 2, D: 3, E: 4);
+impl_table_row_for_tuple!(A: 0, B: 1, C: 2, D: 3, E: 4, F: 5);
@@ -0,0 +1,98 @@
+use crate::{
+    list_option::ListOption,
+    test::fake_backend,
+    ui::{Key, KeyModifiers},
+    InquireError, TableSelect,
+};
+
+use super::prompt::{align_row, column_widths};
+
+fn containers() -> Vec<[&'static str; 3]> {
+    vec![
+        ["web", "nginx:1.25", "running"],
+        ["db", "postgres:16", "running"],
+        ["cache", "redis:7", "exited"],
+    ]
+}
+
+fn cells(row: &[&str]) -> Vec<String> {
+    row.iter().map(|cell| String::from(*cell)).collect()
+}
+
+#[test]
+fn columns_are_aligned_by_widest_cell() {
+    let headers = cells(&["NAME", "IMAGE", "STATUS"]);
+    let rows = containers()
+        .iter()
+        .map(|row| cells(row))
+        .collect::<Vec<_>>();
+
+    let widths = column_widths(&headers, &rows);
+
+    assert_eq!(vec![5, 11, 7], widths);
+    assert_eq!("NAME   IMAGE        STATUS", align_row(&headers, &widths));
+    assert_eq!("web    nginx:1.25   running", align_row(&rows[0], &widths));
+    assert_eq!("cache  redis:7      exited", align_row(&rows[2], &widths));
+}
+
+#[test]
+fn rows_with_missing_cells_are_not_padded() {
+    let rows = vec![cells(&["a", "bb", "ccc"]), cells(&["dddd"])];
+
+    let widths = column_widths(&[], &rows);
+
+    assert_eq!(vec![4, 2, 3], widths);
+    assert_eq!("a     bb  ccc", align_row(&rows[0], &widths));
+    assert_eq!("dddd", align_row(&rows[1], &widths));
+}
+
+#[test]
+fn enter_selects_highlighted_row() {
+    let mut backend = fake_backend(vec![Key::Down(KeyModifiers::NONE), Key::Enter]);
+
+    let ans = TableSelect::new("Question", containers())
+        .with_headers(&["NAME", "IMAGE", "STATUS"])
+        .prompt_with_backend(&mut backend)
+        .unwrap();
+
+    assert_eq!(ListOption::new(1, ["db", "postgres:16", "running"]), ans);
+}
+
+#[test]
+fn filter_matches_any_column() {
+    let mut backend = fake_backend(vec![
+        Key::Char('e', KeyModifiers::NONE),
+        Key::Char('x', KeyModifiers::NONE),
+        Key::Char('i', KeyModifiers::NONE),
+        Key::Char('t', KeyModifiers::NONE),
+        Key::Enter,
+    ]);
+
+    let ans = TableSelect::new("Question", containers())
+        .prompt_with_backend(&mut backend)
+        .unwrap();
+
+    assert_eq!(ListOption::new(2, ["cache", "redis:7", "exited"]), ans);
+}
+
+#[test]
+fn rows_can_be_tuples() {
+    let mut backend = fake_backend(vec![Key::End, Key::Enter]);
+
+    let ans = TableSelect::new("Question", vec![("http", 80), ("https", 443)])
+        .with_headers(&["SERVICE", "PORT"])
+        .prompt_with_backend(&mut backend)
+        .unwrap();
+
+    assert_eq!(ListOption::new(1, ("https", 443)), ans);
+}
+
+#[test]
+fn empty_options_are_rejected() {
+    let mut backend = fake_backend(vec![Key::Enter]);
+
+    let ans =
+        TableSelect::new("Question", Vec::<[&str; 2]>::new()).prompt_with_backend(&mut backend);
+
+    assert!(matches!(ans, Err(InquireError::InvalidConfiguration(_))));
+}
@@ -0,0 +1,57 @@
+use crate::{
+    ui::{Key, KeyModifiers},
+    InnerAction, InputAction,
+};
+
+use super::config::TagsConfig;
+
+/// Set of actions for a TagsPrompt.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+#[allow(clippy::enum_variant_names)]
+pub enum TagsPromptAction {
+    /// Action on the value text input handler.
+    ///
+    /// Deleting backwards from an empty input removes the last tag.
+    ValueInput(InputAction),
+    /// Adds the current input, or the highlighted suggestion, as a new tag.
+    AddTag,
+    /// When a suggestion list exists, moves the cursor to the option above.
+    MoveToSuggestionAbove,
+    /// When a suggestion list exists, moves the cursor to the option below.
+    MoveToSuggestionBelow,
+    /// When a suggestion list exists, moves the cursor to the page above.
+    MoveToSuggestionPageUp,
+    /// When a suggestion list exists, moves the cursor to the page below.
+    MoveToSuggestionPageDown,
+    /// When a suggestion list exists, autocompletes the text input with the current suggestion.
+    UseCurrentSuggestion,
+}
+
+impl InnerAction for TagsPromptAction {
+    type Config = TagsConfig;
+
+    fn from_key(key: Key, config: &TagsConfig) -> Option<Self> {
+        let action = match key {
+            Key::Char(c, KeyModifiers::NONE) if config.separators.contains(&c) => Self::AddTag,
+
+            Key::Up(KeyModifiers::NONE) | Key::Char('p', KeyModifiers::CONTROL) => {
+                Self::MoveToSuggestionAbove
+            }
+            Key::PageUp(_) => Self::MoveToSuggestionPageUp,
+
+            Key::Down(KeyModifiers::NONE) | Key::Char('n', KeyModifiers::CONTROL) => {
+                Self::MoveToSuggestionBelow
+            }
+            Key::PageDown(_) => Self::MoveToSuggestionPageDown,
+
+            Key::Tab => Self::UseCurrentSuggestion,
+
+            key => match InputAction::from_key(key, &()) {
+                Some(action) => Self::ValueInput(action),
+                None => return None,
+            },
+        };
+
+        Some(action)
+    }
+}
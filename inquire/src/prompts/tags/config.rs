@@ -0,0 +1,19 @@
+use crate::Tags;
+
+/// Configuration settings used in the execution of a TagsPrompt.
+#[derive(Clone, Debug)]
+pub struct TagsConfig {
+    /// Page size of the suggestion list, if it exists.
+    pub page_size: usize,
+    /// Characters that add the current input as a tag when typed.
+    pub separators: Vec<char>,
+}
+
+impl From<&Tags<'_>> for TagsConfig {
+    fn from(value: &Tags<'_>) -> Self {
+        Self {
+            page_size: value.page_size,
+            separators: value.separators.clone(),
+        }
+    }
+}
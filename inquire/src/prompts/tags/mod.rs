@@ -0,0 +1,289 @@
+mod action;
+mod config;
+mod prompt;
+#[cfg(test)]
+mod test;
+
+pub use action::*;
+
+use crate::{
+    config::get_configuration,
+    error::{InquireError, InquireResult},
+    formatter::TagsFormatter,
+    prompts::prompt::Prompt,
+    terminal::get_default_terminal,
+    ui::{Backend, RenderConfig, TagsBackend},
+    validator::StringValidator,
+    Autocomplete,
+};
+
+use self::prompt::TagsPrompt;
+
+/// Prompt suitable for when you need the user to enter a list of free-form values, such as labels, keywords or email recipients.
+///
+/// The user types a value and presses enter or one of the separator characters, a comma by default, to add it to the list. Each value is rendered as a chip next to the prompt message and backspace on an empty input removes the last one. Pressing enter on an empty input submits the list.
+///
+/// Like [Text](crate::Text) prompts, tags prompts can display suggestions provided by an [`Autocomplete`] implementation, which the user can navigate with the arrow keys and autocomplete with tab. Pressing enter or a separator with a suggestion highlighted adds the suggestion as a tag.
+///
+/// Values are trimmed and empty values are ignored. By default, a value that was already added is rejected with an error message and is not suggested again.
+///
+/// Tags prompts provide several options of configuration:
+///
+/// - **Prompt message**: Required when creating the prompt.
+/// - **Initial tags**: Tags already added when the prompt is first rendered.
+/// - **Separators**: Characters that add the current input as a tag when typed, a comma by default.
+/// - **Allow duplicates**: Whether the same value can be added more than once, false by default.
+/// - **Placeholder**: Short hint that describes the expected value of the input.
+/// - **Help message**: Message displayed at the line below the prompt.
+/// - **Autocompleter**: Custom autocompleter that provides suggestions for the value being typed.
+/// - **Validators**: Custom validators applied to each value before it is added, displaying an error message if the value does not pass the requirements.
+/// - **Formatter**: Custom formatter in case you need to pre-process the user input before showing it as the final answer.
+///   - Prints the tags separated by commas by default.
+/// - **Page size**: Number of suggestions displayed at once, 7 by default.
+///
+/// # Example
+///
+/// ```no_run
+/// use inquire::{validator::Validation, Tags};
+///
+/// let labels = Tags::new("Labels:")
+///     .with_initial_tags(&["bug"])
+///     .with_validator(|tag: &str| match tag.len() {
+///         0..=20 => Ok(Validation::Valid),
+///         _ => Ok(Validation::Invalid("Labels have at most 20 characters".into())),
+///     })
+///     .prompt();
+///
+/// match labels {
+///     Ok(labels) => println!("Labels: {}", labels.join(" ")),
+///     Err(_) => println!("The labels could not be processed"),
+/// }
+/// ```
+#[derive(Clone)]
+pub struct Tags<'a> {
+    /// Message to be presented to the user.
+    pub message: &'a str,
+
+    /// Tags already added when the prompt is first rendered.
+    pub initial_tags: Vec<String>,
+
+    /// Characters that add the current input as a tag when typed.
+    pub separators: Vec<char>,
+
+    /// Whether the same value can be added more than once.
+    pub allow_duplicates: bool,
+
+    /// Short hint that describes the expected value of the input.
+    pub placeholder: Option<&'a str>,
+
+    /// Help message to be presented to the user.
+    pub help_message: Option<&'a str>,
+
+    /// Function that formats the user input and presents it to the user as the final rendering of the prompt.
+    pub formatter: TagsFormatter<'a>,
+
+    /// Autocompleter responsible for handling suggestions and input completions.
+    pub autocompleter: Option<Box<dyn Autocomplete>>,
+
+    /// Collection of validators to apply to each value before it is added.
+    ///
+    /// Validators are executed in the order they are stored, stopping at and displaying to the user
+    /// only the first validation error that might appear.
+    ///
+    /// The possible error is displayed to the user one line above the prompt.
+    pub validators: Vec<Box<dyn StringValidator>>,
+
+    /// Page size of the suggestions displayed to the user, when applicable.
+    pub page_size: usize,
+
+    /// RenderConfig to apply to the rendered interface.
+    ///
+    /// Note: The default render config considers if the NO_COLOR environment variable
+    /// is set to decide whether to render the colored config or the empty one.
+    ///
+    /// When overriding the config in a prompt, NO_COLOR is no longer considered and your
+    /// config is treated as the only source of truth. If you want to customize colors
+    /// and still support NO_COLOR, you will have to do this on your end.
+    pub render_config: RenderConfig<'a>,
+}
+
+impl<'a> Tags<'a> {
+    /// Default formatter, which prints the tags separated by commas.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use inquire::Tags;
+    ///
+    /// let formatter = Tags::DEFAULT_FORMATTER;
+    /// assert_eq!(String::from("bug, ui"), formatter(&[String::from("bug"), String::from("ui")]));
+    /// ```
+    pub const DEFAULT_FORMATTER: TagsFormatter<'a> = &|tags| tags.join(", ");
+
+    /// Default page size, equal to the global default page size [config::DEFAULT_PAGE_SIZE]
+    pub const DEFAULT_PAGE_SIZE: usize = crate::config::DEFAULT_PAGE_SIZE;
+
+    /// Default separator characters, only the comma.
+    pub const DEFAULT_SEPARATORS: &'static [char] = &[','];
+
+    /// Default value of whether duplicated values are allowed.
+    pub const DEFAULT_ALLOW_DUPLICATES: bool = false;
+
+    /// Default validators added to the [Tags] prompt, none.
+    pub const DEFAULT_VALIDATORS: Vec<Box<dyn StringValidator>> = vec![];
+
+    /// Default help message.
+    pub const DEFAULT_HELP_MESSAGE: Option<&'a str> =
+        Some("enter to add, backspace to remove, enter on an empty input to submit");
+
+    /// Creates a [Tags] with the provided message, along with default configuration values.
+    pub fn new(message: &'a str) -> Self {
+        Self {
+            message,
+            initial_tags: vec![],
+            separators: Self::DEFAULT_SEPARATORS.to_vec(),
+            allow_duplicates: Self::DEFAULT_ALLOW_DUPLICATES,
+            placeholder: None,
+            help_message: Self::DEFAULT_HELP_MESSAGE,
+            formatter: Self::DEFAULT_FORMATTER,
+            autocompleter: None,
+            validators: Self::DEFAULT_VALIDATORS,
+            page_size: Self::DEFAULT_PAGE_SIZE,
+            render_config: get_configuration(),
+        }
+    }
+
+    /// Sets the tags already added when the prompt is first rendered.
+    pub fn with_initial_tags(mut self, tags: &[&str]) -> Self {
+        self.initial_tags = tags.iter().map(|tag| String::from(*tag)).collect();
+        self
+    }
+
+    /// Sets the characters that add the current input as a tag when typed.
+    ///
+    /// Enter always adds the current input as a tag, so an empty slice
+    /// means that only enter can be used.
+    pub fn with_separators(mut self, separators: &[char]) -> Self {
+        self.separators = separators.to_vec();
+        self
+    }
+
+    /// Sets whether the same value can be added more than once.
+    pub fn with_allow_duplicates(mut self, allow_duplicates: bool) -> Self {
+        self.allow_duplicates = allow_duplicates;
+        self
+    }
+
+    /// Sets the placeholder.
+    pub fn with_placeholder(mut self, placeholder: &'a str) -> Self {
+        self.placeholder = Some(placeholder);
+        self
+    }
+
+    /// Sets the help message of the prompt.
+    pub fn with_help_message(mut self, message: &'a str) -> Self {
+        self.help_message = Some(message);
+        self
+    }
+
+    /// Removes the set help message.
+    pub fn without_help_message(mut self) -> Self {
+        self.help_message = None;
+        self
+    }
+
+    /// Sets a new autocompleter
+    pub fn with_autocomplete<AC>(mut self, ac: AC) -> Self
+    where
+        AC: Autocomplete + 'static,
+    {
+        self.autocompleter = Some(Box::new(ac));
+        self
+    }
+
+    /// Sets the formatter.
+    pub fn with_formatter(mut self, formatter: TagsFormatter<'a>) -> Self {
+        self.formatter = formatter;
+        self
+    }
+
+    /// Sets the page size
+    pub fn with_page_size(mut self, page_size: usize) -> Self {
+        self.page_size = page_size;
+        self
+    }
+
+    /// Adds a validator to the collection of validators. You might want to use this feature
+    /// in case you need to require certain features from each value, such as a maximum length.
+    ///
+    /// Validators are executed in the order they are stored, stopping at and displaying to the user
+    /// only the first validation error that might appear.
+    ///
+    /// The possible error is displayed to the user one line above the prompt.
+    pub fn with_validator<V>(mut self, validator: V) -> Self
+    where
+        V: StringValidator + 'static,
+    {
+        self.validators.push(Box::new(validator));
+        self
+    }
+
+    /// Adds the validators to the collection of validators in the order they are given.
+    ///
+    /// Validators are executed in the order they are stored, stopping at and displaying to the user
+    /// only the first validation error that might appear.
+    ///
+    /// The possible error is displayed to the user one line above the prompt.
+    pub fn with_validators(mut self, validators: &[Box<dyn StringValidator>]) -> Self {
+        for validator in validators {
+            #[allow(suspicious_double_ref_op)]
+            self.validators.push(validator.clone());
+        }
+        self
+    }
+
+    /// Sets the provided color theme to this prompt.
+    ///
+    /// Note: The default render config considers if the NO_COLOR environment variable
+    /// is set to decide whether to render the colored config or the empty one.
+    ///
+    /// When overriding the config in a prompt, NO_COLOR is no longer considered and your
+    /// config is treated as the only source of truth. If you want to customize colors
+    /// and still support NO_COLOR, you will have to do this on your end.
+    pub fn with_render_config(mut self, render_config: RenderConfig<'a>) -> Self {
+        self.render_config = render_config;
+        self
+    }
+
+    /// Parses the provided behavioral and rendering options and prompts
+    /// the CLI user for input according to the defined rules.
+    ///
+    /// This method is intended for flows where the user skipping/cancelling
+    /// the prompt - by pressing ESC - is considered normal behavior. In this case,
+    /// it does not return `Err(InquireError::OperationCanceled)`, but `Ok(None)`.
+    ///
+    /// Meanwhile, if the user does submit an answer, the method wraps the return
+    /// type with `Some`.
+    pub fn prompt_skippable(self) -> InquireResult<Option<Vec<String>>> {
+        match self.prompt() {
+            Ok(answer) => Ok(Some(answer)),
+            Err(InquireError::OperationCanceled) => Ok(None),
+            Err(err) => Err(err),
+        }
+    }
+
+    /// Parses the provided behavioral and rendering options and prompts
+    /// the CLI user for input according to the defined rules.
+    pub fn prompt(self) -> InquireResult<Vec<String>> {
+        let (input_reader, terminal) = get_default_terminal()?;
+        let mut backend = Backend::new(input_reader, terminal, self.render_config)?;
+        self.prompt_with_backend(&mut backend)
+    }
+
+    pub(crate) fn prompt_with_backend<B: TagsBackend>(
+        self,
+        backend: &mut B,
+    ) -> InquireResult<Vec<String>> {
+        TagsPrompt::from(self).prompt(backend)
+    }
+}
@@ -0,0 +1,293 @@
+use std::cmp::min;
+
+use crate::{
+    autocompletion::{NoAutoCompletion, Replacement},
+    error::InquireResult,
+    formatter::TagsFormatter,
+    input::{Input, InputActionResult, LineDirection},
+    list_option::ListOption,
+    prompts::prompt::{ActionResult, Prompt},
+    ui::TagsBackend,
+    utils::paginate,
+    validator::{ErrorMessage, StringValidator, Validation},
+    Autocomplete, InputAction, InquireError, Tags,
+};
+
+use super::{action::TagsPromptAction, config::TagsConfig};
+
+pub struct TagsPrompt<'a> {
+    message: &'a str,
+    config: TagsConfig,
+    tags: Vec<String>,
+    placeholder: Option<&'a str>,
+    input: Input,
+    help_message: Option<&'a str>,
+    allow_duplicates: bool,
+    formatter: TagsFormatter<'a>,
+    validators: Vec<Box<dyn StringValidator>>,
+    error: Option<ErrorMessage>,
+    autocompleter: Box<dyn Autocomplete>,
+    suggested_options: Vec<String>,
+    suggestion_cursor_index: Option<usize>,
+}
+
+impl<'a> From<Tags<'a>> for TagsPrompt<'a> {
+    fn from(so: Tags<'a>) -> Self {
+        let mut tags: Vec<String> = vec![];
+        for tag in so.initial_tags.iter() {
+            if so.allow_duplicates || !tags.contains(tag) {
+                tags.push(tag.clone());
+            }
+        }
+
+        Self {
+            message: so.message,
+            config: (&so).into(),
+            tags,
+            placeholder: so.placeholder,
+            input: Self::new_input(so.placeholder),
+            help_message: so.help_message,
+            allow_duplicates: so.allow_duplicates,
+            formatter: so.formatter,
+            validators: so.validators,
+            error: None,
+            autocompleter: so
+                .autocompleter
+                .unwrap_or_else(|| Box::<NoAutoCompletion>::default()),
+            suggested_options: vec![],
+            suggestion_cursor_index: None,
+        }
+    }
+}
+
+impl<'a> TagsPrompt<'a> {
+    fn new_input(placeholder: Option<&str>) -> Input {
+        match placeholder {
+            Some(placeholder) => Input::new().with_placeholder(placeholder),
+            None => Input::new(),
+        }
+    }
+
+    fn update_suggestions(&mut self) -> InquireResult<()> {
+        let mut suggestions = self.autocompleter.get_suggestions(self.input.content())?;
+
+        if !self.allow_duplicates {
+            suggestions.retain(|suggestion| !self.tags.contains(suggestion));
+        }
+
+        self.suggested_options = suggestions;
+        self.suggestion_cursor_index = None;
+
+        Ok(())
+    }
+
+    fn get_highlighted_suggestion(&self) -> Option<&str> {
+        self.suggestion_cursor_index
+            .and_then(|cursor| self.suggested_options.get(cursor))
+            .map(String::as_str)
+    }
+
+    fn move_cursor_up(&mut self, qty: usize) -> ActionResult {
+        let new_cursor_index = match self.suggestion_cursor_index {
+            None => None,
+            Some(index) if index < qty => None,
+            Some(index) => Some(index.saturating_sub(qty)),
+        };
+
+        self.update_suggestion_cursor_pos(new_cursor_index)
+    }
+
+    fn move_cursor_down(&mut self, qty: usize) -> ActionResult {
+        let last_index = self.suggested_options.len().saturating_sub(1);
+
+        let new_cursor_index = if self.suggested_options.is_empty() {
+            None
+        } else {
+            match self.suggestion_cursor_index {
+                None if qty == 0 => None,
+                None => Some(min(qty.saturating_sub(1), last_index)),
+                Some(index) => Some(min(index.saturating_add(qty), last_index)),
+            }
+        };
+
+        self.update_suggestion_cursor_pos(new_cursor_index)
+    }
+
+    fn update_suggestion_cursor_pos(&mut self, new_position: Option<usize>) -> ActionResult {
+        if new_position != self.suggestion_cursor_index {
+            self.suggestion_cursor_index = new_position;
+            ActionResult::NeedsRedraw
+        } else {
+            ActionResult::Clean
+        }
+    }
+
+    fn use_current_suggestion(&mut self) -> InquireResult<ActionResult> {
+        let suggestion = self.get_highlighted_suggestion().map(|s| s.to_owned());
+        match self
+            .autocompleter
+            .get_completion(self.input.content(), suggestion)?
+        {
+            Replacement::Some(value) => {
+                self.input = Input::new_with(value);
+                self.update_suggestions()?;
+                Ok(ActionResult::NeedsRedraw)
+            }
+            Replacement::None => Ok(ActionResult::Clean),
+        }
+    }
+
+    /// The highlighted suggestion takes precedence over the typed input,
+    /// just like in [Text](crate::Text) prompts.
+    fn get_current_tag(&self) -> String {
+        self.get_highlighted_suggestion()
+            .unwrap_or_else(|| self.input.content())
+            .trim()
+            .to_owned()
+    }
+
+    fn validate_tag(&self, tag: &str) -> InquireResult<Validation> {
+        if !self.allow_duplicates && self.tags.iter().any(|t| t == tag) {
+            return Ok(Validation::Invalid(
+                format!("'{tag}' was already added").into(),
+            ));
+        }
+
+        for validator in &self.validators {
+            match validator.validate(tag) {
+                Ok(Validation::Valid) => {}
+                Ok(Validation::Invalid(msg)) => return Ok(Validation::Invalid(msg)),
+                Err(err) => return Err(InquireError::Custom(err)),
+            }
+        }
+
+        Ok(Validation::Valid)
+    }
+
+    fn add_current_tag(&mut self) -> InquireResult<ActionResult> {
+        let tag = self.get_current_tag();
+
+        if tag.is_empty() {
+            return Ok(ActionResult::Clean);
+        }
+
+        match self.validate_tag(&tag)? {
+            Validation::Valid => {
+                self.tags.push(tag);
+                self.input = Self::new_input(self.placeholder);
+                self.error = None;
+                self.update_suggestions()?;
+            }
+            Validation::Invalid(msg) => self.error = Some(msg),
+        }
+
+        Ok(ActionResult::NeedsRedraw)
+    }
+
+    fn remove_last_tag(&mut self) -> InquireResult<ActionResult> {
+        if self.tags.pop().is_none() {
+            return Ok(ActionResult::Clean);
+        }
+
+        self.error = None;
+        self.update_suggestions()?;
+
+        Ok(ActionResult::NeedsRedraw)
+    }
+}
+
+impl<'a, Backend> Prompt<Backend> for TagsPrompt<'a>
+where
+    Backend: TagsBackend,
+{
+    type Config = TagsConfig;
+    type InnerAction = TagsPromptAction;
+    type Output = Vec<String>;
+
+    fn message(&self) -> &str {
+        self.message
+    }
+
+    fn config(&self) -> &TagsConfig {
+        &self.config
+    }
+
+    fn format_answer(&self, answer: &Vec<String>) -> String {
+        (self.formatter)(answer)
+    }
+
+    fn setup(&mut self) -> InquireResult<()> {
+        self.update_suggestions()
+    }
+
+    fn submit(&mut self) -> InquireResult<Option<Vec<String>>> {
+        // enter adds the pending input as a tag, the prompt is only
+        // submitted by pressing enter on an empty input
+        if !self.get_current_tag().is_empty() {
+            self.add_current_tag()?;
+            return Ok(None);
+        }
+
+        Ok(Some(std::mem::take(&mut self.tags)))
+    }
+
+    fn handle(&mut self, action: TagsPromptAction) -> InquireResult<ActionResult> {
+        let result = match action {
+            TagsPromptAction::ValueInput(InputAction::Delete(_, LineDirection::Left))
+                if self.input.content().is_empty() =>
+            {
+                self.remove_last_tag()?
+            }
+            TagsPromptAction::ValueInput(input_action) => {
+                let result = self.input.handle(input_action);
+
+                if let InputActionResult::ContentChanged = result {
+                    self.update_suggestions()?;
+                }
+
+                result.into()
+            }
+            TagsPromptAction::AddTag => self.add_current_tag()?,
+            TagsPromptAction::MoveToSuggestionAbove => self.move_cursor_up(1),
+            TagsPromptAction::MoveToSuggestionBelow => self.move_cursor_down(1),
+            TagsPromptAction::MoveToSuggestionPageUp => self.move_cursor_up(self.config.page_size),
+            TagsPromptAction::MoveToSuggestionPageDown => {
+                self.move_cursor_down(self.config.page_size)
+            }
+            TagsPromptAction::UseCurrentSuggestion => self.use_current_suggestion()?,
+        };
+
+        Ok(result)
+    }
+
+    fn render(&self, backend: &mut Backend) -> InquireResult<()> {
+        let prompt = &self.message;
+
+        if let Some(err) = &self.error {
+            backend.render_error_message(err)?;
+        }
+
+        backend.render_tags_prompt(prompt, &self.tags, &self.input)?;
+
+        let choices = self
+            .suggested_options
+            .iter()
+            .enumerate()
+            .map(|(i, val)| ListOption::new(i, val.as_ref()))
+            .collect::<Vec<ListOption<&str>>>();
+
+        let page = paginate(
+            self.config.page_size,
+            &choices,
+            self.suggestion_cursor_index,
+        );
+
+        backend.render_suggestions(page)?;
+
+        if let Some(message) = self.help_message {
+            backend.render_help_message(message)?;
+        }
+
+        Ok(())
+    }
+}
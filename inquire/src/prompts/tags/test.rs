@@ -0,0 +1,160 @@
+use crate::{
+    error::InquireResult,
+    ui::{
+        test::{FakeBackend, Token},
+        Key, KeyModifiers,
+    },
+    validator::{ErrorMessage, Validation},
+    Tags,
+};
+
+fn keys(steps: &[&str]) -> Vec<Key> {
+    let mut keys = vec![];
+    for step in steps {
+        keys.extend(Key::char_keys_from_str(step));
+        keys.push(Key::Enter);
+    }
+    keys
+}
+
+#[test]
+fn values_are_added_with_enter_and_separators() -> InquireResult<()> {
+    let mut input = Key::char_keys_from_str("bug, ui,");
+    input.extend(keys(&["docs", ""]));
+    let mut backend = FakeBackend::new(input);
+
+    let ans = Tags::new("Labels").prompt_with_backend(&mut backend)?;
+
+    assert_eq!(vec!["bug", "ui", "docs"], ans);
+    assert!(backend
+        .frames()
+        .last()
+        .unwrap()
+        .has_token(&Token::AnsweredPrompt(
+            "Labels".into(),
+            "bug, ui, docs".into()
+        )));
+
+    Ok(())
+}
+
+#[test]
+fn enter_on_empty_input_submits_initial_tags() -> InquireResult<()> {
+    let mut backend = FakeBackend::new(vec![Key::Enter]);
+
+    let ans = Tags::new("Labels")
+        .with_initial_tags(&["bug", "ui", "bug"])
+        .prompt_with_backend(&mut backend)?;
+
+    assert_eq!(vec!["bug", "ui"], ans);
+    assert!(backend.frames()[0].has_token(&Token::Tags(vec!["bug".into(), "ui".into()])));
+
+    Ok(())
+}
+
+#[test]
+fn backspace_on_empty_input_removes_last_tag() -> InquireResult<()> {
+    let mut input = keys(&["bug", "ui"]);
+    input.push(Key::Backspace);
+    input.push(Key::Enter);
+    let mut backend = FakeBackend::new(input);
+
+    let ans = Tags::new("Labels").prompt_with_backend(&mut backend)?;
+
+    assert_eq!(vec!["bug"], ans);
+
+    Ok(())
+}
+
+#[test]
+fn duplicates_are_rejected() -> InquireResult<()> {
+    let mut input = keys(&["bug", "bug"]);
+    input.extend([Key::Backspace, Key::Backspace, Key::Backspace, Key::Enter]);
+    let mut backend = FakeBackend::new(input);
+
+    let ans = Tags::new("Labels").prompt_with_backend(&mut backend)?;
+
+    assert_eq!(vec!["bug"], ans);
+    assert!(
+        backend.frames()[8].has_token(&Token::ErrorMessage(ErrorMessage::Custom(
+            "'bug' was already added".into()
+        )))
+    );
+
+    let mut backend = FakeBackend::new(keys(&["bug", "bug", ""]));
+
+    let ans = Tags::new("Labels")
+        .with_allow_duplicates(true)
+        .prompt_with_backend(&mut backend)?;
+
+    assert_eq!(vec!["bug", "bug"], ans);
+
+    Ok(())
+}
+
+#[test]
+fn highlighted_suggestion_is_added() -> InquireResult<()> {
+    let suggester = |input: &str| {
+        Ok(["bug", "build", "ui"]
+            .iter()
+            .filter(|s| s.starts_with(input))
+            .map(|s| String::from(*s))
+            .collect())
+    };
+
+    let mut input = Key::char_keys_from_str("b");
+    input.push(Key::Down(KeyModifiers::NONE));
+    input.push(Key::Down(KeyModifiers::NONE));
+    input.push(Key::Enter);
+    input.extend(Key::char_keys_from_str("b"));
+    input.push(Key::Down(KeyModifiers::NONE));
+    input.push(Key::Enter);
+    input.push(Key::Enter);
+    let mut backend = FakeBackend::new(input);
+
+    let ans = Tags::new("Labels")
+        .with_autocomplete(suggester)
+        .prompt_with_backend(&mut backend)?;
+
+    assert_eq!(vec!["build", "bug"], ans);
+    assert!(backend.frames()[5].has_token(&Token::Suggestions {
+        options: vec!["bug".into()],
+        cursor: None,
+    }));
+
+    Ok(())
+}
+
+#[test]
+fn validators_are_applied_to_each_tag() -> InquireResult<()> {
+    let mut input = keys(&["toolong"]);
+    input.extend([
+        Key::Backspace,
+        Key::Backspace,
+        Key::Backspace,
+        Key::Enter,
+        Key::Enter,
+    ]);
+    let mut backend = FakeBackend::new(input);
+
+    let ans = Tags::new("Labels")
+        .with_validator(|tag: &str| {
+            if tag.len() > 5 {
+                Ok(Validation::Invalid(
+                    "Labels have at most 5 characters".into(),
+                ))
+            } else {
+                Ok(Validation::Valid)
+            }
+        })
+        .prompt_with_backend(&mut backend)?;
+
+    assert_eq!(vec!["tool"], ans);
+    assert!(
+        backend.frames()[8].has_token(&Token::ErrorMessage(ErrorMessage::Custom(
+            "Labels have at most 5 characters".into()
+        )))
+    );
+
+    Ok(())
+}
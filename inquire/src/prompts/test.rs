@@ -1,23 +1,74 @@
+use std::{collections::VecDeque, time::Duration};
+
 use crate::{
     terminal::crossterm::CrosstermTerminal,
-    ui::{Backend, InputReader, Key, RenderConfig},
+    ui::{Backend, InputEvent, InputReader, Key, RenderConfig},
 };
 
 impl<T> InputReader for T
 where
-    T: Iterator<Item = Key>,
+    T: Iterator<Item = InputEvent>,
 {
-    fn read_key(&mut self) -> crate::error::InquireResult<Key> {
-        let key = self.next();
+    fn read_event(&mut self) -> crate::error::InquireResult<InputEvent> {
+        let event = self.next();
 
-        match key {
-            Some(key) => Ok(key),
+        match event {
+            Some(event) => Ok(event),
             None => panic!("EOF"),
         }
     }
+
+    // the events are read right away, as if the keys were pressed in time
+    fn supports_timeout(&self) -> bool {
+        true
+    }
 }
 
 pub fn fake_backend(input: Vec<Key>) -> Backend<'static, impl InputReader, CrosstermTerminal> {
+    fake_backend_with_events(input.into_iter().map(InputEvent::Key).collect())
+}
+
+/// Backend reading the events, such as pasted text, instead of key presses only.
+pub fn fake_backend_with_events(
+    input: Vec<InputEvent>,
+) -> Backend<'static, impl InputReader, CrosstermTerminal> {
     let output = CrosstermTerminal::new_in_memory_output();
     Backend::new(input.into_iter(), output, RenderConfig::default()).unwrap()
 }
+
+/// Reader simulating the user not pressing any key once its events run out,
+/// each poll waiting for the whole timeout as a terminal would.
+pub struct TimedReader(VecDeque<InputEvent>);
+
+impl InputReader for TimedReader {
+    fn read_event(&mut self) -> crate::error::InquireResult<InputEvent> {
+        match self.0.pop_front() {
+            Some(event) => Ok(event),
+            None => panic!("EOF"),
+        }
+    }
+
+    fn poll_event(&mut self, timeout: Duration) -> crate::error::InquireResult<Option<InputEvent>> {
+        let event = self.0.pop_front();
+
+        if event.is_none() {
+            std::thread::sleep(timeout);
+        }
+
+        Ok(event)
+    }
+
+    fn supports_timeout(&self) -> bool {
+        true
+    }
+}
+
+/// Backend whose reader times out once the keys run out, driving the ticks
+/// of the prompts changing over time.
+pub fn fake_backend_with_timeouts(
+    input: Vec<Key>,
+) -> Backend<'static, TimedReader, CrosstermTerminal> {
+    let reader = TimedReader(input.into_iter().map(InputEvent::Key).collect());
+    let output = CrosstermTerminal::new_in_memory_output();
+    Backend::new(reader, output, RenderConfig::default()).unwrap()
+}
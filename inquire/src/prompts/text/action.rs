@@ -1,4 +1,5 @@
 use crate::{
+    input::{LineDirection, Magnitude},
     ui::{Key, KeyModifiers},
     InnerAction, InputAction,
 };
@@ -21,13 +22,59 @@ pub enum TextPromptAction {
     MoveToSuggestionPageDown,
     /// When a suggestion list exists, autocompletes the text input with the current suggestion.
     UseCurrentSuggestion,
+    /// Reverts the last edit made to the text input.
+    Undo,
+    /// Reapplies the last edit reverted.
+    Redo,
+    /// With vim mode enabled, switches to normal mode.
+    EnterNormalMode,
+    /// With vim mode enabled, switches back to insert mode before the cursor.
+    EnterInsertMode,
+    /// With vim mode enabled, switches back to insert mode after the cursor.
+    EnterInsertModeAfterCursor,
+    /// With vim mode enabled, deletes the whole input when pressed twice in a row.
+    DeleteLine,
+}
+
+impl TextPromptAction {
+    /// Derives an action from a key pressed in vim normal mode, where
+    /// characters are not typed into the input.
+    pub(super) fn from_normal_mode_key(key: Key) -> Option<Self> {
+        let c = match key {
+            Key::Char(c, KeyModifiers::NONE | KeyModifiers::SHIFT) => c,
+            _ => return None,
+        };
+
+        let action = match c {
+            'h' => InputAction::MoveCursor(Magnitude::Char, LineDirection::Left),
+            'l' => InputAction::MoveCursor(Magnitude::Char, LineDirection::Right),
+            'b' => InputAction::MoveCursor(Magnitude::Word, LineDirection::Left),
+            'w' => InputAction::MoveCursor(Magnitude::Word, LineDirection::Right),
+            '0' => InputAction::MoveCursor(Magnitude::Line, LineDirection::Left),
+            '$' => InputAction::MoveCursor(Magnitude::Line, LineDirection::Right),
+            'x' => InputAction::Delete(Magnitude::Char, LineDirection::Right),
+            'i' => return Some(Self::EnterInsertMode),
+            'a' => return Some(Self::EnterInsertModeAfterCursor),
+            'd' => return Some(Self::DeleteLine),
+            _ => return None,
+        };
+
+        Some(Self::ValueInput(action))
+    }
 }
 
 impl InnerAction for TextPromptAction {
     type Config = TextConfig;
 
-    fn from_key(key: Key, _config: &TextConfig) -> Option<Self> {
+    fn from_key(key: Key, config: &TextConfig) -> Option<Self> {
+        if let Some(action) = config.keymap.yank_action(key) {
+            return Some(Self::ValueInput(action));
+        }
+
         let action = match key {
+            key if key == config.undo_keys.undo => Self::Undo,
+            key if key == config.undo_keys.redo => Self::Redo,
+
             Key::Up(KeyModifiers::NONE) | Key::Char('p', KeyModifiers::CONTROL) => {
                 Self::MoveToSuggestionAbove
             }
@@ -40,7 +87,7 @@ impl InnerAction for TextPromptAction {
 
             Key::Tab => Self::UseCurrentSuggestion,
 
-            key => match InputAction::from_key(key, &()) {
+            key => match config.keymap.action(key) {
                 Some(action) => Self::ValueInput(action),
                 None => return None,
             },
@@ -1,16 +1,43 @@
-use crate::Text;
+use crate::{InputKeymap, LengthUnit, Text, UndoKeys};
 
 /// Configuration settings used in the execution of a TextPrompt.
 #[derive(Copy, Clone, Debug)]
 pub struct TextConfig {
     /// Page size of the suggestion list, if it exists.
     pub page_size: usize,
+    /// Whether the best suggestion is displayed as ghost text instead of a list.
+    pub ghost_text: bool,
+    /// Whether the suggestions are listed as a dropdown anchored under the cursor.
+    pub dropdown: bool,
+    /// Maximum length displayed along with the current length of the input.
+    pub length_counter: Option<usize>,
+    /// Length beyond which typed characters are rejected.
+    pub max_length: Option<usize>,
+    /// Unit in which the length of the input is counted.
+    pub length_unit: LengthUnit,
+    /// Whether the input is edited with modal key bindings inspired by vim.
+    pub vim_mode: bool,
+    /// Character separating the multiple values of the input.
+    pub delimiter: Option<char>,
+    /// Key bindings to undo and redo the edits made to the input.
+    pub undo_keys: UndoKeys,
+    /// Preset of key bindings used to edit the input.
+    pub keymap: InputKeymap,
 }
 
 impl From<&Text<'_>> for TextConfig {
     fn from(value: &Text<'_>) -> Self {
         Self {
             page_size: value.page_size,
+            ghost_text: value.ghost_text,
+            dropdown: value.dropdown,
+            length_counter: value.length_counter,
+            max_length: value.max_length,
+            length_unit: value.length_unit,
+            vim_mode: value.vim_mode,
+            delimiter: value.delimiter,
+            undo_keys: value.undo_keys,
+            keymap: value.keymap,
         }
     }
 }
@@ -10,17 +10,68 @@ pub use action::*;
 use crate::{
     autocompletion::Autocomplete,
     config::get_configuration,
+    default_value::DefaultChain,
     error::{InquireError, InquireResult},
     formatter::{StringFormatter, DEFAULT_STRING_FORMATTER},
+    history::History,
+    mask::{InputMask, MaskedAnswer},
     prompts::prompt::Prompt,
     terminal::get_default_terminal,
+    type_aliases::{Hinter, InputTransformer, OnChange},
     ui::{Backend, RenderConfig, TextBackend},
     validator::StringValidator,
+    InputKeymap, UndoKeys,
 };
 
+use unicode_segmentation::UnicodeSegmentation;
+
 use self::prompt::TextPrompt;
 
 const DEFAULT_HELP_MESSAGE_WITH_AC: &str = "↑↓ to move, tab to autocomplete, enter to submit";
+const DEFAULT_HELP_MESSAGE_WITH_GHOST_TEXT: &str = "→ or tab to complete, enter to submit";
+const DEFAULT_HELP_MESSAGE_WITH_DROPDOWN: &str = "↑↓ or tab to move, enter to submit";
+const VIM_NORMAL_MODE_INDICATOR: &str = "NORMAL";
+
+/// Unit in which the length of the input of a [`Text`] prompt is counted.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub enum LengthUnit {
+    /// Characters as perceived by the user, so that an emoji or an accented
+    /// letter count as one.
+    #[default]
+    Characters,
+    /// Bytes of the input encoded in UTF-8, e.g. for fields stored in
+    /// columns of a fixed size.
+    Bytes,
+}
+
+impl LengthUnit {
+    /// Length of the text in this unit.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use inquire::LengthUnit;
+    ///
+    /// assert_eq!(4, LengthUnit::Characters.measure("café"));
+    /// assert_eq!(5, LengthUnit::Bytes.measure("café"));
+    /// ```
+    pub fn measure(self, text: &str) -> usize {
+        match self {
+            Self::Characters => text.graphemes(true).count(),
+            Self::Bytes => text.len(),
+        }
+    }
+}
+
+/// Values of an input holding delimited values, trimmed, the empty ones
+/// being skipped.
+fn split_values(input: &str, delimiter: char) -> Vec<&str> {
+    input
+        .split(delimiter)
+        .map(str::trim)
+        .filter(|value| !value.is_empty())
+        .collect()
+}
 
 /// Standard text prompt that returns the user string input.
 ///
@@ -32,11 +83,26 @@ const DEFAULT_HELP_MESSAGE_WITH_AC: &str = "↑↓ to move, tab to autocomplete,
 /// - **Prompt message**: Main message when prompting the user for input, `"What is your name?"` in the example below.
 /// - **Help message**: Message displayed at the line below the prompt.
 /// - **Default value**: Default value returned when the user submits an empty response.
+/// - **Default chain**: Sources of the default value tried in order when the prompt is displayed, such as an environment variable, a key of a configuration file or the last answer, optionally displaying the source that provided it. The explicit default value takes precedence. See [`DefaultChain`].
 /// - **Initial value**: Initial value of the prompt's text input, in case you want to display the prompt with something already filled in.
 /// - **Placeholder**: Short hint that describes the expected value of the input.
+/// - **Dynamic hints**: Functions computing the placeholder and the help message from the current input after every keystroke, e.g. to display the slug derived from a title as the user types it. They fall back to the static hints when returning `None`. See [`Hinter`].
+/// - **Mask**: Format of structured inputs, such as `##/##/####`, whose literals are inserted automatically and whose slots only accept matching characters. An incomplete input can not be submitted and the mask with its slots displayed as `_` is the default placeholder. See [`InputMask`] for more details.
+/// - **Delimiter**: Character separating the multiple values of the input, such as `,` in `a.com, b.com`. The values are displayed below the input as they are typed, checked one by one by the validators and returned by [`Text::prompt_values`]. Disabled by default.
 /// - **Validators**: Custom validators to the user's input, displaying an error message if the input does not pass the requirements.
+/// - **Transformer**: Function applied to the characters typed or pasted into the input, so that the displayed and returned value are always normalized, e.g. in lowercase. The suggestions and the default value are transformed as well, while the initial value and the history entries are not. See [`InputTransformer`].
+/// - **On change hook**: Function called with the current input whenever it changes, so that the application can react to it live, e.g. updating a preview. See [`OnChange`].
 /// - **Formatter**: Custom formatter in case you need to pre-process the user input before showing it as the final answer.
 /// - **Suggester**: Custom function that returns a list of input suggestions based on the current text input. See more on "Autocomplete" below.
+/// - **History**: Store of the previous answers, which the user can cycle through with the up and down arrows, like in a shell, the input typed so far being restored when going back down past the most recent one. The arrows move through the suggestions instead while they are listed. Non-empty answers are recorded in the history on submit. See [`History`] for more details.
+/// - **Undo keys**: Key bindings to undo and redo the edits made to the input, `Ctrl+z` and `Ctrl+y` by default. See [`UndoKeys`].
+/// - **Keymap**: Preset of key bindings used to edit the input, such as the GNU readline ones. See [`InputKeymap`].
+/// - **Ghost text flag**: Whether the first suggestion starting with the text input is displayed dimmed after the cursor, like in the fish shell, instead of listing the suggestions. Defaults to false.
+/// - **Dropdown flag**: Whether the suggestions are listed as a dropdown anchored under the cursor, highlighting the fragment matching the input, with `tab` cycling through them. See more on "Autocomplete" below. Defaults to false.
+/// - **Length counter**: Maximum length of the input displayed along with its current length after the input, e.g. `23/80`, switching to the error style once exceeded. Disabled by default.
+/// - **Maximum length**: Length beyond which typed characters are rejected, the input briefly flashing instead, and at which pasted text, completed suggestions and history entries are cut. Unlimited by default.
+/// - **Length unit**: Whether lengths are counted in characters or in bytes. See [`LengthUnit`]. Defaults to characters.
+/// - **Vim mode flag**: Whether the input is edited with modal key bindings inspired by vim. See more on "Vim mode" below. Defaults to false.
 ///
 /// ## Default behaviors
 ///
@@ -61,6 +127,23 @@ const DEFAULT_HELP_MESSAGE_WITH_AC: &str = "↑↓ to move, tab to autocomplete,
 ///
 /// Every time `get_suggestions` is called, the method returns the list of paths that match the user input. When the user presses the autocompletion hotkey, the `FilePathCompleter` checks whether there is any path selected from the list, if there is, it decides to replace the current text input for it. The interesting piece of functionality is that if there isn't a path selected from the list, the `FilePathCompleter` calculates the longest common prefix amongst all scanned paths and updates the text input to an unambiguous new value. Similar to how terminals work when traversing paths.
 ///
+/// With ghost text enabled, the rest of the first suggestion starting with the text input is displayed after the cursor, with the `ghost_text` style sheet, and pressing the right arrow at the end of the input or `tab` completes it.
+///
+/// With the dropdown enabled, the suggestions are listed below the input, starting at the column of the cursor, with the fragment matching the input highlighted with the `option_match` style sheet. Pressing `tab` cycles through them instead of autocompleting the input, and submitting while one is highlighted returns it. Ghost text takes precedence over the dropdown when both are enabled.
+///
+/// ## Vim mode
+///
+/// With vim mode enabled, the prompt starts in insert mode, where keys are handled as usual, and `esc` switches to normal mode instead of cancelling the prompt. In normal mode, which is displayed by a `[NORMAL]` indicator after the prompt message:
+///
+/// - `h` and `l` move the cursor by one character, `b` and `w` by one word, and `0` and `$` to the start and end of the input.
+/// - `x` deletes the character under the cursor and `dd` deletes the whole input.
+/// - `i` and `a` return to insert mode before or after the cursor.
+/// - `esc` cancels the prompt and `enter` submits it, as usual.
+///
+/// Other characters are not typed into the input.
+///
+/// Slow suggestions, such as the ones backed by network APIs, can be computed on a worker thread by wrapping the autocompleter in a [`BackgroundAutocomplete`](crate::autocompletion::BackgroundAutocomplete). While they are loading, the loading message is displayed below the previous suggestions.
+///
 /// # Example
 ///
 /// ```no_run
@@ -88,18 +171,47 @@ pub struct Text<'a> {
     /// Default value, returned when the user input is empty.
     pub default: Option<&'a str>,
 
+    /// Sources of the default value, used when no explicit default is set.
+    pub default_chain: Option<DefaultChain>,
+
     /// Short hint that describes the expected value of the input.
     pub placeholder: Option<&'a str>,
 
+    /// Function computing the placeholder from the current input, taking
+    /// precedence over the static placeholder.
+    pub dynamic_placeholder: Option<Hinter<'a>>,
+
+    /// Mask formatting the input while the user types.
+    pub mask: Option<InputMask>,
+
+    /// Character separating the multiple values of the input.
+    pub delimiter: Option<char>,
+
     /// Help message to be presented to the user.
     pub help_message: Option<&'a str>,
 
+    /// Function computing the help message from the current input, taking
+    /// precedence over the static help message.
+    pub dynamic_help_message: Option<Hinter<'a>>,
+
     /// Function that formats the user input and presents it to the user as the final rendering of the prompt.
     pub formatter: StringFormatter<'a>,
 
     /// Autocompleter responsible for handling suggestions and input completions.
     pub autocompleter: Option<Box<dyn Autocomplete>>,
 
+    /// Message displayed below the suggestions while they are being loaded.
+    pub loading_message: &'a str,
+
+    /// Store of the previous answers, browsed with the up and down arrows.
+    pub history: Option<Box<dyn History>>,
+
+    /// Key bindings to undo and redo the edits made to the input.
+    pub undo_keys: UndoKeys,
+
+    /// Preset of key bindings used to edit the input, in addition to the default ones.
+    pub keymap: InputKeymap,
+
     /// Collection of validators to apply to the user input.
     ///
     /// Validators are executed in the order they are stored, stopping at and displaying to the user
@@ -108,9 +220,36 @@ pub struct Text<'a> {
     /// The possible error is displayed to the user one line above the prompt.
     pub validators: Vec<Box<dyn StringValidator>>,
 
+    /// Function called with the current input whenever it changes.
+    pub on_change: Option<OnChange<'a>>,
+
+    /// Function applied to the text typed or pasted into the input, as well
+    /// as to the suggestions and the default value.
+    pub transformer: Option<InputTransformer<'a>>,
+
     /// Page size of the suggestions displayed to the user, when applicable.
     pub page_size: usize,
 
+    /// Whether the best suggestion is displayed as ghost text after the
+    /// cursor, instead of listing the suggestions.
+    pub ghost_text: bool,
+
+    /// Whether the suggestions are listed as a dropdown anchored under the
+    /// cursor, cycled through with `tab`.
+    pub dropdown: bool,
+
+    /// Maximum length displayed along with the current length of the input.
+    pub length_counter: Option<usize>,
+
+    /// Length beyond which the input is not extended.
+    pub max_length: Option<usize>,
+
+    /// Unit in which the length of the input is counted.
+    pub length_unit: LengthUnit,
+
+    /// Whether the input is edited with modal key bindings inspired by vim.
+    pub vim_mode: bool,
+
     /// RenderConfig to apply to the rendered interface.
     ///
     /// Note: The default render config considers if the NO_COLOR environment variable
@@ -135,18 +274,47 @@ impl<'a> Text<'a> {
     /// Default help message.
     pub const DEFAULT_HELP_MESSAGE: Option<&'a str> = None;
 
+    /// Default value of the ghost text flag, false.
+    pub const DEFAULT_GHOST_TEXT: bool = false;
+
+    /// Default value of the dropdown flag, false.
+    pub const DEFAULT_DROPDOWN: bool = false;
+
+    /// Default value of the vim mode flag, false.
+    pub const DEFAULT_VIM_MODE: bool = false;
+
+    /// Default message displayed while the suggestions are being loaded.
+    pub const DEFAULT_LOADING_MESSAGE: &'a str = "loading…";
+
     /// Creates a [Text] with the provided message and default options.
     pub fn new(message: &'a str) -> Self {
         Self {
             message,
             placeholder: None,
+            dynamic_placeholder: None,
             initial_value: None,
+            mask: None,
+            delimiter: None,
             default: None,
+            default_chain: None,
             help_message: Self::DEFAULT_HELP_MESSAGE,
+            dynamic_help_message: None,
             validators: Self::DEFAULT_VALIDATORS,
+            on_change: None,
+            transformer: None,
             formatter: Self::DEFAULT_FORMATTER,
             page_size: Self::DEFAULT_PAGE_SIZE,
+            ghost_text: Self::DEFAULT_GHOST_TEXT,
+            dropdown: Self::DEFAULT_DROPDOWN,
+            length_counter: None,
+            max_length: None,
+            length_unit: LengthUnit::default(),
+            vim_mode: Self::DEFAULT_VIM_MODE,
             autocompleter: None,
+            loading_message: Self::DEFAULT_LOADING_MESSAGE,
+            history: None,
+            undo_keys: UndoKeys::default(),
+            keymap: InputKeymap::default(),
             render_config: get_configuration(),
         }
     }
@@ -157,6 +325,27 @@ impl<'a> Text<'a> {
         self
     }
 
+    /// Sets the function computing the help message from the current input,
+    /// which falls back to the static help message when returning `None`.
+    pub fn with_dynamic_help_message(mut self, hinter: Hinter<'a>) -> Self {
+        self.dynamic_help_message = Some(hinter);
+        self
+    }
+
+    /// Sets the mask formatting the input while the user types, parsed from
+    /// a specification such as `##/##/####`. See [`InputMask`].
+    pub fn with_mask(mut self, mask: &str) -> Self {
+        self.mask = Some(InputMask::new(mask));
+        self
+    }
+
+    /// Sets the character separating the multiple values of the input, which
+    /// are then validated one by one. See [`Text::prompt_values`].
+    pub fn with_delimiter(mut self, delimiter: char) -> Self {
+        self.delimiter = Some(delimiter);
+        self
+    }
+
     /// Sets the initial value of the prompt's text input.
     ///
     /// If you want to set a default value for the prompt, returned when the user's submission is empty, see [`with_default`].
@@ -173,12 +362,31 @@ impl<'a> Text<'a> {
         self
     }
 
+    /// Sets the sources of the default value, tried in order when the prompt
+    /// is displayed. An explicit default set with [`Text::with_default`]
+    /// takes precedence over them.
+    pub fn with_default_chain(mut self, chain: DefaultChain) -> Self {
+        self.default_chain = Some(chain);
+        self
+    }
+
     /// Sets the placeholder.
     pub fn with_placeholder(mut self, placeholder: &'a str) -> Self {
         self.placeholder = Some(placeholder);
         self
     }
 
+    /// Sets the function computing the placeholder from the current input,
+    /// which falls back to the static placeholder when returning `None`.
+    ///
+    /// As the placeholder is only displayed while the input is empty, the
+    /// function is mostly useful for hints depending on the state of the
+    /// application, such as the answer to a previous prompt.
+    pub fn with_dynamic_placeholder(mut self, hinter: Hinter<'a>) -> Self {
+        self.dynamic_placeholder = Some(hinter);
+        self
+    }
+
     /// Sets a new autocompleter
     pub fn with_autocomplete<AC>(mut self, ac: AC) -> Self
     where
@@ -188,6 +396,56 @@ impl<'a> Text<'a> {
         self
     }
 
+    /// Sets the message displayed below the suggestions while they are being
+    /// loaded, e.g. by a [`BackgroundAutocomplete`](crate::autocompletion::BackgroundAutocomplete).
+    pub fn with_loading_message(mut self, loading_message: &'a str) -> Self {
+        self.loading_message = loading_message;
+        self
+    }
+
+    /// Sets the store of the previous answers, browsed with the up and down
+    /// arrows and recorded on submit.
+    ///
+    /// The prompt owns the history, so pass an `Rc<RefCell<_>>` of it to see
+    /// the recorded answers afterwards.
+    pub fn with_history<H>(mut self, history: H) -> Self
+    where
+        H: History + 'static,
+    {
+        self.history = Some(Box::new(history));
+        self
+    }
+
+    /// Sets the key bindings to undo and redo the edits made to the input.
+    pub fn with_undo_keys(mut self, undo_keys: UndoKeys) -> Self {
+        self.undo_keys = undo_keys;
+        self
+    }
+
+    /// Sets the preset of key bindings used to edit the input, such as
+    /// [`InputKeymap::Readline`].
+    pub fn with_keymap(mut self, keymap: InputKeymap) -> Self {
+        self.keymap = keymap;
+        self
+    }
+
+    /// Sets the function called with the current input whenever it changes.
+    pub fn with_on_change(mut self, on_change: OnChange<'a>) -> Self {
+        self.on_change = Some(on_change);
+        self
+    }
+
+    /// Sets the function applied to the characters typed or pasted into the
+    /// input, which may replace each of them by any number of characters.
+    ///
+    /// The suggestions, whether completed or submitted, and the default
+    /// value, including the one provided by a [`DefaultChain`], are
+    /// transformed as well.
+    pub fn with_transformer(mut self, transformer: InputTransformer<'a>) -> Self {
+        self.transformer = Some(transformer);
+        self
+    }
+
     /// Sets the formatter.
     pub fn with_formatter(mut self, formatter: StringFormatter<'a>) -> Self {
         self.formatter = formatter;
@@ -200,6 +458,55 @@ impl<'a> Text<'a> {
         self
     }
 
+    /// Sets whether the best suggestion is displayed as ghost text after the
+    /// cursor, completed by pressing the right arrow or `tab`, instead of
+    /// listing the suggestions.
+    pub fn with_ghost_text(mut self, ghost_text: bool) -> Self {
+        self.ghost_text = ghost_text;
+        self
+    }
+
+    /// Sets whether the suggestions are listed as a dropdown anchored under
+    /// the cursor, highlighting the fragment matching the input, with `tab`
+    /// cycling through them instead of autocompleting the input.
+    pub fn with_dropdown(mut self, dropdown: bool) -> Self {
+        self.dropdown = dropdown;
+        self
+    }
+
+    /// Displays the length of the input along with the provided maximum after
+    /// the input, e.g. `23/80`, switching to the error style once exceeded.
+    ///
+    /// The maximum is not enforced, for which you can add a validator such as
+    /// [`max_length!`](crate::max_length).
+    pub fn with_length_counter(mut self, max: usize) -> Self {
+        self.length_counter = Some(max);
+        self
+    }
+
+    /// Sets the length beyond which typed characters are rejected, the input
+    /// briefly flashing instead.
+    ///
+    /// Pasted text, completed suggestions and history entries are cut at the
+    /// maximum length as well, while the initial value is kept whole.
+    pub fn with_max_length(mut self, max_length: usize) -> Self {
+        self.max_length = Some(max_length);
+        self
+    }
+
+    /// Sets the unit in which the length of the input is counted.
+    pub fn with_length_unit(mut self, length_unit: LengthUnit) -> Self {
+        self.length_unit = length_unit;
+        self
+    }
+
+    /// Sets whether the input is edited with modal key bindings inspired by
+    /// vim, `esc` switching to normal mode and `i` or `a` back to insert mode.
+    pub fn with_vim_mode(mut self, vim_mode: bool) -> Self {
+        self.vim_mode = vim_mode;
+        self
+    }
+
     /// Adds a validator to the collection of validators. You might want to use this feature
     /// in case you need to require certain features from the user's answer, such as
     /// defining a limit of characters.
@@ -270,6 +577,54 @@ impl<'a> Text<'a> {
         self.prompt_with_backend(&mut backend)
     }
 
+    /// Parses the provided behavioral and rendering options and prompts
+    /// the CLI user for input according to the defined rules.
+    ///
+    /// Returns the answer both with the mask applied and as typed by the
+    /// user, without the literals of the mask. Without a mask, both are the
+    /// same.
+    pub fn masked_prompt(self) -> InquireResult<MaskedAnswer> {
+        let mask = self.mask.clone();
+        let formatted = self.prompt()?;
+
+        let raw = match mask {
+            Some(mask) => mask.raw(&formatted),
+            None => formatted.clone(),
+        };
+
+        Ok(MaskedAnswer { raw, formatted })
+    }
+
+    /// Parses the provided behavioral and rendering options and prompts
+    /// the CLI user for input according to the defined rules.
+    ///
+    /// Returns the values of the answer split at the delimiter, trimmed, the
+    /// empty ones being skipped. Without a delimiter, the whole answer is the
+    /// only value.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use inquire::Text;
+    ///
+    /// let hosts = Text::new("Hosts:")
+    ///     .with_delimiter(',')
+    ///     .with_placeholder("db1.example.com, db2.example.com")
+    ///     .prompt_values();
+    /// ```
+    pub fn prompt_values(self) -> InquireResult<Vec<String>> {
+        let delimiter = self.delimiter;
+        let answer = self.prompt()?;
+
+        Ok(match delimiter {
+            Some(delimiter) => split_values(&answer, delimiter)
+                .into_iter()
+                .map(String::from)
+                .collect(),
+            None => vec![answer],
+        })
+    }
+
     pub(crate) fn prompt_with_backend<B: TextBackend>(
         self,
         backend: &mut B,
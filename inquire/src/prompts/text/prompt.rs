@@ -1,32 +1,71 @@
-use std::cmp::min;
+use std::{borrow::Cow, cmp::min, time::Duration};
+
+use unicode_segmentation::UnicodeSegmentation;
 
 use crate::{
     autocompletion::{NoAutoCompletion, Replacement},
+    default_value::DefaultChain,
     error::InquireResult,
     formatter::StringFormatter,
-    input::{Input, InputActionResult},
+    history::History,
+    input::{clean_paste, EditHistory, Input, InputActionResult, LineDirection, Magnitude},
     list_option::ListOption,
-    prompts::prompt::{ActionResult, Prompt},
-    ui::TextBackend,
+    mask::InputMask,
+    prompts::{
+        action::Action,
+        prompt::{ActionResult, Prompt},
+        select::LOADER_POLL_INTERVAL,
+    },
+    type_aliases::{Hinter, InputTransformer, OnChange},
+    ui::{Key, TextBackend, TextInputDetails},
     utils::paginate,
     validator::{ErrorMessage, StringValidator, Validation},
-    Autocomplete, InquireError, Text,
+    Autocomplete, InputAction, InquireError, Text,
+};
+
+use super::{
+    action::TextPromptAction, config::TextConfig, split_values, DEFAULT_HELP_MESSAGE_WITH_AC,
+    DEFAULT_HELP_MESSAGE_WITH_DROPDOWN, DEFAULT_HELP_MESSAGE_WITH_GHOST_TEXT,
+    VIM_NORMAL_MODE_INDICATOR,
 };
 
-use super::{action::TextPromptAction, config::TextConfig, DEFAULT_HELP_MESSAGE_WITH_AC};
+/// Duration of the flash of the input when a typed character is rejected.
+const FLASH_DURATION: Duration = Duration::from_millis(150);
 
 pub struct TextPrompt<'a> {
     message: &'a str,
     config: TextConfig,
-    default: Option<&'a str>,
+    default: Option<Cow<'a, str>>,
+    // source of the default resolved from the chain, when it is displayed
+    default_source: Option<String>,
     help_message: Option<&'a str>,
+    dynamic_placeholder: Option<Hinter<'a>>,
+    dynamic_help_message: Option<Hinter<'a>>,
+    on_change: Option<OnChange<'a>>,
+    transformer: Option<InputTransformer<'a>>,
     input: Input,
+    mask: Option<InputMask>,
+    edits: EditHistory,
     formatter: StringFormatter<'a>,
     validators: Vec<Box<dyn StringValidator>>,
     error: Option<ErrorMessage>,
     autocompleter: Box<dyn Autocomplete>,
+    loading_message: &'a str,
     suggested_options: Vec<String>,
     suggestion_cursor_index: Option<usize>,
+    history: Option<Box<dyn History>>,
+    // entries of the history, the most recent first
+    history_entries: Vec<String>,
+    // position in the history of the entry displayed in the input, if any
+    history_index: Option<usize>,
+    // input typed before browsing the history, restored when leaving it
+    draft: String,
+    // whether the vim normal mode is active, instead of the insert mode
+    normal_mode: bool,
+    // whether the first `d` of a `dd` was pressed in normal mode
+    pending_delete: bool,
+    // whether the input flashes, until the next tick
+    flashing: bool,
 }
 
 impl<'a> From<Text<'a>> for TextPrompt<'a> {
@@ -34,24 +73,63 @@ impl<'a> From<Text<'a>> for TextPrompt<'a> {
         let input = Input::new_with(so.initial_value.unwrap_or_default());
         let input = if let Some(placeholder) = so.placeholder {
             input.with_placeholder(placeholder)
+        } else if let Some(mask) = &so.mask {
+            input.with_placeholder(&mask.placeholder())
         } else {
             input
         };
 
+        // an explicit default takes precedence over the chain
+        let resolved = match so.default {
+            Some(_) => None,
+            None => so.default_chain.as_ref().and_then(DefaultChain::resolve),
+        };
+        let displays_source = so
+            .default_chain
+            .as_ref()
+            .map(DefaultChain::displays_source)
+            .unwrap_or_default();
+        let (default, default_source) = match resolved {
+            Some(resolved) => (
+                Some(Cow::Owned(resolved.value)),
+                displays_source.then_some(resolved.source),
+            ),
+            None => (so.default.map(Cow::Borrowed), None),
+        };
+
         Self {
             message: so.message,
             config: (&so).into(),
-            default: so.default,
+            default,
+            default_source,
             help_message: so.help_message,
+            dynamic_placeholder: so.dynamic_placeholder,
+            dynamic_help_message: so.dynamic_help_message,
+            on_change: so.on_change,
+            transformer: so.transformer,
             formatter: so.formatter,
             autocompleter: so
                 .autocompleter
                 .unwrap_or_else(|| Box::<NoAutoCompletion>::default()),
+            loading_message: so.loading_message,
             input,
+            mask: so.mask,
+            edits: EditHistory::default(),
             error: None,
             suggestion_cursor_index: None,
             suggested_options: vec![],
             validators: so.validators,
+            history_entries: so
+                .history
+                .as_ref()
+                .map(|history| history.entries())
+                .unwrap_or_default(),
+            history: so.history,
+            history_index: None,
+            draft: String::new(),
+            normal_mode: false,
+            pending_delete: false,
+            flashing: false,
         }
     }
 }
@@ -66,6 +144,8 @@ impl<'a> TextPrompt<'a> {
     fn update_suggestions(&mut self) -> InquireResult<()> {
         self.suggested_options = self.autocompleter.get_suggestions(self.input.content())?;
         self.suggestion_cursor_index = None;
+        // an edited entry of the history becomes the input typed so far
+        self.history_index = None;
 
         Ok(())
     }
@@ -79,6 +159,281 @@ impl<'a> TextPrompt<'a> {
         }
     }
 
+    /// First suggestion starting with the input, whose rest is displayed as
+    /// ghost text while the cursor is at the end of the input.
+    fn get_ghost_suggestion(&self) -> Option<&str> {
+        let content = self.input.content();
+
+        if !self.config.ghost_text
+            || self.normal_mode
+            || content.is_empty()
+            || self.input.cursor() < self.input.length()
+        {
+            return None;
+        }
+
+        self.suggested_options
+            .iter()
+            .map(String::as_str)
+            .find(|suggestion| suggestion.len() > content.len() && suggestion.starts_with(content))
+    }
+
+    fn use_ghost_suggestion(&mut self) -> InquireResult<ActionResult> {
+        match self.get_ghost_suggestion() {
+            Some(suggestion) => {
+                let suggestion = self.transform(suggestion);
+                let suggestion = Input::new_with(self.fit_max_length("", &suggestion));
+                self.edits
+                    .push(std::mem::replace(&mut self.input, suggestion));
+                self.update_suggestions()?;
+                Ok(ActionResult::NeedsRedraw)
+            }
+            None => Ok(ActionResult::Clean),
+        }
+    }
+
+    /// Replaces the content of the input, as much of it as the maximum length
+    /// allows, keeping its placeholder.
+    fn replace_input(&mut self, content: String) {
+        let input = Input::new_with(self.fit_max_length("", &content));
+
+        self.input = match self.input.placeholder() {
+            Some(placeholder) => input.with_placeholder(placeholder),
+            None => input,
+        };
+    }
+
+    /// Whether the up and down arrows browse the history, which they do
+    /// unless suggestions are listed.
+    fn browses_history(&self) -> bool {
+        self.history.is_some() && (self.config.ghost_text || self.suggested_options.is_empty())
+    }
+
+    /// Replaces the input by the previous entry of the history, saving the
+    /// input typed so far when starting to browse it.
+    fn previous_history_entry(&mut self) -> ActionResult {
+        let index = match self.history_index {
+            Some(index) => index + 1,
+            None => 0,
+        };
+
+        let entry = match self.history_entries.get(index) {
+            Some(entry) => entry.clone(),
+            None => return ActionResult::Clean,
+        };
+
+        if self.history_index.is_none() {
+            self.draft = self.input.content().to_string();
+        }
+
+        self.history_index = Some(index);
+        self.replace_input(entry);
+        self.suggested_options.clear();
+        self.suggestion_cursor_index = None;
+
+        ActionResult::NeedsRedraw
+    }
+
+    /// Replaces the input by the next entry of the history, or by the input
+    /// typed before browsing it after the most recent entry.
+    fn next_history_entry(&mut self) -> ActionResult {
+        let content = match self.history_index {
+            None => return ActionResult::Clean,
+            Some(0) => {
+                self.history_index = None;
+                std::mem::take(&mut self.draft)
+            }
+            Some(index) => {
+                self.history_index = Some(index - 1);
+                self.history_entries[index - 1].clone()
+            }
+        };
+
+        self.replace_input(content);
+
+        ActionResult::NeedsRedraw
+    }
+
+    /// Updates the suggestions after an edit of the input was undone or redone.
+    fn refresh_reverted_input(&mut self, reverted: bool) -> InquireResult<ActionResult> {
+        if !reverted {
+            return Ok(ActionResult::Clean);
+        }
+
+        self.update_suggestions()?;
+        Ok(ActionResult::NeedsRedraw)
+    }
+
+    /// Deletes the whole input, as the `dd` command of vim does in normal mode.
+    fn delete_line(&mut self) -> InquireResult<ActionResult> {
+        if self.input.is_empty() {
+            return Ok(ActionResult::Clean);
+        }
+
+        let before = self.input.clone();
+        self.replace_input(String::new());
+        self.edits.push(before);
+        self.update_suggestions()?;
+
+        Ok(ActionResult::NeedsRedraw)
+    }
+
+    /// Types or deletes the characters filling the slots of the mask, the
+    /// input being formatted again after each edit.
+    fn handle_masked_input(&mut self, action: InputAction) -> InquireResult<ActionResult> {
+        let mask = match &self.mask {
+            Some(mask) => mask,
+            None => return Ok(ActionResult::Clean),
+        };
+
+        let mut raw = mask.raw(self.input.content());
+
+        match action {
+            InputAction::Write(c) if mask.accepts(raw.chars().count(), c) => raw.push(c),
+            InputAction::Delete(Magnitude::Char, LineDirection::Left) => {
+                raw.pop();
+            }
+            InputAction::Delete(_, LineDirection::Left) => raw.clear(),
+            // the cursor stays at the end of the input, right before the next slot
+            _ => return Ok(ActionResult::Clean),
+        }
+
+        self.replace_masked_input(&raw)
+    }
+
+    /// Types the pasted characters accepted by the slots of the mask, at once.
+    fn paste_masked_input(&mut self, text: &str) -> InquireResult<ActionResult> {
+        let mask = match &self.mask {
+            Some(mask) => mask,
+            None => return Ok(ActionResult::Clean),
+        };
+
+        let mut raw = mask.raw(self.input.content());
+
+        for c in text.chars() {
+            if mask.accepts(raw.chars().count(), c) {
+                raw.push(c);
+            }
+        }
+
+        self.replace_masked_input(&raw)
+    }
+
+    /// Replaces the input by the characters typed in the slots of the mask,
+    /// formatted, as a single edit.
+    fn replace_masked_input(&mut self, raw: &str) -> InquireResult<ActionResult> {
+        let formatted = match &self.mask {
+            Some(mask) => mask.format(raw),
+            None => return Ok(ActionResult::Clean),
+        };
+
+        if formatted == self.input.content() {
+            return Ok(ActionResult::Clean);
+        }
+
+        let before = self.input.clone();
+        self.replace_input(formatted);
+        self.edits.push(before);
+        self.update_suggestions()?;
+
+        Ok(ActionResult::NeedsRedraw)
+    }
+
+    /// Whether applying the action makes the input longer than its maximum
+    /// length, in which case the action is rejected.
+    fn exceeds_max_length(&self, action: InputAction) -> bool {
+        let max_length = match self.config.max_length {
+            Some(max_length) => max_length,
+            None => return false,
+        };
+
+        let edited = match action {
+            InputAction::Yank | InputAction::YankPop => {
+                match self.edits.preview_yank(&self.input, action) {
+                    Some(edited) => edited,
+                    None => return false,
+                }
+            }
+            action => {
+                let mut edited = self.input.clone();
+                edited.handle(action);
+                edited
+            }
+        };
+
+        self.config.length_unit.measure(edited.content()) > max_length
+    }
+
+    /// Part of the text fitting in the maximum length of the input along with
+    /// the content it is added to, which is empty when the text replaces the
+    /// whole input.
+    fn fit_max_length<'t>(&self, content: &str, text: &'t str) -> &'t str {
+        let max_length = match self.config.max_length {
+            Some(max_length) => max_length,
+            None => return text,
+        };
+
+        let length_unit = self.config.length_unit;
+        let mut available = max_length.saturating_sub(length_unit.measure(content));
+        let mut end = 0;
+
+        for grapheme in text.graphemes(true) {
+            let length = length_unit.measure(grapheme);
+
+            if length > available {
+                break;
+            }
+
+            available -= length;
+            end += grapheme.len();
+        }
+
+        &text[..end]
+    }
+
+    /// Help message computed from the current input, if any, or else the
+    /// static one.
+    fn current_help_message(&self) -> Option<Cow<'a, str>> {
+        self.dynamic_help_message
+            .and_then(|hinter| hinter(self.input.content()))
+            .map(Cow::Owned)
+            .or_else(|| self.help_message.map(Cow::Borrowed))
+    }
+
+    /// Renders the line of the prompt, with the indicators around the input.
+    fn render_prompt_line<Backend: TextBackend>(&self, backend: &mut Backend) -> InquireResult<()> {
+        let ghost_text = self
+            .get_ghost_suggestion()
+            .map(|suggestion| &suggestion[self.input.content().len()..]);
+
+        let details = TextInputDetails {
+            ghost_text,
+            mode: self.normal_mode.then_some(VIM_NORMAL_MODE_INDICATOR),
+            length_counter: self.config.length_counter.map(|max| {
+                let length = self.config.length_unit.measure(self.input.content());
+                (length, max)
+            }),
+            flash: self.flashing,
+        };
+
+        let input = match self
+            .dynamic_placeholder
+            .and_then(|hinter| hinter(self.input.content()))
+        {
+            Some(placeholder) => Cow::Owned(self.input.clone().with_placeholder(&placeholder)),
+            None => Cow::Borrowed(&self.input),
+        };
+
+        let default = match (&self.default, &self.default_source) {
+            (Some(default), Some(source)) => Some(Cow::Owned(format!("{default} from {source}"))),
+            (default, _) => default.clone(),
+        };
+
+        backend.render_prompt(self.message, default.as_deref(), &input, &details)?;
+
+        Ok(())
+    }
+
     fn move_cursor_up(&mut self, qty: usize) -> ActionResult {
         let new_cursor_index = match self.suggestion_cursor_index {
             None => None,
@@ -117,90 +472,209 @@ impl<'a> TextPrompt<'a> {
         }
     }
 
+    /// Whether the suggestions are listed as a dropdown, unless ghost text
+    /// is displayed instead.
+    fn lists_dropdown(&self) -> bool {
+        self.config.dropdown && !self.config.ghost_text
+    }
+
+    /// Highlights the next suggestion of the dropdown, going back to the
+    /// first one after the last.
+    fn cycle_suggestions(&mut self) -> ActionResult {
+        let next = match self.suggestion_cursor_index {
+            Some(index) if index + 1 < self.suggested_options.len() => index + 1,
+            _ => 0,
+        };
+
+        self.update_suggestion_cursor_pos(Some(next))
+    }
+
     fn use_current_suggestion(&mut self) -> InquireResult<ActionResult> {
-        let suggestion = self.get_highlighted_suggestion().map(|s| s.to_owned());
+        let suggestion = match self.config.ghost_text {
+            true => self.get_ghost_suggestion(),
+            false => self.get_highlighted_suggestion(),
+        };
+        let suggestion = suggestion.map(|s| s.to_owned());
         match self
             .autocompleter
             .get_completion(self.input.content(), suggestion)?
         {
             Replacement::Some(value) => {
-                self.input = Input::new_with(value);
+                let value = self.transform(&value);
+                let input = Input::new_with(self.fit_max_length("", &value));
+                self.edits.push(std::mem::replace(&mut self.input, input));
                 Ok(ActionResult::NeedsRedraw)
             }
             Replacement::None => Ok(ActionResult::Clean),
         }
     }
 
-    fn get_current_answer(&self) -> &str {
+    fn get_current_answer(&self) -> Cow<'_, str> {
         // If there is a highlighted suggestion, assume user wanted it as
         // the answer.
         if let Some(suggestion) = self.get_highlighted_suggestion() {
-            return suggestion;
+            return self.transform(suggestion);
         }
 
         // Empty input with default values override any validators.
         if self.input.content().is_empty() {
-            if let Some(val) = self.default {
-                return val;
+            if let Some(val) = &self.default {
+                return self.transform(val);
             }
         }
 
-        self.input.content()
+        // the typed and pasted text went through the transformer already
+        Cow::Borrowed(self.input.content())
+    }
+
+    /// Text as the transformer turns it, if any, for the values that do not
+    /// go through it when typed, such as the suggestions and the default.
+    fn transform<'t>(&self, text: &'t str) -> Cow<'t, str> {
+        match self.transformer {
+            Some(transformer) => Cow::Owned(transformer(text)),
+            None => Cow::Borrowed(text),
+        }
     }
 
     fn validate_current_answer(&self) -> InquireResult<Validation> {
-        for validator in &self.validators {
-            match validator.validate(self.get_current_answer()) {
-                Ok(Validation::Valid) => {}
-                Ok(Validation::Invalid(msg)) => return Ok(Validation::Invalid(msg)),
-                Err(err) => return Err(InquireError::Custom(err)),
+        if let Some(mask) = &self.mask {
+            let answer = self.get_current_answer();
+
+            if !answer.is_empty() && !mask.is_complete(&answer) {
+                return Ok(Validation::Invalid(
+                    format!("Input must match the format {}", mask.placeholder()).into(),
+                ));
+            }
+        }
+
+        let answer = self.get_current_answer();
+        let values = match self.config.delimiter {
+            Some(delimiter) => split_values(&answer, delimiter),
+            None => vec![answer.as_ref()],
+        };
+
+        for value in values {
+            for validator in &self.validators {
+                match validator.validate(value) {
+                    Ok(Validation::Valid) => {}
+                    Ok(Validation::Invalid(msg)) => return Ok(Validation::Invalid(msg)),
+                    Err(err) => return Err(InquireError::Custom(err)),
+                }
             }
         }
 
         Ok(Validation::Valid)
     }
-}
 
-impl<'a, Backend> Prompt<Backend> for TextPrompt<'a>
-where
-    Backend: TextBackend,
-{
-    type Config = TextConfig;
-    type InnerAction = TextPromptAction;
-    type Output = String;
+    /// Inserts the pasted text as a single edit.
+    fn paste(&mut self, text: &str) -> InquireResult<ActionResult> {
+        // the lines are joined, as a line break would submit the answer
+        let text = clean_paste(text, " ");
 
-    fn message(&self) -> &str {
-        self.message
+        match self.transformer {
+            Some(transformer) => self.insert_text(&transformer(&text)),
+            None => self.insert_text(&text),
+        }
     }
 
-    fn config(&self) -> &TextConfig {
-        &self.config
-    }
+    /// Inserts the text at the cursor position as a single edit, as much of
+    /// it as the mask or the maximum length of the input allow.
+    fn insert_text(&mut self, text: &str) -> InquireResult<ActionResult> {
+        if self.mask.is_some() {
+            return self.paste_masked_input(text);
+        }
 
-    fn format_answer(&self, answer: &String) -> String {
-        (self.formatter)(answer)
+        let fitting = self.fit_max_length(self.input.content(), text);
+        let result = self.edits.paste(&mut self.input, fitting);
+
+        if let InputActionResult::ContentChanged = result {
+            self.update_suggestions()?;
+        }
+
+        // the input flashes as when typing the characters dropped
+        if fitting.len() < text.len() {
+            self.flashing = true;
+            return Ok(ActionResult::NeedsRedraw);
+        }
+
+        Ok(result.into())
     }
 
-    fn setup(&mut self) -> InquireResult<()> {
-        self.update_suggestions()
+    /// Calls the on change hook if the input differs from its previous
+    /// content, which is only recorded when there is a hook.
+    fn notify_change(&self, previous: Option<String>) {
+        if let (Some(on_change), Some(previous)) = (self.on_change, previous) {
+            if previous != self.input.content() {
+                on_change(self.input.content());
+            }
+        }
     }
 
-    fn submit(&mut self) -> InquireResult<Option<String>> {
-        let result = match self.validate_current_answer()? {
-            Validation::Valid => Some(self.get_current_answer().to_owned()),
-            Validation::Invalid(msg) => {
-                self.error = Some(msg);
-                None
+    fn handle_action(&mut self, action: TextPromptAction) -> InquireResult<ActionResult> {
+        let pending_delete = std::mem::take(&mut self.pending_delete);
+
+        let action = match (self.transformer, action) {
+            (Some(transformer), TextPromptAction::ValueInput(InputAction::Write(c))) => {
+                let transformed = transformer(&c.to_string());
+                let mut chars = transformed.chars();
+
+                match (chars.next(), chars.next()) {
+                    (None, _) => return Ok(ActionResult::Clean),
+                    (Some(c), None) => TextPromptAction::ValueInput(InputAction::Write(c)),
+                    (Some(_), Some(_)) => return self.insert_text(&transformed),
+                }
             }
+            (_, action) => action,
         };
 
-        Ok(result)
-    }
+        if let TextPromptAction::ValueInput(
+            input_action @ (InputAction::Write(_) | InputAction::Yank | InputAction::YankPop),
+        ) = action
+        {
+            if self.exceeds_max_length(input_action) {
+                self.flashing = true;
+                return Ok(ActionResult::NeedsRedraw);
+            }
+        }
+
+        if self.browses_history() {
+            match action {
+                TextPromptAction::MoveToSuggestionAbove => return Ok(self.previous_history_entry()),
+                TextPromptAction::MoveToSuggestionBelow => return Ok(self.next_history_entry()),
+                _ => {}
+            }
+        }
+
+        if self.config.ghost_text {
+            match action {
+                TextPromptAction::ValueInput(InputAction::MoveCursor(
+                    Magnitude::Char,
+                    LineDirection::Right,
+                )) if self.get_ghost_suggestion().is_some() => {
+                    return self.use_ghost_suggestion();
+                }
+                // the suggestions are not listed, so there is nothing to move through
+                TextPromptAction::MoveToSuggestionAbove
+                | TextPromptAction::MoveToSuggestionBelow
+                | TextPromptAction::MoveToSuggestionPageUp
+                | TextPromptAction::MoveToSuggestionPageDown => return Ok(ActionResult::Clean),
+                _ => {}
+            }
+        }
+
+        if self.lists_dropdown()
+            && !self.suggested_options.is_empty()
+            && action == TextPromptAction::UseCurrentSuggestion
+        {
+            return Ok(self.cycle_suggestions());
+        }
 
-    fn handle(&mut self, action: TextPromptAction) -> InquireResult<ActionResult> {
         let result = match action {
+            TextPromptAction::ValueInput(input_action) if self.mask.is_some() => {
+                self.handle_masked_input(input_action)?
+            }
             TextPromptAction::ValueInput(input_action) => {
-                let result = self.input.handle(input_action);
+                let result = self.edits.handle(&mut self.input, input_action);
 
                 if let InputActionResult::ContentChanged = result {
                     self.update_suggestions()?;
@@ -214,6 +688,40 @@ where
             TextPromptAction::MoveToSuggestionPageDown => {
                 self.move_cursor_down(self.config.page_size)
             }
+            TextPromptAction::Undo => {
+                let reverted = self.edits.undo(&mut self.input);
+                self.refresh_reverted_input(reverted)?
+            }
+            TextPromptAction::Redo => {
+                let reverted = self.edits.redo(&mut self.input);
+                self.refresh_reverted_input(reverted)?
+            }
+            TextPromptAction::EnterNormalMode => {
+                self.normal_mode = true;
+                // the cursor rests on the last character typed, as in vim
+                self.edits.handle(
+                    &mut self.input,
+                    InputAction::MoveCursor(Magnitude::Char, LineDirection::Left),
+                );
+                ActionResult::NeedsRedraw
+            }
+            TextPromptAction::EnterInsertMode => {
+                self.normal_mode = false;
+                ActionResult::NeedsRedraw
+            }
+            TextPromptAction::EnterInsertModeAfterCursor => {
+                self.normal_mode = false;
+                self.edits.handle(
+                    &mut self.input,
+                    InputAction::MoveCursor(Magnitude::Char, LineDirection::Right),
+                );
+                ActionResult::NeedsRedraw
+            }
+            TextPromptAction::DeleteLine if pending_delete => self.delete_line()?,
+            TextPromptAction::DeleteLine => {
+                self.pending_delete = true;
+                ActionResult::Clean
+            }
             TextPromptAction::UseCurrentSuggestion => {
                 let result = self.use_current_suggestion()?;
 
@@ -227,15 +735,148 @@ where
 
         Ok(result)
     }
+}
+
+impl<'a, Backend> Prompt<Backend> for TextPrompt<'a>
+where
+    Backend: TextBackend,
+{
+    type Config = TextConfig;
+    type InnerAction = TextPromptAction;
+    type Output = String;
+
+    fn message(&self) -> &str {
+        self.message
+    }
 
-    fn render(&self, backend: &mut Backend) -> InquireResult<()> {
-        let prompt = &self.message;
+    fn config(&self) -> &TextConfig {
+        &self.config
+    }
 
+    fn format_answer(&self, answer: &String) -> String {
+        (self.formatter)(answer)
+    }
+
+    fn parse_key(&self, key: Key) -> Option<Action<TextPromptAction>> {
+        if !self.config.vim_mode {
+            return Action::from_key(key, &self.config);
+        }
+
+        if !self.normal_mode {
+            return match key {
+                Key::Escape => Some(Action::Inner(TextPromptAction::EnterNormalMode)),
+                key => Action::from_key(key, &self.config),
+            };
+        }
+
+        TextPromptAction::from_normal_mode_key(key)
+            .map(Action::Inner)
+            .or_else(|| match Action::from_key(key, &self.config) {
+                // characters are not typed into the input in normal mode
+                Some(Action::Inner(TextPromptAction::ValueInput(InputAction::Write(_)))) => None,
+                action => action,
+            })
+    }
+
+    fn handle_paste(&mut self, text: &str) -> InquireResult<ActionResult> {
+        let previous = self.on_change.map(|_| self.input.content().to_owned());
+        let result = self.paste(text)?;
+        self.notify_change(previous);
+
+        Ok(result)
+    }
+
+    fn setup(&mut self) -> InquireResult<()> {
+        self.update_suggestions()
+    }
+
+    fn tick_interval(&self) -> Option<Duration> {
+        if self.flashing {
+            return Some(FLASH_DURATION);
+        }
+
+        self.autocompleter
+            .is_loading()
+            .then_some(LOADER_POLL_INTERVAL)
+    }
+
+    // the flash of the input lasts until the next key press otherwise
+    fn requires_ticks(&self) -> bool {
+        self.autocompleter.is_loading()
+    }
+
+    fn tick(&mut self) -> InquireResult<Option<Action<TextPromptAction>>> {
+        self.flashing = false;
+
+        if let Some(suggestions) = self.autocompleter.poll_suggestions()? {
+            // suggestions are hidden while the history is browsed
+            if self.history_index.is_none() {
+                self.suggested_options = suggestions;
+                self.suggestion_cursor_index = None;
+            }
+        }
+
+        Ok(None)
+    }
+
+    fn submit(&mut self) -> InquireResult<Option<String>> {
+        let result = match self.validate_current_answer()? {
+            Validation::Valid => {
+                let answer = self.get_current_answer().into_owned();
+
+                if let Some(history) = self.history.as_mut() {
+                    if !answer.is_empty() {
+                        history.record(&answer);
+                    }
+                }
+
+                Some(answer)
+            }
+            Validation::Invalid(msg) => {
+                self.error = Some(msg);
+                None
+            }
+        };
+
+        Ok(result)
+    }
+
+    fn handle(&mut self, action: TextPromptAction) -> InquireResult<ActionResult> {
+        let previous = self.on_change.map(|_| self.input.content().to_owned());
+        let result = self.handle_action(action)?;
+        self.notify_change(previous);
+
+        Ok(result)
+    }
+
+    fn render(&self, backend: &mut Backend) -> InquireResult<()> {
         if let Some(err) = &self.error {
             backend.render_error_message(err)?;
         }
 
-        backend.render_prompt(prompt, self.default, &self.input)?;
+        self.render_prompt_line(backend)?;
+
+        if let Some(delimiter) = self.config.delimiter {
+            let values = split_values(self.input.content(), delimiter);
+
+            if !values.is_empty() {
+                backend.render_values(&values)?;
+            }
+        }
+
+        if self.config.ghost_text {
+            if self.autocompleter.is_loading() {
+                backend.render_loading_indicator(self.loading_message)?;
+            }
+
+            if let Some(message) = self.current_help_message() {
+                backend.render_help_message(&message)?;
+            } else if self.get_ghost_suggestion().is_some() {
+                backend.render_help_message(DEFAULT_HELP_MESSAGE_WITH_GHOST_TEXT)?;
+            }
+
+            return Ok(());
+        }
 
         let choices = self
             .suggested_options
@@ -250,12 +891,22 @@ where
             self.suggestion_cursor_index,
         );
 
-        backend.render_suggestions(page)?;
+        match self.lists_dropdown() {
+            true => backend.render_dropdown(page, self.input.content())?,
+            false => backend.render_suggestions(page)?,
+        }
+
+        if self.autocompleter.is_loading() {
+            backend.render_loading_indicator(self.loading_message)?;
+        }
 
-        if let Some(message) = self.help_message {
-            backend.render_help_message(message)?;
+        if let Some(message) = self.current_help_message() {
+            backend.render_help_message(&message)?;
         } else if !choices.is_empty() {
-            backend.render_help_message(DEFAULT_HELP_MESSAGE_WITH_AC)?;
+            match self.lists_dropdown() {
+                true => backend.render_help_message(DEFAULT_HELP_MESSAGE_WITH_DROPDOWN)?,
+                false => backend.render_help_message(DEFAULT_HELP_MESSAGE_WITH_AC)?,
+            }
         }
 
         Ok(())
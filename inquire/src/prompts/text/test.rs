@@ -1,5 +1,8 @@
+use std::{cell::RefCell, rc::Rc};
+
 use super::Text;
-use crate::ui::{Key, KeyModifiers};
+use crate::history::History;
+use crate::ui::{InputEvent, Key, KeyModifiers};
 use crate::validator::{ErrorMessage, Validation};
 
 fn default<'a>() -> Text<'a> {
@@ -111,3 +114,502 @@ text_test!(
         _ => Ok(Validation::Invalid(ErrorMessage::Default)),
     })
 );
+
+fn fruit_suggester(input: &str) -> Result<Vec<String>, crate::CustomUserError> {
+    Ok(["apple", "apricot", "banana"]
+        .iter()
+        .filter(|fruit| fruit.contains(input))
+        .map(|fruit| String::from(*fruit))
+        .collect())
+}
+
+text_test!(
+    right_arrow_completes_ghost_text,
+    {
+        let mut events = text_to_events!("apr");
+        events.push(Key::Right(KeyModifiers::NONE));
+        events.push(Key::Enter);
+        events
+    },
+    "apricot",
+    Text::new("")
+        .with_autocomplete(fruit_suggester)
+        .with_ghost_text(true)
+);
+
+text_test!(
+    tab_completes_ghost_text_without_listing_suggestions,
+    {
+        let mut events = text_to_events!("a");
+        events.push(Key::Down(KeyModifiers::NONE));
+        events.push(Key::Tab);
+        events.push(Key::Enter);
+        events
+    },
+    "apple",
+    Text::new("")
+        .with_autocomplete(fruit_suggester)
+        .with_ghost_text(true)
+);
+
+#[test]
+fn tab_cycles_through_the_dropdown() {
+    let mut events = text_to_events!("a");
+    events.append(&mut vec![Key::Tab; 4]);
+    events.push(Key::Enter);
+    let mut backend = crate::ui::test::FakeBackend::new(events);
+
+    let ans = Text::new("")
+        .with_autocomplete(fruit_suggester)
+        .with_dropdown(true)
+        .prompt_with_backend(&mut backend)
+        .unwrap();
+
+    let dropdown = |cursor| crate::ui::test::Token::Dropdown {
+        options: vec!["apple".into(), "apricot".into(), "banana".into()],
+        cursor,
+        fragment: "a".into(),
+    };
+
+    assert_eq!("apple", ans);
+    assert!(backend.frames()[1].has_token(&dropdown(None)));
+    assert!(backend.frames()[4].has_token(&dropdown(Some(2))));
+    assert!(backend.frames()[5].has_token(&dropdown(Some(0))));
+}
+
+#[derive(Clone)]
+struct SharedHistory(Rc<RefCell<Vec<String>>>);
+
+impl History for SharedHistory {
+    fn entries(&self) -> Vec<String> {
+        self.0.borrow().clone()
+    }
+
+    fn record(&mut self, entry: &str) {
+        self.0.borrow_mut().insert(0, entry.to_string());
+    }
+}
+
+#[test]
+/// Up and down cycle through the history, restoring the input typed so far
+fn arrows_browse_history() {
+    let entries = Rc::new(RefCell::new(vec![
+        String::from("second"),
+        String::from("first"),
+    ]));
+
+    let mut events = text_to_events!("dr");
+    events.push(Key::Up(KeyModifiers::NONE));
+    events.push(Key::Up(KeyModifiers::NONE));
+    events.push(Key::Up(KeyModifiers::NONE));
+    events.push(Key::Down(KeyModifiers::NONE));
+    events.push(Key::Down(KeyModifiers::NONE));
+    events.append(&mut text_to_events!("aft"));
+    events.push(Key::Enter);
+    let mut backend = crate::prompts::test::fake_backend(events);
+
+    let ans = Text::new("")
+        .with_history(SharedHistory(Rc::clone(&entries)))
+        .prompt_with_backend(&mut backend)
+        .unwrap();
+
+    assert_eq!("draft", ans);
+    assert_eq!(vec!["draft", "second", "first"], *entries.borrow());
+
+    let mut events = vec![Key::Up(KeyModifiers::NONE), Key::Up(KeyModifiers::NONE)];
+    events.append(&mut text_to_events!("!"));
+    events.push(Key::Enter);
+    let mut backend = crate::prompts::test::fake_backend(events);
+
+    let ans = Text::new("")
+        .with_history(SharedHistory(Rc::clone(&entries)))
+        .prompt_with_backend(&mut backend)
+        .unwrap();
+
+    assert_eq!("second!", ans);
+}
+
+text_test!(
+    undo_reverts_the_last_word_typed,
+    {
+        let mut events = text_to_events!("hello world");
+        events.push(Key::Char('z', KeyModifiers::CONTROL));
+        events.push(Key::Enter);
+        events
+    },
+    "hello "
+);
+
+text_test!(
+    redo_reapplies_the_reverted_edits,
+    {
+        let mut events = text_to_events!("hello world");
+        events.push(Key::Backspace);
+        events.push(Key::Char('z', KeyModifiers::CONTROL));
+        events.push(Key::Char('z', KeyModifiers::CONTROL));
+        events.push(Key::Char('y', KeyModifiers::CONTROL));
+        events.push(Key::Enter);
+        events
+    },
+    "hello world"
+);
+
+text_test!(
+    readline_keymap_edits_the_input,
+    {
+        let mut events = text_to_events!("hello world");
+        events.push(Key::Char('w', KeyModifiers::CONTROL));
+        events.push(Key::Char('a', KeyModifiers::CONTROL));
+        events.push(Key::Char('k', KeyModifiers::CONTROL));
+        events.append(&mut text_to_events!("bye"));
+        events.push(Key::Enter);
+        events
+    },
+    "bye",
+    Text::new("").with_keymap(crate::InputKeymap::Readline)
+);
+
+text_test!(
+    readline_keymap_yanks_the_deleted_words,
+    {
+        let mut events = text_to_events!("one two three");
+        events.push(Key::Char('w', KeyModifiers::CONTROL));
+        events.push(Key::Char('w', KeyModifiers::CONTROL));
+        events.append(&mut text_to_events!("x "));
+        events.push(Key::Char('y', KeyModifiers::CONTROL));
+        events.push(Key::Enter);
+        events
+    },
+    "one x two three",
+    Text::new("").with_keymap(crate::InputKeymap::Readline)
+);
+
+text_test!(
+    vim_mode_edits_the_input_in_normal_mode,
+    {
+        let mut events = text_to_events!("hello world");
+        events.push(Key::Escape);
+        events.append(&mut text_to_events!("bxq"));
+        events.append(&mut text_to_events!("iW"));
+        events.push(Key::Escape);
+        events.append(&mut text_to_events!("$a!"));
+        events.push(Key::Enter);
+        events
+    },
+    "hello World!",
+    Text::new("").with_vim_mode(true)
+);
+
+text_test!(
+    vim_mode_deletes_the_input_with_dd,
+    {
+        let mut events = text_to_events!("hello");
+        events.push(Key::Escape);
+        events.append(&mut text_to_events!("dxdd"));
+        events.append(&mut text_to_events!("dd"));
+        events.append(&mut text_to_events!("abye"));
+        events.push(Key::Enter);
+        events
+    },
+    "bye",
+    Text::new("").with_vim_mode(true)
+);
+
+text_test!(
+    mask_inserts_literals_and_rejects_other_characters,
+    text_to_events!("12a/0520245\n"),
+    "12/05/2024",
+    Text::new("").with_mask("##/##/####")
+);
+
+text_test!(
+    mask_must_be_complete_to_submit,
+    {
+        let mut events = text_to_events!("1205\n");
+        events.push(Key::Backspace);
+        events.append(&mut text_to_events!("62024\n"));
+        events
+    },
+    "12/06/2024",
+    Text::new("").with_mask("##/##/####")
+);
+
+text_test!(
+    max_length_rejects_characters_typed_beyond_it,
+    {
+        let mut events = text_to_events!("ab🧘🏻‍♂️cd");
+        events.push(Key::Backspace);
+        events.append(&mut text_to_events!("ef\n"));
+        events
+    },
+    "abe",
+    Text::new("").with_max_length(3)
+);
+
+#[test]
+fn max_length_flash_does_not_need_timeouts() {
+    let mut backend = crate::ui::test::FakeBackend::new(text_to_events!("abcd\n"));
+    backend.supports_timeout = false;
+
+    let ans = Text::new("")
+        .with_max_length(3)
+        .prompt_with_backend(&mut backend)
+        .unwrap();
+
+    assert_eq!("abc", ans);
+}
+
+text_test!(
+    max_length_can_be_counted_in_bytes,
+    text_to_events!("añb\n"),
+    "añ",
+    Text::new("")
+        .with_max_length(3)
+        .with_length_unit(crate::LengthUnit::Bytes)
+);
+
+text_test!(
+    ghost_text_is_cut_at_the_max_length,
+    {
+        let mut events = text_to_events!("apr");
+        events.push(Key::Right(KeyModifiers::NONE));
+        events.push(Key::Enter);
+        events
+    },
+    "apric",
+    Text::new("")
+        .with_autocomplete(fruit_suggester)
+        .with_ghost_text(true)
+        .with_max_length(5)
+);
+
+text_test!(
+    completions_are_cut_at_the_max_length,
+    vec![Key::Char('a', KeyModifiers::NONE), Key::Tab, Key::Enter],
+    "app",
+    Text::new("")
+        .with_autocomplete(fruit_suggester)
+        .with_ghost_text(true)
+        .with_max_length(3)
+);
+
+text_test!(
+    history_entries_are_cut_at_the_max_length,
+    vec![Key::Up(KeyModifiers::NONE), Key::Enter],
+    "sec",
+    Text::new("")
+        .with_history(SharedHistory(Rc::new(RefCell::new(vec![String::from(
+            "second"
+        )]))))
+        .with_max_length(3)
+);
+
+#[test]
+fn pasted_lines_are_joined_without_submitting() {
+    let mut backend = crate::prompts::test::fake_backend_with_events(vec![
+        InputEvent::Paste("hello\nbig\r\nworld\n".into()),
+        InputEvent::Key(Key::Enter),
+    ]);
+
+    let ans = default().prompt_with_backend(&mut backend).unwrap();
+
+    assert_eq!("hello big world", ans);
+}
+
+#[test]
+fn pasted_text_is_a_single_edit() {
+    let mut events: Vec<InputEvent> = text_to_events!("say ")
+        .into_iter()
+        .map(InputEvent::Key)
+        .collect();
+    events.push(InputEvent::Paste("hello world".into()));
+    events.push(InputEvent::Key(Key::Char('z', KeyModifiers::CONTROL)));
+    events.push(InputEvent::Key(Key::Enter));
+    let mut backend = crate::prompts::test::fake_backend_with_events(events);
+
+    let ans = default().prompt_with_backend(&mut backend).unwrap();
+
+    assert_eq!("say ", ans);
+}
+
+#[test]
+fn pasted_text_is_cut_at_the_max_length() {
+    let mut backend = crate::prompts::test::fake_backend_with_events(vec![
+        InputEvent::Paste("abcdef".into()),
+        InputEvent::Key(Key::Enter),
+    ]);
+
+    let ans = Text::new("")
+        .with_max_length(4)
+        .prompt_with_backend(&mut backend)
+        .unwrap();
+
+    assert_eq!("abcd", ans);
+}
+
+#[test]
+fn delimited_values_are_split_and_trimmed() {
+    assert_eq!(
+        vec!["a.com", "b.com", "c d"],
+        super::split_values(" a.com,b.com , , c d,", ',')
+    );
+}
+
+text_test!(
+    delimited_values_are_validated_one_by_one,
+    {
+        let mut events = text_to_events!("a.com, b\n");
+        events.append(&mut text_to_events!(".com\n"));
+        events
+    },
+    "a.com, b.com",
+    Text::new("")
+        .with_delimiter(',')
+        .with_validator(|value: &str| {
+            Ok(match value.contains('.') {
+                true => Validation::Valid,
+                false => Validation::Invalid("Hosts must have a domain".into()),
+            })
+        })
+);
+
+#[test]
+fn dynamic_help_message_is_computed_from_the_input() {
+    let mut backend = crate::ui::test::FakeBackend::new(text_to_events!("Hi U\n"));
+
+    let ans = Text::new("Title:")
+        .with_help_message("type a title")
+        .with_dynamic_help_message(&|title| match title.is_empty() {
+            true => None,
+            false => Some(format!("slug: {}", title.to_lowercase().replace(' ', "-"))),
+        })
+        .prompt_with_backend(&mut backend)
+        .unwrap();
+
+    let help_message = |message: &str| crate::ui::test::Token::HelpMessage(message.into());
+
+    assert_eq!("Hi U", ans);
+    assert!(backend.frames()[0].has_token(&help_message("type a title")));
+    assert!(backend.frames()[4].has_token(&help_message("slug: hi-u")));
+}
+
+#[test]
+fn on_change_is_called_when_the_input_changes() {
+    let changes = RefCell::new(vec![]);
+    let on_change = |input: &str| changes.borrow_mut().push(input.to_string());
+
+    let mut events = text_to_events!("ab");
+    events.push(Key::Left(KeyModifiers::NONE));
+    events.push(Key::Backspace);
+    events.push(Key::Enter);
+    let mut backend = crate::prompts::test::fake_backend(events);
+
+    let ans = Text::new("")
+        .with_on_change(&on_change)
+        .prompt_with_backend(&mut backend)
+        .unwrap();
+
+    assert_eq!("b", ans);
+    assert_eq!(vec!["a", "ab", "b"], *changes.borrow());
+}
+
+text_test!(
+    transformer_normalizes_the_typed_characters,
+    text_to_events!("Hello, World!\n"),
+    "hello--world",
+    Text::new("").with_transformer(&|text| {
+        text.chars()
+            .filter(|c| c.is_alphanumeric() || *c == ' ')
+            .collect::<String>()
+            .to_lowercase()
+            .replace(' ', "--")
+    })
+);
+
+text_test!(
+    transformer_applies_to_the_highlighted_suggestion,
+    vec![Key::Down(KeyModifiers::NONE), Key::Enter],
+    "APPLE",
+    Text::new("")
+        .with_autocomplete(fruit_suggester)
+        .with_transformer(&|text| text.to_uppercase())
+);
+
+text_test!(
+    transformer_applies_to_the_completed_suggestion,
+    vec![Key::Down(KeyModifiers::NONE), Key::Tab, Key::Enter],
+    "APPLE",
+    Text::new("")
+        .with_autocomplete(fruit_suggester)
+        .with_transformer(&|text| text.to_uppercase())
+);
+
+text_test!(
+    transformer_applies_to_the_default,
+    vec![Key::Enter],
+    "EU-WEST-1",
+    Text::new("")
+        .with_default("eu-west-1")
+        .with_transformer(&|text| text.to_uppercase())
+);
+
+#[test]
+fn transformer_applies_to_the_default_of_the_chain() {
+    let history = SharedHistory(Rc::new(RefCell::new(vec![String::from("eu-west-1")])));
+    let chain = crate::default_value::DefaultChain::new().with_history(history);
+
+    let mut backend = crate::prompts::test::fake_backend(vec![Key::Enter]);
+    let ans = Text::new("Region:")
+        .with_default_chain(chain)
+        .with_transformer(&|text| text.to_uppercase())
+        .prompt_with_backend(&mut backend)
+        .unwrap();
+
+    assert_eq!("EU-WEST-1", ans);
+}
+
+#[test]
+fn default_chain_provides_the_default_and_its_source() {
+    let history = SharedHistory(Rc::new(RefCell::new(vec![String::from("eu-west-1")])));
+    let chain = crate::default_value::DefaultChain::new()
+        .with_env("INQUIRE_TEST_UNSET_REGION")
+        .with_history(history)
+        .with_source_displayed(true);
+
+    let mut backend = crate::ui::test::FakeBackend::new(vec![Key::Enter]);
+    let ans = Text::new("Region:")
+        .with_default_chain(chain.clone())
+        .prompt_with_backend(&mut backend)
+        .unwrap();
+
+    assert_eq!("eu-west-1", ans);
+    assert!(
+        backend.frames()[0].has_token(&crate::ui::test::Token::DefaultValue(
+            "eu-west-1 from last used".into()
+        ))
+    );
+
+    let mut backend = crate::prompts::test::fake_backend(vec![Key::Enter]);
+    let ans = Text::new("Region:")
+        .with_default("us-east-1")
+        .with_default_chain(chain)
+        .prompt_with_backend(&mut backend)
+        .unwrap();
+
+    assert_eq!("us-east-1", ans);
+}
+
+#[test]
+#[cfg(feature = "mouse")]
+/// Text prompts leave the mouse to the terminal, keeping its text selection
+fn mouse_is_not_captured() {
+    let mut backend = crate::prompts::test::fake_backend(text_to_events!("abc\n"));
+
+    let ans = Text::new("Question?")
+        .prompt_with_backend(&mut backend)
+        .unwrap();
+
+    assert_eq!("abc", ans);
+    let output = String::from_utf8(backend.terminal_mut().get_buffer_content()).unwrap();
+    assert!(!output.contains("\x1b[?1000h"));
+}
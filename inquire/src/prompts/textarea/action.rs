@@ -0,0 +1,52 @@
+use crate::{
+    ui::{Key, KeyModifiers},
+    InnerAction, InputAction,
+};
+
+use super::config::TextAreaConfig;
+
+/// Set of actions for a TextAreaPrompt.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum TextAreaPromptAction {
+    /// Action on the value text input handler.
+    ValueInput(InputAction),
+    /// Inserts a line break at the cursor position.
+    InsertLineBreak,
+    /// Moves the cursor to the line above, keeping the column when possible.
+    MoveToPrevLine,
+    /// Moves the cursor to the line below, keeping the column when possible.
+    MoveToNextLine,
+    /// Moves the cursor to the start of the current line.
+    MoveToLineStart,
+    /// Moves the cursor to the end of the current line.
+    MoveToLineEnd,
+}
+
+impl InnerAction for TextAreaPromptAction {
+    type Config = TextAreaConfig;
+
+    fn from_key(key: Key, _config: &TextAreaConfig) -> Option<Self> {
+        let action = match key {
+            Key::Enter
+            | Key::Char('\n', KeyModifiers::NONE)
+            | Key::Char('j', KeyModifiers::CONTROL) => Self::InsertLineBreak,
+
+            Key::Up(KeyModifiers::NONE) | Key::Char('p', KeyModifiers::CONTROL) => {
+                Self::MoveToPrevLine
+            }
+            Key::Down(KeyModifiers::NONE) | Key::Char('n', KeyModifiers::CONTROL) => {
+                Self::MoveToNextLine
+            }
+
+            Key::Home | Key::Char('a', KeyModifiers::CONTROL) => Self::MoveToLineStart,
+            Key::End | Key::Char('e', KeyModifiers::CONTROL) => Self::MoveToLineEnd,
+
+            key => match InputAction::from_key(key, &()) {
+                Some(action) => Self::ValueInput(action),
+                None => return None,
+            },
+        };
+
+        Some(action)
+    }
+}
@@ -0,0 +1,16 @@
+use crate::TextArea;
+
+/// Configuration settings used in the execution of a TextAreaPrompt.
+#[derive(Copy, Clone, Debug)]
+pub struct TextAreaConfig {
+    /// Amount of lines of the content displayed at once.
+    pub page_size: usize,
+}
+
+impl From<&TextArea<'_>> for TextAreaConfig {
+    fn from(value: &TextArea<'_>) -> Self {
+        Self {
+            page_size: value.page_size,
+        }
+    }
+}
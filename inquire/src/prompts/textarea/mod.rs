@@ -0,0 +1,324 @@
+mod action;
+mod config;
+mod prompt;
+#[cfg(test)]
+mod test;
+
+pub use action::*;
+
+use crate::{
+    config::get_configuration,
+    error::{InquireError, InquireResult},
+    formatter::StringFormatter,
+    highlighter::Highlighter,
+    prompts::prompt::Prompt,
+    terminal::get_default_terminal,
+    ui::{Backend, RenderConfig, TextAreaBackend},
+    validator::StringValidator,
+};
+
+use self::prompt::TextAreaPrompt;
+
+/// Prompt for multi-line text inputs, such as descriptions or commit messages, edited directly in the terminal.
+///
+/// Different from the other prompts, pressing enter inserts a line break instead of submitting the answer. The user submits the answer by pressing `Ctrl+D`, while `Esc` still cancels the prompt.
+///
+/// The cursor can be moved across lines with the up and down arrows, and to the start or end of the current line with `Home` and `End`. When the content is longer than the page size, only the lines around the cursor are displayed.
+///
+/// This prompt is an alternative to the [`Editor`](crate::Editor) prompt for environments where opening an external editor is not available or desirable.
+///
+/// For short code snippets or configuration entries, such as JSON or YAML documents, the content can be syntax highlighted by a [`Highlighter`], the bracket next to the cursor can be highlighted along with the one matching it, and the validators can be run while the user types, so that syntax errors are displayed right away. See [`JsonValidator`](crate::validator::JsonValidator) and [`YamlStructureValidator`](crate::validator::YamlStructureValidator).
+///
+/// `TextArea` prompts provide several options of configuration:
+///
+/// - **Prompt message**: Required when creating the prompt.
+/// - **Initial value**: Initial value of the prompt's text input.
+/// - **Default value**: Default value returned when the user submits an empty response.
+/// - **Placeholder**: Short hint that describes the expected value of the input.
+/// - **Help message**: Message displayed at the line below the prompt.
+/// - **Formatter**: Custom formatter in case you need to pre-process the user input before showing it as the final answer.
+///   - By default, the first line is displayed, followed by the count of remaining lines.
+/// - **Validators**: Custom validators to the user's input, displaying an error message if the input does not pass the requirements.
+/// - **Page size**: Amount of lines displayed at once, 7 by default.
+/// - **Highlighter**: Syntax highlighter applied to each line of the content, none by default.
+/// - **Bracket matching**: Whether to highlight the bracket next to the cursor and the one matching it, false by default.
+/// - **Live validation**: Whether to run the validators whenever the content changes, instead of only on submission, false by default.
+///
+/// # Example
+///
+/// ```no_run
+/// use inquire::TextArea;
+///
+/// let description = TextArea::new("Describe the issue:")
+///     .with_placeholder("Steps to reproduce, expected and actual behavior")
+///     .prompt();
+///
+/// match description {
+///     Ok(description) => println!("{}", description),
+///     Err(_) => println!("An error happened when asking for the description."),
+/// }
+/// ```
+#[derive(Clone)]
+pub struct TextArea<'a> {
+    /// Message to be presented to the user.
+    pub message: &'a str,
+
+    /// Initial value of the prompt's text input.
+    ///
+    /// If you want to set a default value for the prompt, returned when the user's submission is empty, see [`default`].
+    ///
+    /// [`default`]: Self::default
+    pub initial_value: Option<&'a str>,
+
+    /// Default value, returned when the user input is empty.
+    pub default: Option<&'a str>,
+
+    /// Short hint that describes the expected value of the input.
+    pub placeholder: Option<&'a str>,
+
+    /// Help message to be presented to the user.
+    pub help_message: Option<&'a str>,
+
+    /// Function that formats the user input and presents it to the user as the final rendering of the prompt.
+    pub formatter: StringFormatter<'a>,
+
+    /// Collection of validators to apply to the user input.
+    ///
+    /// Validators are executed in the order they are stored, stopping at and displaying to the user
+    /// only the first validation error that might appear.
+    ///
+    /// The possible error is displayed to the user one line above the prompt.
+    pub validators: Vec<Box<dyn StringValidator>>,
+
+    /// Amount of lines of the content displayed at once.
+    pub page_size: usize,
+
+    /// Syntax highlighter applied to each line of the content.
+    pub highlighter: Option<Box<dyn Highlighter>>,
+
+    /// Whether to highlight the bracket next to the cursor and the one matching it.
+    pub bracket_matching: bool,
+
+    /// Whether to run the validators whenever the content changes, displaying
+    /// the possible error while the user types.
+    pub live_validation: bool,
+
+    /// RenderConfig to apply to the rendered interface.
+    ///
+    /// Note: The default render config considers if the NO_COLOR environment variable
+    /// is set to decide whether to render the colored config or the empty one.
+    ///
+    /// When overriding the config in a prompt, NO_COLOR is no longer considered and your
+    /// config is treated as the only source of truth. If you want to customize colors
+    /// and still support NO_COLOR, you will have to do this on your end.
+    pub render_config: RenderConfig<'a>,
+}
+
+impl<'a> TextArea<'a> {
+    /// Default formatter, displays the first line of the answer followed by
+    /// the count of remaining lines, if any.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use inquire::TextArea;
+    ///
+    /// let formatter = TextArea::DEFAULT_FORMATTER;
+    /// assert_eq!(String::from("Single line"), formatter("Single line"));
+    /// assert_eq!(String::from("First line [+2 lines]"), formatter("First line\nSecond\nThird"));
+    /// ```
+    pub const DEFAULT_FORMATTER: StringFormatter<'a> = &|ans| {
+        let mut lines = ans.lines();
+        let first = lines.next().unwrap_or_default();
+
+        match lines.count() {
+            0 => String::from(first),
+            1 => format!("{first} [+1 line]"),
+            n => format!("{first} [+{n} lines]"),
+        }
+    };
+
+    /// Default page size, equal to the global default page size [config::DEFAULT_PAGE_SIZE]
+    pub const DEFAULT_PAGE_SIZE: usize = crate::config::DEFAULT_PAGE_SIZE;
+
+    /// Default validators added to the [TextArea] prompt, none.
+    pub const DEFAULT_VALIDATORS: Vec<Box<dyn StringValidator>> = vec![];
+
+    /// Default value of whether brackets are matched.
+    pub const DEFAULT_BRACKET_MATCHING: bool = false;
+
+    /// Default value of whether the validators run whenever the content changes.
+    pub const DEFAULT_LIVE_VALIDATION: bool = false;
+
+    /// Default help message.
+    pub const DEFAULT_HELP_MESSAGE: Option<&'a str> =
+        Some("enter for a new line, ctrl+d to submit");
+
+    /// Creates a [TextArea] with the provided message and default options.
+    pub fn new(message: &'a str) -> Self {
+        Self {
+            message,
+            initial_value: None,
+            default: None,
+            placeholder: None,
+            help_message: Self::DEFAULT_HELP_MESSAGE,
+            formatter: Self::DEFAULT_FORMATTER,
+            validators: Self::DEFAULT_VALIDATORS,
+            page_size: Self::DEFAULT_PAGE_SIZE,
+            highlighter: None,
+            bracket_matching: Self::DEFAULT_BRACKET_MATCHING,
+            live_validation: Self::DEFAULT_LIVE_VALIDATION,
+            render_config: get_configuration(),
+        }
+    }
+
+    /// Sets the help message of the prompt.
+    pub fn with_help_message(mut self, message: &'a str) -> Self {
+        self.help_message = Some(message);
+        self
+    }
+
+    /// Removes the set help message.
+    pub fn without_help_message(mut self) -> Self {
+        self.help_message = None;
+        self
+    }
+
+    /// Sets the initial value of the prompt's text input.
+    ///
+    /// If you want to set a default value for the prompt, returned when the user's submission is empty, see [`with_default`].
+    ///
+    /// [`with_default`]: Self::with_default
+    pub fn with_initial_value(mut self, message: &'a str) -> Self {
+        self.initial_value = Some(message);
+        self
+    }
+
+    /// Sets the default input.
+    pub fn with_default(mut self, message: &'a str) -> Self {
+        self.default = Some(message);
+        self
+    }
+
+    /// Sets the placeholder.
+    pub fn with_placeholder(mut self, placeholder: &'a str) -> Self {
+        self.placeholder = Some(placeholder);
+        self
+    }
+
+    /// Sets the formatter.
+    pub fn with_formatter(mut self, formatter: StringFormatter<'a>) -> Self {
+        self.formatter = formatter;
+        self
+    }
+
+    /// Sets the page size, i.e. the amount of lines displayed at once.
+    pub fn with_page_size(mut self, page_size: usize) -> Self {
+        self.page_size = page_size;
+        self
+    }
+
+    /// Sets the syntax highlighter applied to each line of the content.
+    ///
+    /// The tokens are styled according to the
+    /// [`CodeRenderConfig`](crate::ui::CodeRenderConfig) of the prompt.
+    pub fn with_highlighter<H>(mut self, highlighter: H) -> Self
+    where
+        H: Highlighter + 'static,
+    {
+        self.highlighter = Some(Box::new(highlighter));
+        self
+    }
+
+    /// Sets whether to highlight the bracket next to the cursor and the one matching it.
+    pub fn with_bracket_matching(mut self, bracket_matching: bool) -> Self {
+        self.bracket_matching = bracket_matching;
+        self
+    }
+
+    /// Sets whether to run the validators whenever the content changes,
+    /// displaying the possible error while the user types.
+    ///
+    /// The answer is still validated on submission.
+    pub fn with_live_validation(mut self, live_validation: bool) -> Self {
+        self.live_validation = live_validation;
+        self
+    }
+
+    /// Adds a validator to the collection of validators. You might want to use this feature
+    /// in case you need to require certain features from the user's answer, such as
+    /// defining a limit of lines.
+    ///
+    /// Validators are executed in the order they are stored, stopping at and displaying to the user
+    /// only the first validation error that might appear.
+    ///
+    /// The possible error is displayed to the user one line above the prompt.
+    pub fn with_validator<V>(mut self, validator: V) -> Self
+    where
+        V: StringValidator + 'static,
+    {
+        self.validators.push(Box::new(validator));
+        self
+    }
+
+    /// Adds the validators to the collection of validators in the order they are given.
+    /// You might want to use this feature in case you need to require certain features
+    /// from the user's answer, such as defining a limit of lines.
+    ///
+    /// Validators are executed in the order they are stored, stopping at and displaying to the user
+    /// only the first validation error that might appear.
+    ///
+    /// The possible error is displayed to the user one line above the prompt.
+    pub fn with_validators(mut self, validators: &[Box<dyn StringValidator>]) -> Self {
+        for validator in validators {
+            #[allow(suspicious_double_ref_op)]
+            self.validators.push(validator.clone());
+        }
+        self
+    }
+
+    /// Sets the provided color theme to this prompt.
+    ///
+    /// Note: The default render config considers if the NO_COLOR environment variable
+    /// is set to decide whether to render the colored config or the empty one.
+    ///
+    /// When overriding the config in a prompt, NO_COLOR is no longer considered and your
+    /// config is treated as the only source of truth. If you want to customize colors
+    /// and still support NO_COLOR, you will have to do this on your end.
+    pub fn with_render_config(mut self, render_config: RenderConfig<'a>) -> Self {
+        self.render_config = render_config;
+        self
+    }
+
+    /// Parses the provided behavioral and rendering options and prompts
+    /// the CLI user for input according to the defined rules.
+    ///
+    /// This method is intended for flows where the user skipping/cancelling
+    /// the prompt - by pressing ESC - is considered normal behavior. In this case,
+    /// it does not return `Err(InquireError::OperationCanceled)`, but `Ok(None)`.
+    ///
+    /// Meanwhile, if the user does submit an answer, the method wraps the return
+    /// type with `Some`.
+    pub fn prompt_skippable(self) -> InquireResult<Option<String>> {
+        match self.prompt() {
+            Ok(answer) => Ok(Some(answer)),
+            Err(InquireError::OperationCanceled) => Ok(None),
+            Err(err) => Err(err),
+        }
+    }
+
+    /// Parses the provided behavioral and rendering options and prompts
+    /// the CLI user for input according to the defined rules.
+    pub fn prompt(self) -> InquireResult<String> {
+        let (input_reader, terminal) = get_default_terminal()?;
+        let mut backend = Backend::new(input_reader, terminal, self.render_config)?;
+        self.prompt_with_backend(&mut backend)
+    }
+
+    pub(crate) fn prompt_with_backend<B: TextAreaBackend>(
+        self,
+        backend: &mut B,
+    ) -> InquireResult<String> {
+        TextAreaPrompt::from(self).prompt(backend)
+    }
+}
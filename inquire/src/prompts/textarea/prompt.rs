@@ -0,0 +1,341 @@
+use unicode_segmentation::UnicodeSegmentation;
+
+use crate::{
+    error::InquireResult,
+    formatter::StringFormatter,
+    highlighter::Highlighter,
+    input::{clean_paste, Input, InputActionResult},
+    prompts::prompt::{ActionResult, Prompt},
+    ui::{Key, KeyModifiers, LineHighlights, TextAreaBackend},
+    utils::paginate,
+    validator::{ErrorMessage, StringValidator, Validation},
+    Action, InnerAction, InquireError, TextArea,
+};
+
+use super::{action::TextAreaPromptAction, config::TextAreaConfig};
+
+pub struct TextAreaPrompt<'a> {
+    message: &'a str,
+    config: TextAreaConfig,
+    default: Option<&'a str>,
+    help_message: Option<&'a str>,
+    input: Input,
+    formatter: StringFormatter<'a>,
+    validators: Vec<Box<dyn StringValidator>>,
+    error: Option<ErrorMessage>,
+    highlighter: Option<Box<dyn Highlighter>>,
+    bracket_matching: bool,
+    live_validation: bool,
+}
+
+impl<'a> From<TextArea<'a>> for TextAreaPrompt<'a> {
+    fn from(so: TextArea<'a>) -> Self {
+        let input = Input::new_with(so.initial_value.unwrap_or_default());
+        let input = if let Some(placeholder) = so.placeholder {
+            input.with_placeholder(placeholder)
+        } else {
+            input
+        };
+
+        Self {
+            message: so.message,
+            config: (&so).into(),
+            default: so.default,
+            help_message: so.help_message,
+            formatter: so.formatter,
+            input,
+            validators: so.validators,
+            error: None,
+            highlighter: so.highlighter,
+            bracket_matching: so.bracket_matching,
+            live_validation: so.live_validation,
+        }
+    }
+}
+
+impl<'a> TextAreaPrompt<'a> {
+    /// Returns the line and column, in graphemes, of the cursor.
+    fn cursor_position(&self) -> (usize, usize) {
+        let pre_cursor = self.input.pre_cursor();
+
+        match pre_cursor.rfind('\n') {
+            Some(idx) => (
+                pre_cursor.matches('\n').count(),
+                pre_cursor[idx + 1..].graphemes(true).count(),
+            ),
+            None => (0, pre_cursor.graphemes(true).count()),
+        }
+    }
+
+    /// Moves the cursor to the given line and column, clamping the column
+    /// to the length of the line.
+    fn move_cursor_to(&mut self, line: usize, column: usize) -> ActionResult {
+        let mut cursor = 0;
+
+        for (idx, content) in self.input.content().split('\n').enumerate() {
+            let length = content.graphemes(true).count();
+
+            if idx == line {
+                cursor += column.min(length);
+                break;
+            }
+
+            // line content plus the line break
+            cursor += length + 1;
+        }
+
+        if cursor == self.input.cursor() {
+            return ActionResult::Clean;
+        }
+
+        self.input = self.input.clone().with_cursor(cursor);
+        ActionResult::NeedsRedraw
+    }
+
+    fn line_count(&self) -> usize {
+        self.input.content().split('\n').count()
+    }
+
+    fn get_current_answer(&self) -> &str {
+        // Empty input with default values override any validators.
+        if self.input.content().is_empty() {
+            if let Some(val) = self.default {
+                return val;
+            }
+        }
+
+        self.input.content()
+    }
+
+    /// Byte positions of the bracket at or right before the cursor and the
+    /// one matching it, if any.
+    fn matched_brackets(&self) -> Option<(usize, usize)> {
+        let content = self.input.content();
+        let cursor = self.input.pre_cursor().len();
+
+        let at_cursor = content[cursor..].chars().next().map(|c| (cursor, c));
+        let before_cursor = content[..cursor]
+            .chars()
+            .next_back()
+            .map(|c| (cursor - c.len_utf8(), c));
+
+        for (position, c) in at_cursor.into_iter().chain(before_cursor) {
+            let (open, close, forward) = match c {
+                '(' => ('(', ')', true),
+                '[' => ('[', ']', true),
+                '{' => ('{', '}', true),
+                ')' => ('(', ')', false),
+                ']' => ('[', ']', false),
+                '}' => ('{', '}', false),
+                _ => continue,
+            };
+
+            let mut depth = 0usize;
+            let matched = match forward {
+                true => content[position..]
+                    .char_indices()
+                    .map(|(i, c)| (position + i, c))
+                    .find(|(_, c)| Self::is_pair_end(*c, open, close, &mut depth)),
+                false => content[..=position]
+                    .char_indices()
+                    .rev()
+                    .find(|(_, c)| Self::is_pair_end(*c, close, open, &mut depth)),
+            };
+
+            return matched.map(|(other, _)| (position, other));
+        }
+
+        None
+    }
+
+    /// Tracks the nesting depth of a bracket pair while scanning the content,
+    /// returning whether the given character closes the first bracket scanned.
+    fn is_pair_end(c: char, start: char, end: char, depth: &mut usize) -> bool {
+        if c == start {
+            *depth += 1;
+        } else if c == end {
+            *depth -= 1;
+            return *depth == 0;
+        }
+
+        false
+    }
+
+    /// Tokens found by the highlighter and byte positions of the matched
+    /// brackets, for each line of the content.
+    fn line_highlights(&self, lines: &[&str]) -> Vec<LineHighlights> {
+        let brackets = match self.bracket_matching {
+            true => self.matched_brackets(),
+            false => None,
+        };
+
+        let mut line_start = 0;
+        lines
+            .iter()
+            .map(|line| {
+                let tokens = match &self.highlighter {
+                    Some(highlighter) => highlighter.highlight(line),
+                    None => vec![],
+                };
+
+                let line_range = line_start..line_start + line.len();
+                let matched_brackets = brackets
+                    .iter()
+                    .flat_map(|(a, b)| [*a, *b])
+                    .filter(|position| line_range.contains(position))
+                    .map(|position| position - line_start)
+                    .collect();
+
+                // line content plus the line break
+                line_start += line.len() + 1;
+
+                LineHighlights {
+                    tokens,
+                    matched_brackets,
+                }
+            })
+            .collect()
+    }
+
+    /// Runs the validators when the content changes, if live validation is enabled.
+    fn on_input_result(&mut self, result: InputActionResult) -> InquireResult<ActionResult> {
+        if self.live_validation && result == InputActionResult::ContentChanged {
+            self.error = match self.validate_current_answer()? {
+                Validation::Valid => None,
+                Validation::Invalid(msg) => Some(msg),
+            };
+
+            return Ok(ActionResult::NeedsRedraw);
+        }
+
+        Ok(result.into())
+    }
+
+    fn validate_current_answer(&self) -> InquireResult<Validation> {
+        for validator in &self.validators {
+            match validator.validate(self.get_current_answer()) {
+                Ok(Validation::Valid) => {}
+                Ok(Validation::Invalid(msg)) => return Ok(Validation::Invalid(msg)),
+                Err(err) => return Err(InquireError::Custom(err)),
+            }
+        }
+
+        Ok(Validation::Valid)
+    }
+}
+
+impl<'a, Backend> Prompt<Backend> for TextAreaPrompt<'a>
+where
+    Backend: TextAreaBackend,
+{
+    type Config = TextAreaConfig;
+    type InnerAction = TextAreaPromptAction;
+    type Output = String;
+
+    fn message(&self) -> &str {
+        self.message
+    }
+
+    fn config(&self) -> &TextAreaConfig {
+        &self.config
+    }
+
+    fn format_answer(&self, answer: &String) -> String {
+        (self.formatter)(answer)
+    }
+
+    fn parse_key(&self, key: Key) -> Option<Action<TextAreaPromptAction>> {
+        // Enter is used to insert line breaks, so the answer is submitted with
+        // ctrl+d instead, which in turn can not be used to cancel the prompt.
+        match key {
+            Key::Char('d', KeyModifiers::CONTROL) => Some(Action::Submit),
+            Key::Escape | Key::Char('g', KeyModifiers::CONTROL) => Some(Action::Cancel),
+            Key::Char('c', KeyModifiers::CONTROL) => Some(Action::Interrupt),
+            key => TextAreaPromptAction::from_key(key, &self.config).map(Action::Inner),
+        }
+    }
+
+    fn handle_paste(&mut self, text: &str) -> InquireResult<ActionResult> {
+        let result = self.input.insert_str(&clean_paste(text, "\n"));
+        self.on_input_result(result)
+    }
+
+    fn submit(&mut self) -> InquireResult<Option<String>> {
+        let result = match self.validate_current_answer()? {
+            Validation::Valid => Some(self.get_current_answer().to_owned()),
+            Validation::Invalid(msg) => {
+                self.error = Some(msg);
+                None
+            }
+        };
+
+        Ok(result)
+    }
+
+    fn handle(&mut self, action: TextAreaPromptAction) -> InquireResult<ActionResult> {
+        let (line, column) = self.cursor_position();
+
+        let result = match action {
+            TextAreaPromptAction::ValueInput(input_action) => {
+                let result = self.input.handle(input_action);
+                self.on_input_result(result)?
+            }
+            TextAreaPromptAction::InsertLineBreak => {
+                let result = self.input.handle(crate::InputAction::Write('\n'));
+                self.on_input_result(result)?
+            }
+            TextAreaPromptAction::MoveToPrevLine => match line {
+                0 => ActionResult::Clean,
+                line => self.move_cursor_to(line - 1, column),
+            },
+            TextAreaPromptAction::MoveToNextLine => match line + 1 < self.line_count() {
+                true => self.move_cursor_to(line + 1, column),
+                false => ActionResult::Clean,
+            },
+            TextAreaPromptAction::MoveToLineStart => self.move_cursor_to(line, 0),
+            TextAreaPromptAction::MoveToLineEnd => self.move_cursor_to(line, usize::MAX),
+        };
+
+        Ok(result)
+    }
+
+    fn render(&self, backend: &mut Backend) -> InquireResult<()> {
+        let prompt = &self.message;
+
+        if let Some(err) = &self.error {
+            backend.render_error_message(err)?;
+        }
+
+        backend.render_text_area_prompt(prompt, self.default)?;
+
+        let (cursor_line, cursor_column) = self.cursor_position();
+
+        let contents = self.input.content().split('\n').collect::<Vec<&str>>();
+        let highlights = self.line_highlights(&contents);
+
+        let lines = if self.input.is_empty() {
+            vec![self.input.clone()]
+        } else {
+            self.input
+                .content()
+                .split('\n')
+                .enumerate()
+                .map(|(idx, line)| match idx == cursor_line {
+                    true => Input::new_with(line).with_cursor(cursor_column),
+                    false => Input::new_with(line),
+                })
+                .collect::<Vec<Input>>()
+        };
+
+        let page_size = self.config.page_size.max(1);
+        let page = paginate(page_size, &lines, Some(cursor_line));
+        let highlights = paginate(page_size, &highlights, Some(cursor_line)).content;
+        backend.render_text_area_lines(page, highlights)?;
+
+        if let Some(message) = self.help_message {
+            backend.render_help_message(message)?;
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,218 @@
+use crate::{
+    error::InquireResult,
+    highlighter::{Highlighter, JsonHighlighter},
+    ui::{
+        test::{FakeBackend, Token},
+        InputEvent, Key, KeyModifiers, LineHighlights,
+    },
+    validator::{ErrorMessage, JsonValidator, Validation},
+    InquireError, TextArea,
+};
+
+fn ctrl_d() -> Key {
+    Key::Char('d', KeyModifiers::CONTROL)
+}
+
+#[test]
+fn enter_inserts_line_breaks() -> InquireResult<()> {
+    let mut keys = Key::char_keys_from_str("first");
+    keys.push(Key::Enter);
+    keys.extend(Key::char_keys_from_str("second"));
+    keys.push(ctrl_d());
+    let mut backend = FakeBackend::new(keys);
+
+    let ans = TextArea::new("Question").prompt_with_backend(&mut backend)?;
+
+    assert_eq!("first\nsecond", ans);
+    assert!(backend
+        .frames()
+        .last()
+        .unwrap()
+        .has_token(&Token::AnsweredPrompt(
+            "Question".into(),
+            "first [+1 line]".into()
+        )));
+
+    Ok(())
+}
+
+#[test]
+fn vertical_arrows_keep_column_when_possible() -> InquireResult<()> {
+    let mut keys = vec![
+        Key::Up(KeyModifiers::NONE),
+        Key::Char('X', KeyModifiers::NONE),
+        Key::Down(KeyModifiers::NONE),
+        Key::Down(KeyModifiers::NONE),
+        Key::Char('Y', KeyModifiers::NONE),
+    ];
+    keys.push(ctrl_d());
+    let mut backend = FakeBackend::new(keys);
+
+    let ans = TextArea::new("Question")
+        .with_initial_value("a\nlonger line\nab")
+        .prompt_with_backend(&mut backend)?;
+
+    assert_eq!("a\nloXnger line\nabY", ans);
+
+    Ok(())
+}
+
+#[test]
+fn home_and_end_move_within_current_line() -> InquireResult<()> {
+    let keys = vec![
+        Key::Up(KeyModifiers::NONE),
+        Key::Home,
+        Key::Char('>', KeyModifiers::NONE),
+        Key::End,
+        Key::Char('<', KeyModifiers::NONE),
+        ctrl_d(),
+    ];
+    let mut backend = FakeBackend::new(keys);
+
+    let ans = TextArea::new("Question")
+        .with_initial_value("one\ntwo")
+        .prompt_with_backend(&mut backend)?;
+
+    assert_eq!(">one<\ntwo", ans);
+
+    Ok(())
+}
+
+#[test]
+fn escape_cancels_prompt() {
+    let mut backend = FakeBackend::new(vec![Key::Enter, Key::Escape]);
+
+    let ans = TextArea::new("Question").prompt_with_backend(&mut backend);
+
+    assert!(matches!(ans, Err(InquireError::OperationCanceled)));
+}
+
+#[test]
+fn empty_input_returns_default() -> InquireResult<()> {
+    let mut backend = FakeBackend::new(vec![ctrl_d()]);
+
+    let ans = TextArea::new("Question")
+        .with_default("nothing to add")
+        .prompt_with_backend(&mut backend)?;
+
+    assert_eq!("nothing to add", ans);
+
+    Ok(())
+}
+
+#[test]
+fn validator_blocks_submission() -> InquireResult<()> {
+    let mut keys = Key::char_keys_from_str("one line");
+    keys.push(ctrl_d());
+    keys.push(Key::Enter);
+    keys.extend(Key::char_keys_from_str("two lines"));
+    keys.push(ctrl_d());
+    let mut backend = FakeBackend::new(keys);
+
+    let validator = |ans: &str| match ans.lines().count() {
+        1 => Ok(Validation::Invalid("Write at least two lines".into())),
+        _ => Ok(Validation::Valid),
+    };
+
+    let ans = TextArea::new("Question")
+        .with_validator(validator)
+        .prompt_with_backend(&mut backend)?;
+
+    assert_eq!("one line\ntwo lines", ans);
+    assert!(
+        backend.frames()[9].has_token(&Token::ErrorMessage(ErrorMessage::Custom(
+            "Write at least two lines".into()
+        )))
+    );
+
+    Ok(())
+}
+
+#[test]
+fn highlighter_tokens_are_rendered() -> InquireResult<()> {
+    let mut backend = FakeBackend::new(vec![ctrl_d()]);
+
+    TextArea::new("Question")
+        .with_initial_value("{\"a\": 1}\nnot json")
+        .with_highlighter(JsonHighlighter)
+        .prompt_with_backend(&mut backend)?;
+
+    assert!(
+        backend.frames()[0].has_token(&Token::Highlights(LineHighlights {
+            tokens: JsonHighlighter.highlight("{\"a\": 1}"),
+            matched_brackets: vec![],
+        }))
+    );
+
+    Ok(())
+}
+
+#[test]
+fn brackets_next_to_cursor_are_matched_across_lines() -> InquireResult<()> {
+    let keys = vec![Key::Up(KeyModifiers::NONE), Key::End, ctrl_d()];
+    let mut backend = FakeBackend::new(keys);
+
+    TextArea::new("Question")
+        .with_initial_value("{\n  [1]\n}")
+        .with_bracket_matching(true)
+        .prompt_with_backend(&mut backend)?;
+
+    let outer = Token::Highlights(LineHighlights {
+        tokens: vec![],
+        matched_brackets: vec![0],
+    });
+    assert_eq!(
+        2,
+        backend.frames()[0]
+            .tokens()
+            .iter()
+            .filter(|t| **t == outer)
+            .count()
+    );
+    assert!(
+        backend.frames()[2].has_token(&Token::Highlights(LineHighlights {
+            tokens: vec![],
+            matched_brackets: vec![4, 2],
+        }))
+    );
+
+    Ok(())
+}
+
+#[test]
+fn live_validation_displays_errors_while_typing() -> InquireResult<()> {
+    let mut keys = Key::char_keys_from_str("[1]");
+    keys.push(ctrl_d());
+    let mut backend = FakeBackend::new(keys);
+
+    let ans = TextArea::new("Question")
+        .with_validator(JsonValidator)
+        .with_live_validation(true)
+        .prompt_with_backend(&mut backend)?;
+
+    let error = Token::ErrorMessage(ErrorMessage::Custom(
+        "Invalid JSON at line 1, column 3: expected ',' or ']'".into(),
+    ));
+
+    assert_eq!("[1]", ans);
+    assert!(backend.frames()[2].has_token(&error));
+    assert!(!backend.frames()[3]
+        .tokens()
+        .iter()
+        .any(|t| matches!(t, Token::ErrorMessage(_))));
+
+    Ok(())
+}
+
+#[test]
+fn pasted_lines_are_kept() -> InquireResult<()> {
+    let mut events = vec![InputEvent::Paste("first\r\nsecond\n".into())];
+    events.push(InputEvent::Key(ctrl_d()));
+    let mut backend = FakeBackend::with_events(events);
+
+    let ans = TextArea::new("Question").prompt_with_backend(&mut backend)?;
+
+    assert_eq!("first\nsecond", ans);
+
+    Ok(())
+}
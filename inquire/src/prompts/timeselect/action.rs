@@ -0,0 +1,52 @@
+use crate::{
+    ui::{Key, KeyModifiers},
+    InnerAction,
+};
+
+use super::config::TimeSelectConfig;
+
+/// Set of actions for a TimeSelectPrompt.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum TimeSelectPromptAction {
+    /// Move the cursor to the previous field, e.g. from minutes to hours.
+    SelectPrevField,
+    /// Move the cursor to the next field, e.g. from hours to minutes.
+    SelectNextField,
+    /// Increment the value of the selected field.
+    IncrementField,
+    /// Decrement the value of the selected field.
+    DecrementField,
+}
+
+impl InnerAction for TimeSelectPromptAction {
+    type Config = TimeSelectConfig;
+
+    fn from_key(key: Key, _: &TimeSelectConfig) -> Option<Self> {
+        let action = match key {
+            Key::Left(KeyModifiers::NONE) // standard
+            | Key::Char('b', KeyModifiers::CONTROL) // emacs
+            | Key::Char('h', KeyModifiers::NONE) // vim
+            => Self::SelectPrevField,
+
+            Key::Right(KeyModifiers::NONE) // standard
+            | Key::Tab
+            | Key::Char('f', KeyModifiers::CONTROL) // emacs
+            | Key::Char('l' | ':', KeyModifiers::NONE) // vim and time separator
+            => Self::SelectNextField,
+
+            Key::Up(KeyModifiers::NONE) // standard
+            | Key::Char('p', KeyModifiers::CONTROL) // emacs
+            | Key::Char('k', KeyModifiers::NONE) // vim
+            => Self::IncrementField,
+
+            Key::Down(KeyModifiers::NONE) // standard
+            | Key::Char('n', KeyModifiers::CONTROL) // emacs
+            | Key::Char('j', KeyModifiers::NONE) // vim
+            => Self::DecrementField,
+
+            _ => return None,
+        };
+
+        Some(action)
+    }
+}
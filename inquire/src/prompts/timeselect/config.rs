@@ -0,0 +1,24 @@
+use crate::{HourFormat, TimeSelect};
+
+/// Configuration settings used in the execution of a TimeSelectPrompt.
+#[derive(Copy, Clone, Debug)]
+pub struct TimeSelectConfig {
+    /// Whether hours are displayed in the 12-hour or 24-hour format.
+    pub hour_format: HourFormat,
+
+    /// Whether the seconds field is displayed and selectable.
+    pub with_seconds: bool,
+
+    /// Amount of minutes added or subtracted on each change of the minutes field.
+    pub minute_step: u32,
+}
+
+impl From<&TimeSelect<'_>> for TimeSelectConfig {
+    fn from(value: &TimeSelect<'_>) -> Self {
+        Self {
+            hour_format: value.hour_format,
+            with_seconds: value.with_seconds,
+            minute_step: value.minute_step,
+        }
+    }
+}
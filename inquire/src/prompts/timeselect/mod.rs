@@ -0,0 +1,270 @@
+mod action;
+mod config;
+mod picker;
+mod prompt;
+#[cfg(test)]
+mod test;
+
+pub use action::*;
+
+pub(crate) use config::TimeSelectConfig;
+pub(crate) use picker::TimePicker;
+
+use chrono::NaiveTime;
+
+use crate::{
+    config::get_configuration,
+    date_utils::get_current_time,
+    error::{InquireError, InquireResult},
+    formatter::TimeFormatter,
+    prompts::prompt::Prompt,
+    terminal::get_default_terminal,
+    ui::{date::TimeSelectBackend, Backend, RenderConfig},
+    validator::TimeValidator,
+};
+
+use self::prompt::TimeSelectPrompt;
+
+/// Format in which hours are displayed in time prompts.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum HourFormat {
+    /// Hours are displayed from 00 to 23.
+    TwentyFourHour,
+
+    /// Hours are displayed from 01 to 12, followed by an AM/PM field.
+    TwelveHour,
+}
+
+/// Prompt that allows user to select a time of the day from an interactive time picker. Available via the `date` feature.
+///
+/// By default, the initial selected time is the current time. The time is displayed as a sequence of fields, hours and minutes by default, and the user can move between them with the left and right arrows, changing the value of the selected field with the up and down arrows.
+///
+/// Values wrap around on each field, e.g. incrementing 59 minutes results in 00 minutes, without changing the hour.
+///
+/// `TimeSelect` prompts provide several options of configuration:
+///
+/// - **Prompt message**: Required when creating the prompt.
+/// - **Default value**: Time selected when the prompt is displayed, and the one returned if the user submits without any previous actions. Current time by default.
+/// - **Hour format**: Whether hours are displayed in the 24-hour format, the default, or in the 12-hour format with an additional AM/PM field.
+/// - **Seconds**: Whether a seconds field is displayed, false by default. When disabled, the returned time always has zero seconds.
+/// - **Minute step**: Amount of minutes added or subtracted on each change of the minutes field, 1 by default. Must be a divisor of 60.
+/// - **Help message**: Message displayed at the line below the prompt.
+/// - **Formatter**: Custom formatter in case you need to pre-process the user input before showing it as the final answer.
+///   - By default, the answer is formatted the same way it is displayed in the prompt.
+/// - **Validators**: Custom validators to the user's selected time, displaying an error message if the time does not pass the requirements.
+///
+/// # Example
+///
+/// ```no_run
+/// use chrono::NaiveTime;
+/// use inquire::{HourFormat, TimeSelect};
+///
+/// let time = TimeSelect::new("When should the meeting start?")
+///     .with_starting_time(NaiveTime::from_hms_opt(9, 0, 0).unwrap())
+///     .with_hour_format(HourFormat::TwelveHour)
+///     .with_minute_step(15)
+///     .prompt();
+///
+/// match time {
+///     Ok(time) => println!("Meeting scheduled at {}", time),
+///     Err(_) => println!("There was an error in the system."),
+/// }
+/// ```
+#[derive(Clone)]
+pub struct TimeSelect<'a> {
+    /// Message to be presented to the user.
+    pub message: &'a str,
+
+    /// Starting time to be selected.
+    pub starting_time: NaiveTime,
+
+    /// Whether hours are displayed in the 12-hour or 24-hour format.
+    pub hour_format: HourFormat,
+
+    /// Whether the seconds field is displayed and selectable.
+    pub with_seconds: bool,
+
+    /// Amount of minutes added or subtracted on each change of the minutes field.
+    pub minute_step: u32,
+
+    /// Help message to be presented to the user.
+    pub help_message: Option<&'a str>,
+
+    /// Function that formats the user input and presents it to the user as the final rendering of the prompt.
+    ///
+    /// If not set, the answer is formatted the same way it is displayed in the prompt.
+    pub formatter: Option<TimeFormatter<'a>>,
+
+    /// Collection of validators to apply to the user input.
+    ///
+    /// Validators are executed in the order they are stored, stopping at and displaying to the user
+    /// only the first validation error that might appear.
+    ///
+    /// The possible error is displayed to the user one line above the prompt.
+    pub validators: Vec<Box<dyn TimeValidator>>,
+
+    /// RenderConfig to apply to the rendered interface.
+    ///
+    /// Note: The default render config considers if the NO_COLOR environment variable
+    /// is set to decide whether to render the colored config or the empty one.
+    ///
+    /// When overriding the config in a prompt, NO_COLOR is no longer considered and your
+    /// config is treated as the only source of truth. If you want to customize colors
+    /// and still support NO_COLOR, you will have to do this on your end.
+    pub render_config: RenderConfig<'a>,
+}
+
+impl<'a> TimeSelect<'a> {
+    /// Default hour format, 24-hour.
+    pub const DEFAULT_HOUR_FORMAT: HourFormat = HourFormat::TwentyFourHour;
+
+    /// Default value of whether seconds are displayed, false.
+    pub const DEFAULT_WITH_SECONDS: bool = false;
+
+    /// Default minute step, 1.
+    pub const DEFAULT_MINUTE_STEP: u32 = 1;
+
+    /// Default help message.
+    pub const DEFAULT_HELP_MESSAGE: Option<&'a str> =
+        Some("←→ to move between fields, ↑↓ to change the value, enter to select");
+
+    /// Default validators added to the [TimeSelect] prompt, none.
+    pub const DEFAULT_VALIDATORS: Vec<Box<dyn TimeValidator>> = vec![];
+
+    /// Creates a [TimeSelect] with the provided message, along with default configuration values.
+    pub fn new(message: &'a str) -> Self {
+        Self {
+            message,
+            starting_time: get_current_time(),
+            hour_format: Self::DEFAULT_HOUR_FORMAT,
+            with_seconds: Self::DEFAULT_WITH_SECONDS,
+            minute_step: Self::DEFAULT_MINUTE_STEP,
+            help_message: Self::DEFAULT_HELP_MESSAGE,
+            formatter: None,
+            validators: Self::DEFAULT_VALIDATORS,
+            render_config: get_configuration(),
+        }
+    }
+
+    /// Sets the help message of the prompt.
+    pub fn with_help_message(mut self, message: &'a str) -> Self {
+        self.help_message = Some(message);
+        self
+    }
+
+    /// Removes the set help message.
+    pub fn without_help_message(mut self) -> Self {
+        self.help_message = None;
+        self
+    }
+
+    /// Sets the default time of the prompt. Equivalent to [TimeSelect::with_starting_time](TimeSelect::with_starting_time).
+    pub fn with_default(self, default: NaiveTime) -> Self {
+        self.with_starting_time(default)
+    }
+
+    /// Sets the starting time. Equivalent to [TimeSelect::with_default](TimeSelect::with_default).
+    pub fn with_starting_time(mut self, starting_time: NaiveTime) -> Self {
+        self.starting_time = starting_time;
+        self
+    }
+
+    /// Sets the hour format.
+    pub fn with_hour_format(mut self, hour_format: HourFormat) -> Self {
+        self.hour_format = hour_format;
+        self
+    }
+
+    /// Sets whether the seconds field is displayed.
+    pub fn with_seconds(mut self, with_seconds: bool) -> Self {
+        self.with_seconds = with_seconds;
+        self
+    }
+
+    /// Sets the minute step. It must be a divisor of 60, such as 5, 15 or 30.
+    pub fn with_minute_step(mut self, minute_step: u32) -> Self {
+        self.minute_step = minute_step;
+        self
+    }
+
+    /// Adds a validator to the collection of validators. You might want to use this feature
+    /// in case you need to limit the user to specific choices, such as business hours.
+    ///
+    /// Validators are executed in the order they are stored, stopping at and displaying to the user
+    /// only the first validation error that might appear.
+    ///
+    /// The possible error is displayed to the user one line above the prompt.
+    pub fn with_validator<V>(mut self, validator: V) -> Self
+    where
+        V: TimeValidator + 'static,
+    {
+        self.validators.push(Box::new(validator));
+        self
+    }
+
+    /// Adds the validators to the collection of validators in the order they are given.
+    /// You might want to use this feature in case you need to limit the user to specific
+    /// choices, such as business hours.
+    ///
+    /// Validators are executed in the order they are stored, stopping at and displaying to the user
+    /// only the first validation error that might appear.
+    ///
+    /// The possible error is displayed to the user one line above the prompt.
+    pub fn with_validators(mut self, validators: &[Box<dyn TimeValidator>]) -> Self {
+        for validator in validators {
+            self.validators.push(validator.clone());
+        }
+        self
+    }
+
+    /// Sets the formatter.
+    pub fn with_formatter(mut self, formatter: TimeFormatter<'a>) -> Self {
+        self.formatter = Some(formatter);
+        self
+    }
+
+    /// Sets the provided color theme to this prompt.
+    ///
+    /// Note: The default render config considers if the NO_COLOR environment variable
+    /// is set to decide whether to render the colored config or the empty one.
+    ///
+    /// When overriding the config in a prompt, NO_COLOR is no longer considered and your
+    /// config is treated as the only source of truth. If you want to customize colors
+    /// and still support NO_COLOR, you will have to do this on your end.
+    pub fn with_render_config(mut self, render_config: RenderConfig<'a>) -> Self {
+        self.render_config = render_config;
+        self
+    }
+
+    /// Parses the provided behavioral and rendering options and prompts
+    /// the CLI user for input according to the defined rules.
+    ///
+    /// This method is intended for flows where the user skipping/cancelling
+    /// the prompt - by pressing ESC - is considered normal behavior. In this case,
+    /// it does not return `Err(InquireError::OperationCanceled)`, but `Ok(None)`.
+    ///
+    /// Meanwhile, if the user does submit an answer, the method wraps the return
+    /// type with `Some`.
+    pub fn prompt_skippable(self) -> InquireResult<Option<NaiveTime>> {
+        match self.prompt() {
+            Ok(answer) => Ok(Some(answer)),
+            Err(InquireError::OperationCanceled) => Ok(None),
+            Err(err) => Err(err),
+        }
+    }
+
+    /// Parses the provided behavioral and rendering options and prompts
+    /// the CLI user for input according to the defined rules.
+    pub fn prompt(self) -> InquireResult<NaiveTime> {
+        let (input_reader, terminal) = get_default_terminal()?;
+        let mut backend = Backend::new(input_reader, terminal, self.render_config)?;
+        self.prompt_with_backend(&mut backend)
+    }
+
+    pub(crate) fn prompt_with_backend<B: TimeSelectBackend>(
+        self,
+        backend: &mut B,
+    ) -> InquireResult<NaiveTime> {
+        TimeSelectPrompt::new(self)?.prompt(backend)
+    }
+}
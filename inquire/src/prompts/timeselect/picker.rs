@@ -0,0 +1,187 @@
+use std::ops::Range;
+
+use chrono::{NaiveTime, Timelike};
+
+use crate::{prompts::prompt::ActionResult, HourFormat};
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+enum TimeField {
+    Hour,
+    Minute,
+    Second,
+    Period,
+}
+
+/// State of an interactive time picker, where the user moves between the
+/// fields of a time (hours, minutes and so on) and increments or decrements
+/// the selected one.
+///
+/// Values wrap around on each field, e.g. incrementing 59 minutes results in
+/// 0 minutes, without changing the hour.
+#[derive(Clone, Debug)]
+pub struct TimePicker {
+    time: NaiveTime,
+    fields: Vec<TimeField>,
+    cursor: usize,
+    hour_format: HourFormat,
+    minute_step: u32,
+}
+
+impl TimePicker {
+    /// Creates a picker starting at `time`, discarding the parts of it that
+    /// can not be selected: seconds when they are not displayed and minutes
+    /// that are not a multiple of the step.
+    ///
+    /// `minute_step` must be a divisor of 60.
+    pub fn new(
+        time: NaiveTime,
+        hour_format: HourFormat,
+        with_seconds: bool,
+        minute_step: u32,
+    ) -> Self {
+        let mut fields = vec![TimeField::Hour, TimeField::Minute];
+        if with_seconds {
+            fields.push(TimeField::Second);
+        }
+        if hour_format == HourFormat::TwelveHour {
+            fields.push(TimeField::Period);
+        }
+
+        let minute = time.minute() - time.minute() % minute_step;
+        let second = if with_seconds { time.second() } else { 0 };
+        let time = NaiveTime::from_hms_opt(time.hour(), minute, second).unwrap_or(time);
+
+        Self {
+            time,
+            fields,
+            cursor: 0,
+            hour_format,
+            minute_step,
+        }
+    }
+
+    /// Currently selected time.
+    pub fn time(&self) -> NaiveTime {
+        self.time
+    }
+
+    pub fn select_prev_field(&mut self) -> ActionResult {
+        if self.cursor == 0 {
+            return ActionResult::Clean;
+        }
+
+        self.cursor -= 1;
+        ActionResult::NeedsRedraw
+    }
+
+    pub fn select_next_field(&mut self) -> ActionResult {
+        if self.cursor + 1 >= self.fields.len() {
+            return ActionResult::Clean;
+        }
+
+        self.cursor += 1;
+        ActionResult::NeedsRedraw
+    }
+
+    pub fn increment(&mut self) -> ActionResult {
+        self.shift(true)
+    }
+
+    pub fn decrement(&mut self) -> ActionResult {
+        self.shift(false)
+    }
+
+    fn shift(&mut self, increment: bool) -> ActionResult {
+        let wrap = |value: u32, amount: u32, modulo: u32| match increment {
+            true => (value + amount) % modulo,
+            false => (value + modulo - amount) % modulo,
+        };
+
+        let (mut hour, mut minute, mut second) =
+            (self.time.hour(), self.time.minute(), self.time.second());
+
+        match self.fields[self.cursor] {
+            TimeField::Hour => hour = wrap(hour, 1, 24),
+            TimeField::Minute => minute = wrap(minute, self.minute_step, 60),
+            TimeField::Second => second = wrap(second, 1, 60),
+            TimeField::Period => hour = wrap(hour, 12, 24),
+        }
+
+        match NaiveTime::from_hms_opt(hour, minute, second) {
+            Some(time) if time != self.time => {
+                self.time = time;
+                ActionResult::NeedsRedraw
+            }
+            _ => ActionResult::Clean,
+        }
+    }
+
+    /// Returns the textual representation of the time, along with the range
+    /// of the currently selected field in it.
+    pub fn display(&self) -> (String, Range<usize>) {
+        let mut content = String::new();
+        let mut selected = 0..0;
+
+        for (i, field) in self.fields.iter().enumerate() {
+            let value = match field {
+                TimeField::Hour => match self.hour_format {
+                    HourFormat::TwentyFourHour => format!("{:02}", self.time.hour()),
+                    HourFormat::TwelveHour => format!("{:02}", self.time.hour12().1),
+                },
+                TimeField::Minute => format!("{:02}", self.time.minute()),
+                TimeField::Second => format!("{:02}", self.time.second()),
+                TimeField::Period => match self.time.hour12().0 {
+                    true => String::from("PM"),
+                    false => String::from("AM"),
+                },
+            };
+
+            match field {
+                TimeField::Hour => {}
+                TimeField::Minute | TimeField::Second => content.push(':'),
+                TimeField::Period => content.push(' '),
+            }
+
+            if i == self.cursor {
+                selected = content.len()..content.len() + value.len();
+            }
+
+            content.push_str(&value);
+        }
+
+        (content, selected)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use chrono::NaiveTime;
+
+    use crate::HourFormat;
+
+    use super::TimePicker;
+
+    #[test]
+    fn starting_time_is_truncated_to_selectable_values() {
+        let time = NaiveTime::from_hms_opt(10, 37, 42).unwrap();
+
+        let picker = TimePicker::new(time, HourFormat::TwentyFourHour, false, 15);
+        assert_eq!(NaiveTime::from_hms_opt(10, 30, 0).unwrap(), picker.time());
+
+        let picker = TimePicker::new(time, HourFormat::TwentyFourHour, true, 1);
+        assert_eq!(time, picker.time());
+    }
+
+    #[test]
+    fn display_marks_selected_field() {
+        let time = NaiveTime::from_hms_opt(15, 5, 9).unwrap();
+
+        let mut picker = TimePicker::new(time, HourFormat::TwelveHour, true, 1);
+        assert_eq!((String::from("03:05:09 PM"), 0..2), picker.display());
+
+        picker.select_next_field();
+        picker.select_next_field();
+        picker.select_next_field();
+        assert_eq!((String::from("03:05:09 PM"), 9..11), picker.display());
+    }
+}
@@ -0,0 +1,129 @@
+use chrono::NaiveTime;
+
+use crate::{
+    error::InquireResult,
+    formatter::TimeFormatter,
+    prompts::prompt::{ActionResult, Prompt},
+    ui::date::TimeSelectBackend,
+    validator::{ErrorMessage, TimeValidator, Validation},
+    InquireError, TimeSelect,
+};
+
+use super::{action::TimeSelectPromptAction, config::TimeSelectConfig, TimePicker};
+
+pub struct TimeSelectPrompt<'a> {
+    message: &'a str,
+    config: TimeSelectConfig,
+    picker: TimePicker,
+    help_message: Option<&'a str>,
+    formatter: Option<TimeFormatter<'a>>,
+    validators: Vec<Box<dyn TimeValidator>>,
+    error: Option<ErrorMessage>,
+}
+
+impl<'a> TimeSelectPrompt<'a> {
+    pub fn new(so: TimeSelect<'a>) -> InquireResult<Self> {
+        if so.minute_step == 0 || 60 % so.minute_step != 0 {
+            return Err(InquireError::InvalidConfiguration(
+                "Minute step must be a divisor of 60".into(),
+            ));
+        }
+
+        let picker = TimePicker::new(
+            so.starting_time,
+            so.hour_format,
+            so.with_seconds,
+            so.minute_step,
+        );
+
+        Ok(Self {
+            message: so.message,
+            config: (&so).into(),
+            picker,
+            help_message: so.help_message,
+            formatter: so.formatter,
+            validators: so.validators,
+            error: None,
+        })
+    }
+
+    fn validate_current_answer(&self) -> InquireResult<Validation> {
+        for validator in &self.validators {
+            match validator.validate(self.cur_answer()) {
+                Ok(Validation::Valid) => {}
+                Ok(Validation::Invalid(msg)) => return Ok(Validation::Invalid(msg)),
+                Err(err) => return Err(InquireError::Custom(err)),
+            }
+        }
+
+        Ok(Validation::Valid)
+    }
+
+    fn cur_answer(&self) -> NaiveTime {
+        self.picker.time()
+    }
+}
+
+impl<'a, B> Prompt<B> for TimeSelectPrompt<'a>
+where
+    B: TimeSelectBackend,
+{
+    type Config = TimeSelectConfig;
+    type InnerAction = TimeSelectPromptAction;
+    type Output = NaiveTime;
+
+    fn message(&self) -> &str {
+        self.message
+    }
+
+    fn format_answer(&self, answer: &NaiveTime) -> String {
+        match self.formatter {
+            Some(formatter) => formatter(*answer),
+            None => self.picker.display().0,
+        }
+    }
+
+    fn config(&self) -> &TimeSelectConfig {
+        &self.config
+    }
+
+    fn submit(&mut self) -> InquireResult<Option<NaiveTime>> {
+        let answer = match self.validate_current_answer()? {
+            Validation::Valid => Some(self.cur_answer()),
+            Validation::Invalid(msg) => {
+                self.error = Some(msg);
+                None
+            }
+        };
+
+        Ok(answer)
+    }
+
+    fn handle(&mut self, action: TimeSelectPromptAction) -> InquireResult<ActionResult> {
+        let result = match action {
+            TimeSelectPromptAction::SelectPrevField => self.picker.select_prev_field(),
+            TimeSelectPromptAction::SelectNextField => self.picker.select_next_field(),
+            TimeSelectPromptAction::IncrementField => self.picker.increment(),
+            TimeSelectPromptAction::DecrementField => self.picker.decrement(),
+        };
+
+        Ok(result)
+    }
+
+    fn render(&self, backend: &mut B) -> InquireResult<()> {
+        let prompt = &self.message;
+
+        if let Some(err) = &self.error {
+            backend.render_error_message(err)?;
+        }
+
+        let (time, selected_field) = self.picker.display();
+        backend.render_time_prompt(prompt, &time, selected_field)?;
+
+        if let Some(help_message) = self.help_message {
+            backend.render_help_message(help_message)?;
+        }
+
+        Ok(())
+    }
+}
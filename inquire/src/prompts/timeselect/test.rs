@@ -0,0 +1,150 @@
+use chrono::NaiveTime;
+
+use crate::{
+    error::InquireResult,
+    ui::{
+        test::{FakeBackend, Token},
+        Key, KeyModifiers,
+    },
+    validator::{ErrorMessage, Validation},
+    HourFormat, InquireError, TimeSelect,
+};
+
+fn time(hour: u32, minute: u32, second: u32) -> NaiveTime {
+    NaiveTime::from_hms_opt(hour, minute, second).unwrap()
+}
+
+#[test]
+fn enter_returns_starting_time() -> InquireResult<()> {
+    let mut backend = FakeBackend::new(vec![Key::Enter]);
+
+    let ans = TimeSelect::new("Question")
+        .with_starting_time(time(14, 30, 0))
+        .prompt_with_backend(&mut backend)?;
+
+    assert_eq!(time(14, 30, 0), ans);
+
+    Ok(())
+}
+
+#[test]
+fn fields_wrap_around_independently() -> InquireResult<()> {
+    let mut backend = FakeBackend::new(vec![
+        Key::Up(KeyModifiers::NONE),
+        Key::Right(KeyModifiers::NONE),
+        Key::Up(KeyModifiers::NONE),
+        Key::Enter,
+    ]);
+
+    let ans = TimeSelect::new("Question")
+        .with_starting_time(time(23, 59, 0))
+        .prompt_with_backend(&mut backend)?;
+
+    assert_eq!(time(0, 0, 0), ans);
+
+    Ok(())
+}
+
+#[test]
+fn minute_step_is_applied() -> InquireResult<()> {
+    let mut backend = FakeBackend::new(vec![
+        Key::Right(KeyModifiers::NONE),
+        Key::Down(KeyModifiers::NONE),
+        Key::Down(KeyModifiers::NONE),
+        Key::Down(KeyModifiers::NONE),
+        Key::Enter,
+    ]);
+
+    let ans = TimeSelect::new("Question")
+        .with_starting_time(time(9, 20, 0))
+        .with_minute_step(15)
+        .prompt_with_backend(&mut backend)?;
+
+    assert_eq!(time(9, 30, 0), ans);
+
+    Ok(())
+}
+
+#[test]
+fn twelve_hour_format_is_rendered_with_period() -> InquireResult<()> {
+    let mut backend = FakeBackend::new(vec![
+        Key::Right(KeyModifiers::NONE),
+        Key::Right(KeyModifiers::NONE),
+        Key::Up(KeyModifiers::NONE),
+        Key::Enter,
+    ]);
+
+    let ans = TimeSelect::new("Question")
+        .with_starting_time(time(8, 45, 0))
+        .with_hour_format(HourFormat::TwelveHour)
+        .prompt_with_backend(&mut backend)?;
+
+    assert_eq!(time(20, 45, 0), ans);
+
+    let frames = backend.frames();
+    assert!(frames[0].has_token(&Token::Time {
+        content: "08:45 AM".into(),
+        selected: Some(0..2),
+    }));
+    assert!(frames[3].has_token(&Token::Time {
+        content: "08:45 PM".into(),
+        selected: Some(6..8),
+    }));
+    assert!(frames[4].has_token(&Token::AnsweredPrompt("Question".into(), "08:45 PM".into())));
+
+    Ok(())
+}
+
+#[test]
+fn seconds_field_is_selectable_when_enabled() -> InquireResult<()> {
+    let mut backend = FakeBackend::new(vec![
+        Key::Right(KeyModifiers::NONE),
+        Key::Right(KeyModifiers::NONE),
+        Key::Down(KeyModifiers::NONE),
+        Key::Enter,
+    ]);
+
+    let ans = TimeSelect::new("Question")
+        .with_starting_time(time(12, 0, 0))
+        .with_seconds(true)
+        .prompt_with_backend(&mut backend)?;
+
+    assert_eq!(time(12, 0, 59), ans);
+
+    Ok(())
+}
+
+#[test]
+fn validator_blocks_submission() -> InquireResult<()> {
+    let mut backend = FakeBackend::new(vec![Key::Enter, Key::Down(KeyModifiers::NONE), Key::Enter]);
+
+    let validator = |t: NaiveTime| match t < time(18, 0, 0) {
+        true => Ok(Validation::Valid),
+        false => Ok(Validation::Invalid("Too late".into())),
+    };
+
+    let ans = TimeSelect::new("Question")
+        .with_starting_time(time(18, 0, 0))
+        .with_validator(validator)
+        .prompt_with_backend(&mut backend)?;
+
+    assert_eq!(time(17, 0, 0), ans);
+    assert!(
+        backend.frames()[1].has_token(&Token::ErrorMessage(ErrorMessage::Custom(
+            "Too late".into()
+        )))
+    );
+
+    Ok(())
+}
+
+#[test]
+fn invalid_minute_step_is_invalid_configuration() {
+    let mut backend = FakeBackend::new(vec![Key::Enter]);
+
+    let ans = TimeSelect::new("Question")
+        .with_minute_step(7)
+        .prompt_with_backend(&mut backend);
+
+    assert!(matches!(ans, Err(InquireError::InvalidConfiguration(_))));
+}
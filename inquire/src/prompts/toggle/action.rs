@@ -0,0 +1,44 @@
+use crate::{
+    ui::{Key, KeyModifiers},
+    InnerAction,
+};
+
+use super::config::ToggleConfig;
+
+/// Set of actions for a TogglePrompt.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum TogglePromptAction {
+    /// Selects the "on" state, displayed on the left.
+    TurnOn,
+    /// Selects the "off" state, displayed on the right.
+    TurnOff,
+    /// Switches to the state not currently selected.
+    Toggle,
+}
+
+impl InnerAction for TogglePromptAction {
+    type Config = ToggleConfig;
+
+    fn from_key(key: Key, _config: &ToggleConfig) -> Option<Self> {
+        let action = match key {
+            Key::Left(KeyModifiers::NONE) // standard
+            | Key::Char('b', KeyModifiers::CONTROL) // emacs
+            | Key::Char('h', KeyModifiers::NONE) // vim
+            => Self::TurnOn,
+
+            Key::Right(KeyModifiers::NONE) // standard
+            | Key::Char('f', KeyModifiers::CONTROL) // emacs
+            | Key::Char('l', KeyModifiers::NONE) // vim
+            => Self::TurnOff,
+
+            Key::Char(' ', KeyModifiers::NONE)
+            | Key::Tab
+            | Key::Up(KeyModifiers::NONE)
+            | Key::Down(KeyModifiers::NONE) => Self::Toggle,
+
+            _ => return None,
+        };
+
+        Some(action)
+    }
+}
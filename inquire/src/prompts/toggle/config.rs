@@ -0,0 +1,11 @@
+use crate::Toggle;
+
+/// Configuration settings used in the execution of a TogglePrompt.
+#[derive(Copy, Clone, Debug)]
+pub struct ToggleConfig {}
+
+impl From<&Toggle<'_>> for ToggleConfig {
+    fn from(_value: &Toggle<'_>) -> Self {
+        Self {}
+    }
+}
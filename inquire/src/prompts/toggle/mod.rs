@@ -0,0 +1,174 @@
+mod action;
+mod config;
+mod prompt;
+#[cfg(test)]
+mod test;
+
+pub use action::*;
+
+use crate::{
+    config::get_configuration,
+    error::{InquireError, InquireResult},
+    formatter::BoolFormatter,
+    prompts::prompt::Prompt,
+    terminal::get_default_terminal,
+    ui::{Backend, RenderConfig, ToggleBackend},
+};
+
+use self::prompt::TogglePrompt;
+
+/// Prompt for binary switches, rendering both states side by side, e.g. `[On]  Off`.
+///
+/// Unlike [`Confirm`](crate::Confirm), where the user types the answer, the user switches between the two states with the arrow keys: left selects the "on" state and right selects the "off" state, while space, tab and the up and down arrows flip the current state. Pressing enter submits the selected state, `true` for "on" and `false` for "off".
+///
+/// Toggle prompts provide several options of configuration:
+///
+/// - **Prompt message**: Required when creating the prompt.
+/// - **Starting value**: State selected when the prompt is first rendered, "off" by default.
+/// - **Labels**: Text displayed for each of the states, "On" and "Off" by default.
+/// - **Help message**: Message displayed at the line below the prompt.
+/// - **Formatter**: Custom formatter in case you need to pre-process the user input before showing it as the final answer.
+///   - Displays the label of the selected state by default.
+///
+/// # Example
+///
+/// ```no_run
+/// use inquire::Toggle;
+///
+/// let ans = Toggle::new("Telemetry:")
+///     .with_labels("Enabled", "Disabled")
+///     .with_starting_value(true)
+///     .prompt();
+///
+/// match ans {
+///     Ok(true) => println!("Thanks for helping us improve!"),
+///     Ok(false) => println!("No data will be collected."),
+///     Err(_) => println!("Error with questionnaire, try again later"),
+/// }
+/// ```
+#[derive(Clone)]
+pub struct Toggle<'a> {
+    /// Message to be presented to the user.
+    pub message: &'a str,
+
+    /// State selected when the prompt is first rendered.
+    pub starting_value: bool,
+
+    /// Label displayed for the "on" state, which returns `true`.
+    pub on_label: &'a str,
+
+    /// Label displayed for the "off" state, which returns `false`.
+    pub off_label: &'a str,
+
+    /// Help message to be presented to the user.
+    pub help_message: Option<&'a str>,
+
+    /// Function that formats the user input and presents it to the user as the final rendering of the prompt.
+    ///
+    /// When `None`, the label of the selected state is displayed.
+    pub formatter: Option<BoolFormatter<'a>>,
+
+    /// RenderConfig to apply to the rendered interface.
+    ///
+    /// Note: The default render config considers if the NO_COLOR environment variable
+    /// is set to decide whether to render the colored config or the empty one.
+    ///
+    /// When overriding the config in a prompt, NO_COLOR is no longer considered and your
+    /// config is treated as the only source of truth. If you want to customize colors
+    /// and still support NO_COLOR, you will have to do this on your end.
+    pub render_config: RenderConfig<'a>,
+}
+
+impl<'a> Toggle<'a> {
+    /// Default label of the "on" state.
+    pub const DEFAULT_ON_LABEL: &'a str = "On";
+
+    /// Default label of the "off" state.
+    pub const DEFAULT_OFF_LABEL: &'a str = "Off";
+
+    /// Default state selected when the prompt is first rendered.
+    pub const DEFAULT_STARTING_VALUE: bool = false;
+
+    /// Creates a [Toggle] with the provided message, along with default configuration values.
+    pub fn new(message: &'a str) -> Self {
+        Self {
+            message,
+            starting_value: Self::DEFAULT_STARTING_VALUE,
+            on_label: Self::DEFAULT_ON_LABEL,
+            off_label: Self::DEFAULT_OFF_LABEL,
+            help_message: None,
+            formatter: None,
+            render_config: get_configuration(),
+        }
+    }
+
+    /// Sets the state selected when the prompt is first rendered.
+    pub fn with_starting_value(mut self, starting_value: bool) -> Self {
+        self.starting_value = starting_value;
+        self
+    }
+
+    /// Sets the labels displayed for the "on" and "off" states, respectively.
+    pub fn with_labels(mut self, on_label: &'a str, off_label: &'a str) -> Self {
+        self.on_label = on_label;
+        self.off_label = off_label;
+        self
+    }
+
+    /// Sets the help message of the prompt.
+    pub fn with_help_message(mut self, message: &'a str) -> Self {
+        self.help_message = Some(message);
+        self
+    }
+
+    /// Sets the formatter.
+    pub fn with_formatter(mut self, formatter: BoolFormatter<'a>) -> Self {
+        self.formatter = Some(formatter);
+        self
+    }
+
+    /// Sets the provided color theme to this prompt.
+    ///
+    /// Note: The default render config considers if the NO_COLOR environment variable
+    /// is set to decide whether to render the colored config or the empty one.
+    ///
+    /// When overriding the config in a prompt, NO_COLOR is no longer considered and your
+    /// config is treated as the only source of truth. If you want to customize colors
+    /// and still support NO_COLOR, you will have to do this on your end.
+    pub fn with_render_config(mut self, render_config: RenderConfig<'a>) -> Self {
+        self.render_config = render_config;
+        self
+    }
+
+    /// Parses the provided behavioral and rendering options and prompts
+    /// the CLI user for input according to the defined rules.
+    ///
+    /// This method is intended for flows where the user skipping/cancelling
+    /// the prompt - by pressing ESC - is considered normal behavior. In this case,
+    /// it does not return `Err(InquireError::OperationCanceled)`, but `Ok(None)`.
+    ///
+    /// Meanwhile, if the user does submit an answer, the method wraps the return
+    /// type with `Some`.
+    pub fn prompt_skippable(self) -> InquireResult<Option<bool>> {
+        match self.prompt() {
+            Ok(answer) => Ok(Some(answer)),
+            Err(InquireError::OperationCanceled) => Ok(None),
+            Err(err) => Err(err),
+        }
+    }
+
+    /// Parses the provided behavioral and rendering options and prompts
+    /// the CLI user for input according to the defined rules.
+    pub fn prompt(self) -> InquireResult<bool> {
+        let (input_reader, terminal) = get_default_terminal()?;
+        let mut backend = Backend::new(input_reader, terminal, self.render_config)?;
+        self.prompt_with_backend(&mut backend)
+    }
+
+    pub(crate) fn prompt_with_backend<B: ToggleBackend>(
+        self,
+        backend: &mut B,
+    ) -> InquireResult<bool> {
+        TogglePrompt::from(self).prompt(backend)
+    }
+}
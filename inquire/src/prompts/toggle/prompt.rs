@@ -0,0 +1,95 @@
+use crate::{
+    error::InquireResult,
+    formatter::BoolFormatter,
+    prompts::prompt::{ActionResult, Prompt},
+    ui::ToggleBackend,
+    Toggle,
+};
+
+use super::{action::TogglePromptAction, config::ToggleConfig};
+
+pub struct TogglePrompt<'a> {
+    message: &'a str,
+    config: ToggleConfig,
+    value: bool,
+    on_label: &'a str,
+    off_label: &'a str,
+    help_message: Option<&'a str>,
+    formatter: Option<BoolFormatter<'a>>,
+}
+
+impl<'a> From<Toggle<'a>> for TogglePrompt<'a> {
+    fn from(to: Toggle<'a>) -> Self {
+        Self {
+            message: to.message,
+            config: (&to).into(),
+            value: to.starting_value,
+            on_label: to.on_label,
+            off_label: to.off_label,
+            help_message: to.help_message,
+            formatter: to.formatter,
+        }
+    }
+}
+
+impl<'a> TogglePrompt<'a> {
+    fn set_value(&mut self, value: bool) -> ActionResult {
+        if value == self.value {
+            return ActionResult::Clean;
+        }
+
+        self.value = value;
+        ActionResult::NeedsRedraw
+    }
+}
+
+impl<'a, Backend> Prompt<Backend> for TogglePrompt<'a>
+where
+    Backend: ToggleBackend,
+{
+    type Config = ToggleConfig;
+    type InnerAction = TogglePromptAction;
+    type Output = bool;
+
+    fn message(&self) -> &str {
+        self.message
+    }
+
+    fn config(&self) -> &ToggleConfig {
+        &self.config
+    }
+
+    fn format_answer(&self, answer: &bool) -> String {
+        match (self.formatter, answer) {
+            (Some(formatter), _) => formatter(*answer),
+            (None, true) => self.on_label.to_string(),
+            (None, false) => self.off_label.to_string(),
+        }
+    }
+
+    fn submit(&mut self) -> InquireResult<Option<bool>> {
+        Ok(Some(self.value))
+    }
+
+    fn handle(&mut self, action: TogglePromptAction) -> InquireResult<ActionResult> {
+        let result = match action {
+            TogglePromptAction::TurnOn => self.set_value(true),
+            TogglePromptAction::TurnOff => self.set_value(false),
+            TogglePromptAction::Toggle => self.set_value(!self.value),
+        };
+
+        Ok(result)
+    }
+
+    fn render(&self, backend: &mut Backend) -> InquireResult<()> {
+        let prompt = &self.message;
+
+        backend.render_toggle_prompt(prompt, self.on_label, self.off_label, self.value)?;
+
+        if let Some(message) = self.help_message {
+            backend.render_help_message(message)?;
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,105 @@
+use crate::{
+    error::InquireResult,
+    ui::{
+        test::{FakeBackend, Token},
+        Key, KeyModifiers,
+    },
+    InquireError, Toggle,
+};
+
+#[test]
+fn starts_off_by_default() -> InquireResult<()> {
+    let mut backend = FakeBackend::new(vec![Key::Enter]);
+
+    let ans = Toggle::new("Question").prompt_with_backend(&mut backend)?;
+
+    assert!(!ans);
+    assert!(backend.frames()[1].has_token(&Token::AnsweredPrompt("Question".into(), "Off".into())));
+
+    Ok(())
+}
+
+#[test]
+fn arrows_select_each_side() -> InquireResult<()> {
+    let mut backend = FakeBackend::new(vec![
+        Key::Left(KeyModifiers::NONE),
+        Key::Left(KeyModifiers::NONE),
+        Key::Right(KeyModifiers::NONE),
+        Key::Left(KeyModifiers::NONE),
+        Key::Enter,
+    ]);
+
+    let ans = Toggle::new("Question").prompt_with_backend(&mut backend)?;
+
+    assert!(ans);
+    // initial render, three redraws and the answer, as pressing left
+    // for the second time does not change the state
+    assert_eq!(5, backend.frames().len());
+
+    Ok(())
+}
+
+#[test]
+fn space_flips_state() -> InquireResult<()> {
+    let mut backend = FakeBackend::new(vec![
+        Key::Char(' ', KeyModifiers::NONE),
+        Key::Char(' ', KeyModifiers::NONE),
+        Key::Char(' ', KeyModifiers::NONE),
+        Key::Enter,
+    ]);
+
+    let ans = Toggle::new("Question")
+        .with_starting_value(true)
+        .prompt_with_backend(&mut backend)?;
+
+    assert!(!ans);
+
+    Ok(())
+}
+
+#[test]
+fn custom_labels_are_rendered_and_used_as_answer() -> InquireResult<()> {
+    let mut backend = FakeBackend::new(vec![Key::Enter]);
+
+    let ans = Toggle::new("Question")
+        .with_labels("Enabled", "Disabled")
+        .with_starting_value(true)
+        .prompt_with_backend(&mut backend)?;
+
+    assert!(ans);
+    assert!(backend.frames()[0].has_token(&Token::Toggle {
+        on_label: "Enabled".into(),
+        off_label: "Disabled".into(),
+        value: true,
+    }));
+    assert!(
+        backend.frames()[1].has_token(&Token::AnsweredPrompt("Question".into(), "Enabled".into()))
+    );
+
+    Ok(())
+}
+
+#[test]
+fn custom_formatter_overrides_labels() -> InquireResult<()> {
+    let mut backend = FakeBackend::new(vec![Key::Enter]);
+
+    Toggle::new("Question")
+        .with_formatter(&|ans| match ans {
+            true => "Yes".into(),
+            false => "No".into(),
+        })
+        .prompt_with_backend(&mut backend)?;
+
+    assert!(backend.frames()[1].has_token(&Token::AnsweredPrompt("Question".into(), "No".into())));
+
+    Ok(())
+}
+
+#[test]
+fn escape_cancels_prompt() {
+    let mut backend = FakeBackend::new(vec![Key::Escape]);
+
+    let ans = Toggle::new("Question").prompt_with_backend(&mut backend);
+
+    assert!(matches!(ans, Err(InquireError::OperationCanceled)));
+}
@@ -0,0 +1,74 @@
+use crate::{
+    ui::{Key, KeyModifiers},
+    InnerAction,
+};
+
+use super::config::TransferConfig;
+
+/// Set of actions for a TransferPrompt.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum TransferPromptAction {
+    /// Moves the cursor to the option above in the focused pane.
+    MoveUp,
+    /// Moves the cursor to the option below in the focused pane.
+    MoveDown,
+    /// Moves the cursor to the page above in the focused pane.
+    PageUp,
+    /// Moves the cursor to the page below in the focused pane.
+    PageDown,
+    /// Moves the cursor to the start of the focused pane.
+    MoveToStart,
+    /// Moves the cursor to the end of the focused pane.
+    MoveToEnd,
+    /// Moves the focus to the other pane.
+    SwitchPane,
+    /// Moves the focus to the pane of available options.
+    FocusAvailable,
+    /// Moves the focus to the pane of chosen options.
+    FocusChosen,
+    /// Moves the highlighted option to the other pane.
+    TransferCurrentOption,
+    /// Moves all options of the focused pane to the other pane.
+    TransferAll,
+}
+
+impl InnerAction for TransferPromptAction {
+    type Config = TransferConfig;
+
+    fn from_key(key: Key, config: &TransferConfig) -> Option<Self> {
+        if config.vim_mode {
+            let action = match key {
+                Key::Char('k', KeyModifiers::NONE) => Some(Self::MoveUp),
+                Key::Char('j', KeyModifiers::NONE) => Some(Self::MoveDown),
+                Key::Char('h', KeyModifiers::NONE) => Some(Self::FocusAvailable),
+                Key::Char('l', KeyModifiers::NONE) => Some(Self::FocusChosen),
+                _ => None,
+            };
+
+            if action.is_some() {
+                return action;
+            }
+        }
+
+        let action = match key {
+            Key::Up(KeyModifiers::NONE) | Key::Char('p', KeyModifiers::CONTROL) => Self::MoveUp,
+            Key::PageUp(_) => Self::PageUp,
+            Key::Home => Self::MoveToStart,
+
+            Key::Down(KeyModifiers::NONE) | Key::Char('n', KeyModifiers::CONTROL) => Self::MoveDown,
+            Key::PageDown(_) => Self::PageDown,
+            Key::End => Self::MoveToEnd,
+
+            Key::Tab => Self::SwitchPane,
+            Key::Left(KeyModifiers::NONE) => Self::FocusAvailable,
+            Key::Right(KeyModifiers::NONE) => Self::FocusChosen,
+
+            Key::Char(' ', KeyModifiers::NONE) => Self::TransferCurrentOption,
+            Key::Char('a', KeyModifiers::CONTROL) => Self::TransferAll,
+
+            _ => return None,
+        };
+
+        Some(action)
+    }
+}
@@ -0,0 +1,19 @@
+use crate::Transfer;
+
+/// Configuration settings used in the execution of a TransferPrompt.
+#[derive(Copy, Clone, Debug)]
+pub struct TransferConfig {
+    /// Whether to use vim-style keybindings.
+    pub vim_mode: bool,
+    /// Page size of each pane.
+    pub page_size: usize,
+}
+
+impl<T> From<&Transfer<'_, T>> for TransferConfig {
+    fn from(value: &Transfer<'_, T>) -> Self {
+        Self {
+            vim_mode: value.vim_mode,
+            page_size: value.page_size,
+        }
+    }
+}
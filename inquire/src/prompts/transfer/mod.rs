@@ -0,0 +1,281 @@
+mod action;
+mod config;
+mod prompt;
+#[cfg(test)]
+#[cfg(feature = "crossterm")]
+mod test;
+
+pub use action::*;
+
+use std::fmt::Display;
+
+use crate::{
+    config::get_configuration,
+    error::{InquireError, InquireResult},
+    formatter::MultiOptionFormatter,
+    list_option::ListOption,
+    prompts::prompt::Prompt,
+    terminal::get_default_terminal,
+    ui::{Backend, RenderConfig, TransferBackend},
+    validator::MultiOptionValidator,
+};
+
+use self::prompt::TransferPrompt;
+
+/// Prompt suitable for when you need the user to choose a subset of options, displaying the available and the chosen options side by side.
+///
+/// Compared to [`MultiSelect`](crate::MultiSelect), the chosen options are always visible in their own pane, which makes this prompt clearer when the chosen set matters visually or when it is large.
+///
+/// The user moves the cursor of the focused pane with the up and down arrows, and switches the focus between the panes with tab, or with the left and right arrows. Pressing space moves the highlighted option to the other pane, while `Ctrl+A` moves all options of the focused pane. Pressing enter submits the options in the chosen pane, in the order they were chosen.
+///
+/// This prompt requires a prompt message and a **non-empty** `Vec` of options to be displayed to the user. The options can be of any type as long as they implement the `Display` trait.
+/// - If the list is empty, the prompt operation will fail with an `InquireError::InvalidConfiguration` error.
+///
+/// Transfer prompts provide several options of configuration:
+///
+/// - **Prompt message**: Required when creating the prompt.
+/// - **Options list**: Options displayed to the user. Must be **non-empty**.
+/// - **Default chosen options**: Indexes of the options that start in the chosen pane. If any of the indexes is out-of-range of the option list, the prompt will fail with an `InquireError::InvalidConfiguration` error.
+/// - **Pane titles**: Titles rendered above each pane, "Available" and "Chosen" by default.
+/// - **Help message**: Message displayed at the line below the prompt.
+/// - **Formatter**: Custom formatter in case you need to pre-process the user input before showing it as the final answer.
+///   - Prints the string value of all chosen options, separated by commas.
+/// - **Validator**: Custom validator to make sure a given submitted input pass the specified requirements, e.g. not allowing 0 chosen options.
+///   - No validators are on by default.
+/// - **Page size**: Number of options displayed at once in each pane, 7 by default.
+///
+/// # Example
+///
+/// ```no_run
+/// use inquire::Transfer;
+///
+/// let members = vec!["alice", "bob", "carol", "dave", "erin"];
+///
+/// let ans = Transfer::new("Who should be part of the on-call rotation?", members)
+///     .with_default(&[1])
+///     .with_pane_titles("Team", "Rotation")
+///     .prompt();
+///
+/// match ans {
+///     Ok(rotation) => println!("Rotation: {}", rotation.join(" -> ")),
+///     Err(_) => println!("The rotation could not be processed"),
+/// }
+/// ```
+#[derive(Clone)]
+pub struct Transfer<'a, T> {
+    /// Message to be presented to the user.
+    pub message: &'a str,
+
+    /// Options displayed to the user.
+    pub options: Vec<T>,
+
+    /// Indexes of the options that start in the chosen pane.
+    pub default: Option<Vec<usize>>,
+
+    /// Title rendered above the pane of available options.
+    pub available_title: &'a str,
+
+    /// Title rendered above the pane of chosen options.
+    pub chosen_title: &'a str,
+
+    /// Help message to be presented to the user.
+    pub help_message: Option<&'a str>,
+
+    /// Page size of each pane.
+    pub page_size: usize,
+
+    /// Whether vim mode is enabled. When enabled, the user can
+    /// navigate through the options using jk and switch panes using hl.
+    pub vim_mode: bool,
+
+    /// Function that formats the user input and presents it to the user as the final rendering of the prompt.
+    pub formatter: MultiOptionFormatter<'a, T>,
+
+    /// Validator to apply to the user input.
+    ///
+    /// In case of error, the message is displayed one line above the prompt.
+    pub validator: Option<Box<dyn MultiOptionValidator<T>>>,
+
+    /// RenderConfig to apply to the rendered interface.
+    ///
+    /// Note: The default render config considers if the NO_COLOR environment variable
+    /// is set to decide whether to render the colored config or the empty one.
+    ///
+    /// When overriding the config in a prompt, NO_COLOR is no longer considered and your
+    /// config is treated as the only source of truth. If you want to customize colors
+    /// and still support NO_COLOR, you will have to do this on your end.
+    pub render_config: RenderConfig<'a>,
+}
+
+impl<'a, T> Transfer<'a, T>
+where
+    T: Display,
+{
+    /// String formatter used by default in [Transfer](crate::Transfer) prompts.
+    /// Prints the string value of all chosen options, separated by commas.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use inquire::list_option::ListOption;
+    /// use inquire::Transfer;
+    ///
+    /// let formatter = Transfer::<&str>::DEFAULT_FORMATTER;
+    ///
+    /// let ans = vec![ListOption::new(3, &"dave"), ListOption::new(0, &"alice")];
+    /// assert_eq!(String::from("dave, alice"), formatter(&ans));
+    /// ```
+    pub const DEFAULT_FORMATTER: MultiOptionFormatter<'a, T> = &|ans| {
+        ans.iter()
+            .map(|opt| opt.to_string())
+            .collect::<Vec<String>>()
+            .join(", ")
+    };
+
+    /// Default page size.
+    pub const DEFAULT_PAGE_SIZE: usize = crate::config::DEFAULT_PAGE_SIZE;
+
+    /// Default value of vim mode.
+    pub const DEFAULT_VIM_MODE: bool = crate::config::DEFAULT_VIM_MODE;
+
+    /// Default title of the pane of available options.
+    pub const DEFAULT_AVAILABLE_TITLE: &'a str = "Available";
+
+    /// Default title of the pane of chosen options.
+    pub const DEFAULT_CHOSEN_TITLE: &'a str = "Chosen";
+
+    /// Default help message.
+    pub const DEFAULT_HELP_MESSAGE: Option<&'a str> =
+        Some("↑↓ to move, tab to switch panes, space to transfer, enter to submit");
+
+    /// Creates a [Transfer] with the provided message and options, along with default configuration values.
+    pub fn new(message: &'a str, options: Vec<T>) -> Self {
+        Self {
+            message,
+            options,
+            default: None,
+            available_title: Self::DEFAULT_AVAILABLE_TITLE,
+            chosen_title: Self::DEFAULT_CHOSEN_TITLE,
+            help_message: Self::DEFAULT_HELP_MESSAGE,
+            page_size: Self::DEFAULT_PAGE_SIZE,
+            vim_mode: Self::DEFAULT_VIM_MODE,
+            formatter: Self::DEFAULT_FORMATTER,
+            validator: None,
+            render_config: get_configuration(),
+        }
+    }
+
+    /// Sets the indexes of the options that start in the chosen pane, in the given order.
+    ///
+    /// Duplicated indexes are ignored.
+    pub fn with_default(mut self, default: &'a [usize]) -> Self {
+        self.default = Some(default.to_vec());
+        self
+    }
+
+    /// Sets the titles rendered above the panes of available and chosen options, respectively.
+    pub fn with_pane_titles(mut self, available_title: &'a str, chosen_title: &'a str) -> Self {
+        self.available_title = available_title;
+        self.chosen_title = chosen_title;
+        self
+    }
+
+    /// Sets the help message of the prompt.
+    pub fn with_help_message(mut self, message: &'a str) -> Self {
+        self.help_message = Some(message);
+        self
+    }
+
+    /// Removes the set help message.
+    pub fn without_help_message(mut self) -> Self {
+        self.help_message = None;
+        self
+    }
+
+    /// Sets the page size.
+    pub fn with_page_size(mut self, page_size: usize) -> Self {
+        self.page_size = page_size;
+        self
+    }
+
+    /// Enables or disables vim_mode.
+    pub fn with_vim_mode(mut self, vim_mode: bool) -> Self {
+        self.vim_mode = vim_mode;
+        self
+    }
+
+    /// Sets the formatter.
+    pub fn with_formatter(mut self, formatter: MultiOptionFormatter<'a, T>) -> Self {
+        self.formatter = formatter;
+        self
+    }
+
+    /// Sets the validator to apply to the user input. You might want to use this feature
+    /// in case you need to limit the user to a given number of chosen options.
+    ///
+    /// In case of error, the message is displayed one line above the prompt.
+    pub fn with_validator<V>(mut self, validator: V) -> Self
+    where
+        V: MultiOptionValidator<T> + 'static,
+    {
+        self.validator = Some(Box::new(validator));
+        self
+    }
+
+    /// Sets the provided color theme to this prompt.
+    ///
+    /// Note: The default render config considers if the NO_COLOR environment variable
+    /// is set to decide whether to render the colored config or the empty one.
+    ///
+    /// When overriding the config in a prompt, NO_COLOR is no longer considered and your
+    /// config is treated as the only source of truth. If you want to customize colors
+    /// and still support NO_COLOR, you will have to do this on your end.
+    pub fn with_render_config(mut self, render_config: RenderConfig<'a>) -> Self {
+        self.render_config = render_config;
+        self
+    }
+
+    /// Parses the provided behavioral and rendering options and prompts
+    /// the CLI user for input according to the defined rules.
+    ///
+    /// Returns the owned chosen options, in the order they were chosen.
+    pub fn prompt(self) -> InquireResult<Vec<T>> {
+        self.raw_prompt()
+            .map(|options| options.into_iter().map(|op| op.value).collect())
+    }
+
+    /// Parses the provided behavioral and rendering options and prompts
+    /// the CLI user for input according to the defined rules.
+    ///
+    /// This method is intended for flows where the user skipping/cancelling
+    /// the prompt - by pressing ESC - is considered normal behavior. In this case,
+    /// it does not return `Err(InquireError::OperationCanceled)`, but `Ok(None)`.
+    ///
+    /// Meanwhile, if the user does submit an answer, the method wraps the return
+    /// type with `Some`.
+    pub fn prompt_skippable(self) -> InquireResult<Option<Vec<T>>> {
+        match self.prompt() {
+            Ok(answer) => Ok(Some(answer)),
+            Err(InquireError::OperationCanceled) => Ok(None),
+            Err(err) => Err(err),
+        }
+    }
+
+    /// Parses the provided behavioral and rendering options and prompts
+    /// the CLI user for input according to the defined rules.
+    ///
+    /// Returns a vector of [`ListOption`](crate::list_option::ListOption)s, in the order
+    /// they were chosen, each of them containing the index of the option and the owned object.
+    pub fn raw_prompt(self) -> InquireResult<Vec<ListOption<T>>> {
+        let (input_reader, terminal) = get_default_terminal()?;
+        let mut backend = Backend::new(input_reader, terminal, self.render_config)?;
+        self.prompt_with_backend(&mut backend)
+    }
+
+    pub(crate) fn prompt_with_backend<B: TransferBackend>(
+        self,
+        backend: &mut B,
+    ) -> InquireResult<Vec<ListOption<T>>> {
+        TransferPrompt::new(self)?.prompt(backend)
+    }
+}
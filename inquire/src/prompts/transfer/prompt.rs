@@ -0,0 +1,344 @@
+use std::fmt::Display;
+
+use unicode_width::UnicodeWidthStr;
+
+use crate::{
+    error::InquireResult,
+    formatter::MultiOptionFormatter,
+    list_option::ListOption,
+    prompts::prompt::{ActionResult, Prompt},
+    ui::TransferBackend,
+    utils::paginate,
+    validator::{ErrorMessage, MultiOptionValidator, Validation},
+    InquireError, Transfer,
+};
+
+use super::{action::TransferPromptAction, config::TransferConfig};
+
+/// Panes of a [TransferPrompt].
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+enum Pane {
+    Available,
+    Chosen,
+}
+
+pub struct TransferPrompt<'a, T> {
+    message: &'a str,
+    config: TransferConfig,
+    options: Vec<T>,
+    string_options: Vec<String>,
+    /// Indexes of the available options, in their original order.
+    available: Vec<usize>,
+    /// Indexes of the chosen options, in the order they were chosen.
+    chosen: Vec<usize>,
+    focus: Pane,
+    available_cursor: usize,
+    chosen_cursor: usize,
+    available_title: &'a str,
+    chosen_title: &'a str,
+    pane_width: usize,
+    help_message: Option<&'a str>,
+    formatter: MultiOptionFormatter<'a, T>,
+    validator: Option<Box<dyn MultiOptionValidator<T>>>,
+    error: Option<ErrorMessage>,
+}
+
+impl<'a, T> TransferPrompt<'a, T>
+where
+    T: Display,
+{
+    pub fn new(to: Transfer<'a, T>) -> InquireResult<Self> {
+        if to.options.is_empty() {
+            return Err(InquireError::InvalidConfiguration(
+                "Available options can not be empty".into(),
+            ));
+        }
+
+        let mut chosen: Vec<usize> = vec![];
+
+        for i in to.default.iter().flatten() {
+            if *i >= to.options.len() {
+                return Err(InquireError::InvalidConfiguration(format!(
+                    "Index {} is out-of-bounds for length {} of options",
+                    i,
+                    &to.options.len()
+                )));
+            }
+
+            if !chosen.contains(i) {
+                chosen.push(*i);
+            }
+        }
+
+        let available = (0..to.options.len())
+            .filter(|i| !chosen.contains(i))
+            .collect();
+
+        let string_options = to.options.iter().map(T::to_string).collect::<Vec<String>>();
+
+        let pane_width = string_options
+            .iter()
+            .map(|s| s.width())
+            .chain(
+                [to.available_title, to.chosen_title]
+                    .iter()
+                    .map(|title| format!("{} ({})", title, to.options.len()).width()),
+            )
+            .max()
+            .unwrap_or_default();
+
+        Ok(Self {
+            message: to.message,
+            config: (&to).into(),
+            options: to.options,
+            string_options,
+            available,
+            chosen,
+            focus: Pane::Available,
+            available_cursor: 0,
+            chosen_cursor: 0,
+            available_title: to.available_title,
+            chosen_title: to.chosen_title,
+            pane_width,
+            help_message: to.help_message,
+            formatter: to.formatter,
+            validator: to.validator,
+            error: None,
+        })
+    }
+
+    fn focused_pane(&mut self) -> (&mut Vec<usize>, &mut usize) {
+        match self.focus {
+            Pane::Available => (&mut self.available, &mut self.available_cursor),
+            Pane::Chosen => (&mut self.chosen, &mut self.chosen_cursor),
+        }
+    }
+
+    fn move_cursor_up(&mut self, qty: usize, wrap: bool) -> ActionResult {
+        let (options, cursor) = self.focused_pane();
+
+        let new_position = if wrap {
+            let after_wrap = qty.saturating_sub(*cursor);
+            cursor
+                .checked_sub(qty)
+                .unwrap_or_else(|| options.len().saturating_sub(after_wrap))
+        } else {
+            cursor.saturating_sub(qty)
+        };
+
+        Self::update_cursor_position(cursor, new_position)
+    }
+
+    fn move_cursor_down(&mut self, qty: usize, wrap: bool) -> ActionResult {
+        let (options, cursor) = self.focused_pane();
+
+        let mut new_position = cursor.saturating_add(qty);
+
+        if new_position >= options.len() {
+            new_position = if options.is_empty() {
+                0
+            } else if wrap {
+                new_position % options.len()
+            } else {
+                options.len().saturating_sub(1)
+            }
+        }
+
+        Self::update_cursor_position(cursor, new_position)
+    }
+
+    fn update_cursor_position(cursor: &mut usize, new_position: usize) -> ActionResult {
+        if new_position != *cursor {
+            *cursor = new_position;
+            ActionResult::NeedsRedraw
+        } else {
+            ActionResult::Clean
+        }
+    }
+
+    fn focus(&mut self, pane: Pane) -> ActionResult {
+        if self.focus == pane {
+            return ActionResult::Clean;
+        }
+
+        self.focus = pane;
+        ActionResult::NeedsRedraw
+    }
+
+    fn transfer_current_option(&mut self) -> ActionResult {
+        let (options, cursor) = self.focused_pane();
+
+        if *cursor >= options.len() {
+            return ActionResult::Clean;
+        }
+
+        let index = options.remove(*cursor);
+
+        if *cursor >= options.len() {
+            *cursor = options.len().saturating_sub(1);
+        }
+
+        match self.focus {
+            Pane::Available => self.chosen.push(index),
+            Pane::Chosen => {
+                let position = self.available.partition_point(|i| *i < index);
+                self.available.insert(position, index);
+            }
+        }
+
+        ActionResult::NeedsRedraw
+    }
+
+    fn transfer_all(&mut self) -> ActionResult {
+        let (options, cursor) = self.focused_pane();
+
+        if options.is_empty() {
+            return ActionResult::Clean;
+        }
+
+        let moved = std::mem::take(options);
+        *cursor = 0;
+
+        match self.focus {
+            Pane::Available => self.chosen.extend(moved),
+            Pane::Chosen => {
+                self.available.extend(moved);
+                self.available.sort_unstable();
+            }
+        }
+
+        ActionResult::NeedsRedraw
+    }
+
+    fn validate_current_answer(&self) -> InquireResult<Validation> {
+        if let Some(validator) = &self.validator {
+            let chosen_options = self
+                .chosen
+                .iter()
+                .map(|idx| ListOption::new(*idx, &self.options[*idx]))
+                .collect::<Vec<_>>();
+
+            let res = validator.validate(&chosen_options)?;
+            Ok(res)
+        } else {
+            Ok(Validation::Valid)
+        }
+    }
+
+    fn get_final_answer(&mut self) -> Vec<ListOption<T>> {
+        let mut options = std::mem::take(&mut self.options)
+            .into_iter()
+            .map(Some)
+            .collect::<Vec<Option<T>>>();
+
+        self.chosen
+            .iter()
+            .filter_map(|idx| {
+                options[*idx]
+                    .take()
+                    .map(|value| ListOption::new(*idx, value))
+            })
+            .collect()
+    }
+
+    fn pane_choices(&self, pane: &[usize]) -> Vec<ListOption<&String>> {
+        pane.iter()
+            .map(|idx| ListOption::new(*idx, &self.string_options[*idx]))
+            .collect()
+    }
+}
+
+impl<'a, Backend, T> Prompt<Backend> for TransferPrompt<'a, T>
+where
+    Backend: TransferBackend,
+    T: Display,
+{
+    type Config = TransferConfig;
+    type InnerAction = TransferPromptAction;
+    type Output = Vec<ListOption<T>>;
+
+    fn message(&self) -> &str {
+        self.message
+    }
+
+    fn config(&self) -> &TransferConfig {
+        &self.config
+    }
+
+    fn format_answer(&self, answer: &Vec<ListOption<T>>) -> String {
+        let refs: Vec<ListOption<&T>> = answer.iter().map(ListOption::as_ref).collect();
+        (self.formatter)(&refs)
+    }
+
+    fn submit(&mut self) -> InquireResult<Option<Vec<ListOption<T>>>> {
+        let answer = match self.validate_current_answer()? {
+            Validation::Valid => Some(self.get_final_answer()),
+            Validation::Invalid(msg) => {
+                self.error = Some(msg);
+                None
+            }
+        };
+
+        Ok(answer)
+    }
+
+    fn handle(&mut self, action: TransferPromptAction) -> InquireResult<ActionResult> {
+        let result = match action {
+            TransferPromptAction::MoveUp => self.move_cursor_up(1, true),
+            TransferPromptAction::MoveDown => self.move_cursor_down(1, true),
+            TransferPromptAction::PageUp => self.move_cursor_up(self.config.page_size, false),
+            TransferPromptAction::PageDown => self.move_cursor_down(self.config.page_size, false),
+            TransferPromptAction::MoveToStart => self.move_cursor_up(usize::MAX, false),
+            TransferPromptAction::MoveToEnd => self.move_cursor_down(usize::MAX, false),
+            TransferPromptAction::SwitchPane => match self.focus {
+                Pane::Available => self.focus(Pane::Chosen),
+                Pane::Chosen => self.focus(Pane::Available),
+            },
+            TransferPromptAction::FocusAvailable => self.focus(Pane::Available),
+            TransferPromptAction::FocusChosen => self.focus(Pane::Chosen),
+            TransferPromptAction::TransferCurrentOption => self.transfer_current_option(),
+            TransferPromptAction::TransferAll => self.transfer_all(),
+        };
+
+        Ok(result)
+    }
+
+    fn render(&self, backend: &mut Backend) -> InquireResult<()> {
+        let prompt = &self.message;
+
+        if let Some(err) = &self.error {
+            backend.render_error_message(err)?;
+        }
+
+        backend.render_transfer_prompt(prompt)?;
+
+        let available = self.pane_choices(&self.available);
+        let chosen = self.pane_choices(&self.chosen);
+
+        let mut available_page = paginate(
+            self.config.page_size,
+            &available,
+            Some(self.available_cursor),
+        );
+        let mut chosen_page = paginate(self.config.page_size, &chosen, Some(self.chosen_cursor));
+
+        // only the focused pane displays its cursor
+        match self.focus {
+            Pane::Available => chosen_page.cursor = None,
+            Pane::Chosen => available_page.cursor = None,
+        }
+
+        backend.render_transfer_panes(
+            (self.available_title, self.chosen_title),
+            available_page,
+            chosen_page,
+            self.pane_width,
+        )?;
+
+        if let Some(help_message) = self.help_message {
+            backend.render_help_message(help_message)?;
+        }
+
+        Ok(())
+    }
+}
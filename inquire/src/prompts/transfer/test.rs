@@ -0,0 +1,119 @@
+use crate::{
+    list_option::ListOption,
+    test::fake_backend,
+    ui::{Key, KeyModifiers},
+    validator::Validation,
+    InquireError, Transfer,
+};
+
+fn options() -> Vec<&'static str> {
+    vec!["a", "b", "c", "d"]
+}
+
+#[test]
+fn submitting_right_away_returns_default_options() {
+    let mut backend = fake_backend(vec![Key::Enter]);
+
+    let ans = Transfer::new("Question", options())
+        .with_default(&[2, 0, 2])
+        .prompt_with_backend(&mut backend)
+        .unwrap();
+
+    assert_eq!(vec![ListOption::new(2, "c"), ListOption::new(0, "a")], ans);
+}
+
+#[test]
+fn space_moves_options_in_the_order_they_are_chosen() {
+    let mut backend = fake_backend(vec![
+        Key::Down(KeyModifiers::NONE),
+        Key::Down(KeyModifiers::NONE),
+        Key::Char(' ', KeyModifiers::NONE),
+        Key::Home,
+        Key::Char(' ', KeyModifiers::NONE),
+        Key::Enter,
+    ]);
+
+    let ans = Transfer::new("Question", options())
+        .prompt_with_backend(&mut backend)
+        .unwrap();
+
+    assert_eq!(vec![ListOption::new(2, "c"), ListOption::new(0, "a")], ans);
+}
+
+#[test]
+fn options_moved_back_return_to_original_position() {
+    let mut backend = fake_backend(vec![
+        Key::Tab,
+        Key::Char(' ', KeyModifiers::NONE),
+        Key::Left(KeyModifiers::NONE),
+        Key::Down(KeyModifiers::NONE),
+        Key::Char(' ', KeyModifiers::NONE),
+        Key::Enter,
+    ]);
+
+    // "b" goes back to the available pane, between "a" and "c",
+    // so that moving the second available option transfers "b" again
+    let ans = Transfer::new("Question", options())
+        .with_default(&[1, 3])
+        .prompt_with_backend(&mut backend)
+        .unwrap();
+
+    assert_eq!(vec![ListOption::new(3, "d"), ListOption::new(1, "b")], ans);
+}
+
+#[test]
+fn ctrl_a_moves_all_options_of_focused_pane() {
+    let mut backend = fake_backend(vec![
+        Key::Char('a', KeyModifiers::CONTROL),
+        Key::Right(KeyModifiers::NONE),
+        Key::Char(' ', KeyModifiers::NONE),
+        Key::Enter,
+    ]);
+
+    let ans = Transfer::new("Question", options())
+        .prompt_with_backend(&mut backend)
+        .unwrap();
+
+    assert_eq!(
+        vec![
+            ListOption::new(1, "b"),
+            ListOption::new(2, "c"),
+            ListOption::new(3, "d"),
+        ],
+        ans
+    );
+}
+
+#[test]
+fn validator_blocks_submission() {
+    let mut backend = fake_backend(vec![
+        Key::Enter,
+        Key::Char(' ', KeyModifiers::NONE),
+        Key::Enter,
+    ]);
+
+    let ans = Transfer::new("Question", options())
+        .with_validator(|chosen: &[ListOption<&&str>]| match chosen.is_empty() {
+            true => Ok(Validation::Invalid("Choose at least one option".into())),
+            false => Ok(Validation::Valid),
+        })
+        .prompt_with_backend(&mut backend)
+        .unwrap();
+
+    assert_eq!(vec![ListOption::new(0, "a")], ans);
+}
+
+#[test]
+fn invalid_configurations_are_rejected() {
+    let empty = Transfer::new("Question", Vec::<&str>::new())
+        .prompt_with_backend(&mut fake_backend(vec![Key::Enter]));
+    assert!(matches!(empty, Err(InquireError::InvalidConfiguration(_))));
+
+    let out_of_bounds = Transfer::new("Question", options())
+        .with_default(&[4])
+        .prompt_with_backend(&mut fake_backend(vec![Key::Enter]));
+    assert!(matches!(
+        out_of_bounds,
+        Err(InquireError::InvalidConfiguration(_))
+    ));
+}
@@ -0,0 +1,68 @@
+use crate::{
+    ui::{Key, KeyModifiers},
+    InnerAction,
+};
+
+use super::config::TreeSelectConfig;
+
+/// Set of actions for a TreeSelectPrompt.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum TreeSelectPromptAction {
+    /// Moves the cursor to the node above.
+    MoveUp,
+    /// Moves the cursor to the node below.
+    MoveDown,
+    /// Moves the cursor to the page above.
+    PageUp,
+    /// Moves the cursor to the page below.
+    PageDown,
+    /// Moves the cursor to the start of the list.
+    MoveToStart,
+    /// Moves the cursor to the end of the list.
+    MoveToEnd,
+    /// Expands the highlighted node or, if it is already expanded,
+    /// moves the cursor to its first child.
+    Expand,
+    /// Collapses the highlighted node or, if it is already collapsed,
+    /// moves the cursor to its parent.
+    Collapse,
+    /// Expands or collapses the highlighted node.
+    ToggleExpansion,
+}
+
+impl InnerAction for TreeSelectPromptAction {
+    type Config = TreeSelectConfig;
+
+    fn from_key(key: Key, config: &TreeSelectConfig) -> Option<Self> {
+        if config.vim_mode {
+            let action = match key {
+                Key::Char('k', KeyModifiers::NONE) => Some(Self::MoveUp),
+                Key::Char('j', KeyModifiers::NONE) => Some(Self::MoveDown),
+                Key::Char('l', KeyModifiers::NONE) => Some(Self::Expand),
+                Key::Char('h', KeyModifiers::NONE) => Some(Self::Collapse),
+                _ => None,
+            };
+
+            if action.is_some() {
+                return action;
+            }
+        }
+
+        let action = match key {
+            Key::Up(KeyModifiers::NONE) | Key::Char('p', KeyModifiers::CONTROL) => Self::MoveUp,
+            Key::PageUp(_) => Self::PageUp,
+            Key::Home => Self::MoveToStart,
+
+            Key::Down(KeyModifiers::NONE) | Key::Char('n', KeyModifiers::CONTROL) => Self::MoveDown,
+            Key::PageDown(_) => Self::PageDown,
+            Key::End => Self::MoveToEnd,
+
+            Key::Right(KeyModifiers::NONE) => Self::Expand,
+            Key::Left(KeyModifiers::NONE) => Self::Collapse,
+            Key::Char(' ', KeyModifiers::NONE) => Self::ToggleExpansion,
+            _ => return None,
+        };
+
+        Some(action)
+    }
+}
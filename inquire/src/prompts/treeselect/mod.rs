@@ -0,0 +1,254 @@
+mod action;
+mod config;
+mod node;
+mod prompt;
+#[cfg(test)]
+#[cfg(feature = "crossterm")]
+mod test;
+
+pub use action::*;
+pub use node::*;
+
+use std::fmt::Display;
+
+use crate::{
+    config::get_configuration,
+    error::{InquireError, InquireResult},
+    formatter::OptionFormatter,
+    list_option::ListOption,
+    prompts::prompt::Prompt,
+    terminal::get_default_terminal,
+    ui::{Backend, RenderConfig, TreeSelectBackend},
+};
+
+use self::prompt::TreeSelectPrompt;
+
+/// Prompt suitable for when you need the user to select one item of a nested structure, such as org units, package trees or config sections.
+///
+/// The prompt receives a list of root [`TreeNode`]s, each of them possibly containing child nodes. Nodes with children can be expanded with the right arrow and collapsed with the left arrow, while space toggles the expansion of the highlighted node. Indentation guides show the depth of each node.
+///
+/// The user can select and submit the highlighted node by pressing enter. By default any node can be selected, but the prompt can be configured to accept leaf nodes only, in which case pressing enter on a node with children toggles its expansion.
+///
+/// This prompt requires a prompt message and a **non-empty** `Vec` of root nodes. The values can be of any type as long as they implement the `Display` trait.
+/// - If the list is empty, the prompt operation will fail with an `InquireError::InvalidConfiguration` error.
+///
+/// Tree selects provide several options of configuration:
+///
+/// - **Prompt message**: Required when creating the prompt.
+/// - **Nodes**: Root nodes of the tree. Must be **non-empty**.
+/// - **Leaves only**: Whether only nodes without children can be selected, false by default.
+/// - **Expanded**: Whether all nodes start expanded, false by default.
+/// - **Help message**: Message displayed at the line below the prompt.
+/// - **Formatter**: Custom formatter in case you need to pre-process the user input before showing it as the final answer.
+///   - Prints the selected node value by default.
+/// - **Page size**: Number of nodes displayed at once, 7 by default.
+///
+/// The index of the returned [`ListOption`] is the position of the node when the tree is traversed in pre-order, i.e. every node comes right before its children.
+///
+/// # Example
+///
+/// ```no_run
+/// use inquire::{TreeNode, TreeSelect};
+///
+/// let units = vec![
+///     TreeNode::new("Engineering").with_children(vec![
+///         TreeNode::new("Backend"),
+///         TreeNode::new("Frontend"),
+///     ]),
+///     TreeNode::new("Sales"),
+/// ];
+///
+/// let ans = TreeSelect::new("Which unit do you belong to?", units)
+///     .with_leaves_only(true)
+///     .prompt();
+///
+/// match ans {
+///     Ok(unit) => println!("Welcome to {}!", unit),
+///     Err(_) => println!("There was an error, please try again"),
+/// }
+/// ```
+#[derive(Clone)]
+pub struct TreeSelect<'a, T> {
+    /// Message to be presented to the user.
+    pub message: &'a str,
+
+    /// Root nodes of the tree displayed to the user.
+    pub nodes: Vec<TreeNode<T>>,
+
+    /// Whether only nodes without children can be selected.
+    pub leaves_only: bool,
+
+    /// Whether all nodes are expanded when the prompt is first rendered.
+    pub expanded: bool,
+
+    /// Help message to be presented to the user.
+    pub help_message: Option<&'a str>,
+
+    /// Page size of the nodes displayed to the user.
+    pub page_size: usize,
+
+    /// Whether vim mode is enabled. When enabled, the user can
+    /// navigate through the nodes using hjkl.
+    pub vim_mode: bool,
+
+    /// Function that formats the user input and presents it to the user as the final rendering of the prompt.
+    pub formatter: OptionFormatter<'a, T>,
+
+    /// RenderConfig to apply to the rendered interface.
+    ///
+    /// Note: The default render config considers if the NO_COLOR environment variable
+    /// is set to decide whether to render the colored config or the empty one.
+    ///
+    /// When overriding the config in a prompt, NO_COLOR is no longer considered and your
+    /// config is treated as the only source of truth. If you want to customize colors
+    /// and still support NO_COLOR, you will have to do this on your end.
+    pub render_config: RenderConfig<'a>,
+}
+
+impl<'a, T> TreeSelect<'a, T>
+where
+    T: Display,
+{
+    /// String formatter used by default in [TreeSelect](crate::TreeSelect) prompts.
+    /// Simply prints the string value contained in the selected node.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use inquire::list_option::ListOption;
+    /// use inquire::TreeSelect;
+    ///
+    /// let formatter = TreeSelect::<&str>::DEFAULT_FORMATTER;
+    /// assert_eq!(String::from("Backend"), formatter(ListOption::new(1, &"Backend")));
+    /// ```
+    pub const DEFAULT_FORMATTER: OptionFormatter<'a, T> = &|ans| ans.to_string();
+
+    /// Default page size.
+    pub const DEFAULT_PAGE_SIZE: usize = crate::config::DEFAULT_PAGE_SIZE;
+
+    /// Default value of vim mode.
+    pub const DEFAULT_VIM_MODE: bool = crate::config::DEFAULT_VIM_MODE;
+
+    /// Default value of whether only leaf nodes can be selected.
+    pub const DEFAULT_LEAVES_ONLY: bool = false;
+
+    /// Default value of whether all nodes start expanded.
+    pub const DEFAULT_EXPANDED: bool = false;
+
+    /// Default help message.
+    pub const DEFAULT_HELP_MESSAGE: Option<&'a str> =
+        Some("↑↓ to move, → to expand, ← to collapse, enter to select");
+
+    /// Creates a [TreeSelect] with the provided message and root nodes, along with default configuration values.
+    pub fn new(message: &'a str, nodes: Vec<TreeNode<T>>) -> Self {
+        Self {
+            message,
+            nodes,
+            leaves_only: Self::DEFAULT_LEAVES_ONLY,
+            expanded: Self::DEFAULT_EXPANDED,
+            help_message: Self::DEFAULT_HELP_MESSAGE,
+            page_size: Self::DEFAULT_PAGE_SIZE,
+            vim_mode: Self::DEFAULT_VIM_MODE,
+            formatter: Self::DEFAULT_FORMATTER,
+            render_config: get_configuration(),
+        }
+    }
+
+    /// Sets whether only nodes without children can be selected.
+    pub fn with_leaves_only(mut self, leaves_only: bool) -> Self {
+        self.leaves_only = leaves_only;
+        self
+    }
+
+    /// Sets whether all nodes are expanded when the prompt is first rendered.
+    pub fn with_expanded(mut self, expanded: bool) -> Self {
+        self.expanded = expanded;
+        self
+    }
+
+    /// Sets the help message of the prompt.
+    pub fn with_help_message(mut self, message: &'a str) -> Self {
+        self.help_message = Some(message);
+        self
+    }
+
+    /// Removes the set help message.
+    pub fn without_help_message(mut self) -> Self {
+        self.help_message = None;
+        self
+    }
+
+    /// Sets the page size.
+    pub fn with_page_size(mut self, page_size: usize) -> Self {
+        self.page_size = page_size;
+        self
+    }
+
+    /// Enables or disables vim_mode.
+    pub fn with_vim_mode(mut self, vim_mode: bool) -> Self {
+        self.vim_mode = vim_mode;
+        self
+    }
+
+    /// Sets the formatter.
+    pub fn with_formatter(mut self, formatter: OptionFormatter<'a, T>) -> Self {
+        self.formatter = formatter;
+        self
+    }
+
+    /// Sets the provided color theme to this prompt.
+    ///
+    /// Note: The default render config considers if the NO_COLOR environment variable
+    /// is set to decide whether to render the colored config or the empty one.
+    ///
+    /// When overriding the config in a prompt, NO_COLOR is no longer considered and your
+    /// config is treated as the only source of truth. If you want to customize colors
+    /// and still support NO_COLOR, you will have to do this on your end.
+    pub fn with_render_config(mut self, render_config: RenderConfig<'a>) -> Self {
+        self.render_config = render_config;
+        self
+    }
+
+    /// Parses the provided behavioral and rendering options and prompts
+    /// the CLI user for input according to the defined rules.
+    ///
+    /// Returns the owned value of the node selected by the user.
+    pub fn prompt(self) -> InquireResult<T> {
+        self.raw_prompt().map(|op| op.value)
+    }
+
+    /// Parses the provided behavioral and rendering options and prompts
+    /// the CLI user for input according to the defined rules.
+    ///
+    /// This method is intended for flows where the user skipping/cancelling
+    /// the prompt - by pressing ESC - is considered normal behavior. In this case,
+    /// it does not return `Err(InquireError::OperationCanceled)`, but `Ok(None)`.
+    ///
+    /// Meanwhile, if the user does submit an answer, the method wraps the return
+    /// type with `Some`.
+    pub fn prompt_skippable(self) -> InquireResult<Option<T>> {
+        match self.prompt() {
+            Ok(answer) => Ok(Some(answer)),
+            Err(InquireError::OperationCanceled) => Ok(None),
+            Err(err) => Err(err),
+        }
+    }
+
+    /// Parses the provided behavioral and rendering options and prompts
+    /// the CLI user for input according to the defined rules.
+    ///
+    /// Returns a [`ListOption`](crate::list_option::ListOption) containing
+    /// the pre-order index of the selected node and its owned value.
+    pub fn raw_prompt(self) -> InquireResult<ListOption<T>> {
+        let (input_reader, terminal) = get_default_terminal()?;
+        let mut backend = Backend::new(input_reader, terminal, self.render_config)?;
+        self.prompt_with_backend(&mut backend)
+    }
+
+    pub(crate) fn prompt_with_backend<B: TreeSelectBackend>(
+        self,
+        backend: &mut B,
+    ) -> InquireResult<ListOption<T>> {
+        TreeSelectPrompt::new(self)?.prompt(backend)
+    }
+}
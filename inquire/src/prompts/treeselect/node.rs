@@ -0,0 +1,59 @@
+/// Node of the tree displayed by a [`TreeSelect`](crate::TreeSelect) prompt.
+///
+/// Nodes hold a value, displayed to the user, and an arbitrary number of
+/// child nodes. Nodes without children are considered leaves.
+///
+/// # Example
+///
+/// ```
+/// use inquire::TreeNode;
+///
+/// let tree = TreeNode::new("Engineering").with_children(vec![
+///     TreeNode::new("Backend"),
+///     TreeNode::new("Frontend"),
+/// ]);
+///
+/// assert!(!tree.is_leaf());
+/// assert_eq!(2, tree.children.len());
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct TreeNode<T> {
+    /// Value of the node, displayed to the user and returned on selection.
+    pub value: T,
+
+    /// Child nodes, displayed below this node when it is expanded.
+    pub children: Vec<TreeNode<T>>,
+}
+
+impl<T> TreeNode<T> {
+    /// Creates a node without children.
+    pub fn new(value: T) -> Self {
+        Self {
+            value,
+            children: vec![],
+        }
+    }
+
+    /// Sets the children of the node.
+    pub fn with_children(mut self, children: Vec<TreeNode<T>>) -> Self {
+        self.children = children;
+        self
+    }
+
+    /// Adds a child to the node.
+    pub fn with_child(mut self, child: TreeNode<T>) -> Self {
+        self.children.push(child);
+        self
+    }
+
+    /// Whether the node has no children.
+    pub fn is_leaf(&self) -> bool {
+        self.children.is_empty()
+    }
+}
+
+impl<T> From<T> for TreeNode<T> {
+    fn from(value: T) -> Self {
+        Self::new(value)
+    }
+}
@@ -0,0 +1,322 @@
+use std::fmt::Display;
+
+use crate::{
+    error::InquireResult,
+    formatter::OptionFormatter,
+    list_option::ListOption,
+    prompts::prompt::{ActionResult, Prompt},
+    ui::TreeSelectBackend,
+    utils::paginate,
+    InquireError, TreeNode, TreeSelect,
+};
+
+use super::{action::TreeSelectPromptAction, config::TreeSelectConfig};
+
+/// Position of a node in the flattened tree.
+struct FlatNode {
+    parent: Option<usize>,
+    depth: usize,
+    is_last_sibling: bool,
+    has_children: bool,
+}
+
+pub struct TreeSelectPrompt<'a, T> {
+    message: &'a str,
+    config: TreeSelectConfig,
+    values: Vec<T>,
+    nodes: Vec<FlatNode>,
+    expanded: Vec<bool>,
+    visible_nodes: Vec<usize>,
+    leaves_only: bool,
+    help_message: Option<&'a str>,
+    cursor_index: usize,
+    formatter: OptionFormatter<'a, T>,
+}
+
+impl<'a, T> TreeSelectPrompt<'a, T>
+where
+    T: Display,
+{
+    pub fn new(to: TreeSelect<'a, T>) -> InquireResult<Self> {
+        if to.nodes.is_empty() {
+            return Err(InquireError::InvalidConfiguration(
+                "Available options can not be empty".into(),
+            ));
+        }
+
+        let config = (&to).into();
+
+        let mut values = vec![];
+        let mut nodes = vec![];
+        flatten(to.nodes, None, 0, &mut nodes, &mut values);
+
+        let expand_all = to.expanded;
+        let expanded = nodes
+            .iter()
+            .map(|node| expand_all && node.has_children)
+            .collect();
+
+        let mut prompt = Self {
+            message: to.message,
+            config,
+            values,
+            nodes,
+            expanded,
+            visible_nodes: vec![],
+            leaves_only: to.leaves_only,
+            help_message: to.help_message,
+            cursor_index: 0,
+            formatter: to.formatter,
+        };
+
+        prompt.update_visible_nodes();
+
+        Ok(prompt)
+    }
+
+    /// Lists, in pre-order, the nodes whose ancestors are all expanded.
+    fn update_visible_nodes(&mut self) {
+        let mut is_visible = vec![false; self.nodes.len()];
+
+        for (idx, node) in self.nodes.iter().enumerate() {
+            is_visible[idx] = match node.parent {
+                Some(parent) => is_visible[parent] && self.expanded[parent],
+                None => true,
+            };
+        }
+
+        self.visible_nodes = (0..self.nodes.len()).filter(|i| is_visible[*i]).collect();
+    }
+
+    fn highlighted_node(&self) -> usize {
+        // the cursor always points to a visible node, as collapsing a node
+        // keeps it visible and the list is never empty
+        self.visible_nodes[self.cursor_index]
+    }
+
+    fn set_expanded(&mut self, node: usize, expanded: bool) -> ActionResult {
+        if !self.nodes[node].has_children || self.expanded[node] == expanded {
+            return ActionResult::Clean;
+        }
+
+        self.expanded[node] = expanded;
+        self.update_visible_nodes();
+
+        if let Some(position) = self.visible_nodes.iter().position(|i| *i == node) {
+            self.cursor_index = position;
+        }
+
+        ActionResult::NeedsRedraw
+    }
+
+    fn expand(&mut self) -> ActionResult {
+        let node = self.highlighted_node();
+
+        match self.expanded[node] {
+            // the first child is right below its parent
+            true => self.move_cursor_down(1, false),
+            false => self.set_expanded(node, true),
+        }
+    }
+
+    fn collapse(&mut self) -> ActionResult {
+        let node = self.highlighted_node();
+
+        if self.expanded[node] {
+            return self.set_expanded(node, false);
+        }
+
+        match self.nodes[node].parent {
+            Some(parent) => match self.visible_nodes.iter().position(|i| *i == parent) {
+                Some(position) => self.update_cursor_position(position),
+                None => ActionResult::Clean,
+            },
+            None => ActionResult::Clean,
+        }
+    }
+
+    fn move_cursor_up(&mut self, qty: usize, wrap: bool) -> ActionResult {
+        let new_position = if wrap {
+            let after_wrap = qty.saturating_sub(self.cursor_index);
+            self.cursor_index
+                .checked_sub(qty)
+                .unwrap_or_else(|| self.visible_nodes.len().saturating_sub(after_wrap))
+        } else {
+            self.cursor_index.saturating_sub(qty)
+        };
+
+        self.update_cursor_position(new_position)
+    }
+
+    fn move_cursor_down(&mut self, qty: usize, wrap: bool) -> ActionResult {
+        let mut new_position = self.cursor_index.saturating_add(qty);
+
+        if new_position >= self.visible_nodes.len() {
+            new_position = if wrap {
+                new_position % self.visible_nodes.len()
+            } else {
+                self.visible_nodes.len().saturating_sub(1)
+            }
+        }
+
+        self.update_cursor_position(new_position)
+    }
+
+    fn update_cursor_position(&mut self, new_position: usize) -> ActionResult {
+        if new_position != self.cursor_index {
+            self.cursor_index = new_position;
+            ActionResult::NeedsRedraw
+        } else {
+            ActionResult::Clean
+        }
+    }
+
+    /// Indentation guides and expansion marker rendered before the value of the node.
+    fn guide(&self, node: usize) -> String {
+        let mut ancestors = vec![];
+        let mut current = self.nodes[node].parent;
+
+        while let Some(ancestor) = current {
+            ancestors.push(ancestor);
+            current = self.nodes[ancestor].parent;
+        }
+
+        let mut guide = String::new();
+
+        for ancestor in ancestors.iter().rev() {
+            let ancestor = &self.nodes[*ancestor];
+
+            if ancestor.depth > 0 {
+                guide.push_str(match ancestor.is_last_sibling {
+                    true => "   ",
+                    false => "│  ",
+                });
+            }
+        }
+
+        if self.nodes[node].depth > 0 {
+            guide.push_str(match self.nodes[node].is_last_sibling {
+                true => "└─ ",
+                false => "├─ ",
+            });
+        }
+
+        if self.nodes[node].has_children {
+            guide.push_str(match self.expanded[node] {
+                true => "▾ ",
+                false => "▸ ",
+            });
+        }
+
+        guide
+    }
+}
+
+/// Flattens the nodes in pre-order, so that every node comes right before its children.
+fn flatten<T>(
+    nodes: Vec<TreeNode<T>>,
+    parent: Option<usize>,
+    depth: usize,
+    flat_nodes: &mut Vec<FlatNode>,
+    values: &mut Vec<T>,
+) {
+    let count = nodes.len();
+
+    for (i, node) in nodes.into_iter().enumerate() {
+        let idx = flat_nodes.len();
+
+        flat_nodes.push(FlatNode {
+            parent,
+            depth,
+            is_last_sibling: i + 1 == count,
+            has_children: !node.children.is_empty(),
+        });
+        values.push(node.value);
+
+        flatten(node.children, Some(idx), depth + 1, flat_nodes, values);
+    }
+}
+
+impl<'a, Backend, T> Prompt<Backend> for TreeSelectPrompt<'a, T>
+where
+    Backend: TreeSelectBackend,
+    T: Display,
+{
+    type Config = TreeSelectConfig;
+    type InnerAction = TreeSelectPromptAction;
+    type Output = ListOption<T>;
+
+    fn message(&self) -> &str {
+        self.message
+    }
+
+    fn config(&self) -> &TreeSelectConfig {
+        &self.config
+    }
+
+    fn format_answer(&self, answer: &ListOption<T>) -> String {
+        (self.formatter)(answer.as_ref())
+    }
+
+    fn submit(&mut self) -> InquireResult<Option<ListOption<T>>> {
+        let node = self.highlighted_node();
+
+        if self.leaves_only && self.nodes[node].has_children {
+            let expanded = self.expanded[node];
+            self.set_expanded(node, !expanded);
+            return Ok(None);
+        }
+
+        let value = self.values.swap_remove(node);
+
+        Ok(Some(ListOption::new(node, value)))
+    }
+
+    fn handle(&mut self, action: TreeSelectPromptAction) -> InquireResult<ActionResult> {
+        let result = match action {
+            TreeSelectPromptAction::MoveUp => self.move_cursor_up(1, true),
+            TreeSelectPromptAction::MoveDown => self.move_cursor_down(1, true),
+            TreeSelectPromptAction::PageUp => self.move_cursor_up(self.config.page_size, false),
+            TreeSelectPromptAction::PageDown => self.move_cursor_down(self.config.page_size, false),
+            TreeSelectPromptAction::MoveToStart => self.move_cursor_up(usize::MAX, false),
+            TreeSelectPromptAction::MoveToEnd => self.move_cursor_down(usize::MAX, false),
+            TreeSelectPromptAction::Expand => self.expand(),
+            TreeSelectPromptAction::Collapse => self.collapse(),
+            TreeSelectPromptAction::ToggleExpansion => {
+                let node = self.highlighted_node();
+                let expanded = self.expanded[node];
+                self.set_expanded(node, !expanded)
+            }
+        };
+
+        Ok(result)
+    }
+
+    fn render(&self, backend: &mut Backend) -> InquireResult<()> {
+        let prompt = &self.message;
+
+        backend.render_tree_select_prompt(prompt)?;
+
+        let choices = self
+            .visible_nodes
+            .iter()
+            .map(|i| ListOption::new(*i, &self.values[*i]))
+            .collect::<Vec<ListOption<&T>>>();
+
+        let page = paginate(self.config.page_size, &choices, Some(self.cursor_index));
+
+        let guides = page
+            .content
+            .iter()
+            .map(|option| self.guide(option.index))
+            .collect::<Vec<String>>();
+
+        backend.render_tree_nodes(page, &guides)?;
+
+        if let Some(help_message) = self.help_message {
+            backend.render_help_message(help_message)?;
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,145 @@
+use crate::{
+    list_option::ListOption,
+    test::fake_backend,
+    ui::{Key, KeyModifiers},
+    InquireError, TreeNode, TreeSelect,
+};
+
+/// Pre-order: Engineering, Backend, Frontend, Web, Mobile, Sales.
+fn units() -> Vec<TreeNode<&'static str>> {
+    vec![
+        TreeNode::new("Engineering").with_children(vec![
+            TreeNode::new("Backend"),
+            TreeNode::new("Frontend").with_children(vec!["Web".into(), "Mobile".into()]),
+        ]),
+        TreeNode::new("Sales"),
+    ]
+}
+
+#[test]
+fn nodes_start_collapsed() {
+    let mut backend = fake_backend(vec![Key::Down(KeyModifiers::NONE), Key::Enter]);
+
+    let ans = TreeSelect::new("Question", units())
+        .prompt_with_backend(&mut backend)
+        .unwrap();
+
+    assert_eq!(ListOption::new(5, "Sales"), ans);
+}
+
+#[test]
+fn any_node_can_be_selected_by_default() {
+    let mut backend = fake_backend(vec![Key::Enter]);
+
+    let ans = TreeSelect::new("Question", units())
+        .prompt_with_backend(&mut backend)
+        .unwrap();
+
+    assert_eq!(ListOption::new(0, "Engineering"), ans);
+}
+
+#[test]
+fn right_arrow_expands_and_moves_to_first_child() {
+    let mut backend = fake_backend(vec![
+        Key::Right(KeyModifiers::NONE),
+        Key::Right(KeyModifiers::NONE),
+        Key::Down(KeyModifiers::NONE),
+        Key::Right(KeyModifiers::NONE),
+        Key::Down(KeyModifiers::NONE),
+        Key::Enter,
+    ]);
+
+    let ans = TreeSelect::new("Question", units())
+        .prompt_with_backend(&mut backend)
+        .unwrap();
+
+    assert_eq!(ListOption::new(3, "Web"), ans);
+}
+
+#[test]
+fn left_arrow_moves_to_parent_and_collapses() {
+    let mut backend = fake_backend(vec![
+        Key::Down(KeyModifiers::NONE),
+        Key::Down(KeyModifiers::NONE),
+        Key::Down(KeyModifiers::NONE),
+        // Web -> Frontend
+        Key::Left(KeyModifiers::NONE),
+        // collapses Frontend, hiding Web and Mobile
+        Key::Left(KeyModifiers::NONE),
+        Key::Down(KeyModifiers::NONE),
+        Key::Enter,
+    ]);
+
+    let ans = TreeSelect::new("Question", units())
+        .with_expanded(true)
+        .prompt_with_backend(&mut backend)
+        .unwrap();
+
+    assert_eq!(ListOption::new(5, "Sales"), ans);
+}
+
+#[test]
+fn space_toggles_expansion() {
+    let mut backend = fake_backend(vec![
+        Key::Char(' ', KeyModifiers::NONE),
+        Key::Char(' ', KeyModifiers::NONE),
+        Key::Char(' ', KeyModifiers::NONE),
+        Key::Down(KeyModifiers::NONE),
+        Key::Enter,
+    ]);
+
+    let ans = TreeSelect::new("Question", units())
+        .prompt_with_backend(&mut backend)
+        .unwrap();
+
+    assert_eq!(ListOption::new(1, "Backend"), ans);
+}
+
+#[test]
+fn enter_toggles_parents_when_only_leaves_are_selectable() {
+    let mut backend = fake_backend(vec![
+        Key::Enter,
+        Key::Down(KeyModifiers::NONE),
+        Key::Down(KeyModifiers::NONE),
+        Key::Enter,
+        Key::Down(KeyModifiers::NONE),
+        Key::Down(KeyModifiers::NONE),
+        Key::Enter,
+    ]);
+
+    let ans = TreeSelect::new("Question", units())
+        .with_leaves_only(true)
+        .prompt_with_backend(&mut backend)
+        .unwrap();
+
+    assert_eq!(ListOption::new(4, "Mobile"), ans);
+}
+
+#[test]
+fn vim_mode_navigates_the_tree() {
+    let mut backend = fake_backend(vec![
+        Key::Char('l', KeyModifiers::NONE),
+        Key::Char('j', KeyModifiers::NONE),
+        Key::Char('j', KeyModifiers::NONE),
+        Key::Char('h', KeyModifiers::NONE),
+        Key::Char('h', KeyModifiers::NONE),
+        Key::Char('j', KeyModifiers::NONE),
+        Key::Enter,
+    ]);
+
+    let ans = TreeSelect::new("Question", units())
+        .with_vim_mode(true)
+        .prompt_with_backend(&mut backend)
+        .unwrap();
+
+    assert_eq!(ListOption::new(5, "Sales"), ans);
+}
+
+#[test]
+fn empty_tree_is_invalid_configuration() {
+    let mut backend = fake_backend(vec![Key::Enter]);
+
+    let ans = TreeSelect::<&str>::new("Question", vec![]).prompt_with_backend(&mut backend);
+
+    assert!(matches!(ans, Err(InquireError::InvalidConfiguration(_))));
+}
@@ -0,0 +1,23 @@
+use crate::{ui::Key, InnerAction, InputAction};
+
+use super::config::TypeToConfirmConfig;
+
+/// Set of actions for a TypeToConfirmPrompt.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum TypeToConfirmPromptAction {
+    /// Action on the value text input handler.
+    ValueInput(InputAction),
+}
+
+impl InnerAction for TypeToConfirmPromptAction {
+    type Config = TypeToConfirmConfig;
+
+    fn from_key(key: Key, _config: &TypeToConfirmConfig) -> Option<Self> {
+        let action = match InputAction::from_key(key, &()) {
+            Some(action) => Self::ValueInput(action),
+            None => return None,
+        };
+
+        Some(action)
+    }
+}
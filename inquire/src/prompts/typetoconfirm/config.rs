@@ -0,0 +1,10 @@
+use crate::TypeToConfirm;
+
+/// Configuration settings used in the execution of a TypeToConfirmPrompt.
+pub struct TypeToConfirmConfig {}
+
+impl From<&TypeToConfirm<'_>> for TypeToConfirmConfig {
+    fn from(_value: &TypeToConfirm<'_>) -> Self {
+        Self {}
+    }
+}
@@ -0,0 +1,154 @@
+mod action;
+mod config;
+mod prompt;
+#[cfg(test)]
+mod test;
+
+pub use action::*;
+
+use crate::{
+    config::get_configuration,
+    error::{InquireError, InquireResult},
+    prompts::prompt::Prompt,
+    terminal::get_default_terminal,
+    ui::{Backend, RenderConfig, TypeToConfirmBackend},
+};
+
+use self::prompt::TypeToConfirmPrompt;
+
+/// Prompt to confirm a destructive action, which requires the user to type an exact phrase, such as the name of the resource being deleted, to proceed.
+///
+/// The prompt message is rendered with the prefix of error messages, followed by a line asking the user to type the expected phrase. While the user types, the characters that do not match the phrase are highlighted with the style of error messages.
+///
+/// The comparison is exact and case-sensitive. Submitting anything other than the phrase displays the error message and keeps the prompt open, so that the only ways out of it are typing the phrase or cancelling the prompt.
+///
+/// - **Prompt message**: Required when creating the prompt, describing the action to confirm.
+/// - **Phrase**: Required when creating the prompt, the exact text the user has to type. Must be **non-empty**, otherwise the prompt will fail with an [`InquireError::InvalidConfiguration`] error.
+/// - **Help message**: Message displayed at the line below the prompt.
+/// - **Error message**: Message displayed when the submitted text does not match the phrase.
+///
+/// # Example
+///
+/// ```no_run
+/// use inquire::TypeToConfirm;
+///
+/// let confirmed = TypeToConfirm::new("This will permanently delete the repository.", "octocat/hello-world")
+///     .with_help_message("This action can not be undone")
+///     .prompt_skippable();
+///
+/// match confirmed {
+///     Ok(true) => println!("Deleting repository..."),
+///     Ok(false) => println!("The repository was kept"),
+///     Err(_) => println!("There was an error, please try again"),
+/// }
+/// ```
+///
+/// [`InquireError::InvalidConfiguration`]: crate::error::InquireError::InvalidConfiguration
+#[derive(Clone)]
+pub struct TypeToConfirm<'a> {
+    /// Message to be presented to the user.
+    pub message: &'a str,
+
+    /// Phrase the user has to type to confirm the action.
+    pub phrase: &'a str,
+
+    /// Help message to be presented to the user.
+    pub help_message: Option<&'a str>,
+
+    /// Error message displayed when the submitted text does not match the phrase.
+    pub error_message: String,
+
+    /// RenderConfig to apply to the rendered interface.
+    ///
+    /// Note: The default render config considers if the NO_COLOR environment variable
+    /// is set to decide whether to render the colored config or the empty one.
+    ///
+    /// When overriding the config in a prompt, NO_COLOR is no longer considered and your
+    /// config is treated as the only source of truth. If you want to customize colors
+    /// and still support NO_COLOR, you will have to do this on your end.
+    pub render_config: RenderConfig<'a>,
+}
+
+impl<'a> TypeToConfirm<'a> {
+    /// Default help message.
+    pub const DEFAULT_HELP_MESSAGE: Option<&'a str> = None;
+
+    /// Default error message.
+    pub const DEFAULT_ERROR_MESSAGE: &'a str = "The typed text does not match the expected phrase";
+
+    /// Creates a [TypeToConfirm] with the provided message and phrase, along with default configuration values.
+    pub fn new(message: &'a str, phrase: &'a str) -> Self {
+        Self {
+            message,
+            phrase,
+            help_message: Self::DEFAULT_HELP_MESSAGE,
+            error_message: String::from(Self::DEFAULT_ERROR_MESSAGE),
+            render_config: get_configuration(),
+        }
+    }
+
+    /// Sets the help message of the prompt.
+    pub fn with_help_message(mut self, message: &'a str) -> Self {
+        self.help_message = Some(message);
+        self
+    }
+
+    /// Removes the set help message.
+    pub fn without_help_message(mut self) -> Self {
+        self.help_message = None;
+        self
+    }
+
+    /// Sets the error message displayed when the submitted text does not match the phrase.
+    pub fn with_error_message(mut self, error_message: &'a str) -> Self {
+        self.error_message = String::from(error_message);
+        self
+    }
+
+    /// Sets the provided color theme to this prompt.
+    ///
+    /// Note: The default render config considers if the NO_COLOR environment variable
+    /// is set to decide whether to render the colored config or the empty one.
+    ///
+    /// When overriding the config in a prompt, NO_COLOR is no longer considered and your
+    /// config is treated as the only source of truth. If you want to customize colors
+    /// and still support NO_COLOR, you will have to do this on your end.
+    pub fn with_render_config(mut self, render_config: RenderConfig<'a>) -> Self {
+        self.render_config = render_config;
+        self
+    }
+
+    /// Parses the provided behavioral and rendering options and prompts
+    /// the CLI user for input according to the defined rules.
+    ///
+    /// This method is intended for flows where the user skipping/cancelling
+    /// the prompt - by pressing ESC - is considered normal behavior. In this case,
+    /// it does not return `Err(InquireError::OperationCanceled)`, but `Ok(false)`.
+    ///
+    /// Meanwhile, if the user does type the phrase, the method returns `Ok(true)`.
+    pub fn prompt_skippable(self) -> InquireResult<bool> {
+        match self.prompt() {
+            Ok(()) => Ok(true),
+            Err(InquireError::OperationCanceled) => Ok(false),
+            Err(err) => Err(err),
+        }
+    }
+
+    /// Parses the provided behavioral and rendering options and prompts
+    /// the CLI user for input according to the defined rules.
+    ///
+    /// Returns `Ok(())` once the user types the phrase, as it is the only
+    /// answer the prompt accepts.
+    pub fn prompt(self) -> InquireResult<()> {
+        let (input_reader, terminal) = get_default_terminal()?;
+        let mut backend = Backend::new(input_reader, terminal, self.render_config)?;
+        self.prompt_with_backend(&mut backend)
+    }
+
+    pub(crate) fn prompt_with_backend<B: TypeToConfirmBackend>(
+        self,
+        backend: &mut B,
+    ) -> InquireResult<()> {
+        TypeToConfirmPrompt::new(self)?.prompt(backend)
+    }
+}
@@ -0,0 +1,115 @@
+use crate::{
+    error::InquireResult,
+    input::Input,
+    prompts::prompt::{ActionResult, Prompt},
+    ui::TypeToConfirmBackend,
+    validator::ErrorMessage,
+    InquireError, TypeToConfirm,
+};
+
+use super::{action::TypeToConfirmPromptAction, config::TypeToConfirmConfig};
+
+pub struct TypeToConfirmPrompt<'a> {
+    message: &'a str,
+    config: TypeToConfirmConfig,
+    phrase: &'a str,
+    error: Option<ErrorMessage>,
+    error_message: String,
+    help_message: Option<&'a str>,
+    input: Input,
+}
+
+impl<'a> TypeToConfirmPrompt<'a> {
+    pub fn new(tc: TypeToConfirm<'a>) -> InquireResult<Self> {
+        if tc.phrase.is_empty() {
+            return Err(InquireError::InvalidConfiguration(
+                "Confirmation phrase can not be empty".into(),
+            ));
+        }
+
+        Ok(Self {
+            message: tc.message,
+            config: (&tc).into(),
+            phrase: tc.phrase,
+            error: None,
+            error_message: tc.error_message,
+            help_message: tc.help_message,
+            input: Input::new(),
+        })
+    }
+
+    /// Indexes of the typed characters that do not match the phrase at
+    /// the same position, including the ones typed past its end.
+    fn mismatched_chars(&self) -> Vec<usize> {
+        let mut expected = self.phrase.chars();
+
+        self.input
+            .content()
+            .chars()
+            .enumerate()
+            .filter(|(_, c)| expected.next() != Some(*c))
+            .map(|(i, _)| i)
+            .collect()
+    }
+}
+
+impl<'a, Backend> Prompt<Backend> for TypeToConfirmPrompt<'a>
+where
+    Backend: TypeToConfirmBackend,
+{
+    type Config = TypeToConfirmConfig;
+    type InnerAction = TypeToConfirmPromptAction;
+    type Output = ();
+
+    fn message(&self) -> &str {
+        self.message
+    }
+
+    fn config(&self) -> &TypeToConfirmConfig {
+        &self.config
+    }
+
+    fn format_answer(&self, _answer: &()) -> String {
+        self.phrase.to_string()
+    }
+
+    fn submit(&mut self) -> InquireResult<Option<()>> {
+        if self.input.content() == self.phrase {
+            return Ok(Some(()));
+        }
+
+        self.error = Some(ErrorMessage::Custom(self.error_message.clone()));
+        Ok(None)
+    }
+
+    fn handle(&mut self, action: TypeToConfirmPromptAction) -> InquireResult<ActionResult> {
+        let result = match action {
+            TypeToConfirmPromptAction::ValueInput(input_action) => {
+                self.input.handle(input_action).into()
+            }
+        };
+
+        Ok(result)
+    }
+
+    fn render(&self, backend: &mut Backend) -> InquireResult<()> {
+        let prompt = &self.message;
+
+        if let Some(error) = &self.error {
+            backend.render_error_message(error)?;
+        }
+
+        backend.render_type_to_confirm_prompt(
+            prompt,
+            self.phrase,
+            &self.input,
+            &self.mismatched_chars(),
+        )?;
+
+        if let Some(message) = self.help_message {
+            backend.render_help_message(message)?;
+        }
+
+        Ok(())
+    }
+}
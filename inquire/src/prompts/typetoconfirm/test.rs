@@ -0,0 +1,85 @@
+use crate::{
+    error::InquireResult,
+    ui::{
+        test::{FakeBackend, Token},
+        Key, KeyModifiers,
+    },
+    validator::ErrorMessage,
+    InquireError, TypeToConfirm,
+};
+
+#[test]
+fn typing_the_phrase_confirms() -> InquireResult<()> {
+    let mut input = Key::char_keys_from_str("octocat/hello");
+    input.push(Key::Enter);
+    let mut backend = FakeBackend::new(input);
+
+    TypeToConfirm::new("Delete repository?", "octocat/hello").prompt_with_backend(&mut backend)?;
+
+    assert!(backend
+        .frames()
+        .last()
+        .unwrap()
+        .has_token(&Token::AnsweredPrompt(
+            "Delete repository?".into(),
+            "octocat/hello".into()
+        )));
+
+    Ok(())
+}
+
+#[test]
+fn mismatched_characters_are_reported() -> InquireResult<()> {
+    let mut input = Key::char_keys_from_str("ocTocat!!");
+    input.extend([Key::Backspace, Key::Backspace]);
+    input.extend([
+        Key::Home,
+        Key::Delete(KeyModifiers::NONE),
+        Key::Delete(KeyModifiers::NONE),
+        Key::Delete(KeyModifiers::NONE),
+    ]);
+    input.extend(Key::char_keys_from_str("oct"));
+    input.push(Key::Enter);
+    let mut backend = FakeBackend::new(input);
+
+    TypeToConfirm::new("Delete repository?", "octocat").prompt_with_backend(&mut backend)?;
+
+    assert!(backend.frames()[9].has_token(&Token::ConfirmPhrase {
+        phrase: "octocat".into(),
+        mismatched_chars: vec![2, 7, 8],
+    }));
+
+    Ok(())
+}
+
+#[test]
+fn comparison_is_case_sensitive() -> InquireResult<()> {
+    let mut input = Key::char_keys_from_str("Octocat");
+    input.push(Key::Enter);
+    input.push(Key::Escape);
+    let mut backend = FakeBackend::new(input);
+
+    let confirmed =
+        TypeToConfirm::new("Delete repository?", "octocat").prompt_with_backend(&mut backend);
+
+    assert!(matches!(confirmed, Err(InquireError::OperationCanceled)));
+    assert!(
+        backend.frames()[8].has_token(&Token::ErrorMessage(ErrorMessage::Custom(
+            TypeToConfirm::DEFAULT_ERROR_MESSAGE.into()
+        )))
+    );
+
+    Ok(())
+}
+
+#[test]
+fn empty_phrase_is_invalid_configuration() {
+    let mut backend = FakeBackend::new(vec![Key::Enter]);
+
+    let confirmed = TypeToConfirm::new("Delete repository?", "").prompt_with_backend(&mut backend);
+
+    assert!(matches!(
+        confirmed,
+        Err(InquireError::InvalidConfiguration(_))
+    ));
+}
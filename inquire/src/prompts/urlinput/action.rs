@@ -0,0 +1,23 @@
+use crate::{ui::Key, InnerAction, InputAction};
+
+use super::config::UrlConfig;
+
+/// Set of actions for a UrlPrompt.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum UrlPromptAction {
+    /// Action on the value text input handler.
+    ValueInput(InputAction),
+}
+
+impl InnerAction for UrlPromptAction {
+    type Config = UrlConfig;
+
+    fn from_key(key: Key, _config: &UrlConfig) -> Option<Self> {
+        let action = match InputAction::from_key(key, &()) {
+            Some(action) => Self::ValueInput(action),
+            None => return None,
+        };
+
+        Some(action)
+    }
+}
@@ -0,0 +1,10 @@
+use crate::Url;
+
+/// Configuration settings used in the execution of a UrlPrompt.
+pub struct UrlConfig {}
+
+impl From<&Url<'_>> for UrlConfig {
+    fn from(_value: &Url<'_>) -> Self {
+        Self {}
+    }
+}
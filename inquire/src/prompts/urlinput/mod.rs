@@ -0,0 +1,281 @@
+mod action;
+mod config;
+mod prompt;
+#[cfg(test)]
+mod test;
+
+pub use action::*;
+
+use crate::{
+    config::get_configuration,
+    error::{InquireError, InquireResult},
+    formatter::UrlFormatter,
+    prompts::prompt::Prompt,
+    terminal::get_default_terminal,
+    ui::{Backend, CustomTypeBackend, RenderConfig},
+    validator::CustomTypeValidator,
+};
+
+use self::prompt::UrlPrompt;
+
+/// Prompt suitable for URLs, such as `https://example.com/docs`, returning a parsed [`url::Url`].
+///
+/// The input is parsed when the user submits it, displaying an error message if it is not a valid URL, e.g. when the host is missing or contains invalid characters.
+///
+/// When the input has no scheme, i.e. it does not contain `://`, the default scheme is prepended to it, so that the user can simply type `example.com`. The scheme is `https` by default and can be changed or removed. The prompt can also be restricted to `http` and `https` URLs only.
+///
+/// Url prompts provide several options of configuration:
+///
+/// - **Prompt message**: Required when creating the prompt.
+/// - **Starting input**: Initial value of the prompt's text input.
+/// - **Default value**: Default value returned when the user submits an empty response.
+/// - **Placeholder**: Short hint that describes the expected value of the input.
+/// - **Help message**: Message displayed at the line below the prompt.
+/// - **Default scheme**: Scheme prepended to inputs without one, `https` by default.
+/// - **HTTP only**: Whether only `http` and `https` URLs are accepted, false by default.
+/// - **Formatter**: Custom formatter in case you need to pre-process the user input before showing it as the final answer.
+///   - Prints the parsed URL by default, e.g. `https://example.com/` for an input of `example.com`.
+/// - **Validators**: Custom validators to the parsed URL, displaying an error message if it does not pass the requirements.
+///
+/// # Example
+///
+/// ```no_run
+/// use inquire::Url;
+///
+/// let ans = Url::new("Homepage:")
+///     .with_placeholder("example.com")
+///     .with_http_only(true)
+///     .prompt();
+///
+/// match ans {
+///     Ok(url) => println!("Fetching {}", url.host_str().unwrap_or_default()),
+///     Err(_) => println!("Could not read the URL"),
+/// }
+/// ```
+#[derive(Clone)]
+pub struct Url<'a> {
+    /// Message to be presented to the user.
+    pub message: &'a str,
+
+    /// Initial value of the prompt's text input.
+    ///
+    /// If you want to set a default value for the prompt, returned when the user's submission is empty, see [`default`].
+    ///
+    /// [`default`]: Self::default
+    pub starting_input: Option<&'a str>,
+
+    /// Default value, returned when the user input is empty.
+    pub default: Option<url::Url>,
+
+    /// Short hint that describes the expected value of the input.
+    pub placeholder: Option<&'a str>,
+
+    /// Help message to be presented to the user.
+    pub help_message: Option<&'a str>,
+
+    /// Scheme prepended to inputs that do not contain `://`.
+    pub default_scheme: Option<&'a str>,
+
+    /// Whether only `http` and `https` URLs are accepted.
+    pub http_only: bool,
+
+    /// Function that formats the user input and presents it to the user as the final rendering of the prompt.
+    pub formatter: UrlFormatter<'a>,
+
+    /// Function that formats the default value when it is displayed next to the prompt message.
+    pub default_value_formatter: UrlFormatter<'a>,
+
+    /// Collection of validators to apply to the parsed URL.
+    ///
+    /// Validators are executed in the order they are stored, stopping at and displaying to the user
+    /// only the first validation error that might appear.
+    ///
+    /// The possible error is displayed to the user one line above the prompt.
+    pub validators: Vec<Box<dyn CustomTypeValidator<url::Url>>>,
+
+    /// RenderConfig to apply to the rendered interface.
+    ///
+    /// Note: The default render config considers if the NO_COLOR environment variable
+    /// is set to decide whether to render the colored config or the empty one.
+    ///
+    /// When overriding the config in a prompt, NO_COLOR is no longer considered and your
+    /// config is treated as the only source of truth. If you want to customize colors
+    /// and still support NO_COLOR, you will have to do this on your end.
+    pub render_config: RenderConfig<'a>,
+}
+
+impl<'a> Url<'a> {
+    /// Default formatter, which prints the parsed URL.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use inquire::Url;
+    ///
+    /// let formatter = Url::DEFAULT_FORMATTER;
+    /// let url = url::Url::parse("https://example.com").unwrap();
+    /// assert_eq!(String::from("https://example.com/"), formatter(&url));
+    /// ```
+    pub const DEFAULT_FORMATTER: UrlFormatter<'a> = &|url| url.to_string();
+
+    /// Default scheme prepended to inputs without one.
+    pub const DEFAULT_SCHEME: Option<&'a str> = Some("https");
+
+    /// Default value of whether only `http` and `https` URLs are accepted.
+    pub const DEFAULT_HTTP_ONLY: bool = false;
+
+    /// Default validators added to the [Url] prompt, none.
+    pub const DEFAULT_VALIDATORS: Vec<Box<dyn CustomTypeValidator<url::Url>>> = vec![];
+
+    /// Creates a [Url] with the provided message, along with default configuration values.
+    pub fn new(message: &'a str) -> Self {
+        Self {
+            message,
+            starting_input: None,
+            default: None,
+            placeholder: None,
+            help_message: None,
+            default_scheme: Self::DEFAULT_SCHEME,
+            http_only: Self::DEFAULT_HTTP_ONLY,
+            formatter: Self::DEFAULT_FORMATTER,
+            default_value_formatter: Self::DEFAULT_FORMATTER,
+            validators: Self::DEFAULT_VALIDATORS,
+            render_config: get_configuration(),
+        }
+    }
+
+    /// Sets the initial value of the prompt's text input.
+    ///
+    /// If you want to set a default value for the prompt, returned when the user's submission is empty, see [`with_default`].
+    ///
+    /// [`with_default`]: Self::with_default
+    pub fn with_starting_input(mut self, message: &'a str) -> Self {
+        self.starting_input = Some(message);
+        self
+    }
+
+    /// Sets the default input.
+    pub fn with_default(mut self, default: url::Url) -> Self {
+        self.default = Some(default);
+        self
+    }
+
+    /// Sets the placeholder.
+    pub fn with_placeholder(mut self, placeholder: &'a str) -> Self {
+        self.placeholder = Some(placeholder);
+        self
+    }
+
+    /// Sets the help message of the prompt.
+    pub fn with_help_message(mut self, message: &'a str) -> Self {
+        self.help_message = Some(message);
+        self
+    }
+
+    /// Sets the scheme prepended to inputs that do not contain `://`.
+    pub fn with_default_scheme(mut self, scheme: &'a str) -> Self {
+        self.default_scheme = Some(scheme);
+        self
+    }
+
+    /// Removes the default scheme, rejecting inputs without one.
+    pub fn without_default_scheme(mut self) -> Self {
+        self.default_scheme = None;
+        self
+    }
+
+    /// Sets whether only `http` and `https` URLs are accepted.
+    pub fn with_http_only(mut self, http_only: bool) -> Self {
+        self.http_only = http_only;
+        self
+    }
+
+    /// Sets the formatter.
+    pub fn with_formatter(mut self, formatter: UrlFormatter<'a>) -> Self {
+        self.formatter = formatter;
+        self
+    }
+
+    /// Sets the formatter for default values.
+    pub fn with_default_value_formatter(mut self, formatter: UrlFormatter<'a>) -> Self {
+        self.default_value_formatter = formatter;
+        self
+    }
+
+    /// Adds a validator to the collection of validators. You might want to use this feature
+    /// in case you need to require certain features from the parsed URL, such as a specific host.
+    ///
+    /// Validators are executed in the order they are stored, stopping at and displaying to the user
+    /// only the first validation error that might appear.
+    ///
+    /// The possible error is displayed to the user one line above the prompt.
+    pub fn with_validator<V>(mut self, validator: V) -> Self
+    where
+        V: CustomTypeValidator<url::Url> + 'static,
+    {
+        self.validators.push(Box::new(validator));
+        self
+    }
+
+    /// Adds the validators to the collection of validators in the order they are given.
+    ///
+    /// Validators are executed in the order they are stored, stopping at and displaying to the user
+    /// only the first validation error that might appear.
+    ///
+    /// The possible error is displayed to the user one line above the prompt.
+    pub fn with_validators(
+        mut self,
+        validators: &[Box<dyn CustomTypeValidator<url::Url>>],
+    ) -> Self {
+        for validator in validators {
+            #[allow(suspicious_double_ref_op)]
+            self.validators.push(validator.clone());
+        }
+        self
+    }
+
+    /// Sets the provided color theme to this prompt.
+    ///
+    /// Note: The default render config considers if the NO_COLOR environment variable
+    /// is set to decide whether to render the colored config or the empty one.
+    ///
+    /// When overriding the config in a prompt, NO_COLOR is no longer considered and your
+    /// config is treated as the only source of truth. If you want to customize colors
+    /// and still support NO_COLOR, you will have to do this on your end.
+    pub fn with_render_config(mut self, render_config: RenderConfig<'a>) -> Self {
+        self.render_config = render_config;
+        self
+    }
+
+    /// Parses the provided behavioral and rendering options and prompts
+    /// the CLI user for input according to the defined rules.
+    ///
+    /// This method is intended for flows where the user skipping/cancelling
+    /// the prompt - by pressing ESC - is considered normal behavior. In this case,
+    /// it does not return `Err(InquireError::OperationCanceled)`, but `Ok(None)`.
+    ///
+    /// Meanwhile, if the user does submit an answer, the method wraps the return
+    /// type with `Some`.
+    pub fn prompt_skippable(self) -> InquireResult<Option<url::Url>> {
+        match self.prompt() {
+            Ok(answer) => Ok(Some(answer)),
+            Err(InquireError::OperationCanceled) => Ok(None),
+            Err(err) => Err(err),
+        }
+    }
+
+    /// Parses the provided behavioral and rendering options and prompts
+    /// the CLI user for input according to the defined rules.
+    pub fn prompt(self) -> InquireResult<url::Url> {
+        let (input_reader, terminal) = get_default_terminal()?;
+        let mut backend = Backend::new(input_reader, terminal, self.render_config)?;
+        self.prompt_with_backend(&mut backend)
+    }
+
+    pub(crate) fn prompt_with_backend<B: CustomTypeBackend>(
+        self,
+        backend: &mut B,
+    ) -> InquireResult<url::Url> {
+        UrlPrompt::from(self).prompt(backend)
+    }
+}
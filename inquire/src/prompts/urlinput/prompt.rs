@@ -0,0 +1,163 @@
+use url::ParseError;
+
+use crate::{
+    error::InquireResult,
+    formatter::UrlFormatter,
+    input::Input,
+    prompts::prompt::{ActionResult, Prompt},
+    ui::CustomTypeBackend,
+    validator::{CustomTypeValidator, ErrorMessage, Validation},
+    InquireError, Url,
+};
+
+use super::{action::UrlPromptAction, config::UrlConfig};
+
+pub struct UrlPrompt<'a> {
+    message: &'a str,
+    config: UrlConfig,
+    error: Option<ErrorMessage>,
+    help_message: Option<&'a str>,
+    default: Option<url::Url>,
+    input: Input,
+    default_scheme: Option<&'a str>,
+    http_only: bool,
+    formatter: UrlFormatter<'a>,
+    default_value_formatter: UrlFormatter<'a>,
+    validators: Vec<Box<dyn CustomTypeValidator<url::Url>>>,
+}
+
+impl<'a> From<Url<'a>> for UrlPrompt<'a> {
+    fn from(uo: Url<'a>) -> Self {
+        let input = Input::new_with(uo.starting_input.unwrap_or_default());
+        let input = if let Some(placeholder) = uo.placeholder {
+            input.with_placeholder(placeholder)
+        } else {
+            input
+        };
+
+        Self {
+            message: uo.message,
+            config: (&uo).into(),
+            error: None,
+            help_message: uo.help_message,
+            default: uo.default,
+            input,
+            default_scheme: uo.default_scheme,
+            http_only: uo.http_only,
+            formatter: uo.formatter,
+            default_value_formatter: uo.default_value_formatter,
+            validators: uo.validators,
+        }
+    }
+}
+
+impl<'a> UrlPrompt<'a> {
+    fn parse_url(&self, input: &str) -> Result<url::Url, String> {
+        let input = input.trim();
+
+        let url = match self.default_scheme {
+            Some(scheme) if !input.contains("://") => {
+                url::Url::parse(&format!("{scheme}://{input}"))
+            }
+            _ => url::Url::parse(input),
+        };
+
+        let url = url.map_err(|err| match err {
+            ParseError::RelativeUrlWithoutBase => {
+                String::from("URL must start with a scheme, e.g. https://")
+            }
+            ParseError::EmptyHost => String::from("URL must have a host, e.g. example.com"),
+            err => format!("Invalid URL: {err}"),
+        })?;
+
+        if self.http_only && url.scheme() != "http" && url.scheme() != "https" {
+            return Err(String::from("URL must start with http:// or https://"));
+        }
+
+        Ok(url)
+    }
+
+    fn get_final_answer(&self) -> Result<url::Url, String> {
+        match &self.default {
+            Some(val) if self.input.content().trim().is_empty() => Ok(val.clone()),
+            _ => self.parse_url(self.input.content()),
+        }
+    }
+
+    fn validate_current_answer(&self, value: &url::Url) -> InquireResult<Validation> {
+        for validator in &self.validators {
+            match validator.validate(value) {
+                Ok(Validation::Valid) => {}
+                Ok(Validation::Invalid(msg)) => return Ok(Validation::Invalid(msg)),
+                Err(err) => return Err(InquireError::Custom(err)),
+            }
+        }
+
+        Ok(Validation::Valid)
+    }
+}
+
+impl<'a, Backend> Prompt<Backend> for UrlPrompt<'a>
+where
+    Backend: CustomTypeBackend,
+{
+    type Config = UrlConfig;
+    type InnerAction = UrlPromptAction;
+    type Output = url::Url;
+
+    fn message(&self) -> &str {
+        self.message
+    }
+
+    fn config(&self) -> &UrlConfig {
+        &self.config
+    }
+
+    fn format_answer(&self, answer: &url::Url) -> String {
+        (self.formatter)(answer)
+    }
+
+    fn submit(&mut self) -> InquireResult<Option<url::Url>> {
+        let answer = match self.get_final_answer() {
+            Ok(answer) => match self.validate_current_answer(&answer)? {
+                Validation::Valid => Some(answer),
+                Validation::Invalid(msg) => {
+                    self.error = Some(msg);
+                    None
+                }
+            },
+            Err(message) => {
+                self.error = Some(message.into());
+                None
+            }
+        };
+
+        Ok(answer)
+    }
+
+    fn handle(&mut self, action: UrlPromptAction) -> InquireResult<ActionResult> {
+        let result = match action {
+            UrlPromptAction::ValueInput(input_action) => self.input.handle(input_action).into(),
+        };
+
+        Ok(result)
+    }
+
+    fn render(&self, backend: &mut Backend) -> InquireResult<()> {
+        let prompt = &self.message;
+
+        if let Some(error) = &self.error {
+            backend.render_error_message(error)?;
+        }
+
+        let default_message = self.default.as_ref().map(self.default_value_formatter);
+
+        backend.render_prompt(prompt, default_message.as_deref(), &self.input)?;
+
+        if let Some(message) = self.help_message {
+            backend.render_help_message(message)?;
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,141 @@
+use crate::{
+    error::InquireResult,
+    ui::{
+        test::{FakeBackend, Token},
+        Key,
+    },
+    validator::{ErrorMessage, Validation},
+    Url,
+};
+
+fn keys(steps: &[&str]) -> Vec<Key> {
+    let mut keys = vec![];
+    for step in steps {
+        keys.extend(Key::char_keys_from_str(step));
+        keys.push(Key::Enter);
+    }
+    keys
+}
+
+fn clear(count: usize) -> Vec<Key> {
+    vec![Key::Backspace; count]
+}
+
+fn error(message: &str) -> Token {
+    Token::ErrorMessage(ErrorMessage::Custom(message.into()))
+}
+
+#[test]
+fn typed_url_is_parsed() -> InquireResult<()> {
+    let mut backend = FakeBackend::new(keys(&["http://example.com/docs?page=2"]));
+
+    let ans = Url::new("URL").prompt_with_backend(&mut backend)?;
+
+    assert_eq!("http", ans.scheme());
+    assert_eq!(Some("example.com"), ans.host_str());
+    assert_eq!("/docs", ans.path());
+    assert_eq!(Some("page=2"), ans.query());
+
+    Ok(())
+}
+
+#[test]
+fn default_scheme_is_prepended() -> InquireResult<()> {
+    let mut backend = FakeBackend::new(keys(&["example.com"]));
+
+    let ans = Url::new("URL").prompt_with_backend(&mut backend)?;
+
+    assert_eq!("https://example.com/", ans.as_str());
+    assert!(backend
+        .frames()
+        .last()
+        .unwrap()
+        .has_token(&Token::AnsweredPrompt(
+            "URL".into(),
+            "https://example.com/".into()
+        )));
+
+    let mut input = keys(&["example.com"]);
+    input.extend(clear(11));
+    input.extend(keys(&["ftp://example.com"]));
+    let mut backend = FakeBackend::new(input);
+
+    let ans = Url::new("URL")
+        .without_default_scheme()
+        .prompt_with_backend(&mut backend)?;
+
+    assert_eq!("ftp://example.com/", ans.as_str());
+    assert!(backend.frames()[12].has_token(&error("URL must start with a scheme, e.g. https://")));
+
+    Ok(())
+}
+
+#[test]
+fn invalid_hosts_are_rejected_on_submit() -> InquireResult<()> {
+    let mut input = keys(&["https://"]);
+    input.extend(keys(&["example.com"]));
+    let mut backend = FakeBackend::new(input);
+
+    let ans = Url::new("URL").prompt_with_backend(&mut backend)?;
+
+    assert_eq!("https://example.com/", ans.as_str());
+    assert!(backend.frames()[9].has_token(&error("URL must have a host, e.g. example.com")));
+
+    Ok(())
+}
+
+#[test]
+fn http_only_rejects_other_schemes() -> InquireResult<()> {
+    let mut input = keys(&["ftp://example.com"]);
+    input.extend(clear(17));
+    input.extend(keys(&["example.com"]));
+    let mut backend = FakeBackend::new(input);
+
+    let ans = Url::new("URL")
+        .with_http_only(true)
+        .prompt_with_backend(&mut backend)?;
+
+    assert_eq!("https://example.com/", ans.as_str());
+    assert!(backend.frames()[18].has_token(&error("URL must start with http:// or https://")));
+
+    Ok(())
+}
+
+#[test]
+fn empty_input_returns_the_default() -> InquireResult<()> {
+    let mut backend = FakeBackend::new(vec![Key::Enter]);
+
+    let default = url::Url::parse("https://example.com").unwrap();
+
+    let ans = Url::new("URL")
+        .with_default(default.clone())
+        .prompt_with_backend(&mut backend)?;
+
+    assert_eq!(default, ans);
+    assert!(backend.frames()[0].has_token(&Token::DefaultValue("https://example.com/".into())));
+
+    Ok(())
+}
+
+#[test]
+fn validators_receive_the_parsed_url() -> InquireResult<()> {
+    let mut input = keys(&["example.org"]);
+    input.extend(clear(3));
+    input.extend(keys(&["com"]));
+    let mut backend = FakeBackend::new(input);
+
+    let ans = Url::new("URL")
+        .with_validator(|url: &url::Url| {
+            if url.host_str() == Some("example.com") {
+                Ok(Validation::Valid)
+            } else {
+                Ok(Validation::Invalid("Only example.com is supported".into()))
+            }
+        })
+        .prompt_with_backend(&mut backend)?;
+
+    assert_eq!(Some("example.com"), ans.host_str());
+    assert!(backend.frames()[12].has_token(&error("Only example.com is supported")));
+
+    Ok(())
+}
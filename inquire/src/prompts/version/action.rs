@@ -0,0 +1,43 @@
+use crate::{
+    ui::{Key, KeyModifiers},
+    InnerAction, InputAction,
+};
+
+use super::config::VersionConfig;
+
+/// Set of actions for a VersionPrompt.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+#[allow(clippy::enum_variant_names)]
+pub enum VersionPromptAction {
+    /// Action on the value text input handler.
+    ValueInput(InputAction),
+    /// When bump suggestions are displayed, moves the cursor to the suggestion above.
+    MoveToSuggestionAbove,
+    /// When bump suggestions are displayed, moves the cursor to the suggestion below.
+    MoveToSuggestionBelow,
+    /// When bump suggestions are displayed, fills the text input with the current suggestion.
+    UseCurrentSuggestion,
+}
+
+impl InnerAction for VersionPromptAction {
+    type Config = VersionConfig;
+
+    fn from_key(key: Key, _config: &VersionConfig) -> Option<Self> {
+        let action = match key {
+            Key::Up(KeyModifiers::NONE) | Key::Char('p', KeyModifiers::CONTROL) => {
+                Self::MoveToSuggestionAbove
+            }
+            Key::Down(KeyModifiers::NONE) | Key::Char('n', KeyModifiers::CONTROL) => {
+                Self::MoveToSuggestionBelow
+            }
+            Key::Tab => Self::UseCurrentSuggestion,
+
+            key => match InputAction::from_key(key, &()) {
+                Some(action) => Self::ValueInput(action),
+                None => return None,
+            },
+        };
+
+        Some(action)
+    }
+}
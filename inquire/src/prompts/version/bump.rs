@@ -0,0 +1,62 @@
+use std::fmt::Display;
+
+/// Kinds of version bumps suggested by [`Version`](crate::Version) prompts.
+///
+/// Bumps follow the [semver](https://semver.org) rules: the bumped component
+/// is incremented and the lower ones are reset to zero, while pre-release
+/// and build metadata are dropped. A pre-release version is bumped to its
+/// release instead when the lower components are already zero, e.g. the
+/// minor bump of `2.1.0-rc.1` is `2.1.0`.
+///
+/// # Examples
+///
+/// ```
+/// use inquire::VersionBump;
+///
+/// let current = semver::Version::new(1, 4, 2);
+///
+/// assert_eq!(semver::Version::new(1, 4, 3), VersionBump::Patch.apply(&current));
+/// assert_eq!(semver::Version::new(1, 5, 0), VersionBump::Minor.apply(&current));
+/// assert_eq!(semver::Version::new(2, 0, 0), VersionBump::Major.apply(&current));
+/// ```
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum VersionBump {
+    /// Increments the patch version, e.g. `1.4.2` to `1.4.3`.
+    Patch,
+    /// Increments the minor version, e.g. `1.4.2` to `1.5.0`.
+    Minor,
+    /// Increments the major version, e.g. `1.4.2` to `2.0.0`.
+    Major,
+}
+
+impl VersionBump {
+    /// Every kind of bump, in the order they are suggested.
+    pub const ALL: [VersionBump; 3] = [Self::Patch, Self::Minor, Self::Major];
+
+    /// Returns the version that follows `version` according to this bump.
+    pub fn apply(self, version: &semver::Version) -> semver::Version {
+        let is_release = version.pre.is_empty();
+        let (major, minor, patch) = (version.major, version.minor, version.patch);
+
+        match self {
+            Self::Patch if is_release => semver::Version::new(major, minor, patch + 1),
+            Self::Patch => semver::Version::new(major, minor, patch),
+            Self::Minor if is_release || patch > 0 => semver::Version::new(major, minor + 1, 0),
+            Self::Minor => semver::Version::new(major, minor, 0),
+            Self::Major if is_release || minor > 0 || patch > 0 => {
+                semver::Version::new(major + 1, 0, 0)
+            }
+            Self::Major => semver::Version::new(major, 0, 0),
+        }
+    }
+}
+
+impl Display for VersionBump {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Patch => write!(f, "patch"),
+            Self::Minor => write!(f, "minor"),
+            Self::Major => write!(f, "major"),
+        }
+    }
+}
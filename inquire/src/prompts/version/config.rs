@@ -0,0 +1,10 @@
+use crate::Version;
+
+/// Configuration settings used in the execution of a VersionPrompt.
+pub struct VersionConfig {}
+
+impl From<&Version<'_>> for VersionConfig {
+    fn from(_value: &Version<'_>) -> Self {
+        Self {}
+    }
+}
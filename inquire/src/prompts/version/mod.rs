@@ -0,0 +1,270 @@
+mod action;
+mod bump;
+mod config;
+mod prompt;
+#[cfg(test)]
+mod test;
+
+pub use action::*;
+pub use bump::VersionBump;
+
+use crate::{
+    config::get_configuration,
+    error::{InquireError, InquireResult},
+    formatter::VersionFormatter,
+    prompts::prompt::Prompt,
+    terminal::get_default_terminal,
+    ui::{Backend, RenderConfig, TextBackend},
+    validator::CustomTypeValidator,
+};
+
+use self::prompt::VersionPrompt;
+
+/// Prompt suitable for semantic versions, such as `1.4.2` or `2.0.0-rc.1`, returning a parsed [`semver::Version`].
+///
+/// The input is parsed when the user submits it, displaying an error message if it is not a valid semantic version. A leading `v`, as in `v1.4.2`, is accepted and ignored.
+///
+/// When the current version is provided, the prompt suggests its patch, minor and major [bumps](VersionBump) below the input, filtered by what the user typed. The user can highlight a suggestion with the Up and Down arrows and submit it with enter, or fill the input with it by pressing tab. Typed versions can also be required to be greater than the current one.
+///
+/// This prompt is available by enabling the `semver` feature.
+///
+/// Version prompts provide several options of configuration:
+///
+/// - **Prompt message**: Required when creating the prompt.
+/// - **Starting input**: Initial value of the prompt's text input.
+/// - **Default value**: Default value returned when the user submits an empty response.
+/// - **Current version**: Version from which the bump suggestions are made.
+/// - **Require greater**: Whether the version must be greater than the current version, false by default.
+/// - **Placeholder**: Short hint that describes the expected value of the input.
+/// - **Help message**: Message displayed at the line below the prompt.
+/// - **Formatter**: Custom formatter in case you need to pre-process the user input before showing it as the final answer.
+///   - Prints the parsed version by default.
+/// - **Validators**: Custom validators to the parsed version, displaying an error message if it does not pass the requirements.
+///
+/// # Example
+///
+/// ```no_run
+/// use inquire::Version;
+///
+/// let current = semver::Version::new(1, 4, 2);
+///
+/// let ans = Version::new("Release version:")
+///     .with_current_version(current)
+///     .with_require_greater(true)
+///     .prompt();
+///
+/// match ans {
+///     Ok(version) => println!("Tagging v{}", version),
+///     Err(_) => println!("Could not read the version"),
+/// }
+/// ```
+#[derive(Clone)]
+pub struct Version<'a> {
+    /// Message to be presented to the user.
+    pub message: &'a str,
+
+    /// Initial value of the prompt's text input.
+    ///
+    /// If you want to set a default value for the prompt, returned when the user's submission is empty, see [`default`].
+    ///
+    /// [`default`]: Self::default
+    pub starting_input: Option<&'a str>,
+
+    /// Default value, returned when the user input is empty.
+    pub default: Option<semver::Version>,
+
+    /// Version from which the bump suggestions are made.
+    pub current_version: Option<semver::Version>,
+
+    /// Whether the version must be greater than the current version.
+    pub require_greater: bool,
+
+    /// Short hint that describes the expected value of the input.
+    pub placeholder: Option<&'a str>,
+
+    /// Help message to be presented to the user.
+    pub help_message: Option<&'a str>,
+
+    /// Function that formats the user input and presents it to the user as the final rendering of the prompt.
+    pub formatter: VersionFormatter<'a>,
+
+    /// Collection of validators to apply to the parsed version.
+    ///
+    /// Validators are executed in the order they are stored, stopping at and displaying to the user
+    /// only the first validation error that might appear.
+    ///
+    /// The possible error is displayed to the user one line above the prompt.
+    pub validators: Vec<Box<dyn CustomTypeValidator<semver::Version>>>,
+
+    /// RenderConfig to apply to the rendered interface.
+    ///
+    /// Note: The default render config considers if the NO_COLOR environment variable
+    /// is set to decide whether to render the colored config or the empty one.
+    ///
+    /// When overriding the config in a prompt, NO_COLOR is no longer considered and your
+    /// config is treated as the only source of truth. If you want to customize colors
+    /// and still support NO_COLOR, you will have to do this on your end.
+    pub render_config: RenderConfig<'a>,
+}
+
+impl<'a> Version<'a> {
+    /// Default formatter, which prints the parsed version.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use inquire::Version;
+    ///
+    /// let formatter = Version::DEFAULT_FORMATTER;
+    /// let version = semver::Version::parse("1.4.2-rc.1").unwrap();
+    /// assert_eq!(String::from("1.4.2-rc.1"), formatter(&version));
+    /// ```
+    pub const DEFAULT_FORMATTER: VersionFormatter<'a> = &|version| version.to_string();
+
+    /// Default value of whether the version must be greater than the current version.
+    pub const DEFAULT_REQUIRE_GREATER: bool = false;
+
+    /// Default validators added to the [Version] prompt, none.
+    pub const DEFAULT_VALIDATORS: Vec<Box<dyn CustomTypeValidator<semver::Version>>> = vec![];
+
+    /// Creates a [Version] with the provided message, along with default configuration values.
+    pub fn new(message: &'a str) -> Self {
+        Self {
+            message,
+            starting_input: None,
+            default: None,
+            current_version: None,
+            require_greater: Self::DEFAULT_REQUIRE_GREATER,
+            placeholder: None,
+            help_message: None,
+            formatter: Self::DEFAULT_FORMATTER,
+            validators: Self::DEFAULT_VALIDATORS,
+            render_config: get_configuration(),
+        }
+    }
+
+    /// Sets the initial value of the prompt's text input.
+    ///
+    /// If you want to set a default value for the prompt, returned when the user's submission is empty, see [`with_default`].
+    ///
+    /// [`with_default`]: Self::with_default
+    pub fn with_starting_input(mut self, message: &'a str) -> Self {
+        self.starting_input = Some(message);
+        self
+    }
+
+    /// Sets the default input.
+    pub fn with_default(mut self, default: semver::Version) -> Self {
+        self.default = Some(default);
+        self
+    }
+
+    /// Sets the current version, from which the bump suggestions are made.
+    pub fn with_current_version(mut self, current_version: semver::Version) -> Self {
+        self.current_version = Some(current_version);
+        self
+    }
+
+    /// Sets whether the version must be greater than the current version.
+    pub fn with_require_greater(mut self, require_greater: bool) -> Self {
+        self.require_greater = require_greater;
+        self
+    }
+
+    /// Sets the placeholder.
+    pub fn with_placeholder(mut self, placeholder: &'a str) -> Self {
+        self.placeholder = Some(placeholder);
+        self
+    }
+
+    /// Sets the help message of the prompt.
+    pub fn with_help_message(mut self, message: &'a str) -> Self {
+        self.help_message = Some(message);
+        self
+    }
+
+    /// Sets the formatter.
+    pub fn with_formatter(mut self, formatter: VersionFormatter<'a>) -> Self {
+        self.formatter = formatter;
+        self
+    }
+
+    /// Adds a validator to the collection of validators. You might want to use this feature
+    /// in case you need to require certain features from the parsed version.
+    ///
+    /// Validators are executed in the order they are stored, stopping at and displaying to the user
+    /// only the first validation error that might appear.
+    ///
+    /// The possible error is displayed to the user one line above the prompt.
+    pub fn with_validator<V>(mut self, validator: V) -> Self
+    where
+        V: CustomTypeValidator<semver::Version> + 'static,
+    {
+        self.validators.push(Box::new(validator));
+        self
+    }
+
+    /// Adds the validators to the collection of validators in the order they are given.
+    /// You might want to use this feature in case you need to require certain features
+    /// from the parsed version.
+    ///
+    /// Validators are executed in the order they are stored, stopping at and displaying to the user
+    /// only the first validation error that might appear.
+    ///
+    /// The possible error is displayed to the user one line above the prompt.
+    pub fn with_validators(
+        mut self,
+        validators: &[Box<dyn CustomTypeValidator<semver::Version>>],
+    ) -> Self {
+        for validator in validators {
+            #[allow(suspicious_double_ref_op)]
+            self.validators.push(validator.clone());
+        }
+        self
+    }
+
+    /// Sets the provided color theme to this prompt.
+    ///
+    /// Note: The default render config considers if the NO_COLOR environment variable
+    /// is set to decide whether to render the colored config or the empty one.
+    ///
+    /// When overriding the config in a prompt, NO_COLOR is no longer considered and your
+    /// config is treated as the only source of truth. If you want to customize colors
+    /// and still support NO_COLOR, you will have to do this on your end.
+    pub fn with_render_config(mut self, render_config: RenderConfig<'a>) -> Self {
+        self.render_config = render_config;
+        self
+    }
+
+    /// Parses the provided behavioral and rendering options and prompts
+    /// the CLI user for input according to the defined rules.
+    ///
+    /// This method is intended for flows where the user skipping/cancelling
+    /// the prompt - by pressing ESC - is considered normal behavior. In this case,
+    /// it does not return `Err(InquireError::OperationCanceled)`, but `Ok(None)`.
+    ///
+    /// Meanwhile, if the user does submit an answer, the method wraps the return
+    /// type with `Some`.
+    pub fn prompt_skippable(self) -> InquireResult<Option<semver::Version>> {
+        match self.prompt() {
+            Ok(answer) => Ok(Some(answer)),
+            Err(InquireError::OperationCanceled) => Ok(None),
+            Err(err) => Err(err),
+        }
+    }
+
+    /// Parses the provided behavioral and rendering options and prompts
+    /// the CLI user for input according to the defined rules.
+    pub fn prompt(self) -> InquireResult<semver::Version> {
+        let (input_reader, terminal) = get_default_terminal()?;
+        let mut backend = Backend::new(input_reader, terminal, self.render_config)?;
+        self.prompt_with_backend(&mut backend)
+    }
+
+    pub(crate) fn prompt_with_backend<B: TextBackend>(
+        self,
+        backend: &mut B,
+    ) -> InquireResult<semver::Version> {
+        VersionPrompt::new(self)?.prompt(backend)
+    }
+}
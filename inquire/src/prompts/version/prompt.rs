@@ -0,0 +1,294 @@
+use crate::{
+    error::InquireResult,
+    formatter::VersionFormatter,
+    input::{Input, InputActionResult},
+    list_option::ListOption,
+    prompts::prompt::{ActionResult, Prompt},
+    ui::{TextBackend, TextInputDetails},
+    utils::paginate,
+    validator::{CustomTypeValidator, ErrorMessage, Validation},
+    InquireError, Version,
+};
+
+use super::{action::VersionPromptAction, config::VersionConfig, VersionBump};
+
+const DEFAULT_HELP_MESSAGE_WITH_SUGGESTIONS: &str =
+    "↑↓ to move, tab to autocomplete, enter to submit";
+
+pub struct VersionPrompt<'a> {
+    message: &'a str,
+    config: VersionConfig,
+    error: Option<ErrorMessage>,
+    help_message: Option<&'a str>,
+    default: Option<semver::Version>,
+    current_version: Option<semver::Version>,
+    require_greater: bool,
+    input: Input,
+    suggestions: Vec<(VersionBump, semver::Version)>,
+    matched_suggestions: Vec<usize>,
+    suggestion_cursor_index: Option<usize>,
+    formatter: VersionFormatter<'a>,
+    validators: Vec<Box<dyn CustomTypeValidator<semver::Version>>>,
+}
+
+impl<'a> VersionPrompt<'a> {
+    pub fn new(vo: Version<'a>) -> InquireResult<Self> {
+        if let (Some(default), Some(current), true) =
+            (&vo.default, &vo.current_version, vo.require_greater)
+        {
+            if default <= current {
+                return Err(InquireError::InvalidConfiguration(
+                    "Default value must be greater than the current version".into(),
+                ));
+            }
+        }
+
+        let input = Input::new_with(vo.starting_input.unwrap_or_default());
+        let input = if let Some(placeholder) = vo.placeholder {
+            input.with_placeholder(placeholder)
+        } else {
+            input
+        };
+
+        let suggestions = match &vo.current_version {
+            Some(current) => VersionBump::ALL
+                .iter()
+                .map(|bump| (*bump, bump.apply(current)))
+                .collect(),
+            None => vec![],
+        };
+
+        let mut prompt = Self {
+            message: vo.message,
+            config: (&vo).into(),
+            error: None,
+            help_message: vo.help_message,
+            default: vo.default,
+            current_version: vo.current_version,
+            require_greater: vo.require_greater,
+            input,
+            suggestions,
+            matched_suggestions: vec![],
+            suggestion_cursor_index: None,
+            formatter: vo.formatter,
+            validators: vo.validators,
+        };
+        prompt.update_suggestions();
+
+        Ok(prompt)
+    }
+
+    fn parse(input: &str) -> Result<semver::Version, String> {
+        let input = input.trim();
+        let input = input.strip_prefix('v').unwrap_or(input);
+
+        semver::Version::parse(input).map_err(|err| format!("Invalid version: {}", err))
+    }
+
+    /// Keeps the bump suggestions that start with the current input.
+    fn update_suggestions(&mut self) {
+        let content = self.input.content().trim();
+        let content = content.strip_prefix('v').unwrap_or(content);
+
+        self.matched_suggestions = self
+            .suggestions
+            .iter()
+            .enumerate()
+            .filter(|(_, (_, version))| version.to_string().starts_with(content))
+            .map(|(idx, _)| idx)
+            .collect();
+        self.suggestion_cursor_index = None;
+    }
+
+    fn get_highlighted_suggestion(&self) -> Option<&semver::Version> {
+        self.suggestion_cursor_index
+            .and_then(|cursor| self.matched_suggestions.get(cursor))
+            .map(|idx| &self.suggestions[*idx].1)
+    }
+
+    fn move_cursor_up(&mut self) -> ActionResult {
+        let new_cursor_index = match self.suggestion_cursor_index {
+            None | Some(0) => None,
+            Some(index) => Some(index - 1),
+        };
+
+        self.update_suggestion_cursor_pos(new_cursor_index)
+    }
+
+    fn move_cursor_down(&mut self) -> ActionResult {
+        let last = match self.matched_suggestions.len().checked_sub(1) {
+            Some(last) => last,
+            None => return ActionResult::Clean,
+        };
+
+        let new_cursor_index = match self.suggestion_cursor_index {
+            None => Some(0),
+            Some(index) => Some((index + 1).min(last)),
+        };
+
+        self.update_suggestion_cursor_pos(new_cursor_index)
+    }
+
+    fn update_suggestion_cursor_pos(&mut self, new_position: Option<usize>) -> ActionResult {
+        if new_position != self.suggestion_cursor_index {
+            self.suggestion_cursor_index = new_position;
+            ActionResult::NeedsRedraw
+        } else {
+            ActionResult::Clean
+        }
+    }
+
+    /// Fills the input with the highlighted suggestion or, if there is
+    /// none, the first one matching the input.
+    fn use_current_suggestion(&mut self) -> ActionResult {
+        let suggestion = self.get_highlighted_suggestion().or_else(|| {
+            self.matched_suggestions
+                .first()
+                .map(|idx| &self.suggestions[*idx].1)
+        });
+
+        match suggestion.map(|version| version.to_string()) {
+            Some(value) if value != self.input.content() => {
+                self.input = Input::new_with(value);
+                self.update_suggestions();
+                ActionResult::NeedsRedraw
+            }
+            _ => ActionResult::Clean,
+        }
+    }
+
+    fn validate_current_answer(&self, version: &semver::Version) -> InquireResult<Validation> {
+        if let (Some(current), true) = (&self.current_version, self.require_greater) {
+            if version <= current {
+                return Ok(Validation::Invalid(
+                    format!("Version must be greater than {}", current).into(),
+                ));
+            }
+        }
+
+        for validator in &self.validators {
+            match validator.validate(version) {
+                Ok(Validation::Valid) => {}
+                Ok(Validation::Invalid(msg)) => return Ok(Validation::Invalid(msg)),
+                Err(err) => return Err(InquireError::Custom(err)),
+            }
+        }
+
+        Ok(Validation::Valid)
+    }
+
+    fn get_final_answer(&self) -> Result<semver::Version, String> {
+        // If there is a highlighted suggestion, assume user wanted it as
+        // the answer.
+        if let Some(suggestion) = self.get_highlighted_suggestion() {
+            return Ok(suggestion.clone());
+        }
+
+        match &self.default {
+            Some(val) if self.input.content().is_empty() => return Ok(val.clone()),
+            _ => {}
+        }
+
+        Self::parse(self.input.content())
+    }
+}
+
+impl<'a, Backend> Prompt<Backend> for VersionPrompt<'a>
+where
+    Backend: TextBackend,
+{
+    type Config = VersionConfig;
+    type InnerAction = VersionPromptAction;
+    type Output = semver::Version;
+
+    fn message(&self) -> &str {
+        self.message
+    }
+
+    fn config(&self) -> &VersionConfig {
+        &self.config
+    }
+
+    fn format_answer(&self, answer: &semver::Version) -> String {
+        (self.formatter)(answer)
+    }
+
+    fn submit(&mut self) -> InquireResult<Option<semver::Version>> {
+        let answer = match self.get_final_answer() {
+            Ok(answer) => match self.validate_current_answer(&answer)? {
+                Validation::Valid => Some(answer),
+                Validation::Invalid(msg) => {
+                    self.error = Some(msg);
+                    None
+                }
+            },
+            Err(message) => {
+                self.error = Some(message.into());
+                None
+            }
+        };
+
+        Ok(answer)
+    }
+
+    fn handle(&mut self, action: VersionPromptAction) -> InquireResult<ActionResult> {
+        let result = match action {
+            VersionPromptAction::ValueInput(input_action) => {
+                let result = self.input.handle(input_action);
+
+                if let InputActionResult::ContentChanged = result {
+                    self.update_suggestions();
+                }
+
+                result.into()
+            }
+            VersionPromptAction::MoveToSuggestionAbove => self.move_cursor_up(),
+            VersionPromptAction::MoveToSuggestionBelow => self.move_cursor_down(),
+            VersionPromptAction::UseCurrentSuggestion => self.use_current_suggestion(),
+        };
+
+        Ok(result)
+    }
+
+    fn render(&self, backend: &mut Backend) -> InquireResult<()> {
+        let prompt = &self.message;
+
+        if let Some(error) = &self.error {
+            backend.render_error_message(error)?;
+        }
+
+        let default_message = self.default.as_ref().map(self.formatter);
+
+        backend.render_prompt(
+            prompt,
+            default_message.as_deref(),
+            &self.input,
+            &TextInputDetails::default(),
+        )?;
+
+        let choices = self
+            .matched_suggestions
+            .iter()
+            .map(|idx| {
+                let (bump, version) = &self.suggestions[*idx];
+                ListOption::new(*idx, format!("{} ({})", version, bump))
+            })
+            .collect::<Vec<ListOption<String>>>();
+
+        let page = paginate(
+            VersionBump::ALL.len(),
+            &choices,
+            self.suggestion_cursor_index,
+        );
+
+        backend.render_suggestions(page)?;
+
+        if let Some(message) = self.help_message {
+            backend.render_help_message(message)?;
+        } else if !choices.is_empty() {
+            backend.render_help_message(DEFAULT_HELP_MESSAGE_WITH_SUGGESTIONS)?;
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,126 @@
+use crate::{
+    error::InquireResult,
+    ui::{
+        test::{FakeBackend, Token},
+        Key, KeyModifiers,
+    },
+    validator::ErrorMessage,
+    Version, VersionBump,
+};
+
+fn v(input: &str) -> semver::Version {
+    semver::Version::parse(input).unwrap()
+}
+
+#[test]
+fn typed_version_is_parsed() -> InquireResult<()> {
+    let mut input = Key::char_keys_from_str("v2.0.0-rc.1");
+    input.push(Key::Enter);
+    let mut backend = FakeBackend::new(input);
+
+    let ans = Version::new("Version").prompt_with_backend(&mut backend)?;
+
+    assert_eq!(v("2.0.0-rc.1"), ans);
+
+    Ok(())
+}
+
+#[test]
+fn invalid_version_is_rejected() -> InquireResult<()> {
+    let mut input = Key::char_keys_from_str("1.2");
+    input.push(Key::Enter);
+    input.extend(Key::char_keys_from_str(".3"));
+    input.push(Key::Enter);
+    let mut backend = FakeBackend::new(input);
+
+    let ans = Version::new("Version").prompt_with_backend(&mut backend)?;
+
+    assert_eq!(v("1.2.3"), ans);
+    assert!(backend.frames()[4].tokens().iter().any(|t| matches!(
+        t,
+        Token::ErrorMessage(ErrorMessage::Custom(msg)) if msg.starts_with("Invalid version: ")
+    )));
+
+    Ok(())
+}
+
+#[test]
+fn bumps_are_suggested_from_current_version() -> InquireResult<()> {
+    let mut backend = FakeBackend::new(vec![
+        Key::Down(KeyModifiers::NONE),
+        Key::Down(KeyModifiers::NONE),
+        Key::Enter,
+    ]);
+
+    let ans = Version::new("Version")
+        .with_current_version(v("1.4.2"))
+        .prompt_with_backend(&mut backend)?;
+
+    assert_eq!(v("1.5.0"), ans);
+    assert!(backend.frames()[0].has_token(&Token::Suggestions {
+        options: vec![
+            "1.4.3 (patch)".into(),
+            "1.5.0 (minor)".into(),
+            "2.0.0 (major)".into()
+        ],
+        cursor: None,
+    }));
+
+    Ok(())
+}
+
+#[test]
+fn suggestions_are_filtered_and_autocompleted() -> InquireResult<()> {
+    let mut input = Key::char_keys_from_str("1.");
+    input.push(Key::Tab);
+    input.push(Key::Enter);
+    let mut backend = FakeBackend::new(input);
+
+    let ans = Version::new("Version")
+        .with_current_version(v("1.4.2"))
+        .prompt_with_backend(&mut backend)?;
+
+    assert_eq!(v("1.4.3"), ans);
+    assert!(backend.frames()[2].has_token(&Token::Suggestions {
+        options: vec!["1.4.3 (patch)".into(), "1.5.0 (minor)".into()],
+        cursor: None,
+    }));
+
+    Ok(())
+}
+
+#[test]
+fn lower_version_is_rejected_when_greater_is_required() -> InquireResult<()> {
+    let mut input = Key::char_keys_from_str("1.4.2");
+    input.push(Key::Enter);
+    input.push(Key::Backspace);
+    input.extend(Key::char_keys_from_str("5"));
+    input.push(Key::Enter);
+    let mut backend = FakeBackend::new(input);
+
+    let ans = Version::new("Version")
+        .with_current_version(v("1.4.2"))
+        .with_require_greater(true)
+        .prompt_with_backend(&mut backend)?;
+
+    assert_eq!(v("1.4.5"), ans);
+    assert!(
+        backend.frames()[6].has_token(&Token::ErrorMessage(ErrorMessage::Custom(
+            "Version must be greater than 1.4.2".into()
+        )))
+    );
+
+    Ok(())
+}
+
+#[test]
+fn pre_releases_are_bumped_to_their_release() {
+    assert_eq!(v("2.1.0"), VersionBump::Patch.apply(&v("2.1.0-rc.1")));
+    assert_eq!(v("2.1.0"), VersionBump::Minor.apply(&v("2.1.0-rc.1")));
+    assert_eq!(v("3.0.0"), VersionBump::Major.apply(&v("2.1.0-rc.1")));
+    assert_eq!(
+        v("3.0.0"),
+        VersionBump::Major.apply(&v("3.0.0-alpha+build.5"))
+    );
+    assert_eq!(v("1.3.0"), VersionBump::Minor.apply(&v("1.2.1-beta")));
+}
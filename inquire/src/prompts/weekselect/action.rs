@@ -0,0 +1,67 @@
+use crate::{
+    ui::{Key, KeyModifiers},
+    InnerAction,
+};
+
+use super::config::WeekSelectConfig;
+
+/// Set of actions for a WeekSelectPrompt.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+#[allow(clippy::enum_variant_names)]
+pub enum WeekSelectPromptAction {
+    /// Move week cursor to the previous week.
+    GoToPrevWeek,
+    /// Move week cursor to the next week.
+    GoToNextWeek,
+    /// Move week cursor to the week of the previous month.
+    GoToPrevMonth,
+    /// Move week cursor to the week of the next month.
+    GoToNextMonth,
+    /// Move week cursor to the same week number of the previous year.
+    GoToPrevYear,
+    /// Move week cursor to the same week number of the next year.
+    GoToNextYear,
+}
+
+impl InnerAction for WeekSelectPromptAction {
+    type Config = WeekSelectConfig;
+
+    fn from_key(key: Key, _: &WeekSelectConfig) -> Option<Self> {
+        let action = match key {
+            Key::Up(KeyModifiers::NONE) | Key::Left(KeyModifiers::NONE) // standard
+            | Key::Char('p' | 'b', KeyModifiers::CONTROL) // emacs
+            | Key::Char('k' | 'h', KeyModifiers::NONE) // vim
+            => Self::GoToPrevWeek,
+
+            Key::Down(KeyModifiers::NONE) | Key::Right(KeyModifiers::NONE) // standard
+            | Key::Char('n' | 'f', KeyModifiers::CONTROL) // emacs
+            | Key::Char('j' | 'l', KeyModifiers::NONE) // vim
+            | Key::Tab
+            => Self::GoToNextWeek,
+
+            Key::PageUp(KeyModifiers::NONE) // standard
+            | Key::Char('[', KeyModifiers::NONE) // alternative when page up is not available
+            | Key::Left(_) // alternative 2, when the left above with no modifiers is not matched
+            => Self::GoToPrevMonth,
+
+            Key::PageDown(KeyModifiers::NONE) // standard
+            | Key::Char(']', KeyModifiers::NONE) // alternative when page down is not available
+            | Key::Right(_) // alternative 2, when the right above with no modifiers is not matched
+            => Self::GoToNextMonth,
+
+            Key::PageUp(_) // standard, when the above with no modifiers is not matched
+            | Key::Char('{' | '[', _) // alternative when page up is not available
+            | Key::Up(_) // alternative 2, when the up above with no modifiers is not matched
+            => Self::GoToPrevYear,
+
+            Key::PageDown(_) // standard, when the above with no modifiers is not matched
+            | Key::Char('}' | ']', _) // alternative when page down is not available
+            | Key::Down(_) // alternative 2, when the down above with no modifiers is not matched
+            => Self::GoToNextYear,
+
+            _ => return None,
+        };
+
+        Some(action)
+    }
+}
@@ -0,0 +1,28 @@
+use chrono::{Datelike, NaiveDate, Weekday};
+
+use crate::WeekSelect;
+
+/// Configuration settings used in the execution of a WeekSelectPrompt.
+#[derive(Copy, Clone, Debug)]
+pub struct WeekSelectConfig {
+    /// Monday of the min week allowed to be selected.
+    pub min_week: Option<NaiveDate>,
+
+    /// Monday of the max week allowed to be selected.
+    pub max_week: Option<NaiveDate>,
+}
+
+impl From<&WeekSelect<'_>> for WeekSelectConfig {
+    fn from(value: &WeekSelect<'_>) -> Self {
+        Self {
+            min_week: value.min_date.map(monday_of_week),
+            max_week: value.max_date.map(monday_of_week),
+        }
+    }
+}
+
+/// Returns the Monday of the ISO week of the given date.
+pub(crate) fn monday_of_week(date: NaiveDate) -> NaiveDate {
+    let week = date.iso_week();
+    NaiveDate::from_isoywd_opt(week.year(), week.week(), Weekday::Mon).unwrap_or(date)
+}
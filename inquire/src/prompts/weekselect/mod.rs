@@ -0,0 +1,289 @@
+mod action;
+mod config;
+mod prompt;
+#[cfg(test)]
+#[cfg(feature = "crossterm")]
+mod test;
+
+pub use action::*;
+
+use chrono::{IsoWeek, NaiveDate, Weekday};
+
+use crate::{
+    config::get_configuration,
+    date_utils::get_current_date,
+    error::{InquireError, InquireResult},
+    formatter::DateFormatter,
+    prompts::prompt::Prompt,
+    terminal::get_default_terminal,
+    ui::{date::WeekSelectBackend, Backend, RenderConfig},
+    validator::DateValidator,
+};
+
+use self::prompt::WeekSelectPrompt;
+
+/// Prompt that allows user to select an ISO week, a year and a week number, from an interactive calendar. Available via the `date` feature.
+///
+/// The calendar is displayed with weeks starting on Monday and their ISO week numbers on the first column, highlighting every day of the selected week. By default, the initial selected week is the current one. The user can navigate through the calendar by pressing the keyboard arrows.
+///
+/// More specifically:
+/// - Up and left arrows move to the previous week, while down and right arrows move to the next week.
+/// - Page up and page down, or the left and right arrows when pressed with `ctrl`, move to a week of the previous or next month.
+/// - The up and down arrows, when pressed with `ctrl`, move to the same week number of the previous or next year.
+///
+/// Finally, the user selects a week by pressing the space or enter keys.
+///
+/// The answer is returned as a `chrono::IsoWeek`, or as the Monday of the selected week when using [WeekSelect::prompt_monday].
+///
+/// `WeekSelect` prompts provide several options of configuration:
+///
+/// - **Prompt message**: Required when creating the prompt.
+/// - **Default value**: Week selected when the calendar is displayed, and the one returned if the user submits without any previous actions. Current week by default.
+/// - **Help message**: Message displayed at the line below the prompt.
+/// - **Formatter**: Custom formatter in case you need to pre-process the user input before showing it as the final answer. It receives the Monday of the selected week.
+///   - Formats to the ISO 8601 week notation, e.g. "2021-W30", by default.
+/// - **Validators**: Custom validators to the Monday of the user's selected week, displaying an error message if it does not pass the requirements.
+/// - **Min and max date**: Inclusive boundaries of allowed weeks, any day of the week can be used. If any boundary is set, the user will not be able to move past them.
+///
+/// # Example
+///
+/// ```no_run
+/// use chrono::NaiveDate;
+/// use inquire::WeekSelect;
+///
+/// let week = WeekSelect::new("Which sprint should be reported?")
+///     .with_min_date(NaiveDate::from_ymd_opt(2024, 1, 1).unwrap())
+///     .with_help_message("Sprints start on Mondays")
+///     .prompt();
+///
+/// match week {
+///     Ok(week) => println!("Generating report of week {} of {}", week.week(), week.year()),
+///     Err(_) => println!("There was an error in the system."),
+/// }
+/// ```
+#[derive(Clone)]
+pub struct WeekSelect<'a> {
+    /// Message to be presented to the user.
+    pub message: &'a str,
+
+    /// Starting week to be selected, any day of the week can be used.
+    pub starting_date: NaiveDate,
+
+    /// Min week allowed to be selected, any day of the week can be used.
+    pub min_date: Option<NaiveDate>,
+
+    /// Max week allowed to be selected, any day of the week can be used.
+    pub max_date: Option<NaiveDate>,
+
+    /// Help message to be presented to the user.
+    pub help_message: Option<&'a str>,
+
+    /// Function that formats the user input and presents it to the user as the final rendering of the prompt.
+    pub formatter: DateFormatter<'a>,
+
+    /// Collection of validators to apply to the user input.
+    ///
+    /// Validators are executed in the order they are stored, stopping at and displaying to the user
+    /// only the first validation error that might appear.
+    ///
+    /// The possible error is displayed to the user one line above the prompt.
+    pub validators: Vec<Box<dyn DateValidator>>,
+
+    /// RenderConfig to apply to the rendered interface.
+    ///
+    /// Note: The default render config considers if the NO_COLOR environment variable
+    /// is set to decide whether to render the colored config or the empty one.
+    ///
+    /// When overriding the config in a prompt, NO_COLOR is no longer considered and your
+    /// config is treated as the only source of truth. If you want to customize colors
+    /// and still support NO_COLOR, you will have to do this on your end.
+    pub render_config: RenderConfig<'a>,
+}
+
+impl<'a> WeekSelect<'a> {
+    /// Default formatter, printing the week in the ISO 8601 week notation.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chrono::NaiveDate;
+    /// use inquire::WeekSelect;
+    ///
+    /// let formatter = WeekSelect::DEFAULT_FORMATTER;
+    /// assert_eq!(
+    ///     String::from("2021-W30"),
+    ///     formatter(NaiveDate::from_ymd_opt(2021, 7, 26).unwrap()),
+    /// );
+    /// ```
+    pub const DEFAULT_FORMATTER: DateFormatter<'a> = &|val| val.format("%G-W%V").to_string();
+
+    /// Default help message.
+    pub const DEFAULT_HELP_MESSAGE: Option<&'a str> =
+        Some("arrows to move, []{} move months and years, enter to select");
+
+    /// Default validators added to the [WeekSelect] prompt, none.
+    pub const DEFAULT_VALIDATORS: Vec<Box<dyn DateValidator>> = vec![];
+
+    /// Default min date.
+    pub const DEFAULT_MIN_DATE: Option<NaiveDate> = None;
+
+    /// Default max date.
+    pub const DEFAULT_MAX_DATE: Option<NaiveDate> = None;
+
+    /// Creates a [WeekSelect] with the provided message, along with default configuration values.
+    pub fn new(message: &'a str) -> Self {
+        Self {
+            message,
+            starting_date: get_current_date(),
+            min_date: Self::DEFAULT_MIN_DATE,
+            max_date: Self::DEFAULT_MAX_DATE,
+            help_message: Self::DEFAULT_HELP_MESSAGE,
+            formatter: Self::DEFAULT_FORMATTER,
+            validators: Self::DEFAULT_VALIDATORS,
+            render_config: get_configuration(),
+        }
+    }
+
+    /// Sets the help message of the prompt.
+    pub fn with_help_message(mut self, message: &'a str) -> Self {
+        self.help_message = Some(message);
+        self
+    }
+
+    /// Removes the set help message.
+    pub fn without_help_message(mut self) -> Self {
+        self.help_message = None;
+        self
+    }
+
+    /// Sets the default week of the prompt. Equivalent to [WeekSelect::with_starting_date](WeekSelect::with_starting_date).
+    pub fn with_default(self, default: NaiveDate) -> Self {
+        self.with_starting_date(default)
+    }
+
+    /// Sets the default week of the prompt from an ISO week.
+    pub fn with_default_week(self, week: IsoWeek) -> Self {
+        match NaiveDate::from_isoywd_opt(week.year(), week.week(), Weekday::Mon) {
+            Some(monday) => self.with_starting_date(monday),
+            None => self,
+        }
+    }
+
+    /// Sets the min date, of which only the week is considered.
+    pub fn with_min_date(mut self, min_date: NaiveDate) -> Self {
+        self.min_date = Some(min_date);
+        self
+    }
+
+    /// Sets the max date, of which only the week is considered.
+    pub fn with_max_date(mut self, max_date: NaiveDate) -> Self {
+        self.max_date = Some(max_date);
+        self
+    }
+
+    /// Sets the starting week, of which any day can be used.
+    /// Equivalent to [WeekSelect::with_default](WeekSelect::with_default).
+    pub fn with_starting_date(mut self, starting_date: NaiveDate) -> Self {
+        self.starting_date = starting_date;
+        self
+    }
+
+    /// Adds a validator to the collection of validators. You might want to use this feature
+    /// in case you need to limit the user to specific choices, such as not allowing weeks
+    /// that were already reported.
+    ///
+    /// Validators receive the Monday of the selected week.
+    ///
+    /// Validators are executed in the order they are stored, stopping at and displaying to the user
+    /// only the first validation error that might appear.
+    ///
+    /// The possible error is displayed to the user one line above the prompt.
+    pub fn with_validator<V>(mut self, validator: V) -> Self
+    where
+        V: DateValidator + 'static,
+    {
+        self.validators.push(Box::new(validator));
+        self
+    }
+
+    /// Adds the validators to the collection of validators in the order they are given.
+    /// You might want to use this feature in case you need to limit the user to specific
+    /// choices, such as not allowing weeks that were already reported.
+    ///
+    /// Validators receive the Monday of the selected week.
+    ///
+    /// Validators are executed in the order they are stored, stopping at and displaying to the user
+    /// only the first validation error that might appear.
+    ///
+    /// The possible error is displayed to the user one line above the prompt.
+    pub fn with_validators(mut self, validators: &[Box<dyn DateValidator>]) -> Self {
+        for validator in validators {
+            self.validators.push(validator.clone());
+        }
+        self
+    }
+
+    /// Sets the formatter.
+    pub fn with_formatter(mut self, formatter: DateFormatter<'a>) -> Self {
+        self.formatter = formatter;
+        self
+    }
+
+    /// Sets the provided color theme to this prompt.
+    ///
+    /// Note: The default render config considers if the NO_COLOR environment variable
+    /// is set to decide whether to render the colored config or the empty one.
+    ///
+    /// When overriding the config in a prompt, NO_COLOR is no longer considered and your
+    /// config is treated as the only source of truth. If you want to customize colors
+    /// and still support NO_COLOR, you will have to do this on your end.
+    pub fn with_render_config(mut self, render_config: RenderConfig<'a>) -> Self {
+        self.render_config = render_config;
+        self
+    }
+
+    /// Parses the provided behavioral and rendering options and prompts
+    /// the CLI user for input according to the defined rules.
+    ///
+    /// This method is intended for flows where the user skipping/cancelling
+    /// the prompt - by pressing ESC - is considered normal behavior. In this case,
+    /// it does not return `Err(InquireError::OperationCanceled)`, but `Ok(None)`.
+    ///
+    /// Meanwhile, if the user does submit an answer, the method wraps the return
+    /// type with `Some`.
+    pub fn prompt_skippable(self) -> InquireResult<Option<IsoWeek>> {
+        match self.prompt() {
+            Ok(answer) => Ok(Some(answer)),
+            Err(InquireError::OperationCanceled) => Ok(None),
+            Err(err) => Err(err),
+        }
+    }
+
+    /// Parses the provided behavioral and rendering options and prompts
+    /// the CLI user for input according to the defined rules.
+    ///
+    /// Returns the ISO week selected by the user.
+    pub fn prompt(self) -> InquireResult<IsoWeek> {
+        let (input_reader, terminal) = get_default_terminal()?;
+        let mut backend = Backend::new(input_reader, terminal, self.render_config)?;
+        self.prompt_with_backend(&mut backend)
+    }
+
+    /// Parses the provided behavioral and rendering options and prompts
+    /// the CLI user for input according to the defined rules.
+    ///
+    /// Returns the Monday of the week selected by the user.
+    pub fn prompt_monday(self) -> InquireResult<NaiveDate> {
+        self.prompt().map(|week| {
+            NaiveDate::from_isoywd_opt(week.year(), week.week(), Weekday::Mon)
+                .expect("selected week should always have a Monday")
+        })
+    }
+
+    pub(crate) fn prompt_with_backend<B: WeekSelectBackend>(
+        self,
+        backend: &mut B,
+    ) -> InquireResult<IsoWeek> {
+        WeekSelectPrompt::new(self)?.prompt(backend)
+    }
+}
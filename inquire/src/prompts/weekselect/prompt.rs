@@ -0,0 +1,198 @@
+use std::cmp::{max, min};
+
+use chrono::{Datelike, Days, IsoWeek, NaiveDate, Weekday};
+
+use crate::{
+    date_utils::{get_current_date, get_month},
+    error::InquireResult,
+    formatter::DateFormatter,
+    prompts::{
+        dateselect::shift_date,
+        prompt::{ActionResult, Prompt},
+    },
+    ui::date::WeekSelectBackend,
+    validator::{DateValidator, ErrorMessage, Validation},
+    DateSelectPromptAction, InquireError, WeekSelect,
+};
+
+use super::{
+    action::WeekSelectPromptAction,
+    config::{monday_of_week, WeekSelectConfig},
+};
+
+pub struct WeekSelectPrompt<'a> {
+    message: &'a str,
+    config: WeekSelectConfig,
+    // Monday of the selected week
+    current_week: NaiveDate,
+    help_message: Option<&'a str>,
+    formatter: DateFormatter<'a>,
+    validators: Vec<Box<dyn DateValidator>>,
+    error: Option<ErrorMessage>,
+}
+
+impl<'a> WeekSelectPrompt<'a> {
+    pub fn new(ws: WeekSelect<'a>) -> InquireResult<Self> {
+        let config: WeekSelectConfig = (&ws).into();
+        let starting_week = monday_of_week(ws.starting_date);
+
+        if let Some(min_week) = config.min_week {
+            if min_week > starting_week {
+                return Err(InquireError::InvalidConfiguration(
+                    "Min date can not be greater than starting date".into(),
+                ));
+            }
+        }
+        if let Some(max_week) = config.max_week {
+            if max_week < starting_week {
+                return Err(InquireError::InvalidConfiguration(
+                    "Max date can not be smaller than starting date".into(),
+                ));
+            }
+        }
+
+        Ok(Self {
+            message: ws.message,
+            current_week: starting_week,
+            config,
+            help_message: ws.help_message,
+            formatter: ws.formatter,
+            validators: ws.validators,
+            error: None,
+        })
+    }
+
+    fn update_week(&mut self, new_week: NaiveDate) -> ActionResult {
+        let mut new_week = new_week;
+        if let Some(min_week) = self.config.min_week {
+            new_week = max(new_week, min_week);
+        }
+        if let Some(max_week) = self.config.max_week {
+            new_week = min(new_week, max_week);
+        }
+
+        if self.current_week == new_week {
+            return ActionResult::Clean;
+        }
+
+        self.current_week = new_week;
+        ActionResult::NeedsRedraw
+    }
+
+    /// Returns the Monday of the week with the same number in the year shifted
+    /// by the given quantity, or of the last week of that year when it has less weeks.
+    fn shift_years(&self, qty: i32) -> NaiveDate {
+        let week = self.current_week.iso_week();
+        let year = week.year().saturating_add(qty);
+
+        NaiveDate::from_isoywd_opt(year, week.week(), Weekday::Mon)
+            .or_else(|| NaiveDate::from_isoywd_opt(year, week.week() - 1, Weekday::Mon))
+            .unwrap_or(self.current_week)
+    }
+
+    fn validate_current_answer(&self) -> InquireResult<Validation> {
+        for validator in &self.validators {
+            match validator.validate(self.current_week) {
+                Ok(Validation::Valid) => {}
+                Ok(Validation::Invalid(msg)) => return Ok(Validation::Invalid(msg)),
+                Err(err) => return Err(InquireError::Custom(err)),
+            }
+        }
+
+        Ok(Validation::Valid)
+    }
+
+    fn cur_answer(&self) -> IsoWeek {
+        self.current_week.iso_week()
+    }
+}
+
+impl<'a, B> Prompt<B> for WeekSelectPrompt<'a>
+where
+    B: WeekSelectBackend,
+{
+    type Config = WeekSelectConfig;
+    type InnerAction = WeekSelectPromptAction;
+    type Output = IsoWeek;
+
+    fn message(&self) -> &str {
+        self.message
+    }
+
+    fn format_answer(&self, answer: &IsoWeek) -> String {
+        let monday = NaiveDate::from_isoywd_opt(answer.year(), answer.week(), Weekday::Mon)
+            .unwrap_or(self.current_week);
+
+        (self.formatter)(monday)
+    }
+
+    fn config(&self) -> &WeekSelectConfig {
+        &self.config
+    }
+
+    fn submit(&mut self) -> InquireResult<Option<IsoWeek>> {
+        let answer = match self.validate_current_answer()? {
+            Validation::Valid => Some(self.cur_answer()),
+            Validation::Invalid(msg) => {
+                self.error = Some(msg);
+                None
+            }
+        };
+
+        Ok(answer)
+    }
+
+    fn handle(&mut self, action: WeekSelectPromptAction) -> InquireResult<ActionResult> {
+        let new_week = match action {
+            WeekSelectPromptAction::GoToPrevWeek => {
+                shift_date(self.current_week, DateSelectPromptAction::GoToPrevWeek)
+            }
+            WeekSelectPromptAction::GoToNextWeek => {
+                shift_date(self.current_week, DateSelectPromptAction::GoToNextWeek)
+            }
+            WeekSelectPromptAction::GoToPrevMonth => monday_of_week(shift_date(
+                self.current_week,
+                DateSelectPromptAction::GoToPrevMonth,
+            )),
+            WeekSelectPromptAction::GoToNextMonth => monday_of_week(shift_date(
+                self.current_week,
+                DateSelectPromptAction::GoToNextMonth,
+            )),
+            WeekSelectPromptAction::GoToPrevYear => self.shift_years(-1),
+            WeekSelectPromptAction::GoToNextYear => self.shift_years(1),
+        };
+
+        Ok(self.update_week(new_week))
+    }
+
+    fn render(&self, backend: &mut B) -> InquireResult<()> {
+        let prompt = &self.message;
+
+        if let Some(err) = &self.error {
+            backend.render_error_message(err)?;
+        }
+
+        backend.render_week_prompt(prompt)?;
+
+        // the displayed month is the one of the Thursday, which defines the
+        // ISO year of the week as well
+        let shift_days =
+            |monday: NaiveDate, qty: u64| monday.checked_add_days(Days::new(qty)).unwrap_or(monday);
+        let thursday = shift_days(self.current_week, 3);
+
+        backend.render_week_calendar(
+            get_month(thursday.month()),
+            thursday.year(),
+            get_current_date(),
+            self.cur_answer(),
+            self.config.min_week,
+            self.config.max_week.map(|max_week| shift_days(max_week, 6)),
+        )?;
+
+        if let Some(help_message) = self.help_message {
+            backend.render_help_message(help_message)?;
+        }
+
+        Ok(())
+    }
+}
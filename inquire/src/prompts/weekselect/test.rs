@@ -0,0 +1,151 @@
+use crate::{
+    date_utils::get_current_date,
+    error::InquireResult,
+    ui::{
+        test::{FakeBackend, Token},
+        Key, KeyModifiers,
+    },
+    InquireError, WeekSelect,
+};
+use chrono::{Datelike, Month, NaiveDate};
+
+fn date(year: i32, month: u32, day: u32) -> NaiveDate {
+    NaiveDate::from_ymd_opt(year, month, day).unwrap()
+}
+
+fn default<'a>() -> WeekSelect<'a> {
+    // friday of week 46
+    WeekSelect::new("Question?").with_default(date(2023, 11, 17))
+}
+
+macro_rules! week_test {
+    ($name:ident,$input:expr,$output:expr) => {
+        week_test! {$name, $input, $output, default()}
+    };
+
+    ($name:ident,$input:expr,$output:expr,$prompt:expr) => {
+        #[test]
+        fn $name() -> InquireResult<()> {
+            let mut backend = FakeBackend::new($input);
+
+            let ans = $prompt.prompt_with_backend(&mut backend)?;
+
+            assert_eq!($output, (ans.year(), ans.week()));
+
+            Ok(())
+        }
+    };
+}
+
+week_test!(default_week, vec![Key::Enter], (2023, 46));
+
+week_test!(
+    current_week_by_default,
+    vec![Key::Enter],
+    (
+        get_current_date().iso_week().year(),
+        get_current_date().iso_week().week()
+    ),
+    WeekSelect::new("Question?")
+);
+
+week_test!(
+    arrows_move_by_weeks,
+    vec![
+        Key::Down(KeyModifiers::NONE),
+        Key::Right(KeyModifiers::NONE),
+        Key::Char('k', KeyModifiers::NONE),
+        Key::Down(KeyModifiers::NONE),
+        Key::Enter
+    ],
+    (2023, 48)
+);
+
+week_test!(
+    next_month_moves_to_the_week_a_month_later,
+    vec![Key::PageDown(KeyModifiers::NONE), Key::Enter],
+    (2023, 50)
+);
+
+week_test!(
+    next_year_keeps_the_week_number,
+    vec![Key::Down(KeyModifiers::CONTROL), Key::Enter],
+    (2024, 46)
+);
+
+week_test!(
+    next_year_without_53rd_week_moves_to_the_last_one,
+    vec![Key::Down(KeyModifiers::CONTROL), Key::Enter],
+    (2021, 52),
+    WeekSelect::new("Question?").with_default(date(2020, 12, 31))
+);
+
+week_test!(
+    movement_is_clamped_to_max_week,
+    vec![
+        Key::Down(KeyModifiers::NONE),
+        Key::Down(KeyModifiers::NONE),
+        Key::Enter
+    ],
+    (2023, 47),
+    default().with_max_date(date(2023, 11, 22))
+);
+
+week_test!(
+    movement_is_clamped_to_min_week,
+    vec![Key::PageUp(KeyModifiers::NONE), Key::Enter],
+    (2023, 45),
+    default().with_min_date(date(2023, 11, 12))
+);
+
+#[test]
+fn calendar_highlights_the_selected_week() -> InquireResult<()> {
+    let mut backend = FakeBackend::new(vec![Key::Enter]);
+
+    default()
+        .with_max_date(date(2023, 11, 22))
+        .prompt_with_backend(&mut backend)?;
+
+    assert!(backend.frames()[0].has_token(&Token::WeekCalendar {
+        month: Month::November,
+        year: 2023,
+        today: get_current_date(),
+        selected_week: date(2023, 11, 13).iso_week(),
+        min_date: None,
+        max_date: Some(date(2023, 11, 26)),
+    }));
+
+    Ok(())
+}
+
+#[test]
+fn calendar_displays_the_month_of_the_thursday() -> InquireResult<()> {
+    let mut backend = FakeBackend::new(vec![Key::Enter]);
+
+    let ans = WeekSelect::new("Question?")
+        .with_default(date(2024, 12, 30))
+        .prompt_with_backend(&mut backend)?;
+
+    assert_eq!((2025, 1), (ans.year(), ans.week()));
+    assert!(backend.frames()[0].has_token(&Token::WeekCalendar {
+        month: Month::January,
+        year: 2025,
+        today: get_current_date(),
+        selected_week: ans,
+        min_date: None,
+        max_date: None,
+    }));
+
+    Ok(())
+}
+
+#[test]
+fn starting_week_after_max_week_is_invalid() {
+    let mut backend = FakeBackend::new(vec![Key::Enter]);
+
+    let ans = default()
+        .with_max_date(date(2023, 11, 12))
+        .prompt_with_backend(&mut backend);
+
+    assert!(matches!(ans, Err(InquireError::InvalidConfiguration(_))));
+}
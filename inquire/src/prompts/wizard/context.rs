@@ -0,0 +1,96 @@
+use std::any::Any;
+
+/// Context of the step being run by a [`Wizard`](crate::Wizard).
+///
+/// It gives the step access to its position in the flow, to the answers of
+/// the previous steps and, when the user went back to it, to the answer it
+/// had before, which can be used to pre-fill the prompt.
+pub struct WizardContext<'c> {
+    pub(super) index: usize,
+    pub(super) keys: &'c [&'c str],
+    pub(super) answers: &'c [Option<Box<dyn Any>>],
+}
+
+impl<'c> WizardContext<'c> {
+    /// Position of the current step in the flow, starting at 1.
+    pub fn step(&self) -> usize {
+        self.index + 1
+    }
+
+    /// Total number of steps in the flow.
+    pub fn total(&self) -> usize {
+        self.keys.len()
+    }
+
+    /// Returns the message prefixed with the progress of the flow,
+    /// e.g. `[3/7] Name:`.
+    pub fn message(&self, message: &str) -> String {
+        format!("[{}/{}] {}", self.step(), self.total(), message)
+    }
+
+    /// Answer previously given to the current step, if the user went back to it.
+    ///
+    /// Returns `None` if the step is being run for the first time
+    /// or if `T` is not the type of its answer.
+    pub fn previous<T: Any>(&self) -> Option<&T> {
+        self.answers
+            .get(self.index)
+            .and_then(|answer| answer.as_ref())
+            .and_then(|answer| answer.downcast_ref())
+    }
+
+    /// Answer given to one of the previous steps, identified by its key.
+    ///
+    /// Returns `None` if there is no previous step with this key
+    /// or if `T` is not the type of its answer.
+    pub fn get<T: Any>(&self, key: &str) -> Option<&T> {
+        self.keys[..self.index]
+            .iter()
+            .zip(self.answers)
+            .find(|(k, _)| **k == key)
+            .and_then(|(_, answer)| answer.as_ref())
+            .and_then(|answer| answer.downcast_ref())
+    }
+}
+
+/// Answers collected by a [`Wizard`](crate::Wizard), identified by the keys of their steps.
+pub struct WizardAnswers<'a> {
+    pub(super) answers: Vec<(&'a str, Box<dyn Any>)>,
+}
+
+impl<'a> WizardAnswers<'a> {
+    /// Returns a reference to the answer of the step with the given key.
+    ///
+    /// Returns `None` if there is no step with this key
+    /// or if `T` is not the type of its answer.
+    pub fn get<T: Any>(&self, key: &str) -> Option<&T> {
+        self.answers
+            .iter()
+            .find(|(k, _)| *k == key)
+            .and_then(|(_, answer)| answer.downcast_ref())
+    }
+
+    /// Removes and returns the answer of the step with the given key.
+    ///
+    /// Returns `None` if there is no step with this key
+    /// or if `T` is not the type of its answer.
+    pub fn remove<T: Any>(&mut self, key: &str) -> Option<T> {
+        let position = self
+            .answers
+            .iter()
+            .position(|(k, answer)| *k == key && answer.is::<T>())?;
+
+        let (_, answer) = self.answers.remove(position);
+        answer.downcast().ok().map(|answer| *answer)
+    }
+
+    /// Number of answers collected.
+    pub fn len(&self) -> usize {
+        self.answers.len()
+    }
+
+    /// Returns `true` if no answers were collected, i.e. the wizard had no steps.
+    pub fn is_empty(&self) -> bool {
+        self.answers.is_empty()
+    }
+}
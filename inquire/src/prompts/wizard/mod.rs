@@ -0,0 +1,134 @@
+mod context;
+#[cfg(test)]
+mod test;
+
+pub use context::*;
+
+use std::any::Any;
+
+use crate::error::{InquireError, InquireResult};
+
+type StepFn<'a> = Box<dyn FnMut(&WizardContext<'_>) -> InquireResult<Box<dyn Any>> + 'a>;
+
+struct WizardStep<'a> {
+    key: &'a str,
+    run: StepFn<'a>,
+}
+
+/// Runner of a sequence of prompts, where the user can go back and edit a previous answer without restarting the flow.
+///
+/// Each step is a function that runs a prompt and returns its answer, identified by a key. The function receives a [`WizardContext`], giving access to:
+///
+/// - The progress of the flow, through [`WizardContext::message`], which prefixes the prompt message with e.g. `[3/7]`;
+/// - The answers of the previous steps, through [`WizardContext::get`], allowing steps to adapt to them;
+/// - The answer the step had before, through [`WizardContext::previous`], when the user went back to it. Use it to pre-fill the prompt, e.g. as the initial value of a [`Text`](crate::Text) prompt or the starting cursor of a [`Select`](crate::Select).
+///
+/// When the user cancels a prompt by pressing ESC, the wizard goes back to the previous step instead of being canceled. Only canceling the first step cancels the whole flow, returning an [`InquireError::OperationCanceled`] error. Any other error returned by a step, such as the user interrupting the prompt with Ctrl+C, stops the flow and is returned as is.
+///
+/// Once the last step is answered, the wizard returns the [`WizardAnswers`], from which each answer can be retrieved by its key and type.
+///
+/// # Example
+///
+/// ```no_run
+/// use inquire::{Select, Text, Wizard};
+///
+/// let languages = vec!["Rust", "Go", "Python"];
+///
+/// let answers = Wizard::new()
+///     .with_step("name", |ctx| {
+///         let previous = ctx.previous::<String>().cloned().unwrap_or_default();
+///
+///         Text::new(&ctx.message("Project name:"))
+///             .with_initial_value(&previous)
+///             .with_help_message("esc to go back")
+///             .prompt()
+///     })
+///     .with_step("language", |ctx| {
+///         let cursor = ctx.previous::<usize>().copied().unwrap_or_default();
+///
+///         Select::new(&ctx.message("Language:"), languages.clone())
+///             .with_starting_cursor(cursor)
+///             .with_help_message("esc to go back")
+///             .raw_prompt()
+///             .map(|option| option.index)
+///     })
+///     .run();
+///
+/// match answers {
+///     Ok(answers) => {
+///         let name = answers.get::<String>("name").unwrap();
+///         let language = languages[*answers.get::<usize>("language").unwrap()];
+///         println!("Creating {} project {}", language, name);
+///     }
+///     Err(_) => println!("The project was not created"),
+/// }
+/// ```
+///
+/// [`InquireError::OperationCanceled`]: crate::error::InquireError::OperationCanceled
+#[derive(Default)]
+pub struct Wizard<'a> {
+    steps: Vec<WizardStep<'a>>,
+}
+
+impl<'a> Wizard<'a> {
+    /// Creates a [Wizard] without any steps.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds a step to the end of the flow, identified by the provided key.
+    ///
+    /// The step function is called every time the step is reached, including
+    /// when the user goes back to it, and should run a prompt returning its answer.
+    pub fn with_step<T, F>(mut self, key: &'a str, mut step: F) -> Self
+    where
+        T: Any,
+        F: FnMut(&WizardContext<'_>) -> InquireResult<T> + 'a,
+    {
+        self.steps.push(WizardStep {
+            key,
+            run: Box::new(move |ctx| {
+                let answer: Box<dyn Any> = Box::new(step(ctx)?);
+                Ok(answer)
+            }),
+        });
+        self
+    }
+
+    /// Runs the steps in order, going back to the previous step when the
+    /// user cancels a prompt, and returns the answers of all of them.
+    pub fn run(mut self) -> InquireResult<WizardAnswers<'a>> {
+        let keys = self
+            .steps
+            .iter()
+            .map(|step| step.key)
+            .collect::<Vec<&str>>();
+        let mut answers: Vec<Option<Box<dyn Any>>> = keys.iter().map(|_| None).collect();
+        let mut index = 0;
+
+        while let Some(step) = self.steps.get_mut(index) {
+            let ctx = WizardContext {
+                index,
+                keys: &keys,
+                answers: &answers,
+            };
+
+            match (step.run)(&ctx) {
+                Ok(answer) => {
+                    answers[index] = Some(answer);
+                    index += 1;
+                }
+                Err(InquireError::OperationCanceled) if index > 0 => index -= 1,
+                Err(err) => return Err(err),
+            }
+        }
+
+        let answers = keys
+            .into_iter()
+            .zip(answers)
+            .filter_map(|(key, answer)| answer.map(|answer| (key, answer)))
+            .collect();
+
+        Ok(WizardAnswers { answers })
+    }
+}
@@ -0,0 +1,163 @@
+use std::{cell::RefCell, collections::VecDeque};
+
+use crate::{
+    error::InquireResult,
+    ui::{
+        test::{FakeBackend, Token},
+        Key, KeyModifiers,
+    },
+    Confirm, InquireError, Text, Wizard,
+};
+
+/// Input of each prompt of a test flow, consumed in order.
+struct Inputs(RefCell<VecDeque<FakeBackend>>);
+
+impl Inputs {
+    fn new(steps: &[&[Key]]) -> Self {
+        let backends = steps
+            .iter()
+            .map(|keys| FakeBackend::new(keys.to_vec()))
+            .collect();
+        Self(RefCell::new(backends))
+    }
+
+    fn next(&self) -> FakeBackend {
+        self.0.borrow_mut().pop_front().expect("no input left")
+    }
+}
+
+fn text(value: &str) -> Vec<Key> {
+    let mut keys = Key::char_keys_from_str(value);
+    keys.push(Key::Enter);
+    keys
+}
+
+#[test]
+fn steps_are_run_in_order() -> InquireResult<()> {
+    let inputs = Inputs::new(&[&text("Ferris"), &text("y")]);
+    let messages = RefCell::new(vec![]);
+
+    let mut answers = Wizard::new()
+        .with_step("name", |ctx| {
+            let mut backend = inputs.next();
+            let ans = Text::new(&ctx.message("Name")).prompt_with_backend(&mut backend);
+            messages
+                .borrow_mut()
+                .push(backend.frames()[0].tokens()[0].clone());
+            ans
+        })
+        .with_step("subscribe", |ctx| {
+            Confirm::new(&ctx.message("Subscribe?")).prompt_with_backend(&mut inputs.next())
+        })
+        .run()?;
+
+    assert_eq!(2, answers.len());
+    assert_eq!(Some(&true), answers.get::<bool>("subscribe"));
+    assert_eq!(
+        Some(String::from("Ferris")),
+        answers.remove::<String>("name")
+    );
+    assert_eq!(
+        vec![Token::Prompt("[1/2] Name".into())],
+        messages.into_inner()
+    );
+
+    Ok(())
+}
+
+#[test]
+fn escape_goes_back_to_previous_step() -> InquireResult<()> {
+    let inputs = Inputs::new(&[&text("Ferr"), &[Key::Escape], &text("is"), &text("y")]);
+
+    let answers = Wizard::new()
+        .with_step("name", |ctx| {
+            let previous = ctx.previous::<String>().cloned().unwrap_or_default();
+
+            Text::new("Name")
+                .with_initial_value(&previous)
+                .prompt_with_backend(&mut inputs.next())
+        })
+        .with_step("subscribe", |_ctx| {
+            Confirm::new("Subscribe?").prompt_with_backend(&mut inputs.next())
+        })
+        .run()?;
+
+    assert_eq!(Some(&String::from("Ferris")), answers.get::<String>("name"));
+    assert_eq!(Some(&true), answers.get::<bool>("subscribe"));
+
+    Ok(())
+}
+
+#[test]
+fn escape_on_first_step_cancels_the_flow() {
+    let inputs = Inputs::new(&[&[Key::Escape]]);
+
+    let answers = Wizard::new()
+        .with_step("name", |_ctx| {
+            Text::new("Name").prompt_with_backend(&mut inputs.next())
+        })
+        .run();
+
+    assert!(matches!(answers, Err(InquireError::OperationCanceled)));
+}
+
+#[test]
+fn interruptions_stop_the_flow() {
+    let inputs = Inputs::new(&[&text("Ferris"), &[Key::Char('c', KeyModifiers::CONTROL)]]);
+
+    let answers = Wizard::new()
+        .with_step("name", |_ctx| {
+            Text::new("Name").prompt_with_backend(&mut inputs.next())
+        })
+        .with_step("subscribe", |_ctx| {
+            Confirm::new("Subscribe?").prompt_with_backend(&mut inputs.next())
+        })
+        .run();
+
+    assert!(matches!(answers, Err(InquireError::OperationInterrupted)));
+}
+
+#[test]
+fn steps_can_read_previous_answers() -> InquireResult<()> {
+    let inputs = Inputs::new(&[&text("Ferris"), &text("")]);
+
+    let answers = Wizard::new()
+        .with_step("name", |_ctx| {
+            Text::new("Name").prompt_with_backend(&mut inputs.next())
+        })
+        .with_step("email", |ctx| {
+            let default = format!("{}@example.com", ctx.get::<String>("name").unwrap());
+            // answers of the current and next steps are not visible
+            assert!(ctx.get::<String>("email").is_none());
+
+            Text::new("Email")
+                .with_default(&default.to_lowercase())
+                .prompt_with_backend(&mut inputs.next())
+        })
+        .run()?;
+
+    assert_eq!(
+        Some(&String::from("ferris@example.com")),
+        answers.get::<String>("email")
+    );
+
+    Ok(())
+}
+
+#[test]
+fn answers_are_retrieved_by_type() -> InquireResult<()> {
+    let inputs = Inputs::new(&[&text("y")]);
+
+    let mut answers = Wizard::new()
+        .with_step("subscribe", |_ctx| {
+            Confirm::new("Subscribe?").prompt_with_backend(&mut inputs.next())
+        })
+        .run()?;
+
+    assert_eq!(None, answers.get::<String>("subscribe"));
+    assert_eq!(None, answers.remove::<String>("subscribe"));
+    assert_eq!(Some(true), answers.remove::<bool>("subscribe"));
+    assert!(answers.is_empty());
+
+    Ok(())
+}
@@ -4,7 +4,7 @@ use console::{Attribute, Color, Key, Style, Term};
 
 use crate::{
     error::InquireResult,
-    ui::{Attributes, InputReader, StyleSheet, Styled},
+    ui::{Attributes, InputEvent, InputReader, StyleSheet, Styled},
 };
 
 use super::Terminal;
@@ -23,9 +23,12 @@ impl ConsoleTerminal {
     }
 }
 
+// console only switches the terminal to raw mode while reading a key, so
+// its input can not be polled with a timeout: the keys typed in between
+// are not available before a line break
 impl InputReader for ConsoleTerminal {
-    fn read_key(&mut self) -> InquireResult<crate::ui::Key> {
-        let key = self.term.read_key()?;
+    fn read_event(&mut self) -> InquireResult<InputEvent> {
+        let key: crate::ui::Key = self.term.read_key()?.into();
         Ok(key.into())
     }
 }
@@ -101,6 +104,14 @@ impl Terminal for ConsoleTerminal {
     fn cursor_show(&mut self) -> Result<()> {
         self.term.show_cursor()
     }
+
+    fn enter_alternate_screen(&mut self) -> Result<()> {
+        write!(self.term, "\x1b[?1049h\x1b[H")
+    }
+
+    fn leave_alternate_screen(&mut self) -> Result<()> {
+        write!(self.term, "\x1b[?1049l")
+    }
 }
 
 impl Drop for ConsoleTerminal {
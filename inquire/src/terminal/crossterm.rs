@@ -1,4 +1,7 @@
-use std::io::{stderr, Result, Stderr, Write};
+use std::{
+    io::{stderr, Result, Stderr, Write},
+    time::{Duration, Instant},
+};
 
 use crossterm::{
     cursor,
@@ -11,7 +14,7 @@ use crossterm::{
 
 use crate::{
     error::InquireResult,
-    ui::{Attributes, InputReader, Key, Styled},
+    ui::{Attributes, InputEvent, InputReader, Key, Styled},
 };
 
 use super::Terminal;
@@ -26,28 +29,109 @@ pub struct CrosstermTerminal {
     io: IO,
 }
 
-pub struct CrosstermKeyReader;
+/// Maximum interval between two clicks at the same position for them to be
+/// reported as a double click.
+#[cfg(feature = "mouse")]
+const DOUBLE_CLICK_INTERVAL: Duration = Duration::from_millis(500);
+
+pub struct CrosstermKeyReader {
+    // time and position of the last click, to detect double clicks
+    #[cfg(feature = "mouse")]
+    last_click: Option<(Instant, u16, u16)>,
+}
 
 impl CrosstermKeyReader {
     pub fn new() -> Self {
-        Self
+        Self {
+            #[cfg(feature = "mouse")]
+            last_click: None,
+        }
+    }
+
+    /// Converts the event into one reported to the prompts, if any.
+    #[cfg_attr(not(feature = "mouse"), allow(clippy::unused_self))]
+    fn convert_event(&mut self, event: event::Event) -> Option<InputEvent> {
+        match event {
+            event::Event::Key(key_event) => Some(Key::from(key_event).into()),
+            event::Event::Paste(text) => Some(InputEvent::Paste(text)),
+            event::Event::Resize(..) => Some(InputEvent::Resize),
+            #[cfg(feature = "mouse")]
+            event::Event::Mouse(mouse_event) => {
+                self.convert_mouse_event(mouse_event).map(Into::into)
+            }
+            _ => None,
+        }
+    }
+
+    #[cfg(feature = "mouse")]
+    fn convert_mouse_event(&mut self, event: event::MouseEvent) -> Option<Key> {
+        use crate::ui::{MouseEvent, MouseEventKind};
+
+        let kind = match event.kind {
+            event::MouseEventKind::Down(event::MouseButton::Left) => {
+                let now = Instant::now();
+                let is_double_click = matches!(
+                    self.last_click,
+                    Some((time, row, column))
+                        if now.duration_since(time) <= DOUBLE_CLICK_INTERVAL
+                            && (row, column) == (event.row, event.column)
+                );
+
+                if is_double_click {
+                    self.last_click = None;
+                    MouseEventKind::DoubleClick
+                } else {
+                    self.last_click = Some((now, event.row, event.column));
+                    MouseEventKind::Click
+                }
+            }
+            event::MouseEventKind::ScrollUp => MouseEventKind::ScrollUp,
+            event::MouseEventKind::ScrollDown => MouseEventKind::ScrollDown,
+            _ => return None,
+        };
+
+        Some(Key::Mouse(MouseEvent::new(kind, event.row, event.column)))
     }
 }
 
 impl InputReader for CrosstermKeyReader {
-    fn read_key(&mut self) -> InquireResult<Key> {
+    fn read_event(&mut self) -> InquireResult<InputEvent> {
+        loop {
+            if let Some(event) = self.convert_event(event::read()?) {
+                return Ok(event);
+            }
+        }
+    }
+
+    fn poll_event(&mut self, timeout: Duration) -> InquireResult<Option<InputEvent>> {
+        let deadline = Instant::now() + timeout;
+
         loop {
-            if let event::Event::Key(key_event) = event::read()? {
-                return Ok(key_event.into());
+            let remaining = deadline.saturating_duration_since(Instant::now());
+
+            if !event::poll(remaining)? {
+                return Ok(None);
+            }
+
+            if let Some(event) = self.convert_event(event::read()?) {
+                return Ok(Some(event));
             }
         }
     }
+
+    fn supports_timeout(&self) -> bool {
+        true
+    }
 }
 
 impl CrosstermTerminal {
     pub fn new() -> InquireResult<Self> {
         terminal::enable_raw_mode()?;
 
+        // terminals that do not support bracketed paste keep reporting the
+        // pasted text as a key press per character
+        let _unused = crossterm::execute!(stderr(), event::EnableBracketedPaste);
+
         Ok(Self {
             io: IO::Std(stderr()),
         })
@@ -133,6 +217,28 @@ impl Terminal for CrosstermTerminal {
         self.get_writer().flush()
     }
 
+    fn cursor_row(&mut self) -> Result<Option<u16>> {
+        match self.io {
+            // terminals that do not answer the query are treated like the
+            // ones that can not report the cursor position
+            IO::Std(_) => Ok(cursor::position().ok().map(|(_column, row)| row)),
+            IO::Test(_) => Ok(None),
+        }
+    }
+
+    // the capture is left to the prompts reading mouse events, as it
+    // disables the text selection of the terminal
+    #[cfg(feature = "mouse")]
+    fn set_mouse_capture(&mut self, enabled: bool) -> Result<()> {
+        if enabled {
+            self.write_command(event::EnableMouseCapture)?;
+        } else {
+            self.write_command(event::DisableMouseCapture)?;
+        }
+
+        self.flush()
+    }
+
     fn get_size(&self) -> Result<super::TerminalSize> {
         terminal::size().map(|(width, height)| super::TerminalSize::new(width, height))
     }
@@ -182,13 +288,28 @@ impl Terminal for CrosstermTerminal {
     fn cursor_show(&mut self) -> Result<()> {
         self.write_command(cursor::Show)
     }
+
+    fn enter_alternate_screen(&mut self) -> Result<()> {
+        self.write_command(terminal::EnterAlternateScreen)?;
+        self.write_command(cursor::MoveTo(0, 0))
+    }
+
+    fn leave_alternate_screen(&mut self) -> Result<()> {
+        self.write_command(terminal::LeaveAlternateScreen)
+    }
 }
 
 impl Drop for CrosstermTerminal {
     fn drop(&mut self) {
         let _unused = self.flush();
         let _unused = match self.io {
-            IO::Std(_) => terminal::disable_raw_mode(),
+            IO::Std(ref mut io) => {
+                #[cfg(feature = "mouse")]
+                let _unused = crossterm::execute!(io, event::DisableMouseCapture);
+                let _unused = crossterm::execute!(io, event::DisableBracketedPaste);
+
+                terminal::disable_raw_mode()
+            }
             IO::Test(_) => Ok(()),
         };
     }
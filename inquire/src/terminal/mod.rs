@@ -40,6 +40,19 @@ pub trait Terminal: Sized {
     #[allow(unused)]
     fn cursor_move_to_column(&mut self, idx: u16) -> Result<()>;
 
+    /// Row of the terminal the cursor is at, if the terminal can report it.
+    fn cursor_row(&mut self) -> Result<Option<u16>> {
+        Ok(None)
+    }
+
+    /// Starts or stops reporting mouse events, if the terminal can report them.
+    fn set_mouse_capture(&mut self, _enabled: bool) -> Result<()> {
+        Ok(())
+    }
+
+    fn enter_alternate_screen(&mut self) -> Result<()>;
+    fn leave_alternate_screen(&mut self) -> Result<()>;
+
     fn flush(&mut self) -> Result<()>;
 }
 
@@ -1,21 +1,29 @@
 use core::fmt;
 use std::{
+    collections::VecDeque,
+    convert::TryFrom,
     fs::File,
-    io::{Result, Write},
+    io::{self, Read, Result, Write},
+    time::{Duration, Instant},
+};
+
+use rustix::{
+    event::{poll, PollFd, PollFlags, Timespec},
+    io::Errno,
 };
 
 use termion::{
     color::{self, Color},
     cursor,
-    event::Key,
-    input::{Keys, TermRead},
+    event::{parse_event, Event, Key},
+    input::TermRead,
     raw::{IntoRawMode, RawTerminal},
     terminal_size,
 };
 
 use crate::{
     error::InquireResult,
-    ui::{Attributes, InputReader, Styled},
+    ui::{Attributes, InputEvent, InputReader, Styled},
 };
 
 use super::Terminal;
@@ -28,27 +36,116 @@ enum IO<'a> {
 }
 
 pub struct TermionKeyReader {
-    keys: Keys<File>,
+    tty: File,
+    // bytes read from the terminal that are not parsed into events yet
+    pending: VecDeque<u8>,
 }
 
 impl TermionKeyReader {
     #[allow(unused)]
     pub fn new() -> InquireResult<Self> {
         Ok(Self {
-            keys: termion::get_tty()?.keys(),
+            tty: termion::get_tty()?,
+            pending: VecDeque::new(),
         })
     }
+
+    /// Waits at most `timeout`, or indefinitely without one, for input on
+    /// the terminal, returning whether there is any.
+    fn wait_for_input(&self, timeout: Option<Duration>) -> Result<bool> {
+        let timeout = timeout.and_then(|timeout| Timespec::try_from(timeout).ok());
+        let mut fds = [PollFd::new(&self.tty, PollFlags::IN)];
+
+        match poll(&mut fds, timeout.as_ref()) {
+            Ok(ready) => Ok(ready > 0),
+            // e.g. on SIGWINCH, when the terminal is resized
+            Err(Errno::INTR) => Ok(false),
+            Err(err) => Err(err.into()),
+        }
+    }
+
+    /// Reads the bytes available on the terminal, blocking until there is
+    /// at least one.
+    fn read_input(&mut self) -> Result<()> {
+        let mut buffer = [0; 1024];
+
+        match self.tty.read(&mut buffer)? {
+            0 => Err(io::Error::new(
+                io::ErrorKind::UnexpectedEof,
+                "The terminal was closed",
+            )),
+            read => {
+                self.pending.extend(&buffer[..read]);
+                Ok(())
+            }
+        }
+    }
+
+    /// Parses the next event out of the bytes read so far, if any.
+    fn parse_pending(&mut self) -> Result<Option<Event>> {
+        let first = match self.pending.pop_front() {
+            Some(first) => first,
+            None => return Ok(None),
+        };
+
+        // the escape sequences are read at once, unlike a lone escape key
+        if first == b'\x1B' && self.pending.is_empty() {
+            return Ok(Some(Event::Key(Key::Esc)));
+        }
+
+        let (pending, tty) = (&mut self.pending, &mut self.tty);
+        // the bytes of a character split between reads are waited for
+        let mut rest = std::iter::from_fn(|| {
+            if let Some(byte) = pending.pop_front() {
+                return Some(Ok(byte));
+            }
+
+            let mut byte = [0];
+            Some(tty.read_exact(&mut byte).map(|()| byte[0]))
+        });
+
+        parse_event(first, &mut rest).map(Some)
+    }
+
+    /// Next key pressed, if any before the deadline, skipping the other
+    /// events reported by the terminal.
+    fn next_key(&mut self, deadline: Option<Instant>) -> InquireResult<Option<InputEvent>> {
+        loop {
+            while let Some(event) = self.parse_pending()? {
+                if let Event::Key(key) = event {
+                    let key: crate::ui::Key = key.into();
+                    return Ok(Some(key.into()));
+                }
+            }
+
+            let timeout =
+                deadline.map(|deadline| deadline.saturating_duration_since(Instant::now()));
+
+            if !self.wait_for_input(timeout)? && timeout.is_some() {
+                return Ok(None);
+            }
+
+            self.read_input()?;
+        }
+    }
 }
 
 impl InputReader for TermionKeyReader {
-    fn read_key(&mut self) -> InquireResult<crate::ui::Key> {
+    fn read_event(&mut self) -> InquireResult<InputEvent> {
         loop {
-            if let Some(key) = self.keys.next() {
-                let key = key?;
-                return Ok(key.into());
+            if let Some(event) = self.next_key(None)? {
+                return Ok(event);
             }
         }
     }
+
+    fn poll_event(&mut self, timeout: Duration) -> InquireResult<Option<InputEvent>> {
+        self.next_key(Some(Instant::now() + timeout))
+    }
+
+    fn supports_timeout(&self) -> bool {
+        true
+    }
 }
 
 pub struct TermionTerminal<'a> {
@@ -202,6 +299,19 @@ impl<'a> Terminal for TermionTerminal<'a> {
     fn cursor_show(&mut self) -> Result<()> {
         write!(self.get_writer(), "{}", cursor::Show)
     }
+
+    fn enter_alternate_screen(&mut self) -> Result<()> {
+        write!(
+            self.get_writer(),
+            "{}{}",
+            termion::screen::ToAlternateScreen,
+            cursor::Goto(1, 1)
+        )
+    }
+
+    fn leave_alternate_screen(&mut self) -> Result<()> {
+        write!(self.get_writer(), "{}", termion::screen::ToMainScreen)
+    }
 }
 
 impl<'a> Drop for TermionTerminal<'a> {
@@ -1,5 +1,7 @@
 //! General type aliases.
 
+use std::cmp::Ordering;
+
 use crate::error::CustomUserError;
 
 /// Type alias to represent the function used to Score and filter options.
@@ -11,7 +13,11 @@ use crate::error::CustomUserError;
 /// - Index of the current option in the original list
 ///
 /// The return type should be a score determining the order options should be displayed to the user.
-/// The greater the score, the higher on the list it will be displayed.
+/// The greater the score, the higher on the list it will be displayed. Options with the same score
+/// keep the order of the original list, and options scored as `None` are not displayed at all.
+///
+/// This allows plugging in matchers such as `skim` or `nucleo`, by returning the score they assign
+/// to the string value of each option.
 ///
 /// # Examples
 ///
@@ -82,3 +88,86 @@ pub type Suggester<'a> = &'a dyn Fn(&str) -> Result<Vec<String>, CustomUserError
 /// The function receives the current input and should return the suggestion (if any)
 /// that will replace the current input.
 pub type Completer<'a> = &'a dyn Fn(&str) -> Result<Option<String>, CustomUserError>;
+
+/// Type alias to represent the function used to compute a hint from the current input,
+/// such as the placeholder or the help message of a [Text](crate::Text) prompt. Returning
+/// `None` displays the static hint instead, if any.
+///
+/// # Examples
+///
+/// ```
+/// use inquire::type_aliases::Hinter;
+///
+/// let slug: Hinter = &|title| match title.is_empty() {
+///     true => None,
+///     false => Some(format!("slug: {}", title.to_lowercase().replace(' ', "-"))),
+/// };
+///
+/// assert_eq!(Some(String::from("slug: hello-world")), slug("Hello World"));
+/// assert_eq!(None, slug(""));
+/// ```
+pub type Hinter<'a> = &'a dyn Fn(&str) -> Option<String>;
+
+/// Type alias to represent the function called with the current input whenever it
+/// changes, so that applications can react to it live, e.g. updating a preview or
+/// prefetching data.
+///
+/// # Examples
+///
+/// ```
+/// use std::cell::RefCell;
+/// use inquire::type_aliases::OnChange;
+///
+/// let latest = RefCell::new(String::new());
+/// let on_change: OnChange = &|input| *latest.borrow_mut() = input.to_uppercase();
+///
+/// on_change("hello");
+/// assert_eq!("HELLO", latest.borrow().as_str());
+/// ```
+pub type OnChange<'a> = &'a dyn Fn(&str);
+
+/// Type alias to represent the function applied to the text inserted into an input,
+/// either a typed character or a pasted text, so that the input is normalized while
+/// the user types, e.g. forcing lowercase or mapping spaces to dashes.
+///
+/// # Examples
+///
+/// ```
+/// use inquire::type_aliases::InputTransformer;
+///
+/// let slug: InputTransformer = &|text| text.to_lowercase().replace(' ', "-");
+///
+/// assert_eq!("-", slug(" "));
+/// assert_eq!("hello-world", slug("Hello World"));
+/// ```
+pub type InputTransformer<'a> = &'a dyn Fn(&str) -> String;
+
+/// Type alias to represent the function used to generate the preview of an option.
+/// The function receives the option currently highlighted and should return the
+/// content, possibly spanning multiple lines, to be displayed in the preview pane.
+///
+/// # Examples
+///
+/// ```
+/// use inquire::type_aliases::Previewer;
+///
+/// let previewer: Previewer<&str> = &|path| format!("path: {path}\nlength: {}", path.len());
+///
+/// assert_eq!("path: Cargo.toml\nlength: 10", previewer(&"Cargo.toml"));
+/// ```
+pub type Previewer<'a, T> = &'a dyn Fn(&T) -> String;
+
+/// Type alias to represent the function used to compare two options when
+/// listing them in a custom sort order, such as alphabetically or by date.
+///
+/// # Examples
+///
+/// ```
+/// use std::cmp::Ordering;
+/// use inquire::type_aliases::Comparator;
+///
+/// let by_length: Comparator<&str> = &|a, b| a.len().cmp(&b.len());
+///
+/// assert_eq!(Ordering::Less, by_length(&"Pear", &"Banana"));
+/// ```
+pub type Comparator<'a, T> = &'a dyn Fn(&T, &T) -> Ordering;
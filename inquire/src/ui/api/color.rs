@@ -165,4 +165,42 @@ impl Color {
     pub fn rgb(r: u8, g: u8, b: u8) -> Color {
         Color::Rgb { r, g, b }
     }
+
+    /// Parses a color from its hexadecimal RGB notation, such as `#ff8800`.
+    ///
+    /// The leading `#` is optional and the three-digit shorthand notation,
+    /// such as `#f80`, is also accepted. Returns `None` when the value is not
+    /// a valid hexadecimal color.
+    ///
+    /// ```
+    /// # use inquire::ui::Color;
+    ///
+    /// assert_eq!(Some(Color::rgb(255, 136, 0)), Color::from_hex("#ff8800"));
+    /// assert_eq!(Some(Color::rgb(255, 136, 0)), Color::from_hex("f80"));
+    /// assert_eq!(None, Color::from_hex("#ff88"));
+    /// assert_eq!(None, Color::from_hex("orange"));
+    /// ```
+    pub fn from_hex(hex: &str) -> Option<Color> {
+        let hex = hex.strip_prefix('#').unwrap_or(hex);
+
+        if !hex.chars().all(|c| c.is_ascii_hexdigit()) {
+            return None;
+        }
+
+        let component = |digits: &str| u8::from_str_radix(digits, 16).ok();
+
+        match hex.len() {
+            3 => Some(Color::rgb(
+                component(&hex[0..1])? * 17,
+                component(&hex[1..2])? * 17,
+                component(&hex[2..3])? * 17,
+            )),
+            6 => Some(Color::rgb(
+                component(&hex[0..2])?,
+                component(&hex[2..4])?,
+                component(&hex[4..6])?,
+            )),
+            _ => None,
+        }
+    }
 }
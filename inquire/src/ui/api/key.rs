@@ -3,43 +3,187 @@ use bitflags::bitflags;
 // Using the same struct, but without importing, to cut prompts' direct dependencies to crossterm
 // https://github.com/crossterm-rs/crossterm/blob/e1260446e94e9a8f7809fef61dc1369b6f8d6e12/src/event.rs#L376-L385
 bitflags! {
+    /// Modifier keys held while a [`Key`] was pressed.
     #[derive(PartialEq, Eq, PartialOrd, Ord, Hash, Debug, Clone, Copy)]
     pub struct KeyModifiers: u8 {
+        /// Shift key.
         const SHIFT = 0b0000_0001;
+        /// Control key.
         const CONTROL = 0b0000_0010;
+        /// Alt key, also known as Option on macOS.
         const ALT = 0b0000_0100;
+        /// Super key, such as the Windows or Command keys.
         const SUPER = 0b0000_1000;
+        /// Hyper key.
         const HYPER = 0b0001_0000;
+        /// Meta key.
         const META = 0b0010_0000;
+        /// No modifiers.
         const NONE = 0b0000_0000;
     }
 }
 
+/// Kind of a [`MouseEvent`].
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum MouseEventKind {
+    /// Press of the left button.
+    Click,
+    /// Second press of the left button at the same position, shortly after
+    /// the first one.
+    DoubleClick,
+    /// Scroll wheel moved up.
+    ScrollUp,
+    /// Scroll wheel moved down.
+    ScrollDown,
+}
+
+/// Mouse event read from the terminal.
+///
+/// The position of the event is resolved against the last rendered frame,
+/// so that prompts can tell which of their options is under the pointer.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct MouseEvent {
+    /// Kind of the event.
+    pub kind: MouseEventKind,
+    /// Row of the terminal where the event happened, starting at 0.
+    pub row: u16,
+    /// Column of the terminal where the event happened, starting at 0.
+    pub column: u16,
+    /// Position in the current page of the option under the pointer, if any.
+    pub option: Option<usize>,
+    /// Whether the pointer is over the checkbox of the option.
+    pub on_checkbox: bool,
+}
+
+impl MouseEvent {
+    /// Creates an event at the given position of the terminal, not
+    /// resolved to any option yet.
+    pub fn new(kind: MouseEventKind, row: u16, column: u16) -> Self {
+        Self {
+            kind,
+            row,
+            column,
+            option: None,
+            on_checkbox: false,
+        }
+    }
+}
+
+/// Key event read from the terminal, such as `Ctrl+K`.
+///
+/// Not every terminal back-end reports every modifier: `crossterm` reports
+/// all of them, while `termion` and `console` only report a subset, mostly
+/// for character keys.
+///
+/// The [`Display`](std::fmt::Display) implementation prints the key combination
+/// in a human-readable form, with the modifiers before the key name.
+///
+/// ```
+/// use inquire::ui::{Key, KeyModifiers};
+///
+/// assert_eq!("Ctrl+K", Key::Char('k', KeyModifiers::CONTROL).to_string());
+/// assert_eq!("Ctrl+Shift+K", Key::Char('K', KeyModifiers::CONTROL | KeyModifiers::SHIFT).to_string());
+/// assert_eq!("Alt+Left", Key::Left(KeyModifiers::ALT).to_string());
+/// assert_eq!("Space", Key::Char(' ', KeyModifiers::NONE).to_string());
+/// ```
 #[derive(Debug, Copy, Clone, PartialEq, Eq)]
 pub enum Key {
+    /// Escape key.
     Escape,
+    /// Enter key.
     Enter,
+    /// Backspace key.
     Backspace,
+    /// Tab key.
     Tab,
+    /// Delete key.
     Delete(KeyModifiers),
+    /// Home key.
     Home,
+    /// End key.
     End,
+    /// Page up key.
     PageUp(KeyModifiers),
+    /// Page down key.
     PageDown(KeyModifiers),
+    /// Up arrow key.
     Up(KeyModifiers),
+    /// Down arrow key.
     Down(KeyModifiers),
+    /// Left arrow key.
     Left(KeyModifiers),
+    /// Right arrow key.
     Right(KeyModifiers),
+    /// Character key, such as a letter, a digit or the space bar.
     Char(char, KeyModifiers),
+    /// Mouse event, only reported by the `crossterm` back-end when the
+    /// `mouse` feature is enabled.
+    Mouse(MouseEvent),
+    /// Any key not mapped to one of the other variants.
     #[deprecated(note = "If the key you want isn't mapped, please open a PR.")]
     Any,
 }
 
+impl std::fmt::Display for Key {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        #[allow(deprecated)]
+        let (name, modifiers) = match *self {
+            Key::Escape => (String::from("Esc"), KeyModifiers::NONE),
+            Key::Enter => (String::from("Enter"), KeyModifiers::NONE),
+            Key::Backspace => (String::from("Backspace"), KeyModifiers::NONE),
+            Key::Tab => (String::from("Tab"), KeyModifiers::NONE),
+            Key::Delete(m) => (String::from("Delete"), m),
+            Key::Home => (String::from("Home"), KeyModifiers::NONE),
+            Key::End => (String::from("End"), KeyModifiers::NONE),
+            Key::PageUp(m) => (String::from("PageUp"), m),
+            Key::PageDown(m) => (String::from("PageDown"), m),
+            Key::Up(m) => (String::from("Up"), m),
+            Key::Down(m) => (String::from("Down"), m),
+            Key::Left(m) => (String::from("Left"), m),
+            Key::Right(m) => (String::from("Right"), m),
+            Key::Char(' ', m) => (String::from("Space"), m),
+            // back-ends that do not report the shift modifier
+            // still report the uppercase character instead
+            Key::Char(c, m) if c.is_uppercase() => (c.to_string(), m | KeyModifiers::SHIFT),
+            Key::Char(c, m) => (c.to_uppercase().to_string(), m),
+            Key::Mouse(event) => {
+                let name = match event.kind {
+                    MouseEventKind::Click => "Click",
+                    MouseEventKind::DoubleClick => "DoubleClick",
+                    MouseEventKind::ScrollUp => "ScrollUp",
+                    MouseEventKind::ScrollDown => "ScrollDown",
+                };
+
+                (String::from(name), KeyModifiers::NONE)
+            }
+            Key::Any => (String::from("Unknown"), KeyModifiers::NONE),
+        };
+
+        let modifier_names = [
+            (KeyModifiers::CONTROL, "Ctrl"),
+            (KeyModifiers::ALT, "Alt"),
+            (KeyModifiers::SHIFT, "Shift"),
+            (KeyModifiers::SUPER, "Super"),
+            (KeyModifiers::HYPER, "Hyper"),
+            (KeyModifiers::META, "Meta"),
+        ];
+
+        for (modifier, modifier_name) in modifier_names {
+            if modifiers.contains(modifier) {
+                write!(f, "{modifier_name}+")?;
+            }
+        }
+
+        write!(f, "{name}")
+    }
+}
+
 #[cfg(test)]
 pub(crate) mod key_test {
     use super::{Key, KeyModifiers};
 
     impl Key {
+        /// Converts each character of the string into a key press without modifiers.
         pub fn char_keys_from_str(s: &str) -> Vec<Self> {
             s.chars()
                 .map(|c| Key::Char(c, KeyModifiers::NONE))
@@ -1,6 +1,6 @@
 use std::env;
 
-use super::{Color, StyleSheet, Styled};
+use super::{Attributes, Color, StyleSheet, Styled};
 
 /// Rendering configuration that can be applied to a prompt.
 ///
@@ -68,6 +68,21 @@ pub struct RenderConfig<'a> {
     /// a separator from the prompt message (or default value display).
     pub text_input: StyleSheet,
 
+    /// Style sheet for the rest of the suggestion completing the text input,
+    /// displayed after the cursor by [`Text`](crate::Text) prompts with ghost
+    /// text enabled.
+    pub ghost_text: StyleSheet,
+
+    /// Style sheet for the mode indicator displayed in brackets after the
+    /// prompt message of [`Text`](crate::Text) prompts in vim normal mode.
+    pub mode_indicator: StyleSheet,
+
+    /// Style sheet for the length counter displayed after the text input of
+    /// [`Text`](crate::Text) prompts, formatted as `23/80`. Once the maximum
+    /// is exceeded, the counter is rendered with the style sheet of error
+    /// messages instead.
+    pub length_counter: StyleSheet,
+
     /// Render configuration of final prompt answers (submissions).
     ///
     /// Note: a non-styled space character is added before the answer as
@@ -118,6 +133,31 @@ pub struct RenderConfig<'a> {
     /// option value to the right.
     pub unselected_checkbox: Styled<&'a str>,
 
+    /// Separator line rendered between the options of list prompts.
+    pub option_separator: Styled<&'a str>,
+
+    /// Style sheet for the indicators of how many options are listed above
+    /// and below the current page, such as `▲ 120 more`, rendered by list
+    /// prompts such as [`Select`](crate::Select) and
+    /// [`MultiSelect`](crate::MultiSelect) when the options do not fit in a
+    /// single page. If the value is None, the indicators are not displayed.
+    pub scroll_indicator: Option<StyleSheet>,
+
+    /// Checkbox of locked options in multi-select options, which are
+    /// selected and can not be unselected.
+    ///
+    /// Note: a space character will be added to separate the checkbox
+    /// from a possible prefix, and to separate the checkbox from the
+    /// option value to the right.
+    pub locked_checkbox: Styled<&'a str>,
+
+    /// Checkbox of group headers in multi-select options, when only some of
+    /// the options of the group are selected.
+    ///
+    /// Note: a space character will be added to separate the checkbox
+    /// from the header to the right.
+    pub partial_checkbox: Styled<&'a str>,
+
     /// Definition of index prefixes in option lists.
     pub option_index_prefix: IndexPrefix,
 
@@ -134,6 +174,44 @@ pub struct RenderConfig<'a> {
     /// a separator from the prefix.
     pub selected_option: Option<StyleSheet>,
 
+    /// Style sheet for the characters of an option that match the current
+    /// filter input, applied on top of the option's own style.
+    pub option_match: StyleSheet,
+
+    /// Style sheet for options that can not be selected, along with the
+    /// reason they are disabled, if any.
+    ///
+    /// Note: the reason is displayed wrapped in parenthesis after the
+    /// option value, e.g. (out of stock).
+    pub disabled_option: StyleSheet,
+
+    /// Style sheet for the descriptions displayed in the line below options.
+    pub option_description: StyleSheet,
+
+    /// Style sheet for the counter of selected options displayed next to the
+    /// prompt of [`MultiSelect`](crate::MultiSelect) prompts. If the value is
+    /// None, the counter is not displayed.
+    ///
+    /// The counter is formatted as `3/12 selected`.
+    pub selection_counter: Option<StyleSheet>,
+
+    /// Style sheet for the number of options matching the filter out of the
+    /// total, displayed after the filter input of [`Select`](crate::Select) and
+    /// [`MultiSelect`](crate::MultiSelect) prompts while it is not empty. If
+    /// the value is None, the count is not displayed.
+    ///
+    /// The count is formatted as `24/1,203`.
+    pub filter_count: Option<StyleSheet>,
+
+    /// Style sheet for the label of the custom sort order selected in
+    /// [`Select`](crate::Select) prompts, displayed in brackets at the end of
+    /// the prompt line.
+    pub sort_indicator: StyleSheet,
+
+    /// Render configuration of code snippets typed in [`TextArea`](crate::TextArea)
+    /// prompts with syntax highlighting or bracket matching enabled.
+    pub code: CodeRenderConfig,
+
     /// Render configuration for calendar
 
     #[cfg(feature = "date")]
@@ -159,6 +237,9 @@ impl<'a> RenderConfig<'a> {
             placeholder: StyleSheet::empty(),
             help_message: StyleSheet::empty(),
             text_input: StyleSheet::empty(),
+            ghost_text: StyleSheet::empty(),
+            mode_indicator: StyleSheet::empty(),
+            length_counter: StyleSheet::empty(),
             error_message: ErrorMessageRenderConfig::empty(),
             answer: StyleSheet::empty(),
             canceled_prompt_indicator: Styled::new("<canceled>"),
@@ -168,9 +249,20 @@ impl<'a> RenderConfig<'a> {
             scroll_down_prefix: Styled::new("v"),
             selected_checkbox: Styled::new("[x]"),
             unselected_checkbox: Styled::new("[ ]"),
+            locked_checkbox: Styled::new("[*]"),
+            option_separator: Styled::new("───"),
+            scroll_indicator: None,
+            partial_checkbox: Styled::new("[-]"),
             option_index_prefix: IndexPrefix::None,
             option: StyleSheet::empty(),
             selected_option: None,
+            option_match: StyleSheet::empty(),
+            disabled_option: StyleSheet::empty(),
+            option_description: StyleSheet::empty(),
+            selection_counter: Some(StyleSheet::empty()),
+            filter_count: Some(StyleSheet::empty()),
+            sort_indicator: StyleSheet::empty(),
+            code: CodeRenderConfig::empty(),
 
             #[cfg(feature = "date")]
             calendar: calendar::CalendarRenderConfig::empty(),
@@ -190,6 +282,9 @@ impl<'a> RenderConfig<'a> {
             placeholder: StyleSheet::new().with_fg(Color::DarkGrey),
             help_message: StyleSheet::empty().with_fg(Color::LightCyan),
             text_input: StyleSheet::empty(),
+            ghost_text: StyleSheet::new().with_fg(Color::DarkGrey),
+            mode_indicator: StyleSheet::new().with_fg(Color::DarkYellow),
+            length_counter: StyleSheet::new().with_fg(Color::DarkGrey),
             error_message: ErrorMessageRenderConfig::default_colored(),
             password_mask: '*',
             answer: StyleSheet::empty().with_fg(Color::LightCyan),
@@ -199,9 +294,22 @@ impl<'a> RenderConfig<'a> {
             scroll_down_prefix: Styled::new("v"),
             selected_checkbox: Styled::new("[x]").with_fg(Color::LightGreen),
             unselected_checkbox: Styled::new("[ ]"),
+            locked_checkbox: Styled::new("[*]").with_fg(Color::DarkGrey),
+            option_separator: Styled::new("───").with_fg(Color::DarkGrey),
+            scroll_indicator: None,
+            partial_checkbox: Styled::new("[-]").with_fg(Color::LightGreen),
             option_index_prefix: IndexPrefix::None,
             option: StyleSheet::empty(),
             selected_option: Some(StyleSheet::new().with_fg(Color::LightCyan)),
+            option_match: StyleSheet::new()
+                .with_fg(Color::LightYellow)
+                .with_attr(Attributes::BOLD),
+            disabled_option: StyleSheet::new().with_fg(Color::DarkGrey),
+            option_description: StyleSheet::new().with_fg(Color::DarkGrey),
+            selection_counter: Some(StyleSheet::new().with_fg(Color::DarkGrey)),
+            filter_count: Some(StyleSheet::new().with_fg(Color::DarkGrey)),
+            sort_indicator: StyleSheet::new().with_fg(Color::DarkCyan),
+            code: CodeRenderConfig::default_colored(),
 
             #[cfg(feature = "date")]
             calendar: calendar::CalendarRenderConfig::default_colored(),
@@ -229,6 +337,24 @@ impl<'a> RenderConfig<'a> {
         self
     }
 
+    /// Sets the style sheet for the ghost text completing the text input.
+    pub fn with_ghost_text(mut self, ghost_text: StyleSheet) -> Self {
+        self.ghost_text = ghost_text;
+        self
+    }
+
+    /// Sets the style sheet for the vim mode indicator of text inputs.
+    pub fn with_mode_indicator(mut self, mode_indicator: StyleSheet) -> Self {
+        self.mode_indicator = mode_indicator;
+        self
+    }
+
+    /// Sets the style sheet for the length counter of text inputs.
+    pub fn with_length_counter(mut self, length_counter: StyleSheet) -> Self {
+        self.length_counter = length_counter;
+        self
+    }
+
     /// Sets the style sheet for default values.
     pub fn with_default_value(mut self, default_value: StyleSheet) -> Self {
         self.default_value = default_value;
@@ -286,6 +412,31 @@ impl<'a> RenderConfig<'a> {
         self
     }
 
+    /// Sets the styled component for checkboxes of partially selected groups.
+    pub fn with_partial_checkbox(mut self, partial_checkbox: Styled<&'a str>) -> Self {
+        self.partial_checkbox = partial_checkbox;
+        self
+    }
+
+    /// Sets the styled component for separator lines between options.
+    pub fn with_option_separator(mut self, option_separator: Styled<&'a str>) -> Self {
+        self.option_separator = option_separator;
+        self
+    }
+
+    /// Sets the style sheet for the indicators of the options above and below
+    /// the current page, or hides them if None.
+    pub fn with_scroll_indicator(mut self, scroll_indicator: Option<StyleSheet>) -> Self {
+        self.scroll_indicator = scroll_indicator;
+        self
+    }
+
+    /// Sets the styled component for checkboxes of locked options.
+    pub fn with_locked_checkbox(mut self, locked_checkbox: Styled<&'a str>) -> Self {
+        self.locked_checkbox = locked_checkbox;
+        self
+    }
+
     /// Sets the index prefix for option lists.
     pub fn with_option_index_prefix(mut self, index_prefix: IndexPrefix) -> Self {
         self.option_index_prefix = index_prefix;
@@ -304,6 +455,42 @@ impl<'a> RenderConfig<'a> {
         self
     }
 
+    /// Sets the style sheet for the characters of an option that match the filter input.
+    pub fn with_option_match(mut self, option_match: StyleSheet) -> Self {
+        self.option_match = option_match;
+        self
+    }
+
+    /// Sets the style sheet for disabled options.
+    pub fn with_disabled_option(mut self, disabled_option: StyleSheet) -> Self {
+        self.disabled_option = disabled_option;
+        self
+    }
+
+    /// Sets the style sheet for option descriptions.
+    pub fn with_option_description(mut self, option_description: StyleSheet) -> Self {
+        self.option_description = option_description;
+        self
+    }
+
+    /// Sets the style sheet for the counter of selected options, or hides it if None.
+    pub fn with_selection_counter(mut self, selection_counter: Option<StyleSheet>) -> Self {
+        self.selection_counter = selection_counter;
+        self
+    }
+
+    /// Sets the style sheet for the count of options matching the filter, or hides it if None.
+    pub fn with_filter_count(mut self, filter_count: Option<StyleSheet>) -> Self {
+        self.filter_count = filter_count;
+        self
+    }
+
+    /// Sets the style sheet for the label of the custom sort order.
+    pub fn with_sort_indicator(mut self, sort_indicator: StyleSheet) -> Self {
+        self.sort_indicator = sort_indicator;
+        self
+    }
+
     /// Sets the indicator for canceled prompts.
     pub fn with_canceled_prompt_indicator(
         mut self,
@@ -313,6 +500,12 @@ impl<'a> RenderConfig<'a> {
         self
     }
 
+    /// Sets the render configuration of code snippets.
+    pub fn with_code_config(mut self, code: CodeRenderConfig) -> Self {
+        self.code = code;
+        self
+    }
+
     #[cfg(feature = "date")]
     /// Sets the render configuration for calendars.
     pub fn with_calendar_config(mut self, calendar: calendar::CalendarRenderConfig<'a>) -> Self {
@@ -435,6 +628,104 @@ impl<'a> ErrorMessageRenderConfig<'a> {
     }
 }
 
+/// Render configuration for code snippets, styling the tokens found by a
+/// [`Highlighter`](crate::highlighter::Highlighter) and the matched brackets.
+#[derive(Copy, Clone, Debug)]
+pub struct CodeRenderConfig {
+    /// Style sheet of the keys of mappings.
+    pub key: StyleSheet,
+
+    /// Style sheet of string literals.
+    pub string: StyleSheet,
+
+    /// Style sheet of number literals.
+    pub number: StyleSheet,
+
+    /// Style sheet of reserved words, such as `true` or `null`.
+    pub keyword: StyleSheet,
+
+    /// Style sheet of comments.
+    pub comment: StyleSheet,
+
+    /// Style sheet of punctuation, such as brackets and commas.
+    pub punctuation: StyleSheet,
+
+    /// Style sheet of the bracket next to the cursor and the one matching it.
+    pub matched_bracket: StyleSheet,
+}
+
+impl CodeRenderConfig {
+    /// Render configuration in which no colors or attributes are applied.
+    pub fn empty() -> Self {
+        Self {
+            key: StyleSheet::empty(),
+            string: StyleSheet::empty(),
+            number: StyleSheet::empty(),
+            keyword: StyleSheet::empty(),
+            comment: StyleSheet::empty(),
+            punctuation: StyleSheet::empty(),
+            matched_bracket: StyleSheet::empty(),
+        }
+    }
+
+    /// Render configuration where default colors and attributes are applied.
+    pub fn default_colored() -> Self {
+        Self {
+            key: StyleSheet::empty().with_fg(Color::LightCyan),
+            string: StyleSheet::empty().with_fg(Color::LightGreen),
+            number: StyleSheet::empty().with_fg(Color::LightMagenta),
+            keyword: StyleSheet::empty().with_fg(Color::LightYellow),
+            comment: StyleSheet::empty().with_fg(Color::DarkGrey),
+            punctuation: StyleSheet::empty(),
+            matched_bracket: StyleSheet::empty()
+                .with_fg(Color::LightYellow)
+                .with_attr(Attributes::BOLD),
+        }
+    }
+
+    /// Sets the style sheet of the keys of mappings.
+    pub fn with_key(mut self, key: StyleSheet) -> Self {
+        self.key = key;
+        self
+    }
+
+    /// Sets the style sheet of string literals.
+    pub fn with_string(mut self, string: StyleSheet) -> Self {
+        self.string = string;
+        self
+    }
+
+    /// Sets the style sheet of number literals.
+    pub fn with_number(mut self, number: StyleSheet) -> Self {
+        self.number = number;
+        self
+    }
+
+    /// Sets the style sheet of reserved words.
+    pub fn with_keyword(mut self, keyword: StyleSheet) -> Self {
+        self.keyword = keyword;
+        self
+    }
+
+    /// Sets the style sheet of comments.
+    pub fn with_comment(mut self, comment: StyleSheet) -> Self {
+        self.comment = comment;
+        self
+    }
+
+    /// Sets the style sheet of punctuation.
+    pub fn with_punctuation(mut self, punctuation: StyleSheet) -> Self {
+        self.punctuation = punctuation;
+        self
+    }
+
+    /// Sets the style sheet of matched brackets.
+    pub fn with_matched_bracket(mut self, matched_bracket: StyleSheet) -> Self {
+        self.matched_bracket = matched_bracket;
+        self
+    }
+}
+
 #[cfg(feature = "date")]
 pub mod calendar {
     //! Module containing additional render config for date prompts.
@@ -1,22 +1,34 @@
-use std::{collections::BTreeSet, fmt::Display, io::Result};
+use std::{
+    collections::{BTreeMap, BTreeSet},
+    fmt::Display,
+    io::Result,
+    ops::Range,
+    path::Path,
+    time::Duration,
+};
 
 use unicode_width::UnicodeWidthStr;
 
 use crate::{
     error::InquireResult,
+    highlighter::TokenKind,
     input::Input,
     list_option::ListOption,
-    terminal::Terminal,
-    ui::{IndexPrefix, Key, RenderConfig, Styled},
-    utils::{int_log10, Page},
+    terminal::{Terminal, TerminalSize},
+    ui::{Color, IndexPrefix, Key, RenderConfig, StyleSheet, Styled},
+    utils::{group_digits, int_log10, paginate, Page},
     validator::ErrorMessage,
 };
 
-use super::{frame_renderer::FrameRenderer, InputReader};
+use super::{frame_renderer::FrameRenderer, InputEvent, InputReader};
 
 pub trait CommonBackend: InputReader {
     fn frame_setup(&mut self) -> Result<()>;
     fn frame_finish(&mut self, is_last_frame: bool) -> Result<()>;
+    fn screen_size(&mut self) -> TerminalSize;
+
+    /// Starts or stops reporting mouse events while the prompt is open.
+    fn set_mouse_capture(&mut self, enabled: bool) -> Result<()>;
 
     fn render_canceled_prompt(&mut self, prompt: &str) -> Result<()>;
     fn render_prompt_with_answer(&mut self, prompt: &str, answer: &str) -> Result<()>;
@@ -26,13 +38,53 @@ pub trait CommonBackend: InputReader {
 }
 
 pub trait TextBackend: CommonBackend {
+    /// Renders the prompt with the indicators displayed around the input.
     fn render_prompt(
         &mut self,
         prompt: &str,
         default: Option<&str>,
         cur_input: &Input,
+        details: &TextInputDetails<'_>,
     ) -> Result<()>;
+    /// Renders the values parsed from an input holding delimited values,
+    /// at the line below the prompt.
+    fn render_values(&mut self, values: &[&str]) -> Result<()>;
     fn render_suggestions<D: Display>(&mut self, page: Page<'_, ListOption<D>>) -> Result<()>;
+    /// Renders the suggestions below the prompt, starting at the column of
+    /// the cursor, highlighting the fragment of each one matching the input.
+    fn render_dropdown<D: Display>(
+        &mut self,
+        page: Page<'_, ListOption<D>>,
+        fragment: &str,
+    ) -> Result<()>;
+    fn render_loading_indicator(&mut self, message: &str) -> Result<()>;
+}
+
+pub trait TagsBackend: TextBackend {
+    fn render_tags_prompt(
+        &mut self,
+        prompt: &str,
+        tags: &[String],
+        cur_input: &Input,
+    ) -> Result<()>;
+}
+
+/// Highlighted parts of a line of the user's text input, as rendered by the prompts.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct LineHighlights {
+    /// Tokens found by the highlighter.
+    pub tokens: Vec<(Range<usize>, TokenKind)>,
+    /// Byte positions of the brackets matched around the cursor.
+    pub matched_brackets: Vec<usize>,
+}
+
+pub trait TextAreaBackend: CommonBackend {
+    fn render_text_area_prompt(&mut self, prompt: &str, default: Option<&str>) -> Result<()>;
+    fn render_text_area_lines(
+        &mut self,
+        page: Page<'_, Input>,
+        highlights: &[LineHighlights],
+    ) -> Result<()>;
 }
 
 #[cfg(feature = "editor")]
@@ -40,18 +92,286 @@ pub trait EditorBackend: CommonBackend {
     fn render_prompt(&mut self, prompt: &str, editor_command: &str) -> Result<()>;
 }
 
+/// Indicators of [Text](crate::Text) prompts rendered around the text input.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct TextInputDetails<'a> {
+    /// Rest of the suggestion completing the input, displayed after the
+    /// cursor, which is at the end of the input.
+    pub ghost_text: Option<&'a str>,
+    /// Editing mode displayed in brackets after the prompt message.
+    pub mode: Option<&'a str>,
+    /// Length of the input and its maximum, displayed after the input, with
+    /// the error style sheet once the maximum is exceeded.
+    pub length_counter: Option<(usize, usize)>,
+    /// Whether the input flashes, being rendered with the error style sheet,
+    /// e.g. when typing beyond its maximum length.
+    pub flash: bool,
+}
+
+/// Details of the options of [Select](crate::Select) and [MultiSelect](crate::MultiSelect)
+/// prompts rendered along with their values, keyed by the indexes of the options.
+#[derive(Clone, Debug, Default)]
+pub struct OptionDetails<'a> {
+    /// Options that are selected and can not be unselected.
+    pub locked: BTreeSet<usize>,
+    /// Options that can not be selected, with the reason why, if any.
+    pub disabled: BTreeMap<usize, Option<&'a str>>,
+    /// Descriptions rendered in the line below the options.
+    pub descriptions: BTreeMap<usize, &'a str>,
+    /// Icons rendered before the values of the options.
+    pub icons: BTreeMap<usize, Styled<&'a str>>,
+    /// Options preceded by a separator line, unless they are the first one
+    /// of the page.
+    pub separators: BTreeSet<usize>,
+    /// Headers of the groups the options belong to, rendered above the
+    /// first option of each group.
+    pub groups: BTreeMap<usize, &'a str>,
+    /// Indexes of the characters of the options matched by the filter input,
+    /// rendered with the `option_match` style sheet.
+    pub matched: BTreeMap<usize, Vec<usize>>,
+    /// Whether the group headers are prefixed by a checkbox reflecting the
+    /// selection of their options.
+    pub group_checkboxes: bool,
+    /// Whether the options of the page are prefixed by their numeric
+    /// shortcuts, from `1)` to `9)`, instead of their indexes.
+    pub shortcuts: bool,
+}
+
+impl<'a> OptionDetails<'a> {
+    /// Header to be rendered above the option at the given position of the page,
+    /// when it starts a group. The header is repeated at the top of the page
+    /// when a group is split between pages.
+    fn group_header<D>(&self, position: usize, page: &Page<'_, ListOption<D>>) -> Option<&'a str> {
+        let header = self.groups.get(&page.content[position].index).copied()?;
+
+        let starts_group =
+            position == 0 || self.groups.get(&page.content[position - 1].index) != Some(&header);
+
+        starts_group.then_some(header)
+    }
+
+    /// Number of lines rendered for the options of the page, counting their
+    /// descriptions along with the separators and group headers between them.
+    pub fn page_height<D>(&self, page: &Page<'_, ListOption<D>>) -> usize {
+        (0..page.content.len())
+            .map(|position| {
+                let index = page.content[position].index;
+
+                1 + usize::from(position > 0 && self.separators.contains(&index))
+                    + usize::from(self.group_header(position, page).is_some())
+                    + usize::from(self.descriptions.contains_key(&index))
+            })
+            .sum()
+    }
+
+    /// Largest page size whose page of the choices, around the cursor, fits
+    /// in the given number of lines. At least one option is always listed.
+    pub fn fit_page_size<D>(
+        &self,
+        height: usize,
+        choices: &[ListOption<D>],
+        cursor: Option<usize>,
+    ) -> usize {
+        (1..=height)
+            .rev()
+            .find(|&page_size| self.page_height(&paginate(page_size, choices, cursor)) <= height)
+            .unwrap_or(1)
+    }
+
+    /// Width of the widest of the choices along with its icon, to which the
+    /// options are padded when laid out in columns.
+    pub fn label_width<D: Display>(&self, choices: &[ListOption<D>]) -> usize {
+        choices
+            .iter()
+            .map(|option| self.icon_width(option.index) + option.value.to_string().width())
+            .max()
+            .unwrap_or_default()
+    }
+
+    /// Largest number of columns the choices can be laid out in within the
+    /// given width. At least one column is always used.
+    pub fn fit_columns<D: Display>(&self, width: usize, choices: &[ListOption<D>]) -> usize {
+        // each cell has a separator, the cursor prefix and another separator
+        // before the label, and each row starts with the scroll indicator
+        let cell_width = self.label_width(choices) + 3;
+        let columns = width.saturating_sub(2) / cell_width;
+
+        columns.clamp(1, choices.len().max(1))
+    }
+
+    /// Indexes of the enabled options of the group with the given header.
+    pub fn group_members<'b>(&'b self, header: &'b str) -> impl Iterator<Item = usize> + 'b {
+        self.groups
+            .iter()
+            .filter(move |(idx, h)| **h == header && !self.disabled.contains_key(idx))
+            .map(|(idx, _)| *idx)
+    }
+
+    /// Width of the icon printed before the value of the option, along with
+    /// the space separating them.
+    fn icon_width(&self, index: usize) -> usize {
+        self.icons
+            .get(&index)
+            .map(|icon| icon.content.width() + 1)
+            .unwrap_or_default()
+    }
+
+    /// Numeric shortcut of the option at the given position of the page,
+    /// padded for the options past the ninth one, which have none.
+    fn shortcut_prefix(position: usize) -> String {
+        match position {
+            0..=8 => format!("{})", position + 1),
+            _ => String::from("  "),
+        }
+    }
+}
+
 pub trait SelectBackend: CommonBackend {
     fn render_select_prompt(&mut self, prompt: &str, cur_input: Option<&Input>) -> Result<()>;
+    /// Renders the prompt line along with the number of options matching the
+    /// filter out of the total and the label of the custom sort order, if any.
+    fn render_select_header(
+        &mut self,
+        prompt: &str,
+        cur_input: Option<&Input>,
+        filter_count: Option<(usize, usize)>,
+        sort_order: Option<&str>,
+    ) -> Result<()>;
     fn render_options<D: Display>(&mut self, page: Page<'_, ListOption<D>>) -> Result<()>;
+    /// Renders the options of the page along with their details, one per
+    /// line, or in rows of the given number of columns when there are more
+    /// than one, leaving the descriptions, separators, group headers and
+    /// index prefixes out.
+    fn render_options_with_details<D: Display>(
+        &mut self,
+        page: Page<'_, ListOption<D>>,
+        details: &OptionDetails<'_>,
+        columns: usize,
+    ) -> Result<()>;
+    fn render_preview(&mut self, preview: &str, height: usize) -> Result<()>;
+    fn render_loading_indicator(&mut self, message: &str) -> Result<()>;
+    /// Number of lines rendered around the options to indicate how many
+    /// of them are above and below the page.
+    fn scroll_indicators_height(&self) -> usize;
+}
+
+pub trait FuzzyFinderBackend: CommonBackend {
+    fn enter_full_screen(&mut self) -> Result<()>;
+
+    fn render_fuzzy_finder_prompt(
+        &mut self,
+        prompt: &str,
+        cur_input: &Input,
+        matched: usize,
+        total: usize,
+    ) -> Result<()>;
+    fn render_matched_options<D: Display>(
+        &mut self,
+        page: Page<'_, ListOption<D>>,
+        matched_chars: &[Vec<usize>],
+    ) -> Result<()>;
+    fn render_preview(&mut self, preview: &str, height: usize) -> Result<()>;
+}
+
+pub trait CommandPaletteBackend: CommonBackend {
+    fn render_command_palette_prompt(&mut self, prompt: &str, cur_input: &Input) -> Result<()>;
+    fn render_commands<D: Display>(
+        &mut self,
+        page: Page<'_, ListOption<D>>,
+        headers: &[Option<&str>],
+        shortcuts: &[Option<&str>],
+        matched_chars: &[Vec<usize>],
+    ) -> Result<()>;
+}
+
+pub trait GridSelectBackend: CommonBackend {
+    fn render_grid_select_prompt(&mut self, prompt: &str) -> Result<()>;
+    fn render_grid<D: Display>(
+        &mut self,
+        page: Page<'_, ListOption<D>>,
+        columns: usize,
+        label_width: usize,
+    ) -> Result<()>;
+}
+
+pub trait CheckboxMatrixBackend: CommonBackend {
+    fn render_checkbox_matrix_prompt(&mut self, prompt: &str) -> Result<()>;
+    fn render_checkbox_matrix<D: Display>(
+        &mut self,
+        rows: Page<'_, ListOption<D>>,
+        columns: &[String],
+        checked: &BTreeSet<(usize, usize)>,
+        cursor_column: usize,
+    ) -> Result<()>;
+}
+
+pub trait FileSelectBackend: SelectBackend {
+    fn render_current_directory(&mut self, directory: &Path) -> Result<()>;
+}
+
+pub trait TableSelectBackend: SelectBackend {
+    fn render_table_header(&mut self, header: &str, total: usize) -> Result<()>;
+}
+
+pub trait TableEditorBackend: CommonBackend {
+    fn render_table_editor_prompt(&mut self, prompt: &str) -> Result<()>;
+    fn render_table_editor(
+        &mut self,
+        columns: &[&str],
+        rows: Page<'_, ListOption<&Vec<String>>>,
+        cursor_column: usize,
+        input: &Input,
+    ) -> Result<()>;
+}
+
+pub trait TreeSelectBackend: CommonBackend {
+    fn render_tree_select_prompt(&mut self, prompt: &str) -> Result<()>;
+    fn render_tree_nodes<D: Display>(
+        &mut self,
+        page: Page<'_, ListOption<D>>,
+        guides: &[String],
+    ) -> Result<()>;
+}
+
+pub trait MenuBackend: CommonBackend {
+    fn render_menu_prompt(&mut self, prompt: &str, breadcrumb: &[String]) -> Result<()>;
+    fn render_menu_entries<D: Display>(
+        &mut self,
+        page: Page<'_, ListOption<D>>,
+        submenus: &[bool],
+    ) -> Result<()>;
+}
+
+pub trait TransferBackend: CommonBackend {
+    fn render_transfer_prompt(&mut self, prompt: &str) -> Result<()>;
+    fn render_transfer_panes<D: Display>(
+        &mut self,
+        titles: (&str, &str),
+        available: Page<'_, ListOption<D>>,
+        chosen: Page<'_, ListOption<D>>,
+        pane_width: usize,
+    ) -> Result<()>;
 }
 
 pub trait MultiSelectBackend: CommonBackend {
-    fn render_multiselect_prompt(&mut self, prompt: &str, cur_input: Option<&Input>) -> Result<()>;
+    fn render_multiselect_prompt(
+        &mut self,
+        prompt: &str,
+        cur_input: Option<&Input>,
+        selected: usize,
+        matched: Option<usize>,
+        total: usize,
+    ) -> Result<()>;
     fn render_options<D: Display>(
         &mut self,
         page: Page<'_, ListOption<D>>,
         checked: &BTreeSet<usize>,
+        details: &OptionDetails<'_>,
     ) -> Result<()>;
+    fn render_loading_indicator(&mut self, message: &str) -> Result<()>;
+    /// Number of lines rendered around the options to indicate how many
+    /// of them are above and below the page.
+    fn scroll_indicators_height(&self) -> usize;
 }
 
 pub trait CustomTypeBackend: CommonBackend {
@@ -63,18 +383,125 @@ pub trait CustomTypeBackend: CommonBackend {
     ) -> Result<()>;
 }
 
+pub trait PercentageBackend: CustomTypeBackend {
+    fn render_percentage_bar(&mut self, filled: usize, width: usize) -> Result<()>;
+}
+
+pub trait MaskBackend: CommonBackend {
+    fn render_masked_prompt(
+        &mut self,
+        prompt: &str,
+        default: Option<&str>,
+        filled: &str,
+        remaining: &str,
+    ) -> Result<()>;
+}
+
+pub trait NumberRangeBackend: CommonBackend {
+    fn render_number_range_prompt(
+        &mut self,
+        prompt: &str,
+        default: Option<&str>,
+        lower: &Input,
+        upper: &Input,
+        upper_focused: bool,
+    ) -> Result<()>;
+}
+
+pub trait DurationInputBackend: CustomTypeBackend {
+    fn render_duration_preview(&mut self, preview: std::result::Result<&str, &str>) -> Result<()>;
+}
+
+pub trait SliderBackend: CommonBackend {
+    fn render_slider_prompt(
+        &mut self,
+        prompt: &str,
+        position: usize,
+        width: usize,
+        value: &str,
+    ) -> Result<()>;
+}
+
+pub trait SpinnerBackend: CommonBackend {
+    fn render_spinner(
+        &mut self,
+        frame: &str,
+        message: &str,
+        position: Option<(u64, u64)>,
+    ) -> Result<()>;
+}
+
+pub trait ToggleBackend: CommonBackend {
+    fn render_toggle_prompt(
+        &mut self,
+        prompt: &str,
+        on_label: &str,
+        off_label: &str,
+        value: bool,
+    ) -> Result<()>;
+}
+
+pub trait HotkeySelectBackend: CommonBackend {
+    fn render_hotkey_select_prompt(
+        &mut self,
+        prompt: &str,
+        options: &[(String, char, String)],
+    ) -> Result<()>;
+}
+
+pub trait RatingBackend: CommonBackend {
+    fn render_rating_prompt(
+        &mut self,
+        prompt: &str,
+        filled_symbol: &str,
+        empty_symbol: &str,
+        value: u8,
+        max: u8,
+    ) -> Result<()>;
+}
+
+pub trait ColorSelectBackend: CommonBackend {
+    fn render_color_select_prompt(&mut self, prompt: &str, hex_input: Option<&Input>)
+        -> Result<()>;
+    fn render_palette(&mut self, colors: &[Color], columns: usize, cursor: usize) -> Result<()>;
+}
+
 pub trait PasswordBackend: CommonBackend {
     fn render_prompt(&mut self, prompt: &str) -> Result<()>;
     fn render_prompt_with_masked_input(&mut self, prompt: &str, cur_input: &Input) -> Result<()>;
     fn render_prompt_with_full_input(&mut self, prompt: &str, cur_input: &Input) -> Result<()>;
 }
 
+pub trait TypeToConfirmBackend: CommonBackend {
+    fn render_type_to_confirm_prompt(
+        &mut self,
+        prompt: &str,
+        phrase: &str,
+        cur_input: &Input,
+        mismatched_chars: &[usize],
+    ) -> Result<()>;
+}
+
 #[derive(Clone, Copy, Debug, Default)]
 pub struct Position {
     pub row: u16,
     pub col: u16,
 }
 
+/// Rows of the rendered frame taken by an option, used to resolve the
+/// position of mouse events.
+struct MouseTarget {
+    /// Rows taken by the option, including its description.
+    rows: Range<u16>,
+    /// Position of the option in the current page.
+    position: usize,
+    /// Columns of the checkbox of the option, if any.
+    columns: Option<Range<u16>>,
+    /// Columns of the cell of the option, when the options are laid out in
+    /// columns, sharing their rows.
+    cell: Option<Range<u16>>,
+}
+
 pub struct Backend<'a, I, T>
 where
     I: InputReader,
@@ -83,6 +510,8 @@ where
     frame_renderer: FrameRenderer<T>,
     input_reader: I,
     render_config: RenderConfig<'a>,
+    // options of the last rendered frame, along with their checkbox columns
+    mouse_targets: Vec<MouseTarget>,
 }
 
 impl<'a, I, T> Backend<'a, I, T>
@@ -96,15 +525,76 @@ where
             frame_renderer: FrameRenderer::new(terminal)?,
             input_reader,
             render_config,
+            mouse_targets: Vec::new(),
         };
 
         Ok(backend)
     }
 
-    fn print_option_prefix<D: Display>(
+    /// Records the rows taken by the option rendered since the given
+    /// position, along with the columns of its checkbox, if any.
+    fn push_mouse_target(
+        &mut self,
+        start: Position,
+        position: usize,
+        checkbox: Option<Range<u16>>,
+    ) {
+        let end = self.frame_renderer.current_position();
+
+        self.mouse_targets.push(MouseTarget {
+            rows: start.row..end.row.max(start.row + 1),
+            position,
+            columns: checkbox,
+            cell: None,
+        });
+    }
+
+    /// Records the cell of the option rendered since the given position, in
+    /// a row shared with the other options of the same row.
+    fn push_mouse_cell(&mut self, start: Position, position: usize) {
+        let end = self.frame_renderer.current_position();
+
+        self.mouse_targets.push(MouseTarget {
+            rows: start.row..start.row + 1,
+            position,
+            columns: None,
+            cell: Some(start.col..end.col),
+        });
+    }
+
+    /// Resolves the option under the pointer of mouse events, from the
+    /// layout of the last rendered frame.
+    fn resolve_mouse_target(&mut self, event: InputEvent) -> InquireResult<InputEvent> {
+        let mut mouse = match event {
+            InputEvent::Key(Key::Mouse(mouse)) => mouse,
+            event => return Ok(event),
+        };
+
+        let row = match self.frame_renderer.frame_row(mouse.row)? {
+            Some(row) => row,
+            None => return Ok(event),
+        };
+
+        let target = self.mouse_targets.iter().find(|t| match &t.cell {
+            Some(cell) => t.rows.contains(&row) && cell.contains(&mouse.column),
+            None => t.rows.contains(&row),
+        });
+
+        if let Some(target) = target {
+            mouse.option = Some(target.position);
+            mouse.on_checkbox = matches!(
+                &target.columns,
+                Some(columns) if row == target.rows.start && columns.contains(&mouse.column)
+            );
+        }
+
+        Ok(Key::Mouse(mouse).into())
+    }
+
+    fn print_option_prefix<D>(
         &mut self,
         option_relative_index: usize,
-        page: &Page<'_, ListOption<D>>,
+        page: &Page<'_, D>,
     ) -> Result<()> {
         let empty_prefix = Styled::new(" ");
 
@@ -121,107 +611,455 @@ where
         self.frame_renderer.write_styled(x)
     }
 
+    fn print_highlighted_line(&mut self, line: &str, highlights: &LineHighlights) -> Result<()> {
+        let code = self.render_config.code;
+        let text_input = self.render_config.text_input;
+        let style_at = |position: usize| {
+            if highlights.matched_brackets.contains(&position) {
+                return code.matched_bracket;
+            }
+
+            let kind = highlights
+                .tokens
+                .iter()
+                .find(|(range, _)| range.contains(&position))
+                .map(|(_, kind)| kind);
+
+            match kind {
+                Some(TokenKind::Key) => code.key,
+                Some(TokenKind::String) => code.string,
+                Some(TokenKind::Number) => code.number,
+                Some(TokenKind::Keyword) => code.keyword,
+                Some(TokenKind::Comment) => code.comment,
+                Some(TokenKind::Punctuation) => code.punctuation,
+                None => text_input,
+            }
+        };
+
+        let mut segment_start = 0;
+        let mut segment_style = style_at(0);
+
+        for (position, _) in line.char_indices().skip(1) {
+            let style = style_at(position);
+
+            if style != segment_style {
+                self.frame_renderer.write_styled(
+                    Styled::new(&line[segment_start..position]).with_style_sheet(segment_style),
+                )?;
+                segment_start = position;
+                segment_style = style;
+            }
+        }
+
+        self.frame_renderer
+            .write_styled(Styled::new(&line[segment_start..]).with_style_sheet(segment_style))
+    }
+
     fn print_option_value<D: Display>(
         &mut self,
         option_relative_index: usize,
         option: &ListOption<D>,
         page: &Page<'_, ListOption<D>>,
     ) -> Result<()> {
-        let stylesheet = if let Some(selected_option_style) = self.render_config.selected_option {
-            match page.cursor {
-                Some(cursor) if cursor == option_relative_index => selected_option_style,
-                _ => self.render_config.option,
-            }
-        } else {
-            self.render_config.option
-        };
+        let stylesheet = self.option_style(option_relative_index, page);
 
         self.frame_renderer
             .write_styled(Styled::new(&option.value).with_style_sheet(stylesheet))
     }
 
-    fn print_option_index_prefix(&mut self, index: usize, max_index: usize) -> Option<Result<()>> {
-        let index = index.saturating_add(1);
-
-        let content = match self.render_config.option_index_prefix {
-            IndexPrefix::None => None,
-            IndexPrefix::Simple => Some(format!("{index})")),
-            IndexPrefix::SpacePadded => {
-                let width = int_log10(max_index.saturating_add(1));
-                Some(format!("{index:width$})"))
-            }
-            IndexPrefix::ZeroPadded => {
-                let width = int_log10(max_index.saturating_add(1));
-                Some(format!("{index:0width$})"))
+    /// Prints the value of an option, highlighting the characters matched by
+    /// the filter input when there are any.
+    fn print_option_value_with_matches<D: Display>(
+        &mut self,
+        option_relative_index: usize,
+        option: &ListOption<D>,
+        page: &Page<'_, ListOption<D>>,
+        details: &OptionDetails<'_>,
+    ) -> Result<()> {
+        match details.matched.get(&option.index) {
+            Some(matched) => {
+                let stylesheet = self.option_style(option_relative_index, page);
+                self.print_matched_value(&option.value.to_string(), matched, stylesheet, usize::MAX)
             }
-        };
-
-        content.map(|prefix| {
-            self.frame_renderer
-                .write_styled(Styled::new(prefix).with_style_sheet(self.render_config.option))
-        })
+            None => self.print_option_value(option_relative_index, option, page),
+        }
     }
 
-    fn print_default_value(&mut self, value: &str) -> Result<()> {
-        let content = format!("({value})");
-        let token = Styled::new(content).with_style_sheet(self.render_config.default_value);
-
-        self.frame_renderer.write_styled(token)
+    fn option_style<D>(
+        &self,
+        option_relative_index: usize,
+        page: &Page<'_, ListOption<D>>,
+    ) -> StyleSheet {
+        match (self.render_config.selected_option, page.cursor) {
+            (Some(selected_option_style), Some(cursor)) if cursor == option_relative_index => {
+                selected_option_style
+            }
+            _ => self.render_config.option,
+        }
     }
 
-    fn print_prompt_with_prefix(&mut self, prefix: Styled<&str>, prompt: &str) -> Result<()> {
-        self.frame_renderer.write_styled(prefix)?;
+    /// Prints the preview of an option below a separator line, truncating it
+    /// at `height` lines and at the width of the screen.
+    fn print_preview(&mut self, preview: &str, height: usize) -> Result<()> {
+        let width = self.screen_size().width() as usize;
 
-        self.frame_renderer.write(" ")?;
+        let separator = Styled::new("─".repeat(width.saturating_sub(1)))
+            .with_style_sheet(self.render_config.help_message);
+        self.frame_renderer.write_styled(separator)?;
+        self.new_line()?;
 
-        self.frame_renderer
-            .write_styled(Styled::new(prompt).with_style_sheet(self.render_config.prompt))?;
+        for line in preview.lines().take(height) {
+            let mut line_width = 0;
+            let line = line
+                .chars()
+                .take_while(|c| {
+                    line_width += c.to_string().width();
+                    line_width < width
+                })
+                .collect::<String>();
+
+            self.frame_renderer.write(line)?;
+            self.new_line()?;
+        }
 
         Ok(())
     }
 
-    fn print_prompt(&mut self, prompt: &str) -> Result<()> {
-        self.print_prompt_with_prefix(self.render_config.prompt_prefix, prompt)
+    /// Prints the message displayed below the options while more of them are
+    /// being loaded.
+    fn print_loading_indicator(&mut self, message: &str) -> Result<()> {
+        self.frame_renderer
+            .write_styled(Styled::new(message).with_style_sheet(self.render_config.placeholder))?;
+        self.new_line()
     }
 
-    fn print_input(&mut self, input: &Input) -> Result<()> {
-        self.frame_renderer.write(" ")?;
-
-        // The cursor is at the beginning of the input line.
-        // From here it's easier to mark the wanted cursor position
-        // (based on the underlying input struct), as it's a simple
-        // cur_pos + offset calculation.
-        self.frame_renderer
-            .mark_cursor_position(input.pre_cursor().width() as isize);
+    /// Width of the prefixes printed before the value of an option, such as
+    /// the cursor and the index of the option.
+    fn option_value_indent(&self, index_prefix: Option<&str>) -> usize {
+        let prefix_width = self.render_config.highlighted_option_prefix.content.width() + 1;
 
-        if input.is_empty() {
-            match input.placeholder() {
-                Some("") | None => {}
-                Some(p) => self.frame_renderer.write_styled(
-                    Styled::new(p).with_style_sheet(self.render_config.placeholder),
-                )?,
-            }
-        } else {
-            self.frame_renderer.write_styled(
-                Styled::new(input.content()).with_style_sheet(self.render_config.text_input),
-            )?;
+        match index_prefix {
+            Some(index_prefix) => prefix_width + index_prefix.width() + 1,
+            None => prefix_width,
         }
+    }
 
-        // if cursor is at end of input, we need to add
-        // a space, otherwise the cursor will render on the
-        // \n character, on the next line.
-        if input.cursor() == input.length() {
-            self.frame_renderer.write(' ')?;
+    /// Prefix printed before the value of the option at the given position of
+    /// the page: its numeric shortcut when enabled, or its index otherwise.
+    fn option_details_prefix<D>(
+        &self,
+        position: usize,
+        page: &Page<'_, ListOption<D>>,
+        details: &OptionDetails<'_>,
+    ) -> Option<String> {
+        match details.shortcuts {
+            true => Some(OptionDetails::shortcut_prefix(position)),
+            false => self.option_index_prefix(page.content[position].index, page.total),
         }
+    }
 
-        Ok(())
+    /// Prints the icon of an option, followed by the space separating it from
+    /// the option value.
+    fn print_option_icon(&mut self, icon: Option<&Styled<&str>>) -> Result<()> {
+        match icon {
+            Some(icon) => {
+                self.frame_renderer.write_styled(*icon)?;
+                self.frame_renderer.write(" ")
+            }
+            None => Ok(()),
+        }
     }
 
-    fn print_prompt_with_input(
+    /// Prints the options of the page in rows of the given number of columns,
+    /// each of them padded to the width of the widest one.
+    fn print_option_columns<D: Display>(
         &mut self,
-        prompt: &str,
-        default: Option<&str>,
-        input: &Input,
+        page: &Page<'_, ListOption<D>>,
+        details: &OptionDetails<'_>,
+        columns: usize,
+    ) -> Result<()> {
+        let prefix_width = self.render_config.highlighted_option_prefix.content.width();
+        let label_width = details.label_width(page.content);
+        let row_count = (page.content.len() + columns - 1) / columns;
+
+        for (row_idx, row) in page.content.chunks(columns).enumerate() {
+            let row_prefix = if row_idx == 0 && !page.first {
+                self.render_config.scroll_up_prefix
+            } else if row_idx + 1 == row_count && !page.last {
+                self.render_config.scroll_down_prefix
+            } else {
+                Styled::new(" ")
+            };
+            self.frame_renderer.write_styled(row_prefix)?;
+
+            for (col_idx, option) in row.iter().enumerate() {
+                let idx = row_idx * columns + col_idx;
+                let start = self.frame_renderer.current_position();
+
+                self.frame_renderer.write(" ")?;
+                if page.cursor == Some(idx) {
+                    self.frame_renderer
+                        .write_styled(self.render_config.highlighted_option_prefix)?;
+                } else {
+                    self.frame_renderer.write(" ".repeat(prefix_width))?;
+                }
+                self.frame_renderer.write(" ")?;
+
+                self.print_option_icon(details.icons.get(&option.index))?;
+
+                match details.disabled.get(&option.index) {
+                    Some(_) => self.print_disabled_option_value(option, None)?,
+                    None => self.print_option_value_with_matches(idx, option, page, details)?,
+                }
+
+                // the last column is not padded, avoiding trailing whitespace
+                if col_idx + 1 < row.len() {
+                    let width = details.icon_width(option.index) + option.value.to_string().width();
+                    self.frame_renderer
+                        .write(" ".repeat(label_width.saturating_sub(width)))?;
+                }
+
+                self.push_mouse_cell(start, idx);
+            }
+
+            self.new_line()?;
+        }
+
+        Ok(())
+    }
+
+    /// Prints the description of an option in its own line, aligned with
+    /// the option value.
+    /// Prints how many options are listed above or below the page, if the
+    /// indicators are enabled and the options do not fit in the page.
+    ///
+    /// The line is left empty when there are no options in that direction,
+    /// so that the frame keeps its height while scrolling.
+    fn print_scroll_indicator<D>(&mut self, page: &Page<'_, D>, above: bool) -> Result<()> {
+        let stylesheet = match self.render_config.scroll_indicator {
+            Some(stylesheet) if !(page.first && page.last) => stylesheet,
+            _ => return Ok(()),
+        };
+
+        let (arrow, count) = match above {
+            true => ("▲", page.start),
+            false => ("▼", page.total - page.start - page.content.len()),
+        };
+
+        match count {
+            // empty lines are not rendered
+            0 => self.frame_renderer.write(" ")?,
+            count => {
+                let indicator = format!("  {arrow} {} more", group_digits(count));
+                self.frame_renderer
+                    .write_styled(Styled::new(indicator).with_style_sheet(stylesheet))?;
+            }
+        }
+
+        self.new_line()
+    }
+
+    fn print_option_description(&mut self, description: &str, indent: usize) -> Result<()> {
+        self.frame_renderer.write(" ".repeat(indent))?;
+        self.frame_renderer.write_styled(
+            Styled::new(description).with_style_sheet(self.render_config.option_description),
+        )?;
+
+        self.new_line()
+    }
+
+    /// Prints the value of a disabled option, followed by the reason it can
+    /// not be selected, if any.
+    fn print_disabled_option_value<D: Display>(
+        &mut self,
+        option: &ListOption<D>,
+        reason: Option<&str>,
+    ) -> Result<()> {
+        let stylesheet = self.render_config.disabled_option;
+
+        self.frame_renderer
+            .write_styled(Styled::new(&option.value).with_style_sheet(stylesheet))?;
+
+        if let Some(reason) = reason {
+            self.frame_renderer.write(" ")?;
+            self.frame_renderer
+                .write_styled(Styled::new(format!("({reason})")).with_style_sheet(stylesheet))?;
+        }
+
+        Ok(())
+    }
+
+    /// Prints the value of an option, highlighting the characters at the
+    /// `matched` indexes and truncating it at `max_width` columns.
+    fn print_matched_value(
+        &mut self,
+        value: &str,
+        matched: &[usize],
+        style: StyleSheet,
+        max_width: usize,
+    ) -> Result<()> {
+        let option_match = self.render_config.option_match;
+        let match_style = StyleSheet {
+            fg: option_match.fg.or(style.fg),
+            bg: option_match.bg.or(style.bg),
+            att: style.att | option_match.att,
+        };
+
+        let mut width = 0;
+        let mut segment = String::new();
+        let mut segment_matched = false;
+
+        for (i, c) in value.chars().enumerate() {
+            width += c.to_string().width();
+            if width > max_width {
+                break;
+            }
+
+            let is_matched = matched.contains(&i);
+            if is_matched != segment_matched && !segment.is_empty() {
+                let sheet = if segment_matched { match_style } else { style };
+                self.frame_renderer.write_styled(
+                    Styled::new(std::mem::take(&mut segment)).with_style_sheet(sheet),
+                )?;
+            }
+
+            segment_matched = is_matched;
+            segment.push(c);
+        }
+
+        if !segment.is_empty() {
+            let sheet = if segment_matched { match_style } else { style };
+            self.frame_renderer
+                .write_styled(Styled::new(segment).with_style_sheet(sheet))?;
+        }
+
+        Ok(())
+    }
+
+    fn option_index_prefix(&self, index: usize, max_index: usize) -> Option<String> {
+        let index = index.saturating_add(1);
+
+        match self.render_config.option_index_prefix {
+            IndexPrefix::None => None,
+            IndexPrefix::Simple => Some(format!("{index})")),
+            IndexPrefix::SpacePadded => {
+                let width = int_log10(max_index.saturating_add(1));
+                Some(format!("{index:width$})"))
+            }
+            IndexPrefix::ZeroPadded => {
+                let width = int_log10(max_index.saturating_add(1));
+                Some(format!("{index:0width$})"))
+            }
+        }
+    }
+
+    fn print_option_index_prefix(&mut self, prefix: &str) -> Result<()> {
+        self.frame_renderer
+            .write_styled(Styled::new(prefix).with_style_sheet(self.render_config.option))?;
+        self.frame_renderer.write(" ")
+    }
+
+    fn print_default_value(&mut self, value: &str) -> Result<()> {
+        let content = format!("({value})");
+        let token = Styled::new(content).with_style_sheet(self.render_config.default_value);
+
+        self.frame_renderer.write_styled(token)
+    }
+
+    fn print_prompt_with_prefix(&mut self, prefix: Styled<&str>, prompt: &str) -> Result<()> {
+        self.frame_renderer.write_styled(prefix)?;
+
+        self.frame_renderer.write(" ")?;
+
+        self.frame_renderer
+            .write_styled(Styled::new(prompt).with_style_sheet(self.render_config.prompt))?;
+
+        Ok(())
+    }
+
+    fn print_prompt(&mut self, prompt: &str) -> Result<()> {
+        self.print_prompt_with_prefix(self.render_config.prompt_prefix, prompt)
+    }
+
+    fn print_input(&mut self, input: &Input) -> Result<()> {
+        self.print_input_with_style_sheet(input, self.render_config.text_input)
+    }
+
+    fn print_input_with_style_sheet(
+        &mut self,
+        input: &Input,
+        style_sheet: StyleSheet,
+    ) -> Result<()> {
+        self.frame_renderer.write(" ")?;
+
+        // The cursor is at the beginning of the input line.
+        // From here it's easier to mark the wanted cursor position
+        // (based on the underlying input struct), as it's a simple
+        // cur_pos + offset calculation.
+        self.frame_renderer
+            .mark_cursor_position(input.pre_cursor().width() as isize);
+
+        if input.is_empty() {
+            match input.placeholder() {
+                Some("") | None => {}
+                Some(p) => self.frame_renderer.write_styled(
+                    Styled::new(p).with_style_sheet(self.render_config.placeholder),
+                )?,
+            }
+        } else {
+            self.frame_renderer
+                .write_styled(Styled::new(input.content()).with_style_sheet(style_sheet))?;
+        }
+
+        // if cursor is at end of input, we need to add
+        // a space, otherwise the cursor will render on the
+        // \n character, on the next line.
+        if input.cursor() == input.length() {
+            self.frame_renderer.write(' ')?;
+        }
+
+        Ok(())
+    }
+
+    fn print_filter_count(&mut self, matched: usize, total: usize) -> Result<()> {
+        if let Some(stylesheet) = self.render_config.filter_count {
+            let count = format!("{}/{}", group_digits(matched), group_digits(total));
+
+            self.frame_renderer.write(" ")?;
+            self.frame_renderer
+                .write_styled(Styled::new(count).with_style_sheet(stylesheet))?;
+        }
+
+        Ok(())
+    }
+
+    fn print_range_field(&mut self, input: &Input, is_focused: bool) -> Result<()> {
+        if is_focused {
+            self.frame_renderer
+                .mark_cursor_position(input.pre_cursor().width() as isize);
+        }
+
+        if input.is_empty() {
+            match input.placeholder() {
+                Some("") | None => {}
+                Some(p) => self.frame_renderer.write_styled(
+                    Styled::new(p).with_style_sheet(self.render_config.placeholder),
+                )?,
+            }
+        } else {
+            self.frame_renderer.write_styled(
+                Styled::new(input.content()).with_style_sheet(self.render_config.text_input),
+            )?;
+        }
+
+        Ok(())
+    }
+
+    fn print_prompt_with_input(
+        &mut self,
+        prompt: &str,
+        default: Option<&str>,
+        input: &Input,
     ) -> Result<()> {
         self.print_prompt(prompt)?;
 
@@ -249,6 +1087,7 @@ where
     T: Terminal,
 {
     fn frame_setup(&mut self) -> Result<()> {
+        self.mouse_targets.clear();
         self.frame_renderer.start_frame()
     }
 
@@ -256,6 +1095,14 @@ where
         self.frame_renderer.finish_current_frame(is_last_frame)
     }
 
+    fn screen_size(&mut self) -> TerminalSize {
+        self.frame_renderer.terminal_size()
+    }
+
+    fn set_mouse_capture(&mut self, enabled: bool) -> Result<()> {
+        self.frame_renderer.set_mouse_capture(enabled)
+    }
+
     fn render_canceled_prompt(&mut self, prompt: &str) -> Result<()> {
         self.print_prompt(prompt)?;
 
@@ -330,8 +1177,68 @@ where
         prompt: &str,
         default: Option<&str>,
         cur_input: &Input,
+        details: &TextInputDetails<'_>,
     ) -> Result<()> {
-        self.print_prompt_with_input(prompt, default, cur_input)
+        self.print_prompt(prompt)?;
+
+        if let Some(mode) = details.mode {
+            self.frame_renderer.write(" ")?;
+            self.frame_renderer.write_styled(
+                Styled::new(format!("[{mode}]"))
+                    .with_style_sheet(self.render_config.mode_indicator),
+            )?;
+        }
+
+        if let Some(default) = default {
+            self.frame_renderer.write(" ")?;
+            self.print_default_value(default)?;
+        }
+
+        match details.ghost_text {
+            Some(ghost_text) => {
+                // the cursor rests on the first character of the ghost text
+                self.frame_renderer.write(" ")?;
+                self.frame_renderer
+                    .mark_cursor_position(cur_input.content().width() as isize);
+                self.frame_renderer.write_styled(
+                    Styled::new(cur_input.content())
+                        .with_style_sheet(self.render_config.text_input),
+                )?;
+                self.frame_renderer.write_styled(
+                    Styled::new(ghost_text).with_style_sheet(self.render_config.ghost_text),
+                )?;
+            }
+            None if details.flash => self.print_input_with_style_sheet(
+                cur_input,
+                self.render_config.error_message.message,
+            )?,
+            None => self.print_input(cur_input)?,
+        }
+
+        if let Some((length, max)) = details.length_counter {
+            let style_sheet = match length > max {
+                true => self.render_config.error_message.message,
+                false => self.render_config.length_counter,
+            };
+
+            self.frame_renderer.write(" ")?;
+            self.frame_renderer.write_styled(
+                Styled::new(format!("{length}/{max}")).with_style_sheet(style_sheet),
+            )?;
+        }
+
+        self.new_line()
+    }
+
+    fn render_values(&mut self, values: &[&str]) -> Result<()> {
+        for value in values {
+            self.frame_renderer.write(" ")?;
+            self.frame_renderer.write_styled(
+                Styled::new(format!("[{value}]")).with_style_sheet(self.render_config.answer),
+            )?;
+        }
+
+        self.new_line()
     }
 
     fn render_suggestions<D: Display>(&mut self, page: Page<'_, ListOption<D>>) -> Result<()> {
@@ -346,54 +1253,141 @@ where
 
         Ok(())
     }
+
+    fn render_dropdown<D: Display>(
+        &mut self,
+        page: Page<'_, ListOption<D>>,
+        fragment: &str,
+    ) -> Result<()> {
+        let values = page
+            .content
+            .iter()
+            .map(|option| option.value.to_string())
+            .collect::<Vec<String>>();
+
+        let width = self.screen_size().width() as usize;
+        let prefix_width = self.render_config.highlighted_option_prefix.content.width() + 1;
+        let widest = values.iter().map(|value| value.width()).max().unwrap_or(0);
+
+        // the dropdown is moved to the left when it would overflow the screen
+        let column = self
+            .frame_renderer
+            .expected_cursor_column()
+            .map(usize::from)
+            .unwrap_or(0)
+            .min(width.saturating_sub(prefix_width + widest + 1));
+        let max_width = width.saturating_sub(column + prefix_width + 1);
+
+        for (idx, value) in values.iter().enumerate() {
+            self.frame_renderer.write(" ".repeat(column))?;
+            self.print_option_prefix(idx, &page)?;
+            self.frame_renderer.write(" ")?;
+
+            let style = self.option_style(idx, &page);
+            let matched = matched_fragment(value, fragment);
+            self.print_matched_value(value, &matched, style, max_width)?;
+
+            self.new_line()?;
+        }
+
+        Ok(())
+    }
+
+    fn render_loading_indicator(&mut self, message: &str) -> Result<()> {
+        self.print_loading_indicator(message)
+    }
 }
 
-#[cfg(feature = "editor")]
-impl<'a, I, T> EditorBackend for Backend<'a, I, T>
+/// Indexes of the characters of the first occurrence of the fragment in the
+/// value, ignoring case, if any.
+fn matched_fragment(value: &str, fragment: &str) -> Vec<usize> {
+    let value = value.chars().collect::<Vec<char>>();
+    let fragment = fragment.chars().collect::<Vec<char>>();
+
+    if fragment.is_empty() || fragment.len() > value.len() {
+        return vec![];
+    }
+
+    (0..=value.len() - fragment.len())
+        .find(|start| {
+            value[*start..]
+                .iter()
+                .zip(&fragment)
+                .all(|(a, b)| a.to_lowercase().eq(b.to_lowercase()))
+        })
+        .map(|start| (start..start + fragment.len()).collect())
+        .unwrap_or_default()
+}
+
+impl<'a, I, T> TagsBackend for Backend<'a, I, T>
 where
     I: InputReader,
     T: Terminal,
 {
-    fn render_prompt(&mut self, prompt: &str, editor_command: &str) -> Result<()> {
+    fn render_tags_prompt(
+        &mut self,
+        prompt: &str,
+        tags: &[String],
+        cur_input: &Input,
+    ) -> Result<()> {
         self.print_prompt(prompt)?;
 
-        self.frame_renderer.write(" ")?;
-
-        let message = format!("[(e) to open {}, (enter) to submit]", editor_command);
-        let token = Styled::new(message).with_style_sheet(self.render_config.editor_prompt);
-        self.frame_renderer.write_styled(token)?;
-
-        self.new_line()?;
+        for tag in tags {
+            self.frame_renderer.write(" ")?;
+            self.frame_renderer.write_styled(
+                Styled::new(format!("[{tag}]")).with_style_sheet(self.render_config.answer),
+            )?;
+        }
 
-        Ok(())
+        self.print_input(cur_input)?;
+        self.new_line()
     }
 }
 
-impl<'a, I, T> SelectBackend for Backend<'a, I, T>
+impl<'a, I, T> TextAreaBackend for Backend<'a, I, T>
 where
     I: InputReader,
     T: Terminal,
 {
-    fn render_select_prompt(&mut self, prompt: &str, cur_input: Option<&Input>) -> Result<()> {
-        if let Some(input) = cur_input {
-            self.print_prompt_with_input(prompt, None, input)
-        } else {
-            self.print_prompt(prompt)
+    fn render_text_area_prompt(&mut self, prompt: &str, default: Option<&str>) -> Result<()> {
+        self.print_prompt(prompt)?;
+
+        if let Some(default) = default {
+            self.frame_renderer.write(" ")?;
+            self.print_default_value(default)?;
         }
+
+        self.new_line()
     }
 
-    fn render_options<D: Display>(&mut self, page: Page<'_, ListOption<D>>) -> Result<()> {
-        for (idx, option) in page.content.iter().enumerate() {
+    fn render_text_area_lines(
+        &mut self,
+        page: Page<'_, Input>,
+        highlights: &[LineHighlights],
+    ) -> Result<()> {
+        for (idx, line) in page.content.iter().enumerate() {
             self.print_option_prefix(idx, &page)?;
 
-            self.frame_renderer.write(" ")?;
-
-            if let Some(res) = self.print_option_index_prefix(option.index, page.total) {
-                res?;
+            if page.cursor == Some(idx) && line.is_empty() {
+                self.print_input(line)?;
+            } else {
                 self.frame_renderer.write(" ")?;
-            }
 
-            self.print_option_value(idx, option, &page)?;
+                if page.cursor == Some(idx) {
+                    self.frame_renderer
+                        .mark_cursor_position(line.pre_cursor().width() as isize);
+                }
+
+                let empty = LineHighlights::default();
+                let highlights = highlights.get(idx).unwrap_or(&empty);
+                self.print_highlighted_line(line.content(), highlights)?;
+
+                // same as text inputs, so that the cursor at the end of the
+                // line does not render on the next one
+                if page.cursor == Some(idx) && line.cursor() == line.length() {
+                    self.frame_renderer.write(' ')?;
+                }
+            }
 
             self.new_line()?;
         }
@@ -402,12 +1396,33 @@ where
     }
 }
 
-impl<'a, I, T> MultiSelectBackend for Backend<'a, I, T>
+#[cfg(feature = "editor")]
+impl<'a, I, T> EditorBackend for Backend<'a, I, T>
+where
+    I: InputReader,
+    T: Terminal,
+{
+    fn render_prompt(&mut self, prompt: &str, editor_command: &str) -> Result<()> {
+        self.print_prompt(prompt)?;
+
+        self.frame_renderer.write(" ")?;
+
+        let message = format!("[(e) to open {}, (enter) to submit]", editor_command);
+        let token = Styled::new(message).with_style_sheet(self.render_config.editor_prompt);
+        self.frame_renderer.write_styled(token)?;
+
+        self.new_line()?;
+
+        Ok(())
+    }
+}
+
+impl<'a, I, T> SelectBackend for Backend<'a, I, T>
 where
     I: InputReader,
     T: Terminal,
 {
-    fn render_multiselect_prompt(&mut self, prompt: &str, cur_input: Option<&Input>) -> Result<()> {
+    fn render_select_prompt(&mut self, prompt: &str, cur_input: Option<&Input>) -> Result<()> {
         if let Some(input) = cur_input {
             self.print_prompt_with_input(prompt, None, input)
         } else {
@@ -415,427 +1430,2587 @@ where
         }
     }
 
-    fn render_options<D: Display>(
+    fn render_select_header(
         &mut self,
-        page: Page<'_, ListOption<D>>,
-        checked: &BTreeSet<usize>,
+        prompt: &str,
+        cur_input: Option<&Input>,
+        filter_count: Option<(usize, usize)>,
+        sort_order: Option<&str>,
     ) -> Result<()> {
-        for (idx, option) in page.content.iter().enumerate() {
-            self.print_option_prefix(idx, &page)?;
-
-            self.frame_renderer.write(" ")?;
-
-            if let Some(res) = self.print_option_index_prefix(option.index, page.total) {
-                res?;
-                self.frame_renderer.write(" ")?;
-            }
-
-            let mut checkbox = match checked.contains(&option.index) {
-                true => self.render_config.selected_checkbox,
-                false => self.render_config.unselected_checkbox,
-            };
-
-            match (self.render_config.selected_option, page.cursor) {
-                (Some(stylesheet), Some(cursor)) if cursor == idx => checkbox.style = stylesheet,
-                _ => {}
-            }
-
-            self.frame_renderer.write_styled(checkbox)?;
+        self.print_prompt(prompt)?;
 
+        if let Some(input) = cur_input {
+            self.print_input(input)?;
+        }
+        if let Some((matched, total)) = filter_count {
+            self.print_filter_count(matched, total)?;
+        }
+        if let Some(label) = sort_order {
             self.frame_renderer.write(" ")?;
-
-            self.print_option_value(idx, option, &page)?;
-
-            self.new_line()?;
+            self.frame_renderer.write_styled(
+                Styled::new(format!("[{label}]"))
+                    .with_style_sheet(self.render_config.sort_indicator),
+            )?;
         }
 
-        Ok(())
+        self.new_line()
     }
-}
-
-#[cfg(feature = "date")]
-pub mod date {
-    use std::{io::Result, ops::Sub};
-
-    use chrono::{Datelike, Duration};
-
-    use crate::{
-        date_utils::get_start_date,
-        terminal::Terminal,
-        ui::{InputReader, Styled},
-    };
-
-    use super::{Backend, CommonBackend};
-
-    pub trait DateSelectBackend: CommonBackend {
-        fn render_calendar_prompt(&mut self, prompt: &str) -> Result<()>;
 
-        #[allow(clippy::too_many_arguments)]
-        fn render_calendar(
-            &mut self,
-            month: chrono::Month,
-            year: i32,
-            week_start: chrono::Weekday,
-            today: chrono::NaiveDate,
-            selected_date: chrono::NaiveDate,
-            min_date: Option<chrono::NaiveDate>,
-            max_date: Option<chrono::NaiveDate>,
-        ) -> Result<()>;
+    fn render_options<D: Display>(&mut self, page: Page<'_, ListOption<D>>) -> Result<()> {
+        self.render_options_with_details(page, &OptionDetails::default(), 1)
     }
 
-    impl<'a, I, T> DateSelectBackend for Backend<'a, I, T>
-    where
-        I: InputReader,
-        T: Terminal,
-    {
-        fn render_calendar_prompt(&mut self, prompt: &str) -> Result<()> {
-            self.print_prompt(prompt)?;
-            self.new_line()?;
-            Ok(())
+    fn render_options_with_details<D: Display>(
+        &mut self,
+        page: Page<'_, ListOption<D>>,
+        details: &OptionDetails<'_>,
+        columns: usize,
+    ) -> Result<()> {
+        self.print_scroll_indicator(&page, true)?;
+
+        if columns > 1 {
+            self.print_option_columns(&page, details, columns)?;
+            return self.print_scroll_indicator(&page, false);
         }
 
-        fn render_calendar(
-            &mut self,
-            month: chrono::Month,
-            year: i32,
-            week_start: chrono::Weekday,
-            today: chrono::NaiveDate,
-            selected_date: chrono::NaiveDate,
-            min_date: Option<chrono::NaiveDate>,
-            max_date: Option<chrono::NaiveDate>,
-        ) -> Result<()> {
-            macro_rules! write_prefix {
-                () => {{
-                    self.frame_renderer
-                        .write_styled(self.render_config.calendar.prefix)?;
-                    self.frame_renderer.write(" ")
-                }};
+        for (idx, option) in page.content.iter().enumerate() {
+            if idx > 0 && details.separators.contains(&option.index) {
+                self.frame_renderer
+                    .write_styled(self.render_config.option_separator)?;
+                self.new_line()?;
             }
 
-            // print header (month year)
-            let header = format!("{} {}", month.name().to_lowercase(), year);
-            let header = format!("{header:^20}");
-            let header = Styled::new(header).with_style_sheet(self.render_config.calendar.header);
-
-            write_prefix!()?;
+            if let Some(header) = details.group_header(idx, &page) {
+                let header = Styled::new(header).with_style_sheet(self.render_config.help_message);
+                self.frame_renderer.write_styled(header)?;
+                self.new_line()?;
+            }
 
-            self.frame_renderer.write_styled(header)?;
+            let start = self.frame_renderer.current_position();
 
-            self.new_line()?;
+            self.print_option_prefix(idx, &page)?;
 
-            // print week header
-            let mut current_weekday = week_start;
-            let mut week_days: Vec<String> = vec![];
-            for _ in 0..7 {
-                let mut formatted = format!("{current_weekday}");
-                formatted.make_ascii_lowercase();
-                formatted.pop();
-                week_days.push(formatted);
+            self.frame_renderer.write(" ")?;
 
-                current_weekday = current_weekday.succ();
+            let index_prefix = self.option_details_prefix(idx, &page, details);
+            if let Some(prefix) = &index_prefix {
+                self.print_option_index_prefix(prefix)?;
             }
 
-            let week_days = Styled::new(week_days.join(" "))
-                .with_style_sheet(self.render_config.calendar.week_header);
+            self.print_option_icon(details.icons.get(&option.index))?;
 
-            write_prefix!()?;
+            match details.disabled.get(&option.index) {
+                Some(reason) => self.print_disabled_option_value(option, *reason)?,
+                None => self.print_option_value_with_matches(idx, option, &page, details)?,
+            }
 
-            self.frame_renderer.write_styled(week_days)?;
             self.new_line()?;
 
-            // print dates
-            let mut date_it = get_start_date(month, year);
-            // first date of week-line is possibly in the previous month
-            if date_it.weekday() == week_start {
-                date_it = date_it.sub(
-                    Duration::try_weeks(1).expect("overflow when calculating duration of 1 week"),
-                );
-            } else {
-                while date_it.weekday() != week_start {
-                    date_it = match date_it.pred_opt() {
-                        Some(date) => date,
-                        None => break,
-                    };
-                }
+            if let Some(description) = details.descriptions.get(&option.index) {
+                let indent = self.option_value_indent(index_prefix.as_deref())
+                    + details.icon_width(option.index);
+                self.print_option_description(description, indent)?;
             }
 
-            for _ in 0..6 {
-                write_prefix!()?;
-
-                for i in 0..7 {
-                    if i > 0 {
-                        self.frame_renderer.write(" ")?;
-                    }
-
-                    let date = format!("{:2}", date_it.day());
-
-                    let cursor_offset = if date_it.day() < 10 { 1 } else { 0 };
-
-                    let mut style_sheet = crate::ui::StyleSheet::empty();
-
-                    if date_it == selected_date {
-                        self.frame_renderer.mark_cursor_position(cursor_offset);
-                        if let Some(custom_style_sheet) = self.render_config.calendar.selected_date
-                        {
-                            style_sheet = custom_style_sheet;
-                        }
-                    } else if date_it == today {
-                        style_sheet = self.render_config.calendar.today_date;
-                    } else if date_it.month() != month.number_from_month() {
-                        style_sheet = self.render_config.calendar.different_month_date;
-                    }
-
-                    if let Some(min_date) = min_date {
-                        if date_it < min_date {
-                            style_sheet = self.render_config.calendar.unavailable_date;
-                        }
-                    }
-
-                    if let Some(max_date) = max_date {
-                        if date_it > max_date {
-                            style_sheet = self.render_config.calendar.unavailable_date;
-                        }
-                    }
+            self.push_mouse_target(start, idx, None);
+        }
 
-                    let token = Styled::new(date).with_style_sheet(style_sheet);
-                    self.frame_renderer.write_styled(token)?;
+        self.print_scroll_indicator(&page, false)
+    }
 
-                    date_it = date_it.succ_opt().unwrap_or(date_it);
-                }
+    fn render_preview(&mut self, preview: &str, height: usize) -> Result<()> {
+        self.print_preview(preview, height)
+    }
 
-                self.new_line()?;
-            }
+    fn render_loading_indicator(&mut self, message: &str) -> Result<()> {
+        self.print_loading_indicator(message)
+    }
 
-            Ok(())
-        }
+    fn scroll_indicators_height(&self) -> usize {
+        2 * usize::from(self.render_config.scroll_indicator.is_some())
     }
 }
 
-impl<'a, I, T> CustomTypeBackend for Backend<'a, I, T>
+impl<'a, I, T> FuzzyFinderBackend for Backend<'a, I, T>
 where
     I: InputReader,
     T: Terminal,
 {
-    fn render_prompt(
+    fn enter_full_screen(&mut self) -> Result<()> {
+        self.frame_renderer.enter_alternate_screen()
+    }
+
+    fn render_fuzzy_finder_prompt(
         &mut self,
         prompt: &str,
-        default: Option<&str>,
         cur_input: &Input,
+        matched: usize,
+        total: usize,
     ) -> Result<()> {
-        self.print_prompt_with_input(prompt, default, cur_input)
+        self.print_prompt_with_input(prompt, None, cur_input)?;
+
+        let counter = Styled::new(format!("  {matched}/{total}"))
+            .with_style_sheet(self.render_config.help_message);
+        self.frame_renderer.write_styled(counter)?;
+        self.new_line()
+    }
+
+    fn render_matched_options<D: Display>(
+        &mut self,
+        page: Page<'_, ListOption<D>>,
+        matched_chars: &[Vec<usize>],
+    ) -> Result<()> {
+        // options longer than the screen would wrap and push the rest of
+        // the frame out of the screen, so they are truncated instead
+        let max_width = (self.screen_size().width() as usize).saturating_sub(3);
+
+        for (idx, option) in page.content.iter().enumerate() {
+            self.print_option_prefix(idx, &page)?;
+            self.frame_renderer.write(" ")?;
+
+            let style = match self.render_config.selected_option {
+                Some(selected_option) if page.cursor == Some(idx) => selected_option,
+                _ => self.render_config.option,
+            };
+            let matched = matched_chars
+                .get(idx)
+                .map(Vec::as_slice)
+                .unwrap_or_default();
+
+            self.print_matched_value(&option.value.to_string(), matched, style, max_width)?;
+
+            self.new_line()?;
+        }
+
+        Ok(())
+    }
+
+    fn render_preview(&mut self, preview: &str, height: usize) -> Result<()> {
+        self.print_preview(preview, height)
     }
 }
 
-impl<'a, I, T> PasswordBackend for Backend<'a, I, T>
+impl<'a, I, T> CommandPaletteBackend for Backend<'a, I, T>
 where
     I: InputReader,
     T: Terminal,
 {
-    fn render_prompt(&mut self, prompt: &str) -> Result<()> {
-        self.print_prompt(prompt)?;
+    fn render_command_palette_prompt(&mut self, prompt: &str, cur_input: &Input) -> Result<()> {
+        self.print_prompt_with_input(prompt, None, cur_input)
+    }
+
+    fn render_commands<D: Display>(
+        &mut self,
+        page: Page<'_, ListOption<D>>,
+        headers: &[Option<&str>],
+        shortcuts: &[Option<&str>],
+        matched_chars: &[Vec<usize>],
+    ) -> Result<()> {
+        let max_width = (self.screen_size().width() as usize).saturating_sub(3);
+
+        for (idx, option) in page.content.iter().enumerate() {
+            if let Some(header) = headers.get(idx).copied().flatten() {
+                let header = Styled::new(header).with_style_sheet(self.render_config.help_message);
+                self.frame_renderer.write_styled(header)?;
+                self.new_line()?;
+            }
+
+            self.print_option_prefix(idx, &page)?;
+            self.frame_renderer.write(" ")?;
+
+            let style = match self.render_config.selected_option {
+                Some(selected_option) if page.cursor == Some(idx) => selected_option,
+                _ => self.render_config.option,
+            };
+            let matched = matched_chars
+                .get(idx)
+                .map(Vec::as_slice)
+                .unwrap_or_default();
+
+            self.print_matched_value(&option.value.to_string(), matched, style, max_width)?;
+
+            if let Some(shortcut) = shortcuts.get(idx).copied().flatten() {
+                self.frame_renderer.write("  ")?;
+                let shortcut =
+                    Styled::new(shortcut).with_style_sheet(self.render_config.help_message);
+                self.frame_renderer.write_styled(shortcut)?;
+            }
+
+            self.new_line()?;
+        }
+
+        Ok(())
+    }
+}
+
+impl<'a, I, T> GridSelectBackend for Backend<'a, I, T>
+where
+    I: InputReader,
+    T: Terminal,
+{
+    fn render_grid_select_prompt(&mut self, prompt: &str) -> Result<()> {
+        self.print_prompt(prompt)?;
+        self.new_line()
+    }
+
+    fn render_grid<D: Display>(
+        &mut self,
+        page: Page<'_, ListOption<D>>,
+        columns: usize,
+        label_width: usize,
+    ) -> Result<()> {
+        let prefix_width = self.render_config.highlighted_option_prefix.content.width();
+        let row_count = (page.content.len() + columns - 1) / columns;
+
+        for (row_idx, row) in page.content.chunks(columns).enumerate() {
+            let row_prefix = if row_idx == 0 && !page.first {
+                self.render_config.scroll_up_prefix
+            } else if row_idx + 1 == row_count && !page.last {
+                self.render_config.scroll_down_prefix
+            } else {
+                Styled::new(" ")
+            };
+            self.frame_renderer.write_styled(row_prefix)?;
+
+            for (col_idx, option) in row.iter().enumerate() {
+                let is_highlighted = page.cursor == Some(row_idx * columns + col_idx);
+
+                self.frame_renderer.write(" ")?;
+                if is_highlighted {
+                    self.frame_renderer
+                        .write_styled(self.render_config.highlighted_option_prefix)?;
+                } else {
+                    self.frame_renderer.write(" ".repeat(prefix_width))?;
+                }
+                self.frame_renderer.write(" ")?;
+
+                let stylesheet = match self.render_config.selected_option {
+                    Some(selected_option) if is_highlighted => selected_option,
+                    _ => self.render_config.option,
+                };
+
+                let label = option.value.to_string();
+                let padding = label_width.saturating_sub(label.width());
+                self.frame_renderer
+                    .write_styled(Styled::new(label).with_style_sheet(stylesheet))?;
+
+                // the last column is not padded, avoiding trailing whitespace
+                if col_idx + 1 < row.len() {
+                    self.frame_renderer.write(" ".repeat(padding))?;
+                }
+            }
+
+            self.new_line()?;
+        }
+
+        Ok(())
+    }
+}
+
+impl<'a, I, T> CheckboxMatrixBackend for Backend<'a, I, T>
+where
+    I: InputReader,
+    T: Terminal,
+{
+    fn render_checkbox_matrix_prompt(&mut self, prompt: &str) -> Result<()> {
+        self.print_prompt(prompt)?;
+        self.new_line()
+    }
+
+    fn render_checkbox_matrix<D: Display>(
+        &mut self,
+        rows: Page<'_, ListOption<D>>,
+        columns: &[String],
+        checked: &BTreeSet<(usize, usize)>,
+        cursor_column: usize,
+    ) -> Result<()> {
+        let prefix_width = self.render_config.highlighted_option_prefix.content.width();
+        let checkbox_width = self
+            .render_config
+            .selected_checkbox
+            .content
+            .width()
+            .max(self.render_config.unselected_checkbox.content.width());
+
+        let labels = rows
+            .content
+            .iter()
+            .map(|row| row.value.to_string())
+            .collect::<Vec<String>>();
+        let label_width = labels.iter().map(|l| l.width()).max().unwrap_or_default();
+        let cell_widths = columns
+            .iter()
+            .map(|column| column.width().max(checkbox_width))
+            .collect::<Vec<usize>>();
+
+        // the header is aligned with the checkboxes, skipping the row prefix and labels
+        self.frame_renderer
+            .write(" ".repeat(prefix_width + 1 + label_width))?;
+        for (idx, column) in columns.iter().enumerate() {
+            self.frame_renderer.write(" ")?;
+            self.frame_renderer.write_styled(
+                Styled::new(column.as_str()).with_style_sheet(self.render_config.option),
+            )?;
+
+            if idx + 1 < columns.len() {
+                let padding = cell_widths[idx].saturating_sub(column.width());
+                self.frame_renderer.write(" ".repeat(padding))?;
+            }
+        }
         self.new_line()?;
+
+        for (idx, (row, label)) in rows.content.iter().zip(labels.iter()).enumerate() {
+            let is_cursor_row = rows.cursor == Some(idx);
+
+            self.print_option_prefix(idx, &rows)?;
+            self.frame_renderer.write(" ")?;
+
+            let stylesheet = match self.render_config.selected_option {
+                Some(selected_option) if is_cursor_row => selected_option,
+                _ => self.render_config.option,
+            };
+            self.frame_renderer
+                .write_styled(Styled::new(label.as_str()).with_style_sheet(stylesheet))?;
+            self.frame_renderer
+                .write(" ".repeat(label_width.saturating_sub(label.width())))?;
+
+            for (column, cell_width) in cell_widths.iter().enumerate() {
+                self.frame_renderer.write(" ")?;
+
+                let mut checkbox = match checked.contains(&(row.index, column)) {
+                    true => self.render_config.selected_checkbox,
+                    false => self.render_config.unselected_checkbox,
+                };
+
+                match self.render_config.selected_option {
+                    Some(stylesheet) if is_cursor_row && column == cursor_column => {
+                        checkbox.style = stylesheet;
+                    }
+                    _ => {}
+                }
+
+                let padding = cell_width.saturating_sub(checkbox.content.width());
+                self.frame_renderer.write_styled(checkbox)?;
+
+                // the last column is not padded, avoiding trailing whitespace
+                if column + 1 < cell_widths.len() {
+                    self.frame_renderer.write(" ".repeat(padding))?;
+                }
+            }
+
+            self.new_line()?;
+        }
+
         Ok(())
     }
+}
 
-    fn render_prompt_with_masked_input(&mut self, prompt: &str, cur_input: &Input) -> Result<()> {
-        let masked_string: String = (0..cur_input.length())
-            .map(|_| self.render_config.password_mask)
-            .collect();
+impl<'a, I, T> FileSelectBackend for Backend<'a, I, T>
+where
+    I: InputReader,
+    T: Terminal,
+{
+    fn render_current_directory(&mut self, directory: &Path) -> Result<()> {
+        let token = Styled::new(directory.display().to_string())
+            .with_style_sheet(self.render_config.default_value);
 
-        let masked_input = Input::new_with(masked_string).with_cursor(cur_input.cursor());
+        self.frame_renderer.write("  ")?;
+        self.frame_renderer.write_styled(token)?;
+        self.new_line()
+    }
+}
 
-        self.print_prompt_with_input(prompt, None, &masked_input)
+impl<'a, I, T> TableSelectBackend for Backend<'a, I, T>
+where
+    I: InputReader,
+    T: Terminal,
+{
+    fn render_table_header(&mut self, header: &str, total: usize) -> Result<()> {
+        // aligns the header with the values of the options, which
+        // are rendered after the option and index prefixes
+        let mut offset = self.render_config.highlighted_option_prefix.content.width() + 1;
+
+        if let Some(prefix) = self.option_index_prefix(total.saturating_sub(1), total) {
+            offset += prefix.width() + 1;
+        }
+
+        self.frame_renderer.write(" ".repeat(offset))?;
+        self.frame_renderer
+            .write_styled(Styled::new(header).with_style_sheet(self.render_config.default_value))?;
+        self.new_line()
     }
+}
 
-    fn render_prompt_with_full_input(&mut self, prompt: &str, cur_input: &Input) -> Result<()> {
-        self.print_prompt_with_input(prompt, None, cur_input)
+impl<'a, I, T> TableEditorBackend for Backend<'a, I, T>
+where
+    I: InputReader,
+    T: Terminal,
+{
+    fn render_table_editor_prompt(&mut self, prompt: &str) -> Result<()> {
+        self.print_prompt(prompt)?;
+        self.new_line()
+    }
+
+    fn render_table_editor(
+        &mut self,
+        columns: &[&str],
+        rows: Page<'_, ListOption<&Vec<String>>>,
+        cursor_column: usize,
+        input: &Input,
+    ) -> Result<()> {
+        const COLUMN_SEPARATOR: &str = "  ";
+
+        let prefix_width = self.render_config.highlighted_option_prefix.content.width();
+
+        let widths = columns
+            .iter()
+            .enumerate()
+            .map(|(column, name)| {
+                rows.content
+                    .iter()
+                    .map(|row| row.value[column].width())
+                    .fold(name.width(), usize::max)
+            })
+            .collect::<Vec<usize>>();
+
+        // the header is aligned with the cells, skipping the row prefix
+        self.frame_renderer.write(" ".repeat(prefix_width + 1))?;
+        for (column, name) in columns.iter().enumerate() {
+            if column > 0 {
+                self.frame_renderer.write(COLUMN_SEPARATOR)?;
+            }
+
+            self.frame_renderer.write_styled(
+                Styled::new(*name).with_style_sheet(self.render_config.default_value),
+            )?;
+
+            if column + 1 < columns.len() {
+                let padding = widths[column].saturating_sub(name.width());
+                self.frame_renderer.write(" ".repeat(padding))?;
+            }
+        }
+        self.new_line()?;
+
+        for (idx, row) in rows.content.iter().enumerate() {
+            let is_cursor_row = rows.cursor == Some(idx);
+
+            self.print_option_prefix(idx, &rows)?;
+            self.frame_renderer.write(" ")?;
+
+            for (column, cell) in row.value.iter().enumerate() {
+                if column > 0 {
+                    self.frame_renderer.write(COLUMN_SEPARATOR)?;
+                }
+
+                let stylesheet = match self.render_config.selected_option {
+                    _ if is_cursor_row && column == cursor_column => {
+                        self.frame_renderer
+                            .mark_cursor_position(input.pre_cursor().width() as isize);
+                        self.render_config.text_input
+                    }
+                    Some(selected_option) if is_cursor_row => selected_option,
+                    _ => self.render_config.option,
+                };
+                self.frame_renderer
+                    .write_styled(Styled::new(cell.as_str()).with_style_sheet(stylesheet))?;
+
+                // the last column is not padded, avoiding trailing whitespace
+                if column + 1 < widths.len() {
+                    let padding = widths[column].saturating_sub(cell.width());
+                    self.frame_renderer.write(" ".repeat(padding))?;
+                }
+            }
+
+            self.new_line()?;
+        }
+
+        Ok(())
+    }
+}
+
+impl<'a, I, T> TreeSelectBackend for Backend<'a, I, T>
+where
+    I: InputReader,
+    T: Terminal,
+{
+    fn render_tree_select_prompt(&mut self, prompt: &str) -> Result<()> {
+        self.print_prompt(prompt)?;
+        self.new_line()
+    }
+
+    fn render_tree_nodes<D: Display>(
+        &mut self,
+        page: Page<'_, ListOption<D>>,
+        guides: &[String],
+    ) -> Result<()> {
+        for (idx, option) in page.content.iter().enumerate() {
+            self.print_option_prefix(idx, &page)?;
+
+            self.frame_renderer.write(" ")?;
+
+            if let Some(guide) = guides.get(idx) {
+                self.frame_renderer.write(guide)?;
+            }
+
+            self.print_option_value(idx, option, &page)?;
+
+            self.new_line()?;
+        }
+
+        Ok(())
+    }
+}
+
+impl<'a, I, T> MenuBackend for Backend<'a, I, T>
+where
+    I: InputReader,
+    T: Terminal,
+{
+    fn render_menu_prompt(&mut self, prompt: &str, breadcrumb: &[String]) -> Result<()> {
+        self.print_prompt(prompt)?;
+
+        if !breadcrumb.is_empty() {
+            self.frame_renderer.write(" ")?;
+            self.frame_renderer.write_styled(
+                Styled::new(breadcrumb.join(" › "))
+                    .with_style_sheet(self.render_config.default_value),
+            )?;
+        }
+
+        self.new_line()
+    }
+
+    fn render_menu_entries<D: Display>(
+        &mut self,
+        page: Page<'_, ListOption<D>>,
+        submenus: &[bool],
+    ) -> Result<()> {
+        for (idx, option) in page.content.iter().enumerate() {
+            self.print_option_prefix(idx, &page)?;
+
+            self.frame_renderer.write(" ")?;
+            self.print_option_value(idx, option, &page)?;
+
+            if submenus.get(idx).copied().unwrap_or_default() {
+                self.frame_renderer.write(" ›")?;
+            }
+
+            self.new_line()?;
+        }
+
+        Ok(())
     }
 }
 
-impl<'a, I, T> InputReader for Backend<'a, I, T>
-where
-    I: InputReader,
-    T: Terminal,
-{
-    fn read_key(&mut self) -> InquireResult<Key> {
-        self.input_reader.read_key()
+impl<'a, I, T> MultiSelectBackend for Backend<'a, I, T>
+where
+    I: InputReader,
+    T: Terminal,
+{
+    fn render_multiselect_prompt(
+        &mut self,
+        prompt: &str,
+        cur_input: Option<&Input>,
+        selected: usize,
+        matched: Option<usize>,
+        total: usize,
+    ) -> Result<()> {
+        self.print_prompt(prompt)?;
+
+        if let Some(stylesheet) = self.render_config.selection_counter {
+            let counter = format!("{selected}/{total} selected");
+
+            self.frame_renderer.write(" ")?;
+            self.frame_renderer
+                .write_styled(Styled::new(counter).with_style_sheet(stylesheet))?;
+        }
+
+        if let Some(input) = cur_input {
+            self.print_input(input)?;
+
+            if let Some(matched) = matched {
+                self.print_filter_count(matched, total)?;
+            }
+
+            self.new_line()?;
+        }
+
+        Ok(())
+    }
+
+    fn render_options<D: Display>(
+        &mut self,
+        page: Page<'_, ListOption<D>>,
+        checked: &BTreeSet<usize>,
+        details: &OptionDetails<'_>,
+    ) -> Result<()> {
+        self.print_scroll_indicator(&page, true)?;
+
+        for (idx, option) in page.content.iter().enumerate() {
+            if idx > 0 && details.separators.contains(&option.index) {
+                self.frame_renderer
+                    .write_styled(self.render_config.option_separator)?;
+                self.new_line()?;
+            }
+
+            if let Some(header) = details.group_header(idx, &page) {
+                if details.group_checkboxes {
+                    let members = details.group_members(header).collect::<Vec<usize>>();
+                    let selected = members.iter().filter(|i| checked.contains(i)).count();
+
+                    let checkbox = match selected {
+                        0 => self.render_config.unselected_checkbox,
+                        s if s == members.len() => self.render_config.selected_checkbox,
+                        _ => self.render_config.partial_checkbox,
+                    };
+
+                    self.frame_renderer.write_styled(checkbox)?;
+                    self.frame_renderer.write(" ")?;
+                }
+
+                let header = Styled::new(header).with_style_sheet(self.render_config.help_message);
+                self.frame_renderer.write_styled(header)?;
+                self.new_line()?;
+            }
+
+            let start = self.frame_renderer.current_position();
+
+            self.print_option_prefix(idx, &page)?;
+
+            self.frame_renderer.write(" ")?;
+
+            let index_prefix = self.option_details_prefix(idx, &page, details);
+            if let Some(prefix) = &index_prefix {
+                self.print_option_index_prefix(prefix)?;
+            }
+
+            let mut checkbox = match checked.contains(&option.index) {
+                _ if details.locked.contains(&option.index) => self.render_config.locked_checkbox,
+                true => self.render_config.selected_checkbox,
+                false => self.render_config.unselected_checkbox,
+            };
+
+            let reason = details.disabled.get(&option.index);
+
+            match (self.render_config.selected_option, page.cursor) {
+                _ if reason.is_some() => checkbox.style = self.render_config.disabled_option,
+                (Some(stylesheet), Some(cursor)) if cursor == idx => checkbox.style = stylesheet,
+                _ => {}
+            }
+
+            let checkbox_start = self.frame_renderer.current_position().col;
+            self.frame_renderer.write_styled(checkbox)?;
+            let checkbox_columns = checkbox_start..self.frame_renderer.current_position().col;
+
+            self.frame_renderer.write(" ")?;
+
+            self.print_option_icon(details.icons.get(&option.index))?;
+
+            match reason {
+                Some(reason) => self.print_disabled_option_value(option, *reason)?,
+                None => self.print_option_value_with_matches(idx, option, &page, details)?,
+            }
+
+            self.new_line()?;
+
+            if let Some(description) = details.descriptions.get(&option.index) {
+                let indent = self.option_value_indent(index_prefix.as_deref())
+                    + checkbox.content.width()
+                    + 1
+                    + details.icon_width(option.index);
+                self.print_option_description(description, indent)?;
+            }
+
+            self.push_mouse_target(start, idx, Some(checkbox_columns));
+        }
+
+        self.print_scroll_indicator(&page, false)
+    }
+
+    fn render_loading_indicator(&mut self, message: &str) -> Result<()> {
+        self.print_loading_indicator(message)
+    }
+
+    fn scroll_indicators_height(&self) -> usize {
+        2 * usize::from(self.render_config.scroll_indicator.is_some())
+    }
+}
+
+impl<'a, I, T> TransferBackend for Backend<'a, I, T>
+where
+    I: InputReader,
+    T: Terminal,
+{
+    fn render_transfer_prompt(&mut self, prompt: &str) -> Result<()> {
+        self.print_prompt(prompt)?;
+        self.new_line()
+    }
+
+    fn render_transfer_panes<D: Display>(
+        &mut self,
+        titles: (&str, &str),
+        available: Page<'_, ListOption<D>>,
+        chosen: Page<'_, ListOption<D>>,
+        pane_width: usize,
+    ) -> Result<()> {
+        // the option prefix and the space after it
+        let prefix_width = self.render_config.highlighted_option_prefix.content.width() + 1;
+        let column_width = prefix_width + pane_width;
+
+        let panes = [(titles.0, &available), (titles.1, &chosen)];
+
+        for (idx, (title, page)) in panes.iter().enumerate() {
+            let title = format!("{} ({})", title, page.total);
+            let style = match page.cursor {
+                Some(_) => self.render_config.prompt,
+                None => self.render_config.default_value,
+            };
+
+            self.frame_renderer.write(" ".repeat(prefix_width))?;
+            self.frame_renderer
+                .write_styled(Styled::new(&title).with_style_sheet(style))?;
+
+            if idx == 0 {
+                let padding = pane_width.saturating_sub(title.width());
+                self.frame_renderer.write(" ".repeat(padding))?;
+                self.frame_renderer.write(" │ ")?;
+            }
+        }
+
+        self.new_line()?;
+
+        let rows = available.content.len().max(chosen.content.len());
+
+        for row in 0..rows {
+            match available.content.get(row) {
+                Some(option) => {
+                    self.print_option_prefix(row, &available)?;
+                    self.frame_renderer.write(" ")?;
+                    self.print_option_value(row, option, &available)?;
+
+                    let padding = pane_width.saturating_sub(option.value.to_string().width());
+                    self.frame_renderer.write(" ".repeat(padding))?;
+                }
+                None => self.frame_renderer.write(" ".repeat(column_width))?,
+            }
+
+            self.frame_renderer.write(" │ ")?;
+
+            if let Some(option) = chosen.content.get(row) {
+                self.print_option_prefix(row, &chosen)?;
+                self.frame_renderer.write(" ")?;
+                self.print_option_value(row, option, &chosen)?;
+            }
+
+            self.new_line()?;
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(feature = "date")]
+pub mod date {
+    use std::{
+        io::Result,
+        ops::{Range, RangeInclusive, Sub},
+    };
+
+    use chrono::{Datelike, Duration};
+
+    use crate::{
+        date_utils::{get_month, get_start_date},
+        terminal::Terminal,
+        ui::{InputReader, Styled},
+    };
+
+    use super::{Backend, CommonBackend};
+
+    pub trait DateSelectBackend: CommonBackend {
+        fn render_calendar_prompt(&mut self, prompt: &str) -> Result<()>;
+
+        #[allow(clippy::too_many_arguments)]
+        fn render_calendar(
+            &mut self,
+            month: chrono::Month,
+            year: i32,
+            week_start: chrono::Weekday,
+            today: chrono::NaiveDate,
+            selected_date: chrono::NaiveDate,
+            min_date: Option<chrono::NaiveDate>,
+            max_date: Option<chrono::NaiveDate>,
+        ) -> Result<()>;
+    }
+
+    pub trait TimeSelectBackend: CommonBackend {
+        fn render_time_prompt(
+            &mut self,
+            prompt: &str,
+            time: &str,
+            selected_field: Range<usize>,
+        ) -> Result<()>;
+    }
+
+    pub trait DateTimeSelectBackend: DateSelectBackend {
+        fn render_time(&mut self, time: &str, selected_field: Option<Range<usize>>) -> Result<()>;
+    }
+
+    pub trait WeekSelectBackend: CommonBackend {
+        fn render_week_prompt(&mut self, prompt: &str) -> Result<()>;
+
+        fn render_week_calendar(
+            &mut self,
+            month: chrono::Month,
+            year: i32,
+            today: chrono::NaiveDate,
+            selected_week: chrono::IsoWeek,
+            min_date: Option<chrono::NaiveDate>,
+            max_date: Option<chrono::NaiveDate>,
+        ) -> Result<()>;
+    }
+
+    pub trait MonthSelectBackend: CommonBackend {
+        fn render_month_prompt(&mut self, prompt: &str) -> Result<()>;
+
+        fn render_months(
+            &mut self,
+            selected_month: chrono::NaiveDate,
+            current_month: chrono::NaiveDate,
+            min_month: Option<chrono::NaiveDate>,
+            max_month: Option<chrono::NaiveDate>,
+        ) -> Result<()>;
+    }
+
+    impl<'a, I, T> DateSelectBackend for Backend<'a, I, T>
+    where
+        I: InputReader,
+        T: Terminal,
+    {
+        fn render_calendar_prompt(&mut self, prompt: &str) -> Result<()> {
+            self.print_prompt(prompt)?;
+            self.new_line()?;
+            Ok(())
+        }
+
+        fn render_calendar(
+            &mut self,
+            month: chrono::Month,
+            year: i32,
+            week_start: chrono::Weekday,
+            today: chrono::NaiveDate,
+            selected_date: chrono::NaiveDate,
+            min_date: Option<chrono::NaiveDate>,
+            max_date: Option<chrono::NaiveDate>,
+        ) -> Result<()> {
+            self.print_calendar(
+                month,
+                year,
+                week_start,
+                today,
+                selected_date..=selected_date,
+                min_date,
+                max_date,
+                false,
+            )
+        }
+    }
+
+    impl<'a, I, T> Backend<'a, I, T>
+    where
+        I: InputReader,
+        T: Terminal,
+    {
+        /// Prints the calendar of the given month, highlighting the selected
+        /// range of dates and marking the cursor position at its start.
+        ///
+        /// When `week_numbers` is set, each week line is preceded by its ISO
+        /// week number.
+        #[allow(clippy::too_many_arguments)]
+        fn print_calendar(
+            &mut self,
+            month: chrono::Month,
+            year: i32,
+            week_start: chrono::Weekday,
+            today: chrono::NaiveDate,
+            selected: RangeInclusive<chrono::NaiveDate>,
+            min_date: Option<chrono::NaiveDate>,
+            max_date: Option<chrono::NaiveDate>,
+            week_numbers: bool,
+        ) -> Result<()> {
+            macro_rules! write_prefix {
+                () => {{
+                    self.frame_renderer
+                        .write_styled(self.render_config.calendar.prefix)?;
+                    self.frame_renderer.write(" ")
+                }};
+            }
+
+            let week_number_width = if week_numbers { 3 } else { 0 };
+
+            // print header (month year)
+            let header = format!("{} {}", month.name().to_lowercase(), year);
+            let header = format!("{header:^width$}", width = 20 + week_number_width);
+            let header = Styled::new(header).with_style_sheet(self.render_config.calendar.header);
+
+            write_prefix!()?;
+
+            self.frame_renderer.write_styled(header)?;
+
+            self.new_line()?;
+
+            // print week header
+            let mut current_weekday = week_start;
+            let mut week_days: Vec<String> = vec![];
+            if week_numbers {
+                week_days.push(String::from("wk"));
+            }
+            for _ in 0..7 {
+                let mut formatted = format!("{current_weekday}");
+                formatted.make_ascii_lowercase();
+                formatted.pop();
+                week_days.push(formatted);
+
+                current_weekday = current_weekday.succ();
+            }
+
+            let week_days = Styled::new(week_days.join(" "))
+                .with_style_sheet(self.render_config.calendar.week_header);
+
+            write_prefix!()?;
+
+            self.frame_renderer.write_styled(week_days)?;
+            self.new_line()?;
+
+            // print dates
+            let mut date_it = get_start_date(month, year);
+            // first date of week-line is possibly in the previous month
+            if date_it.weekday() == week_start {
+                date_it = date_it.sub(
+                    Duration::try_weeks(1).expect("overflow when calculating duration of 1 week"),
+                );
+            } else {
+                while date_it.weekday() != week_start {
+                    date_it = match date_it.pred_opt() {
+                        Some(date) => date,
+                        None => break,
+                    };
+                }
+            }
+
+            let selected_style_sheet = self.render_config.calendar.selected_date;
+
+            for _ in 0..6 {
+                write_prefix!()?;
+
+                if week_numbers {
+                    let week_number = format!("{:2} ", date_it.iso_week().week());
+                    let token = Styled::new(week_number)
+                        .with_style_sheet(self.render_config.calendar.week_header);
+                    self.frame_renderer.write_styled(token)?;
+                }
+
+                for i in 0..7 {
+                    if i > 0 {
+                        // the gap between two selected dates is part of the highlighted range
+                        let in_selection =
+                            selected.contains(&date_it) && date_it != *selected.start();
+                        match (in_selection, selected_style_sheet) {
+                            (true, Some(style_sheet)) => self
+                                .frame_renderer
+                                .write_styled(Styled::new(" ").with_style_sheet(style_sheet))?,
+                            _ => self.frame_renderer.write(" ")?,
+                        }
+                    }
+
+                    let date = format!("{:2}", date_it.day());
+
+                    let cursor_offset = if date_it.day() < 10 { 1 } else { 0 };
+
+                    let mut style_sheet = crate::ui::StyleSheet::empty();
+
+                    if selected.contains(&date_it) {
+                        if date_it == *selected.start() {
+                            self.frame_renderer.mark_cursor_position(cursor_offset);
+                        }
+                        if let Some(custom_style_sheet) = selected_style_sheet {
+                            style_sheet = custom_style_sheet;
+                        }
+                    } else if date_it == today {
+                        style_sheet = self.render_config.calendar.today_date;
+                    } else if date_it.month() != month.number_from_month() {
+                        style_sheet = self.render_config.calendar.different_month_date;
+                    }
+
+                    if let Some(min_date) = min_date {
+                        if date_it < min_date {
+                            style_sheet = self.render_config.calendar.unavailable_date;
+                        }
+                    }
+
+                    if let Some(max_date) = max_date {
+                        if date_it > max_date {
+                            style_sheet = self.render_config.calendar.unavailable_date;
+                        }
+                    }
+
+                    let token = Styled::new(date).with_style_sheet(style_sheet);
+                    self.frame_renderer.write_styled(token)?;
+
+                    date_it = date_it.succ_opt().unwrap_or(date_it);
+                }
+
+                self.new_line()?;
+            }
+
+            Ok(())
+        }
+
+        fn print_time(&mut self, time: &str, selected_field: Option<Range<usize>>) -> Result<()> {
+            let selected_field = match selected_field {
+                Some(selected_field) => selected_field,
+                None => {
+                    return self.frame_renderer.write_styled(
+                        Styled::new(time).with_style_sheet(self.render_config.text_input),
+                    )
+                }
+            };
+
+            let (before, rest) = time.split_at(selected_field.start);
+            let (selected, after) = rest.split_at(selected_field.len());
+
+            self.frame_renderer.write_styled(
+                Styled::new(before).with_style_sheet(self.render_config.text_input),
+            )?;
+
+            self.frame_renderer.mark_cursor_position(0);
+            let style_sheet = self
+                .render_config
+                .calendar
+                .selected_date
+                .unwrap_or(self.render_config.text_input);
+            self.frame_renderer
+                .write_styled(Styled::new(selected).with_style_sheet(style_sheet))?;
+
+            self.frame_renderer
+                .write_styled(Styled::new(after).with_style_sheet(self.render_config.text_input))
+        }
+    }
+
+    impl<'a, I, T> TimeSelectBackend for Backend<'a, I, T>
+    where
+        I: InputReader,
+        T: Terminal,
+    {
+        fn render_time_prompt(
+            &mut self,
+            prompt: &str,
+            time: &str,
+            selected_field: Range<usize>,
+        ) -> Result<()> {
+            self.print_prompt(prompt)?;
+            self.frame_renderer.write(" ")?;
+            self.print_time(time, Some(selected_field))?;
+            self.new_line()?;
+            Ok(())
+        }
+    }
+
+    impl<'a, I, T> DateTimeSelectBackend for Backend<'a, I, T>
+    where
+        I: InputReader,
+        T: Terminal,
+    {
+        fn render_time(&mut self, time: &str, selected_field: Option<Range<usize>>) -> Result<()> {
+            self.frame_renderer
+                .write_styled(self.render_config.calendar.prefix)?;
+            self.frame_renderer.write(" ")?;
+            self.print_time(time, selected_field)?;
+            self.new_line()?;
+            Ok(())
+        }
+    }
+
+    impl<'a, I, T> WeekSelectBackend for Backend<'a, I, T>
+    where
+        I: InputReader,
+        T: Terminal,
+    {
+        fn render_week_prompt(&mut self, prompt: &str) -> Result<()> {
+            self.print_prompt(prompt)?;
+            self.new_line()?;
+            Ok(())
+        }
+
+        fn render_week_calendar(
+            &mut self,
+            month: chrono::Month,
+            year: i32,
+            today: chrono::NaiveDate,
+            selected_week: chrono::IsoWeek,
+            min_date: Option<chrono::NaiveDate>,
+            max_date: Option<chrono::NaiveDate>,
+        ) -> Result<()> {
+            let monday = chrono::NaiveDate::from_isoywd_opt(
+                selected_week.year(),
+                selected_week.week(),
+                chrono::Weekday::Mon,
+            )
+            .expect("unexpected invalid ISO week");
+            let sunday = monday
+                .checked_add_days(chrono::Days::new(6))
+                .unwrap_or(monday);
+
+            self.print_calendar(
+                month,
+                year,
+                chrono::Weekday::Mon,
+                today,
+                monday..=sunday,
+                min_date,
+                max_date,
+                true,
+            )
+        }
+    }
+
+    impl<'a, I, T> MonthSelectBackend for Backend<'a, I, T>
+    where
+        I: InputReader,
+        T: Terminal,
+    {
+        fn render_month_prompt(&mut self, prompt: &str) -> Result<()> {
+            self.print_prompt(prompt)?;
+            self.new_line()?;
+            Ok(())
+        }
+
+        fn render_months(
+            &mut self,
+            selected_month: chrono::NaiveDate,
+            current_month: chrono::NaiveDate,
+            min_month: Option<chrono::NaiveDate>,
+            max_month: Option<chrono::NaiveDate>,
+        ) -> Result<()> {
+            macro_rules! write_prefix {
+                () => {{
+                    self.frame_renderer
+                        .write_styled(self.render_config.calendar.prefix)?;
+                    self.frame_renderer.write(" ")
+                }};
+            }
+
+            let year = selected_month.year();
+
+            // print header (year), centered over the 3 columns of months
+            let header = format!("{year:^11}");
+            let header = Styled::new(header).with_style_sheet(self.render_config.calendar.header);
+
+            write_prefix!()?;
+            self.frame_renderer.write_styled(header)?;
+            self.new_line()?;
+
+            // print months, one quarter per line
+            for quarter in 0..4 {
+                write_prefix!()?;
+
+                for i in 0..3 {
+                    if i > 0 {
+                        self.frame_renderer.write(" ")?;
+                    }
+
+                    let month = chrono::NaiveDate::from_ymd_opt(year, quarter * 3 + i + 1, 1)
+                        .expect("unexpected invalid first day of month");
+
+                    let mut name = get_month(month.month()).name()[..3].to_string();
+                    name.make_ascii_lowercase();
+
+                    let mut style_sheet = crate::ui::StyleSheet::empty();
+
+                    if month == selected_month {
+                        self.frame_renderer.mark_cursor_position(0);
+                        if let Some(custom_style_sheet) = self.render_config.calendar.selected_date
+                        {
+                            style_sheet = custom_style_sheet;
+                        }
+                    } else if month == current_month {
+                        style_sheet = self.render_config.calendar.today_date;
+                    }
+
+                    let before_min = min_month.map(|min| month < min).unwrap_or(false);
+                    let after_max = max_month.map(|max| month > max).unwrap_or(false);
+                    if before_min || after_max {
+                        style_sheet = self.render_config.calendar.unavailable_date;
+                    }
+
+                    let token = Styled::new(name).with_style_sheet(style_sheet);
+                    self.frame_renderer.write_styled(token)?;
+                }
+
+                self.new_line()?;
+            }
+
+            Ok(())
+        }
+    }
+}
+
+impl<'a, I, T> CustomTypeBackend for Backend<'a, I, T>
+where
+    I: InputReader,
+    T: Terminal,
+{
+    fn render_prompt(
+        &mut self,
+        prompt: &str,
+        default: Option<&str>,
+        cur_input: &Input,
+    ) -> Result<()> {
+        self.print_prompt_with_input(prompt, default, cur_input)
+    }
+}
+
+impl<'a, I, T> PercentageBackend for Backend<'a, I, T>
+where
+    I: InputReader,
+    T: Terminal,
+{
+    fn render_percentage_bar(&mut self, filled: usize, width: usize) -> Result<()> {
+        let filled = filled.min(width);
+
+        self.frame_renderer.write("[")?;
+        self.frame_renderer.write_styled(
+            Styled::new("#".repeat(filled)).with_style_sheet(self.render_config.text_input),
+        )?;
+        self.frame_renderer.write_styled(
+            Styled::new("-".repeat(width - filled))
+                .with_style_sheet(self.render_config.placeholder),
+        )?;
+        self.frame_renderer.write("]")?;
+
+        self.new_line()
+    }
+}
+
+impl<'a, I, T> MaskBackend for Backend<'a, I, T>
+where
+    I: InputReader,
+    T: Terminal,
+{
+    fn render_masked_prompt(
+        &mut self,
+        prompt: &str,
+        default: Option<&str>,
+        filled: &str,
+        remaining: &str,
+    ) -> Result<()> {
+        self.print_prompt(prompt)?;
+
+        if let Some(default) = default {
+            self.frame_renderer.write(" ")?;
+            self.print_default_value(default)?;
+        }
+
+        self.frame_renderer.write(" ")?;
+        self.frame_renderer
+            .mark_cursor_position(filled.width() as isize);
+        self.frame_renderer
+            .write_styled(Styled::new(filled).with_style_sheet(self.render_config.text_input))?;
+
+        if remaining.is_empty() {
+            // the cursor would otherwise render on the \n character
+            self.frame_renderer.write(' ')?;
+        } else {
+            self.frame_renderer.write_styled(
+                Styled::new(remaining).with_style_sheet(self.render_config.placeholder),
+            )?;
+        }
+
+        self.new_line()
+    }
+}
+
+impl<'a, I, T> NumberRangeBackend for Backend<'a, I, T>
+where
+    I: InputReader,
+    T: Terminal,
+{
+    fn render_number_range_prompt(
+        &mut self,
+        prompt: &str,
+        default: Option<&str>,
+        lower: &Input,
+        upper: &Input,
+        upper_focused: bool,
+    ) -> Result<()> {
+        self.print_prompt(prompt)?;
+
+        if let Some(default) = default {
+            self.frame_renderer.write(" ")?;
+            self.print_default_value(default)?;
+        }
+
+        self.frame_renderer.write(" ")?;
+        self.print_range_field(lower, !upper_focused)?;
+        self.frame_renderer.write(" - ")?;
+        self.print_range_field(upper, upper_focused)?;
+
+        // if cursor is at end of input, we need to add
+        // a space, otherwise the cursor will render on the
+        // \n character, on the next line.
+        if upper_focused && upper.cursor() == upper.length() {
+            self.frame_renderer.write(' ')?;
+        }
+
+        self.new_line()
+    }
+}
+
+impl<'a, I, T> DurationInputBackend for Backend<'a, I, T>
+where
+    I: InputReader,
+    T: Terminal,
+{
+    fn render_duration_preview(&mut self, preview: std::result::Result<&str, &str>) -> Result<()> {
+        let token = match preview {
+            Ok(duration) => Styled::new(format!("= {duration}"))
+                .with_style_sheet(self.render_config.default_value),
+            Err(reason) => Styled::new(reason.to_string())
+                .with_style_sheet(self.render_config.error_message.message),
+        };
+
+        self.frame_renderer.write_styled(token)?;
+        self.new_line()
+    }
+}
+
+impl<'a, I, T> SpinnerBackend for Backend<'a, I, T>
+where
+    I: InputReader,
+    T: Terminal,
+{
+    fn render_spinner(
+        &mut self,
+        frame: &str,
+        message: &str,
+        position: Option<(u64, u64)>,
+    ) -> Result<()> {
+        let prefix = Styled::new(frame).with_style_sheet(self.render_config.prompt_prefix.style);
+        self.print_prompt_with_prefix(prefix, message)?;
+
+        if let Some((done, total)) = position {
+            const BAR_WIDTH: u64 = 20;
+
+            let filled = (done * BAR_WIDTH).checked_div(total).unwrap_or(BAR_WIDTH);
+            let bar = format!(
+                "[{}{}] {}/{}",
+                "#".repeat(filled as usize),
+                "-".repeat((BAR_WIDTH - filled) as usize),
+                done,
+                total
+            );
+
+            self.frame_renderer.write(" ")?;
+            self.frame_renderer.write_styled(
+                Styled::new(bar).with_style_sheet(self.render_config.default_value),
+            )?;
+        }
+
+        self.new_line()
+    }
+}
+
+impl<'a, I, T> ToggleBackend for Backend<'a, I, T>
+where
+    I: InputReader,
+    T: Terminal,
+{
+    fn render_toggle_prompt(
+        &mut self,
+        prompt: &str,
+        on_label: &str,
+        off_label: &str,
+        value: bool,
+    ) -> Result<()> {
+        self.print_prompt(prompt)?;
+        self.frame_renderer.write(" ")?;
+
+        let selected_style = self
+            .render_config
+            .selected_option
+            .unwrap_or(self.render_config.answer);
+
+        for (label, is_selected) in [(on_label, value), (off_label, !value)] {
+            if is_selected {
+                self.frame_renderer.mark_cursor_position(1);
+                self.frame_renderer.write_styled(
+                    Styled::new(format!("[{label}]")).with_style_sheet(selected_style),
+                )?;
+            } else {
+                self.frame_renderer.write_styled(
+                    Styled::new(format!(" {label} "))
+                        .with_style_sheet(self.render_config.placeholder),
+                )?;
+            }
+
+            self.frame_renderer.write(" ")?;
+        }
+
+        self.new_line()
+    }
+}
+
+impl<'a, I, T> HotkeySelectBackend for Backend<'a, I, T>
+where
+    I: InputReader,
+    T: Terminal,
+{
+    fn render_hotkey_select_prompt(
+        &mut self,
+        prompt: &str,
+        options: &[(String, char, String)],
+    ) -> Result<()> {
+        self.print_prompt(prompt)?;
+
+        for (prefix, hotkey, suffix) in options {
+            self.frame_renderer.write(" ")?;
+            self.frame_renderer.write_styled(
+                Styled::new(prefix.as_str()).with_style_sheet(self.render_config.option),
+            )?;
+            self.frame_renderer.write_styled(
+                Styled::new(format!("[{hotkey}]"))
+                    .with_style_sheet(self.render_config.option_match),
+            )?;
+            self.frame_renderer.write_styled(
+                Styled::new(suffix.as_str()).with_style_sheet(self.render_config.option),
+            )?;
+            self.frame_renderer.write(" ")?;
+        }
+
+        self.new_line()
+    }
+}
+
+impl<'a, I, T> RatingBackend for Backend<'a, I, T>
+where
+    I: InputReader,
+    T: Terminal,
+{
+    fn render_rating_prompt(
+        &mut self,
+        prompt: &str,
+        filled_symbol: &str,
+        empty_symbol: &str,
+        value: u8,
+        max: u8,
+    ) -> Result<()> {
+        self.print_prompt(prompt)?;
+        self.frame_renderer.write(" ")?;
+
+        let filled_style = self
+            .render_config
+            .selected_option
+            .unwrap_or(self.render_config.answer);
+
+        self.frame_renderer.write_styled(
+            Styled::new(filled_symbol.repeat(value as usize)).with_style_sheet(filled_style),
+        )?;
+        self.frame_renderer.write_styled(
+            Styled::new(empty_symbol.repeat(max.saturating_sub(value) as usize))
+                .with_style_sheet(self.render_config.placeholder),
+        )?;
+
+        self.frame_renderer.write(" ")?;
+        self.print_default_value(&format!("{value}/{max}"))?;
+
+        self.new_line()
+    }
+}
+
+impl<'a, I, T> ColorSelectBackend for Backend<'a, I, T>
+where
+    I: InputReader,
+    T: Terminal,
+{
+    fn render_color_select_prompt(
+        &mut self,
+        prompt: &str,
+        hex_input: Option<&Input>,
+    ) -> Result<()> {
+        if let Some(input) = hex_input {
+            self.print_prompt_with_input(prompt, None, input)
+        } else {
+            self.print_prompt(prompt)?;
+            self.new_line()
+        }
+    }
+
+    fn render_palette(&mut self, colors: &[Color], columns: usize, cursor: usize) -> Result<()> {
+        for (row_idx, row) in colors.chunks(columns).enumerate() {
+            self.frame_renderer.write(" ")?;
+
+            for (col_idx, color) in row.iter().enumerate() {
+                let swatch = Styled::new("███").with_fg(*color);
+
+                if row_idx * columns + col_idx == cursor {
+                    self.frame_renderer.write("[")?;
+                    self.frame_renderer.write_styled(swatch)?;
+                    self.frame_renderer.write("]")?;
+                } else {
+                    self.frame_renderer.write(" ")?;
+                    self.frame_renderer.write_styled(swatch)?;
+                    self.frame_renderer.write(" ")?;
+                }
+            }
+
+            self.new_line()?;
+        }
+
+        Ok(())
+    }
+}
+
+impl<'a, I, T> SliderBackend for Backend<'a, I, T>
+where
+    I: InputReader,
+    T: Terminal,
+{
+    fn render_slider_prompt(
+        &mut self,
+        prompt: &str,
+        position: usize,
+        width: usize,
+        value: &str,
+    ) -> Result<()> {
+        self.print_prompt(prompt)?;
+        self.frame_renderer.write(" [")?;
+
+        let filled = "=".repeat(position);
+        let empty = "-".repeat(width.saturating_sub(position).saturating_sub(1));
+
+        self.frame_renderer
+            .write_styled(Styled::new(filled).with_style_sheet(self.render_config.text_input))?;
+
+        self.frame_renderer.mark_cursor_position(0);
+        let handle_style = self
+            .render_config
+            .selected_option
+            .unwrap_or(self.render_config.option);
+        self.frame_renderer
+            .write_styled(Styled::new("O").with_style_sheet(handle_style))?;
+
+        self.frame_renderer
+            .write_styled(Styled::new(empty).with_style_sheet(self.render_config.placeholder))?;
+
+        self.frame_renderer.write("] ")?;
+        self.frame_renderer
+            .write_styled(Styled::new(value).with_style_sheet(self.render_config.answer))?;
+
+        self.new_line()?;
+
+        Ok(())
+    }
+}
+
+impl<'a, I, T> PasswordBackend for Backend<'a, I, T>
+where
+    I: InputReader,
+    T: Terminal,
+{
+    fn render_prompt(&mut self, prompt: &str) -> Result<()> {
+        self.print_prompt(prompt)?;
+        self.new_line()?;
+        Ok(())
+    }
+
+    fn render_prompt_with_masked_input(&mut self, prompt: &str, cur_input: &Input) -> Result<()> {
+        let masked_string: String = (0..cur_input.length())
+            .map(|_| self.render_config.password_mask)
+            .collect();
+
+        let masked_input = Input::new_with(masked_string).with_cursor(cur_input.cursor());
+
+        self.print_prompt_with_input(prompt, None, &masked_input)
+    }
+
+    fn render_prompt_with_full_input(&mut self, prompt: &str, cur_input: &Input) -> Result<()> {
+        self.print_prompt_with_input(prompt, None, cur_input)
+    }
+}
+
+impl<'a, I, T> TypeToConfirmBackend for Backend<'a, I, T>
+where
+    I: InputReader,
+    T: Terminal,
+{
+    fn render_type_to_confirm_prompt(
+        &mut self,
+        prompt: &str,
+        phrase: &str,
+        cur_input: &Input,
+        mismatched_chars: &[usize],
+    ) -> Result<()> {
+        self.print_prompt_with_prefix(self.render_config.error_message.prefix, prompt)?;
+        self.new_line()?;
+
+        self.frame_renderer.write("  Type ")?;
+        self.frame_renderer
+            .write_styled(Styled::new(phrase).with_style_sheet(self.render_config.answer))?;
+        self.frame_renderer.write(" to confirm: ")?;
+
+        self.frame_renderer
+            .mark_cursor_position(cur_input.pre_cursor().width() as isize);
+
+        for (i, c) in cur_input.content().chars().enumerate() {
+            let style = match mismatched_chars.contains(&i) {
+                true => self.render_config.error_message.message,
+                false => self.render_config.text_input,
+            };
+
+            self.frame_renderer
+                .write_styled(Styled::new(c).with_style_sheet(style))?;
+        }
+
+        // if cursor is at end of input, we need to add
+        // a space, otherwise the cursor will render on the
+        // \n character, on the next line.
+        if cur_input.cursor() == cur_input.length() {
+            self.frame_renderer.write(' ')?;
+        }
+
+        self.new_line()
+    }
+}
+
+impl<'a, I, T> InputReader for Backend<'a, I, T>
+where
+    I: InputReader,
+    T: Terminal,
+{
+    fn read_event(&mut self) -> InquireResult<InputEvent> {
+        let event = self.input_reader.read_event()?;
+        self.resolve_mouse_target(event)
+    }
+
+    fn poll_event(&mut self, timeout: Duration) -> InquireResult<Option<InputEvent>> {
+        match self.input_reader.poll_event(timeout)? {
+            Some(event) => self.resolve_mouse_target(event).map(Some),
+            None => Ok(None),
+        }
+    }
+
+    fn supports_timeout(&self) -> bool {
+        self.input_reader.supports_timeout()
+    }
+}
+
+#[cfg(test)]
+pub(crate) mod test {
+    use std::{
+        collections::{BTreeSet, VecDeque},
+        fmt::Display,
+    };
+
+    use chrono::{IsoWeek, Month, NaiveDate, Weekday};
+
+    use crate::{
+        input::Input,
+        list_option::ListOption,
+        terminal::TerminalSize,
+        ui::{InputEvent, InputReader, Key},
+        validator::ErrorMessage,
+    };
+
+    use crate::utils::Page;
+
+    use super::{
+        CheckboxMatrixBackend, ColorSelectBackend, CommandPaletteBackend, CommonBackend,
+        CustomTypeBackend, DurationInputBackend, FuzzyFinderBackend, GridSelectBackend,
+        HotkeySelectBackend, LineHighlights, MaskBackend, MenuBackend, NumberRangeBackend,
+        PercentageBackend, RatingBackend, SliderBackend, SpinnerBackend, TableEditorBackend,
+        TagsBackend, TextAreaBackend, TextBackend, TextInputDetails, ToggleBackend,
+        TypeToConfirmBackend,
+    };
+
+    #[cfg(feature = "mouse")]
+    impl<'a, I, T> super::Backend<'a, I, T>
+    where
+        I: InputReader,
+        T: crate::terminal::Terminal,
+    {
+        pub fn terminal_mut(&mut self) -> &mut T {
+            self.frame_renderer.terminal_mut()
+        }
+    }
+
+    #[derive(Debug, Clone, PartialEq)]
+    pub enum Token {
+        Prompt(String),
+        DefaultValue(String),
+        Input(Input),
+        Highlights(LineHighlights),
+        CanceledPrompt(String),
+        AnsweredPrompt(String, String),
+        ErrorMessage(ErrorMessage),
+        HelpMessage(String),
+        LoadingIndicator(String),
+        GhostText(String),
+        ModeIndicator(String),
+        LengthCounter(usize, usize),
+        Values(Vec<String>),
+        #[allow(dead_code)]
+        Calendar {
+            month: Month,
+            year: i32,
+            week_start: Weekday,
+            today: NaiveDate,
+            selected_date: NaiveDate,
+            min_date: Option<NaiveDate>,
+            max_date: Option<NaiveDate>,
+        },
+        #[allow(dead_code)]
+        WeekCalendar {
+            month: Month,
+            year: i32,
+            today: NaiveDate,
+            selected_week: IsoWeek,
+            min_date: Option<NaiveDate>,
+            max_date: Option<NaiveDate>,
+        },
+        #[allow(dead_code)]
+        Months {
+            selected_month: NaiveDate,
+            current_month: NaiveDate,
+            min_month: Option<NaiveDate>,
+            max_month: Option<NaiveDate>,
+        },
+        #[allow(dead_code)]
+        Time {
+            content: String,
+            selected: Option<std::ops::Range<usize>>,
+        },
+        Slider {
+            position: usize,
+            width: usize,
+            value: String,
+        },
+        DurationPreview(Result<String, String>),
+        NumberRange {
+            lower: String,
+            upper: String,
+            upper_focused: bool,
+        },
+        Tags(Vec<String>),
+        Table {
+            rows: Vec<Vec<String>>,
+            cursor: Option<(usize, usize)>,
+        },
+        Masked {
+            filled: String,
+            remaining: String,
+        },
+        PercentageBar {
+            filled: usize,
+            width: usize,
+        },
+        Breadcrumb(Vec<String>),
+        MenuEntries {
+            options: Vec<String>,
+            submenus: Vec<bool>,
+            cursor: Option<usize>,
+        },
+        FullScreen,
+        MatchCounter {
+            matched: usize,
+            total: usize,
+        },
+        MatchedOptions {
+            options: Vec<String>,
+            matched_chars: Vec<Vec<usize>>,
+            cursor: Option<usize>,
+        },
+        Preview(String),
+        ConfirmPhrase {
+            phrase: String,
+            mismatched_chars: Vec<usize>,
+        },
+        Commands {
+            options: Vec<String>,
+            headers: Vec<Option<String>>,
+            shortcuts: Vec<Option<String>>,
+            matched_chars: Vec<Vec<usize>>,
+            cursor: Option<usize>,
+        },
+        Grid {
+            options: Vec<String>,
+            columns: usize,
+            cursor: Option<usize>,
+        },
+        Matrix {
+            rows: Vec<String>,
+            checked: Vec<(usize, usize)>,
+            cursor: Option<(usize, usize)>,
+        },
+        Suggestions {
+            options: Vec<String>,
+            cursor: Option<usize>,
+        },
+        Dropdown {
+            options: Vec<String>,
+            cursor: Option<usize>,
+            fragment: String,
+        },
+        Spinner {
+            frame: String,
+            message: String,
+            position: Option<(u64, u64)>,
+        },
+        Hotkeys(Vec<String>),
+        Toggle {
+            on_label: String,
+            off_label: String,
+            value: bool,
+        },
+        Rating {
+            value: u8,
+            max: u8,
+        },
+        Palette {
+            columns: usize,
+            cursor: usize,
+        },
+        PromptEnd,
+    }
+
+    #[derive(Default, Debug, Clone)]
+    pub struct Frame {
+        content: Vec<Token>,
+    }
+
+    impl Frame {
+        pub fn has_token(&self, token: &Token) -> bool {
+            self.content.iter().any(|t| t == token)
+        }
+
+        pub fn tokens(&self) -> &[Token] {
+            &self.content
+        }
+    }
+
+    #[derive(Default, Debug, Clone)]
+    pub struct FakeBackend {
+        pub input: VecDeque<InputEvent>,
+        pub frames: Vec<Frame>,
+        pub cur_frame: Option<Frame>,
+        pub full_screen: bool,
+        /// Whether waiting for an event with a timeout is supported, which
+        /// it is unless simulating the `console` backend.
+        pub supports_timeout: bool,
+    }
+
+    impl FakeBackend {
+        pub fn new(input: Vec<Key>) -> Self {
+            Self::with_events(input.into_iter().map(InputEvent::Key).collect())
+        }
+
+        /// Backend reading the events, such as pasted text, instead of key
+        /// presses only.
+        pub fn with_events(input: Vec<InputEvent>) -> Self {
+            Self {
+                input: input.into(),
+                frames: vec![],
+                cur_frame: None,
+                full_screen: false,
+                supports_timeout: true,
+            }
+        }
+
+        fn push_token(&mut self, token: Token) {
+            if let Some(frame) = self.cur_frame.as_mut() {
+                frame.content.push(token);
+            } else {
+                panic!("No frame to push token");
+            }
+        }
+        pub fn frames(&self) -> &[Frame] {
+            &self.frames
+        }
+    }
+
+    impl InputReader for FakeBackend {
+        fn read_event(&mut self) -> crate::error::InquireResult<InputEvent> {
+            self.input
+                .pop_front()
+                .ok_or(crate::error::InquireError::IO(std::io::Error::new(
+                    std::io::ErrorKind::UnexpectedEof,
+                    "No more keys in input",
+                )))
+        }
+
+        fn poll_event(
+            &mut self,
+            timeout: std::time::Duration,
+        ) -> crate::error::InquireResult<Option<InputEvent>> {
+            // an empty input simulates the user not pressing any key
+            if let Some(event) = self.input.pop_front() {
+                Ok(Some(event))
+            } else {
+                std::thread::sleep(timeout);
+                Ok(None)
+            }
+        }
+
+        fn supports_timeout(&self) -> bool {
+            self.supports_timeout
+        }
+    }
+
+    impl CommonBackend for FakeBackend {
+        fn frame_setup(&mut self) -> std::io::Result<()> {
+            self.cur_frame = Some(Frame::default());
+            Ok(())
+        }
+
+        fn frame_finish(&mut self, is_last_frame: bool) -> std::io::Result<()> {
+            if is_last_frame {
+                self.push_token(Token::PromptEnd);
+            }
+
+            if let Some(frame) = self.cur_frame.take() {
+                self.frames.push(frame);
+            } else {
+                panic!("No frame to finish");
+            }
+            Ok(())
+        }
+
+        fn screen_size(&mut self) -> TerminalSize {
+            TerminalSize::new(80, 12)
+        }
+
+        fn set_mouse_capture(&mut self, _enabled: bool) -> std::io::Result<()> {
+            Ok(())
+        }
+
+        fn render_canceled_prompt(&mut self, prompt: &str) -> std::io::Result<()> {
+            self.push_token(Token::CanceledPrompt(prompt.to_string()));
+            Ok(())
+        }
+
+        fn render_prompt_with_answer(&mut self, prompt: &str, answer: &str) -> std::io::Result<()> {
+            self.push_token(Token::AnsweredPrompt(
+                prompt.to_string(),
+                answer.to_string(),
+            ));
+            Ok(())
+        }
+
+        fn render_error_message(&mut self, error: &ErrorMessage) -> std::io::Result<()> {
+            self.push_token(Token::ErrorMessage(error.clone()));
+            Ok(())
+        }
+
+        fn render_help_message(&mut self, help: &str) -> std::io::Result<()> {
+            self.push_token(Token::HelpMessage(help.to_string()));
+            Ok(())
+        }
+    }
+
+    #[cfg(feature = "date")]
+    impl crate::ui::date::DateSelectBackend for FakeBackend {
+        fn render_calendar_prompt(&mut self, prompt: &str) -> std::io::Result<()> {
+            self.push_token(Token::Prompt(prompt.to_string()));
+            Ok(())
+        }
+
+        fn render_calendar(
+            &mut self,
+            month: Month,
+            year: i32,
+            week_start: Weekday,
+            today: NaiveDate,
+            selected_date: NaiveDate,
+            min_date: Option<NaiveDate>,
+            max_date: Option<NaiveDate>,
+        ) -> std::io::Result<()> {
+            self.push_token(Token::Calendar {
+                month,
+                year,
+                week_start,
+                today,
+                selected_date,
+                min_date,
+                max_date,
+            });
+            Ok(())
+        }
+    }
+
+    #[cfg(feature = "date")]
+    impl crate::ui::date::TimeSelectBackend for FakeBackend {
+        fn render_time_prompt(
+            &mut self,
+            prompt: &str,
+            time: &str,
+            selected_field: std::ops::Range<usize>,
+        ) -> std::io::Result<()> {
+            self.push_token(Token::Prompt(prompt.to_string()));
+            self.push_token(Token::Time {
+                content: time.to_string(),
+                selected: Some(selected_field),
+            });
+            Ok(())
+        }
+    }
+
+    #[cfg(feature = "date")]
+    impl crate::ui::date::DateTimeSelectBackend for FakeBackend {
+        fn render_time(
+            &mut self,
+            time: &str,
+            selected_field: Option<std::ops::Range<usize>>,
+        ) -> std::io::Result<()> {
+            self.push_token(Token::Time {
+                content: time.to_string(),
+                selected: selected_field,
+            });
+            Ok(())
+        }
+    }
+
+    #[cfg(feature = "date")]
+    impl crate::ui::date::WeekSelectBackend for FakeBackend {
+        fn render_week_prompt(&mut self, prompt: &str) -> std::io::Result<()> {
+            self.push_token(Token::Prompt(prompt.to_string()));
+            Ok(())
+        }
+
+        fn render_week_calendar(
+            &mut self,
+            month: Month,
+            year: i32,
+            today: NaiveDate,
+            selected_week: IsoWeek,
+            min_date: Option<NaiveDate>,
+            max_date: Option<NaiveDate>,
+        ) -> std::io::Result<()> {
+            self.push_token(Token::WeekCalendar {
+                month,
+                year,
+                today,
+                selected_week,
+                min_date,
+                max_date,
+            });
+            Ok(())
+        }
+    }
+
+    #[cfg(feature = "date")]
+    impl crate::ui::date::MonthSelectBackend for FakeBackend {
+        fn render_month_prompt(&mut self, prompt: &str) -> std::io::Result<()> {
+            self.push_token(Token::Prompt(prompt.to_string()));
+            Ok(())
+        }
+
+        fn render_months(
+            &mut self,
+            selected_month: NaiveDate,
+            current_month: NaiveDate,
+            min_month: Option<NaiveDate>,
+            max_month: Option<NaiveDate>,
+        ) -> std::io::Result<()> {
+            self.push_token(Token::Months {
+                selected_month,
+                current_month,
+                min_month,
+                max_month,
+            });
+            Ok(())
+        }
+    }
+
+    impl CustomTypeBackend for FakeBackend {
+        fn render_prompt(
+            &mut self,
+            prompt: &str,
+            default: Option<&str>,
+            cur_input: &Input,
+        ) -> std::io::Result<()> {
+            self.push_token(Token::Prompt(prompt.to_string()));
+            if let Some(default) = default {
+                self.push_token(Token::DefaultValue(default.to_string()));
+            }
+            self.push_token(Token::Input(cur_input.clone()));
+            Ok(())
+        }
+    }
+
+    impl DurationInputBackend for FakeBackend {
+        fn render_duration_preview(&mut self, preview: Result<&str, &str>) -> std::io::Result<()> {
+            self.push_token(Token::DurationPreview(
+                preview.map(String::from).map_err(String::from),
+            ));
+            Ok(())
+        }
+    }
+
+    impl SliderBackend for FakeBackend {
+        fn render_slider_prompt(
+            &mut self,
+            prompt: &str,
+            position: usize,
+            width: usize,
+            value: &str,
+        ) -> std::io::Result<()> {
+            self.push_token(Token::Prompt(prompt.to_string()));
+            self.push_token(Token::Slider {
+                position,
+                width,
+                value: value.to_string(),
+            });
+            Ok(())
+        }
+    }
+
+    impl SpinnerBackend for FakeBackend {
+        fn render_spinner(
+            &mut self,
+            frame: &str,
+            message: &str,
+            position: Option<(u64, u64)>,
+        ) -> std::io::Result<()> {
+            self.push_token(Token::Spinner {
+                frame: frame.to_string(),
+                message: message.to_string(),
+                position,
+            });
+            Ok(())
+        }
+    }
+
+    impl ToggleBackend for FakeBackend {
+        fn render_toggle_prompt(
+            &mut self,
+            prompt: &str,
+            on_label: &str,
+            off_label: &str,
+            value: bool,
+        ) -> std::io::Result<()> {
+            self.push_token(Token::Prompt(prompt.to_string()));
+            self.push_token(Token::Toggle {
+                on_label: on_label.to_string(),
+                off_label: off_label.to_string(),
+                value,
+            });
+            Ok(())
+        }
+    }
+
+    impl RatingBackend for FakeBackend {
+        fn render_rating_prompt(
+            &mut self,
+            prompt: &str,
+            _filled_symbol: &str,
+            _empty_symbol: &str,
+            value: u8,
+            max: u8,
+        ) -> std::io::Result<()> {
+            self.push_token(Token::Prompt(prompt.to_string()));
+            self.push_token(Token::Rating { value, max });
+            Ok(())
+        }
+    }
+
+    impl ColorSelectBackend for FakeBackend {
+        fn render_color_select_prompt(
+            &mut self,
+            prompt: &str,
+            hex_input: Option<&Input>,
+        ) -> std::io::Result<()> {
+            self.push_token(Token::Prompt(prompt.to_string()));
+            if let Some(input) = hex_input {
+                self.push_token(Token::Input(input.clone()));
+            }
+            Ok(())
+        }
+
+        fn render_palette(
+            &mut self,
+            _colors: &[crate::ui::Color],
+            columns: usize,
+            cursor: usize,
+        ) -> std::io::Result<()> {
+            self.push_token(Token::Palette { columns, cursor });
+            Ok(())
+        }
     }
-}
 
-#[cfg(test)]
-pub(crate) mod test {
-    use std::collections::VecDeque;
+    impl TextBackend for FakeBackend {
+        fn render_prompt(
+            &mut self,
+            prompt: &str,
+            default: Option<&str>,
+            cur_input: &Input,
+            details: &TextInputDetails<'_>,
+        ) -> std::io::Result<()> {
+            if let Some(mode) = details.mode {
+                self.push_token(Token::ModeIndicator(mode.to_string()));
+            }
+            self.push_token(Token::Prompt(prompt.to_string()));
+            if let Some(default) = default {
+                self.push_token(Token::DefaultValue(default.to_string()));
+            }
+            self.push_token(Token::Input(cur_input.clone()));
+            if let Some(ghost_text) = details.ghost_text {
+                self.push_token(Token::GhostText(ghost_text.to_string()));
+            }
+            if let Some((length, max)) = details.length_counter {
+                self.push_token(Token::LengthCounter(length, max));
+            }
+            Ok(())
+        }
 
-    use chrono::{Month, NaiveDate, Weekday};
+        fn render_values(&mut self, values: &[&str]) -> std::io::Result<()> {
+            self.push_token(Token::Values(
+                values.iter().map(|value| (*value).to_string()).collect(),
+            ));
+            Ok(())
+        }
 
-    use crate::{
-        input::Input,
-        ui::{InputReader, Key},
-        validator::ErrorMessage,
-    };
+        fn render_suggestions<D: Display>(
+            &mut self,
+            page: Page<'_, ListOption<D>>,
+        ) -> std::io::Result<()> {
+            if !page.content.is_empty() {
+                self.push_token(Token::Suggestions {
+                    options: page.content.iter().map(|o| o.value.to_string()).collect(),
+                    cursor: page.cursor,
+                });
+            }
+            Ok(())
+        }
 
-    use super::{CommonBackend, CustomTypeBackend};
+        fn render_dropdown<D: Display>(
+            &mut self,
+            page: Page<'_, ListOption<D>>,
+            fragment: &str,
+        ) -> std::io::Result<()> {
+            if !page.content.is_empty() {
+                self.push_token(Token::Dropdown {
+                    options: page.content.iter().map(|o| o.value.to_string()).collect(),
+                    cursor: page.cursor,
+                    fragment: fragment.to_string(),
+                });
+            }
+            Ok(())
+        }
 
-    #[derive(Debug, Clone, PartialEq)]
-    pub enum Token {
-        Prompt(String),
-        DefaultValue(String),
-        Input(Input),
-        CanceledPrompt(String),
-        AnsweredPrompt(String, String),
-        ErrorMessage(ErrorMessage),
-        HelpMessage(String),
-        Calendar {
-            month: Month,
-            year: i32,
-            week_start: Weekday,
-            today: NaiveDate,
-            selected_date: NaiveDate,
-            min_date: Option<NaiveDate>,
-            max_date: Option<NaiveDate>,
-        },
-        PromptEnd,
+        fn render_loading_indicator(&mut self, message: &str) -> std::io::Result<()> {
+            self.push_token(Token::LoadingIndicator(message.to_string()));
+            Ok(())
+        }
     }
 
-    #[derive(Default, Debug, Clone)]
-    pub struct Frame {
-        content: Vec<Token>,
+    impl TagsBackend for FakeBackend {
+        fn render_tags_prompt(
+            &mut self,
+            prompt: &str,
+            tags: &[String],
+            cur_input: &Input,
+        ) -> std::io::Result<()> {
+            self.push_token(Token::Prompt(prompt.to_string()));
+            self.push_token(Token::Tags(tags.to_vec()));
+            self.push_token(Token::Input(cur_input.clone()));
+            Ok(())
+        }
     }
 
-    impl Frame {
-        pub fn has_token(&self, token: &Token) -> bool {
-            self.content.iter().any(|t| t == token)
+    impl FuzzyFinderBackend for FakeBackend {
+        fn enter_full_screen(&mut self) -> std::io::Result<()> {
+            self.full_screen = true;
+            Ok(())
         }
 
-        pub fn tokens(&self) -> &[Token] {
-            &self.content
+        fn render_fuzzy_finder_prompt(
+            &mut self,
+            prompt: &str,
+            cur_input: &Input,
+            matched: usize,
+            total: usize,
+        ) -> std::io::Result<()> {
+            if self.full_screen {
+                self.push_token(Token::FullScreen);
+            }
+            self.push_token(Token::Prompt(prompt.to_string()));
+            self.push_token(Token::Input(cur_input.clone()));
+            self.push_token(Token::MatchCounter { matched, total });
+            Ok(())
         }
-    }
 
-    #[derive(Default, Debug, Clone)]
-    pub struct FakeBackend {
-        pub input: VecDeque<Key>,
-        pub frames: Vec<Frame>,
-        pub cur_frame: Option<Frame>,
+        fn render_matched_options<D: Display>(
+            &mut self,
+            page: Page<'_, ListOption<D>>,
+            matched_chars: &[Vec<usize>],
+        ) -> std::io::Result<()> {
+            self.push_token(Token::MatchedOptions {
+                options: page.content.iter().map(|o| o.value.to_string()).collect(),
+                matched_chars: matched_chars.to_vec(),
+                cursor: page.cursor,
+            });
+            Ok(())
+        }
+
+        fn render_preview(&mut self, preview: &str, height: usize) -> std::io::Result<()> {
+            let preview = preview.lines().take(height).collect::<Vec<&str>>();
+            self.push_token(Token::Preview(preview.join("\n")));
+            Ok(())
+        }
     }
 
-    impl FakeBackend {
-        pub fn new(input: Vec<Key>) -> Self {
-            Self {
-                input: input.into(),
-                frames: vec![],
-                cur_frame: None,
-            }
+    impl CommandPaletteBackend for FakeBackend {
+        fn render_command_palette_prompt(
+            &mut self,
+            prompt: &str,
+            cur_input: &Input,
+        ) -> std::io::Result<()> {
+            self.push_token(Token::Prompt(prompt.to_string()));
+            self.push_token(Token::Input(cur_input.clone()));
+            Ok(())
         }
 
-        fn push_token(&mut self, token: Token) {
-            if let Some(frame) = self.cur_frame.as_mut() {
-                frame.content.push(token);
-            } else {
-                panic!("No frame to push token");
-            }
+        fn render_commands<D: Display>(
+            &mut self,
+            page: Page<'_, ListOption<D>>,
+            headers: &[Option<&str>],
+            shortcuts: &[Option<&str>],
+            matched_chars: &[Vec<usize>],
+        ) -> std::io::Result<()> {
+            let to_owned = |values: &[Option<&str>]| {
+                values
+                    .iter()
+                    .map(|value| value.map(String::from))
+                    .collect::<Vec<Option<String>>>()
+            };
+
+            self.push_token(Token::Commands {
+                options: page.content.iter().map(|o| o.value.to_string()).collect(),
+                headers: to_owned(headers),
+                shortcuts: to_owned(shortcuts),
+                matched_chars: matched_chars.to_vec(),
+                cursor: page.cursor,
+            });
+            Ok(())
         }
-        pub fn frames(&self) -> &[Frame] {
-            &self.frames
+    }
+
+    impl GridSelectBackend for FakeBackend {
+        fn render_grid_select_prompt(&mut self, prompt: &str) -> std::io::Result<()> {
+            self.push_token(Token::Prompt(prompt.to_string()));
+            Ok(())
+        }
+
+        fn render_grid<D: Display>(
+            &mut self,
+            page: Page<'_, ListOption<D>>,
+            columns: usize,
+            _label_width: usize,
+        ) -> std::io::Result<()> {
+            self.push_token(Token::Grid {
+                options: page.content.iter().map(|o| o.value.to_string()).collect(),
+                columns,
+                cursor: page.cursor,
+            });
+            Ok(())
         }
     }
 
-    impl InputReader for FakeBackend {
-        fn read_key(&mut self) -> crate::error::InquireResult<Key> {
-            self.input
-                .pop_front()
-                .ok_or(crate::error::InquireError::IO(std::io::Error::new(
-                    std::io::ErrorKind::UnexpectedEof,
-                    "No more keys in input",
-                )))
+    impl PercentageBackend for FakeBackend {
+        fn render_percentage_bar(&mut self, filled: usize, width: usize) -> std::io::Result<()> {
+            self.push_token(Token::PercentageBar { filled, width });
+            Ok(())
         }
     }
 
-    impl CommonBackend for FakeBackend {
-        fn frame_setup(&mut self) -> std::io::Result<()> {
-            self.cur_frame = Some(Frame::default());
+    impl HotkeySelectBackend for FakeBackend {
+        fn render_hotkey_select_prompt(
+            &mut self,
+            prompt: &str,
+            options: &[(String, char, String)],
+        ) -> std::io::Result<()> {
+            self.push_token(Token::Prompt(prompt.to_string()));
+            self.push_token(Token::Hotkeys(
+                options
+                    .iter()
+                    .map(|(prefix, hotkey, suffix)| format!("{prefix}[{hotkey}]{suffix}"))
+                    .collect(),
+            ));
             Ok(())
         }
+    }
 
-        fn frame_finish(&mut self, is_last_frame: bool) -> std::io::Result<()> {
-            if is_last_frame {
-                self.push_token(Token::PromptEnd);
+    impl MaskBackend for FakeBackend {
+        fn render_masked_prompt(
+            &mut self,
+            prompt: &str,
+            default: Option<&str>,
+            filled: &str,
+            remaining: &str,
+        ) -> std::io::Result<()> {
+            self.push_token(Token::Prompt(prompt.to_string()));
+            if let Some(default) = default {
+                self.push_token(Token::DefaultValue(default.to_string()));
             }
+            self.push_token(Token::Masked {
+                filled: filled.to_string(),
+                remaining: remaining.to_string(),
+            });
+            Ok(())
+        }
+    }
 
-            if let Some(frame) = self.cur_frame.take() {
-                self.frames.push(frame);
-            } else {
-                panic!("No frame to finish");
-            }
+    impl MenuBackend for FakeBackend {
+        fn render_menu_prompt(
+            &mut self,
+            prompt: &str,
+            breadcrumb: &[String],
+        ) -> std::io::Result<()> {
+            self.push_token(Token::Prompt(prompt.to_string()));
+            self.push_token(Token::Breadcrumb(breadcrumb.to_vec()));
             Ok(())
         }
 
-        fn render_canceled_prompt(&mut self, prompt: &str) -> std::io::Result<()> {
-            self.push_token(Token::CanceledPrompt(prompt.to_string()));
+        fn render_menu_entries<D: Display>(
+            &mut self,
+            page: Page<'_, ListOption<D>>,
+            submenus: &[bool],
+        ) -> std::io::Result<()> {
+            self.push_token(Token::MenuEntries {
+                options: page.content.iter().map(|o| o.value.to_string()).collect(),
+                submenus: submenus.to_vec(),
+                cursor: page.cursor,
+            });
             Ok(())
         }
+    }
 
-        fn render_prompt_with_answer(&mut self, prompt: &str, answer: &str) -> std::io::Result<()> {
-            self.push_token(Token::AnsweredPrompt(
-                prompt.to_string(),
-                answer.to_string(),
-            ));
+    impl NumberRangeBackend for FakeBackend {
+        fn render_number_range_prompt(
+            &mut self,
+            prompt: &str,
+            default: Option<&str>,
+            lower: &Input,
+            upper: &Input,
+            upper_focused: bool,
+        ) -> std::io::Result<()> {
+            self.push_token(Token::Prompt(prompt.to_string()));
+            if let Some(default) = default {
+                self.push_token(Token::DefaultValue(default.to_string()));
+            }
+            self.push_token(Token::NumberRange {
+                lower: lower.content().to_string(),
+                upper: upper.content().to_string(),
+                upper_focused,
+            });
             Ok(())
         }
+    }
 
-        fn render_error_message(&mut self, error: &ErrorMessage) -> std::io::Result<()> {
-            self.push_token(Token::ErrorMessage(error.clone()));
+    impl CheckboxMatrixBackend for FakeBackend {
+        fn render_checkbox_matrix_prompt(&mut self, prompt: &str) -> std::io::Result<()> {
+            self.push_token(Token::Prompt(prompt.to_string()));
             Ok(())
         }
 
-        fn render_help_message(&mut self, help: &str) -> std::io::Result<()> {
-            self.push_token(Token::HelpMessage(help.to_string()));
+        fn render_checkbox_matrix<D: Display>(
+            &mut self,
+            rows: Page<'_, ListOption<D>>,
+            _columns: &[String],
+            checked: &BTreeSet<(usize, usize)>,
+            cursor_column: usize,
+        ) -> std::io::Result<()> {
+            self.push_token(Token::Matrix {
+                rows: rows.content.iter().map(|o| o.value.to_string()).collect(),
+                checked: checked.iter().copied().collect(),
+                cursor: rows
+                    .cursor
+                    .and_then(|cursor| rows.content.get(cursor))
+                    .map(|row| (row.index, cursor_column)),
+            });
             Ok(())
         }
     }
 
-    #[cfg(feature = "date")]
-    impl crate::ui::date::DateSelectBackend for FakeBackend {
-        fn render_calendar_prompt(&mut self, prompt: &str) -> std::io::Result<()> {
+    impl TableEditorBackend for FakeBackend {
+        fn render_table_editor_prompt(&mut self, prompt: &str) -> std::io::Result<()> {
             self.push_token(Token::Prompt(prompt.to_string()));
             Ok(())
         }
 
-        fn render_calendar(
+        fn render_table_editor(
             &mut self,
-            month: Month,
-            year: i32,
-            week_start: Weekday,
-            today: NaiveDate,
-            selected_date: NaiveDate,
-            min_date: Option<NaiveDate>,
-            max_date: Option<NaiveDate>,
+            _columns: &[&str],
+            rows: Page<'_, ListOption<&Vec<String>>>,
+            cursor_column: usize,
+            input: &Input,
         ) -> std::io::Result<()> {
-            self.push_token(Token::Calendar {
-                month,
-                year,
-                week_start,
-                today,
-                selected_date,
-                min_date,
-                max_date,
+            self.push_token(Token::Table {
+                rows: rows.content.iter().map(|o| o.value.clone()).collect(),
+                cursor: rows
+                    .cursor
+                    .and_then(|cursor| rows.content.get(cursor))
+                    .map(|row| (row.index, cursor_column)),
             });
+            self.push_token(Token::Input(input.clone()));
             Ok(())
         }
     }
 
-    impl CustomTypeBackend for FakeBackend {
-        fn render_prompt(
+    impl TypeToConfirmBackend for FakeBackend {
+        fn render_type_to_confirm_prompt(
             &mut self,
             prompt: &str,
-            default: Option<&str>,
+            phrase: &str,
             cur_input: &Input,
+            mismatched_chars: &[usize],
+        ) -> std::io::Result<()> {
+            self.push_token(Token::Prompt(prompt.to_string()));
+            self.push_token(Token::ConfirmPhrase {
+                phrase: phrase.to_string(),
+                mismatched_chars: mismatched_chars.to_vec(),
+            });
+            self.push_token(Token::Input(cur_input.clone()));
+            Ok(())
+        }
+    }
+
+    impl TextAreaBackend for FakeBackend {
+        fn render_text_area_prompt(
+            &mut self,
+            prompt: &str,
+            default: Option<&str>,
         ) -> std::io::Result<()> {
             self.push_token(Token::Prompt(prompt.to_string()));
             if let Some(default) = default {
                 self.push_token(Token::DefaultValue(default.to_string()));
             }
-            self.push_token(Token::Input(cur_input.clone()));
+            Ok(())
+        }
+
+        fn render_text_area_lines(
+            &mut self,
+            page: Page<'_, Input>,
+            highlights: &[LineHighlights],
+        ) -> std::io::Result<()> {
+            for (idx, line) in page.content.iter().enumerate() {
+                self.push_token(Token::Input(line.clone()));
+
+                if let Some(highlights) = highlights
+                    .get(idx)
+                    .filter(|h| **h != LineHighlights::default())
+                {
+                    self.push_token(Token::Highlights(highlights.clone()));
+                }
+            }
             Ok(())
         }
     }
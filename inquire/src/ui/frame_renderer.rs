@@ -191,6 +191,7 @@ where
     terminal: T,
     cursor_position: Position,
     state: RenderState,
+    in_alternate_screen: bool,
 }
 
 impl<T> FrameRenderer<T>
@@ -202,9 +203,56 @@ where
             terminal,
             cursor_position: Position::default(),
             state: RenderState::Initial,
+            in_alternate_screen: false,
         })
     }
 
+    /// Switches to the alternate screen, where frames are rendered from the
+    /// top-left corner of an empty screen.
+    ///
+    /// The main screen is restored right before the last frame is rendered,
+    /// so that the final state of the prompt is kept in the terminal history.
+    pub fn enter_alternate_screen(&mut self) -> io::Result<()> {
+        if self.in_alternate_screen {
+            return Ok(());
+        }
+
+        self.terminal.enter_alternate_screen()?;
+        self.cursor_position = Position::default();
+        self.state = RenderState::Initial;
+        self.in_alternate_screen = true;
+
+        Ok(())
+    }
+
+    fn leave_alternate_screen(&mut self) -> io::Result<()> {
+        if !self.in_alternate_screen {
+            return Ok(());
+        }
+
+        // the main screen restores the cursor to where it was when the
+        // alternate screen was entered, i.e. the start of the prompt
+        self.terminal.leave_alternate_screen()?;
+        self.cursor_position = Position::default();
+        self.state = RenderState::Initial;
+        self.in_alternate_screen = false;
+
+        Ok(())
+    }
+
+    pub fn set_mouse_capture(&mut self, enabled: bool) -> io::Result<()> {
+        self.terminal.set_mouse_capture(enabled)
+    }
+
+    #[cfg(all(test, feature = "mouse"))]
+    pub fn terminal_mut(&mut self) -> &mut T {
+        &mut self.terminal
+    }
+
+    pub fn terminal_size(&mut self) -> TerminalSize {
+        self.refresh_terminal_size()
+    }
+
     pub fn write(&mut self, value: impl Display) -> io::Result<()> {
         self.write_styled(Styled::new(value))
     }
@@ -237,6 +285,40 @@ where
         }
     }
 
+    /// Position in the frame being rendered where the next content is written.
+    pub fn current_position(&self) -> Position {
+        match &self.state {
+            RenderState::ActiveRender { current_frame, .. } => Position {
+                row: current_frame.finished_rows.len() as u16,
+                col: current_frame.current_line_width,
+            },
+            RenderState::Rendered(_) | RenderState::Initial => Position::default(),
+        }
+    }
+
+    /// Column where the cursor is put once the frame being rendered is
+    /// finished, if it was marked.
+    pub fn expected_cursor_column(&self) -> Option<u16> {
+        match &self.state {
+            RenderState::ActiveRender { current_frame, .. } => current_frame
+                .expected_cursor_position
+                .map(|position| position.col),
+            RenderState::Rendered(_) | RenderState::Initial => None,
+        }
+    }
+
+    /// Row of the last rendered frame at the given row of the terminal, if
+    /// the terminal can report the position of the cursor.
+    pub fn frame_row(&mut self, screen_row: u16) -> io::Result<Option<u16>> {
+        let cursor_row = match self.terminal.cursor_row()? {
+            Some(cursor_row) => cursor_row,
+            None => return Ok(None),
+        };
+
+        let top = cursor_row.saturating_sub(self.cursor_position.row);
+        Ok(screen_row.checked_sub(top))
+    }
+
     pub fn start_frame(&mut self) -> io::Result<()> {
         let terminal_size = self.refresh_terminal_size();
 
@@ -276,6 +358,15 @@ where
 
         current_frame.finish();
 
+        // the last frame is the one kept in the terminal history, so it is
+        // rendered from scratch on the main screen
+        let last_rendered_frame = if add_empty_line && self.in_alternate_screen {
+            self.leave_alternate_screen()?;
+            FrameState::new(current_frame.terminal_size)
+        } else {
+            last_rendered_frame
+        };
+
         let rows_to_iterate = std::cmp::max(
             last_rendered_frame.frame_size.height(),
             current_frame.frame_size.height(),
@@ -434,6 +525,7 @@ where
     T: Terminal,
 {
     fn drop(&mut self) {
+        let _unused = self.leave_alternate_screen();
         let _unused = self.move_cursor_to_end_position();
         let _unused = self.terminal.cursor_show();
         let _unused = self.terminal.flush();
@@ -444,7 +536,10 @@ where
 mod test {
     use crate::{
         error::InquireResult,
-        terminal::{test::MockTerminal, TerminalSize},
+        terminal::{
+            test::{MockTerminal, MockTerminalToken},
+            TerminalSize,
+        },
     };
 
     use super::FrameRenderer;
@@ -470,4 +565,33 @@ mod test {
 
         Ok(())
     }
+
+    #[test]
+    fn last_frame_is_rendered_on_main_screen() -> InquireResult<()> {
+        let terminal = MockTerminal::new().with_size(TerminalSize::new(200, 200));
+        let mut renderer = FrameRenderer::new(terminal)?;
+
+        renderer.enter_alternate_screen()?;
+        renderer.start_frame()?;
+        renderer.write("Hello\nWorld")?;
+        renderer.finish_current_frame(false)?;
+        renderer.start_frame()?;
+        renderer.write("Hello")?;
+        renderer.finish_current_frame(true)?;
+
+        let terminal = &mut renderer.terminal;
+
+        terminal.find_and_expect_token(MockTerminalToken::EnterAlternateScreen);
+        terminal.find_and_expect_token("World".into());
+        terminal.find_and_expect_token(MockTerminalToken::LeaveAlternateScreen);
+        // the main screen was never written to, so the last frame is rendered
+        // in full instead of being diffed against the previous one
+        assert_eq!(
+            Some(MockTerminalToken::CursorHide),
+            terminal.output.pop_front()
+        );
+        terminal.find_and_expect_token("Hello".into());
+
+        Ok(())
+    }
 }
@@ -1,7 +1,48 @@
+use std::time::Duration;
+
 use crate::error::InquireResult;
 
 use super::Key;
 
+/// Event read from the terminal.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum InputEvent {
+    /// Key pressed by the user.
+    Key(Key),
+    /// Text pasted by the user, reported at once by the terminals supporting
+    /// bracketed paste instead of as a key press per character.
+    ///
+    /// Only reported by the crossterm backend.
+    #[cfg_attr(not(feature = "crossterm"), allow(dead_code))]
+    Paste(String),
+    /// Terminal resized by the user, after which the prompt is rendered again
+    /// to fit its new size.
+    ///
+    /// Only reported by the crossterm backend.
+    #[cfg_attr(not(feature = "crossterm"), allow(dead_code))]
+    Resize,
+}
+
+impl From<Key> for InputEvent {
+    fn from(key: Key) -> Self {
+        Self::Key(key)
+    }
+}
+
 pub trait InputReader: Sized {
-    fn read_key(&mut self) -> InquireResult<Key>;
+    fn read_event(&mut self) -> InquireResult<InputEvent>;
+
+    /// Waits at most `timeout` for an event, returning `None` if no key
+    /// was pressed in time.
+    ///
+    /// Readers that do not support timeouts block until a key is pressed.
+    fn poll_event(&mut self, _timeout: Duration) -> InquireResult<Option<InputEvent>> {
+        self.read_event().map(Some)
+    }
+
+    /// Whether `poll_event` returns once its timeout elapses, which the
+    /// prompts changing over time, e.g. a countdown, rely on.
+    fn supports_timeout(&self) -> bool {
+        false
+    }
 }
@@ -17,6 +17,9 @@ pub struct Page<'a, T> {
 
     /// Total amount of elements in the original list of choices.
     pub total: usize,
+
+    /// Index in the original list of choices of the first element of the page.
+    pub start: usize,
 }
 
 pub fn paginate<T>(page_size: usize, choices: &[T], sel: Option<usize>) -> Page<'_, T> {
@@ -65,9 +68,66 @@ pub fn paginate<T>(page_size: usize, choices: &[T], sel: Option<usize>) -> Page<
         content: &choices[start..end],
         cursor,
         total: choices.len(),
+        start,
     }
 }
 
+/// Paginates choices laid out in rows of `columns` choices, from left to
+/// right, keeping up to `page_size` whole rows around the selected one.
+pub fn paginate_rows<T>(
+    page_size: usize,
+    columns: usize,
+    choices: &[T],
+    sel: Option<usize>,
+) -> Page<'_, T> {
+    let row_count = (choices.len() + columns - 1) / columns;
+    let rows = (0..row_count).collect::<Vec<usize>>();
+    let row_page = paginate(page_size, &rows, sel.map(|index| index / columns));
+
+    let start = row_page.content.first().copied().unwrap_or_default() * columns;
+    let end = std::cmp::min(start + row_page.content.len() * columns, choices.len());
+
+    Page {
+        first: row_page.first,
+        last: row_page.last,
+        content: &choices[start..end],
+        cursor: sel.map(|index| index - start),
+        total: choices.len(),
+        start,
+    }
+}
+
+/// Position of the entry closest to `position` that is enabled, looking in
+/// the direction of the movement first. The search wraps around the list
+/// when `wrap` is set, otherwise it continues in the opposite direction.
+///
+/// Returns `None` if none of the `count` entries is enabled.
+pub fn closest_enabled<F>(
+    position: usize,
+    count: usize,
+    upwards: bool,
+    wrap: bool,
+    is_enabled: F,
+) -> Option<usize>
+where
+    F: Fn(usize) -> bool,
+{
+    let above = (0..position.min(count)).rev();
+    let below = position.saturating_add(1)..count;
+
+    let candidates: Vec<usize> = match (upwards, wrap) {
+        (true, true) => above.chain(below.rev()).collect(),
+        (true, false) => above.chain(below).collect(),
+        (false, true) => below.chain(above.rev()).collect(),
+        (false, false) => below.chain(above).collect(),
+    };
+
+    std::iter::once(position)
+        .filter(|p| *p < count)
+        .chain(candidates)
+        .find(|p| is_enabled(*p))
+}
+
 pub fn int_log10<T>(mut i: T) -> usize
 where
     T: std::ops::DivAssign + PartialOrd + From<u8> + Copy,
@@ -84,6 +144,21 @@ where
     len
 }
 
+/// Formats the number with commas separating each group of thousands, e.g. `1,203`.
+pub fn group_digits(n: usize) -> String {
+    let digits = n.to_string();
+    let mut grouped = String::with_capacity(digits.len() + digits.len() / 3);
+
+    for (i, c) in digits.chars().enumerate() {
+        if i > 0 && (digits.len() - i) % 3 == 0 {
+            grouped.push(',');
+        }
+        grouped.push(c);
+    }
+
+    grouped
+}
+
 impl<'a, T> Debug for Page<'a, T> {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         f.debug_struct("Page")
@@ -92,6 +167,7 @@ impl<'a, T> Debug for Page<'a, T> {
             .field("content", &format!("({} elements)", &self.content.len()))
             .field("cursor", &self.cursor)
             .field("total", &self.total)
+            .field("start", &self.start)
             .finish()
     }
 }
@@ -102,7 +178,7 @@ mod test {
 
     use crate::{
         list_option::ListOption,
-        utils::{int_log10, paginate},
+        utils::{closest_enabled, group_digits, int_log10, paginate, paginate_rows},
     };
 
     impl<T> ListOption<T> {
@@ -114,6 +190,16 @@ mod test {
         }
     }
 
+    #[test]
+    fn group_digits_separates_thousands() {
+        assert_eq!("0", group_digits(0));
+        assert_eq!("24", group_digits(24));
+        assert_eq!("999", group_digits(999));
+        assert_eq!("1,203", group_digits(1203));
+        assert_eq!("120,300", group_digits(120300));
+        assert_eq!("1,000,000", group_digits(1000000));
+    }
+
     #[test]
     fn int_log10_works() {
         for i in 1..10 {
@@ -133,6 +219,24 @@ mod test {
         }
     }
 
+    #[test]
+    fn closest_enabled_looks_in_the_direction_of_the_movement_first() {
+        let enabled = |p: usize| p != 2 && p != 3;
+
+        assert_eq!(Some(1), closest_enabled(3, 5, true, true, enabled));
+        assert_eq!(Some(4), closest_enabled(2, 5, false, true, enabled));
+        assert_eq!(Some(0), closest_enabled(0, 5, true, false, enabled));
+    }
+
+    #[test]
+    fn closest_enabled_wraps_around_or_turns_back() {
+        let enabled = |p: usize| p < 2;
+
+        assert_eq!(Some(0), closest_enabled(4, 5, false, true, enabled));
+        assert_eq!(Some(1), closest_enabled(4, 5, false, false, enabled));
+        assert_eq!(None, closest_enabled(4, 5, false, false, |_| false));
+    }
+
     #[test]
     fn paginate_too_few() {
         let choices = ListOption::from_list(vec!["1", "2", "3"]);
@@ -260,4 +364,24 @@ mod test {
         assert_eq!(false, page.last);
         assert_eq!(6, page.total);
     }
+
+    #[test]
+    fn paginate_rows_keeps_whole_rows() {
+        let choices = ListOption::from_list(vec!["1", "2", "3", "4", "5", "6", "7", "8"]);
+
+        let page = paginate_rows(2, 3, &choices, Some(7));
+
+        assert_eq!(choices[3..8], page.content[..]);
+        assert_eq!(Some(4usize), page.cursor);
+        assert_eq!(false, page.first);
+        assert_eq!(true, page.last);
+        assert_eq!(3, page.start);
+
+        let page = paginate_rows(2, 3, &choices, Some(1));
+
+        assert_eq!(choices[0..6], page.content[..]);
+        assert_eq!(Some(1usize), page.cursor);
+        assert_eq!(true, page.first);
+        assert_eq!(false, page.last);
+    }
 }
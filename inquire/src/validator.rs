@@ -153,6 +153,114 @@ where
     }
 }
 
+/// Validator used in [`TimeSelect`](crate::TimeSelect) prompts.
+///
+/// If the input provided by the user is valid, your validator should return `Ok(Validation::Valid)`.
+///
+/// If the input is not valid, your validator should return `Ok(Validation::Invalid(ErrorMessage))`,
+/// where the content of `ErrorMessage` is recommended to be a string whose content will be displayed
+/// to the user as an error message. It is also recommended that this value gives a helpful feedback to the user.
+///
+/// # Examples
+///
+/// ```
+/// use chrono::{NaiveTime, Timelike};
+/// use inquire::validator::{TimeValidator, Validation};
+///
+/// let validator = |input: NaiveTime| {
+///     if input.hour() < 9 || input.hour() >= 18 {
+///         Ok(Validation::Invalid("Outside of business hours".into()))
+///     } else {
+///         Ok(Validation::Valid)
+///     }
+/// };
+///
+/// assert_eq!(Validation::Valid, validator.validate(NaiveTime::from_hms_opt(10, 0, 0).unwrap())?);
+/// assert_eq!(
+///     Validation::Invalid("Outside of business hours".into()),
+///     validator.validate(NaiveTime::from_hms_opt(20, 0, 0).unwrap())?
+/// );
+/// # Ok::<(), inquire::error::CustomUserError>(())
+/// ```
+#[cfg(feature = "date")]
+pub trait TimeValidator: DynClone {
+    /// Confirm the given input time is a valid value.
+    fn validate(&self, input: chrono::NaiveTime) -> Result<Validation, CustomUserError>;
+}
+
+#[cfg(feature = "date")]
+impl Clone for Box<dyn TimeValidator> {
+    fn clone(&self) -> Self {
+        dyn_clone::clone_box(&**self)
+    }
+}
+
+#[cfg(feature = "date")]
+impl<F> TimeValidator for F
+where
+    F: Fn(chrono::NaiveTime) -> Result<Validation, CustomUserError> + Clone,
+{
+    fn validate(&self, input: chrono::NaiveTime) -> Result<Validation, CustomUserError> {
+        (self)(input)
+    }
+}
+
+/// Validator used in [`DateTimeSelect`](crate::DateTimeSelect) prompts.
+///
+/// If the input provided by the user is valid, your validator should return `Ok(Validation::Valid)`.
+///
+/// If the input is not valid, your validator should return `Ok(Validation::Invalid(ErrorMessage))`,
+/// where the content of `ErrorMessage` is recommended to be a string whose content will be displayed
+/// to the user as an error message. It is also recommended that this value gives a helpful feedback to the user.
+///
+/// # Examples
+///
+/// ```
+/// use chrono::{NaiveDate, NaiveDateTime};
+/// use inquire::validator::{DateTimeValidator, Validation};
+///
+/// let now = NaiveDate::from_ymd_opt(2021, 7, 25).unwrap().and_hms_opt(12, 0, 0).unwrap();
+/// let validator = move |input: NaiveDateTime| {
+///     if input < now {
+///         Ok(Validation::Invalid("The date and time must be in the future".into()))
+///     } else {
+///         Ok(Validation::Valid)
+///     }
+/// };
+///
+/// assert_eq!(
+///     Validation::Valid,
+///     validator.validate(NaiveDate::from_ymd_opt(2021, 7, 25).unwrap().and_hms_opt(13, 0, 0).unwrap())?
+/// );
+/// assert_eq!(
+///     Validation::Invalid("The date and time must be in the future".into()),
+///     validator.validate(NaiveDate::from_ymd_opt(2021, 7, 25).unwrap().and_hms_opt(11, 0, 0).unwrap())?
+/// );
+/// # Ok::<(), inquire::error::CustomUserError>(())
+/// ```
+#[cfg(feature = "date")]
+pub trait DateTimeValidator: DynClone {
+    /// Confirm the given input date and time is a valid value.
+    fn validate(&self, input: chrono::NaiveDateTime) -> Result<Validation, CustomUserError>;
+}
+
+#[cfg(feature = "date")]
+impl Clone for Box<dyn DateTimeValidator> {
+    fn clone(&self) -> Self {
+        dyn_clone::clone_box(&**self)
+    }
+}
+
+#[cfg(feature = "date")]
+impl<F> DateTimeValidator for F
+where
+    F: Fn(chrono::NaiveDateTime) -> Result<Validation, CustomUserError> + Clone,
+{
+    fn validate(&self, input: chrono::NaiveDateTime) -> Result<Validation, CustomUserError> {
+        (self)(input)
+    }
+}
+
 /// Validator used in [`MultiSelect`](crate::MultiSelect) prompts.
 ///
 /// If the input provided by the user is valid, your validator should return `Ok(Validation::Valid)`.
@@ -245,7 +353,7 @@ pub trait CustomTypeValidator<T: ?Sized>: DynClone {
     fn validate(&self, input: &T) -> Result<Validation, CustomUserError>;
 }
 
-impl<T> Clone for Box<dyn CustomTypeValidator<T>> {
+impl<T: ?Sized> Clone for Box<dyn CustomTypeValidator<T>> {
     fn clone(&self) -> Self {
         dyn_clone::clone_box(&**self)
     }
@@ -694,14 +802,327 @@ macro_rules! length {
     };
 }
 
+/// Built-in validator that checks whether the answer is a syntactically valid
+/// JSON document, reporting the line and column of the first error found.
+///
+/// # Examples
+///
+/// ```
+/// use inquire::validator::{JsonValidator, StringValidator, Validation};
+///
+/// let validator = JsonValidator;
+/// assert_eq!(Validation::Valid, validator.validate(r#"{"tags": ["a", "b"]}"#)?);
+/// assert_eq!(
+///     Validation::Invalid("Invalid JSON at line 2, column 1: expected ',' or ']'".into()),
+///     validator.validate("[1, 2\n}")?,
+/// );
+/// # Ok::<(), inquire::error::CustomUserError>(())
+/// ```
+#[derive(Copy, Clone, Debug, Default)]
+pub struct JsonValidator;
+
+impl StringValidator for JsonValidator {
+    fn validate(&self, input: &str) -> Result<Validation, CustomUserError> {
+        let chars = input.chars().collect::<Vec<char>>();
+        let mut checker = JsonChecker { chars, pos: 0 };
+
+        Ok(match checker.check_document() {
+            Ok(()) => Validation::Valid,
+            Err(message) => {
+                let (line, column) = line_and_column(&checker.chars[..checker.pos]);
+                Validation::Invalid(
+                    format!("Invalid JSON at line {line}, column {column}: {message}").into(),
+                )
+            }
+        })
+    }
+}
+
+/// Recursive descent checker of JSON documents, stopping at the first error.
+struct JsonChecker {
+    chars: Vec<char>,
+    pos: usize,
+}
+
+impl JsonChecker {
+    fn peek(&self) -> Option<char> {
+        self.chars.get(self.pos).copied()
+    }
+
+    fn skip_whitespaces(&mut self) {
+        while matches!(self.peek(), Some(' ' | '\t' | '\n' | '\r')) {
+            self.pos += 1;
+        }
+    }
+
+    fn expect(&mut self, expected: char, message: &'static str) -> Result<(), &'static str> {
+        self.skip_whitespaces();
+
+        match self.peek() {
+            Some(c) if c == expected => {
+                self.pos += 1;
+                Ok(())
+            }
+            _ => Err(message),
+        }
+    }
+
+    fn check_document(&mut self) -> Result<(), &'static str> {
+        self.check_value()?;
+        self.skip_whitespaces();
+
+        match self.peek() {
+            Some(_) => Err("unexpected characters after the value"),
+            None => Ok(()),
+        }
+    }
+
+    fn check_value(&mut self) -> Result<(), &'static str> {
+        self.skip_whitespaces();
+
+        match self.peek() {
+            Some('{') => self.check_collection('}', true),
+            Some('[') => self.check_collection(']', false),
+            Some('"') => self.check_string(),
+            Some(c) if c == '-' || c.is_ascii_digit() => self.check_number(),
+            Some(c) if c.is_ascii_alphabetic() => self.check_literal(),
+            Some(_) => Err("expected a value"),
+            None => Err("unexpected end of input"),
+        }
+    }
+
+    fn check_collection(&mut self, closing: char, is_object: bool) -> Result<(), &'static str> {
+        // opening bracket
+        self.pos += 1;
+        self.skip_whitespaces();
+
+        if self.peek() == Some(closing) {
+            self.pos += 1;
+            return Ok(());
+        }
+
+        loop {
+            if is_object {
+                self.skip_whitespaces();
+                if self.peek() != Some('"') {
+                    return Err("expected a string key");
+                }
+                self.check_string()?;
+                self.expect(':', "expected ':'")?;
+            }
+
+            self.check_value()?;
+            self.skip_whitespaces();
+
+            match self.peek() {
+                Some(',') => self.pos += 1,
+                Some(c) if c == closing => {
+                    self.pos += 1;
+                    return Ok(());
+                }
+                _ if is_object => return Err("expected ',' or '}'"),
+                _ => return Err("expected ',' or ']'"),
+            }
+        }
+    }
+
+    fn check_string(&mut self) -> Result<(), &'static str> {
+        // opening quote
+        self.pos += 1;
+
+        loop {
+            match self.peek() {
+                Some('"') => {
+                    self.pos += 1;
+                    return Ok(());
+                }
+                Some('\\') => {
+                    self.pos += 1;
+                    match self.peek() {
+                        Some('"' | '\\' | '/' | 'b' | 'f' | 'n' | 'r' | 't') => self.pos += 1,
+                        Some('u') => {
+                            self.pos += 1;
+                            for _ in 0..4 {
+                                match self.peek() {
+                                    Some(c) if c.is_ascii_hexdigit() => self.pos += 1,
+                                    _ => return Err("invalid unicode escape"),
+                                }
+                            }
+                        }
+                        _ => return Err("invalid escape sequence"),
+                    }
+                }
+                Some(c) if c < ' ' => return Err("control characters must be escaped"),
+                Some(_) => self.pos += 1,
+                None => return Err("unterminated string"),
+            }
+        }
+    }
+
+    fn check_digits(&mut self) -> Result<(), &'static str> {
+        let start = self.pos;
+        while matches!(self.peek(), Some(c) if c.is_ascii_digit()) {
+            self.pos += 1;
+        }
+
+        match self.pos > start {
+            true => Ok(()),
+            false => Err("invalid number"),
+        }
+    }
+
+    fn check_number(&mut self) -> Result<(), &'static str> {
+        if self.peek() == Some('-') {
+            self.pos += 1;
+        }
+
+        match self.peek() {
+            Some('0') => self.pos += 1,
+            _ => self.check_digits()?,
+        }
+
+        if self.peek() == Some('.') {
+            self.pos += 1;
+            self.check_digits()?;
+        }
+
+        if matches!(self.peek(), Some('e' | 'E')) {
+            self.pos += 1;
+            if matches!(self.peek(), Some('+' | '-')) {
+                self.pos += 1;
+            }
+            self.check_digits()?;
+        }
+
+        Ok(())
+    }
+
+    fn check_literal(&mut self) -> Result<(), &'static str> {
+        let start = self.pos;
+        while matches!(self.peek(), Some(c) if c.is_ascii_alphabetic()) {
+            self.pos += 1;
+        }
+
+        let literal = self.chars[start..self.pos].iter().collect::<String>();
+        match literal.as_str() {
+            "true" | "false" | "null" => Ok(()),
+            _ => {
+                self.pos = start;
+                Err("expected a value")
+            }
+        }
+    }
+}
+
+/// Line and column, both starting at 1, of the position right after the given characters.
+fn line_and_column(chars: &[char]) -> (usize, usize) {
+    let line = chars.iter().filter(|c| **c == '\n').count() + 1;
+    let column = chars.iter().rev().take_while(|c| **c != '\n').count() + 1;
+
+    (line, column)
+}
+
+/// Built-in validator that checks the structure of YAML documents, reporting
+/// the line of the first error found.
+///
+/// This is not a YAML parser: it only rejects tabs used for indentation,
+/// unterminated quoted strings and unbalanced brackets of flow collections.
+/// Other errors, such as inconsistent indentation or duplicated keys, are not
+/// caught, so documents passing this validator might still be invalid YAML.
+///
+/// # Examples
+///
+/// ```
+/// use inquire::validator::{StringValidator, Validation, YamlStructureValidator};
+///
+/// let validator = YamlStructureValidator;
+/// assert_eq!(Validation::Valid, validator.validate("name: inquire\ntags: [cli, prompt]")?);
+/// assert_eq!(
+///     Validation::Invalid("Invalid YAML at line 2: tabs can not be used for indentation".into()),
+///     validator.validate("deps:\n\tserde: 1")?,
+/// );
+/// # Ok::<(), inquire::error::CustomUserError>(())
+/// ```
+#[derive(Copy, Clone, Debug, Default)]
+pub struct YamlStructureValidator;
+
+impl YamlStructureValidator {
+    fn check(input: &str) -> Result<(), (usize, String)> {
+        let mut brackets = vec![];
+        let mut quote: Option<(char, usize)> = None;
+
+        for (idx, line) in input.lines().enumerate() {
+            let line_number = idx + 1;
+
+            let indentation = &line[..line.len() - line.trim_start().len()];
+            if quote.is_none() && indentation.contains('\t') {
+                return Err((line_number, "tabs can not be used for indentation".into()));
+            }
+
+            let mut previous = None;
+            for c in line.chars() {
+                match (quote, c) {
+                    (Some((q, _)), c) if c == q => quote = None,
+                    // a quote only starts a quoted string at the start of a value
+                    (None, '"' | '\'')
+                        if matches!(
+                            previous,
+                            None | Some(' ' | '\t' | ':' | '-' | '[' | '{' | ',')
+                        ) =>
+                    {
+                        quote = Some((c, line_number));
+                    }
+                    (None, '#') if matches!(previous, None | Some(' ' | '\t')) => break,
+                    (None, '[' | '{') => brackets.push((c, line_number)),
+                    (None, ']' | '}') => {
+                        let expected = match c {
+                            ']' => '[',
+                            _ => '{',
+                        };
+
+                        match brackets.pop() {
+                            Some((open, _)) if open == expected => {}
+                            _ => return Err((line_number, format!("unexpected '{c}'"))),
+                        }
+                    }
+                    _ => {}
+                }
+
+                previous = Some(c);
+            }
+        }
+
+        if let Some((_, line_number)) = quote {
+            return Err((line_number, "unterminated quoted string".into()));
+        }
+
+        if let Some((open, line_number)) = brackets.pop() {
+            return Err((line_number, format!("unclosed '{open}'")));
+        }
+
+        Ok(())
+    }
+}
+
+impl StringValidator for YamlStructureValidator {
+    fn validate(&self, input: &str) -> Result<Validation, CustomUserError> {
+        Ok(match Self::check(input) {
+            Ok(()) => Validation::Valid,
+            Err((line, message)) => {
+                Validation::Invalid(format!("Invalid YAML at line {line}: {message}").into())
+            }
+        })
+    }
+}
+
 #[cfg(test)]
 mod validators_test {
     use crate::{
         error::CustomUserError,
         list_option::ListOption,
         validator::{
-            ExactLengthValidator, MaxLengthValidator, MinLengthValidator, MultiOptionValidator,
-            StringValidator, Validation,
+            ExactLengthValidator, JsonValidator, MaxLengthValidator, MinLengthValidator,
+            MultiOptionValidator, StringValidator, Validation, YamlStructureValidator,
         },
     };
 
@@ -925,4 +1346,73 @@ mod validators_test {
 
         Ok(())
     }
+
+    fn error_of(validation: Validation) -> String {
+        match validation {
+            Validation::Invalid(crate::validator::ErrorMessage::Custom(msg)) => msg,
+            validation => panic!("unexpected {:?}", validation),
+        }
+    }
+
+    #[test]
+    fn json_validator_reports_first_error() -> Result<(), CustomUserError> {
+        let validator = JsonValidator;
+
+        assert_eq!(
+            Validation::Valid,
+            validator.validate("{\"a\": [1, -0.5e+3, \"\\u00e9\"], \"b\": {\"c\": null}}")?
+        );
+
+        let cases = [
+            ("", "line 1, column 1: unexpected end of input"),
+            ("{\"a\" 1}", "line 1, column 6: expected ':'"),
+            (
+                "{\n  \"a\": 1,\n  b: 2\n}",
+                "line 3, column 3: expected a string key",
+            ),
+            ("[01]", "line 1, column 3: expected ',' or ']'"),
+            ("[tru]", "line 1, column 2: expected a value"),
+            ("\"abc", "line 1, column 5: unterminated string"),
+            (
+                "{} {}",
+                "line 1, column 4: unexpected characters after the value",
+            ),
+        ];
+
+        for (input, error) in cases {
+            assert_eq!(
+                format!("Invalid JSON at {error}"),
+                error_of(validator.validate(input)?)
+            );
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn yaml_structure_validator_checks_structure() -> Result<(), CustomUserError> {
+        let validator = YamlStructureValidator;
+
+        assert_eq!(
+            Validation::Valid,
+            validator.validate(
+                "name: it's fine # [not closed\nlist: [a, {b: \"c\"}]\nmsg: 'multi\n  line'"
+            )?
+        );
+
+        let cases = [
+            ("a:\n  b: [1, 2}", "line 2: unexpected '}'"),
+            ("a: {\nb: c", "line 1: unclosed '{'"),
+            ("a: \"text\nb: c", "line 1: unterminated quoted string"),
+        ];
+
+        for (input, error) in cases {
+            assert_eq!(
+                format!("Invalid YAML at {error}"),
+                error_of(validator.validate(input)?)
+            );
+        }
+
+        Ok(())
+    }
 }